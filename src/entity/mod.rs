@@ -1,100 +1,149 @@
-use crate::types::{PlaceholderType, NameType, LocationType, PhoneType, DistributionType, PlaceholderArgs};
+use crate::types::{PlaceholderType, NameType, LocationType, PhoneType, InternetType, NetworkType, TextType, DistributionType, PlaceholderArgs, TimeFormat};
 use crate::parser::Placeholder;
 use crate::generator::name::Name;
 use crate::generator::location::Location;
 use crate::generator::primitive::{Float,Guid,Int,Set};
 use crate::generator::phone::Phone;
+use crate::generator::internet::Internet;
+use crate::generator::network::{Ip, Network, Port};
+use crate::generator::text::Text;
 use crate::generator::distribution::Normal;
+use crate::generator::date::{Date, Time};
+use crate::rng;
 
+use rand::Rng;
 use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Entity {
     name: Name,
     location: Location,
     phone: Phone,
-    data: HashMap<String, String>
+    internet: Internet,
+    network: Network,
+    data: HashMap<String, Option<String>>
 }
 
 impl Entity {
     pub fn new() -> Entity {
-        Entity {
-            name: Name::new(),
-            location: Location::new(),
-            phone: Phone::new(),
-            data: HashMap::new()
-        }
+        Entity::default()
     }
 
-    pub fn value_of(&mut self, placeholder: &Placeholder) -> String {
+    /// The entity's value for a placeholder, or `None` when its `null=` modifier chose null.
+    /// Both are remembered, so the entity gives the same answer every time it is asked.
+    pub fn value_of<R: Rng + ?Sized>(&mut self, placeholder: &Placeholder, rng: &mut R) -> Option<String> {
         let placeholder_string = placeholder.to_string();
-        if self.data.contains_key(&placeholder_string) {
-            return self.data.get(&placeholder_string).unwrap().to_string();
-        } else {
-            let generated_data: String = self.generate(&placeholder).unwrap();
-            let returned_data: String = generated_data.clone();
-            self.data.insert(placeholder_string, generated_data);
-            return returned_data;
+        if let Some(data) = self.data.get(&placeholder_string) {
+            return data.clone();
         }
+        let is_null: bool = placeholder.modifiers.null_probability
+            .is_some_and(|probability: f64| rng.gen_bool(probability));
+        let generated_data: Option<String> = match is_null {
+            true => None,
+            false => Some(self.generate(placeholder, rng).unwrap())
+        };
+        self.data.insert(placeholder_string, generated_data.clone());
+        generated_data
     }
 
     pub fn validate(placeholder: &Placeholder) -> Option<String> {
-        Entity::new().generate(&placeholder)
+        Entity::new().generate(placeholder, &mut rng::validation_rng())
     }
 
-    fn generate(&mut self, placeholder: &Placeholder) -> Option<String> {
+    fn generate<R: Rng + ?Sized>(&mut self, placeholder: &Placeholder, rng: &mut R) -> Option<String> {
         let dtype: PlaceholderType = placeholder.data_type.clone();
         let argtype: Option<PlaceholderArgs> = placeholder.data_args.clone();
 
         match dtype {
-            PlaceholderType::Name(name_type) => self.generate_name(&name_type),
-            PlaceholderType::Location(location_type) => self.generate_location(&location_type),
-            PlaceholderType::Phone(phone_type) => self.generate_phone(&phone_type),
-            PlaceholderType::Distribution(distribution_type) => self.generate_distribution(&distribution_type, argtype),
-            PlaceholderType::Float => self.generate_float(argtype),
-            PlaceholderType::Int => self.generate_int(argtype),
-            PlaceholderType::Set => self.generate_set(argtype),
-            PlaceholderType::Guid => Some(Guid::generate()),
+            PlaceholderType::Name(name_type) => self.generate_name(rng, &name_type),
+            PlaceholderType::Location(location_type) => self.generate_location(rng, &location_type),
+            PlaceholderType::Phone(phone_type) => self.generate_phone(rng, &phone_type),
+            PlaceholderType::Internet(internet_type) => self.generate_internet(rng, &internet_type, argtype),
+            PlaceholderType::Network(network_type) => self.generate_network(rng, &network_type, argtype),
+            PlaceholderType::Text(text_type) => self.generate_text(rng, &text_type, argtype),
+            PlaceholderType::Distribution(distribution_type) => self.generate_distribution(rng, &distribution_type, argtype),
+            PlaceholderType::Float => self.generate_float(rng, argtype),
+            PlaceholderType::Int => self.generate_int(rng, argtype),
+            PlaceholderType::Set => self.generate_set(rng, argtype),
+            PlaceholderType::Guid => Some(Guid::generate(rng)),
+            PlaceholderType::Date => self.generate_time(rng, argtype, Date::generate),
+            PlaceholderType::DateTime => self.generate_time(rng, argtype, Time::generate),
+            // Document and external values are supplied by the template's render context, not by an entity.
+            PlaceholderType::Doc(_) | PlaceholderType::External(_) => None
         }
     }
 
-    fn generate_name(&mut self, dtype: &NameType) -> Option<String> {
+    fn generate_name<R: Rng + ?Sized>(&mut self, rng: &mut R, dtype: &NameType) -> Option<String> {
         match dtype {
-            NameType::First => Some(self.name.first()),
-            NameType::Last => Some(self.name.last()),
-            NameType::Full => Some(self.name.full())
+            NameType::First => Some(self.name.first(rng)),
+            NameType::Last => Some(self.name.last(rng)),
+            NameType::Full => Some(self.name.full(rng))
         }
     }
 
-    fn generate_location(&mut self, dtype: &LocationType) -> Option<String> {
+    fn generate_location<R: Rng + ?Sized>(&mut self, rng: &mut R, dtype: &LocationType) -> Option<String> {
         match dtype {
-            LocationType::Place => Some(self.location.place()),
-            LocationType::Street => Some(self.location.street()),
-            LocationType::Address => Some(self.location.address())
+            LocationType::Place => Some(self.location.place(rng)),
+            LocationType::Street => Some(self.location.street(rng)),
+            LocationType::Address => Some(self.location.address(rng))
         }
     }
 
-    fn generate_phone(&mut self, dtype: &PhoneType) -> Option<String> {
+    fn generate_phone<R: Rng + ?Sized>(&mut self, rng: &mut R, dtype: &PhoneType) -> Option<String> {
         match dtype {
-            PhoneType::Any => Some(self.phone.phone()),
-            PhoneType::Mobile => Some(self.phone.mobile()),
-            PhoneType::Landline => Some(self.phone.landline())
+            PhoneType::Any => Some(self.phone.phone(rng)),
+            PhoneType::Mobile => Some(self.phone.mobile(rng)),
+            PhoneType::Landline => Some(self.phone.landline(rng))
+        }
+    }
+
+    fn generate_internet<R: Rng + ?Sized>(&mut self, rng: &mut R, dtype: &InternetType, argtype: Option<PlaceholderArgs>) -> Option<String> {
+        argtype.and_then(|args: PlaceholderArgs| match (dtype, args) {
+            (InternetType::Username, PlaceholderArgs::Username { pattern, lowercase }) =>
+                Some(self.internet.username(rng, &mut self.name, &pattern, lowercase)),
+            (InternetType::Email, PlaceholderArgs::Email { pattern, lowercase, domains }) =>
+                Some(self.internet.email(rng, &mut self.name, &pattern, lowercase, &domains)),
+            _ => None
+        })
+    }
+
+    fn generate_network<R: Rng + ?Sized>(&mut self, rng: &mut R, dtype: &NetworkType, argtype: Option<PlaceholderArgs>) -> Option<String> {
+        match (dtype, argtype) {
+            (NetworkType::Ipv4, Some(PlaceholderArgs::Ipv4 { network, prefix })) => Some(Ip::v4(rng, network, prefix)),
+            (NetworkType::Ipv6, Some(PlaceholderArgs::Ipv6 { network, prefix })) => Some(Ip::v6(rng, network, prefix)),
+            (NetworkType::Mac, _) => Some(self.network.mac(rng)),
+            (NetworkType::Hostname, Some(PlaceholderArgs::Host { domain })) => Some(self.network.hostname(rng, domain.as_deref())),
+            (NetworkType::Url, Some(PlaceholderArgs::Url { scheme, domain })) => Some(self.network.url(rng, &scheme, domain.as_deref())),
+            (NetworkType::Port, Some(PlaceholderArgs::Port { min, max })) => Some(Port::generate(rng, min, max)),
+            (NetworkType::UserAgent, _) => Some(self.network.user_agent(rng)),
+            _ => None
         }
     }
 
-    fn generate_distribution(&mut self, dtype: &DistributionType, argtype: Option<PlaceholderArgs>) -> Option<String> {
+    fn generate_text<R: Rng + ?Sized>(&mut self, rng: &mut R, dtype: &TextType, argtype: Option<PlaceholderArgs>) -> Option<String> {
         argtype.and_then(|args: PlaceholderArgs| match (dtype, args) {
-            (DistributionType::Normal, PlaceholderArgs::Normal { mean, stddev }) => Some(Normal::generate(mean, stddev).to_string()),
+            (TextType::Words, PlaceholderArgs::Length { min, max }) => Some(Text::words(rng, min, max)),
+            (TextType::Sentence, PlaceholderArgs::Length { min, max }) => Some(Text::sentence(rng, min, max)),
+            (TextType::Paragraphs, PlaceholderArgs::Paragraphs { count }) => Some(Text::paragraphs(rng, count)),
+            (TextType::Chars, PlaceholderArgs::Length { min, max }) => Some(Text::chars(rng, min, max)),
             _ => None
         })
     }
 
-    fn generate_int(&mut self, args: Option<PlaceholderArgs>) -> Option<String> {
+    fn generate_distribution<R: Rng + ?Sized>(&mut self, rng: &mut R, dtype: &DistributionType, argtype: Option<PlaceholderArgs>) -> Option<String> {
+        argtype.and_then(|args: PlaceholderArgs| match (dtype, args) {
+            (DistributionType::Normal, PlaceholderArgs::Normal { mean, stddev }) => Normal::new(mean, stddev).ok()
+                .map(|normal: Normal| normal.generate(rng).to_string()),
+            _ => None
+        })
+    }
+
+    fn generate_int<R: Rng + ?Sized>(&mut self, rng: &mut R, args: Option<PlaceholderArgs>) -> Option<String> {
         match args {
-            Some(PlaceholderArgs::Int { min, max })  => Some(Int::generate(min, max).to_string()),
+            Some(PlaceholderArgs::Int { min, max })  => Some(Int::generate(rng, min, max).to_string()),
             Some(PlaceholderArgs::IntRepeated { min, max, repeat })  => {
                 let generated: Vec<String> = (0..repeat)
-                    .map(|_: u64| Int::generate(min, max).to_string())
+                    .map(|_: u64| Int::generate(rng, min, max).to_string())
                     .collect();
                 Some(generated.join(""))
             },
@@ -102,16 +151,23 @@ impl Entity {
         }
     }
 
-    fn generate_float(&mut self, argtype: Option<PlaceholderArgs>) -> Option<String> {
+    fn generate_float<R: Rng + ?Sized>(&mut self, rng: &mut R, argtype: Option<PlaceholderArgs>) -> Option<String> {
+        argtype.and_then(|args: PlaceholderArgs| match args {
+            PlaceholderArgs::Float { min, max } => Some(Float::generate(rng, min, max).to_string()),
+            _ => None
+        })
+    }
+
+    fn generate_time<R: Rng + ?Sized>(&mut self, rng: &mut R, argtype: Option<PlaceholderArgs>, generate: fn(&mut R, i64, i64, &TimeFormat) -> String) -> Option<String> {
         argtype.and_then(|args: PlaceholderArgs| match args {
-            PlaceholderArgs::Float { min, max } => Some(Float::generate(min, max).to_string()),
+            PlaceholderArgs::Time { from, to, format } => Some(generate(rng, from, to, &format)),
             _ => None
         })
     }
 
-    fn generate_set(&mut self, argtype: Option<PlaceholderArgs>) -> Option<String> {
+    fn generate_set<R: Rng + ?Sized>(&mut self, rng: &mut R, argtype: Option<PlaceholderArgs>) -> Option<String> {
         argtype.and_then(|args: PlaceholderArgs| match args {
-            PlaceholderArgs::Set { options } => Some(Set::generate(options)),
+            PlaceholderArgs::Set { options } => Some(Set::generate(rng, &options)),
             _ => None
         })
     }
@@ -0,0 +1 @@
+pub static DOMAIN: [&str; 3] = ["example.com","example.net","example.org"];
@@ -0,0 +1 @@
+pub static HOST_PREFIX: [&str; 16] = ["api","app","auth","cache","cdn","db","files","gateway","mail","proxy","queue","search","static","vpn","web","worker"];
@@ -1 +1 @@
-pub const LAST_NAME: [&'static str; 21985] = ["Aaberg","Aalst","Aara","Aaren","Aarika","Aaron","Aaronson","Ab","Aba","Abad","Abagael","Abagail","Abana","Abate","Abba","Abbate","Abbe","Abbey","Abbi","Abbie","Abbot","Abbotsen","Abbotson","Abbotsun","Abbott","Abbottson","Abby","Abbye","Abdel","Abdella","Abdu","Abdul","Abdulla","Abe","Abebi","Abel","Abelard","Abell","Abercromby","Abernathy","Abernon","Abert","Abeu","Abey","Abie","Abigael","Abigail","Abigale","Abijah","Abisha","Abisia","Abixah","Abner","Aborn","Abott","Abra","Abraham","Abrahams","Abrahamsen","Abrahan","Abram","Abramo","Abrams","Abramson","Abran","Abroms","Absa","Absalom","Abshier","Acacia","Acalia","Accalia","Ace","Acey","Acherman","Achilles","Achorn","Acie","Acima","Acker","Ackerley","Ackerman","Ackler","Ackley","Acquah","Acus","Ad","Ada","Adabel","Adabelle","Adachi","Adah","Adaha","Adai","Adaiha","Adair","Adal","Adala","Adalai","Adalard","Adalbert","Adalheid","Adali","Adalia","Adaliah","Adalie","Adaline","Adall","Adallard","Adam","Adama","Adamec","Adamek","Adamik","Adamina","Adaminah","Adamis","Adamo","Adamok","Adams","Adamsen","Adamski","Adamson","Adamsun","Adan","Adao","Adar","Adara","Adaurd","Aday","Adda","Addam","Addi","Addia","Addie","Addiego","Addiel","Addis","Addison","Addy","Ade","Adebayo","Adel","Adela","Adelaida","Adelaide","Adelaja","Adelbert","Adele","Adelheid","Adelia","Adelice","Adelina","Adelind","Adeline","Adella","Adelle","Adelpho","Adelric","Adena","Ader","Adest","Adey","Adham","Adhamh","Adhern","Adi","Adiana","Adiel","Adiell","Adigun","Adila","Adim","Adin","Adina","Adine","Adis","Adkins","Adlai","Adlar","Adlare","Adlay","Adlee","Adlei","Adler","Adley","Adna","Adnah","Adne","Adnopoz","Ado","Adolf","Adolfo","Adolph","Adolphe","Adolpho","Adolphus","Adon","Adonis","Adora","Adore","Adoree","Adorl","Adorne","Adrea","Adrell","Adria","Adriaens","Adrial","Adrian","Adriana","Adriane","Adrianna","Adrianne","Adriano","Adriel","Adriell","Adrien","Adriena","Adriene","Adrienne","Adur","Aekerly","Aelber","Aenea","Aeneas","Aeneus","Aeniah","Aenneea","Aeriel","Aeriela","Aeriell","Affer","Affra","Affrica","Afra","Africa","Africah","Afrika","Afrikah","Afton","Ag","Agace","Agamemnon","Agan","Agata","Agate","Agatha","Agathe","Agathy","Agbogla","Agee","Aggappe","Aggappera","Aggappora","Aggarwal","Aggi","Aggie","Aggri","Aggy","Agle","Agler","Agna","Agnella","Agnes","Agnese","Agnesse","Agneta","Agnew","Agnola","Agostino","Agosto","Agretha","Agripina","Agrippina","Aguayo","Agueda","Aguie","Aguste","Agustin","Ahab","Aharon","Ahasuerus","Ahders","Ahearn","Ahern","Ahl","Ahlgren","Ahmad","Ahmar","Ahmed","Ahola","Aholah","Aholla","Ahoufe","Ahouh","Ahrendt","Ahrens","Ahron","Aia","Aida","Aidan","Aiden","Aiello","Aigneis","Aiken","Aila","Ailbert","Aile","Ailee","Aileen","Ailene","Ailey","Aili","Ailin","Ailina","Ailis","Ailsa","Ailssa","Ailsun","Ailyn","Aime","Aimee","Aimil","Aimo","Aindrea","Ainslee","Ainsley","Ainslie","Ainsworth","Airel","Aires","Airla","Airlee","Airlia","Airliah","Airlie","Aisha","Ajani","Ajax","Ajay","Ajit","Akanke","Akel","Akela","Aker","Akerboom","Akerley","Akers","Akeyla","Akeylah","Akili","Akim","Akin","Akins","Akira","Aklog","Aksel","Aksoyn","Al","Alabaster","Alage","Alain","Alaine","Alair","Alake","Alameda","Alan","Alana","Alanah","Aland","Alane","Alanna","Alano","Alansen","Alanson","Alard","Alaric","Alarice","Alarick","Alarise","Alasdair","Alastair","Alasteir","Alaster","Alatea","Alathia","Alayne","Alba","Alban","Albarran","Albemarle","Alben","Alber","Alberic","Alberik","Albers","Albert","Alberta","Albertina","Albertine","Alberto","Albertson","Albie","Albin","Albina","Albion","Alboran","Albrecht","Albric","Albright","Albur","Alburg","Alburga","Alby","Alcina","Alcine","Alcinia","Alcock","Alcot","Alcott","Alcus","Alda","Aldarcie","Aldarcy","Aldas","Alded","Alden","Aldercy","Alderman","Alderson","Aldin","Aldis","Aldo","Aldon","Aldora","Aldos","Aldous","Aldred","Aldredge","Aldric","Aldrich","Aldridge","Alduino","Aldus","Aldwin","Aldwon","Alec","Alecia","Aleck","Aleda","Aleece","Aleedis","Aleen","Aleetha","Alegre","Alejandra","Alejandrina","Alejandro","Alejo","Alejoa","Alek","Aleksandr","Alena","Alene","Alenson","Aleras","Aleris","Aleron","Alesandrini","Alessandra","Alessandro","Aleta","Aletha","Alethea","Alethia","Aletta","Alex","Alexa","Alexander","Alexandr","Alexandra","Alexandre","Alexandria","Alexandrina","Alexandro","Alexandros","Alexei","Alexi","Alexia","Alexina","Alexine","Alexio","Alexis","Aley","Aleydis","Alf","Alfeus","Alfi","Alfie","Alfons","Alfonse","Alfonso","Alfonzo","Alford","Alfred","Alfreda","Alfredo","Alfy","Algar","Alger","Algernon","Algie","Alguire","Algy","Ali","Alia","Aliber","Alic","Alica","Alice","Alicea","Alicia","Alick","Alida","Alidia","Alidis","Alidus","Alie","Alika","Alikee","Alina","Aline","Alinna","Alis","Alisa","Alisan","Alisander","Alisen","Alisha","Alisia","Alison","Alissa","Alistair","Alister","Alisun","Alita","Alitha","Alithea","Alithia","Alitta","Alius","Alix","Aliza","Alla","Allain","Allan","Allana","Allanson","Allard","Allare","Allayne","Allbee","Allcot","Alleen","Allegra","Allen","Allene","Alleras","Allerie","Alleris","Allerus","Alley","Alleyn","Alleyne","Alli","Allianora","Alliber","Allie","Allin","Allina","Allis","Allisan","Allison","Allissa","Allista","Allister","Allistir","Allix","Allmon","Allred","Allrud","Allsopp","Allsun","Allveta","Allwein","Allx","Ally","Allyce","Allyn","Allys","Allyson","Alma","Almallah","Almeda","Almeeta","Almeida","Almena","Almeria","Almeta","Almira","Almire","Almita","Almond","Almund","Alo","Alodee","Alodi","Alodie","Aloin","Aloise","Aloisia","Aloisius","Aloke","Alon","Alonso","Alonzo","Aloysia","Aloysius","Alper","Alpers","Alpert","Alphard","Alpheus","Alphonsa","Alphonse","Alphonsine","Alphonso","AlrZc","Alric","Alrich","Alrick","Alroi","Alroy","Also","Alston","Alsworth","Alta","Altaf","Alten","Althea","Althee","Altheta","Altis","Altman","Alton","Aluin","Aluino","Alurd","Alurta","Alva","Alvan","Alvar","Alvarez","Alver","Alvera","Alverson","Alverta","Alves","Alveta","Alviani","Alvie","Alvin","Alvina","Alvinia","Alvira","Alvis","Alvita","Alvord","Alvy","Alwin","Alwitt","Alwyn","Alyce","Alyda","Alyose","Alyosha","Alys","Alysa","Alyse","Alysia","Alyson","Alysoun","Alyss","Alyssa","Alyworth","Ama","Amabel","Amabelle","Amabil","Amadas","Amadeo","Amadeus","Amadis","Amado","Amador","Amadus","Amal","Amalbena","Amalberga","Amalbergas","Amalburga","Amalea","Amalee","Amaleta","Amalia","Amalie","Amalita","Amalle","Aman","Amand","Amanda","Amandi","Amandie","Amando","Amandy","Amann","Amar","Amara","Amaral","Amaras","Amarette","Amargo","Amari","Amarillas","Amarillis","Amaris","Amary","Amaryl","Amaryllis","Amasa","Amata","Amathist","Amathiste","Amati","Amato","Amatruda","Amaty","Amber","Amberly","Ambert","Ambie","Amble","Ambler","Ambrogino","Ambrogio","Ambros","Ambrosane","Ambrose","Ambrosi","Ambrosia","Ambrosine","Ambrosio","Ambrosius","Ambur","Amby","Ame","Amedeo","Amelia","Amelie","Amelina","Ameline","Amelita","Amena","Amend","Amerigo","Amero","Amersham","Amery","Ames","Amethist","Amethyst","Ami","Amias","Amice","Amick","Amie","Amiel","Amieva","Amii","Amil","Amin","Aminta","Amir","Amitie","Amity","Amling","Ammadas","Ammadis","Ammamaria","Ammann","Ammon","Amoakuh","Amor","Amora","Amoreta","Amorete","Amorette","Amorita","Amoritta","Amory","Amos","Amr","Amrita","Amsden","Amund","Amy","Amyas","Amye","An","Ana","Anabal","Anabel","Anabella","Anabelle","Anagnos","Analiese","Analise","Anallese","Anallise","Anana","Ananna","Anastas","Anastase","Anastasia","Anastasie","Anastasio","Anastasius","Anastassia","Anastatius","Anastice","Anastos","Anatol","Anatola","Anatole","Anatolio","Anatollo","Ancalin","Ancel","Ancelin","Anceline","Ancell","Anchie","Ancier","Ancilin","Andee","Andeee","Andel","Ander","Anderea","Anderegg","Anderer","Anders","Andersen","Anderson","Andert","Andi","Andie","Andonis","Andra","Andrade","Andras","Andre","Andrea","Andreana","Andreas","Andree","Andrei","Andrej","Andrel","Andres","Andrew","Andrews","Andrey","Andri","Andria","Andriana","Andrien","Andriette","Andris","Andromache","Andromada","Andromeda","Andromede","Andros","Androw","Andrus","Andryc","Andy","Anestassia","Anet","Anett","Anetta","Anette","Aney","Angadreme","Angadresma","Ange","Angel","Angela","Angele","Angeli","Angelia","Angelica","Angelico","Angelika","Angelina","Angeline","Angelique","Angelis","Angelita","Angell","Angelle","Angelo","Angi","Angie","Angil","Angle","Anglim","Anglo","Angrist","Angus","Angy","Anh","Ania","Aniakudo","Anica","Aniela","Anil","Anis","Anissa","Anita","Anitra","Aniweta","Anjali","Anjanette","Anjela","Ankeny","Ankney","Ann","Ann-Marie","Anna","Anna-Diana","Anna-Diane","Anna-Maria","Annabal","Annabel","Annabela","Annabell","Annabella","Annabelle","Annadiana","Annadiane","Annalee","Annaliese","Annalise","Annamaria","Annamarie","Anne","Anne-Corinne","Anne-Marie","Annecorinne","Anneliese","Annelise","Annemarie","Annetta","Annette","Anni","Annia","Annice","Annie","Anniken","Annis","Annissa","Annmaria","Annmarie","Annnora","Annora","Annorah","Annunciata","Anny","Anora","Anse","Ansel","Ansela","Ansell","Anselm","Anselma","Anselme","Anselmi","Anselmo","Ansilma","Ansilme","Ansley","Anson","Anstice","Anstus","Antebi","Anthe","Anthea","Anthia","Anthiathia","Anthony","Antin","Antipas","Antipus","Antoine","Antoinetta","Antoinette","Anton","Antone","Antonella","Antonetta","Antoni","Antonia","Antonie","Antonietta","Antonin","Antonina","Antonino","Antonio","Antonius","Antons","Antony","Antrim","Anurag","Anuska","Any","Anya","Anyah","Anzovin","Apfel","Apfelstadt","Apgar","Aphra","Aphrodite","Apicella","Apollo","Apollus","Apostles","Appel","Apple","Appleby","Appledorf","Applegate","Appleton","Appolonia","Apps","April","Aprile","Aprilette","Apthorp","Apul","Ara","Arabeila","Arabel","Arabela","Arabele","Arabella","Arabelle","Arad","Arakawa","Araldo","Aramanta","Aramen","Aramenta","Araminta","Aran","Arand","Arathorn","Arbe","Arber","Arbuckle","Arch","Archaimbaud","Archambault","Archangel","Archer","Archibald","Archibaldo","Archibold","Archie","Archle","Archy","Ard","Arda","Ardath","Arde","Ardeen","Ardeha","Ardehs","Ardel","Ardelia","Ardelis","Ardell","Ardella","Ardelle","Arden","Ardene","Ardenia","Ardeth","Ardie","Ardin","Ardine","Ardis","Ardisj","Ardith","Ardme","Ardolino","Ardra","Ardrey","Ardussi","Ardy","Ardyce","Ardys","Ardyth","Arel","Arela","Arella","Arelus","Aret","Areta","Aretha","Aretina","Aretta","Arette","Arezzini","Argent","Argile","Argus","Argyle","Argyres","Arhna","Ari","Aria","Ariadne","Ariana","Ariane","Arianie","Arianna","Arianne","Aribold","Aric","Arica","Arick","Aridatha","Arie","Ariel","Ariela","Ariella","Arielle","Ariew","Arin","Ario","Arissa","Aristotle","Arita","Arjan","Arjun","Ark","Arlan","Arlana","Arlee","Arleen","Arlen","Arlena","Arlene","Arleta","Arlette","Arley","Arleyne","Arlie","Arliene","Arlin","Arlina","Arlinda","Arline","Arlo","Arlon","Arluene","Arly","Arlyn","Arlyne","Arlynne","Armalda","Armalla","Armallas","Arman","Armand","Armanda","Armando","Armbrecht","Armbruster","Armelda","Armil","Armilda","Armilla","Armillas","Armillda","Armillia","Armin","Armington","Armitage","Armond","Armstrong","Armyn","Arnaldo","Arnaud","Arndt","Arne","Arnelle","Arney","Arni","Arnie","Arno","Arnold","Arnoldo","Arnon","Arnst","Arnuad","Arnulfo","Arny","Arola","Aron","Arondel","Arondell","Aronoff","Aronow","Aronson","Arquit","Arratoon","Arri","Arria","Arrio","Arron","Arst","Art","Arta","Artair","Artamas","Arte","Artema","Artemas","Artemis","Artemisa","Artemisia","Artemus","Arther","Arthur","Artie","Artima","Artimas","Artina","Artur","Arturo","Artus","Arty","Aruabea","Arun","Arundel","Arundell","Arv","Arva","Arvad","Arvell","Arvid","Arvie","Arvin","Arvind","Arvo","Arvonio","Arvy","Ary","Aryn","As","Asa","Asabi","Asante","Asaph","Asare","Aschim","Ase","Asel","Ash","Asha","Ashbaugh","Ashbey","Ashby","Ashelman","Ashely","Asher","Ashford","Ashia","Ashien","Ashil","Ashjian","Ashla","Ashlan","Ashlee","Ashleigh","Ashlen","Ashley","Ashli","Ashlie","Ashlin","Ashling","Ashly","Ashman","Ashmead","Ashok","Ashraf","Ashti","Ashton","Ashwell","Ashwin","Asia","Askari","Askwith","Aslam","Asp","Aspa","Aspasia","Aspia","Asquith","Assisi","Asta","Astera","Asteria","Astor","Astra","Astraea","Astrahan","Astrea","Astred","Astri","Astrid","Astrix","Astto","Asuncion","Atal","Atalanta","Atalante","Atalanti","Atalaya","Atalayah","Atalee","Ataliah","Atalie","Atalya","Atcliffe","Athal","Athalee","Athalia","Athalie","Athalla","Athallia","Athelstan","Athena","Athene","Athenian","Athey","Athiste","Atiana","Atkins","Atkinson","Atlanta","Atlante","Atlas","Atlee","Atonsah","Atrice","Atronna","Attah","Attalanta","Attalie","Attenborough","Attenweiler","Atterbury","Atthia","Attlee","Attwood","Atul","Atwater","Atwekk","Atwood","Atworth","Au","Aubarta","Aube","Auberbach","Auberon","Aubert","Auberta","Aubigny","Aubin","Aubine","Aubree","Aubreir","Aubrette","Aubrey","Aubrie","Aubry","Auburn","Auburta","Aubyn","Audette","Audi","Audie","Audley","Audly","Audra","Audras","Audre","Audres","Audrey","Audri","Audrie","Audris","Audrit","Audry","Audrye","Audsley","Audun","Audwen","Audwin","Audy","Auerbach","Aufmann","Augie","August","Augusta","Auguste","Augustin","Augustina","Augustine","Augusto","Augustus","Augy","Aulea","Auliffe","Aun","Aundrea","Aunson","Aura","Aurea","Aurel","Aurelea","Aurelia","Aurelie","Aurelio","Aurelius","Auria","Auric","Aurie","Aurilia","Aurita","Aurlie","Auroora","Aurora","Aurore","Aurthur","Ause","Austen","Austin","Austina","Austine","Auston","Australia","Austreng","Autrey","Autry","Autum","Autumn","Auvil","Av","Ava","Avan","Avaria","Ave","Avelin","Aveline","Avera","Averell","Averi","Averil","Averill","Averir","Avery","Averyl","Avi","Avictor","Avie","Avigdor","Avilla","Avis","Avitzur","Aviv","Aviva","Avivah","Avner","Avra","Avraham","Avram","Avril","Avrit","Avrom","Avron","Avruch","Awad","Ax","Axe","Axel","Aylmar","Aylmer","Aylsworth","Aylward","Aymer","Ayn","Aynat","Ayo","Ayres","Azal","Azalea","Azaleah","Azar","Azarcon","Azaria","Azarria","Azelea","Azeria","Aziza","Azpurua","Azral","Azriel","Baal","Baalbeer","Baalman","Bab","Babara","Babb","Babbette","Babbie","Babby","Babcock","Babette","Babita","Babs","Bac","Bacchus","Bach","Bachman","Backer","Backler","Bacon","Badger","Badr","Baecher","Bael","Baelbeer","Baer","Baerl","Baerman","Baese","Bagger","Baggett","Baggott","Baggs","Bagley","Bahner","Bahr","Baiel","Bail","Bailar","Bailey","Bailie","Baillie","Baillieu","Baily","Bain","Bainbridge","Bainbrudge","Bainter","Baird","Baiss","Bajaj","Bak","Bakeman","Bakemeier","Baker","Bakerman","Bakki","Bal","Bala","Balas","Balbinder","Balbur","Balcer","Balch","Balcke","Bald","Baldridge","Balduin","Baldwin","Bale","Baler","Balf","Balfore","Balfour","Balkin","Ball","Ballard","Balliett","Balling","Ballinger","Balliol","Ballman","Ballou","Balmuth","Balough","Balsam","Balthasar","Balthazar","Bamberger","Bambi","Bambie","Bamby","Bamford","Ban","Bancroft","Bandeen","Bander","Bandler","Bandur","Banebrudge","Banerjee","Bang","Bank","Banks","Banky","Banna","Bannasch","Bannerman","Bannister","Bannon","Banquer","Banwell","Baptist","Baptista","Baptiste","Baptlsta","Bar","Bara","Barabas","Barabbas","Baram","Baras","Barayon","Barb","Barbabas","Barbabra","Barbara","Barbara-Anne","Barbaraanne","Barbarese","Barbaresi","Barbe","Barbee","Barber","Barbette","Barbey","Barbi","Barbie","Barbour","Barboza","Barbra","Barbur","Barbuto","Barby","Barcellona","Barclay","Barcot","Barcroft","Barcus","Bard","Barde","Barden","Bardo","Barfuss","Barger","Bari","Barimah","Barina","Barker","Barkley","Barling","Barlow","Barmen","Barn","Barna","Barnaba","Barnabas","Barnabe","Barnaby","Barnard","Barncard","Barnebas","Barnes","Barnet","Barnett","Barney","Barnie","Barnum","Barny","Barolet","Baron","Barr","Barra","Barrada","Barram","Barraza","Barren","Barret","Barrett","Barri","Barrie","Barrington","Barris","Barron","Barrow","Barrus","Barry","Barsky","Barstow","Bart","Barta","Bartel","Barth","Barthel","Barthelemy","Barthol","Barthold","Bartholemy","Bartholomeo","Bartholomeus","Bartholomew","Bartie","Bartko","Bartle","Bartlet","Bartlett","Bartley","Bartolemo","Bartolome","Bartolomeo","Barton","Bartosch","Bartram","Barty","Baruch","Barvick","Bary","Baryram","Bascio","Bascomb","Base","Baseler","Basham","Bashee","Bashemath","Bashemeth","Bashuk","Basia","Basil","Basile","Basilio","Basilius","Basir","Baskett","Bass","Basset","Bassett","Basso","Bast","Bastian","Bastien","Bat","Batchelor","Bate","Baten","Bates","Batha","Bathelda","Bathesda","Bathilda","Batholomew","Bathsheb","Bathsheba","Bathsheeb","Bathulda","Batish","Batista","Batory","Batruk","Batsheva","Battat","Battista","Battiste","Batty","Baudelaire","Baudin","Baudoin","Bauer","Baugh","Baum","Baumann","Baumbaugh","Baun","Bausch","Bauske","Bautista","Bautram","Bax","Baxie","Baxter","Baxy","Bay","Bayard","Bayer","Bayless","Baylor","Bayly","Baynebridge","Bazar","Bazil","Bazluke","Bea","Beach","Beacham","Beal","Beale","Beall","Bealle","Bean","Beane","Beaner","Bear","Bearce","Beard","Beare","Bearnard","Beasley","Beaston","Beata","Beatrice","Beatrisa","Beatrix","Beatriz","Beattie","Beatty","Beau","Beauchamp","Beaudoin","Beaufert","Beaufort","Beaulieu","Beaumont","Beauregard","Beauvais","Beaver","Bebe","Beberg","Becca","Bechler","Becht","Beck","Becka","Becker","Beckerman","Becket","Beckett","Becki","Beckie","Beckman","Becky","Bedad","Bedelia","Bedell","Bedwell","Bee","Beebe","Beeck","Beedon","Beekman","Beera","Beesley","Beeson","Beetner","Beffrey","Bega","Begga","Beghtol","Behah","Behka","Behl","Behlau","Behlke","Behm","Behn","Behnken","Behre","Behrens","Beichner","Beilul","Bein","Beisel","Beitch","Beitnes","Beitris","Beitz","Beka","Bekah","Bekelja","Beker","Bekha","Bekki","Bel","Bela","Belak","Belamy","Belanger","Belayneh","Belcher","Belda","Belden","Belding","Belen","Belford","Belia","Belicia","Belier","Belinda","Belita","Bell","Bella","Bellamy","Bellanca","Bellaude","Bellda","Belldame","Belldas","Belle","Beller","Bellew","Bellina","Bellis","Bello","Belloir","Belmonte","Belshin","Belsky","Belter","Beltran","Belva","Belvia","Ben","Bena","Bencion","Benco","Bender","Bendick","Bendicta","Bendicty","Bendite","Bendix","Benedetta","Benedetto","Benedic","Benedick","Benedict","Benedicta","Benedicto","Benedikt","Benedikta","Benedix","Benenson","Benetta","Benge","Bengt","Benia","Beniamino","Benil","Benilda","Benildas","Benildis","Benioff","Benis","Benisch","Benita","Benito","Benjamen","Benjamin","Benji","Benjie","Benjy","Benkley","Benn","Bennet","Bennett","Benni","Bennie","Bennink","Bennion","Bennir","Benny","Benoit","Benoite","Bensen","Bensky","Benson","Bent","Bentlee","Bentley","Bently","Benton","Benyamin","Benzel","Beora","Beore","Ber","Berard","Berardo","Berck","Berenice","Beret","Berey","Berfield","Berg","Berga","Bergeman","Bergen","Berger","Bergerac","Bergeron","Bergess","Berget","Bergh","Berghoff","Bergin","Berglund","Bergman","Bergmann","Bergmans","Bergquist","Bergren","Bergstein","Bergstrom","Bergwall","Berhley","Berk","Berke","Berkeley","Berkie","Berkin","Berkley","Berkly","Berkman","Berkow","Berkshire","Berky","Berl","Berlauda","Berlin","Berlinda","Berliner","Berlyn","Berman","Bern","Berna","Bernadene","Bernadette","Bernadina","Bernadine","Bernard","Bernardi","Bernardina","Bernardine","Bernardo","Bernarr","Bernat","Berne","Bernelle","Berner","Berners","Berneta","Bernete","Bernetta","Bernette","Bernhard","Berni","Bernice","Bernie","Bernita","Bernj","Berns","Bernstein","Bernt","Berny","Berri","Berrie","Berriman","Berry","Berstine","Bert","Berta","Bertasi","Berte","Bertelli","Bertero","Bertha","Berthe","Berthold","Berthoud","Berti","Bertie","Bertila","Bertilla","Bertina","Bertine","Bertle","Bertold","Bertolde","Berton","Bertram","Bertrand","Bertrando","Bertsche","Berty","Berwick","Beryl","Beryle","Beshore","Besnard","Bess","Besse","Bessie","Bessy","Best","Beth","Bethanne","Bethany","Bethel","Bethena","Bethesda","Bethesde","Bethezel","Bethina","Betsey","Betsy","Betta","Bette","Bette-Ann","Betteann","Betteanne","Bettencourt","Betthel","Betthezel","Betthezul","Betti","Bettina","Bettine","Betty","Bettye","Bettzel","Betz","Beulah","Beuthel","Beutler","Beutner","Bev","Bevan","Bevash","Bever","Beverie","Beverle","Beverlee","Beverley","Beverlie","Beverly","Bevers","Bevin","Bevis","Bevon","Bevus","Bevvy","Beyer","Bezanson","Bhatt","Bhayani","Biagi","Biagio","Biamonte","Bianca","Biancha","Bianchi","Bianka","Bibbie","Bibby","Bibbye","Bibeau","Bibi","Bible","Bick","Bickart","Bicknell","Biddick","Biddie","Biddle","Biddy","Bidget","Bidle","Biebel","Biegel","Bierman","Biernat","Bigelow","Bigford","Bigg","Biggs","Bigler","Bigner","Bigod","Bigot","Bik","Bikales","Bil","Bilbe","Bilek","Biles","Bili","Bilicki","Bill","Billat","Bille","Billen","Billi","Billie","Billmyre","Bills","Billy","Billye","Bilow","Bilski","Bina","Binah","Bindman","Binetta","Binette","Bing","Bink","Binky","Binni","Binnie","Binnings","Binny","Biondo","Birch","Birchard","Birck","Bird","Birdella","Birdie","Birdt","Birecree","Birgit","Birgitta","Birk","Birkett","Birkle","Birkner","Birmingham","Biron","Bish","Bishop","Bissell","Bisset","Bithia","Bittencourt","Bitthia","Bittner","Bivins","Bixby","Bixler","Bjork","Bjorn","Black","Blackburn","Blackington","Blackman","Blackmore","Blackmun","Blackstock","Blackwell","Blader","Blain","Blaine","Blainey","Blair","Blaire","Blaise","Blake","Blakelee","Blakeley","Blakely","Blalock","Blanc","Blanca","Blanch","Blancha","Blanchard","Blanche","Blanchette","Bland","Blandina","Blanding","Blane","Blank","Blanka","Blankenship","Blas","Blase","Blaseio","Blasien","Blasius","Blatman","Blatt","Blau","Blayne","Blayze","Blaze","Bledsoe","Bleier","Blen","Blessington","Blight","Blim","Blinni","Blinnie","Blinny","Bliss","Blisse","Blithe","Bloch","Block","Blockus","Blodget","Blodgett","Bloem","Blondell","Blondelle","Blondie","Blondy","Blood","Bloom","Bloomer","Blossom","Blount","Bloxberg","Bluefarb","Bluefield","Bluh","Bluhm","Blum","Bluma","Blumenfeld","Blumenthal","Blunk","Blunt","Blus","Blynn","Blythe","Bo","Boak","Boar","Boardman","Boarer","Boaten","Boatwright","Bob","Bobbe","Bobbee","Bobbette","Bobbi","Bobbie","Bobby","Bobbye","Bobette","Bobina","Bobine","Bobinette","Bobker","Bobseine","Bock","Bocock","Bodi","Bodkin","Bodnar","Bodrogi","Bodwell","Body","Boehike","Boehmer","Boeke","Boelter","Boesch","Boeschen","Boff","Boffa","Bogart","Bogey","Boggers","Boggs","Bogie","Bogoch","Bogosian","Bogusz","Bohannon","Bohaty","Bohi","Bohlen","Bohlin","Bohman","Bohner","Bohon","Bohrer","Bohs","Bohun","Boice","Boigie","Boiney","Bois","Bolan","Boland","Bolanger","Bolen","Boles","Boleslaw","Boleyn","Bolger","Bolitho","Bollay","Bollen","Bolling","Bollinger","Bolme","Bolt","Bolte","Bolten","Bolton","Bomke","Bonacci","Bonaparte","Bonar","Bond","Bondie","Bondon","Bondy","Bone","Boni","Boniface","Bonilla","Bonina","Bonine","Bonis","Bonita","Bonn","Bonne","Bonneau","Bonnee","Bonnell","Bonner","Bonnes","Bonnette","Bonney","Bonni","Bonnibelle","Bonnice","Bonnie","Bonns","Bonny","Bonucci","Booker","Booma","Boone","Boonie","Boony","Boor","Boorer","Boorman","Boot","Boote","Booth","Boothe","Boothman","Booze","Bopp","Bor","Bora","Borchers","Borchert","Bord","Borden","Bordie","Bordiuk","Bordy","Bore","Borek","Borer","Bores","Borg","Borgeson","Boris","Bork","Borlase","Borlow","Borman","Born","Bornie","Bornstein","Borras","Borrell","Borreri","Borries","Borroff","Borszcz","Bortman","Bortz","Boru","Bosch","Bose","Boser","Bosson","Bostow","Boswall","Boswell","Botnick","Botsford","Bottali","Botti","Botzow","Bouchard","Boucher","Bouchier","Boudreaux","Bough","Boulanger","Bouldon","Bouley","Bound","Bounds","Bourgeois","Bourke","Bourn","Bourne","Bourque","Boutis","Bouton","Bouzoun","Bove","Bovill","Bow","Bowden","Bowe","Bowen","Bower","Bowerman","Bowers","Bowes","Bowie","Bowlds","Bowler","Bowles","Bowman","Bowne","Bowra","Bowrah","Bowyer","Box","Boy","Boyce","Boycey","Boycie","Boyd","Boyden","Boyer","Boyes","Boykins","Boylan","Boylston","Boynton","Boys","Boyse","Boyt","Bozovich","Bozuwa","Braasch","Brabazon","Braca","Bracci","Brace","Brackely","Brackett","Brad","Bradan","Brade","Braden","Bradeord","Brader","Bradford","Bradlee","Bradleigh","Bradley","Bradly","Bradman","Bradney","Bradshaw","Bradski","Bradstreet","Bradway","Bradwell","Brady","Braeunig","Brag","Brahear","Brainard","Bram","Bramwell","Bran","Brana","Branca","Branch","Brand","Brandais","Brande","Brandea","Branden","Brandenburg","Brander","Brandes","Brandi","Brandice","Brandie","Brandise","Brandon","Brandt","Brandtr","Brandwein","Brandy","Brandyn","Branen","Branham","Brannon","Branscum","Brant","Brantley","Brasca","Brass","Braswell","Brathwaite","Bratton","Braun","Braunstein","Brause","Bravar","Bravin","Brawley","Brawner","Bray","Braynard","Brazee","Breana","Breanne","Brear","Breban","Brebner","Brecher","Brechtel","Bred","Bree","Breech","Breed","Breen","Breena","Breeze","Breger","Brelje","Bremble","Bremen","Bremer","Bremser","Bren","Brena","Brenan","Brenda","Brendan","Brenden","Brendin","Brendis","Brendon","Brenk","Brenn","Brenna","Brennan","Brennen","Brenner","Brent","Brenton","Brentt","Brenza","Bresee","Breskin","Brest","Bret","Brett","Brew","Brewer","Brewster","Brey","Brezin","Bria","Brian","Briana","Brianna","Brianne","Briano","Briant","Brice","Brick","Bricker","Bride","Bridge","Bridges","Bridget","Bridgette","Bridgid","Bridie","Bridwell","Brie","Brien","Brier","Brieta","Brietta","Brig","Brigette","Brigg","Briggs","Brigham","Bright","Brightman","Brighton","Brigid","Brigida","Brigit","Brigitta","Brigitte","Brill","Brina","Brindell","Brindle","Brine","Briney","Bringhurst","Brink","Brinkema","Brinn","Brinna","Brinson","Briny","Brion","Briscoe","Bristow","Brit","Brita","Britney","Britni","Britt","Britta","Brittain","Brittan","Brittaney","Brittani","Brittany","Britte","Britteny","Brittne","Brittnee","Brittney","Brittni","Britton","Brnaba","Brnaby","Broadbent","Brock","Brockie","Brocklin","Brockwell","Brocky","Brod","Broddie","Broddy","Brodench","Broder","Broderic","Broderick","Brodeur","Brodie","Brodsky","Brody","Broeder","Broek","Broeker","Brogle","Broida","Brok","Brom","Bromleigh","Bromley","Bron","Bronder","Bronez","Bronk","Bronnie","Bronny","Bronson","Bronwen","Bronwyn","Brook","Brooke","Brookes","Brookhouse","Brooking","Brookner","Brooks","Broome","Brose","Brosine","Brost","Brosy","Brote","Brothers","Brotherson","Brott","Brottman","Broucek","Brout","Brouwer","Brower","Brown","Browne","Browning","Brownley","Brownson","Brozak","Brubaker","Bruce","Brucie","Bruckner","Bruell","Brufsky","Bruis","Brunell","Brunella","Brunelle","Bruner","Brunhild","Brunhilda","Brunhilde","Bruni","Bruning","Brunk","Brunn","Bruno","Bruns","Bruyn","Bryan","Bryana","Bryant","Bryanty","Bryce","Bryn","Bryna","Bryner","Brynn","Brynna","Brynne","Bryon","Buatti","Bubalo","Bubb","Bucella","Buchalter","Buchanan","Buchbinder","Bucher","Buchheim","Buck","Buckden","Buckels","Buckie","Buckingham","Buckler","Buckley","Bucky","Bud","Budd","Budde","Buddie","Budding","Buddy","Buderus","Budge","Budwig","Budworth","Buehler","Buehrer","Buell","Buerger","Bueschel","Buff","Buffo","Buffum","Buffy","Buford","Bugbee","Buhler","Bui","Buine","Buiron","Buke","Bull","Bullard","Bullen","Buller","Bulley","Bullion","Bullis","Bullivant","Bullock","Bullough","Bully","Bultman","Bum","Bumgardner","Buna","Bunce","Bunch","Bunde","Bunder","Bundy","Bunker","Bunni","Bunnie","Bunns","Bunny","Bunow","Bunting","Buonomo","Buote","Burack","Burbank","Burch","Burchett","Burck","Burd","Burdelle","Burdett","Burford","Burg","Burgener","Burger","Burgess","Burget","Burgwell","Burhans","Burk","Burke","Burkhard","Burkhardt","Burkhart","Burkitt","Burkle","Burkley","Burl","Burleigh","Burley","Burlie","Burman","Burn","Burnaby","Burnard","Burne","Burner","Burnett","Burney","Burnham","Burnie","Burnight","Burnley","Burns","Burnsed","Burnside","Burny","Buroker","Burr","Burra","Burrell","Burrill","Burris","Burroughs","Burrow","Burrows","Burrton","Burrus","Burt","Burta","Burtie","Burtis","Burton","Burty","Burwell","Bury","Busby","Busch","Buschi","Buseck","Busey","Bush","Bushey","Bushore","Bushweller","Busiek","Buskirk","Buskus","Bussey","Bussy","Bust","Butch","Butcher","Butler","Butta","Buttaro","Butte","Butterfield","Butterworth","Button","Buxton","Buyer","Buyers","Buyse","Buzz","Buzzell","Byers","Byler","Byram","Byran","Byrann","Byrd","Byrdie","Byrle","Byrn","Byrne","Byrom","Byron","Bysshe","Bywaters","Bywoods","Cacia","Cacie","Cacilia","Cacilie","Cacka","Cad","Cadal","Caddaric","Caddric","Cade","Cadel","Cadell","Cadman","Cadmann","Cadmar","Cadmarr","Caesar","Caesaria","Caffrey","Cagle","Cahan","Cahilly","Cahn","Cahra","Cai","Caia","Caiaphas","Cailean","Cailly","Cain","Caine","Caines","Cairistiona","Cairns","Caitlin","Caitrin","Cal","Calabrese","Calabresi","Calan","Calandra","Calandria","Calbert","Caldeira","Calder","Caldera","Calderon","Caldwell","Cale","Caleb","Calen","Calendra","Calendre","Calesta","Calhoun","Calia","Calica","Calida","Calie","Calisa","Calise","Calista","Call","Calla","Callahan","Callan","Callas","Calle","Callean","Callery","Calley","Calli","Callida","Callie","Callista","Calloway","Callum","Cally","Calmas","Calondra","Calore","Calv","Calva","Calvano","Calvert","Calvin","Calvina","Calvinna","Calvo","Calypso","Calysta","Cam","Camala","Camarata","Camden","Camel","Camella","Camellia","Cameron","Camey","Camfort","Cami","Camila","Camile","Camilia","Camilla","Camille","Camilo","Camm","Cammi","Cammie","Cammy","Camp","Campagna","Campball","Campbell","Campman","Campney","Campos","Campy","Camus","Can","Canada","Canale","Cand","Candace","Candi","Candice","Candida","Candide","Candie","Candis","Candless","Candra","Candy","Candyce","Caneghem","Canfield","Canica","Canice","Caniff","Cann","Cannell","Cannice","Canning","Cannon","Canon","Canotas","Canter","Cantlon","Cantone","Cantu","Canty","Canute","Capello","Caplan","Capon","Capone","Capp","Cappella","Cappello","Capps","Caprice","Capriola","Caputo","Caputto","Capwell","Car","Cara","Caralie","Caras","Caravette","Caraviello","Carberry","Carbo","Carbone","Carboni","Carbrey","Carce","Card","Carder","Cardew","Cardie","Cardinal","Cardon","Cardwell","Care","Careaga","Caren","Carena","Caresa","Caressa","Caresse","Carew","Carey","Cargian","Carhart","Cari","Caria","Carie","Caril","Carilla","Carilyn","Carin","Carina","Carine","Cariotta","Carisa","Carissa","Carita","Caritta","Carl","Carla","Carlee","Carleen","Carlen","Carlene","Carleton","Carley","Carli","Carlick","Carlie","Carlile","Carlin","Carlina","Carline","Carling","Carlisle","Carlita","Carlo","Carlock","Carlos","Carlota","Carlotta","Carlson","Carlstrom","Carlton","Carly","Carlye","Carlyle","Carlyn","Carlynn","Carlynne","Carma","Carman","Carmel","Carmela","Carmelia","Carmelina","Carmelita","Carmella","Carmelle","Carmelo","Carmen","Carmena","Carmencita","Carmina","Carmine","Carmita","Carmon","Carn","Carnahan","Carnay","Carnes","Carney","Carny","Caro","Carol","Carol-Jean","Carola","Carolan","Carolann","Carole","Carolee","Carolin","Carolina","Caroline","Carolle","Carolus","Carolyn","Carolyne","Carolynn","Carolynne","Caron","Carothers","Carpenter","Carper","Carpet","Carpio","Carr","Carree","Carrel","Carrelli","Carrew","Carri","Carrick","Carrie","Carrillo","Carrington","Carrissa","Carrnan","Carrol","Carroll","Carry","Carson","Cart","Cartan","Carter","Carthy","Cartie","Cartwell","Cartwright","Caruso","Carver","Carvey","Cary","Caryl","Caryn","Cas","Casabonne","Casady","Casaleggio","Casandra","Casanova","Casar","Casavant","Case","Casey","Cash","Casi","Casia","Casie","Casilda","Casilde","Casimir","Casimire","Casmey","Caspar","Casper","Cass","Cassady","Cassandra","Cassandre","Cassandry","Cassaundra","Cassell","Cassella","Cassey","Cassi","Cassiani","Cassidy","Cassie","Cassil","Cassilda","Cassius","Cassondra","Cassy","Casta","Castara","Casteel","Castera","Castillo","Castle","Castor","Castora","Castorina","Castra","Castro","Caswell","Cataldo","Catarina","Cate","Caterina","Cates","Cath","Catha","Catharina","Catharine","Cathe","Cathee","Catherin","Catherina","Catherine","Cathey","Cathi","Cathie","Cathleen","Cathlene","Cathrin","Cathrine","Cathryn","Cathy","Cathyleen","Cati","Catie","Catima","Catina","Catlaina","Catlee","Catlin","Cato","Caton","Catrina","Catriona","Catt","Cattan","Cattier","Cattima","Catto","Catton","Caty","Caughey","Caundra","Cavallaro","Cavan","Cavanagh","Cavanaugh","Cave","Caves","Cavil","Cavill","Cavit","Cavuoto","Cawley","Caye","Cayla","Caylor","Cayser","Caz","Cazzie","Cchaddie","Cece","Cecelia","Cecil","Cecile","Ceciley","Cecilia","Cecilio","Cecilius","Cecilla","Cecily","Ced","Cedar","Cedell","Cedric","Ceevah","Ceil","Cele","Celene","Celeski","Celesta","Celeste","Celestia","Celestina","Celestine","Celestyn","Celestyna","Celia","Celie","Celik","Celin","Celina","Celinda","Celine","Celinka","Celio","Celisse","Celka","Celle","Cello","Celtic","Cenac","Cence","Centeno","Center","Centonze","Ceporah","Cerallua","Cerelia","Cerell","Cerellia","Cerelly","Cerf","Cerracchio","Certie","Cerveny","Cerys","Cesar","Cesare","Cesaria","Cesaro","Cestar","Cesya","Cha","Chabot","Chace","Chad","Chadabe","Chadbourne","Chadburn","Chadd","Chaddie","Chaddy","Chader","Chadwick","Chae","Chafee","Chaffee","Chaffin","Chaffinch","Chaiken","Chaille","Chaim","Chainey","Chaing","Chak","Chaker","Chally","Chalmer","Chalmers","Chamberlain","Chamberlin","Chambers","Chamkis","Champ","Champagne","Champaigne","Chan","Chance","Chancellor","Chancelor","Chancey","Chanda","Chandal","Chandler","Chandless","Chandos","Chandra","Chane","Chaney","Chang","Changaris","Channa","Channing","Chansoo","Chantal","Chantalle","Chao","Chap","Chapa","Chapel","Chapell","Chapen","Chapin","Chapland","Chapman","Chapnick","Chappelka","Chappell","Chappie","Chappy","Chara","Charbonneau","Charbonnier","Chard","Chari","Charie","Charil","Charin","Chariot","Charis","Charissa","Charisse","Charita","Charity","Charla","Charlean","Charleen","Charlena","Charlene","Charles","Charlet","Charleton","Charley","Charlie","Charline","Charlot","Charlotta","Charlotte","Charlton","Charmain","Charmaine","Charmane","Charmian","Charmine","Charmion","Charo","Charpentier","Charron","Charry","Charteris","Charters","Charyl","Chas","Chase","Chasse","Chassin","Chastain","Chastity","Chatav","Chatterjee","Chatwin","Chaudoin","Chaunce","Chauncey","Chavaree","Chaves","Chavey","Chavez","Chaworth","Che","Cheadle","Cheatham","Checani","Chee","Cheffetz","Cheke","Chellman","Chelsae","Chelsea","Chelsey","Chelsie","Chelsy","Chelton","Chem","Chema","Chemar","Chemaram","Chemarin","Chemash","Chemesh","Chemosh","Chemush","Chen","Chenay","Chenee","Cheney","Cheng","Cher","Chere","Cherey","Cheri","Cheria","Cherian","Cherianne","Cherice","Cherida","Cherie","Cherilyn","Cherilynn","Cherin","Cherise","Cherish","Cherlyn","Chernow","Cherri","Cherrita","Cherry","Chery","Cherye","Cheryl","Ches","Cheshire","Cheslie","Chesna","Chesney","Chesnut","Chessa","Chessy","Chester","Cheston","Chet","Cheung","Chev","Chevalier","Chevy","Chew","Cheyne","Cheyney","Chi","Chiaki","Chiang","Chiarra","Chic","Chick","Chickie","Chicky","Chico","Chicoine","Chien","Chil","Chilcote","Child","Childers","Childs","Chiles","Chill","Chilson","Chilt","Chilton","Chimene","Chin","China","Ching","Chinua","Chiou","Chip","Chipman","Chiquia","Chiquita","Chirlin","Chisholm","Chita","Chitkara","Chivers","Chladek","Chlo","Chloe","Chloette","Chloras","Chlores","Chlori","Chloris","Cho","Chobot","Chon","Chong","Choo","Choong","Chor","Chouest","Chow","Chretien","Chris","Chrisman","Chrisoula","Chrissa","Chrisse","Chrissie","Chrissy","Christa","Christabel","Christabella","Christabelle","Christal","Christalle","Christan","Christean","Christel","Christen","Christensen","Christenson","Christi","Christian","Christiana","Christiane","Christianity","Christianna","Christiano","Christiansen","Christianson","Christie","Christin","Christina","Christine","Christis","Christmann","Christmas","Christoffer","Christoforo","Christoper","Christoph","Christophe","Christopher","Christos","Christy","Christye","Christyna","Chrisy","Chrotoem","Chrysa","Chrysler","Chrystal","Chryste","Chrystel","Chu","Chuah","Chubb","Chuch","Chucho","Chuck","Chud","Chui","Chuipek","Chun","Chung","Chura","Church","Churchill","Chute","Chuu","Chyou","Cia","Cianca","Ciapas","Ciapha","Ciaphus","Cibis","Ciccia","Cicely","Cicenia","Cicero","Cichocki","Cicily","Cid","Cida","Ciel","Cila","Cilka","Cilla","Cilo","Cilurzo","Cima","Cimah","Cimbura","Cinda","Cindee","Cindelyn","Cinderella","Cindi","Cindie","Cindra","Cindy","Cinelli","Cini","Cinnamon","Cioban","Cioffred","Ciprian","Circosta","Ciri","Cirilla","Cirillo","Cirilo","Ciro","Cirone","Cirri","Cis","Cissie","Cissiee","Cissy","Cita","Citarella","Citron","Clabo","Claiborn","Claiborne","Clair","Claire","Claman","Clance","Clancy","Clapp","Clapper","Clara","Clarabelle","Clarance","Clardy","Clare","Clarence","Claresta","Clareta","Claretta","Clarette","Clarey","Clarhe","Clari","Claribel","Clarice","Clarie","Clarinda","Clarine","Clarisa","Clarise","Clarissa","Clarisse","Clarita","Clark","Clarke","Clarkin","Clarkson","Clary","Claud","Clauddetta","Claude","Claudell","Claudelle","Claudetta","Claudette","Claudia","Claudian","Claudianus","Claudie","Claudina","Claudine","Claudio","Claudius","Claudy","Claus","Clausen","Clava","Clawson","Clay","Clayberg","Clayborn","Clayborne","Claybourne","Clayson","Clayton","Clea","Cleary","Cleasta","Cleave","Cleaves","Cleavland","Clein","Cleland","Clellan","Clem","Clemen","Clemence","Clemens","Clement","Clementas","Clemente","Clementi","Clementia","Clementina","Clementine","Clementis","Clementius","Clements","Clemmie","Clemmy","Cleo","Cleodal","Cleodel","Cleodell","Cleon","Cleopatra","Cleopatre","Clerc","Clercq","Clere","Cleres","Clerissa","Clerk","Cleti","Cletis","Cletus","Cleve","Cleveland","Clevey","Clevie","Clie","Cliff","Cliffes","Clifford","Clift","Clifton","Clim","Cline","Clint","Clintock","Clinton","Clio","Clippard","Clite","Clive","Clo","Cloe","Cloots","Clorinda","Clorinde","Cloris","Close","Clothilde","Clotilda","Clotilde","Clough","Clougher","Cloutman","Clova","Clovah","Clover","Clovis","Clower","Clute","Cly","Clyde","Clymer","Clynes","Clyte","Clyve","Clywd","Cnut","Coad","Coady","Coates","Coats","Cob","Cobb","Cobbie","Cobby","Coben","Cochard","Cochran","Cochrane","Cock","Cockburn","Cocke","Cocks","Coco","Codd","Codding","Codee","Codel","Codi","Codie","Cody","Coe","Coffee","Coffeng","Coffey","Coffin","Cofsky","Cogan","Cogen","Cogswell","Coh","Cohbath","Cohberg","Cohbert","Cohby","Cohdwell","Cohe","Coheman","Cohen","Cohette","Cohin","Cohl","Cohla","Cohleen","Cohlette","Cohlier","Cohligan","Cohn","Cointon","Coit","Coke","Col","Colan","Colas","Colb","Colbert","Colburn","Colby","Colbye","Cole","Coleen","Coleman","Colene","Colet","Coletta","Colette","Coleville","Colfin","Colier","Colin","Colinson","Colis","Collar","Collayer","Collbaith","Colleen","Collen","Collete","Collette","Colley","Collie","Collier","Colligan","Collimore","Collin","Colline","Collins","Collis","Collum","Colly","Collyer","Colman","Colner","Colombi","Colon","Colp","Colpin","Colson","Colston","Colt","Coltin","Colton","Coltson","Coltun","Columba","Columbine","Columbus","Columbyne","Colver","Colvert","Colville","Colvin","Colwell","Colwen","Colwin","Colyer","Combe","Combes","Combs","Comfort","Compte","Comptom","Compton","Comras","Comstock","Comyns","Con","Conah","Conal","Conall","Conan","Conant","Conard","Concepcion","Concettina","Concha","Conchita","Concoff","Concordia","Condon","Coney","Congdon","Conger","Coniah","Conias","Conlan","Conlee","Conlen","Conley","Conlin","Conlon","Conn","Connel","Connell","Connelley","Connelly","Conner","Conners","Connett","Conney","Conni","Connie","Connolly","Connor","Connors","Conny","Conover","Conrad","Conrade","Conrado","Conroy","Consalve","Consolata","Constance","Constancia","Constancy","Constant","Constanta","Constantia","Constantin","Constantina","Constantine","Constantino","Consuela","Consuelo","Conte","Conti","Converse","Convery","Conway","Cony","Conyers","Cooe","Cook","Cooke","Cookie","Cooley","Coombs","Coonan","Coop","Cooper","Cooperman","Coopersmith","Cooperstein","Cope","Copeland","Copland","Coplin","Copp","Coppinger","Coppins","Coppock","Coppola","Cora","Corabel","Corabella","Corabelle","Coral","Coralie","Coraline","Coralyn","Coray","Corbet","Corbett","Corbie","Corbin","Corby","Cord","Cordalia","Cordeelia","Cordelia","Cordelie","Cordell","Corder","Cordey","Cordi","Cordie","Cordier","Cordle","Cordova","Cordula","Cordy","Coreen","Corel","Corell","Corella","Corena","Corenda","Corene","Coretta","Corette","Corey","Cori","Coridon","Corie","Corilla","Corin","Corina","Corine","Corinna","Corinne","Coriss","Corissa","Corkhill","Corley","Corliss","Corly","Cormac","Cormack","Cormick","Cormier","Cornall","Corneille","Cornel","Cornela","Cornelia","Cornelie","Cornelius","Cornell","Cornelle","Cornew","Corney","Cornia","Cornie","Cornish","Cornwall","Cornwell","Corny","Corotto","Correna","Correy","Corri","Corrianne","Corrie","Corrina","Corrine","Corrinne","Corron","Corry","Corsetti","Corsiglia","Corso","Corson","Cort","Cortie","Cortney","Corty","Corvese","Corvin","Corwin","Corwun","Cory","Coryden","Corydon","Cos","Cosenza","Cosetta","Cosette","Coshow","Cosimo","Cosma","Cosme","Cosmo","Cost","Costa","Costanza","Costanzia","Costello","Coster","Costin","Cote","Cotsen","Cott","Cotter","Cotterell","Cottle","Cottrell","Coucher","Couchman","Coughlin","Coulombe","Coulson","Coulter","Coumas","Countess","Courcy","Court","Courtenay","Courtland","Courtnay","Courtney","Courtund","Cousin","Cousins","Coussoule","Couture","Covell","Coveney","Cowan","Coward","Cowden","Cowen","Cower","Cowey","Cowie","Cowles","Cowley","Cown","Cox","Coy","Coyle","Cozmo","Cozza","Crabb","Craddock","Craggie","Craggy","Craig","Crain","Cralg","Cram","Cramer","Cran","Crandale","Crandall","Crandell","Crane","Craner","Cranford","Cranston","Crary","Craven","Craw","Crawford","Crawley","Creamer","Crean","Creath","Creedon","Creigh","Creight","Creighton","Crelin","Crellen","Crenshaw","Cresa","Crescantia","Crescen","Crescentia","Crescin","Crescint","Cresida","Crespi","Crespo","Cressi","Cressida","Cressler","Cressy","Crichton","Crifasi","Crim","Crin","Cris","Crisey","Crispa","Crispas","Crispen","Crispin","Crissie","Crissy","Crist","Crista","Cristabel","Cristal","Cristen","Cristi","Cristian","Cristiano","Cristie","Cristin","Cristina","Cristine","Cristiona","Cristionna","Cristobal","Cristoforo","Cristy","Criswell","Critchfield","Critta","Crocker","Crockett","Crofoot","Croft","Crofton","Croix","Crompton","Cromwell","Croner","Cronin","Crooks","Croom","Crosby","Crosley","Cross","Crosse","Croteau","Crotty","Crow","Crowe","Crowell","Crowley","Crowns","Croydon","Cruce","Crudden","Cruickshank","Crutcher","Cruz","Cryan","Crysta","Crystal","Crystie","Cthrine","Cuda","Cudlip","Culberson","Culbert","Culbertson","Culhert","Cull","Cullan","Cullen","Culley","Cullie","Cullin","Culliton","Cully","Culosio","Culver","Cumine","Cumings","Cummine","Cummings","Cummins","Cung","Cunningham","Cupo","Curcio","Curhan","Curkell","Curley","Curnin","Curr","Curran","Curren","Currey","Currie","Currier","Curry","Curson","Curt","Curtice","Curtis","Curzon","Cusack","Cusick","Custer","Cut","Cutcheon","Cutcliffe","Cuthbert","Cuthbertson","Cuthburt","Cutler","Cutlerr","Cutlip","Cutlor","Cutter","Cuttie","Cuttler","Cutty","Cuyler","Cy","Cyb","Cybil","Cybill","Cychosz","Cyd","Cykana","Cyler","Cyma","Cymbre","Cyn","Cyna","Cynar","Cynara","Cynarra","Cynde","Cyndi","Cyndia","Cyndie","Cyndy","Cynera","Cynth","Cynthea","Cynthia","Cynthie","Cynthla","Cynthy","Cyprian","Cyprio","Cypro","Cyprus","Cyrano","Cyrie","Cyril","Cyrill","Cyrilla","Cyrille","Cyrillus","Cyrus","Czarra","D'Arcy","Dabbs","Daberath","Dabney","Dace","Dacey","Dachi","Dachia","Dachy","Dacia","Dacie","Dacy","Daegal","Dael","Daffi","Daffie","Daffodil","Daffy","Dafna","Dafodil","Dag","Dagall","Daggett","Daggna","Dagley","Dagmar","Dagna","Dagnah","Dagney","Dagny","Dahl","Dahle","Dahlia","Dahlstrom","Daigle","Dail","Daile","Dailey","Daisey","Daisi","Daisie","Daisy","Daitzman","Dal","Dale","Dalenna","Daley","Dalia","Dalila","Dalis","Dall","Dallas","Dalli","Dallis","Dallman","Dallon","Daloris","Dalpe","Dalston","Dalt","Dalton","Dalury","Daly","Dam","Damal","Damalas","Damales","Damali","Damalis","Damalus","Damara","Damaris","Damarra","Dambro","Dame","Damek","Damian","Damiani","Damiano","Damick","Damicke","Damien","Damita","Damle","Damon","Damour","Dan","Dana","Danae","Danaher","Danais","Danas","Danby","Danczyk","Dane","Danell","Danella","Danelle","Danete","Danette","Daney","Danforth","Dang","Dani","Dania","Daniala","Danialah","Danica","Danice","Danie","Daniel","Daniela","Daniele","Daniell","Daniella","Danielle","Daniels","Danielson","Danieu","Danika","Danila","Danit","Danita","Daniyal","Dann","Danna","Dannel","Danni","Dannica","Dannie","Dannon","Danny","Dannye","Dante","Danuloff","Danya","Danyelle","Danyette","Danyluk","Danzig","Danziger","Dao","Daph","Daphene","Daphie","Daphna","Daphne","Dar","Dara","Darach","Darb","Darbee","Darbie","Darby","Darce","Darcee","Darcey","Darci","Darcia","Darcie","Darcy","Darda","Dardani","Dare","Dareece","Dareen","Darees","Darell","Darelle","Daren","Dari","Daria","Darian","Darice","Darill","Darin","Dario","Darius","Darken","Darla","Darleen","Darlene","Darline","Darlleen","Darmit","Darn","Darnall","Darnell","Daron","Darooge","Darra","Darrel","Darrell","Darrelle","Darren","Darrey","Darrick","Darrill","Darrin","Darrow","Darryl","Darryn","Darsey","Darsie","Dart","Darton","Darwen","Darwin","Darya","Daryl","Daryle","Daryn","Dash","Dasha","Dasi","Dasie","Dasteel","Dasya","Datha","Datnow","Daub","Daugherty","Daughtry","Daukas","Daune","Dav","Dave","Daveda","Daveen","Daven","Davena","Davenport","Daveta","Davey","David","Davida","Davidde","Davide","Davidoff","Davidson","Davie","Davies","Davilman","Davin","Davina","Davine","Davis","Davison","Davita","Davon","Davy","Dawes","Dawkins","Dawn","Dawna","Dawson","Day","Daye","Dayle","Dayna","Ddene","De","De Witt","Deach","Deacon","Deadman","Dean","Deana","Deane","Deaner","Deanna","Deanne","Dearborn","Dearden","Dearman","Dearr","Deb","Debarath","Debbee","Debbi","Debbie","Debbra","Debby","Debee","Debera","Debi","Debor","Debora","Deborah","Deborath","Debra","Decamp","Decato","Decca","December","Decima","Deck","Decker","Deckert","Declan","Dede","Deden","Dedie","Dedra","Dedric","Dedrick","Dee","Dee Dee","DeeAnn","Deeann","Deeanne","Deedee","Deegan","Deena","Deenya","Deer","Deerdre","Deering","Deery","Deeyn","Defant","Dehlia","Dehnel","Deibel","Deidre","Deina","Deirdra","Deirdre","Dekeles","Dekow","Del","Dela","Delacourt","Delaine","Delainey","Delamare","Deland","Delaney","Delanie","Delano","Delanos","Delanty","Delaryd","Delastre","Delbert","Delcina","Delcine","Delfeena","Delfine","Delgado","Delia","Delija","Delila","Delilah","Delinda","Delisle","Dell","Della","Delle","Dellora","Delly","Delmar","Delmer","Delmor","Delmore","Delogu","Delora","Delorenzo","Delores","Deloria","Deloris","Delos","Delp","Delphina","Delphine","Delphinia","Delsman","Delwin","Delwyn","Demaggio","Demakis","Demaria","Demb","Demeter","Demetra","Demetre","Demetri","Demetria","Demetris","Demetrius","Demeyer","Deming","Demitria","Demmer","Demmy","Demodena","Demona","Demott","Demp","Dempsey","Dempster","Dempstor","Demy","Den","Dena","Denae","Denbrook","Denby","Dene","Deni","Denice","Denie","Denis","Denise","Denison","Denman","Denn","Denna","Dennard","Dennet","Dennett","Denney","Denni","Dennie","Dennis","Dennison","Denny","Denoting","Dent","Denten","Denton","Denver","Deny","Denys","Denyse","Denzil","Deonne","Depoliti","Deppy","Der","Deragon","Derayne","Derby","Dercy","Derek","Derian","Derick","Derina","Derinna","Derk","Derman","Dermot","Dermott","Derna","Deron","Deroo","Derr","Derrek","Derrick","Derriey","Derrik","Derril","Derron","Derry","Derte","Derward","Derwin","Derwon","Derwood","Deryl","Derzon","Des","Desai","Desberg","Descombes","Desdamona","Desdamonna","Desdee","Desdemona","Desi","Desimone","Desirae","Desirea","Desireah","Desiree","Desiri","Desma","Desmond","Desmund","Dessma","Desta","Deste","Destinee","Deth","Dett","Detta","Dettmer","Deuno","Deutsch","Dev","Deva","Devan","Devaney","Dever","Devi","Devin","Devina","Devine","Devinna","Devinne","Devitt","Devland","Devlen","Devlin","Devol","Devon","Devona","Devondra","Devonna","Devonne","Devora","Devy","Dew","Dewain","Dewar","Dewayne","Dewees","Dewey","Dewhirst","Dewhurst","Dewie","Dewitt","Dex","Dexter","Dey","Dhar","Dhiman","Dhiren","Dhruv","Dhu","Dhumma","Di","Diahann","Diamante","Diamond","Dian","Diana","Diandra","Diandre","Diane","Diane-Marie","Dianemarie","Diann","Dianna","Dianne","Diannne","Diantha","Dianthe","Diao","Diarmid","Diarmit","Diarmuid","Diaz","Dib","Diba","Dibb","Dibbell","Dibbrun","Dibri","Dibrin","Dibru","Dich","Dichy","Dick","Dickens","Dickenson","Dickerson","Dickey","Dickie","Dickinson","Dickman","Dicks","Dickson","Dicky","Didi","Didier","Dido","Dieball","Diego","Diehl","Diella","Dielle","Dielu","Diena","Dierdre","Dierolf","Diet","Dieter","Dieterich","Dietrich","Dietsche","Dietz","Dikmen","Dilan","Diley","Dilisio","Dilks","Dill","Dillie","Dillon","Dilly","Dimitri","Dimitris","Dimitry","Dimmick","Dimond","Dimphia","Dina","Dinah","Dinan","Dincolo","Dine","Dinerman","Dinesh","Dinin","Dinnage","Dinnie","Dinny","Dino","Dinsdale","Dinse","Dinsmore","Diogenes","Dion","Dione","Dionis","Dionisio","Dionne","Dionysus","Dippold","Dira","Dirk","Disario","Disharoon","Disini","Diskin","Diskson","Disraeli","Dita","Ditmore","Ditter","Dittman","Dituri","Ditzel","Diver","Divine","Dix","Dixie","Dixil","Dixon","Dmitri","Dniren","Doak","Doane","Dobb","Dobbins","Doble","Dobrinsky","Dobson","Docia","Docila","Docile","Docilla","Docilu","Dodd","Dodds","Dode","Dodge","Dodi","Dodie","Dodson","Dodwell","Dody","Doe","Doehne","Doelling","Doerrer","Doersten","Doggett","Dogs","Doherty","Doi","Doig","Dola","Dolan","Dole","Doley","Dolf","Dolhenty","Doll","Dollar","Dolley","Dolli","Dollie","Dolloff","Dolly","Dolora","Dolores","Dolorita","Doloritas","Dolph","Dolphin","Dom","Domash","Dombrowski","Domel","Domela","Domella","Domenech","Domenic","Domenico","Domeniga","Domineca","Dominga","Domingo","Domini","Dominic","Dominica","Dominick","Dominik","Dominique","Dominus","Dominy","Domonic","Domph","Don","Dona","Donadee","Donaghue","Donahoe","Donahue","Donal","Donald","Donaldson","Donall","Donalt","Donata","Donatelli","Donaugh","Donavon","Donegan","Donela","Donell","Donella","Donelle","Donelson","Donelu","Doner","Donetta","Dong","Donia","Donica","Donielle","Donn","Donna","Donnamarie","Donnell","Donnelly","Donnenfeld","Donni","Donnie","Donny","Donoghue","Donoho","Donohue","Donough","Donovan","Doolittle","Doone","Dopp","Dora","Doralia","Doralin","Doralyn","Doralynn","Doralynne","Doran","Dorca","Dorcas","Dorcea","Dorcia","Dorcus","Dorcy","Dore","Doreen","Dorelia","Dorella","Dorelle","Dorena","Dorene","Doretta","Dorette","Dorey","Dorfman","Dori","Doria","Dorian","Dorice","Dorie","Dorin","Dorina","Dorinda","Dorine","Dorion","Doris","Dorisa","Dorise","Dorison","Dorita","Dorkas","Dorkus","Dorlisa","Dorman","Dorn","Doro","Dorolice","Dorolisa","Dorotea","Doroteya","Dorothea","Dorothee","Dorothi","Dorothy","Dorr","Dorran","Dorree","Dorren","Dorri","Dorrie","Dorris","Dorry","Dorsey","Dorsman","Dorsy","Dorthea","Dorthy","Dorweiler","Dorwin","Dory","Doscher","Dosh","Dosi","Dosia","Doss","Dot","Doti","Dotson","Dott","Dotti","Dottie","Dotty","Doty","Doubler","Doug","Dougal","Dougald","Dougall","Dougherty","Doughman","Doughty","Dougie","Douglas","Douglass","Dougy","Douty","Douville","Dov","Dove","Dovev","Dow","Dowd","Dowdell","Dowell","Dowlen","Dowling","Down","Downall","Downe","Downes","Downey","Downing","Downs","Dowski","Dowzall","Doxia","Doy","Doykos","Doyle","Drabeck","Dragelin","Dragon","Dragone","Dragoon","Drain","Drais","Drake","Drandell","Drape","Draper","Dray","Dre","Dream","Dreda","Dreddy","Dredi","Dreeda","Dreher","Dremann","Drescher","Dressel","Dressler","Drew","Drewett","Drews","Drexler","Dreyer","Dric","Drice","Drida","Dripps","Driscoll","Driskill","Drisko","Drislane","Drobman","Drogin","Drolet","Drona","Dronski","Drooff","Dru","Druce","Druci","Drucie","Drucill","Drucilla","Drucy","Drud","Drue","Drugge","Drugi","Drummond","Drus","Drusi","Drusie","Drusilla","Drusus","Drusy","Dry","Dryden","Drye","Dryfoos","DuBois","Duane","Duarte","Duax","Dubenko","Dublin","Ducan","Duck","Dud","Dudden","Dudley","Duer","Duester","Duff","Duffie","Duffy","Dugaid","Dugald","Dugan","Dugas","Duggan","Duhl","Duke","Dukey","Dukie","Duky","Dulce","Dulcea","Dulci","Dulcia","Dulciana","Dulcie","Dulcine","Dulcinea","Dulcle","Dulcy","Duleba","Dulla","Dulsea","Duma","Dumah","Dumanian","Dumas","Dumm","Dumond","Dun","Dunaville","Dunc","Duncan","Dunham","Dunkin","Dunlavy","Dunn","Dunning","Dunseath","Dunson","Dunstan","Dunston","Dunton","Duntson","Duong","Dupaix","Dupin","Dupre","Dupuis","Dupuy","Duquette","Dur","Durand","Durant","Durante","Durarte","Durer","Durgy","Durham","Durkee","Durkin","Durman","Durnan","Durning","Durno","Durr","Durrace","Durrell","Durrett","Durst","Durstin","Durston","Durtschi","Durward","Durware","Durwin","Durwood","Durwyn","Dusa","Dusen","Dust","Dustan","Duster","Dustie","Dustin","Dustman","Duston","Dusty","Dusza","Dutch","Dutchman","Duthie","Duval","Duvall","Duwalt","Duwe","Duyne","Dwain","Dwaine","Dwan","Dwane","Dwayne","Dweck","Dwight","Dwinnell","Dworman","Dwyer","Dyal","Dyan","Dyana","Dyane","Dyann","Dyanna","Dyanne","Dyche","Dyer","Dygal","Dygall","Dygert","Dyke","Dyl","Dylan","Dylana","Dylane","Dymoke","Dympha","Dymphia","Dyna","Dynah","Dysart","Dyson","Dyun","Dzoba","Eachelle","Eachern","Eada","Eade","Eadie","Eadith","Eadmund","Eads","Eadwina","Eadwine","Eagle","Eal","Ealasaid","Eamon","Eanore","Earl","Earla","Earle","Earleen","Earlene","Earley","Earlie","Early","Eartha","Earvin","East","Easter","Eastlake","Eastman","Easton","Eaton","Eatton","Eaves","Eb","Eba","Ebarta","Ebba","Ebbarta","Ebberta","Ebbie","Ebby","Eben","Ebeneser","Ebenezer","Eberhard","Eberhart","Eberle","Eberly","Ebert","Eberta","Eberto","Ebner","Ebneter","Eboh","Ebonee","Ebony","Ebsen","Echikson","Echo","Eckardt","Eckart","Eckblad","Eckel","Eckhardt","Eckmann","Econah","Ed","Eda","Edan","Edana","Edbert","Edd","Edda","Eddana","Eddi","Eddie","Eddina","Eddra","Eddy","Ede","Edea","Edee","Edeline","Edelman","Edelson","Edelstein","Edelsten","Eden","Edette","Edgar","Edgard","Edgardo","Edge","Edgell","Edgerton","Edholm","Edi","Edie","Edik","Edin","Edina","Edison","Edita","Edith","Editha","Edithe","Ediva","Edla","Edlin","Edlun","Edlyn","Edmanda","Edme","Edmea","Edmead","Edmee","Edmon","Edmond","Edmonda","Edmondo","Edmonds","Edmund","Edmunda","Edna","Edny","Edora","Edouard","Edra","Edrea","Edrei","Edric","Edrick","Edris","Edrock","Edroi","Edsel","Edson","Eduard","Eduardo","Eduino","Edva","Edvard","Edveh","Edward","Edwards","Edwin","Edwina","Edwine","Edwyna","Edy","Edyth","Edythe","Effie","Effy","Efram","Efrem","Efren","Efron","Efthim","Egan","Egarton","Egbert","Egerton","Eggett","Eggleston","Egide","Egidio","Egidius","Egin","Eglanteen","Eglantine","Egon","Egor","Egwan","Egwin","Ehling","Ehlke","Ehman","Ehr","Ehrenberg","Ehrlich","Ehrman","Ehrsam","Ehud","Ehudd","Eichman","Eidson","Eiger","Eileen","Eilis","Eimile","Einberger","Einhorn","Eipper","Eirena","Eirene","Eisele","Eisen","Eisenberg","Eisenhart","Eisenstark","Eiser","Eisinger","Eisler","Eiten","Ekaterina","El","Ela","Elah","Elaina","Elaine","Elana","Elane","Elata","Elatia","Elayne","Elazaro","Elbart","Elberfeld","Elbert","Elberta","Elbertina","Elbertine","Elboa","Elbring","Elburr","Elburt","Elconin","Elda","Elden","Elder","Eldin","Eldon","Eldora","Eldorado","Eldoree","Eldoria","Eldred","Eldreda","Eldredge","Eldreeda","Eldrid","Eldrida","Eldridge","Eldwen","Eldwin","Eldwon","Eldwun","Eleanor","Eleanora","Eleanore","Eleazar","Electra","Eleen","Elena","Elene","Eleni","Elenore","Eleonora","Eleonore","Eleph","Elephus","Elery","Elexa","Elfie","Elfont","Elfreda","Elfrida","Elfrieda","Elfstan","Elga","Elgar","Eli","Elia","Eliades","Elianora","Elianore","Elias","Eliason","Eliath","Eliathan","Eliathas","Elicia","Elidad","Elie","Eliezer","Eliga","Elihu","Elijah","Elinor","Elinore","Eliot","Eliott","Elisa","Elisabet","Elisabeth","Elisabetta","Elise","Elisee","Eliseo","Elish","Elisha","Elison","Elissa","Elita","Eliza","Elizabet","Elizabeth","Elka","Elke","Elkin","Ella","Elladine","Ellan","Ellard","Ellary","Ellata","Elle","Ellen","Ellene","Ellerd","Ellerey","Ellersick","Ellery","Ellett","Ellette","Ellga","Elli","Ellicott","Ellie","Ellinger","Ellingston","Elliot","Elliott","Ellis","Ellison","Ellissa","Ellita","Ellmyer","Ellon","Ellora","Ellord","Ellswerth","Ellsworth","Ellwood","Elly","Ellyn","Ellynn","Elma","Elmajian","Elmaleh","Elman","Elmer","Elmina","Elmira","Elmo","Elmore","Elna","Elnar","Elnora","Elnore","Elo","Elodea","Elodia","Elodie","Eloisa","Eloise","Elon","Elonore","Elora","Elreath","Elrod","Elroy","Els","Elsa","Elsbeth","Else","Elset","Elsey","Elsi","Elsie","Elsinore","Elson","Elspet","Elspeth","Elstan","Elston","Elsworth","Elsy","Elton","Elum","Elurd","Elva","Elvah","Elvera","Elvia","Elvie","Elvin","Elvina","Elvira","Elvis","Elvyn","Elwaine","Elwee","Elwin","Elwina","Elwira","Elwood","Elwyn","Ely","Elyn","Elyse","Elysee","Elysha","Elysia","Elyssa","Em","Ema","Emad","Emalee","Emalia","Emanuel","Emanuela","Emanuele","Emarie","Embry","Emee","Emelda","Emelen","Emelia","Emelin","Emelina","Emeline","Emelita","Emelun","Emelyne","Emera","Emerald","Emeric","Emerick","Emersen","Emerson","Emery","Emie","Emil","Emile","Emilee","Emili","Emilia","Emilie","Emiline","Emilio","Emily","Emina","Emlen","Emlin","Emlyn","Emlynn","Emlynne","Emma","Emmalee","Emmaline","Emmalyn","Emmalynn","Emmalynne","Emmanuel","Emmeline","Emmer","Emmeram","Emmerich","Emmerie","Emmery","Emmet","Emmett","Emmey","Emmi","Emmie","Emmit","Emmons","Emmott","Emmuela","Emmy","Emmye","Emogene","Emory","Emrich","Emsmus","Emyle","Emylee","Enalda","Encrata","Encratia","Encratis","End","Ender","Endo","Endor","Endora","Endres","Enenstein","Eng","Engdahl","Engeddi","Engedi","Engedus","Engel","Engelbert","Engelhart","Engen","Engenia","England","Engle","Englebert","Engleman","Englis","English","Engracia","Engud","Engvall","Enid","Ennis","Eno","Enoch","Enos","Enrica","Enrichetta","Enrico","Enrika","Enrique","Enriqueta","Ensign","Ensoll","Entwistle","Enyedy","Eoin","Eolanda","Eolande","Eph","Ephraim","Ephram","Ephrayim","Ephrem","Epifano","Epner","Epp","Epperson","Eppes","Eppie","Epps","Epstein","Er","Eradis","Eran","Eras","Erasme","Erasmo","Erasmus","Erastatus","Eraste","Erastes","Erastus","Erb","Erbe","Erbes","Erda","Erdah","Erdda","Erde","Erdei","Erdman","Erdrich","Erek","Erelia","Erena","Erfert","Ergener","Erhard","Erhart","Eri","Eric","Erica","Erich","Ericha","Erick","Ericka","Ericksen","Erickson","Erida","Erie","Eriha","Erik","Erika","Erikson","Erin","Erina","Erine","Erinn","Erinna","Erkan","Erl","Erland","Erlandson","Erle","Erleena","Erlene","Erlewine","Erlin","Erlina","Erline","Erlinna","Erlond","Erma","Ermanno","Erme","Ermeena","Ermengarde","Ermentrude","Ermey","Ermin","Ermina","Ermine","Erminia","Erminie","Erminna","Ern","Erna","Ernald","Ernaldus","Ernaline","Ernest","Ernesta","Ernestine","Ernesto","Ernestus","Ernie","Ernst","Erny","Errecart","Errick","Errol","Erroll","Erskine","Ertha","Erund","Erv","ErvIn","Ervin","Ervine","Erving","Erwin","Eryn","Esau","Esbensen","Esbenshade","Esch","Esdras","Eshelman","Eshman","Eskil","Eskill","Esma","Esmaria","Esme","Esmeralda","Esmerelda","Esmerolda","Esmond","Espy","Esra","Essa","Essam","Essex","Essie","Essinger","Essy","Esta","Estas","Esteban","Estel","Estele","Estell","Estella","Estelle","Esten","Ester","Estes","Estevan","Estey","Esther","Estis","Estrella","Estrellita","Estren","Estrin","Estus","Eta","Etam","Etan","Etana","Etem","Ethan","Ethban","Ethben","Ethbin","Ethbinium","Ethbun","Ethe","Ethel","Ethelbert","Ethelda","Ethelin","Ethelind","Ethelinda","Etheline","Ethelred","Ethelstan","Ethelyn","Ethyl","Etienne","Etka","Etoile","Etom","Etra","Etrem","Etta","Ettari","Etti","Ettie","Ettinger","Ettore","Etty","Etz","Eudo","Eudoca","Eudocia","Eudora","Eudosia","Eudoxia","Euell","Eugen","Eugene","Eugenia","Eugenides","Eugenie","Eugenio","Eugenius","Eugeniusz","Eugenle","Eugine","Euh","Eula","Eulalee","Eulalia","Eulaliah","Eulalie","Eulau","Eunice","Eupheemia","Euphemia","Euphemiah","Euphemie","Euridice","Eurydice","Eusebio","Eustace","Eustache","Eustacia","Eustashe","Eustasius","Eustatius","Eustazio","Eustis","Euton","Ev","Eva","Evadne","Evadnee","Evaleen","Evalyn","Evan","Evander","Evangelia","Evangelin","Evangelina","Evangeline","Evangelist","Evania","Evanne","Evannia","Evans","Evante","Evanthe","Evars","Eve","Eveleen","Evelin","Evelina","Eveline","Evelinn","Evelunn","Evelyn","Even","Everara","Everard","Evered","Everest","Everett","Everick","Everrs","Evers","Eversole","Everson","Evetta","Evette","Evey","Evie","Evin","Evita","Evonne","Evoy","Evslin","Evvie","Evvy","Evy","Evyn","Ewald","Ewall","Ewan","Eward","Ewart","Ewell","Ewen","Ewens","Ewer","Ewold","Eyde","Eydie","Eyeleen","Eyla","Ez","Ezana","Ezar","Ezara","Ezaria","Ezarra","Ezarras","Ezechiel","Ezekiel","Ezequiel","Eziechiele","Ezmeralda","Ezra","Ezri","Ezzo","Fabe","Faber","Fabi","Fabian","Fabiano","Fabien","Fabio","Fabiola","Fabiolas","Fablan","Fabozzi","Fabri","Fabria","Fabriane","Fabrianna","Fabrianne","Fabrice","Fabrienne","Fabrin","Fabron","Fabyola","Fachan","Fachanan","Fachini","Fadden","Faden","Fadil","Fadiman","Fae","Fagaly","Fagan","Fagen","Fagin","Fahey","Fahland","Fahy","Fai","Faina","Fair","Fairbanks","Faires","Fairfax","Fairfield","Fairleigh","Fairley","Fairlie","Fairman","Fairweather","Faith","Fakieh","Falcone","Falconer","Falda","Faletti","Faline","Falito","Falk","Falkner","Fallon","Faludi","Falzetta","Fan","Fanchan","Fanchet","Fanchette","Fanchie","Fanchon","Fancie","Fancy","Fanechka","Fanestil","Fang","Fania","Fanni","Fannie","Fanning","Fanny","Fantasia","Fante","Fanya","Far","Fara","Farah","Farand","Farant","Farhi","Fari","Faria","Farica","Farika","Fariss","Farkas","Farl","Farland","Farlay","Farlee","Farleigh","Farley","Farlie","Farly","Farman","Farmann","Farmelo","Farmer","Farnham","Farnsworth","Farny","Faro","Farr","Farra","Farrah","Farrand","Farrar","Farrel","Farrell","Farrica","Farrington","Farris","Farrish","Farrison","Farro","Farron","Farrow","Faruq","Farver","Farwell","Fasano","Faso","Fassold","Fast","Fasta","Fasto","Fates","Fatima","Fatimah","Fatma","Fattal","Faubert","Faubion","Fauch","Faucher","Faulkner","Fauman","Faun","Faunia","Faunie","Faus","Faust","Fausta","Faustena","Faustina","Faustine","Faustus","Fauver","Faux","Favata","Favian","Favianus","Favien","Favin","Favrot","Fawcett","Fawcette","Fawn","Fawna","Fawne","Fawnia","Fax","Faxan","Faxen","Faxon","Faxun","Fay","Faydra","Faye","Fayette","Fayina","Fayola","Fayre","Fayth","Faythe","Fazeli","Fe","Featherstone","February","Fechter","Fedak","Federica","Federico","Fedirko","Fedora","Fee","Feeley","Feeney","Feer","Feigin","Feil","Fein","Feinberg","Feingold","Feinleib","Feinstein","Feld","Felder","Feldman","Feldstein","Feldt","Felecia","Feledy","Felic","Felicdad","Felice","Felicia","Felicidad","Felicie","Felicio","Felicity","Felicle","Felike","Feliks","Felipa","Felipe","Felise","Felisha","Felita","Felix","Feliza","Felizio","Fellner","Fellows","Felske","Felt","Felten","Feltie","Felton","Felty","Fem","Femi","Femmine","Fen","Fendig","Fenelia","Fenella","Fenn","Fennell","Fennelly","Fenner","Fennessy","Fennie","Fenny","Fenton","Fenwick","Feodor","Feodora","Feodore","Feola","Ferd","Ferde","Ferdie","Ferdinana","Ferdinand","Ferdinanda","Ferdinande","Ferdy","Fergus","Ferguson","Feriga","Ferino","Fermin","Fern","Ferna","Fernald","Fernand","Fernanda","Fernande","Fernandes","Fernandez","Fernandina","Fernando","Fernas","Ferne","Ferneau","Fernyak","Ferrand","Ferreby","Ferree","Ferrel","Ferrell","Ferren","Ferretti","Ferri","Ferrick","Ferrigno","Ferris","Ferriter","Ferro","Ferullo","Ferwerda","Festa","Festatus","Festus","Feucht","Feune","Fevre","Fey","Fi","Fia","Fiann","Fianna","Fidel","Fidela","Fidelas","Fidele","Fidelia","Fidelio","Fidelis","Fidelity","Fidellas","Fidellia","Fiden","Fidole","Fiedler","Fiedling","Field","Fielding","Fields","Fiertz","Fiester","Fife","Fifi","Fifine","Figge","Figone","Figueroa","Filbert","Filberte","Filberto","Filemon","Files","Filia","Filiano","Filide","Filip","Filipe","Filippa","Filippo","Fillander","Fillbert","Fillender","Filler","Fillian","Filmer","Filmore","Filomena","Fin","Fina","Finbar","Finbur","Findlay","Findley","Fine","Fineberg","Finegan","Finella","Fineman","Finer","Fini","Fink","Finkelstein","Finlay","Finley","Finn","Finnegan","Finnie","Finnigan","Finny","Finstad","Finzer","Fiona","Fionna","Fionnula","Fiora","Fiore","Fiorenza","Fiorenze","Firestone","Firman","Firmin","Firooc","Fisch","Fischer","Fish","Fishback","Fishbein","Fisher","Fishman","Fisk","Fiske","Fisken","Fitting","Fitton","Fitts","Fitz","Fitzger","Fitzgerald","Fitzhugh","Fitzpatrick","Fitzsimmons","Flagler","Flaherty","Flam","Flan","Flanagan","Flanders","Flanigan","Flann","Flanna","Flannery","Flatto","Flavia","Flavian","Flavio","Flavius","Fleck","Fleda","Fleece","Fleeman","Fleeta","Fleischer","Fleisher","Fleisig","Flem","Fleming","Flemings","Flemming","Flessel","Fleta","Fletch","Fletcher","Fleur","Fleurette","Flieger","Flight","Flin","Flinn","Flint","Flip","Flita","Flo","Floeter","Flor","Flora","Florance","Flore","Florella","Florence","Florencia","Florentia","Florenza","Florette","Flori","Floria","Florian","Florida","Floridia","Florie","Florin","Florina","Florinda","Florine","Florio","Floris","Floro","Florri","Florrie","Florry","Flory","Flosi","Floss","Flosser","Flossi","Flossie","Flossy","Flower","Flowers","Floyd","Flss","Flyn","Flynn","Foah","Fogarty","Fogel","Fogg","Fokos","Folberth","Foley","Folger","Follansbee","Follmer","Folly","Folsom","Fonda","Fondea","Fong","Fons","Fonseca","Fonsie","Fontana","Fontes","Fonville","Fonz","Fonzie","Foote","Forbes","Forcier","Ford","Fording","Forelli","Forest","Forester","Forkey","Forland","Forlini","Formenti","Formica","Fornof","Forras","Forrer","Forrest","Forrester","Forsta","Forster","Forsyth","Forta","Fortier","Fortin","Fortna","Fortuna","Fortunato","Fortune","Fortunia","Fortunio","Fortunna","Forward","Foscalina","Fosdick","Foskett","Fosque","Foss","Foster","Fotina","Fotinas","Fougere","Foulk","Four","Foushee","Fowkes","Fowle","Fowler","Fox","Foy","Fraase","Fradin","Frager","Frame","Fran","France","Francene","Frances","Francesca","Francesco","Franchot","Franci","Francie","Francine","Francis","Francisca","Franciscka","Francisco","Franciska","Franciskus","Franck","Francklin","Francklyn","Franckot","Francois","Francoise","Francyne","Franek","Frangos","Frank","Frankel","Frankhouse","Frankie","Franklin","Franklyn","Franky","Franni","Frannie","Franny","Frans","Fransen","Fransis","Fransisco","Frants","Frantz","Franz","Franza","Franzen","Franzoni","Frasch","Frasco","Fraser","Frasier","Frasquito","Fraya","Frayda","Frayne","Fraze","Frazer","Frazier","Frear","Freberg","Frech","Frechette","Fred","Freda","Freddi","Freddie","Freddy","Fredek","Fredel","Fredela","Fredelia","Fredella","Fredenburg","Frederic","Frederica","Frederich","Frederick","Fredericka","Frederico","Frederigo","Frederik","Frederiksen","Frederique","Fredette","Fredi","Fredia","Fredie","Fredkin","Fredra","Fredric","Fredrick","Fredrika","Free","Freeborn","Freed","Freedman","Freeland","Freeman","Freemon","Fregger","Freida","Freiman","Fremont","French","Frendel","Frentz","Frere","Frerichs","Fretwell","Freud","Freudberg","Frey","Freya","Freyah","Freytag","Frick","Fricke","Frida","Friday","Fridell","Fridlund","Fried","Frieda","Friedberg","Friede","Frieder","Friederike","Friedland","Friedlander","Friedly","Friedman","Friedrich","Friedrick","Friend","Frierson","Fries","Frisse","Frissell","Fritts","Fritz","Fritze","Fritzie","Fritzsche","Frodeen","Frodi","Frodin","Frodina","Frodine","Froehlich","Froemming","Froh","Frohman","Frohne","Frolick","Froma","Fromma","Fronia","Fronnia","Fronniah","Frost","Fruin","Frulla","Frum","Fruma","Fry","Fryd","Frydman","Frye","Frymire","Fu","Fuchs","Fugate","Fugazy","Fugere","Fuhrman","Fujio","Ful","Fulbert","Fulbright","Fulcher","Fuld","Fulks","Fuller","Fullerton","Fulmer","Fulmis","Fulton","Fulvi","Fulvia","Fulviah","Funch","Funda","Funk","Furey","Furgeson","Furie","Furiya","Furlani","Furlong","Furmark","Furnary","Furr","Furtek","Fusco","Gaal","Gabbert","Gabbey","Gabbi","Gabbie","Gabby","Gabe","Gabel","Gabey","Gabi","Gabie","Gable","Gabler","Gabor","Gabriel","Gabriela","Gabriele","Gabriell","Gabriella","Gabrielle","Gabrielli","Gabriellia","Gabriello","Gabrielson","Gabrila","Gaby","Gad","Gaddi","Gader","Gadmann","Gadmon","Gae","Gael","Gaelan","Gaeta","Gage","Gagliano","Gagne","Gagnon","Gahan","Gahl","Gaidano","Gaige","Gail","Gaile","Gaillard","Gainer","Gainor","Gaiser","Gaither","Gaivn","Gal","Gala","Galan","Galang","Galanti","Galasyn","Galatea","Galateah","Galatia","Gale","Galen","Galer","Galina","Galitea","Gall","Gallager","Gallagher","Gallard","Gallenz","Galliett","Galligan","Galloway","Gally","Galvan","Galven","Galvin","Gamages","Gamal","Gamali","Gamaliel","Gambell","Gamber","Gambrell","Gambrill","Gamin","Gan","Ganiats","Ganley","Gannes","Gannie","Gannon","Ganny","Gans","Gant","Gapin","Gar","Garald","Garate","Garaway","Garbe","Garber","Garbers","Garceau","Garcia","Garcon","Gard","Garda","Gardal","Gardas","Gardel","Gardell","Gardener","Gardia","Gardie","Gardiner","Gardner","Gardol","Gardy","Gare","Garek","Gareri","Gareth","Garett","Garey","Garfield","Garfinkel","Gargan","Garges","Garibald","Garibold","Garibull","Gariepy","Garik","Garin","Garlaand","Garlan","Garland","Garlanda","Garlen","Garlinda","Garling","Garmaise","Garneau","Garner","Garnes","Garnet","Garnett","Garnette","Garold","Garrard","Garratt","Garrek","Garret","Garreth","Garretson","Garrett","Garrick","Garrik","Garris","Garrison","Garrity","Garrot","Garrott","Garry","Garson","Garth","Garv","Garvey","Garvin","Garvy","Garwin","Garwood","Gary","Garzon","Gascony","Gaskill","Gaskin","Gaskins","Gaspar","Gaspard","Gasparo","Gasper","Gasperoni","Gass","Gasser","Gassman","Gastineau","Gaston","Gates","Gathard","Gathers","Gati","Gatian","Gatias","Gaudet","Gaudette","Gaughan","Gaul","Gauldin","Gaulin","Gault","Gaultiero","Gauntlett","Gausman","Gaut","Gautea","Gauthier","Gautier","Gautious","Gav","Gavan","Gaven","Gavette","Gavin","Gavini","Gavra","Gavrah","Gavriella","Gavrielle","Gavrila","Gavrilla","Gaw","Gawain","Gawen","Gawlas","Gay","Gaye","Gayel","Gayelord","Gayl","Gayla","Gayle","Gayleen","Gaylene","Gayler","Gaylor","Gaylord","Gayn","Gayner","Gaynor","Gazo","Gazzo","Geaghan","Gean","Geanine","Gearalt","Gearard","Gearhart","Gebelein","Gebhardt","Gebler","Geddes","Gee","Geehan","Geer","Geerts","Geesey","Gefell","Gefen","Geffner","Gehlbach","Gehman","Geibel","Geier","Geiger","Geilich","Geis","Geiss","Geithner","Gelasias","Gelasius","Gelb","Geldens","Gelhar","Geller","Gellman","Gelman","Gelya","Gemina","Gemini","Geminian","Geminius","Gemma","Gemmell","Gemoets","Gemperle","Gen","Gena","Genaro","Gene","Genesa","Genesia","Genet","Geneva","Genevieve","Genevra","Genia","Genie","Genisia","Genna","Gennaro","Genni","Gennie","Gennifer","Genny","Geno","Genovera","Gensler","Gensmer","Gent","Gentes","Gentilis","Gentille","Gentry","Genvieve","Geof","Geoff","Geoffrey","Geoffry","Georas","Geordie","Georg","George","Georgeanna","Georgeanne","Georgena","Georges","Georgeta","Georgetta","Georgette","Georgi","Georgia","Georgiana","Georgianna","Georgianne","Georgie","Georgina","Georgine","Georglana","Georgy","Ger","Geraint","Gerald","Geralda","Geraldina","Geraldine","Gerard","Gerardo","Geraud","Gerbold","Gerda","Gerdeen","Gerdi","Gerdy","Gere","Gerek","Gereld","Gereron","Gerfen","Gerge","Gerger","Gerhan","Gerhard","Gerhardine","Gerhardt","Geri","Gerianna","Gerianne","Gerick","Gerik","Gerita","Gerius","Gerkman","Gerlac","Gerladina","Germain","Germaine","German","Germana","Germann","Germano","Germaun","Germayne","Germin","Gernhard","Gerome","Gerrald","Gerrard","Gerri","Gerrie","Gerrilee","Gerrit","Gerry","Gersham","Gershom","Gershon","Gerson","Gerstein","Gerstner","Gert","Gerta","Gerti","Gertie","Gertrud","Gertruda","Gertrude","Gertrudis","Gerty","Gervais","Gervase","Gery","Gesner","Gessner","Getraer","Getter","Gettings","Gewirtz","Ghassan","Gherardi","Gherardo","Gherlein","Ghiselin","Giacamo","Giacinta","Giacobo","Giacomo","Giacopo","Giaimo","Giamo","Gian","Giana","Gianina","Gianna","Gianni","Giannini","Giarla","Giavani","Gib","Gibb","Gibbeon","Gibbie","Gibbon","Gibbons","Gibbs","Gibby","Gibe","Gibeon","Gibert","Gibrian","Gibson","Gibun","Giddings","Gide","Gideon","Giefer","Gies","Giesecke","Giess","Giesser","Giff","Giffard","Giffer","Gifferd","Giffie","Gifford","Giffy","Gigi","Giglio","Gignac","Giguere","Gil","Gilba","Gilbart","Gilbert","Gilberta","Gilberte","Gilbertina","Gilbertine","Gilberto","Gilbertson","Gilboa","Gilburt","Gilbye","Gilchrist","Gilcrest","Gilda","Gildas","Gildea","Gilder","Gildus","Gile","Gilead","Gilemette","Giles","Gilford","Gilges","Giliana","Giliane","Gill","Gillan","Gillead","Gilleod","Gilles","Gillespie","Gillett","Gilletta","Gillette","Gilli","Gilliam","Gillian","Gillie","Gilliette","Gilligan","Gillman","Gillmore","Gilly","Gilman","Gilmer","Gilmore","Gilmour","Gilpin","Gilroy","Gilson","Giltzow","Gilud","Gilus","Gimble","Gimpel","Gina","Ginder","Gine","Ginelle","Ginevra","Ginger","Gingras","Ginni","Ginnie","Ginnifer","Ginny","Gino","Ginsberg","Ginsburg","Gintz","Ginzburg","Gio","Giordano","Giorgi","Giorgia","Giorgio","Giovanna","Giovanni","Gipps","Gipson","Gipsy","Giralda","Giraldo","Girand","Girard","Girardi","Girardo","Giraud","Girhiny","Girish","Girovard","Girvin","Gisela","Giselbert","Gisele","Gisella","Giselle","Gish","Gisser","Gitel","Githens","Gitlow","Gitt","Gittel","Gittle","Giuditta","Giule","Giulia","Giuliana","Giulietta","Giulio","Giuseppe","Giustina","Giustino","Giusto","Given","Giverin","Giza","Gizela","Glaab","Glad","Gladdie","Gladdy","Gladi","Gladine","Gladis","Gladstone","Gladwin","Gladys","Glanti","Glantz","Glanville","Glarum","Glaser","Glasgo","Glass","Glassco","Glassman","Glaudia","Glavin","Gleason","Gleda","Gleeson","Gleich","Glen","Glenda","Glenden","Glendon","Glenine","Glenn","Glenna","Glennie","Glennis","Glennon","Glialentn","Glick","Glimp","Glinys","Glogau","Glori","Gloria","Gloriana","Gloriane","Glorianna","Glory","Glover","Glovsky","Gluck","Glyn","Glynas","Glynda","Glynias","Glynis","Glynn","Glynnis","Gmur","Gnni","Goar","Goat","Gobert","God","Goda","Godard","Godart","Godbeare","Godber","Goddard","Goddart","Godden","Godderd","Godding","Goddord","Godewyn","Godfree","Godfrey","Godfry","Godiva","Godliman","Godred","Godric","Godrich","Godspeed","Godwin","Goebel","Goeger","Goer","Goerke","Goeselt","Goetz","Goff","Goggin","Goines","Gokey","Golanka","Gold","Golda","Goldarina","Goldberg","Golden","Goldenberg","Goldfarb","Goldfinch","Goldi","Goldia","Goldie","Goldin","Goldina","Golding","Goldman","Goldner","Goldshell","Goldshlag","Goldsmith","Goldstein","Goldston","Goldsworthy","Goldwin","Goldy","Goles","Golightly","Gollin","Golliner","Golter","Goltz","Golub","Gomar","Gombach","Gombosi","Gomer","Gomez","Gona","Gonagle","Gone","Gonick","Gonnella","Gonroff","Gonsalve","Gonta","Gonyea","Gonzales","Gonzalez","Gonzalo","Goober","Good","Goodard","Goodden","Goode","Goodhen","Goodill","Goodkin","Goodman","Goodrich","Goodrow","Goodson","Goodspeed","Goodwin","Goody","Goodyear","Googins","Gora","Goran","Goraud","Gord","Gordan","Gorden","Gordie","Gordon","Gordy","Gore","Goren","Gorey","Gorga","Gorges","Gorlicki","Gorlin","Gorman","Gorrian","Gorrono","Gorski","Gorton","Gosnell","Gosney","Goss","Gosselin","Gosser","Gotcher","Goth","Gothar","Gothard","Gothart","Gothurd","Goto","Gottfried","Gotthard","Gotthelf","Gottlieb","Gottuard","Gottwald","Gough","Gould","Goulden","Goulder","Goulet","Goulette","Gove","Gow","Gower","Gowon","Gowrie","Graaf","Grace","Graces","Gracia","Gracie","Gracye","Gradeigh","Gradey","Grados","Grady","Grae","Graehl","Graehme","Graeme","Graf","Graff","Graham","Graig","Grail","Gram","Gran","Grand","Grane","Graner","Granese","Grange","Granger","Grani","Grania","Graniah","Graniela","Granlund","Grannia","Granniah","Grannias","Grannie","Granny","Granoff","Grant","Grantham","Granthem","Grantland","Grantley","Granville","Grassi","Grata","Grath","Grati","Gratia","Gratiana","Gratianna","Gratt","Graubert","Gravante","Graves","Gray","Graybill","Grayce","Grayson","Grazia","Greabe","Grearson","Gredel","Greeley","Green","Greenberg","Greenburg","Greene","Greenebaum","Greenes","Greenfield","Greenland","Greenleaf","Greenlee","Greenman","Greenquist","Greenstein","Greenwald","Greenwell","Greenwood","Greer","Greerson","Greeson","Grefe","Grefer","Greff","Greg","Grega","Gregg","Greggory","Greggs","Gregoire","Gregoor","Gregor","Gregorio","Gregorius","Gregory","Gregrory","Gregson","Greiner","Grekin","Grenier","Grenville","Gresham","Greta","Gretal","Gretchen","Grete","Gretel","Grethel","Gretna","Gretta","Grevera","Grew","Grewitz","Grey","Greyso","Greyson","Greysun","Grider","Gridley","Grier","Grieve","Griff","Griffie","Griffin","Griffis","Griffith","Griffiths","Griffy","Griggs","Grigson","Grim","Grimaldi","Grimaud","Grimbal","Grimbald","Grimbly","Grimes","Grimona","Grimonia","Grindlay","Grindle","Grinnell","Gris","Griselda","Griseldis","Grishilda","Grishilde","Grissel","Grissom","Gristede","Griswold","Griz","Grizel","Grizelda","Groark","Grobe","Grochow","Grodin","Grof","Grogan","Groh","Gromme","Grondin","Gronseth","Groome","Groos","Groot","Grory","Grosberg","Groscr","Grose","Grosmark","Gross","Grossman","Grosvenor","Grosz","Grote","Grounds","Grous","Grove","Groveman","Grover","Groves","Grubb","Grube","Gruber","Grubman","Gruchot","Grunberg","Grunenwald","Grussing","Gruver","Gschu","Guadalupe","Gualterio","Gualtiero","Guarino","Gudren","Gudrin","Gudrun","Guendolen","Guenevere","Guenna","Guenzi","Guerin","Guerra","Guevara","Guglielma","Guglielmo","Gui","Guibert","Guido","Guidotti","Guilbert","Guild","Guildroy","Guillaume","Guillema","Guillemette","Guillermo","Guimar","Guimond","Guinevere","Guinn","Guinna","Guise","Gujral","Gula","Gulgee","Gulick","Gun","Gunar","Gunas","Gundry","Gunilla","Gunn","Gunnar","Gunner","Gunning","Guntar","Gunter","Gunthar","Gunther","Gunzburg","Gupta","Gurango","Gurevich","Guria","Gurias","Gurl","Gurney","Gurolinick","Gurtner","Gus","Gusba","Gusella","Guss","Gussi","Gussie","Gussman","Gussy","Gusta","Gustaf","Gustafson","Gustafsson","Gustav","Gustave","Gustavo","Gustavus","Gusti","Gustie","Gustin","Gusty","Gut","Guthrey","Guthrie","Guthry","Gutow","Guttery","Guy","Guyer","Guyon","Guzel","Gwen","Gwendolen","Gwendolin","Gwendolyn","Gweneth","Gwenette","Gwenn","Gwenneth","Gwenni","Gwennie","Gwenny","Gwenora","Gwenore","Gwyn","Gwyneth","Gwynne","Gyasi","Gyatt","Gyimah","Gylys","Gypsie","Gypsy","Gytle","Ha","Haag","Haakon","Haas","Haase","Haberman","Hach","Hachman","Hachmann","Hachmin","Hackathorn","Hacker","Hackett","Hackney","Had","Haddad","Hadden","Haden","Hadik","Hadlee","Hadleigh","Hadley","Hadria","Hadrian","Hadsall","Hadwin","Hadwyn","Haeckel","Haerle","Haerr","Haff","Hafler","Hagai","Hagan","Hagar","Hagen","Hagerman","Haggai","Haggar","Haggerty","Haggi","Hagi","Hagood","Hahn","Hahnert","Hahnke","Haida","Haig","Haile","Hailee","Hailey","Haily","Haim","Haimes","Haines","Hak","Hakan","Hake","Hakeem","Hakim","Hako","Hakon","Hal","Haland","Halbeib","Halbert","Halda","Haldan","Haldane","Haldas","Haldeman","Halden","Haldes","Haldi","Haldis","Hale","Haleigh","Haletky","Haletta","Halette","Haley","Halfdan","Halfon","Halford","Hali","Halie","Halima","Halimeda","Hall","Halla","Hallagan","Hallam","Halland","Halle","Hallee","Hallerson","Hallett","Hallette","Halley","Halli","Halliday","Hallie","Hallock","Hallsy","Hallvard","Hally","Halona","Halonna","Halpern","Halsey","Halstead","Halsted","Halsy","Halvaard","Halverson","Ham","Hama","Hamachi","Hamal","Haman","Hamann","Hambley","Hamburger","Hamel","Hamer","Hamford","Hamforrd","Hamfurd","Hamid","Hamil","Hamilton","Hamish","Hamlani","Hamlen","Hamlet","Hamlin","Hammad","Hammel","Hammer","Hammerskjold","Hammock","Hammond","Hamner","Hamnet","Hamo","Hamon","Hampton","Hamrah","Hamrnand","Han","Hana","Hanae","Hanafee","Hanako","Hanan","Hance","Hancock","Handal","Handbook","Handel","Handler","Hands","Handy","Haney","Hanford","Hanforrd","Hanfurd","Hank","Hankins","Hanleigh","Hanley","Hanna","Hannah","Hannan","Hanni","Hannibal","Hannie","Hannis","Hannon","Hannover","Hannus","Hanny","Hanover","Hans","Hanschen","Hansel","Hanselka","Hansen","Hanser","Hanshaw","Hansiain","Hanson","Hanus","Hanway","Hanzelin","Happ","Happy","Hapte","Hara","Harald","Harbard","Harberd","Harbert","Harbird","Harbison","Harbot","Harbour","Harcourt","Hardan","Harday","Hardden","Hardej","Harden","Hardi","Hardie","Hardigg","Hardin","Harding","Hardman","Hardner","Hardunn","Hardwick","Hardy","Hare","Harelda","Harewood","Harhay","Harilda","Harim","Harl","Harlamert","Harlan","Harland","Harle","Harleigh","Harlen","Harlene","Harley","Harli","Harlie","Harlin","Harlow","Harman","Harmaning","Harmon","Harmonia","Harmonie","Harmony","Harms","Harned","Harneen","Harness","Harod","Harold","Harolda","Haroldson","Haroun","Harp","Harper","Harpole","Harpp","Harragan","Harrell","Harri","Harrie","Harriet","Harriett","Harrietta","Harriette","Harriman","Harrington","Harriot","Harriott","Harris","Harrison","Harrod","Harrow","Harrus","Harry","Harshman","Harsho","Hart","Harte","Hartfield","Hartill","Hartley","Hartman","Hartmann","Hartmunn","Hartnett","Harts","Hartwell","Harty","Hartzel","Hartzell","Hartzke","Harv","Harvard","Harve","Harvey","Harvie","Harvison","Harwell","Harwill","Harwilll","Harwin","Hasan","Hasen","Hasheem","Hashim","Hashimoto","Hashum","Hasin","Haskel","Haskell","Haskins","Haslam","Haslett","Hasseman","Hassett","Hassi","Hassin","Hastie","Hastings","Hasty","Haswell","Hatch","Hatcher","Hatfield","Hathaway","Hathcock","Hatti","Hattie","Hatty","Hau","Hauck","Hauge","Haugen","Hauger","Haughay","Haukom","Hauser","Hausmann","Hausner","Havard","Havelock","Haveman","Haven","Havener","Havens","Havstad","Hawger","Hawk","Hawken","Hawker","Hawkie","Hawkins","Hawley","Hawthorn","Hax","Hay","Haya","Hayashi","Hayden","Haydon","Haye","Hayes","Hayley","Hayman","Haymes","Haymo","Hayne","Haynes","Haynor","Hayott","Hays","Hayse","Hayton","Hayward","Haywood","Hayyim","Hazaki","Hazard","Haze","Hazeghi","Hazel","Hazelton","Hazem","Hazen","Hazlett","Hazlip","Head","Heady","Healey","Healion","Heall","Healy","Heaps","Hearn","Hearsh","Heater","Heath","Heathcote","Heather","Hebbe","Hebe","Hebel","Heber","Hebert","Hebner","Hebrew","Hecht","Heck","Hecker","Hecklau","Hector","Heda","Hedberg","Hedda","Heddi","Heddie","Heddy","Hedelman","Hedgcock","Hedges","Hedi","Hedley","Hedva","Hedvah","Hedve","Hedveh","Hedvig","Hedvige","Hedwig","Hedwiga","Hedy","Heeley","Heer","Heffron","Hefter","Hegarty","Hege","Heger","Hegyera","Hehre","Heid","Heida","Heidi","Heidie","Heidt","Heidy","Heigho","Heigl","Heilman","Heilner","Heim","Heimer","Heimlich","Hein","Heindrick","Heiner","Heiney","Heinrich","Heinrick","Heinrik","Heinrike","Heins","Heintz","Heise","Heisel","Heiskell","Heisser","Hekker","Hekking","Helaina","Helaine","Helali","Helban","Helbon","Helbona","Helbonia","Helbonna","Helbonnah","Helbonnas","Held","Helen","Helena","Helene","Helenka","Helfand","Helfant","Helga","Helge","Helgeson","Hellene","Heller","Helli","Hellman","Helm","Helman","Helmer","Helms","Helmut","Heloise","Helprin","Helsa","Helse","Helsell","Helsie","Helve","Helyn","Heman","Hembree","Hemingway","Hemminger","Hemphill","Hen","Hendel","Henden","Henderson","Hendon","Hendren","Hendrick","Hendricks","Hendrickson","Hendrik","Hendrika","Hendrix","Hendry","Henebry","Heng","Hengel","Henghold","Henig","Henigman","Henka","Henke","Henleigh","Henley","Henn","Hennahane","Hennebery","Hennessey","Hennessy","Henni","Hennie","Henning","Henri","Henricks","Henrie","Henrieta","Henrietta","Henriette","Henriha","Henrik","Henrion","Henrique","Henriques","Henry","Henryetta","Henryk","Henryson","Henson","Hentrich","Hephzibah","Hephzipa","Hephzipah","Heppman","Hepsiba","Hepsibah","Hepza","Hepzi","Hera","Herald","Herb","Herbert","Herbie","Herbst","Herby","Herc","Hercule","Hercules","Herculie","Hereld","Heriberto","Heringer","Herm","Herman","Hermann","Hermes","Hermia","Hermie","Hermina","Hermine","Herminia","Hermione","Hermon","Hermosa","Hermy","Hernandez","Hernando","Hernardo","Herod","Herodias","Herold","Heron","Herr","Herra","Herrah","Herrera","Herrick","Herries","Herring","Herrington","Herriott","Herrle","Herrmann","Herrod","Hersch","Herschel","Hersh","Hershel","Hershell","Herson","Herstein","Herta","Hertberg","Hertha","Hertz","Hertzfeld","Hertzog","Herv","Herve","Hervey","Herwick","Herwig","Herwin","Herzberg","Herzel","Herzen","Herzig","Herzog","Hescock","Heshum","Hesketh","Hesky","Hesler","Hesper","Hess","Hessler","Hessney","Hesta","Hester","Hesther","Hestia","Heti","Hett","Hetti","Hettie","Hetty","Heurlin","Heuser","Hew","Hewart","Hewe","Hewes","Hewet","Hewett","Hewie","Hewitt","Hey","Heyde","Heydon","Heyer","Heyes","Heyman","Heymann","Heyward","Heywood","Hezekiah","Hi","Hibben","Hibbert","Hibbitts","Hibbs","Hickey","Hickie","Hicks","Hidie","Hieronymus","Hiett","Higbee","Higginbotham","Higgins","Higginson","Higgs","High","Highams","Hightower","Higinbotham","Higley","Hijoung","Hike","Hilaire","Hilar","Hilaria","Hilario","Hilarius","Hilary","Hilbert","Hild","Hilda","Hildagard","Hildagarde","Hilde","Hildebrandt","Hildegaard","Hildegard","Hildegarde","Hildick","Hildie","Hildy","Hilel","Hill","Hillard","Hillari","Hillary","Hilleary","Hillegass","Hillel","Hillell","Hiller","Hillery","Hillhouse","Hilliard","Hilliary","Hillie","Hillier","Hillinck","Hillman","Hills","Hilly","Hillyer","Hiltan","Hilten","Hiltner","Hilton","Him","Hime","Himelman","Hinch","Hinckley","Hinda","Hindorff","Hindu","Hines","Hinkel","Hinkle","Hinman","Hinson","Hintze","Hinze","Hippel","Hirai","Hiram","Hirasuna","Hiro","Hiroko","Hiroshi","Hirsch","Hirschfeld","Hirsh","Hirst","Hirz","Hirza","Hisbe","Hitchcock","Hite","Hitoshi","Hitt","Hittel","Hizar","Hjerpe","Hluchy","Ho","Hoag","Hoagland","Hoang","Hoashis","Hoban","Hobard","Hobart","Hobbie","Hobbs","Hobey","Hobie","Hochman","Hock","Hocker","Hodess","Hodge","Hodges","Hodgkinson","Hodgson","Hodosh","Hoebart","Hoeg","Hoehne","Hoem","Hoenack","Hoes","Hoeve","Hoffarth","Hoffer","Hoffert","Hoffman","Hoffmann","Hofmann","Hofstetter","Hogan","Hogarth","Hogen","Hogg","Hogle","Hogue","Hoi","Hoisch","Hokanson","Hola","Holbrook","Holbrooke","Holcman","Holcomb","Holden","Holder","Holds","Hole","Holey","Holladay","Hollah","Holland","Hollander","Holle","Hollenbeck","Holleran","Hollerman","Holli","Hollie","Hollinger","Hollingsworth","Hollington","Hollis","Hollister","Holloway","Holly","Holly-Anne","Hollyanne","Holman","Holmann","Holmen","Holmes","Holms","Holmun","Holna","Holofernes","Holsworth","Holt","Holton","Holtorf","Holtz","Holub","Holzman","Homans","Home","Homer","Homere","Homerus","Homovec","Honan","Honebein","Honey","Honeyman","Honeywell","Hong","Honig","Honna","Honniball","Honor","Honora","Honoria","Honorine","Hoo","Hooge","Hook","Hooke","Hooker","Hoon","Hoopen","Hooper","Hoopes","Hootman","Hoover","Hope","Hopfinger","Hopkins","Hoppe","Hopper","Horace","Horacio","Horan","Horatia","Horatio","Horatius","Horbal","Horgan","Horick","Horlacher","Horn","Horne","Horner","Hornstein","Horodko","Horowitz","Horsey","Horst","Hort","Horten","Hortensa","Hortense","Hortensia","Horter","Horton","Horvitz","Horwath","Horwitz","Hosbein","Hose","Hosea","Hoseia","Hosfmann","Hoshi","Hoskinson","Hospers","Hotchkiss","Hotze","Hough","Houghton","Houlberg","Hound","Hourigan","Hourihan","Housen","Houser","Houston","Housum","Hovey","How","Howard","Howarth","Howe","Howell","Howenstein","Howes","Howey","Howie","Howlan","Howland","Howlend","Howlond","Howlyn","Howund","Howzell","Hoxie","Hoxsie","Hoy","Hoye","Hoyt","Hrutkay","Hsu","Hu","Huai","Huan","Huang","Huba","Hubbard","Hubble","Hube","Huber","Huberman","Hubert","Huberto","Huberty","Hubey","Hubie","Hubing","Hubsher","Huckaby","Huda","Hudgens","Hudis","Hudnut","Hudson","Huebner","Huei","Huesman","Hueston","Huey","Huff","Hufnagel","Huggins","Hugh","Hughes","Hughett","Hughie","Hughmanick","Hugibert","Hugo","Hugon","Hugues","Hui","Hujsak","Hukill","Hulbard","Hulbert","Hulbig","Hulburt","Hulda","Huldah","Hulen","Hull","Hullda","Hultgren","Hultin","Hulton","Hum","Humbert","Humberto","Humble","Hume","Humfrey","Humfrid","Humfried","Hummel","Humo","Hump","Humpage","Humph","Humphrey","Hun","Hunfredo","Hung","Hungarian","Hunger","Hunley","Hunsinger","Hunt","Hunter","Huntingdon","Huntington","Huntlee","Huntley","Huoh","Huppert","Hurd","Hurff","Hurlbut","Hurlee","Hurleigh","Hurless","Hurley","Hurlow","Hurst","Hurty","Hurwit","Hurwitz","Husain","Husch","Husein","Husha","Huskamp","Huskey","Hussar","Hussein","Hussey","Huston","Hut","Hutchings","Hutchins","Hutchinson","Hutchison","Hutner","Hutson","Hutt","Huttan","Hutton","Hux","Huxham","Huxley","Hwang","Hwu","Hy","Hyacinth","Hyacintha","Hyacinthe","Hyacinthia","Hyacinthie","Hyams","Hyatt","Hyde","Hylan","Hyland","Hylton","Hyman","Hymen","Hymie","Hynda","Hynes","Hyo","Hyozo","Hyps","Hyrup","Iago","Iain","Iams","Ian","Iand","Ianteen","Ianthe","Iaria","Iaverne","Ib","Ibbetson","Ibbie","Ibbison","Ibby","Ibrahim","Ibson","Ichabod","Icken","Id","Ida","Idalia","Idalina","Idaline","Idalla","Idden","Iddo","Ide","Idel","Idelia","Idell","Idelle","Idelson","Iden","Idette","Idleman","Idola","Idolah","Idolla","Idona","Idonah","Idonna","Idou","Idoux","Idzik","Iene","Ier","Ierna","Ieso","Ietta","Iey","Ifill","Igal","Igenia","Iggie","Iggy","Iglesias","Ignace","Ignacia","Ignacio","Ignacius","Ignatia","Ignatius","Ignatz","Ignatzia","Ignaz","Ignazio","Igor","Ihab","Iiette","Iila","Iinde","Iinden","Iives","Ike","Ikeda","Ikey","Ikkela","Ilaire","Ilan","Ilana","Ilario","Ilarrold","Ilbert","Ileana","Ileane","Ilene","Iline","Ilise","Ilka","Ilke","Illa","Illene","Illona","Illyes","Ilona","Ilonka","Ilowell","Ilsa","Ilse","Ilwain","Ilysa","Ilyse","Ilyssa","Im","Ima","Imalda","Iman","Imelda","Imelida","Imena","Immanuel","Imogen","Imogene","Imojean","Imray","Imre","Imtiaz","Ina","Incrocci","Indihar","Indira","Inerney","Ines","Inesita","Ineslta","Inessa","Inez","Infeld","Infield","Ing","Inga","Ingaberg","Ingaborg","Ingalls","Ingamar","Ingar","Inge","Ingeberg","Ingeborg","Ingelbert","Ingemar","Inger","Ingham","Inglebert","Ingles","Inglis","Ingmar","Ingold","Ingra","Ingraham","Ingram","Ingrid","Ingrim","Ingunna","Ingvar","Inigo","Inkster","Inman","Inna","Innes","Inness","Innis","Inoue","Intisar","Intosh","Intyre","Inverson","Iny","Ioab","Iolande","Iolanthe","Iolenta","Ion","Iona","Iong","Iorgo","Iorgos","Iorio","Iormina","Iosep","Ioved","Iover","Ioves","Iow","Ioyal","Iphagenia","Iphigenia","Iphigeniah","Iphlgenia","Ira","Iran","Irby","Iredale","Ireland","Irena","Irene","Irfan","Iridis","Iridissa","Irina","Iris","Irisa","Irish","Irita","Irma","Irme","Irmgard","Irmina","Irmine","Irra","Irv","Irvin","Irvine","Irving","Irwin","Irwinn","Isa","Isaac","Isaacs","Isaacson","Isaak","Isabea","Isabeau","Isabel","Isabelita","Isabella","Isabelle","Isac","Isacco","Isador","Isadora","Isadore","Isahella","Isaiah","Isak","Isbel","Isbella","Isborne","Iseabal","Isherwood","Ishii","Ishmael","Ishmul","Isia","Isiah","Isiahi","Isidor","Isidora","Isidore","Isidoro","Isidro","Isis","Isla","Islaen","Island","Isle","Islean","Isleana","Isleen","Islek","Isma","Isman","Isobel","Isola","Isolda","Isolde","Isolt","Israel","Israeli","Issi","Issiah","Issie","Issy","Ita","Itagaki","Itch","Ithaman","Ithnan","Itin","Iva","Ivah","Ivan","Ivana","Ivanah","Ivanna","Ivar","Ivatts","Ive","Ivens","Iver","Ivers","Iverson","Ives","Iveson","Ivett","Ivette","Ivetts","Ivey","Ivie","Ivo","Ivon","Ivonne","Ivor","Ivory","Ivy","Iy","Iyre","Iz","Izaak","Izabel","Izak","Izawa","Izy","Izzy","Ja","Jaal","Jaala","Jaan","Jaban","Jabe","Jabez","Jabin","Jablon","Jabon","Jac","Jacenta","Jacey","Jacie","Jacinda","Jacinta","Jacintha","Jacinthe","Jacinto","Jack","Jackelyn","Jacki","Jackie","Jacklin","Jacklyn","Jackquelin","Jackqueline","Jackson","Jacky","Jaclin","Jaclyn","Jaco","Jacob","Jacoba","Jacobah","Jacobba","Jacobina","Jacobine","Jacobo","Jacobs","Jacobsen","Jacobsohn","Jacobson","Jacoby","Jacquelin","Jacqueline","Jacquelyn","Jacquelynn","Jacquenetta","Jacquenette","Jacques","Jacquet","Jacquetta","Jacquette","Jacqui","Jacquie","Jacy","Jacynth","Jada","Jadd","Jadda","Jaddan","Jaddo","Jade","Jadwiga","Jae","Jaeger","Jaehne","Jael","Jaela","Jaella","Jaenicke","Jaf","Jaffe","Jagir","Jago","Jahdai","Jahdal","Jahdiel","Jahdol","Jahn","Jahncke","Jaime","Jaime ","Jaimie","Jain","Jaine","Jair","Jairia","Jake","Jakie","Jakob","Jakoba","Jala","Jalbert","Jallier","Jamaal","Jamal","Jamel","James","Jameson","Jamesy","Jamey","Jami","Jamie","Jamieson","Jamil","Jamila","Jamill","Jamilla","Jamille","Jamima","Jamin","Jamison","Jammal","Jammie","Jammin","Jamnes","Jamnis","Jan","Jana","Janaya","Janaye","Jandel","Jandy","Jane","Janean","Janeczka","Janeen","Janek","Janel","Janela","Janella","Janelle","Janene","Janenna","Janerich","Janessa","Janet","Janeta","Janetta","Janette","Janeva","Janey","Jangro","Jania","Janice","Janicki","Janie","Janifer","Janik","Janina","Janine","Janis","Janith","Janiuszck","Janka","Jankell","Jankey","Jann","Janna","Jannel","Jannelle","Jannery","Janos","Janot","Jansen","Jansson","Januarius","January","Januisz","Janus","Jany","Janyte","Japeth","Japha","Japheth","Jaqitsch","Jaquelin","Jaquelyn","Jaquenetta","Jaquenette","Jaquiss","Jaquith","Jara","Jarad","Jard","Jardena","Jareb","Jared","Jarek","Jaret","Jari","Jariah","Jarib","Jarid","Jarietta","Jarita","Jarl","Jarlath","Jarlathus","Jarlen","Jarnagin","Jarrad","Jarred","Jarrell","Jarret","Jarrett","Jarrid","Jarrod","Jarrow","Jarv","Jarvey","Jarvis","Jary","Jase","Jasen","Jasik","Jasisa","Jasmin","Jasmina","Jasmine","Jason","Jasper","Jasun","Jauch","Jaunita","Javed","Javier","Javler","Jaworski","Jay","Jaycee","Jaye","Jaylene","Jayme","Jaymee","Jaymie","Jayne","Jaynell","Jaynes","Jayson","Jazmin","Jdavie","Jea","Jean","Jean-Claude","Jeana","Jeane","Jeanelle","Jeanette","Jeanie","Jeanine","Jeanna","Jeanne","Jeannette","Jeannie","Jeannine","Jeavons","Jeaz","Jeb","Jecho","Jecoa","Jecon","Jeconiah","Jed","Jedd","Jeddy","Jedediah","Jedidiah","Jedlicka","Jedthus","Jeff","Jeffcott","Jefferey","Jeffers","Jefferson","Jeffery","Jeffie","Jeffrey","Jeffries","Jeffry","Jeffy","Jegar","Jeggar","Jegger","Jehanna","Jehiah","Jehial","Jehias","Jehiel","Jehius","Jehoash","Jehovah","Jehu","Jelena","Jelene","Jelks","Jelle","Jelsma","Jem","Jemena","Jemie","Jemima","Jemimah","Jemina","Jeminah","Jemine","Jemma","Jemmie","Jemmy","Jempty","Jemy","Jen","Jena","Jenda","Jenei","Jenelle","Jenesia","Jenette","Jeni","Jenica","Jeniece","Jenifer","Jeniffer","Jenilee","Jenine","Jenkel","Jenkins","Jenks","Jenn","Jenna","Jenne","Jennee","Jenness","Jennette","Jenni","Jennica","Jennie","Jennifer","Jennilee","Jennine","Jennings","Jenny","Jeno","Jens","Jensen","Jentoft","Jephthah","Jephum","Jepson","Jepum","Jer","Jerad","Jerald","Jeraldine","Jeralee","Jeramey","Jeramie","Jere","Jereld","Jereme","Jeremiah","Jeremias","Jeremie","Jeremy","Jeri","Jeritah","Jermain","Jermaine","Jerman","Jermayne","Jermyn","Jerol","Jerold","Jeroma","Jerome","Jeromy","Jerri","Jerrie","Jerrilee","Jerrilyn","Jerrine","Jerrol","Jerrold","Jerroll","Jerrome","Jerry","Jerrylee","Jerusalem","Jervis","Jerz","Jesh","Jesher","Jess","Jessa","Jessabell","Jessalin","Jessalyn","Jessamine","Jessamyn","Jesse","Jessee","Jesselyn","Jessen","Jessey","Jessi","Jessica","Jessie","Jessika","Jessy","Jestude","Jesus","Jeth","Jethro","Jeu","Jeunesse","Jeuz","Jevon","Jew","Jewel","Jewell","Jewelle","Jewett","Jews","Jez","Jezabel","Jezabella","Jezabelle","Jezebel","Jezreel","Ji","Jill","Jillana","Jillane","Jillayne","Jilleen","Jillene","Jilli","Jillian","Jillie","Jilly","Jim","Jimmie","Jimmy","Jinny","Jit","Jo","Jo Ann","Jo-Ann","Jo-Anne","JoAnn","JoAnne","Joab","Joachim","Joachima","Joacima","Joacimah","Joan","Joana","Joane","Joanie","Joann","Joanna","Joanne","Joannes","Joao","Joappa","Joaquin","Joash","Joashus","Job","Jobe","Jobey","Jobi","Jobie","Jobina","Joby","Jobye","Jobyna","Jocelin","Joceline","Jocelyn","Jocelyne","Jochbed","Jochebed","Jock","Jocko","Jodee","Jodi","Jodie","Jodoin","Jody","Joe","Joeann","Joed","Joel","Joela","Joelie","Joell","Joella","Joelle","Joellen","Joelly","Joellyn","Joelynn","Joerg","Joete","Joette","Joey","Joh","Johan","Johanan","Johann","Johanna","Johannah","Johannes","Johannessen","Johansen","Johathan","Johen","Johiah","Johm","John","Johna","Johnath","Johnathan","Johnathon","Johnette","Johnna","Johnnie","Johnny","Johns","Johnson","Johnsson","Johnsten","Johnston","Johnstone","Johny","Johppa","Johppah","Johst","Joice","Joiner","Jojo","Joktan","Jola","Jolanta","Jolda","Jolee","Joleen","Jolene","Jolenta","Joletta","Joli","Jolie","Joliet","Joline","Jollanta","Jollenta","Joly","Jolyn","Jolynn","Jon","Jona","Jonah","Jonas","Jonathan","Jonathon","Jonati","Jone","Jonell","Jones","Jonette","Joni","Jonie","Jonina","Jonis","Jonme","Jonna","Jonny","Joo","Joon","Joost","Jopa","Jordain","Jordan","Jordana","Jordanna","Jordans","Jordanson","Jordison","Jordon","Jorey","Jorgan","Jorge","Jorgensen","Jorgenson","Jori","Jorie","Jorin","Joris","Jorrie","Jorry","Jory","Jos","Joscelin","Jose","Josee","Josefa","Josefina","Joseito","Joselow","Joselyn","Joseph","Josepha","Josephina","Josephine","Josephson","Joses","Josey","Josh","Joshi","Joshia","Joshua","Joshuah","Josi","Josiah","Josias","Josie","Josler","Joslyn","Josselyn","Josy","Jotham","Joub","Joung","Jourdain","Jourdan","Jovi","Jovia","Jovita","Jovitah","Jovitta","Jowett","Joy","Joya","Joyan","Joyann","Joyce","Joycelin","Joye","Jozef","Jsandye","Juan","Juana","Juanita","Juanne","Juback","Jud","Judah","Judas","Judd","Jude","Judenberg","Judi","Judie","Judith","Juditha","Judon","Judsen","Judson","Judus","Judy","Judye","Jueta","Juetta","Juieta","Jule","Julee","Jules","Juley","Juli","Julia","Julian","Juliana","Juliane","Juliann","Julianna","Julianne","Juliano","Julide","Julie","Julienne","Juliet","Julieta","Julietta","Juliette","Julina","Juline","Julio","Julis","Julissa","Julita","Julius","Jumbala","Jump","Jun","Juna","June","Junette","Jung","Juni","Junia","Junie","Junieta","Junina","Junius","Junji","Junko","Junna","Junno","Juno","Jurdi","Jurgen","Jurkoic","Just","Justen","Juster","Justicz","Justin","Justina","Justine","Justinian","Justinn","Justino","Justis","Justus","Juta","Jutta","Juxon","Jyoti","Kablesh","Kacerek","Kacey","Kachine","Kacie","Kacy","Kaczer","Kaden","Kadner","Kado","Kaela","Kaenel","Kaete","Kafka","Kahaleel","Kahl","Kahle","Kahler","Kahlil","Kahn","Kai","Kaia","Kaila","Kaile","Kailey","Kain","Kaine","Kaiser","Kaitlin","Kaitlyn","Kaitlynn","Kaiulani","Kaja","Kajdan","Kakalina","Kal","Kala","Kalagher","Kalasky","Kalb","Kalbli","Kale","Kaleb","Kaleena","Kalfas","Kali","Kalie","Kalikow","Kalil","Kalila","Kalin","Kalina","Kalinda","Kalindi","Kaliope","Kaliski","Kalk","Kall","Kalle","Kalli","Kallick","Kallista","Kallman","Kally","Kalman","Kalmick","Kaltman","Kalvin","Kalvn","Kam","Kama","Kamal","Kamaria","Kamat","Kameko","Kamerman","Kamila","Kamilah","Kamillah","Kamin","Kammerer","Kamp","Kampmann","Kampmeier","Kan","Kanal","Kancler","Kandace","Kandy","Kane","Kania","Kannan","Kannry","Kano","Kant","Kanter","Kantor","Kantos","Kanya","Kape","Kaplan","Kapoor","Kapor","Kappel","Kappenne","Kara","Kara-Lynn","Karalee","Karalynn","Karame","Karas","Karb","Kare","Karee","Kareem","Karel","Karen","Karena","Kari","Karia","Karie","Karil","Karilla","Karilynn","Karim","Karin","Karina","Karine","Kariotta","Karisa","Karissa","Karita","Karl","Karla","Karlan","Karlee","Karleen","Karlen","Karlene","Karlens","Karli","Karlie","Karlik","Karlin","Karlis","Karlise","Karlotta","Karlotte","Karlow","Karly","Karlyn","Karmen","Karna","Karney","Karol","Karola","Karole","Karolina","Karoline","Karoly","Karolyn","Karon","Karp","Karr","Karrah","Karrie","Karry","Karsten","Kartis","Karwan","Kary","Karyl","Karylin","Karyn","Kasevich","Kasey","Kashden","Kask","Kaslik","Kaspar","Kasper","Kass","Kassab","Kassandra","Kassaraba","Kassel","Kassey","Kassi","Kassia","Kassie","Kassity","Kast","Kat","Kata","Katalin","Kataway","Kate","Katee","Katerina","Katerine","Katey","Kath","Katha","Katharina","Katharine","Katharyn","Kathe","Katherin","Katherina","Katherine","Katheryn","Kathi","Kathie","Kathleen","Kathlene","Kathlin","Kathrine","Kathryn","Kathryne","Kathy","Kathye","Kati","Katie","Katina","Katine","Katinka","Katlaps","Katleen","Katlin","Kato","Katonah","Katrina","Katrine","Katrinka","Katsuyama","Katt","Katti","Kattie","Katuscha","Katusha","Katushka","Katy","Katya","Katz","Katzen","Katzir","Katzman","Kauffman","Kauffmann","Kaufman","Kaufmann","Kaule","Kauppi","Kauslick","Kavanagh","Kavanaugh","Kavita","Kawai","Kawasaki","Kay","Kaya","Kaycee","Kaye","Kayla","Kayle","Kaylee","Kayley","Kaylil","Kaylyn","Kayne","Kaz","Kazim","Kazimir","Kazmirci","Kazue","Kealey","Kean","Keane","Keare","Kearney","Keary","Keating","Keavy","Kee","Keefe","Keefer","Keegan","Keel","Keelby","Keele","Keeler","Keeley","Keelia","Keelin","Keely","Keen","Keenan","Keene","Keener","Keese","Keeton","Keever","Keffer","Keg","Kegan","Keheley","Kehoe","Kehr","Kei","Keifer","Keiko","Keil","Keily","Keir","Keisling","Keith","Keithley","Kela","Kelbee","Kelby","Kelcey","Kelci","Kelcie","Kelcy","Kelda","Keldah","Keldon","Kele","Keli","Keligot","Kelila","Kella","Kellby","Kellda","Kelleher","Kellen","Kellene","Keller","Kelley","Kelli","Kellia","Kellie","Kellina","Kellsie","Kelly","Kellyann","Kellyn","Kelsey","Kelsi","Kelson","Kelsy","Kelton","Kelula","Kelvin","Kelwen","Kelwin","Kelwunn","Kemble","Kemeny","Kemme","Kemp","Kempe","Kemppe","Ken","Kenay","Kenaz","Kendal","Kendall","Kendell","Kendra","Kendrah","Kendre","Kendrick","Kendricks","Kendry","Kendy","Kendyl","Kenelm","Kenison","Kenji","Kenlay","Kenlee","Kenleigh","Kenley","Kenn","Kenna","Kennan","Kennard","Kennedy","Kennet","Kenneth","Kennett","Kenney","Kennie","Kennith","Kenny","Kenon","Kenric","Kenrick","Kensell","Kent","Kenta","Kenti","Kentiga","Kentigera","Kentigerma","Kentiggerma","Kenton","Kenward","Kenway","Kenwee","Kenweigh","Kenwood","Kenwrick","Kenyon","Kenzi","Kenzie","Keon","Kepner","Keppel","Ker","Kerby","Kerek","Kerekes","Kerge","Keri","Keriann","Kerianne","Kerin","Kerk","Kerman","Kermie","Kermit","Kermy","Kern","Kernan","Kerns","Kerr","Kerri","Kerrie","Kerril","Kerrill","Kerrin","Kerrison","Kerry","Kersten","Kerstin","Kerwin","Kerwinn","Kerwon","Kery","Kesia","Kesley","Keslie","Kessel","Kessia","Kessiah","Kessler","Kester","Ketchan","Ketchum","Ketti","Kettie","Ketty","Keung","Kev","Kevan","Keven","Keverian","Keverne","Kevin","Kevina","Kevon","Kevyn","Key","Keyek","Keyes","Keynes","Keyser","Keyte","Kezer","Khai","Khajeh","Khalid","Khalil","Khalin","Khalsa","Khan","Khanna","Khano","Khichabia","Kho","Khorma","Khosrow","Khoury","Khudari","Ki","Kiah","Kial","Kidd","Kidder","Kiefer","Kieffer","Kieger","Kiehl","Kiel","Kiele","Kielty","Kienan","Kier","Kieran","Kiernan","Kiersten","Kikelia","Kiker","Kiki","Kila","Kilah","Kilan","Kilar","Kilbride","Kilby","Kile","Kiley","Kilgore","Kilian","Kilk","Killam","Killarney","Killen","Killian","Killie","Killigrew","Killion","Killoran","Killy","Kilmarx","Kilroy","Kim","Kimball","Kimbell","Kimber","Kimberlee","Kimberley","Kimberli","Kimberly","Kimberlyn","Kimble","Kimbra","Kimitri","Kimmel","Kimmi","Kimmie","Kimmy","Kimon","Kimura","Kin","Kinata","Kincaid","Kinch","Kinchen","Kind","Kindig","Kinelski","King","Kingdon","Kinghorn","Kingsbury","Kingsley","Kingsly","Kingston","Kinna","Kinnard","Kinney","Kinnie","Kinnon","Kinny","Kinsler","Kinsley","Kinsman","Kinson","Kinzer","Kiona","Kip","Kipp","Kippar","Kipper","Kippie","Kippy","Kipton","Kira","Kiran","Kirbee","Kirbie","Kirby","Kirch","Kirchner","Kiri","Kirima","Kirimia","Kirit","Kirk","Kirkpatrick","Kirkwood","Kironde","Kirsch","Kirschner","Kirshbaum","Kirst","Kirsten","Kirsteni","Kirsti","Kirstin","Kirstyn","Kirt","Kirtley","Kirven","Kirwin","Kisor","Kissee","Kissel","Kissiah","Kissie","Kissner","Kistner","Kisung","Kit","Kitchen","Kitti","Kittie","Kitty","Kiyohara","Kiyoshi","Kizzee","Kizzie","Kjersti","Klapp","Klara","Klarika","Klarrisa","Klatt","Klaus","Klayman","Klecka","Kleeman","Klehm","Kleiman","Klein","Kleinstein","Klemens","Klement","Klemm","Klemperer","Klenk","Kleon","Klepac","Kleper","Kletter","Kliber","Kliman","Kliment","Klimesh","Klina","Kline","Kling","Klingel","Klinger","Klinges","Klockau","Kloman","Klos","Kloster","Klotz","Klug","Kluge","Klump","Klusek","Klute","Knapp","Kneeland","Knepper","Knick","Knight","Knighton","Knipe","Knitter","Knobloch","Knoll","Knorring","Knowland","Knowle","Knowles","Knowling","Knowlton","Knox","Knudson","Knut","Knute","Knuth","Knutson","Ko","Koa","Koah","Koal","Koball","Kobe","Kobi","Koblas","Koblick","Koby","Kobylak","Koch","Koehler","Koenig","Koeninger","Koenraad","Koeppel","Koerlin","Koerner","Koetke","Koffler","Koffman","Koh","Kohl","Kohler","Kohn","Kokaras","Kokoruda","Kolb","Kolivas","Kolk","Koller","Kolnick","Kolnos","Kolodgie","Kolosick","Koloski","Kolva","Komara","Komarek","Komsa","Kondon","Kone","Kong","Konikow","Kono","Konopka","Konrad","Konstance","Konstantin","Konstantine","Konstanze","Konyn","Koo","Kooima","Koosis","Kopans","Kopaz","Kopp","Koppel","Kopple","Kora","Koral","Koralie","Koralle","Koran","Kordula","Kore","Korella","Koren","Korenblat","Koressa","Korey","Korff","Korfonta","Kori","Korie","Korman","Korney","Kornher","Korns","Korrie","Korry","Kort","Korten","Korwin","Korwun","Kory","Kosak","Kosaka","Kosel","Koser","Kosey","Kosiur","Koslo","Koss","Kosse","Kostival","Kostman","Kotick","Kotta","Kotto","Kotz","Kovacev","Kovacs","Koval","Kovar","Kowal","Kowalski","Kowatch","Kowtko","Koy","Koziara","Koziarz","Koziel","Kozloski","Kraft","Kragh","Krahling","Krahmer","Krakow","Krall","Kramer","Kramlich","Krantz","Kraska","Krasner","Krasnoff","Kraul","Kraus","Krause","Krauss","Kravits","Krawczyk","Kreager","Krebs","Kreda","Kreegar","Krefetz","Kreg","Kreiker","Krein","Kreindler","Kreiner","Kreis","Kreit","Kreitman","Krell","Kremer","Krenek","Krenn","Kresic","Kress","Krever","Kries","Krigsman","Krilov","Kris","Krischer","Krisha","Krishna","Krishnah","Krispin","Kriss","Krissie","Krissy","Krista","Kristal","Kristan","Kriste","Kristel","Kristen","Kristi","Kristian","Kristianson","Kristie","Kristien","Kristin","Kristina","Kristine","Kristo","Kristof","Kristofer","Kristoffer","Kristofor","Kristoforo","Kristopher","Kristos","Kristy","Kristyn","Krock","Kroll","Kronfeld","Krongold","Kronick","Kroo","Krucik","Krueger","Krug","Kruger","Krum","Krusche","Kruse","Krute","Kruter","Krutz","Krys","Kryska","Krysta","Krystal","Krystalle","Krystin","Krystle","Krystyna","Ku","Kubetz","Kubiak","Kubis","Kucik","Kudva","Kuebbing","Kuehn","Kuehnel","Kuhlman","Kuhn","Kulda","Kulseth","Kulsrud","Kumagai","Kumar","Kumler","Kung","Kunin","Kunkle","Kunz","Kuo","Kurland","Kurman","Kurr","Kursh","Kurt","Kurth","Kurtis","Kurtz","Kurtzig","Kurtzman","Kurys","Kurzawa","Kus","Kushner","Kusin","Kuska","Kussell","Kuster","Kutchins","Kuth","Kutzenco","Kutzer","Kwabena","Kwan","Kwang","Kwapong","Kwarteng","Kwasi","Kwei","Kwok","Kwon","Ky","Kyd","Kyl","Kyla","Kylah","Kylander","Kyle","Kylen","Kylie","Kylila","Kylstra","Kylynn","Kym","Kynan","Kyne","Kynthia","Kyriako","Kyrstin","Kyte","La","La Verne","LaBaw","LaMee","LaMonica","LaMori","LaRue","LaSorella","Laaspere","Laban","Labana","Laband","Labanna","Labannah","Labors","Lacagnia","Lacee","Lacefield","Lacey","Lach","Lachance","Lachish","Lachlan","Lachman","Lachus","Lacie","Lacombe","Lacy","Lad","Ladd","Laddie","Laddy","Laden","Ladew","Ladonna","Lady","Lael","Laetitia","Laflam","Lafleur","Laforge","Lagas","Lagasse","Lahey","Lai","Laidlaw","Lail","Laina","Laine","Lainey","Laing","Laird","Lais","Laise","Lait","Laith","Laius","Lakin","Laks","Laktasic","Lal","Lala","Lalage","Lali","Lalise","Lalita","Lalitta","Lalittah","Lalla","Lallage","Lally","Lalo","Lam","Lamar","Lamarre","Lamb","Lambard","Lambart","Lambert","Lamberto","Lambertson","Lambrecht","Lamdin","Lammond","Lamond","Lamont","Lamoree","Lamoureux","Lamp","Lampert","Lamphere","Lamprey","Lamrert","Lamrouex","Lamson","Lan","Lana","Lanae","Lanam","Lananna","Lancaster","Lance","Lancelle","Lancelot","Lancey","Lanctot","Land","Landa","Landahl","Landan","Landau","Landbert","Landel","Lander","Landers","Landes","Landing","Landis","Landmeier","Landon","Landre","Landri","Landrum","Landry","Landsman","Landy","Lane","Lanette","Laney","Lanford","Lanfri","Lang","Langan","Langbehn","Langdon","Lange","Langelo","Langer","Langham","Langill","Langille","Langley","Langsdon","Langston","Lani","Lanie","Lanita","Lankton","Lanna","Lanni","Lannie","Lanny","Lansing","Lanta","Lantha","Lanti","Lantz","Lanza","Lapham","Lapides","Lapointe","Lapotin","Lara","Laraine","Larcher","Lardner","Lareena","Lareine","Larena","Larentia","Laresa","Largent","Lari","Larianna","Larimer","Larimor","Larimore","Larina","Larine","Laris","Larisa","Larissa","Lark","Larkin","Larkins","Larner","Larochelle","Laroy","Larrabee","Larrie","Larrisa","Larry","Lars","Larsen","Larson","Laryssa","Lasala","Lash","Lashar","Lashoh","Lashond","Lashonda","Lashonde","Lashondra","Lasko","Lasky","Lasley","Lasonde","Laspisa","Lasser","Lassiter","Laszlo","Lat","Latashia","Latea","Latham","Lathan","Lathe","Lathrop","Lathrope","Lati","Latia","Latif","Latimer","Latimore","Latin","Latini","Latisha","Latona","Latonia","Latoniah","Latouche","Latoya","Latoye","Latoyia","Latreece","Latreese","Latrell","Latrena","Latreshia","Latrice","Latricia","Latrina","Latt","Latta","Latterll","Lattie","Lattimer","Latton","Lattonia","Latty","Latvina","Lau","Lauber","Laubin","Laud","Lauder","Lauer","Laufer","Laughlin","Laughry","Laughton","Launce","Launcelot","Laundes","Laura","Lauraine","Laural","Lauralee","Laurance","Laure","Lauree","Laureen","Laurel","Laurella","Lauren","Laurena","Laurence","Laurene","Laurens","Laurent","Laurentia","Laurentium","Lauretta","Laurette","Lauri","Laurianne","Laurice","Laurie","Laurin","Laurinda","Laurita","Lauritz","Lauro","Lauryn","Lauter","Laux","Lauzon","Laval","Laveen","Lavella","Lavelle","Laven","Lavena","Lavern","Laverna","Laverne","Lavery","Lavina","Lavine","Lavinia","Lavinie","Lavoie","Lavona","Law","Lawford","Lawler","Lawley","Lawlor","Lawrence","Lawrenson","Lawry","Laws","Lawson","Lawton","Lawtun","Lay","Layla","Layman","Layne","Layney","Layton","Lazar","Lazare","Lazaro","Lazaruk","Lazarus","Lazes","Lazor","Lazos","Le","LeCroy","LeDoux","LeMay","LeRoy","LeVitus","Lea","Leach","Leacock","Leah","Leahey","Leake","Leal","Lean","Leanard","Leander","Leandra","Leandre","Leandro","Leann","Leanna","Leanne","Leanor","Leanora","Leaper","Lear","Leary","Leasia","Leatri","Leatrice","Leavelle","Leavitt","Leavy","Leban","Lebar","Lebaron","Lebbie","Leblanc","Lebna","Leboff","Lechner","Lecia","Leckie","Leclair","Lectra","Leda","Ledah","Ledda","Leddy","Ledeen","Lederer","Lee","LeeAnn","Leeann","Leeanne","Leede","Leeke","Leela","Leelah","Leeland","Leena","Leesa","Leese","Leesen","Leeth","Leff","Leffen","Leffert","Lefkowitz","Lefton","Leftwich","Lefty","Leggat","Legge","Leggett","Legra","Lehet","Lehman","Lehmann","Lehrer","Leia","Leibman","Leicester","Leid","Leif","Leifer","Leifeste","Leigh","Leigha","Leighland","Leighton","Leila","Leilah","Leilani","Leipzig","Leis","Leiser","Leisha","Leitao","Leith","Leitman","Lejeune","Lek","Lela","Lelah","Leland","Leler","Lelia","Lelith","Lello","Lem","Lema","Lemaceon","Lemal","Lemar","Lemcke","Lemieux","Lemire","Lemkul","Lemmie","Lemmuela","Lemmueu","Lemmy","Lemon","Lempres","Lemuel","Lemuela","Lemuelah","Len","Lena","Lenard","Lenci","Lenee","Lenes","Lenette","Lengel","Lenhard","Lenhart","Lenka","Lenna","Lennard","Lenni","Lennie","Lenno","Lennon","Lennox","Lenny","Leno","Lenora","Lenore","Lenox","Lenrow","Lenssen","Lentha","Lenwood","Lenz","Lenzi","Leo","Leod","Leodora","Leoine","Leola","Leoline","Leon","Leona","Leonanie","Leonard","Leonardi","Leonardo","Leone","Leonelle","Leonerd","Leong","Leonhard","Leoni","Leonid","Leonidas","Leonie","Leonor","Leonora","Leonore","Leonsis","Leonteen","Leontina","Leontine","Leontyne","Leopold","Leopoldeen","Leopoldine","Leor","Leora","Leotie","Lepine","Lepley","Lepp","Lepper","Lerner","Leroi","Leroy","Les","Lesak","Leschen","Lesh","Leshia","Lesko","Leslee","Lesley","Lesli","Leslie","Lesly","Lessard","Lesser","Lesslie","Lester","Lesya","Let","Leta","Letch","Letha","Lethia","Leticia","Letisha","Letitia","Letizia","Letreece","Letrice","Letsou","Letta","Lette","Letti","Lettie","Letty","Leund","Leupold","Lev","Levan","Levana","Levania","Levenson","Leventhal","Leventis","Leverett","Leverick","Leveridge","Leveroni","Levesque","Levey","Levi","Levin","Levina","Levine","Levins","Levinson","Levison","Levitan","Levitt","Levon","Levona","Levy","Lew","Lewak","Lewan","Lewanna","Lewellen","Lewendal","Lewert","Lewes","Lewie","Lewin","Lewis","Lewison","Lewiss","Lewls","Lewse","Lexi","Lexie","Lexine","Lexis","Lexy","Ley","Leyes","Leyla","Lezley","Lezlie","Lhary","Li","Lia","Liam","Lian","Liana","Liane","Lianna","Lianne","Lias","Liatrice","Liatris","Lib","Liba","Libb","Libbey","Libbi","Libbie","Libbna","Libby","Libenson","Liberati","Libna","Libnah","Liborio","Libove","Libre","Licastro","Licha","Licht","Lichtenfeld","Lichter","Licko","Lida","Lidah","Lidda","Liddie","Liddle","Liddy","Lidia","Lidstone","Lieberman","Liebermann","Liebman","Liebowitz","Liederman","Lief","Lienhard","Liesa","Lietman","Liew","Lifton","Ligetti","Liggett","Liggitt","Light","Lightfoot","Lightman","Lil","Lila","Lilac","Lilah","Lilas","Lili","Lilia","Lilian","Liliane","Lilias","Lilith","Lilithe","Lilla","Lilli","Lillian","Lillie","Lillis","Lillith","Lilllie","Lilly","Lillywhite","Lily","Lilyan","Lilybel","Lilybelle","Lim","Liman","Limann","Limber","Limbert","Limemann","Limoli","Lin","Lina","Linc","Lincoln","Lind","Linda","Lindahl","Lindberg","Lindblad","Lindbom","Lindeberg","Lindell","Lindemann","Linden","Linder","Linders","Lindgren","Lindholm","Lindi","Lindie","Lindley","Lindly","Lindner","Lindo","Lindon","Lindsay","Lindsey","Lindsley","Lindsy","Lindy","Line","Linea","Linehan","Linell","Linet","Linetta","Linette","Ling","Lingwood","Linis","Link","Linker","Linkoski","Linn","Linnea","Linnell","Linneman","Linnet","Linnette","Linnie","Linoel","Linsk","Linskey","Linson","Linus","Linzer","Linzy","Lion","Lionel","Lionello","Lipcombe","Lipfert","Lipinski","Lipkin","Lipman","Liponis","Lipp","Lippold","Lipps","Lipscomb","Lipsey","Lipski","Lipson","Lira","Liris","Lisa","Lisabet","Lisabeth","Lisan","Lisandra","Lisbeth","Liscomb","Lise","Lisetta","Lisette","Lisha","Lishe","Lisk","Lisle","Liss","Lissa","Lissak","Lissi","Lissie","Lissner","Lissy","Lister","Lita","Litch","Litha","Lithea","Litman","Litt","Litta","Littell","Little","Littlejohn","Littman","Litton","Liu","Liuka","Liv","Liva","Livesay","Livi","Livia","Livingston","Livingstone","Livvi","Livvie","Livvy","Livvyy","Livy","Liz","Liza","Lizabeth","Lizbeth","Lizette","Lizzie","Lizzy","Ljoka","Llewellyn","Llovera","Lloyd","Llywellyn","Loar","Loats","Lobel","Lobell","Lochner","Lock","Locke","Lockhart","Locklin","Lockwood","Lodge","Lodhia","Lodi","Lodie","Lodmilla","Lodovico","Lody","Loeb","Loella","Loesceke","Loferski","Loftis","Loftus","Logan","Loggia","Loggins","Loginov","Lohman","Lohner","Lohrman","Lohse","Lois","Loise","Lola","Lolande","Lolanthe","Lole","Loleta","Lolita","Lolly","Loma","Lomasi","Lomax","Lombard","Lombardi","Lombardo","Lombardy","Lon","Lona","London","Londoner","Lonee","Lonergan","Long","Longan","Longawa","Longerich","Longfellow","Longley","Longmire","Longo","Longtin","Longwood","Loni","Lonier","Lonna","Lonnard","Lonne","Lonni","Lonnie","Lonny","Lontson","Loomis","Loos","Lopes","Lopez","Lora","Lorain","Loraine","Loralee","Loralie","Loralyn","Loram","Lorant","Lord","Lordan","Loredana","Loredo","Loree","Loreen","Lorelei","Lorelie","Lorelle","Loren","Lorena","Lorene","Lorens","Lorenz","Lorenza","Lorenzana","Lorenzo","Loresz","Loretta","Lorette","Lori","Loria","Lorianna","Lorianne","Lorie","Lorien","Lorilee","Lorilyn","Lorimer","Lorin","Lorinda","Lorine","Loriner","Loring","Loris","Lorita","Lorn","Lorna","Lorne","Lorola","Lorolla","Lorollas","Lorou","Lorraine","Lorrayne","Lorri","Lorrie","Lorrimer","Lorrimor","Lorrin","Lorry","Lorsung","Lorusso","Lory","Lose","Loseff","Loss","Lossa","Losse","Lot","Lothair","Lothaire","Lothar","Lothario","Lotson","Lotta","Lotte","Lotti","Lottie","Lotty","Lotus","Lotz","Lou","Louanna","Louanne","Louella","Lough","Lougheed","Loughlin","Louie","Louis","Louisa","Louise","Louisette","Louls","Lounge","Lourdes","Lourie","Louth","Loutitia","Loux","Lovash","Lovato","Love","Lovel","Lovell","Loveridge","Lovering","Lovett","Lovich","Lovmilla","Low","Lowe","Lowell","Lowenstein","Lowenstern","Lower","Lowery","Lowis","Lowndes","Lowney","Lowrance","Lowrie","Lowry","Lowson","Loy","Loyce","Loydie","Lozano","Lozar","Lu","Luana","Luane","Luann","Luanne","Luanni","Luba","Lubba","Lubbi","Lubbock","Lubeck","Luben","Lubet","Lubin","Lubow","Luby","Luca","Lucais","Lucania","Lucas","Lucchesi","Luce","Lucey","Lucho","Luci","Lucia","Lucian","Luciana","Luciano","Lucias","Lucic","Lucie","Lucien","Lucienne","Lucier","Lucila","Lucilia","Lucilla","Lucille","Lucina","Lucinda","Lucine","Lucio","Lucita","Lucius","Luckett","Luckin","Lucky","Lucrece","Lucretia","Lucy","Lud","Ludeman","Ludewig","Ludie","Ludlew","Ludlow","Ludly","Ludmilla","Ludovick","Ludovico","Ludovika","Ludvig","Ludwig","Ludwigg","Ludwog","Luebke","Luedtke","Luehrmann","Luella","Luelle","Lugar","Lugo","Luhe","Luhey","Luht","Luigi","Luigino","Luing","Luis","Luisa","Luise","Luiza","Lukas","Lukash","Lukasz","Luke","Lukey","Lukin","Lula","Lulita","Lull","Lulu","Lumbard","Lumbye","Lumpkin","Luna","Lund","Lundberg","Lundeen","Lundell","Lundgren","Lundin","Lundquist","Lundt","Lune","Lunetta","Lunette","Lunn","Lunna","Lunneta","Lunnete","Lunseth","Lunsford","Lunt","Luo","Lupe","Lupee","Lupien","Lupita","Lura","Lurette","Lurie","Lurleen","Lurlene","Lurline","Lusa","Lussi","Lussier","Lust","Lustick","Lustig","Lusty","Lutero","Luthanen","Luther","Luttrell","Luwana","Lux","Luz","Luzader","Ly","Lyall","Lyckman","Lyda","Lydell","Lydia","Lydie","Lydon","Lyell","Lyford","Lyle","Lyman","Lymann","Lymn","Lyn","Lynch","Lynd","Lynda","Lynde","Lyndel","Lyndell","Lynden","Lyndes","Lyndon","Lyndsay","Lyndsey","Lyndsie","Lyndy","Lynea","Lynelle","Lynett","Lynette","Lynn","Lynna","Lynne","Lynnea","Lynnell","Lynnelle","Lynnet","Lynnett","Lynnette","Lynnworth","Lyns","Lynsey","Lynus","Lyon","Lyons","Lyontine","Lyris","Lysander","Lyssa","Lytle","Lytton","Lyudmila","Ma","Maag","Mab","Mabel","Mabelle","Mable","Mac","MacCarthy","MacDermot","MacDonald","MacDonell","MacDougall","MacEgan","MacFadyn","MacFarlane","MacGregor","MacGuiness","MacIlroy","MacIntosh","MacIntyre","MacKay","MacKenzie","MacLaine","MacLay","MacLean","MacLeod","MacMahon","MacMillan","MacMullin","MacNair","MacNamara","MacPherson","MacRae","MacSwan","Macario","Maccarone","Mace","Macegan","Macey","Machos","Machute","Machutte","Mack","Mackenie","Mackenzie","Mackey","Mackie","Mackintosh","Mackler","Macknair","Mackoff","Macnair","Macomber","Macri","Macur","Macy","Mada","Madai","Madaih","Madalena","Madalyn","Madancy","Madaras","Maddalena","Madden","Maddeu","Maddi","Maddie","Maddis","Maddock","Maddocks","Maddox","Maddy","Madea","Madel","Madelaine","Madeleine","Madelena","Madelene","Madelin","Madelina","Madeline","Madella","Madelle","Madelon","Madelyn","Madge","Madi","Madian","Madid","Madigan","Madison","Madlen","Madlin","Madoc","Madonia","Madonna","Madora","Madox","Madra","Madriene","Madson","Mady","Mae","Maegan","Maeve","Mafala","Mafalda","Maffa","Maffei","Mag","Magan","Magas","Magavern","Magbie","Magda","Magdaia","Magdala","Magdalen","Magdalena","Magdalene","Magdau","Magee","Magel","Magen","Magena","Mages","Maggee","Maggi","Maggie","Maggio","Maggs","Maggy","Maghutte","Magill","Magna","Magner","Magnien","Magnolia","Magnum","Magnus","Magnuson","Magnusson","Magocsi","Magree","Maguire","Magulac","Mahala","Mahalia","Mahan","Mahau","Maher","Mahla","Mahmoud","Mahmud","Mahon","Mahoney","Maia","Maiah","Maibach","Maible","Maice","Maida","Maidel","Maidie","Maidy","Maier","Maiga","Maighdiln","Maighdlin","Mailand","Main","Mainis","Maiocco","Mair","Maire","Maise","Maisel","Maisey","Maisie","Maison","Maite","Maitilde","Maitland","Maitund","Maje","Majka","Major","Mak","Makell","Maker","Mal","Mala","Malachi","Malachy","Malamud","Malamut","Malan","Malanie","Malarkey","Malaspina","Malca","Malcah","Malchus","Malchy","Malcolm","Malcom","Malda","Maleeny","Malek","Maleki","Malena","Malet","Maletta","Mali","Malia","Malik","Malin","Malina","Malinda","Malinde","Malinin","Malinowski","Malissa","Malissia","Malita","Malka","Malkah","Malkin","Mall","Mallen","Maller","Malley","Mallin","Mallina","Mallis","Mallissa","Malloch","Mallon","Mallorie","Mallory","Malloy","Malo","Malone","Maloney","Malonis","Malony","Malorie","Malory","Maloy","Malti","Maltz","Maltzman","Malva","Malvia","Malvie","Malvin","Malvina","Malvino","Malynda","Mame","Mamie","Mamoun","Man","Manaker","Manara","Manard","Manchester","Mancino","Manda","Mandal","Mandel","Mandelbaum","Mandell","Mandeville","Mandi","Mandie","Mandle","Mandler","Mandy","Mandych","Manella","Manfred","Manheim","Mani","Manley","Manlove","Manly","Mann","Mannes","Mannie","Manning","Manno","Mannos","Mannuela","Manny","Mano","Manoff","Manolo","Manon","Manouch","Mansfield","Manson","Mansoor","Mansur","Manthei","Manton","Manuel","Manuela","Manus","Manvel","Manvell","Manvil","Manville","Manwell","Manya","Mapel","Mapes","Maples","Mar","Mara","Marabel","Marabelle","Marala","Marasco","Marashio","Marbut","Marc","Marceau","Marcel","Marcela","Marcelia","Marcell","Marcella","Marcelle","Marcellina","Marcelline","Marcello","Marcellus","Marcelo","March","Marchak","Marchal","Marchall","Marchelle","Marchese","Marci","Marcia","Marciano","Marcie","Marcile","Marcille","Marcin","Marco","Marcos","Marcoux","Marcus","Marcy","Marden","Marder","Marduk","Mareah","Marek","Marela","Mareld","Marelda","Marella","Marelya","Maren","Marena","Marentic","Maressa","Maretz","Marga","Margalit","Margalo","Margaret","Margareta","Margarete","Margaretha","Margarethe","Margaretta","Margarette","Margarida","Margarita","Margaux","Marge","Margeaux","Margery","Marget","Margette","Margetts","Margherita","Margi","Margie","Margit","Margo","Margot","Margret","Margreta","Marguerie","Marguerita","Marguerite","Margy","Mari","Maria","Mariam","Marian","Mariana","Mariand","Mariande","Mariandi","Mariann","Marianna","Marianne","Mariano","Maribel","Maribelle","Maribeth","Marice","Maridel","Marie","Marie-Ann","Marie-Jeanne","Marieann","Mariejeanne","Mariel","Mariele","Marielle","Mariellen","Marienthal","Marietta","Mariette","Marigold","Marigolda","Marigolde","Marijane","Marijn","Marijo","Marika","Mariken","Mariko","Maril","Marilee","Marilin","Marilla","Marillin","Marilou","Marilyn","Marin","Marina","Marinelli","Marinna","Marino","Mario","Marion","Mariquilla","Maris","Marisa","Mariska","Marissa","Marita","Maritsa","Marius","Mariya","Marj","Marja","Marjana","Marje","Marji","Marjie","Marjorie","Marjory","Marjy","Mark","Market","Marketa","Markland","Markman","Marko","Markos","Markowitz","Marks","Markson","Markus","Marl","Marla","Marlane","Marlea","Marleah","Marlee","Marleen","Marlen","Marlena","Marlene","Marler","Marlette","Marley","Marlie","Marlin","Marline","Marlo","Marlon","Marlow","Marlowe","Marlyn","Marmaduke","Marmawke","Marmion","Marna","Marne","Marney","Marni","Marnia","Marnie","Maro","Marola","Marolda","Maroney","Marou","Marozas","Marozik","Marpet","Marquardt","Marquet","Marquez","Marquis","Marquita","Marr","Marra","Marras","Marrilee","Marrin","Marriott","Marris","Marrissa","Marron","Mars","Marsden","Marsh","Marsha","Marshal","Marshall","Marsiella","Marsland","Marston","Mart","Marta","Martainn","Marte","Marteena","Martel","Martell","Martella","Martelle","Martelli","Marten","Martens","Martguerita","Martha","Marthe","Marthena","Marti","Martica","Martie","Martijn","Martin","Martina","Martine","Martineau","Martinelli","Martinez","Martinic","Martino","Martinsen","Martinson","Martita","Martres","Martsen","Marty","Martyn","Martynne","Martz","Marucci","Marutani","Marv","Marva","Marve","Marvel","Marvella","Marven","Marvin","Marwin","Marx","Mary","Marya","Maryann","Maryanna","Maryanne","Marybella","Marybelle","Marybeth","Maryellen","Maryjane","Maryjo","Maryl","Marylee","Marylin","Marylinda","Marylou","Maryly","Marylynne","Maryn","Maryrose","Marys","Marysa","Marzi","Mas","Masao","Mascia","Masera","Masha","Mashe","Mason","Masry","Massarelli","Massey","Massie","Massimiliano","Massimo","Massingill","Masson","Mast","Mastat","Masterson","Mastic","Mastrianni","Mat","Mata","Matazzoni","Matejka","Matelda","Mateo","Materi","Materse","Mateusz","Mateya","Mathe","Matheny","Mather","Matheson","Mathew","Mathews","Mathi","Mathia","Mathian","Mathias","Mathilda","Mathilde","Mathis","Mathre","Mathur","Matias","Matilda","Matilde","Matland","Matless","Matlick","Matrona","Matronna","Matt","Matta","Mattah","Matteo","Matthaeus","Matthaus","Matthei","Mattheus","Matthew","Matthews","Matthia","Matthias","Matthieu","Matthiew","Matthus","Matti","Mattias","Mattie","Mattland","Mattox","Mattson","Matty","Matusow","Mauceri","Mauchi","Maud","Maude","Maudie","Mauer","Mauldon","Maunsell","Maupin","Maura","Mauralia","Maure","Maureen","Maureene","Maurene","Maurer","Mauretta","Maurey","Mauri","Maurice","Mauricio","Maurie","Maurili","Maurilia","Maurilla","Maurine","Maurise","Maurita","Maurits","Maurizia","Maurizio","Mauro","Maurreen","Maury","Mauve","Mavilia","Mavis","Mavra","Max","Maxa","Maxama","Maxantia","Maxentia","Maxey","Maxfield","Maxi","Maxia","Maxie","Maxim","Maxima","Maximilian","Maximilianus","Maximilien","Maximo","Maxine","Maxma","Maxwell","Maxy","May","Maya","Maybelle","Mayberry","Mayce","Mayda","Maye","Mayeda","Mayer","Mayes","Mayfield","Mayhew","Mayman","Maynard","Mayne","Maynord","Mayor","Mays","Mayworm","Maze","Mazel","Maziar","Mazlack","Mazman","Mazonson","Mazur","Mazurek","McAdams","McAfee","McAllister","McArthur","McBride","McCafferty","McCahill","McCall","McCallion","McCallum","McCandless","McCartan","McCarthy","McCarty","McClain","McClary","McClees","McClelland","McClenaghan","McClenon","McClimans","McClish","McClure","McCollum","McComb","McConaghy","McConnell","McCord","McCormac","McCormick","McCourt","McCowyn","McCoy","McCready","McCreary","McCreery","McCulloch","McCullough","McCully","McCurdy","McCutcheon","McDade","McDermott","McDonald","McDougall","McDowell","McEvoy","McFadden","McFarland","McFerren","McGannon","McGaw","McGean","McGee","McGill","McGinnis","McGrath","McGraw","McGray","McGregor","McGrody","McGruter","McGuire","McGurn","McHail","McHale","McHenry","McHugh","McIlroy","McIntosh","McIntyre","McKale","McKay","McKee","McKenna","McKenzie","McKeon","McKinney","McKnight","McLain","McLaughlin","McLaurin","McLeod","McLeroy","McLoughlin","McLyman","McMahon","McMaster","McMath","McMillan","McMullan","McMurry","McNair","McNalley","McNally","McNamara","McNamee","McNeely","McNeil","McNelly","McNully","McNutt","McQuade","McQuillin","McQuoid","McRipley","McRoberts","McSpadden","McTyre","McWherter","McWilliams","Mead","Meade","Meador","Meadow","Meadows","Meagan","Meaghan","Meagher","Meakem","Means","Meara","Meares","Mears","Meave","Mechelle","Mechling","Mecke","Meda","Medarda","Medardas","Medea","Medeah","Medin","Medina","Medlin","Medor","Medora","Medorra","Medovich","Medrek","Medwin","Meece","Meehan","Meek","Meeker","Meeks","Meenen","Meg","Megan","Megargee","Megdal","Megen","Meggi","Meggie","Meggs","Meggy","Meghan","Meghann","Mehala","Mehalek","Mehalick","Mehetabel","Mehitable","Mehta","Mei","Meibers","Meier","Meijer","Meilen","Meill","Meingolda","Meingoldas","Meir","Meisel","Meit","Mel","Mela","Melamed","Melamie","Melan","Melania","Melanie","Melantha","Melany","Melar","Melba","Melborn","Melbourne","Melburn","Melcher","Melda","Meldoh","Meldon","Melena","Melentha","Melesa","Melessa","Meletius","Melgar","Meli","Melia","Melicent","Melina","Melinda","Melinde","Melisa","Melisande","Melisandra","Melise","Melisenda","Melisent","Melissa","Melisse","Melita","Melitta","Mell","Mella","Mellar","Mellen","Melleta","Mellette","Melli","Mellicent","Mellie","Mellins","Mellisa","Mellisent","Mellitz","Mellman","Mello","Melloney","Melly","Melmon","Melnick","Melodee","Melodie","Melody","Melone","Melonie","Melony","Melosa","Melquist","Melton","Melva","Melvena","Melville","Melvin","Melvina","Melvyn","Memberg","Memory","Mena","Menard","Menashem","Mencher","Mendel","Mendelsohn","Mendelson","Mendes","Mendez","Mendie","Mendive","Mendoza","Mendy","Meneau","Menedez","Menell","Menendez","Meng","Menides","Menis","Menken","Menon","Mensch","Menzies","Mera","Meraree","Merari","Meras","Merat","Merc","Mercado","Merce","Mercedes","Merceer","Mercer","Merchant","Merci","Mercie","Mercier","Mercola","Mercorr","Mercuri","Mercy","Merdith","Meredeth","Meredi","Meredith","Meredithe","Merell","Merete","Meri","Meridel","Merideth","Meridith","Meriel","Merilee","Merill","Merilyn","Meris","Merissa","Merkle","Merkley","Merl","Merla","Merle","Merlin","Merlina","Merline","Merna","Merola","Merow","Merralee","Merras","Merrel","Merrell","Merri","Merriam","Merrick","Merridie","Merrie","Merrielle","Merril","Merrile","Merrilee","Merrili","Merrill","Merrily","Merriman","Merriott","Merritt","Merrow","Merry","Mersey","Mert","Merta","Merth","Merton","Merv","Mervin","Merwin","Merwyn","Meryl","Mesics","Messere","Messing","Meta","Metabel","Metcalf","Meter","Methuselah","Metsky","Mettah","Metts","Metzgar","Metzger","Meunier","Meurer","Meuse","Meuser","Meyer","Meyeroff","Meyers","Mezoff","Mia","Mic","Micaela","Micah","Micco","Mich","Michael","Michaela","Michaele","Michaelina","Michaeline","Michaella","Michaeu","Michail","Michal","Michale","Michaud","Miche","Micheal","Micheil","Michel","Michele","Michelina","Micheline","Michell","Michella","Michelle","Michelsen","Michey","Michi","Michigan","Michiko","Michon","Mick","Mickelson","Mickey","Micki","Mickie","Micky","Micro","Miculek","Midas","Middendorf","Middle","Middlesworth","Middleton","Mide","Midge","Midian","Midis","Mientao","Miett","Migeon","Mighell","Mignon","Mignonne","Miguel","Miguela","Miguelita","Mihalco","Mihe","Mika","Mikael","Mikaela","Mikal","Mike","Mikel","Mikes","Mikey","Miki","Mikihisa","Mikiso","Mikkanen","Mikkel","Miko","Mikol","Miksen","Mil","Mila","Milan","Milano","Milburn","Milburr","Milburt","Milda","Milde","Mildred","Mildrid","Mile","Milena","Miles","Milewski","Milford","Milicent","Milinda","Milissa","Milissent","Milka","Milks","Mill","Milla","Millan","Millar","Millard","Millburn","Millda","Miller","Millford","Millham","Millhon","Milli","Millian","Millicent","Millie","Millisent","Millman","Mills","Millur","Millwater","Milly","Milman","Milo","Milon","Milone","Milore","Milson","Milstone","Milt","Miltie","Milton","Milty","Milurd","Milzie","Mima","Mimi","Min","Mina","Minabe","Minardi","Minda","Mindi","Mindy","Miner","Minerva","Mines","Minetta","Minette","Ming","Mingche","Mini","Minica","Minier","Minna","Minnaminnie","Minne","Minni","Minnie","Minnnie","Minny","Minor","Minoru","Minsk","Minta","Minton","Mintun","Mintz","Miof Mela","Miquela","Mir","Mira","Mirabel","Mirabella","Mirabelle","Miran","Miranda","Mireielle","Mireille","Mirella","Mirelle","Miriam","Mirielle","Mirilla","Mirisola","Mirna","Mirth","Miru","Mischa","Misha","Mishaan","Missi","Missie","Missy","Misti","Mistrot","Misty","Mita","Mitch","Mitchael","Mitchel","Mitchell","Mitchiner","Mitinger","Mitman","Mitran","Mittel","Mitzi","Mitzie","Mitzl","Miun","Mixie","Miyasawa","Mizuki","Mlawsky","Mllly","Moazami","Moberg","Mobley","Mochun","Mode","Modern","Modesta","Modeste","Modestia","Modestine","Modesty","Modie","Modla","Moe","Moersch","Moffat","Moffit","Moffitt","Mogerly","Moguel","Mohamed","Mohammad","Mohammed","Mohandas","Mohandis","Mohl","Mohn","Mohr","Mohsen","Mohun","Moia","Moina","Moir","Moira","Moise","Moises","Moishe","Moitoso","Mojgan","Mok","Mokas","Molini","Moll","Mollee","Molli","Mollie","Molloy","Molly","Molton","Mommy","Mona","Monaco","Monafo","Monagan","Monah","Monahan","Monahon","Monarski","Moncear","Mond","Monda","Moneta","Monetta","Mongeau","Monia","Monica","Monie","Monika","Monique","Monjan","Monjo","Monk","Monney","Monreal","Monro","Monroe","Monroy","Monson","Monsour","Mont","Montagna","Montagu","Montague","Montana","Montanez","Montano","Monte","Monteith","Monteria","Montford","Montfort","Montgomery","Monti","Monto","Monty","Moody","Mook","Moon","Mooney","Moonier","Moor","Moore","Moorefield","Moorish","Mor","Mora","Moran","Mord","Mordecai","Mordy","Moreen","Morehouse","Morel","Moreland","Morell","Morena","Moreno","Morentz","Moreta","Moretta","Morette","Moreville","Morey","Morez","Morgan","Morgana","Morganica","Morganne","Morganstein","Morgen","Morgenthaler","Morgun","Mori","Moria","Moriah","Moriarty","Morice","Morie","Morissa","Morita","Moritz","Moriyama","Morlee","Morley","Morly","Morna","Morocco","Morra","Morrell","Morrie","Morril","Morrill","Morris","Morrison","Morrissey","Morry","Morse","Mort","Morten","Mortensen","Mortie","Mortimer","Morton","Morty","Morven","Morville","Morvin","Mosa","Mosby","Moscow","Mose","Moseley","Moselle","Mosenthal","Moser","Mosera","Moses","Moshe","Moshell","Mosier","Mosira","Moskow","Mosley","Mosora","Mosra","Moss","Mossberg","Mossman","Most","Motch","Moth","Mott","Motteo","Mou","Moulden","Mouldon","Moule","Moulton","Mount","Mountford","Mountfort","Mourant","Moureaux","Mowbray","Moya","Moyer","Moyers","Moyna","Moynahan","Moyra","Mozart","Mozelle","Mozes","Mozza","Mraz","Mroz","Mueller","Muffin","Mufi","Mufinella","Muhammad","Muir","Muire","Muirhead","Mukerji","Mukul","Mukund","Mulcahy","Mulderig","Muldon","Mulford","Mullane","Mullen","Muller","Mulligan","Mullins","Mulloy","Mulry","Mulvihill","Mumford","Mun","Muna","Munafo","Muncey","Mundford","Mundt","Mundy","Munford","Mungo","Mungovan","Munmro","Munn","Munniks","Munro","Munroe","Muns","Munsey","Munshi","Munson","Munster","Munt","Mur","Murage","Muraida","Murat","Murdocca","Murdoch","Murdock","Mureil","Muriah","Murial","Muriel","Murielle","Murphy","Murrah","Murray","Murrell","Murry","Murtagh","Murtha","Murton","Murvyn","Musa","Muscolo","Musetta","Musette","Mushro","Muslim","Musser","Mussman","Mutz","My","Mya","Myca","Mycah","Mychael","Mychal","Myer","Myers","Myke","Mylan","Mylander","Myles","Mylo","Mylor","Myna","Myo","Myra","Myrah","Myranda","Myriam","Myrilla","Myrle","Myrlene","Myrna","Myron","Myrt","Myrta","Myrtia","Myrtice","Myrtie","Myrtle","Myrvyn","Myrwyn","Na","Naam","Naaman","Naamana","Naamann","Naara","Naarah","Naashom","Nabal","Nabala","Nabalas","Nabila","Nace","Nachison","Nada","Nadab","Nadaba","Nadabas","Nadabb","Nadabus","Nadaha","Nadbus","Nadda","Nadean","Nadeau","Nadeen","Nader","Nadia","Nadine","Nadiya","Nadler","Nador","Nady","Nadya","Nafis","Naga","Nagel","Nagey","Nagle","Nagy","Nahama","Nahamas","Nahshon","Nahshu","Nahshun","Nahshunn","Nahtanha","Nahum","Naiditch","Naima","Naji","Nakada","Nakashima","Nakasuji","Nalani","Nalda","Naldo","Nalepka","Nally","Nalor","Nam","Naman","Namara","Names","Nan","Nana","Nananne","Nance","Nancee","Nancey","Nanci","Nancie","Nancy","Nandor","Nanete","Nanette","Nani","Nanice","Nanine","Nanji","Nannette","Nanni","Nannie","Nanny","Nanon","Naoma","Naomi","Naor","Nap","Napier","Naples","Napoleon","Nappie","Nappy","Naquin","Nara","Narah","Narayan","Narcho","Narcis","Narcissus","Narda","Naresh","Nari","Nariko","Narine","Narra","Narton","Nary","Nash","Nashbar","Nashner","Nasho","Nashom","Nashoma","Nasia","Nason","Nassi","Nassir","Nastassia","Nasya","Nat","Nata","Natal","Natala","Natale","Natalee","Natalia","Natalie","Natalina","Nataline","Natalya","Nataniel","Natascha","Natasha","Natassia","Nate","Natelson","Nath","Nathalia","Nathalie","Nathan","Nathanael","Nathanial","Nathaniel","Nathanil","Nathanson","Natica","Natie","Natiha","Natika","Nations","Natividad","Natka","Nattie","Natty","Nava","Navada","Naval","Navarro","Nawrocki","Nay","Naylor","Nazar","Nazario","Nazarius","Nazler","Nea","Neal","Neala","Nealah","Neale","Nealey","Neall","Nealon","Nealson","Nealy","Neau","Ned","Neda","Nedda","Neddie","Neddra","Neddy","Nedi","Nedra","Nedrah","Nedrud","Nedry","Nee","Neel","Neela","Neelon","Neely","Neeoma","Nefen","Neff","Negris","Nehemiah","Neibart","Neidhardt","Neil","Neila","Neile","Neill","Neilla","Neille","Neils","Neilson","Neiman","Neisa","Nel","Nela","Nelan","Nelda","Nelia","Nelie","Nell","Nella","Nellda","Nelle","Nelli","Nellie","Nellir","Nelly","Nelrsa","Nels","Nelsen","Nelson","Nema","Nemhauser","Nena","Nenney","Neo","Neom","Neoma","Neomah","Neona","Nepean","Nepil","Nereen","Nereids","Nereus","Neri","Nerin","Nerine","Nerissa","Nerita","Nerland","Nero","Neron","Nert","Nerta","Nerte","Nerti","Nertie","Nerty","Nesbitt","Nesline","Neslund","Ness","Nessa","Nessi","Nessie","Nessim","Nessy","Nesta","Nester","Nesto","Nestor","Nett","Netta","Nette","Netti","Nettie","Nettle","Netty","Neu","Neuberger","Neuburger","Neufer","Neukam","Neumann","Neumark","Neumeyer","Neurath","Nev","Neva","Nevada","Nevai","Neve","Neveda","Nevil","Nevile","Neville","Nevin","Nevins","Nevlin","Nevsa","New","Newberry","Newbill","Newbold","Newby","Newcomb","Newcomer","Newel","Newell","Newfeld","Newhall","Newkirk","Newlin","Newman","Newmann","Newmark","Newsom","Newton","Neysa","Ng","Ngo","Nguyen","Niabi","Nial","Niall","Nibbs","Nic","Nica","Niccolo","Nich","Nichani","Nichol","Nichola","Nicholas","Nichole","Nicholl","Nicholle","Nichols","Nicholson","Nichy","Nick","Nickelsen","Nickerson","Nickey","Nicki","Nickie","Nickles","Nicko","Nickola","Nickolai","Nickolas","Nickolaus","Nicks","Nicky","Nico","Nicodemus","Nicol","Nicola","Nicolai","Nicolais","Nicolas","Nicolau","Nicole","Nicolea","Nicolella","Nicolette","Nicoli","Nicolina","Nicoline","Nicolis","Nicolle","Nidia","Nidorf","Nieberg","Niehaus","Niel","Niela","Niels","Nielsen","Nielson","Nierman","Nies","Nievelt","Nigel","Nightingale","Nihhi","Nihi","Nika","Nikaniki","Nike","Niki","Nikita","Nikki","Nikkie","Niklaus","Niko","Nikola","Nikolai","Nikolaos","Nikolas","Nikolaus","Nikoletta","Nikolia","Nikolos","Nikos","Nil","Nila","Nile","Niles","Nilla","Nils","Nilson","Nimesh","Nimocks","Nims","Nina","Nine","Ninetta","Ninette","Ninnetta","Ninnette","Nino","Ninon","Ninos","Niobe","Nipha","Niple","Nisa","Nisbet","Nisen","Nishi","Nissa","Nisse","Nissensohn","Nissie","Nissy","Nita","Nitin","Nitz","Nitza","Niu","Niven","Nixie","Nixon","Noach","Noah","Noak","Noakes","Noam","Noami","Nobe","Nobel","Nobell","Nobie","Nobile","Noble","Noby","Nochur","Nodab","Nodababus","Nodarse","Noe","Noel","Noelani","Noell","Noella","Noelle","Noellyn","Noelyn","Noemi","Nogas","Noguchi","Nola","Nolan","Nolana","Noland","Nole","Noleta","Noletta","Noli","Nolie","Nolita","Nolitta","Noll","Nollie","Nolly","Nolte","Noma","Noman","Nomi","Nona","Nonah","Noni","Nonie","Nonna","Nonnah","Noonan","Noonberg","Nor","Nora","Norah","Norbert","Norbie","Norby","Nord","Nordgren","Nordin","Nordine","Nore","Norean","Noreen","Norene","Norford","Norina","Norine","Norita","Nork","Norling","Norm","Norma","Normalie","Norman","Normand","Normandy","Normi","Normie","Normy","Norri","Norrie","Norris","Norrv","Norry","Norse","North","Northey","Northington","Northrop","Northrup","Northway","Norton","Norty","Norval","Norvall","Norvan","Norvell","Norven","Norvil","Norvin","Norvol","Norvun","Norward","Norwood","Norword","Nottage","Nova","Novah","Novak","Novelia","Novello","Novia","Novick","Novikoff","Nowell","Noyes","Nozicka","Nudd","Nugent","Nuli","Nunci","Nuncia","Nunciata","Nunes","Nunnery","Nur","Nuri","Nuriel","Nuris","Nurse","Nussbaum","Nutter","Nuzzi","Nyberg","Nydia","Nye","Nyhagen","Nysa","Nyssa","O'Hara","O'Neill","Oak","Oakes","Oakie","Oakleil","Oakley","Oakman","Oaks","Oates","Oatis","Oba","Obadiah","Obadias","Obala","Oballa","Obara","Obau","Obaza","Obbard","Obe","Obed","Obeded","Obediah","Obel","Obelia","Obellia","Obeng","Ober","Oberg","Oberheim","Oberon","Oberstone","Obidiah","Obie","Obla","Obola","Obrien","Oby","Oca","Ocana","Ochs","Ocker","Ocko","Oconnor","Octave","Octavia","Octavian","Octavie","Octavius","Octavla","Octavus","Odab","Odawa","Ode","Odeen","Odel","Odele","Odelet","Odelia","Odelinda","Odell","Odella","Odelle","Odericus","Odessa","Odetta","Odette","Odey","Odie","Odilia","Odille","Odilo","Odin","Odine","Odlo","Odo","Odom","Odoric","Odrick","Ody","Odysseus","Odyssey","Oech","Oeflein","Oehsen","Ofelia","Ofella","Offen","Ofilia","Ofori","Og","Ogata","Ogawa","Ogdan","Ogden","Ogdon","Ogg","Ogilvie","Ogilvy","Oglesby","Ogren","Ohara","Ohare","Ohaus","Ohl","Oilla","Oina","Oira","Okajima","Okechuku","Okubo","Okun","Okwu","Ola","Olaf","Olag","Olatha","Olathe","Olav","Olcott","Old","Older","Olds","Ole","Oleg","Olen","Olenka","Olenolin","Olenta","Oler","Oleta","Oletha","Olethea","Oletta","Olette","Olfe","Olga","Olia","Oliana","Olimpia","Olin","Olinde","Oliva","Olivann","Olive","Oliver","Olivero","Olivette","Olivia","Olivie","Olivier","Oliviero","Oliy","Ollayos","Olli","Ollie","Olly","Olmstead","Olmsted","Olnay","Olnee","Olnek","Olney","Olnton","Olodort","Olpe","Olsen","Olsewski","Olshausen","Olson","Olsson","Olva","Olvan","Olwen","Olwena","Oly","Olympe","Olympia","Olympias","Olympie","Olympium","Om","Oman","Omar","Omari","Omarr","Omer","Omero","Omidyar","Omland","Omor","Omora","Omura","On","Ona","Onder","Ondine","Ondrea","Ondrej","Oneal","Oneida","Oneil","Oneill","Onfre","Onfroi","Ong","Ongun","Oni","Onia","Onida","Oniskey","Onofredo","Onstad","Ontina","Ontine","Onyx","Oona","Opal","Opalina","Opaline","Ophelia","Ophelie","Oppen","Opportina","Opportuna","Ora","Orabel","Orabelle","Oralee","Oralia","Oralie","Oralla","Oralle","Oram","Oran","Orazio","Orbadiah","Orban","Ordway","Orel","Orelee","Orelia","Orelie","Orella","Orelle","Orelu","Oren","Orest","Oreste","Orestes","Orferd","Orfield","Orfinger","Orford","Orfurd","Orgel","Orgell","Ori","Oria","Orian","Oriana","Oriane","Orianna","Oribel","Oribella","Oribelle","Oriel","Orin","Oringa","Oringas","Oriole","Orion","Orit","Orji","Orlan","Orland","Orlando","Orlanta","Orlantha","Orlena","Orlene","Orlina","Orling","Orlosky","Orlov","Orly","Orman","Ormand","Orme","Ormiston","Ormond","Orms","Ormsby","Orna","Ornas","Ornie","Ornstead","Orola","Orose","Orozco","Orpah","Orpha","Orpheus","Orr","Orran","Orren","Orrin","Orsa","Orsay","Orsini","Orsino","Orsola","Orson","Orten","Ortensia","Orth","Orthman","Ortiz","Orton","Ortrud","Ortrude","Oruntha","Orv","Orva","Orvah","Orvan","Orvas","Orvie","Orvil","Orville","Orwin","Os","Osana","Osanna","Osber","Osbert","Osborn","Osborne","Osbourn","Osbourne","Oscar","Osei","Osgood","Osher","Oshinski","Osi","Osithe","Oskar","Osman","Osmen","Osmo","Osmond","Osmund","Osric","Osrick","Osrock","Ossie","Osswald","Ossy","Ostap","Oster","Osterhus","Ostler","Ostraw","Osugi","Oswal","Oswald","Oswell","Oswin","Osy","Osyth","Ot","Otero","Otes","Otha","Othe","Othelia","Othella","Othello","Other","Othilia","Othilie","Otho","Otila","Otilia","Otina","Otis","Ott","Ottavia","Otte","Otter","Otti","Ottie","Ottilie","Ottillia","Ottinger","Otto","Oulman","Outhe","Outlaw","Ovid","Ovida","Owades","Owain","Owen","Owena","Owens","Oxford","Oxley","Oys","Oz","Oza","Ozan","Ozen","Ozkum","Ozmo","Ozzie","Ozzy","O'Brien","O'Callaghan","O'Carroll","O'Connell","O'Conner","O'Connor","O'Dell","O'Doneven","O'Donnell","O'Donoghue","O'Donovan","O'Driscoll","O'Gowan","O'Grady","O'Hara","O'Kelly","O'Mahony","O'Malley","O'Meara","O'Neil","O'Neill","O'Reilly","O'Rourke","O'Shee","O'Toole","Paapanen","Pablo","Pace","Pacheco","Pachston","Pachton","Pacian","Pacien","Pacifa","Pacifica","Pacificas","Pacificia","Pack","Packer","Packston","Packton","Paco","Pacorro","Paddie","Paddy","Padegs","Paderna","Padget","Padgett","Padraic","Padraig","Padriac","Paff","Pagas","Page","Pages","Paget","Pahl","Paige","Paik","Pail","Pain","Paine","Painter","Palecek","Palermo","Palestine","Paley","Palgrave","Palila","Pall","Palla","Palladin","Pallas","Pallaten","Pallaton","Pallua","Palm","Palma","Palmer","Palmira","Palmore","Palocz","Paloma","Pals","Palua","Paluas","Palumbo","Pam","Pamela","Pamelina","Pamella","Pammi","Pammie","Pammy","Pampuch","Pan","Panaggio","Panayiotis","Panchito","Pancho","Pandich","Pandolfi","Pandora","Pang","Pangaro","Pani","Pansie","Pansir","Pansy","Panta","Panter","Panthea","Pantheas","Panther","Panthia","Pantia","Pantin","Paola","Paolina","Paolo","Papagena","Papageno","Pape","Papert","Papke","Papotto","Papp","Pappano","Pappas","Papst","Paquito","Par","Paradies","Parcel","Pardew","Pardner","Pardo","Pardoes","Pare","Parent","Paresh","Parette","Parfitt","Parhe","Parik","Paris","Parish","Park","Parke","Parker","Parks","Parlin","Parnas","Parnell","Parrie","Parris","Parrisch","Parrish","Parrnell","Parrott","Parry","Parsaye","Parshall","Parsifal","Parsons","Partan","Parthen","Parthena","Parthenia","Parthinia","Particia","Partridge","Paryavi","Pas","Pasadis","Pasahow","Pascal","Pascale","Pascasia","Pascha","Paschasia","Pascia","Pasco","Pascoe","Pasho","Pasia","Paske","Pasol","Pasquale","Pass","Past","Pastelki","Pat","Pate","Paten","Paterson","Pathe","Patience","Patin","Patman","Patnode","Paton","Patric","Patrica","Patrice","Patrich","Patricia","Patricio","Patrick","Patrizia","Patrizio","Patrizius","Patsis","Patsy","Patt","Pattani","Patten","Patterman","Patterson","Patti","Pattie","Pattin","Pattison","Patton","Patty","Paucker","Paugh","Pauiie","Paul","Paula","Paule","Pauletta","Paulette","Pauli","Paulie","Paulina","Pauline","Paulita","Paulo","Paulsen","Paulson","Pauly","Pauwles","Pavel","Paver","Pavia","Pavier","Pavior","Paviour","Pavkovic","Pavla","Pavlish","Pavlov","Pavyer","Pawsner","Pax","Paxon","Paxton","Paymar","Payne","Paynter","Payson","Payton","Paz","Paza","Pazia","Pazice","Pazit","Peace","Peacock","Peadar","Peale","Pearce","Pearl","Pearla","Pearle","Pearline","Pearlman","Pearlstein","Pearman","Pears","Pearse","Pearson","Pease","Peatroy","Pebrook","Peck","Peckham","Pedaiah","Pedaias","Peddada","Peder","Pedersen","Pederson","Pedrick","Pedro","Pedrotti","Pedroza","Peer","Peers","Peery","Peg","Pega","Pegasus","Pegeen","Pegg","Peggi","Peggie","Peggir","Peggy","Pegma","Peh","Peirce","Peirsen","Peisch","Pejsach","Pelag","Pelaga","Pelage","Pelagi","Pelagia","Pelagias","Pell","Pellegrini","Pellet","Pelletier","Pelligrini","Pellikka","Pelmas","Pelpel","Pelson","Peltier","Peltz","Pember","Pembroke","Pembrook","Pen","Pena","Pence","Pendergast","Pendleton","Penelopa","Penelope","Pengelly","Penhall","Penland","Penman","Penn","Pennebaker","Penney","Penni","Pennie","Pennington","Penny","Penoyer","Penrod","Penrose","Pentha","Penthea","Pentheam","Pentheas","Peonir","Peony","Peoples","Pepe","Peper","Pepi","Pepillo","Pepin","Pepita","Pepito","Peppard","Peppel","Pepper","Peppi","Peppie","Peppy","Per","Perce","Perceval","Percival","Percy","Perdita","Peregrine","Pergrim","Peri","Peria","Perice","Perkin","Perkins","Perkoff","Perl","Perla","Perle","Perlie","Perlis","Perlman","Perloff","Pernas","Pernell","Perni","Pernick","Pero","Perot","Perpetua","Perr","Perreault","Perren","Perretta","Perri","Perrie","Perrin","Perrine","Perrins","Perron","Perry","Persas","Perseus","Persian","Persis","Persons","Persse","Persson","Perusse","Perzan","Pesek","Peskoff","Pessa","Pestana","Pet","Peta","Pete","Peter","Peterec","Peterman","Peters","Petersen","Peterson","Peterus","Petes","Petey","Peti","Petie","Petigny","Petit","Petite","Petr","Petra","Petracca","Petras","Petrick","Petrie","Petrina","Petrine","Petromilli","Petronella","Petronia","Petronilla","Petronille","Petta","Pettifer","Pettiford","Pettit","Petty","Petua","Petula","Petulah","Petulia","Petunia","Petuu","Peugia","Peursem","Pevzner","Peyter","Peyton","Pfaff","Pfeffer","Pfeifer","Pfister","Pfosi","Phaedra","Phaidra","Phaih","Phail","Phalan","Pharaoh","Phare","Phares","Phebe","Phedra","Phelan","Phelgen","Phelgon","Phelia","Phelips","Phelps","Phemia","Phene","Pheni","Phenica","Phenice","Phi","Phia","Phil","Phila","Philan","Philana","Philander","Philbert","Philbin","Philbo","Philbrook","Philcox","Philemol","Philemon","Philender","Philina","Philine","Philip","Philipa","Philipines","Philipp","Philippa","Philippe","Philippine","Philipps","Philips","Philipson","Philis","Phillada","Phillane","Phillida","Phillie","Phillip","Phillipe","Phillipp","Phillips","Phillis","Philly","Philo","Philomena","Philoo","Philpot","Philps","Phina","Phineas","Phio","Phiona","Phionna","Phip","Phippen","Phipps","Phira","Phoebe","Phonsa","Photima","Photina","Phox","Phyl","Phylis","Phyllida","Phyllis","Phyllys","Phylys","Pia","Piane","Picardi","Picco","Pich","Pickar","Pickard","Pickens","Picker","Pickering","Pickett","Pickford","Piderit","Piefer","Piegari","Pier","Pierce","Pierette","Piero","Pierpont","Pierre","Pierrepont","Pierrette","Pierro","Piers","Pierson","Pieter","Pietje","Pietra","Pietrek","Pietro","Pigeon","Piggy","Pike","Pilar","Pilloff","Pillow","Pillsbury","Pimbley","Pincas","Pinchas","Pincince","Pinckney","Pincus","Pine","Pinebrook","Pineda","Pinelli","Pinette","Ping","Pinkerton","Pinkham","Pinsky","Pinter","Pinto","Pinzler","Piotr","Pip","Piper","Pippa","Pippas","Pippo","Pippy","Pirali","Pirbhai","Pirnot","Pironi","Pirozzo","Pirri","Pirzada","Pisano","Pisarik","Piscatelli","Piselli","Pish","Pitarys","Pitchford","Pitt","Pittel","Pittman","Pitts","Pitzer","Pius","Piwowar","Pizor","Placeeda","Placia","Placida","Placidia","Placido","Plafker","Plank","Plantagenet","Plante","Platas","Plate","Plath","Plato","Platon","Platt","Platto","Platus","Player","Pleasant","Pleione","Plerre","Pliam","Pliner","Pliske","Ploch","Ploss","Plossl","Plotkin","Plumbo","Plume","Plunkett","Plusch","Podvin","Pogue","Poirer","Pokorny","Pol","Polad","Polak","Poland","Polard","Polash","Poler","Poliard","Polik","Polinski","Polish","Politi","Polito","Polivy","Polk","Polky","Poll","Pollack","Pollak","Pollard","Pollerd","Pollie","Pollitt","Polloch","Pollock","Pollux","Polly","Pollyanna","Pomcroy","Pomeroy","Pomfret","Pomfrey","Pomona","Pompea","Pompei","Ponce","Pond","Pontias","Pontius","Ponton","Pontone","Pontus","Ponzo","Poock","Pooh","Pooi","Pool","Poole","Pooley","Poore","Pope","Popele","Popelka","Poppas","Popper","Poppo","Poppy","Porche","Porcia","Poree","Porett","Port","Porta","Porte","Porter","Portia","Portie","Portingale","Portland","Portugal","Portuna","Portwin","Portwine","Porty","Porush","Posehn","Posner","Possing","Post","Postman","Potash","Potter","Potts","Poucher","Poul","Poulter","Pouncey","Pournaras","Powder","Powe","Powel","Powell","Power","Powers","Pownall","Poyssick","Pozzy","Pradeep","Prader","Prady","Prager","Prakash","Prasad","Pratt","Pratte","Pravit","Prebo","Preciosa","Preiser","Prem","Premer","Pren","Prendergast","Prent","Prentice","Prentiss","Presber","Prescott","Presley","Press","Pressey","Pressman","Prestige","Preston","Pretrice","Preuss","Previdi","Prevot","Price","Prichard","Pricilla","Pride","Priebe","Priest","Priestley","Prima","Primalia","Primavera","Primaveras","Primaveria","Primo","Primrosa","Primrose","Prince","Princess","Prinz","Prior","Pris","Prisca","Priscella","Priscilla","Prisilla","Prissie","Prissy","Pritchard","Pritchett","Prober","Prochora","Prochoras","Procora","Procter","Procto","Proctor","Profant","Proffitt","Pronty","Pros","Prosper","Prospero","Prosperus","Prosser","Proud","Proudfoot","Proudlove","Proudman","Proulx","Prouty","Prowel","Pru","Pruchno","Prud","Prudence","Prudhoe","Prudi","Prudie","Prudy","Prue","Prunella","Prussian","Pruter","Pry","Pryce","Pryor","Psyche","Pubilis","Publea","Publia","Publias","Publius","Publus","Pucida","Pudendas","Pudens","Puduns","Puett","Pufahl","Puff","Pugh","Puglia","Puiia","Puklich","Pul","Pulcheria","Pulchi","Pulchia","Pulling","Pulsifer","Pump","Punak","Punke","Purcell","Purdum","Purdy","Puri","Purington","Puritan","Purity","Purpura","Purse","Purvis","Putnam","Putnem","Puto","Putscher","Puttergill","Py","Pyle","Pylle","Pyne","Pyotr","Pyszka","Pytlik","Quackenbush","Quar","Quarta","Quartana","Quartas","Quartet","Quartis","Quartus","Queen","Queena","Queenie","Quenby","Quenna","Quennie","Quent","Quentin","Queri","Querida","Queridas","Questa","Queston","Quick","Quickel","Quickman","Quigley","Quill","Quillan","Quillon","Quin","Quinby","Quince","Quincey","Quincy","Quinlan","Quinn","Quint","Quinta","Quintana","Quintessa","Quintie","Quintilla","Quintin","Quintina","Quinton","Quintus","Quirita","Quirk","Quita","Quiteri","Quiteria","Quiteris","Quitt","Qulllon","Raab","Raama","Raasch","Rab","Rabah","Rabassa","Rabbi","Rabelais","Rabi","Rabiah","Rabin","Rabjohn","Rabkin","Rabush","Race","Rachaba","Rachael","Rachel","Rachele","Rachelle","Racklin","Rad","Radack","Radborne","Radbourne","Radbun","Radburn","Radcliffe","Raddatz","Raddi","Raddie","Raddy","Radferd","Radford","Radie","Radke","Radley","Radloff","Radman","Radmen","Radmilla","Radu","Rae","Raeann","Raf","Rafa","Rafael","Rafaela","Rafaelia","Rafaelita","Rafaelle","Rafaellle","Rafaello","Rafaelof","Rafat","Rafe","Raff","Raffaello","Raffarty","Rafferty","Raffin","Raffo","Rafi","Rafiq","Rafter","Ragan","Ragen","Ragg","Ragland","Ragnar","Ragouzis","Ragucci","Rahal","Rahel","Rahm","Rahman","Rahmann","Rahr","Rai","Raila","Raimes","Raimondo","Raimund","Raimundo","Raina","Rainah","Raine","Rainer","Raines","Rainger","Rainie","Rains","Rainwater","Rajewski","Raji","Rajiv","Rakel","Rakia","Ralaigh","Raleigh","Ralf","Ralfston","Ralina","Ralleigh","Ralli","Ralph","Ralston","Ram","Rama","Ramah","Raman","Ramberg","Rambert","Rambort","Rambow","Ramburt","Rame","Ramey","Ramiah","Ramin","Ramon","Ramona","Ramonda","Ramos","Ramsay","Ramsdell","Ramsden","Ramses","Ramsey","Ramunni","Ran","Rana","Rance","Rancell","Ranchod","Rand","Randa","Randal","Randall","Randee","Randell","Randene","Randi","Randie","Randolf","Randolph","Randy","Ranee","Raney","Range","Rangel","Ranger","Rani","Rania","Ranice","Ranie","Ranique","Ranit","Ranita","Ranite","Ranitta","Ranjiv","Rankin","Rann","Ranna","Ransell","Ransom","Ransome","Ranson","Ranzini","Rao","Raouf","Raoul","Rap","Rape","Raphael","Raphaela","Rapp","Raquel","Raquela","Ras","Raseda","Raseta","Rashida","Rashidi","Rasia","Rask","Raskin","Raskind","Rasla","Rasmussen","Rastus","Rasure","Ratcliff","Ratcliffe","Ratha","Rather","Ratib","Rattan","Rattray","Rauch","Raul","Rausch","Rauscher","Raveaux","Raven","Ravens","Ravi","Ravid","Raviv","Ravo","Rawdan","Rawden","Rawdin","Rawdon","Rawley","Rawlinson","Ray","Raybin","Raybourne","Rayburn","Raychel","Raycher","Raye","Rayford","Rayle","Raymond","Raymonds","Raymund","Rayna","Raynah","Raynard","Raynata","Raynell","Rayner","Raynold","Raynor","Rayshell","Razid","Rea","Reace","Read","Reade","Readus","Ready","Reagan","Reagen","Reahard","Reames","Reamonn","Reamy","Reave","Reba","Rebah","Rebak","Rebane","Rebba","Rebbecca","Rebe","Rebeca","Rebecca","Rebecka","Rebeka","Rebekah","Rebekkah","Rebel","Rebhun","Rech","Recha","Rechaba","Reckford","Recor","Rector","Red","Redd","Reddin","Reddy","Redfield","Redford","Redman","Redmer","Redmond","Redmund","Redvers","Redwine","Ree","Reeba","Reece","Reed","Reede","Reedy","Reeher","Reel","Reena","Rees","Reese","Reeta","Reeva","Reeve","Reeves","Reg","Regan","Regazzi","Regen","Reger","Reggi","Reggie","Reggis","Reggy","Regina","Reginald","Reginauld","Regine","Rego","Rehm","Rehnberg","Reich","Reiche","Reichel","Reichert","Reid","Reidar","Reider","Reifel","Reiko","Reilly","Reimer","Rein","Reina","Reinald","Reinaldo","Reinaldos","Reine","Reiner","Reiners","Reinert","Reinertson","Reinhard","Reinhardt","Reinhart","Reinhold","Reinke","Reinold","Reinwald","Reis","Reisch","Reiser","Reisfield","Reisinger","Reisman","Reiss","Reiter","Reitman","Reld","Rella","Rellia","Relly","Rem","Rema","Remde","Remington","Remmer","Rempe","Remsen","Remus","Remy","Rena","Renado","Renae","Renaldo","Renard","Renata","Renate","Renato","Renaud","Renault","Renckens","Rene","Renee","Renell","Renelle","Reneta","Renferd","Renfred","Reni","Renick","Renie","Renita","Reniti","Rennane","Renner","Rennie","Rennold","Renny","Rento","Rentsch","Rentschler","Renwick","Renzo","Reo","Resa","Rese","Reseda","Resee","Reseta","Resor","Ress","Ressler","Reste","Restivo","Reta","Retha","Rett","Rettig","Rettke","Reube","Reuben","Reuven","Revell","Reviel","Reviere","Revkah","Rew","Rex","Rexana","Rexanna","Rexanne","Rexer","Rexferd","Rexford","Rexfourd","Rey","Reyna","Reynard","Reynold","Reynolds","Rezzani","Rhea","Rheba","Rhee","Rheims","Rheingold","Rheinlander","Rheta","Rhett","Rhetta","Rhiamon","Rhiana","Rhianna","Rhianon","Rhine","Rhines","Rhoades","Rhoads","Rhoda","Rhodes","Rhodia","Rhodie","Rhody","Rhona","Rhonda","Rhu","Rhynd","Rhyne","Rhyner","Rhys","Ri","Ria","Riana","Riancho","Riane","Rianna","Riannon","Rianon","Riba","Ribal","Ribaudo","Ribble","Ric","Rica","Ricard","Ricarda","Ricardama","Ricardo","Ricca","Riccardo","Riccio","Rice","Rich","Richara","Richard","Richarda","Richardo","Richards","Richardson","Richart","Richel","Richela","Richella","Richelle","Richer","Richers","Richey","Richia","Richie","Richlad","Richma","Richmal","Richman","Richmond","Richmound","Richter","Richy","Rici","Rick","Rickard","Rickart","Ricker","Rickert","Ricketts","Rickey","Ricki","Rickie","Ricky","Rico","Ricoriki","Rida","Riddle","Rider","Ridglea","Ridglee","Ridgley","Ridinger","Ridley","Rie","Riebling","Riedel","Riegel","Rieger","Riehl","Riella","Ries","Riesman","Riess","Rieth","Riffle","Rifkin","Rigby","Rigdon","Riggall","Riggins","Riggs","Riha","Rihana","Rik","Rika","Riker","Riki","Rikki","Rilda","Riley","Rillings","Rillis","Rima","Rimas","Rimma","Rimola","Rina","Rinaldo","Rind","Rinee","Ring","Ringe","Ringler","Ringo","Ringsmuth","Rinna","Rintoul","Riobard","Riocard","Rior","Riordan","Riorsson","Rip","Ripleigh","Riplex","Ripley","Ripp","Risa","Rise","Risley","Rissa","Risser","Rist","Risteau","Rita","Ritch","Ritchie","Riti","Ritter","Ritz","Riva","Rivalee","Rivard","River","Rivera","Rivers","Rives","Rivi","Rivkah","Rivy","Rizas","Rizika","Rizzi","Rizzo","Ro","Roach","Roana","Roane","Roanna","Roanne","Roarke","Roath","Rob","Robaina","Robb","Robbert","Robbi","Robbie","Robbin","Robbins","Robby","Robbyn","Robena","Robenia","Robers","Roberson","Robert","Roberta","Roberto","Roberts","Robertson","Robet","Robi","Robillard","Robin","Robina","Robinet","Robinett","Robinetta","Robinette","Robinia","Robins","Robinson","Robison","Robson","Roby","Robyn","Rocca","Rocco","Roch","Roche","Rochell","Rochella","Rochelle","Rochemont","Rocher","Rochester","Rochette","Rochkind","Rochus","Rock","Rockafellow","Rockefeller","Rockel","Rocker","Rockey","Rockie","Rockwell","Rockwood","Rocky","Rocray","Rod","Roda","Rodd","Roddie","Roddy","Rodenhouse","Roderic","Roderica","Roderich","Roderick","Roderigo","Rodge","Rodger","Rodgers","Rodi","Rodie","Rodina","Rodl","Rodman","Rodmann","Rodmun","Rodmur","Rodney","Rodolfo","Rodolph","Rodolphe","Rodrich","Rodrick","Rodrigo","Rodriguez","Rodrique","Roe","Roede","Roee","Roehm","Roer","Roeser","Rog","Roger","Rogerio","Rogers","Rogerson","Rogovy","Rogozen","Rohn","Roi","Roice","Roid","Rois","Rojas","Rokach","Rola","Rolan","Roland","Rolanda","Rolando","Rolandson","Roldan","Roley","Rolf","Rolfe","Rolfston","Rolland","Rollet","Rollie","Rollin","Rollins","Rollo","Rolo","Rolph","Roma","Romain","Romaine","Romalda","Roman","Romanas","Romano","Rombert","Rome","Romelda","Romelle","Romeo","Romeon","Romeu","Romeyn","Romie","Romilda","Romilly","Romina","Romine","Romito","Romney","Romo","Romola","Romona","Romonda","Romulus","Romy","Ron","Rona","Ronal","Ronald","Ronalda","Ronda","Rondi","Rondon","Ronel","Ronen","Ronica","Ronn","Ronna","Ronnholm","Ronni","Ronnica","Ronnie","Ronny","Roobbie","Rooke","Rooker","Rooney","Roos","Roose","Roosevelt","Root","Roots","Roper","Roque","Rora","Rori","Rorie","Rorke","Rorry","Rorrys","Rory","Ros","Rosa","Rosabel","Rosabella","Rosabelle","Rosalba","Rosalee","Rosaleen","Rosalia","Rosalie","Rosalind","Rosalinda","Rosalinde","Rosaline","Rosalyn","Rosalynd","Rosamond","Rosamund","Rosana","Rosane","Rosanna","Rosanne","Rosario","Rosati","Rosco","Roscoe","Rose","Roseann","Roseanna","Roseanne","Rosecan","Rosel","Roselane","Roselani","Roselba","Roselia","Roselin","Roseline","Rosella","Roselle","Roselyn","Rosemare","Rosemari","Rosemaria","Rosemarie","Rosemary","Rosemonde","Rosen","Rosena","Rosenbaum","Rosenberg","Rosenberger","Rosenblast","Rosenblatt","Rosenblum","Rosene","Rosenfeld","Rosenkrantz","Rosenkranz","Rosenquist","Rosenstein","Rosenthal","Rosenwald","Rosenzweig","Rosetta","Rosette","Roshan","Roshelle","Rosie","Rosina","Rosinski","Rosio","Rosita","Roskes","Roslyn","Rosmarin","Rosmunda","Rosner","Rosol","Ross","Rosse","Rossen","Rossi","Rossie","Rossing","Rossner","Rossuck","Rossy","Rostand","Roswald","Roswell","Rosy","Rotberg","Roter","Roth","Rothberg","Rothenberg","Rother","Rothmuller","Rothschild","Rothstein","Rothwell","Roti","Rotman","Rotow","Roumell","Rourke","Routh","Rouvin","Roux","Rovelli","Rovit","Rovner","Row","Rowan","Rowe","Rowell","Rowen","Rowena","Rowland","Rowley","Rowney","Rox","Roxana","Roxane","Roxanna","Roxanne","Roxi","Roxie","Roxine","Roxy","Roy","Royal","Royall","Roybn","Royce","Royd","Roydd","Royden","Roye","Royo","Roz","Rozalie","Rozalin","Rozamond","Rozanna","Rozanne","Roze","Rozek","Rozele","Rozella","Rozelle","Rozina","Rriocard","Ru","Rubbico","Rube","Rubel","Ruben","Rubens","Rubenstein","Ruberta","Rubetta","Rubi","Rubia","Rubie","Rubin","Rubina","Rubinstein","Rubio","Ruby","Rucker","Ruckman","Rudd","Ruddie","Ruddy","Rudelson","Ruder","Rudich","Rudie","Rudiger","Rudin","Rudman","Rudolf","Rudolfo","Rudolph","Rudwik","Rudy","Rudyard","Rue","Ruel","Ruella","Ruelle","Ruelu","Rufe","Rufena","Ruff","Ruffi","Ruffin","Ruffina","Ruffo","Rufford","Rufina","Ruford","Rufus","Rugen","Rugg","Ruggiero","Ruhl","Ruhnke","Ruiz","Rumery","Rumilly","Rumney","Rumpf","Runck","Rundgren","Runkel","Runkle","Runstadler","Rupert","Ruperta","Ruperto","Ruphina","Ruprecht","Rurik","Rus","Ruscher","Ruscio","Rusel","Rusell","Rusert","Rush","Rushing","Ruskin","Russ","Russel","Russell","Russi","Russia","Russian","Russo","Russom","Russon","Rust","Rustice","Rusticus","Rustie","Rustin","Rusty","Rutan","Rutger","Ruth","Ruthann","Ruthanne","Ruthe","Rutherford","Rutherfurd","Ruthi","Ruthie","Ruthven","Ruthy","Rutledge","Rutter","Ruttger","Ruvolo","Ruy","Ruyle","Ruzich","Ryan","Ryann","Rycca","Rydder","Ryder","Rye","Ryle","Ryley","Ryon","Rysler","Ryter","Ryun","Saba","Sabah","Sabba","Sabec","Sabella","Sabelle","Saber","Saberhagen","Saberio","Sabian","Sabina","Sabine","Sabino","Sabir","Sabra","Sabrina","Sabsay","Sabu","Sacci","Sacha","Sachi","Sachiko","Sachs","Sachsse","Sacken","Sackey","Sackman","Sacks","Sacksen","Sackville","Sacttler","Sad","Sada","Saddler","Sadella","Sadick","Sadie","Sadira","Sadirah","Sadiras","Sadler","Sadoc","Sadoff","Sadonia","Sadowski","Sadye","Saeger","Saffian","Saffier","Saffren","Safier","Safir","Safire","Safko","Sage","Sager","Sagerman","Saidee","Saidel","Saideman","Saied","Saiff","Sailesh","Saimon","Saint","Sair","Saire","Saito","Sajovich","Sakhuja","Sakmar","Sakovich","Saks","Sal","Salahi","Salaidh","Salamanca","Salamone","Salangi","Salangia","Salas","Salazar","Salba","Salbu","Salchunas","Sale","Saleem","Salem","Salema","Saleme","Salena","Salene","Salesin","Salim","Salina","Salinas","Salisbarry","Salisbury","Salita","Sall","Sallee","Salli","Sallie","Sally","Sallyann","Sallyanne","Salman","Salmon","Saloma","Salome","Salomi","Salomie","Salomo","Salomon","Salomone","Salot","Salsbury","Salter","Saltsman","Saltzman","Salvador","Salvadore","Salvatore","Salvay","Salvidor","Salvucci","Salzhauer","Sam","Sama","Samal","Samala","Samale","Samalla","Samantha","Samanthia","Samara","Samaria","Samau","Samella","Samford","Sami","Samira","Sammer","Sammie","Sammons","Sammy","Samp","Sampson","Sams","Samson","Samuel","Samuela","Samuele","Samuella","Samuelson","Samul","Samy","Sanalda","Sanbo","Sanborn","Sanborne","Sanburn","Sancha","Sanchez","Sancho","Sand","Sandberg","Sande","Sandeep","Sandell","Sander","Sanders","Sanderson","Sandi","Sandie","Sandler","Sandon","Sandor","Sandra","Sandro","Sandry","Sands","Sandstrom","Sandy","Sandye","Sanferd","Sanfo","Sanford","Sanfourd","Sanfred","Sang","Sanger","Sanjay","Sanjiv","Sankaran","Sankey","Sansbury","Sansen","Sanson","Sansone","Santa","Santana","Santiago","Santini","Santoro","Santos","Sanyu","Sapers","Saphra","Sapienza","Sapowith","Sapphera","Sapphira","Sapphire","Sara","Sara-Ann","Saraann","Sarad","Sarah","Saraiya","Sarajane","Sarazen","Sarchet","Sardella","Saree","Sarena","Sarene","Saretta","Sarette","Sarge","Sargent","Sari","Sarid","Sarilda","Sarina","Sarine","Sarita","Sarkaria","Sarnoff","Sarson","Sartin","Sascha","Sasha","Sashenka","Sasnett","Sass","Sassan","Sateia","Sathrum","Sato","Satterfield","Satterlee","Saturday","Saucy","Sauder","Saudra","Sauer","Sauers","Saul","Sauls","Saum","Sauncho","Saunder","Saunders","Saunderson","Saundra","Sausa","Sauveur","Savadove","Savage","Saval","Savanna","Savannah","Savdeep","Savell","Savick","Savil","Savill","Saville","Savina","Savior","Savitt","Savory","Saw","Sawtelle","Sawyer","Sawyere","Sawyor","Sax","Saxe","Saxen","Saxena","Saxon","Say","Sayce","Sayed","Sayer","Sayers","Sayette","Sayles","Saylor","Sayre","Sayres","Scales","Scammon","Scandura","Scarface","Scarito","Scarlet","Scarlett","Scarrow","Scever","Scevo","Scevor","Scevour","Schaab","Schaaff","Schach","Schacker","Schaefer","Schaeffer","Schafer","Schaffel","Schaffer","Schalles","Schaper","Schapira","Scharaga","Scharf","Scharff","Schargel","Schatz","Schaumberger","Schear","Schechinger","Schechter","Scheck","Schecter","Scheer","Scheers","Scheider","Scheld","Schell","Schellens","Schenck","Scherle","Scherman","Schertz","Schick","Schiff","Schiffman","Schifra","Schild","Schilit","Schilling","Schilt","Schindler","Schinica","Schiro","Schlenger","Schlesinger","Schlessel","Schlessinger","Schlicher","Schlosser","Schluter","Schmeltzer","Schmidt","Schmitt","Schmitz","Schnabel","Schnapp","Schnell","Schnorr","Schnur","Schnurr","Schober","Schoenberg","Schoenburg","Schoenfelder","Schoening","Schofield","Scholem","Scholz","Schonfeld","Schonfield","Schonthal","Schoof","Schott","Schou","Schouten","Schrader","Schram","Schramke","Schreck","Schreib","Schreibe","Schreiber","Schreibman","Schrick","Schriever","Schroder","Schroeder","Schroer","Schroth","Schubert","Schug","Schuh","Schulein","Schuler","Schulman","Schultz","Schulz","Schulze","Schuman","Schumer","Schurman","Schuster","Schuyler","Schwab","Schwartz","Schwarz","Schweiker","Schweitzer","Schwejda","Schwenk","Schwerin","Schwing","Schwinn","Schwitzer","Scibert","Sclar","Sclater","Scoles","Scopp","Scornik","Scot","Scoter","Scotney","Scott","Scotti","Scottie","Scotty","Scoville","Screens","Scribner","Scriven","Scrivenor","Scrivens","Scrivings","Scrogan","Scrope","Sculley","Scully","Scurlock","Scutt","Seabrook","Seabrooke","Seabury","Seaddon","Seaden","Seadon","Seafowl","Seagrave","Seagraves","Seale","Seaman","Seamus","Sean","Seana","Searby","Searcy","Searle","Sears","Season","Seaton","Seaver","Seavey","Seavir","Sebastian","Sebastiano","Sebastien","Sebbie","Secor","Secrest","Secunda","Secundas","Seda","Sedberry","Sedda","Sedgewake","Sedgewick","Sedgewinn","Sedlik","See","Seebeck","Seed","Seedman","Seel","Seely","Seem","Seema","Seen","Seena","Seessel","Seeto","Seften","Sefton","Seftton","Segal","Segalman","Seiber","Seibold","Seidel","Seiden","Seidler","Seidule","Seif","Seigel","Seigler","Seiter","Seitz","Seka","Seko","Sekofski","Sekyere","Sela","Selassie","Selby","Selda","Seldan","Selden","Seldon","Seldun","Selemas","Selena","Selene","Selestina","Seleta","Selfridge","Selhorst","Selia","Selie","Selig","Seligman","Seligmann","Selima","Selimah","Selina","Selinda","Seline","Selinski","Sell","Sella","Selle","Sellers","Sellma","Sello","Sells","Selma","Selmner","Selmore","Selry","Seltzer","Selway","Selwin","Selwyn","Semela","Semele","Semmes","Sena","Senalda","Sender","Senecal","Senhauser","Senior","Senn","Sension","Senskell","Senzer","Seow","Sephira","Seppala","September","Septima","Sera","Serafina","Serafine","Seraphim","Seraphina","Seraphine","Serena","Serene","Serg","Serge","Sergeant","Sergei","Sergent","Sergias","Sergio","Sergius","Sergo","Sergu","Serica","Serilda","Serle","Serles","Seroka","Serra","Serrano","Serrell","Servais","Server","Servetnick","Service","Sessler","Seta","Seth","Sethi","Sethrida","Seto","Seton","Settera","Settle","Seumas","Sev","Seve","Severen","Severin","Severn","Severson","Sevik","Seward","Sewel","Sewell","Sewellyn","Sewole","Sewoll","Sexton","Seyler","Seymour","Seys","Sezen","Shabbir","Shaddock","Shadow","Shae","Shaefer","Shaeffer","Shaer","Shafer","Shaff","Shaffer","Shaffert","Shah","Shaia","Shaikh","Shaina","Shaine","Shakespeare","Shakti","Shalna","Shalne","Shalom","Shama","Shamma","Shamrao","Shamus","Shana","Shanahan","Shanan","Shanda","Shandee","Shandeigh","Shandie","Shandra","Shandy","Shane","Shaner","Shani","Shanie","Shank","Shanks","Shanleigh","Shanley","Shanly","Shanna","Shannah","Shannan","Shannen","Shanney","Shannon","Shanon","Shanta","Shantee","Shantha","Shaper","Shapiro","Shara","Sharai","Shargel","Shari","Sharia","Sharity","Sharl","Sharla","Sharleen","Sharlene","Sharline","Sharma","Sharman","Sharon","Sharona","Sharos","Sharp","Sharpe","Sharron","Sharyl","Shatzer","Shaughn","Shaughnessy","Shaum","Shaun","Shauna","Shaver","Shaw","Shawn","Shawna","Shawnee","Shay","Shaya","Shayla","Shaylah","Shaylyn","Shaylynn","Shayn","Shayna","Shayne","Shea","Sheaff","Shear","Sheba","Shedd","Sheeb","Sheedy","Sheehan","Sheela","Sheelagh","Sheelah","Sheena","Sheepshanks","Sheeran","Sheeree","Sheets","Sheff","Sheffie","Sheffield","Sheffy","Sheila","Sheilah","Shel","Shela","Shelagh","Shelah","Shelba","Shelbi","Shelburne","Shelby","Shelden","Sheldon","Sheley","Shelia","Sheline","Shell","Shellans","Shelley","Shelli","Shellie","Shelly","Shelman","Shelton","Shem","Shena","Shenan","Sheng","Shep","Shepard","Shepherd","Shepley","Sheply","Shepp","Sheppard","Shepperd","Sher","Sherar","Sherard","Sherborn","Sherborne","Sherburn","Sherburne","Shere","Sheree","Sherer","Shererd","Sherfield","Sheri","Sheridan","Sherie","Sherill","Sherilyn","Sherj","Sherl","Sherline","Sherlock","Sherlocke","Sherm","Sherman","Shermie","Shermy","Sherourd","Sherr","Sherrard","Sherrer","Sherri","Sherrie","Sherrill","Sherris","Sherrod","Sherry","Sherurd","Sherwin","Sherwood","Sherwynd","Sherye","Sheryl","Sheryle","Shetrit","Shevlo","Shewchuk","Shewmaker","Sheya","Shiau","Shieh","Shiekh","Shields","Shien","Shiff","Shifra","Shifrah","Shig","Shih","Shiller","Shimberg","Shimkus","Shina","Shinberg","Shing","Shipley","Shipman","Shipp","Shippee","Shir","Shira","Shirah","Shirberg","Shiri","Shirk","Shirl","Shirlee","Shirleen","Shirlene","Shirley","Shirlie","Shirline","Shiroma","Shishko","Shiverick","Shivers","Shlomo","Shoemaker","Shoifet","Sholeen","Sholem","Sholes","Sholley","Sholom","Shore","Shornick","Short","Shorter","Shoshana","Shoshanna","Shotton","Showker","Shreeves","Shreve","Shrier","Shriner","Shriver","Shu","Shue","Shugart","Shulamith","Shulem","Shuler","Shulins","Shull","Shulman","Shulock","Shult","Shultz","Shum","Shuma","Shuman","Shumway","Shuping","Shurlock","Shurlocke","Shurwood","Shushan","Shute","Shutz","Shwalb","Shyamal","Si","Siana","Sianna","Sib","Sibbie","Sibby","Sibeal","Sibel","Sibell","Sibella","Sibelle","Siberson","Sibie","Sibilla","Sible","Siblee","Sibley","Sibyl","Sibylla","Sibylle","Sibyls","Sicard","Sices","Siclari","Sicular","Sid","Sida","Siddon","Siddra","Sidell","Sidhu","Sidky","Sidman","Sidnee","Sidney","Sidoma","Sidon","Sidoney","Sidonia","Sidonie","Sidonius","Sidonnie","Sidoon","Sidra","Sidran","Sidras","Sidwel","Sidwell","Sidwohl","Sieber","Siegel","Siegfried","Siegler","Sielen","Sieracki","Sierra","Siesser","Sievert","Siffre","Sig","Sigfrid","Sigfried","Sigismond","Sigismondo","Sigismund","Sigismundo","Sigler","Sigmund","Signe","Sigrid","Sigsmond","Sigvard","Sihon","Sihonn","Sihun","Sihunn","Sik","Sikata","Sikes","Sikko","Sikorski","Sil","Silas","Silber","Silberman","Silda","Silden","Sile","Sileas","Silin","Sill","Sillsby","Silma","Siloa","Siloam","Siloum","Silsby","Silsbye","Silva","Silvain","Silvan","Silvana","Silvano","Silvanus","Silver","Silverman","Silvers","Silverstein","Silverts","Silvester","Silvestro","Silvia","Silvie","Silvio","Sim","Sima","Simah","Simdars","Simeon","Simmie","Simmonds","Simmons","Simon","Simona","Simone","Simonetta","Simonette","Simonne","Simons","Simonsen","Simpkins","Simpson","Sims","Simsar","Simson","Sinai","Sinclair","Sinclare","Sindee","Sine","Sinegold","Singband","Singer","Singh","Singhal","Singleton","Sink","Sinnard","Siobhan","Sion","Sioux","Siouxie","Sipple","Sirkin","Sirmons","Sirois","Sirotek","Sisak","Sisco","Sisely","Sisile","Siskind","Sissel","Sissie","Sisson","Sissy","Sisto","Sitarski","Sitnik","Sitra","Siubhan","Siusan","Sivia","Sivie","Siward","Sjoberg","Skantze","Skardol","Skees","Skeie","Skell","Skelly","Skelton","Skerl","Skiba","Skier","Skiest","Skilken","Skill","Skillern","Skinner","Skip","Skipp","Skipper","Skippie","Skippy","Skipton","Sklar","Skolnik","Skricki","Skurnik","Skutchan","Skvorak","Sky","Skye","Skyla","Skylar","Skyler","Slaby","Slack","Slade","Sladen","Slater","Slaughter","Slavic","Slavin","Slayton","Sldney","Slemmer","Sletten","Slifka","Slinkman","Sliwa","Sloan","Sloane","Sloatman","Slocum","Slosberg","Slotnick","Sluiter","Sly","Slyke","Smail","Small","Smalley","Smallman","Smart","Smiga","Smiley","Smith","Smitt","Smitty","Smoot","Smukler","Snapp","Snashall","Sneed","Snell","Snider","Snoddy","Snodgrass","Snook","Snow","Snowber","Snowman","Snyder","So","Soane","Sobel","Soble","Socha","Socher","Sochor","Socrates","Soelch","Sofer","Sofia","Sofie","Sofko","Soinski","Sokil","Sokul","Sol","Sola","Solana","Solange","Solberg","Solenne","Solis","Solita","Solitta","Soll","Sollars","Solley","Sollie","Sollows","Solly","Solnit","Soloma","Soloman","Solomon","Solon","Soluk","Som","Somerset","Somerville","Sommer","Sommers","Son","Sondra","Soneson","Song","Soni","Sonia","Sonja","Sonni","Sonnie","Sonnnie","Sonny","Sonstrom","Sontag","Sontich","Sonya","Soo","Soph","Sopher","Sophey","Sophi","Sophia","Sophie","Sophronia","Sophy","Soracco","Soraya","Sorce","Sorcha","Sorci","Sorcim","Sorel","Soren","Sorensen","Sorenson","Sorilda","Sorkin","Sorrows","Sosanna","Sosna","Sosthena","Sosthenna","Sosthina","Sothena","Sotos","Sou","Soule","Soulier","Sousa","Southard","Southworth","Soutor","Souvaine","Souza","Sowell","Sower","Spada","Spain","Spalding","Spalla","Spancake","Spanjian","Spanos","Sparhawk","Spark","Sparke","Sparkie","Sparks","Sparky","Sparrow","Spatola","Spatz","Spaulding","Spear","Spearing","Spearman","Spears","Specht","Spector","Spence","Spencer","Spense","Spenser","Sperling","Speroni","Sperry","Spevek","Spiegel","Spiegelman","Spiegleman","Spieler","Spielman","Spiers","Spike","Spillar","Spindell","Spiro","Spiros","Spitzer","Spohr","Spooner","Spoor","Spracklen","Sprage","Spragens","Sprague","Spratt","Spring","Springer","Sproul","Sprung","Spurgeon","Squier","Squire","Squires","Srini","Staal","Stace","Stacee","Stacey","Staci","Stacia","Stacie","Stacy","Stafani","Staffan","Staffard","Stafford","Staford","Stag","Stagg","Stahl","Stalder","Staley","Stalk","Stalker","Stallworth","Stamata","Stambaugh","Stan","Stander","Standford","Standice","Standing","Standish","Standley","Standush","Stanfield","Stanfill","Stanford","Stanhope","Stanislas","Stanislaus","Stanislaw","Stanleigh","Stanley","Stanly","Stannfield","Stannwood","Stanton","Stanway","Stanwin","Stanwinn","Stanwood","Stanzel","Star","Starbuck","Stargell","Starinsky","Stark","Starkey","Starks","Starla","Starlene","Starlin","Starling","Starobin","Starr","Stasny","Staten","Statis","Stauder","Stauffer","Stav","Stavro","Stavros","Staw","Stclair","Stead","Steady","Stearn","Stearne","Stearns","Steck","Steddman","Stedman","Stedmann","Stedt","Steel","Steele","Steen","Steep","Steere","Stefa","Stefan","Stefanac","Stefania","Stefanie","Stefano","Steffane","Steffen","Steffi","Steffie","Steffin","Steffy","Stegman","Stein","Steinberg","Steiner","Steinke","Steinman","Steinway","Stella","Stelle","Stelmach","Stelu","Stempien","Stempson","Stenger","Stent","Stepha","Stephan","Stephana","Stephani","Stephania","Stephanie","Stephannie","Stephanus","Stephen","Stephenie","Stephens","Stephenson","Stephi","Stephie","Stephine","Sterling","Stern","Sternberg","Sterne","Sterner","Sternick","Sternlight","Sterrett","Stesha","Stets","Stetson","Stevana","Steve","Steven","Stevena","Stevens","Stevenson","Stevie","Stevy","Stew","Steward","Stewardson","Stewart","Stich","Stichter","Stickney","Stiegler","Stieglitz","Stier","Stig","Stila","Stiles","Still","Stilla","Stillas","Stillman","Stillmann","Stilu","Stilwell","Stimson","Stine","Stinky","Stinson","Stirling","Stoat","Stochmal","Stock","Stockmon","Stockton","Stockwell","Stoddard","Stoddart","Stodder","Stoeber","Stoecker","Stoffel","Stokes","Stoll","Stoller","Stolzer","Stone","Stoneham","Stoneman","Stonwin","Stoops","Storer","Storfer","Storm","Stormi","Stormie","Stormy","Stortz","Story","Storz","Stouffer","Stoughton","Stout","Stovall","Stover","Strade","Strader","Strage","Strain","Strait","Stralka","Strander","Strang","Stranger","Stratton","Straub","Straus","Strauss","Strawn","Streeter","Streetman","Streeto","Strenta","Strep","Strephon","Strephonn","Strepphon","Stretch","Stricklan","Strickland","Strickler","Strickman","Stringer","Strohbehn","Strohben","Strohl","Stromberg","Strong","Stronski","Stroud","Stroup","Struve","Stryker","Stu","Stuart","Stubbs","Stubstad","Stucker","Stuckey","Studdard","Studley","Studner","Studnia","Stulin","Stultz","Stuppy","Sturdivant","Sturges","Sturrock","Stutman","Stutsman","Stutzman","Styles","Su","Suanne","Subak","Subir","Sublett","Suchta","Suckow","Sucy","Sudbury","Sudderth","Sudhir","Sudnor","Sue","Suellen","Suelo","Sugar","Sugden","Sugihara","Suh","Suhail","Suilmann","Suk","Sukey","Sukhum","Suki","Sukin","Sula","Sulamith","Sullivan","Sully","Sum","Sumer","Sumerlin","Summer","Summers","Summons","Sumner","Sunda","Sunday","Sundberg","Sunderland","Sundin","Sundstrom","Suneya","Sung","Sunil","Sunny","Sunshine","Sup","Supat","Supen","Supple","Sura","Surbeck","Surovy","Survance","Susan","Susana","Susanetta","Susann","Susanna","Susannah","Susanne","Susette","Susi","Susie","Sussi","Sussman","Sussna","Susumu","Susy","Suter","Sutherlan","Sutherland","Sutphin","Sutton","Suu","Suzan","Suzann","Suzanna","Suzanne","Suzetta","Suzette","Suzi","Suzie","Suzy","Suzzy","Sven","Svend","Svensen","Sverre","Svetlana","Svoboda","Swagerty","Swain","Swaine","Swainson","Swamy","Swan","Swane","Swanhilda","Swanhildas","Swann","Swanson","Swart","Swarts","Swartz","Swayder","Swayne","Sweatt","Swec","Swee","Sweeney","Sweet","Swen","Swenson","Swetiana","Swetlana","Sweyn","Swiercz","Swift","Swigart","Swihart","Swinton","Swirsky","Swisher","Swithbart","Swithbert","Swithin","Switzer","Swope","Swor","Swords","Sy","Sybil","Sybila","Sybilla","Sybille","Sybley","Sybyl","Syck","Syd","Sydel","Sydelle","Sydney","Sykes","Syl","Sylas","Sylvan","Sylvanus","Sylvester","Sylvia","Sylvie","Syman","Symer","Symon","Symons","Synn","Syst","Syverson","TEirtza","Taam","Tab","Tabatha","Tabb","Tabbatha","Tabber","Tabbi","Tabbie","Tabbitha","Tabby","Taber","Tabib","Tabina","Tabitha","Tabor","Tabshey","Tace","Tacita","Tacklind","Tacy","Tacye","Tad","Tada","Tadashi","Tadd","Taddeo","Taddeusz","Tade","Tadeas","Tadeo","Tades","Tadich","Tadio","Taffy","Taft","Tager","Taggart","Tahmosh","Tai","Tailor","Taima","Taimi","Tait","Taite","Tak","Taka","Takakura","Takara","Takashi","Takeo","Takeshi","Takken","Tal","Tala","Talanian","Talanta","Talbert","Talbot","Talbott","Tali","Talia","Talich","Talie","Tallbot","Tallbott","Talley","Tallia","Tallie","Tallou","Tallu","Tallula","Tallulah","Tally","Talmud","Talya","Talyah","Tam","Tama","Tamah","Tamanaha","Tamar","Tamara","Tamarah","Tamarra","Tamaru","Tamas","Tamberg","Tamer","Tamera","Tami","Tamiko","Tamis","Tamma","Tammany","Tammara","Tammi","Tammie","Tammy","Tamqrah","Tamra","Tamsky","Tan","Tana","Tanah","Tanaka","Tanberg","Tandi","Tandie","Tandy","Tanhya","Tani","Tania","Tanitansy","Tankoos","Tann","Tannen","Tannenbaum","Tannenwald","Tanner","Tanney","Tannie","Tanny","Tansey","Tansy","Tanya","Tapes","Tara","Tarabar","Tarah","Taran","Tarazi","Tare","Tareyn","Targett","Tarkany","Taro","Tarr","Tarra","Tarrah","Tarrance","Tarrant","Tarrel","Tarrsus","Tarryn","Tarsus","Tarsuss","Tartaglia","Tartan","Tarton","Tarttan","Taryn","Taryne","Tasha","Tasia","Tasiana","Tat","Tate","Tati","Tatia","Tatiana","Tatianas","Tatiania","Tatianna","Tatman","Tattan","Tatum","Taub","Tav","Taveda","Tavey","Tavi","Tavia","Tavie","Tavis","Tavish","Tavy","Tawney","Tawnya","Tawsha","Tay","Tayib","Tayler","Taylor","Tayyebeb","Tchao","Teador","Teagan","Teage","Teague","Teahan","Teak","Tearle","Tecla","Tecu","Ted","Tedd","Tedda","Tedder","Teddi","Teddie","Teddman","Teddy","Tedi","Tedie","Tedman","Tedmann","Tedmund","Tedra","Tedric","Teece","Teena","Teerell","Teeter","Teevens","Teferi","Tega","Tegan","Teillo","Teilo","Tekla","Telfer","Telford","Telfore","Tella","Tellford","Tem","Tema","Temp","Tempa","Tempest","Templa","Templas","Temple","Templer","Templeton","Templia","Ten","Tena","Tench","Tenenbaum","Tengdin","Tengler","Tenn","Tenner","Tennes","Tenney","Tennies","Teodoor","Teodor","Teodora","Teodorico","Teodoro","Teplica","Teplitz","Tepper","Tera","Terbecki","Terchie","Terena","Terence","Terencio","Teresa","Terese","Teresina","Teresita","Teressa","Terhune","Teri","Teria","Teriann","Terina","Terle","Ternan","Terpstra","Terr","Terra","Terrance","Terrel","Terrell","Terrena","Terrence","Terrene","Terri","Terrie","Terrijo","Terrill","Terrilyn","Terris","Terriss","Territus","Terry","Terrye","Terryl","Terryn","Tersina","Terti","Tertia","Tertias","Tertius","Teryl","Teryn","Terza","Terzas","Tesler","Tess","Tessa","Tessi","Tessie","Tessler","Tessy","Teteak","Teufert","Teuton","Tevis","Tewell","Tewfik","Tews","Thacher","Thacker","Thackeray","Thad","Thaddaus","Thaddeus","Thaddus","Thadeus","Thagard","Thain","Thaine","Thais","Thalassa","Thalia","Tham","Thamora","Thamos","Thanasi","Thane","Thanh","Thanos","Thant","Thapa","Thar","Tharp","Thatch","Thatcher","Thaxter","Thay","Thayer","Thayne","The","Thea","Theadora","Theall","Thebault","Thecla","Theda","Thedric","Thedrick","Theis","Thekla","Thelma","Thema","Themis","Thenna","Theo","Theobald","Theodor","Theodora","Theodore","Theodoric","Theodosia","Theola","Theona","Theone","Thera","Theran","Theresa","Therese","Theresina","Theresita","Theressa","Therine","Theron","Therron","Thesda","Thessa","Theta","Thetes","Thetis","Thetisa","Thetos","Theurer","Theurich","Thevenot","Thia","Thibaud","Thibault","Thibaut","Thielen","Thier","Thierry","Thilda","Thilde","Thill","Thin","Thinia","Thirion","Thirza","Thirzi","Thirzia","Thisbe","Thisbee","Thissa","Thistle","Thoer","Thom","Thoma","Thomajan","Thomas","Thomasa","Thomasin","Thomasina","Thomasine","Thomey","Thompson","Thomsen","Thomson","Thor","Thora","Thorbert","Thordia","Thordis","Thorfinn","Thorin","Thorlay","Thorley","Thorlie","Thorma","Thorman","Thormora","Thorn","Thornburg","Thorncombe","Thorndike","Thorne","Thorner","Thornie","Thornton","Thorny","Thorpe","Thorr","Thorrlow","Thorstein","Thorsten","Thorvald","Thorwald","Thrasher","Three","Threlkeld","Thrift","Thun","Thunell","Thurber","Thurlough","Thurlow","Thurman","Thurmann","Thurmond","Thurnau","Thursby","Thurstan","Thurston","Thury","Thynne","Tia","Tiana","Tibbetts","Tibbitts","Tibbs","Tibold","Tica","Tice","Tichon","Tichonn","Ticknor","Ticon","Tidwell","Tiebold","Tiebout","Tiedeman","Tiemroth","Tien","Tiena","Tierell","Tiernan","Tierney","Tiersten","Tiertza","Tierza","Tifanie","Tiff","Tiffa","Tiffani","Tiffanie","Tiffanle","Tiffany","Tiffi","Tiffie","Tiffy","Tiga","Tigges","Tila","Tilda","Tilden","Tildi","Tildie","Tildy","Tiler","Tilford","Till","Tilla","Tillford","Tillfourd","Tillie","Tillinger","Tillio","Tillion","Tillman","Tillo","Tilly","Tilney","Tiloine","Tim","Tima","Timi","Timmi","Timmie","Timmons","Timms","Timmy","Timofei","Timon","Timoteo","Timothea","Timothee","Timotheus","Timothy","Tina","Tinaret","Tindall","Tine","Tingey","Tingley","Tini","Tiny","Tinya","Tiossem","Tiphane","Tiphani","Tiphanie","Tiphany","Tippets","Tips","Tipton","Tirrell","Tirza","Tirzah","Tisbe","Tisbee","Tisdale","Tish","Tisha","Tisman","Tita","Titania","Tito","Titos","Titus","Tizes","Tjaden","Tjader","Tjon","Tletski","Toback","Tobe","Tobey","Tobi","Tobiah","Tobias","Tobie","Tobin","Tobit","Toby","Tobye","Tocci","Tod","Todd","Toddie","Toddy","Todhunter","Toffey","Toffic","Toft","Toh","Toiboid","Toinette","Tol","Toland","Tolkan","Toll","Tolland","Tolley","Tolliver","Tollman","Tollmann","Tolmach","Tolman","Tolmann","Tom","Toma","Tomas","Tomasina","Tomasine","Tomaso","Tomasz","Tombaugh","Tomchay","Tome","Tomi","Tomkiel","Tomkin","Tomkins","Tomlin","Tomlinson","Tommi","Tommie","Tommy","Tompkins","Toms","Toney","Tongue","Toni","Tonia","Tonie","Tonina","Tonjes","Tonkin","Tonl","Tonneson","Tonnie","Tonry","Tony","Tonya","Tonye","Toogood","Toole","Tooley","Toolis","Toomay","Toombs","Toomin","Toor","Tootsie","Topliffe","Topper","Topping","Tor","Torbart","Torbert","Tore","Torey","Torhert","Tori","Torie","Torin","Tormoria","Torosian","Torp","Torr","Torrance","Torras","Torray","Torre","Torrell","Torrence","Torres","Torrey","Torrie","Torrin","Torrlow","Torruella","Torry","Torto","Tortosa","Tory","Toscano","Tosch","Toshiko","Toth","Touber","Toulon","Tound","Tova","Tove","Towbin","Tower","Towers","Towill","Towland","Town","Towne","Towney","Townie","Townsend","Townshend","Towny","Towrey","Towroy","Toy","Trabue","Tracay","Trace","Tracee","Tracey","Traci","Tracie","Tracy","Trager","Trahern","Trahurn","Trainer","Trainor","Trakas","Trammel","Tran","Tranquada","Trant","Trask","Tratner","Trauner","Trautman","Travax","Traver","Travers","Travis","Travus","Traweek","Tray","Treacy","Treat","Trefler","Trefor","Treharne","Treiber","Trela","Trella","Trellas","Trelu","Tremain","Tremaine","Tremann","Tremayne","Trembly","Tremml","Trenna","Trent","Trenton","Tresa","Trescha","Trescott","Tressa","Tressia","Treulich","Trev","Treva","Trevah","Trevar","Trever","Trevethick","Trevor","Trevorr","Trey","Tri","Trici","Tricia","Trilbee","Trilbi","Trilbie","Trilby","Triley","Trill","Trillbee","Trillby","Trilley","Trilly","Trimble","Trimmer","Trin","Trina","Trinatte","Trinee","Trinetta","Trinette","Trini","Trinia","Trinidad","Trinity","Trinl","Triny","Trip","Triplett","Tripp","Tris","Trisa","Trish","Trisha","Trista","Tristam","Tristan","Tristas","Tristis","Tristram","Trix","Trixi","Trixie","Trixy","Trocki","Trojan","Trometer","Tronna","Troth","Trotta","Trotter","Trout","Trovillion","Trow","Troxell","Troy","Troyes","Trstram","Trubow","Truc","Truda","Trude","Trudey","Trudi","Trudie","Trudnak","Trudy","True","Trueblood","Truelove","Trueman","Truitt","Trula","Trumaine","Truman","Trumann","Truscott","Trust","Trutko","Tryck","Trygve","Tsai","Tsan","Tse","Tseng","Tshombe","Tsuda","Tsui","Tu","Tubb","Tuchman","Tuck","Tucker","Tuckie","Tucky","Tuddor","Tudela","Tudor","Tuesday","Tufts","Tugman","Tuinenga","Tull","Tulley","Tullius","Tullus","Tullusus","Tully","Tumer","Tuneberg","Tung","Tunnell","Tupler","Tuppeny","Turino","Turk","Turley","Turmel","Turnbull","Turne","Turner","Turnheim","Turoff","Turpin","Turrell","Turro","Turtle","Tut","Tutankhamen","Tutt","Tuttle","Tutto","Twedy","Twelve","Twila","Twitt","Twum","Twyla","Ty","Tybald","Tybalt","Tybi","Tybie","Tychon","Tychonn","Tye","Tyika","Tyler","Tymes","Tymon","Tymothy","Tynan","Tyne","Tyra","Tyre","Tyree","Tyrone","Tyrrell","Tyrus","Tyson","Tzong","Ubald","Uball","Ubana","Ube","Uchida","Uchish","Uda","Udale","Udall","Udela","Udele","Udell","Udella","Udelle","Uel","Uela","Uella","Ugo","Uird","Uis","Uke","Ul","Ula","Ulah","Ulane","Ulani","Ulberto","Ulda","Ule","Ulick","Ulises","Ulita","Ulla","Ulland","Ullman","Ullund","Ullyot","Ulphi","Ulphia","Ulphiah","Ulric","Ulrica","Ulrich","Ulrick","Ulrika","Ulrikaumeko","Ulrike","Ultan","Ultann","Ultima","Ultun","Ulu","Ulund","Ulysses","Umberto","Ume","Umeh","Umeko","Ummersen","Umont","Un","Una","Unders","Underwood","Undine","Undis","Undry","Une","Ungley","Uni","Unity","Unni","Uno","Upali","Uphemia","Upshaw","Upton","Urana","Urania","Uranie","Urata","Urba","Urbai","Urbain","Urban","Urbana","Urbani","Urbanna","Urbannai","Urbannal","Urbano","Urbanus","Urbas","Uri","Uria","Uriah","Urial","Urian","Urias","Uriel","Urien","Uriia","Uriiah","Uriisa","Urina","Urion","Urissa","Urita","Urquhart","Ursa","Ursal","Ursala","Ursas","Ursel","Ursi","Ursola","Urson","Ursula","Ursulette","Ursulina","Ursuline","Ury","Usanis","Ushijima","Uta","Utas","Ute","Utham","Uthrop","Utica","Uticas","Utimer","Utley","Utta","Uttasta","Utter","Uttica","Uuge","Uund","Uwton","Uyekawa","Uzia","Uzial","Uziel","Uzzi","Uzzia","Uzzial","Uzziel","Va","Vaas","Vaasta","Vachel","Vachell","Vachil","Vachill","Vacla","Vaclav","Vaclava","Vacuva","Vada","Vaden","Vadim","Vadnee","Vaenfila","Vahe","Vaientina","Vail","Vaios","Vaish","Val","Vala","Valaree","Valaria","Valda","Valdas","Valdemar","Valdes","Valdis","Vale","Valeda","Valenba","Valencia","Valene","Valenka","Valenta","Valente","Valentia","Valentijn","Valentin","Valentina","Valentine","Valentino","Valenza","Valer","Valera","Valeria","Valerian","Valerie","Valerio","Valerlan","Valerle","Valery","Valerye","Valeta","Valiant","Valida","Valina","Valle","Valleau","Vallery","Valley","Valli","Vallie","Vallo","Vallonia","Vally","Valma","Valonia","Valoniah","Valora","Valorie","Valry","Valtin","Van","VanHook","Vance","Vanda","Vanden","Vander","Vanderhoek","Vandervelde","Vandyke","Vanessa","Vange","Vanhomrigh","Vani","Vania","Vanna","Vanni","Vannie","Vanny","Vano","Vanthe","Vanya","Vanzant","Varden","Vardon","Vareck","Vargas","Varhol","Varian","Varick","Varien","Varini","Varion","Varipapa","Varney","Varrian","Vary","Vas","Vashtee","Vashti","Vashtia","Vasileior","Vasilek","Vasili","Vasiliki","Vasilis","Vasiliu","Vasily","Vasos","Vasquez","Vassar","Vassaux","Vassell","Vassili","Vassily","Vasta","Vastah","Vastha","Vasti","Vasya","Vasyuta","Vaughan","Vaughn","Vaules","Veal","Veator","Veats","Veda","Vedetta","Vedette","Vedi","Vedis","Veedis","Velasco","Velda","Veleda","Velick","Veljkov","Velleman","Velma","Velvet","Vena","Venable","Venator","Venditti","Veneaux","Venetia","Venetis","Venezia","Venice","Venita","Venn","Veno","Venola","Venterea","Vento","Ventre","Ventura","Venu","Venus","Venuti","Ver","Vera","Verada","Veradi","Veradia","Veradis","Verbenia","Verda","Verdha","Verdi","Verdie","Vere","Verena","Verene","Verge","Verger","Vergil","Vergne","Vergos","Veriee","Verile","Verina","Verine","Verity","Verla","Verlee","Verlie","Vern","Verna","Verne","Vernen","Verner","Verneuil","Verney","Vernice","Vernier","Vernita","Vernon","Vernor","Veron","Veronica","Veronika","Veronike","Veronique","Verras","Vershen","Vescuso","Vesta","Veta","Vetter","Vevay","Vevina","Vevine","Vey","Vezza","Vharat","Vi","Viafore","Vial","Vic","Viccora","Vick","Vickey","Vicki","Vickie","Vicky","Victoir","Victor","Victoria","Victorie","Victorine","Victory","Vida","Vidal","Vidda","Viddah","Vidovic","Vidovik","Viehmann","Viens","Vierno","Vieva","Vig","Vigen","Viglione","Vigor","Viguerie","Viki","Viking","Vikki","Vikky","Vilberg","Vilhelmina","Villada","Villiers","Vilma","Vin","Vina","Vinaya","Vince","Vincelette","Vincent","Vincenta","Vincentia","Vincents","Vincenty","Vincenz","Vine","Vinia","Vinita","Vinn","Vinna","Vinni","Vinnie","Vinny","Vins","Vinson","Viola","Violante","Viole","Violet","Violeta","Violetta","Violette","Vipul","Viquelia","Viradis","Virendra","Virg","Virge","Virgel","Virgie","Virgil","Virgilia","Virgilio","Virgin","Virgina","Virginia","Virginie","Virgy","Viridi","Viridis","Viridissa","Virnelli","Viscardi","Vish","Vita","Vitale","Vitalis","Vite","Vitek","Vitia","Vitkun","Vito","Vitoria","Vittoria","Vittorio","Vitus","Viv","Viva","Viveca","Vivi","Vivia","Vivian","Viviana","Viviane","Vivianna","Vivianne","Vivica","Vivie","Vivien","Viviene","Vivienne","Viviyan","Vivl","Vivle","Vivyan","Vivyanne","Vizza","Vizzone","Vlad","Vlada","Vladamar","Vladamir","Vladi","Vladimar","Vladimir","Voccola","Voe","Vogel","Vogele","Vogeley","Vola","Volding","Voleta","Voletta","Volin","Volkan","Volnak","Volnay","Volney","Volny","Volotta","Volpe","Voltmer","Voltz","Von","Vona","Vonni","Vonnie","Vonny","Vookles","Voorhis","Vorfeld","Vories","Vorster","Voss","Votaw","Vowel","Vrablik","Vtarj","Vtehsta","Vudimir","Vullo","Vyky","Vyner","Vyse","Waal","Wachtel","Wachter","Wack","Waddell","Waddington","Waddle","Wade","Wadell","Wadesworth","Wadleigh","Wadlinger","Wadsworth","Waechter","Waers","Wager","Wagner","Wagoner","Wagshul","Wagstaff","Wahkuna","Wahl","Wahlstrom","Wailoo","Wain","Waine","Wainwright","Wait","Waite","Waiter","Wake","Wakeen","Wakefield","Wakerly","Waki","Walburga","Walcoff","Walcott","Walczak","Wald","Waldack","Waldemar","Walden","Waldman","Waldner","Waldo","Waldon","Waldos","Waldron","Wales","Walford","Waligore","Walke","Walker","Walkling","Wall","Wallace","Wallach","Wallache","Wallack","Wallas","Waller","Walley","Wallford","Walli","Wallie","Walling","Wallinga","Wallis","Walliw","Wallraff","Walls","Wally","Walrath","Walsh","Walston","Walt","Walter","Walters","Walther","Waltner","Walton","Walworth","Waly","Wampler","Wamsley","Wan","Wanda","Wandie","Wandis","Wandy","Wane","Waneta","Wanfried","Wang","Wanids","Wanonah","Wanyen","Wappes","Warchaw","Ward","Warde","Warden","Warder","Wardieu","Wardlaw","Wardle","Ware","Wareing","Warenne","Warfeld","Warfield","Warfold","Warford","Warfore","Warfourd","Warga","Warila","Waring","Warms","Warner","Warp","Warram","Warren","Warrenne","Warrick","Warrin","Warring","Warthman","Warton","Wartow","Warwick","Wash","Washburn","Washington","Washko","Wasserman","Wasson","Wassyngton","Wat","Watanabe","Waterer","Waterman","Waters","Watkin","Watkins","Watson","Watt","Wattenberg","Watters","Watts","Waugh","Wauters","Wavell","Waverley","Waverly","Wawro","Waxler","Waxman","Way","Waylan","Wayland","Waylen","Waylin","Waylon","Waynant","Wayne","Wayolle","Weaks","Wearing","Weasner","Weatherby","Weatherley","Weathers","Weaver","Web","Webb","Webber","Weber","Webster","Wedurn","Weed","Weeks","Wehner","Wehrle","Wei","Weibel","Weidar","Weide","Weider","Weidman","Weidner","Weig","Weight","Weigle","Weihs","Weikert","Weil","Weiler","Weiman","Wein","Weinberg","Weiner","Weinert","Weingarten","Weingartner","Weinhardt","Weinman","Weinreb","Weinrich","Weinshienk","Weinstein","Weinstock","Weintrob","Weir","Weirick","Weisbart","Weisberg","Weisbrodt","Weisburgh","Weiser","Weisler","Weisman","Weismann","Weiss","Weissberg","Weissman","Weissmann","Weitman","Weitzman","Weixel","Weksler","Welbie","Welby","Welch","Welcher","Welcome","Welcy","Weld","Weldon","Welford","Welker","Welles","Wellesley","Wellington","Wells","Welsh","Welton","Wenda","Wendall","Wendalyn","Wende","Wendel","Wendelin","Wendelina","Wendeline","Wendell","Wendi","Wendie","Wendin","Wendolyn","Wendt","Wendy","Wendye","Wenger","Wengert","Wenn","Wennerholn","Wenoa","Wenona","Wenonah","Wentworth","Wenz","Wera","Werbel","Werby","Werner","Wernher","Wernick","Wernsman","Werra","Wershba","Wertheimer","Wertz","Wes","Wesa","Wescott","Wesla","Wesle","Weslee","Wesley","Wessling","West","Westberg","Westbrook","Westbrooke","Wester","Westerfield","Westfahl","Westfall","Westhead","Westland","Westleigh","Westley","Westlund","Westmoreland","Westney","Weston","Westphal","Wetzel","Wetzell","Wexler","Wey","Weyermann","Weylin","Weywadt","Whale","Whalen","Whall","Whallon","Whang","Wharton","Whatley","Wheaton","Wheeler","Wheelwright","Whelan","Whetstone","Whiffen","Whiney","Whipple","Whit","Whitaker","Whitby","Whitcher","Whitcomb","White","Whitebook","Whitehouse","Whitehurst","Whitelaw","Whiteley","Whitford","Whiting","Whitman","Whitnell","Whitney","Whitson","Whittaker","Whittemore","Whitten","Whitver","Whorton","Whyte","Wiatt","Wiburg","Wichern","Wichman","Wickham","Wickman","Wickner","Wicks","Widera","Wie","Wiebmer","Wieche","Wiedmann","Wiencke","Wiener","Wier","Wieren","Wiersma","Wiese","Wiggins","Wight","Wightman","Wil","Wilber","Wilbert","Wilbur","Wilburn","Wilburt","Wilcox","Wilda","Wilde","Wildee","Wilden","Wilder","Wildermuth","Wildon","Wileen","Wilek","Wilen","Wiles","Wiley","Wilfred","Wilfreda","Wilfrid","Wilhelm","Wilhelmina","Wilhelmine","Wilhide","Wilie","Wilinski","Wilkens","Wilkey","Wilkie","Wilkins","Wilkinson","Wilkison","Will","Willa","Willabella","Willamina","Willard","Willcox","Willdon","Willem","Willet","Willett","Willetta","Willette","Willey","Willi","William","Williams","Williamsen","Williamson","Willie","Willin","Willing","Willis","Willman","Willmert","Willms","Willner","Willock","Willow","Wills","Willtrude","Willumsen","Willy","Willyt","Wilma","Wilmar","Wilmer","Wilmette","Wilmott","Wilona","Wilonah","Wilone","Wilow","Wilscam","Wilser","Wilsey","Wilson","Wilt","Wilterdink","Wilton","Wiltsey","Wiltshire","Wiltz","Wimsatt","Win","Wina","Wincer","Winchell","Winchester","Wind","Windham","Windsor","Windy","Windzer","Winebaum","Winer","Winfield","Winfred","Winfrid","Wing","Wini","Winifield","Winifred","Winikka","Winn","Winna","Winnah","Winne","Winni","Winnick","Winnie","Winnifred","Winny","Winograd","Winola","Winona","Winonah","Winou","Winser","Winshell","Winslow","Winson","Winsor","Winston","Winstonn","Winter","Winterbottom","Winters","Winther","Winthorpe","Winthrop","Winton","Winwaloe","Winzler","Wira","Wirth","Wise","Wiseman","Wiskind","Wisnicki","Wistrup","Wit","Witcher","Witha","Witherspoon","Witkin","Witt","Witte","Wittenburg","Wittie","Witty","Wivestad","Wivina","Wivinah","Wivinia","Wixted","Woehick","Woermer","Wohlen","Wohlert","Wojak","Wojcik","Wolbrom","Wolcott","Wolenik","Wolf","Wolfe","Wolff","Wolfgang","Wolfgram","Wolfie","Wolford","Wolfort","Wolfram","Wolfson","Wolfy","Wolgast","Wolk","Woll","Wollis","Wolpert","Wolsky","Womack","Won","Wonacott","Wong","Woo","Wood","Woodall","Woodberry","Woodcock","Woodford","Woodhead","Woodhouse","Woodie","Woodley","Woodman","Woodring","Woodrow","Woodruff","Woods","Woodson","Woodsum","Woodward","Woody","Woolcott","Wooldridge","Woolley","Woolson","Wooster","Wootan","Woothen","Wootten","Worden","Worl","Worlock","Worrell","Worsham","Worth","Worthington","Worthy","Wrand","Wren","Wrench","Wrennie","Wright","Wrightson","Wrigley","Wsan","Wu","Wulf","Wulfe","Wun","Wunder","Wurst","Wurster","Wurtz","Wyatan","Wyatt","Wyck","Wycoff","Wye","Wylde","Wylen","Wyler","Wylie","Wylma","Wyly","Wymore","Wyn","Wyndham","Wyne","Wynn","Wynne","Wynnie","Wynny","Wyon","Wystand","Xantha","Xanthe","Xanthus","Xavier","Xaviera","Xavler","Xena","Xenia","Xeno","Xenophon","Xenos","Xerxes","Xever","Ximena","Ximenes","Ximenez","Xylia","Xylina","Xylon","Xymenes","Yaakov","Yablon","Yacano","Yacov","Yaeger","Yael","Yager","Yahiya","Yaker","Yale","Yalonda","Yam","Yamauchi","Yanaton","Yance","Yancey","Yancy","Yand","Yank","Yankee","Yann","Yarak","Yard","Yardley","Yaron","Yarvis","Yasmeen","Yasmin","Yasmine","Yasu","Yasui","Yate","Yates","Yatzeck","Yaya","Yazbak","Yeargain","Yearwood","Yeaton","Yecies","Yee","Yeh","Yehudi","Yehudit","Yelena","Yelich","Yelmene","Yemane","Yeo","Yeorgi","Yerga","Yerkovich","Yerxa","Yesima","Yeta","Yetac","Yetah","Yetta","Yetti","Yettie","Yetty","Yeung","Yevette","Yi","Yila","Yim","Yirinec","Ylla","Ynes","Ynez","Yoho","Yoko","Yokoyama","Yokum","Yolanda","Yolande","Yolane","Yolanthe","Yona","Yonah","Yonatan","Yong","Yonina","Yonit","Yonita","Yoo","Yoong","Yordan","Yorgen","Yorgo","Yorgos","Yorick","York","Yorke","Yorker","Yoshi","Yoshiko","Yoshio","Youlton","Young","Younger","Younglove","Youngman","Youngran","Yousuf","Yovonnda","Ysabel","Yseult","Yseulta","Yseulte","Yuhas","Yuille","Yuji","Yuk","Yukio","Yul","Yule","Yulma","Yuma","Yumuk","Yun","Yunfei","Yung","Yunick","Yup","Yuri","Yuria","Yurik","Yursa","Yurt","Yusem","Yusuk","Yuu","Yuzik","Yves","Yvette","Yvon","Yvonne","Yvonner","Yvor","Zabrina","Zabrine","Zacarias","Zaccaria","Zacek","Zach","Zachar","Zacharia","Zachariah","Zacharias","Zacharie","Zachary","Zacherie","Zachery","Zack","Zackariah","Zacks","Zadack","Zadoc","Zahara","Zahavi","Zaid","Zailer","Zak","Zakaria","Zakarias","Zalea","Zales","Zaller","Zalucki","Zamir","Zamora","Zampardi","Zampino","Zandra","Zandt","Zane","Zaneski","Zaneta","Zannini","Zantos","Zanze","Zara","Zaragoza","Zarah","Zared","Zaremski","Zarger","Zaria","Zarla","Zashin","Zaslow","Zasuwa","Zavala","Zavras","Zawde","Zea","Zealand","Zeb","Zeba","Zebada","Zebadiah","Zebapda","Zebe","Zebedee","Zebulen","Zebulon","Zechariah","Zeculon","Zed","Zedekiah","Zeeba","Zeena","Zehe","Zeidman","Zeiger","Zeiler","Zeitler","Zeke","Zel","Zela","Zelazny","Zelda","Zelde","Zelig","Zelikow","Zelle","Zellner","Zelma","Zelten","Zena","Zenas","Zenda","Zendah","Zenger","Zenia","Zennas","Zennie","Zenobia","Zeph","Zephan","Zephaniah","Zeralda","Zerelda","Zerk","Zerla","Zerlina","Zerline","Zeta","Zetana","Zetes","Zetta","Zeus","Zhang","Zia","Ziagos","Zicarelli","Ziegler","Zielsdorf","Zigmund","Zigrang","Ziguard","Zilber","Zildjian","Zilla","Zillah","Zilvia","Zima","Zimmer","Zimmerman","Zimmermann","Zina","Zinah","Zinck","Zindman","Zingale","Zingg","Zink","Zinn","Zinnes","Zins","Zipah","Zipnick","Zippel","Zippora","Zipporah","Zirkle","Zischke","Zita","Zitah","Zitella","Zitvaa","Ziwot","Zoa","Zoara","Zoarah","Zoba","Zobe","Zobias","Zobkiw","Zoe","Zoeller","Zoellick","Zoes","Zoha","Zohar","Zohara","Zoi","Zoie","Zoila","Zoilla","Zola","Zoldi","Zoller","Zollie","Zolly","Zolnay","Zolner","Zoltai","Zonda","Zondra","Zonnya","Zora","Zorah","Zorana","Zorina","Zorine","Zosema","Zosi","Zosima","Zoubek","Zrike","Zsa","Zsa Zsa","Zsazsa","Zsolway","Zubkoff","Zucker","Zuckerman","Zug","Zulch","Zuleika","Zulema","Zullo","Zumstein","Zumwalt","Zurek","Zurheide","Zurkow","Zurn","Zusman","Zuzana","Zwart","Zweig","Zwick","Zwiebel","Zysk"];
\ No newline at end of file
+pub static LAST_NAME: [&str; 21985] = ["Aaberg","Aalst","Aara","Aaren","Aarika","Aaron","Aaronson","Ab","Aba","Abad","Abagael","Abagail","Abana","Abate","Abba","Abbate","Abbe","Abbey","Abbi","Abbie","Abbot","Abbotsen","Abbotson","Abbotsun","Abbott","Abbottson","Abby","Abbye","Abdel","Abdella","Abdu","Abdul","Abdulla","Abe","Abebi","Abel","Abelard","Abell","Abercromby","Abernathy","Abernon","Abert","Abeu","Abey","Abie","Abigael","Abigail","Abigale","Abijah","Abisha","Abisia","Abixah","Abner","Aborn","Abott","Abra","Abraham","Abrahams","Abrahamsen","Abrahan","Abram","Abramo","Abrams","Abramson","Abran","Abroms","Absa","Absalom","Abshier","Acacia","Acalia","Accalia","Ace","Acey","Acherman","Achilles","Achorn","Acie","Acima","Acker","Ackerley","Ackerman","Ackler","Ackley","Acquah","Acus","Ad","Ada","Adabel","Adabelle","Adachi","Adah","Adaha","Adai","Adaiha","Adair","Adal","Adala","Adalai","Adalard","Adalbert","Adalheid","Adali","Adalia","Adaliah","Adalie","Adaline","Adall","Adallard","Adam","Adama","Adamec","Adamek","Adamik","Adamina","Adaminah","Adamis","Adamo","Adamok","Adams","Adamsen","Adamski","Adamson","Adamsun","Adan","Adao","Adar","Adara","Adaurd","Aday","Adda","Addam","Addi","Addia","Addie","Addiego","Addiel","Addis","Addison","Addy","Ade","Adebayo","Adel","Adela","Adelaida","Adelaide","Adelaja","Adelbert","Adele","Adelheid","Adelia","Adelice","Adelina","Adelind","Adeline","Adella","Adelle","Adelpho","Adelric","Adena","Ader","Adest","Adey","Adham","Adhamh","Adhern","Adi","Adiana","Adiel","Adiell","Adigun","Adila","Adim","Adin","Adina","Adine","Adis","Adkins","Adlai","Adlar","Adlare","Adlay","Adlee","Adlei","Adler","Adley","Adna","Adnah","Adne","Adnopoz","Ado","Adolf","Adolfo","Adolph","Adolphe","Adolpho","Adolphus","Adon","Adonis","Adora","Adore","Adoree","Adorl","Adorne","Adrea","Adrell","Adria","Adriaens","Adrial","Adrian","Adriana","Adriane","Adrianna","Adrianne","Adriano","Adriel","Adriell","Adrien","Adriena","Adriene","Adrienne","Adur","Aekerly","Aelber","Aenea","Aeneas","Aeneus","Aeniah","Aenneea","Aeriel","Aeriela","Aeriell","Affer","Affra","Affrica","Afra","Africa","Africah","Afrika","Afrikah","Afton","Ag","Agace","Agamemnon","Agan","Agata","Agate","Agatha","Agathe","Agathy","Agbogla","Agee","Aggappe","Aggappera","Aggappora","Aggarwal","Aggi","Aggie","Aggri","Aggy","Agle","Agler","Agna","Agnella","Agnes","Agnese","Agnesse","Agneta","Agnew","Agnola","Agostino","Agosto","Agretha","Agripina","Agrippina","Aguayo","Agueda","Aguie","Aguste","Agustin","Ahab","Aharon","Ahasuerus","Ahders","Ahearn","Ahern","Ahl","Ahlgren","Ahmad","Ahmar","Ahmed","Ahola","Aholah","Aholla","Ahoufe","Ahouh","Ahrendt","Ahrens","Ahron","Aia","Aida","Aidan","Aiden","Aiello","Aigneis","Aiken","Aila","Ailbert","Aile","Ailee","Aileen","Ailene","Ailey","Aili","Ailin","Ailina","Ailis","Ailsa","Ailssa","Ailsun","Ailyn","Aime","Aimee","Aimil","Aimo","Aindrea","Ainslee","Ainsley","Ainslie","Ainsworth","Airel","Aires","Airla","Airlee","Airlia","Airliah","Airlie","Aisha","Ajani","Ajax","Ajay","Ajit","Akanke","Akel","Akela","Aker","Akerboom","Akerley","Akers","Akeyla","Akeylah","Akili","Akim","Akin","Akins","Akira","Aklog","Aksel","Aksoyn","Al","Alabaster","Alage","Alain","Alaine","Alair","Alake","Alameda","Alan","Alana","Alanah","Aland","Alane","Alanna","Alano","Alansen","Alanson","Alard","Alaric","Alarice","Alarick","Alarise","Alasdair","Alastair","Alasteir","Alaster","Alatea","Alathia","Alayne","Alba","Alban","Albarran","Albemarle","Alben","Alber","Alberic","Alberik","Albers","Albert","Alberta","Albertina","Albertine","Alberto","Albertson","Albie","Albin","Albina","Albion","Alboran","Albrecht","Albric","Albright","Albur","Alburg","Alburga","Alby","Alcina","Alcine","Alcinia","Alcock","Alcot","Alcott","Alcus","Alda","Aldarcie","Aldarcy","Aldas","Alded","Alden","Aldercy","Alderman","Alderson","Aldin","Aldis","Aldo","Aldon","Aldora","Aldos","Aldous","Aldred","Aldredge","Aldric","Aldrich","Aldridge","Alduino","Aldus","Aldwin","Aldwon","Alec","Alecia","Aleck","Aleda","Aleece","Aleedis","Aleen","Aleetha","Alegre","Alejandra","Alejandrina","Alejandro","Alejo","Alejoa","Alek","Aleksandr","Alena","Alene","Alenson","Aleras","Aleris","Aleron","Alesandrini","Alessandra","Alessandro","Aleta","Aletha","Alethea","Alethia","Aletta","Alex","Alexa","Alexander","Alexandr","Alexandra","Alexandre","Alexandria","Alexandrina","Alexandro","Alexandros","Alexei","Alexi","Alexia","Alexina","Alexine","Alexio","Alexis","Aley","Aleydis","Alf","Alfeus","Alfi","Alfie","Alfons","Alfonse","Alfonso","Alfonzo","Alford","Alfred","Alfreda","Alfredo","Alfy","Algar","Alger","Algernon","Algie","Alguire","Algy","Ali","Alia","Aliber","Alic","Alica","Alice","Alicea","Alicia","Alick","Alida","Alidia","Alidis","Alidus","Alie","Alika","Alikee","Alina","Aline","Alinna","Alis","Alisa","Alisan","Alisander","Alisen","Alisha","Alisia","Alison","Alissa","Alistair","Alister","Alisun","Alita","Alitha","Alithea","Alithia","Alitta","Alius","Alix","Aliza","Alla","Allain","Allan","Allana","Allanson","Allard","Allare","Allayne","Allbee","Allcot","Alleen","Allegra","Allen","Allene","Alleras","Allerie","Alleris","Allerus","Alley","Alleyn","Alleyne","Alli","Allianora","Alliber","Allie","Allin","Allina","Allis","Allisan","Allison","Allissa","Allista","Allister","Allistir","Allix","Allmon","Allred","Allrud","Allsopp","Allsun","Allveta","Allwein","Allx","Ally","Allyce","Allyn","Allys","Allyson","Alma","Almallah","Almeda","Almeeta","Almeida","Almena","Almeria","Almeta","Almira","Almire","Almita","Almond","Almund","Alo","Alodee","Alodi","Alodie","Aloin","Aloise","Aloisia","Aloisius","Aloke","Alon","Alonso","Alonzo","Aloysia","Aloysius","Alper","Alpers","Alpert","Alphard","Alpheus","Alphonsa","Alphonse","Alphonsine","Alphonso","AlrZc","Alric","Alrich","Alrick","Alroi","Alroy","Also","Alston","Alsworth","Alta","Altaf","Alten","Althea","Althee","Altheta","Altis","Altman","Alton","Aluin","Aluino","Alurd","Alurta","Alva","Alvan","Alvar","Alvarez","Alver","Alvera","Alverson","Alverta","Alves","Alveta","Alviani","Alvie","Alvin","Alvina","Alvinia","Alvira","Alvis","Alvita","Alvord","Alvy","Alwin","Alwitt","Alwyn","Alyce","Alyda","Alyose","Alyosha","Alys","Alysa","Alyse","Alysia","Alyson","Alysoun","Alyss","Alyssa","Alyworth","Ama","Amabel","Amabelle","Amabil","Amadas","Amadeo","Amadeus","Amadis","Amado","Amador","Amadus","Amal","Amalbena","Amalberga","Amalbergas","Amalburga","Amalea","Amalee","Amaleta","Amalia","Amalie","Amalita","Amalle","Aman","Amand","Amanda","Amandi","Amandie","Amando","Amandy","Amann","Amar","Amara","Amaral","Amaras","Amarette","Amargo","Amari","Amarillas","Amarillis","Amaris","Amary","Amaryl","Amaryllis","Amasa","Amata","Amathist","Amathiste","Amati","Amato","Amatruda","Amaty","Amber","Amberly","Ambert","Ambie","Amble","Ambler","Ambrogino","Ambrogio","Ambros","Ambrosane","Ambrose","Ambrosi","Ambrosia","Ambrosine","Ambrosio","Ambrosius","Ambur","Amby","Ame","Amedeo","Amelia","Amelie","Amelina","Ameline","Amelita","Amena","Amend","Amerigo","Amero","Amersham","Amery","Ames","Amethist","Amethyst","Ami","Amias","Amice","Amick","Amie","Amiel","Amieva","Amii","Amil","Amin","Aminta","Amir","Amitie","Amity","Amling","Ammadas","Ammadis","Ammamaria","Ammann","Ammon","Amoakuh","Amor","Amora","Amoreta","Amorete","Amorette","Amorita","Amoritta","Amory","Amos","Amr","Amrita","Amsden","Amund","Amy","Amyas","Amye","An","Ana","Anabal","Anabel","Anabella","Anabelle","Anagnos","Analiese","Analise","Anallese","Anallise","Anana","Ananna","Anastas","Anastase","Anastasia","Anastasie","Anastasio","Anastasius","Anastassia","Anastatius","Anastice","Anastos","Anatol","Anatola","Anatole","Anatolio","Anatollo","Ancalin","Ancel","Ancelin","Anceline","Ancell","Anchie","Ancier","Ancilin","Andee","Andeee","Andel","Ander","Anderea","Anderegg","Anderer","Anders","Andersen","Anderson","Andert","Andi","Andie","Andonis","Andra","Andrade","Andras","Andre","Andrea","Andreana","Andreas","Andree","Andrei","Andrej","Andrel","Andres","Andrew","Andrews","Andrey","Andri","Andria","Andriana","Andrien","Andriette","Andris","Andromache","Andromada","Andromeda","Andromede","Andros","Androw","Andrus","Andryc","Andy","Anestassia","Anet","Anett","Anetta","Anette","Aney","Angadreme","Angadresma","Ange","Angel","Angela","Angele","Angeli","Angelia","Angelica","Angelico","Angelika","Angelina","Angeline","Angelique","Angelis","Angelita","Angell","Angelle","Angelo","Angi","Angie","Angil","Angle","Anglim","Anglo","Angrist","Angus","Angy","Anh","Ania","Aniakudo","Anica","Aniela","Anil","Anis","Anissa","Anita","Anitra","Aniweta","Anjali","Anjanette","Anjela","Ankeny","Ankney","Ann","Ann-Marie","Anna","Anna-Diana","Anna-Diane","Anna-Maria","Annabal","Annabel","Annabela","Annabell","Annabella","Annabelle","Annadiana","Annadiane","Annalee","Annaliese","Annalise","Annamaria","Annamarie","Anne","Anne-Corinne","Anne-Marie","Annecorinne","Anneliese","Annelise","Annemarie","Annetta","Annette","Anni","Annia","Annice","Annie","Anniken","Annis","Annissa","Annmaria","Annmarie","Annnora","Annora","Annorah","Annunciata","Anny","Anora","Anse","Ansel","Ansela","Ansell","Anselm","Anselma","Anselme","Anselmi","Anselmo","Ansilma","Ansilme","Ansley","Anson","Anstice","Anstus","Antebi","Anthe","Anthea","Anthia","Anthiathia","Anthony","Antin","Antipas","Antipus","Antoine","Antoinetta","Antoinette","Anton","Antone","Antonella","Antonetta","Antoni","Antonia","Antonie","Antonietta","Antonin","Antonina","Antonino","Antonio","Antonius","Antons","Antony","Antrim","Anurag","Anuska","Any","Anya","Anyah","Anzovin","Apfel","Apfelstadt","Apgar","Aphra","Aphrodite","Apicella","Apollo","Apollus","Apostles","Appel","Apple","Appleby","Appledorf","Applegate","Appleton","Appolonia","Apps","April","Aprile","Aprilette","Apthorp","Apul","Ara","Arabeila","Arabel","Arabela","Arabele","Arabella","Arabelle","Arad","Arakawa","Araldo","Aramanta","Aramen","Aramenta","Araminta","Aran","Arand","Arathorn","Arbe","Arber","Arbuckle","Arch","Archaimbaud","Archambault","Archangel","Archer","Archibald","Archibaldo","Archibold","Archie","Archle","Archy","Ard","Arda","Ardath","Arde","Ardeen","Ardeha","Ardehs","Ardel","Ardelia","Ardelis","Ardell","Ardella","Ardelle","Arden","Ardene","Ardenia","Ardeth","Ardie","Ardin","Ardine","Ardis","Ardisj","Ardith","Ardme","Ardolino","Ardra","Ardrey","Ardussi","Ardy","Ardyce","Ardys","Ardyth","Arel","Arela","Arella","Arelus","Aret","Areta","Aretha","Aretina","Aretta","Arette","Arezzini","Argent","Argile","Argus","Argyle","Argyres","Arhna","Ari","Aria","Ariadne","Ariana","Ariane","Arianie","Arianna","Arianne","Aribold","Aric","Arica","Arick","Aridatha","Arie","Ariel","Ariela","Ariella","Arielle","Ariew","Arin","Ario","Arissa","Aristotle","Arita","Arjan","Arjun","Ark","Arlan","Arlana","Arlee","Arleen","Arlen","Arlena","Arlene","Arleta","Arlette","Arley","Arleyne","Arlie","Arliene","Arlin","Arlina","Arlinda","Arline","Arlo","Arlon","Arluene","Arly","Arlyn","Arlyne","Arlynne","Armalda","Armalla","Armallas","Arman","Armand","Armanda","Armando","Armbrecht","Armbruster","Armelda","Armil","Armilda","Armilla","Armillas","Armillda","Armillia","Armin","Armington","Armitage","Armond","Armstrong","Armyn","Arnaldo","Arnaud","Arndt","Arne","Arnelle","Arney","Arni","Arnie","Arno","Arnold","Arnoldo","Arnon","Arnst","Arnuad","Arnulfo","Arny","Arola","Aron","Arondel","Arondell","Aronoff","Aronow","Aronson","Arquit","Arratoon","Arri","Arria","Arrio","Arron","Arst","Art","Arta","Artair","Artamas","Arte","Artema","Artemas","Artemis","Artemisa","Artemisia","Artemus","Arther","Arthur","Artie","Artima","Artimas","Artina","Artur","Arturo","Artus","Arty","Aruabea","Arun","Arundel","Arundell","Arv","Arva","Arvad","Arvell","Arvid","Arvie","Arvin","Arvind","Arvo","Arvonio","Arvy","Ary","Aryn","As","Asa","Asabi","Asante","Asaph","Asare","Aschim","Ase","Asel","Ash","Asha","Ashbaugh","Ashbey","Ashby","Ashelman","Ashely","Asher","Ashford","Ashia","Ashien","Ashil","Ashjian","Ashla","Ashlan","Ashlee","Ashleigh","Ashlen","Ashley","Ashli","Ashlie","Ashlin","Ashling","Ashly","Ashman","Ashmead","Ashok","Ashraf","Ashti","Ashton","Ashwell","Ashwin","Asia","Askari","Askwith","Aslam","Asp","Aspa","Aspasia","Aspia","Asquith","Assisi","Asta","Astera","Asteria","Astor","Astra","Astraea","Astrahan","Astrea","Astred","Astri","Astrid","Astrix","Astto","Asuncion","Atal","Atalanta","Atalante","Atalanti","Atalaya","Atalayah","Atalee","Ataliah","Atalie","Atalya","Atcliffe","Athal","Athalee","Athalia","Athalie","Athalla","Athallia","Athelstan","Athena","Athene","Athenian","Athey","Athiste","Atiana","Atkins","Atkinson","Atlanta","Atlante","Atlas","Atlee","Atonsah","Atrice","Atronna","Attah","Attalanta","Attalie","Attenborough","Attenweiler","Atterbury","Atthia","Attlee","Attwood","Atul","Atwater","Atwekk","Atwood","Atworth","Au","Aubarta","Aube","Auberbach","Auberon","Aubert","Auberta","Aubigny","Aubin","Aubine","Aubree","Aubreir","Aubrette","Aubrey","Aubrie","Aubry","Auburn","Auburta","Aubyn","Audette","Audi","Audie","Audley","Audly","Audra","Audras","Audre","Audres","Audrey","Audri","Audrie","Audris","Audrit","Audry","Audrye","Audsley","Audun","Audwen","Audwin","Audy","Auerbach","Aufmann","Augie","August","Augusta","Auguste","Augustin","Augustina","Augustine","Augusto","Augustus","Augy","Aulea","Auliffe","Aun","Aundrea","Aunson","Aura","Aurea","Aurel","Aurelea","Aurelia","Aurelie","Aurelio","Aurelius","Auria","Auric","Aurie","Aurilia","Aurita","Aurlie","Auroora","Aurora","Aurore","Aurthur","Ause","Austen","Austin","Austina","Austine","Auston","Australia","Austreng","Autrey","Autry","Autum","Autumn","Auvil","Av","Ava","Avan","Avaria","Ave","Avelin","Aveline","Avera","Averell","Averi","Averil","Averill","Averir","Avery","Averyl","Avi","Avictor","Avie","Avigdor","Avilla","Avis","Avitzur","Aviv","Aviva","Avivah","Avner","Avra","Avraham","Avram","Avril","Avrit","Avrom","Avron","Avruch","Awad","Ax","Axe","Axel","Aylmar","Aylmer","Aylsworth","Aylward","Aymer","Ayn","Aynat","Ayo","Ayres","Azal","Azalea","Azaleah","Azar","Azarcon","Azaria","Azarria","Azelea","Azeria","Aziza","Azpurua","Azral","Azriel","Baal","Baalbeer","Baalman","Bab","Babara","Babb","Babbette","Babbie","Babby","Babcock","Babette","Babita","Babs","Bac","Bacchus","Bach","Bachman","Backer","Backler","Bacon","Badger","Badr","Baecher","Bael","Baelbeer","Baer","Baerl","Baerman","Baese","Bagger","Baggett","Baggott","Baggs","Bagley","Bahner","Bahr","Baiel","Bail","Bailar","Bailey","Bailie","Baillie","Baillieu","Baily","Bain","Bainbridge","Bainbrudge","Bainter","Baird","Baiss","Bajaj","Bak","Bakeman","Bakemeier","Baker","Bakerman","Bakki","Bal","Bala","Balas","Balbinder","Balbur","Balcer","Balch","Balcke","Bald","Baldridge","Balduin","Baldwin","Bale","Baler","Balf","Balfore","Balfour","Balkin","Ball","Ballard","Balliett","Balling","Ballinger","Balliol","Ballman","Ballou","Balmuth","Balough","Balsam","Balthasar","Balthazar","Bamberger","Bambi","Bambie","Bamby","Bamford","Ban","Bancroft","Bandeen","Bander","Bandler","Bandur","Banebrudge","Banerjee","Bang","Bank","Banks","Banky","Banna","Bannasch","Bannerman","Bannister","Bannon","Banquer","Banwell","Baptist","Baptista","Baptiste","Baptlsta","Bar","Bara","Barabas","Barabbas","Baram","Baras","Barayon","Barb","Barbabas","Barbabra","Barbara","Barbara-Anne","Barbaraanne","Barbarese","Barbaresi","Barbe","Barbee","Barber","Barbette","Barbey","Barbi","Barbie","Barbour","Barboza","Barbra","Barbur","Barbuto","Barby","Barcellona","Barclay","Barcot","Barcroft","Barcus","Bard","Barde","Barden","Bardo","Barfuss","Barger","Bari","Barimah","Barina","Barker","Barkley","Barling","Barlow","Barmen","Barn","Barna","Barnaba","Barnabas","Barnabe","Barnaby","Barnard","Barncard","Barnebas","Barnes","Barnet","Barnett","Barney","Barnie","Barnum","Barny","Barolet","Baron","Barr","Barra","Barrada","Barram","Barraza","Barren","Barret","Barrett","Barri","Barrie","Barrington","Barris","Barron","Barrow","Barrus","Barry","Barsky","Barstow","Bart","Barta","Bartel","Barth","Barthel","Barthelemy","Barthol","Barthold","Bartholemy","Bartholomeo","Bartholomeus","Bartholomew","Bartie","Bartko","Bartle","Bartlet","Bartlett","Bartley","Bartolemo","Bartolome","Bartolomeo","Barton","Bartosch","Bartram","Barty","Baruch","Barvick","Bary","Baryram","Bascio","Bascomb","Base","Baseler","Basham","Bashee","Bashemath","Bashemeth","Bashuk","Basia","Basil","Basile","Basilio","Basilius","Basir","Baskett","Bass","Basset","Bassett","Basso","Bast","Bastian","Bastien","Bat","Batchelor","Bate","Baten","Bates","Batha","Bathelda","Bathesda","Bathilda","Batholomew","Bathsheb","Bathsheba","Bathsheeb","Bathulda","Batish","Batista","Batory","Batruk","Batsheva","Battat","Battista","Battiste","Batty","Baudelaire","Baudin","Baudoin","Bauer","Baugh","Baum","Baumann","Baumbaugh","Baun","Bausch","Bauske","Bautista","Bautram","Bax","Baxie","Baxter","Baxy","Bay","Bayard","Bayer","Bayless","Baylor","Bayly","Baynebridge","Bazar","Bazil","Bazluke","Bea","Beach","Beacham","Beal","Beale","Beall","Bealle","Bean","Beane","Beaner","Bear","Bearce","Beard","Beare","Bearnard","Beasley","Beaston","Beata","Beatrice","Beatrisa","Beatrix","Beatriz","Beattie","Beatty","Beau","Beauchamp","Beaudoin","Beaufert","Beaufort","Beaulieu","Beaumont","Beauregard","Beauvais","Beaver","Bebe","Beberg","Becca","Bechler","Becht","Beck","Becka","Becker","Beckerman","Becket","Beckett","Becki","Beckie","Beckman","Becky","Bedad","Bedelia","Bedell","Bedwell","Bee","Beebe","Beeck","Beedon","Beekman","Beera","Beesley","Beeson","Beetner","Beffrey","Bega","Begga","Beghtol","Behah","Behka","Behl","Behlau","Behlke","Behm","Behn","Behnken","Behre","Behrens","Beichner","Beilul","Bein","Beisel","Beitch","Beitnes","Beitris","Beitz","Beka","Bekah","Bekelja","Beker","Bekha","Bekki","Bel","Bela","Belak","Belamy","Belanger","Belayneh","Belcher","Belda","Belden","Belding","Belen","Belford","Belia","Belicia","Belier","Belinda","Belita","Bell","Bella","Bellamy","Bellanca","Bellaude","Bellda","Belldame","Belldas","Belle","Beller","Bellew","Bellina","Bellis","Bello","Belloir","Belmonte","Belshin","Belsky","Belter","Beltran","Belva","Belvia","Ben","Bena","Bencion","Benco","Bender","Bendick","Bendicta","Bendicty","Bendite","Bendix","Benedetta","Benedetto","Benedic","Benedick","Benedict","Benedicta","Benedicto","Benedikt","Benedikta","Benedix","Benenson","Benetta","Benge","Bengt","Benia","Beniamino","Benil","Benilda","Benildas","Benildis","Benioff","Benis","Benisch","Benita","Benito","Benjamen","Benjamin","Benji","Benjie","Benjy","Benkley","Benn","Bennet","Bennett","Benni","Bennie","Bennink","Bennion","Bennir","Benny","Benoit","Benoite","Bensen","Bensky","Benson","Bent","Bentlee","Bentley","Bently","Benton","Benyamin","Benzel","Beora","Beore","Ber","Berard","Berardo","Berck","Berenice","Beret","Berey","Berfield","Berg","Berga","Bergeman","Bergen","Berger","Bergerac","Bergeron","Bergess","Berget","Bergh","Berghoff","Bergin","Berglund","Bergman","Bergmann","Bergmans","Bergquist","Bergren","Bergstein","Bergstrom","Bergwall","Berhley","Berk","Berke","Berkeley","Berkie","Berkin","Berkley","Berkly","Berkman","Berkow","Berkshire","Berky","Berl","Berlauda","Berlin","Berlinda","Berliner","Berlyn","Berman","Bern","Berna","Bernadene","Bernadette","Bernadina","Bernadine","Bernard","Bernardi","Bernardina","Bernardine","Bernardo","Bernarr","Bernat","Berne","Bernelle","Berner","Berners","Berneta","Bernete","Bernetta","Bernette","Bernhard","Berni","Bernice","Bernie","Bernita","Bernj","Berns","Bernstein","Bernt","Berny","Berri","Berrie","Berriman","Berry","Berstine","Bert","Berta","Bertasi","Berte","Bertelli","Bertero","Bertha","Berthe","Berthold","Berthoud","Berti","Bertie","Bertila","Bertilla","Bertina","Bertine","Bertle","Bertold","Bertolde","Berton","Bertram","Bertrand","Bertrando","Bertsche","Berty","Berwick","Beryl","Beryle","Beshore","Besnard","Bess","Besse","Bessie","Bessy","Best","Beth","Bethanne","Bethany","Bethel","Bethena","Bethesda","Bethesde","Bethezel","Bethina","Betsey","Betsy","Betta","Bette","Bette-Ann","Betteann","Betteanne","Bettencourt","Betthel","Betthezel","Betthezul","Betti","Bettina","Bettine","Betty","Bettye","Bettzel","Betz","Beulah","Beuthel","Beutler","Beutner","Bev","Bevan","Bevash","Bever","Beverie","Beverle","Beverlee","Beverley","Beverlie","Beverly","Bevers","Bevin","Bevis","Bevon","Bevus","Bevvy","Beyer","Bezanson","Bhatt","Bhayani","Biagi","Biagio","Biamonte","Bianca","Biancha","Bianchi","Bianka","Bibbie","Bibby","Bibbye","Bibeau","Bibi","Bible","Bick","Bickart","Bicknell","Biddick","Biddie","Biddle","Biddy","Bidget","Bidle","Biebel","Biegel","Bierman","Biernat","Bigelow","Bigford","Bigg","Biggs","Bigler","Bigner","Bigod","Bigot","Bik","Bikales","Bil","Bilbe","Bilek","Biles","Bili","Bilicki","Bill","Billat","Bille","Billen","Billi","Billie","Billmyre","Bills","Billy","Billye","Bilow","Bilski","Bina","Binah","Bindman","Binetta","Binette","Bing","Bink","Binky","Binni","Binnie","Binnings","Binny","Biondo","Birch","Birchard","Birck","Bird","Birdella","Birdie","Birdt","Birecree","Birgit","Birgitta","Birk","Birkett","Birkle","Birkner","Birmingham","Biron","Bish","Bishop","Bissell","Bisset","Bithia","Bittencourt","Bitthia","Bittner","Bivins","Bixby","Bixler","Bjork","Bjorn","Black","Blackburn","Blackington","Blackman","Blackmore","Blackmun","Blackstock","Blackwell","Blader","Blain","Blaine","Blainey","Blair","Blaire","Blaise","Blake","Blakelee","Blakeley","Blakely","Blalock","Blanc","Blanca","Blanch","Blancha","Blanchard","Blanche","Blanchette","Bland","Blandina","Blanding","Blane","Blank","Blanka","Blankenship","Blas","Blase","Blaseio","Blasien","Blasius","Blatman","Blatt","Blau","Blayne","Blayze","Blaze","Bledsoe","Bleier","Blen","Blessington","Blight","Blim","Blinni","Blinnie","Blinny","Bliss","Blisse","Blithe","Bloch","Block","Blockus","Blodget","Blodgett","Bloem","Blondell","Blondelle","Blondie","Blondy","Blood","Bloom","Bloomer","Blossom","Blount","Bloxberg","Bluefarb","Bluefield","Bluh","Bluhm","Blum","Bluma","Blumenfeld","Blumenthal","Blunk","Blunt","Blus","Blynn","Blythe","Bo","Boak","Boar","Boardman","Boarer","Boaten","Boatwright","Bob","Bobbe","Bobbee","Bobbette","Bobbi","Bobbie","Bobby","Bobbye","Bobette","Bobina","Bobine","Bobinette","Bobker","Bobseine","Bock","Bocock","Bodi","Bodkin","Bodnar","Bodrogi","Bodwell","Body","Boehike","Boehmer","Boeke","Boelter","Boesch","Boeschen","Boff","Boffa","Bogart","Bogey","Boggers","Boggs","Bogie","Bogoch","Bogosian","Bogusz","Bohannon","Bohaty","Bohi","Bohlen","Bohlin","Bohman","Bohner","Bohon","Bohrer","Bohs","Bohun","Boice","Boigie","Boiney","Bois","Bolan","Boland","Bolanger","Bolen","Boles","Boleslaw","Boleyn","Bolger","Bolitho","Bollay","Bollen","Bolling","Bollinger","Bolme","Bolt","Bolte","Bolten","Bolton","Bomke","Bonacci","Bonaparte","Bonar","Bond","Bondie","Bondon","Bondy","Bone","Boni","Boniface","Bonilla","Bonina","Bonine","Bonis","Bonita","Bonn","Bonne","Bonneau","Bonnee","Bonnell","Bonner","Bonnes","Bonnette","Bonney","Bonni","Bonnibelle","Bonnice","Bonnie","Bonns","Bonny","Bonucci","Booker","Booma","Boone","Boonie","Boony","Boor","Boorer","Boorman","Boot","Boote","Booth","Boothe","Boothman","Booze","Bopp","Bor","Bora","Borchers","Borchert","Bord","Borden","Bordie","Bordiuk","Bordy","Bore","Borek","Borer","Bores","Borg","Borgeson","Boris","Bork","Borlase","Borlow","Borman","Born","Bornie","Bornstein","Borras","Borrell","Borreri","Borries","Borroff","Borszcz","Bortman","Bortz","Boru","Bosch","Bose","Boser","Bosson","Bostow","Boswall","Boswell","Botnick","Botsford","Bottali","Botti","Botzow","Bouchard","Boucher","Bouchier","Boudreaux","Bough","Boulanger","Bouldon","Bouley","Bound","Bounds","Bourgeois","Bourke","Bourn","Bourne","Bourque","Boutis","Bouton","Bouzoun","Bove","Bovill","Bow","Bowden","Bowe","Bowen","Bower","Bowerman","Bowers","Bowes","Bowie","Bowlds","Bowler","Bowles","Bowman","Bowne","Bowra","Bowrah","Bowyer","Box","Boy","Boyce","Boycey","Boycie","Boyd","Boyden","Boyer","Boyes","Boykins","Boylan","Boylston","Boynton","Boys","Boyse","Boyt","Bozovich","Bozuwa","Braasch","Brabazon","Braca","Bracci","Brace","Brackely","Brackett","Brad","Bradan","Brade","Braden","Bradeord","Brader","Bradford","Bradlee","Bradleigh","Bradley","Bradly","Bradman","Bradney","Bradshaw","Bradski","Bradstreet","Bradway","Bradwell","Brady","Braeunig","Brag","Brahear","Brainard","Bram","Bramwell","Bran","Brana","Branca","Branch","Brand","Brandais","Brande","Brandea","Branden","Brandenburg","Brander","Brandes","Brandi","Brandice","Brandie","Brandise","Brandon","Brandt","Brandtr","Brandwein","Brandy","Brandyn","Branen","Branham","Brannon","Branscum","Brant","Brantley","Brasca","Brass","Braswell","Brathwaite","Bratton","Braun","Braunstein","Brause","Bravar","Bravin","Brawley","Brawner","Bray","Braynard","Brazee","Breana","Breanne","Brear","Breban","Brebner","Brecher","Brechtel","Bred","Bree","Breech","Breed","Breen","Breena","Breeze","Breger","Brelje","Bremble","Bremen","Bremer","Bremser","Bren","Brena","Brenan","Brenda","Brendan","Brenden","Brendin","Brendis","Brendon","Brenk","Brenn","Brenna","Brennan","Brennen","Brenner","Brent","Brenton","Brentt","Brenza","Bresee","Breskin","Brest","Bret","Brett","Brew","Brewer","Brewster","Brey","Brezin","Bria","Brian","Briana","Brianna","Brianne","Briano","Briant","Brice","Brick","Bricker","Bride","Bridge","Bridges","Bridget","Bridgette","Bridgid","Bridie","Bridwell","Brie","Brien","Brier","Brieta","Brietta","Brig","Brigette","Brigg","Briggs","Brigham","Bright","Brightman","Brighton","Brigid","Brigida","Brigit","Brigitta","Brigitte","Brill","Brina","Brindell","Brindle","Brine","Briney","Bringhurst","Brink","Brinkema","Brinn","Brinna","Brinson","Briny","Brion","Briscoe","Bristow","Brit","Brita","Britney","Britni","Britt","Britta","Brittain","Brittan","Brittaney","Brittani","Brittany","Britte","Britteny","Brittne","Brittnee","Brittney","Brittni","Britton","Brnaba","Brnaby","Broadbent","Brock","Brockie","Brocklin","Brockwell","Brocky","Brod","Broddie","Broddy","Brodench","Broder","Broderic","Broderick","Brodeur","Brodie","Brodsky","Brody","Broeder","Broek","Broeker","Brogle","Broida","Brok","Brom","Bromleigh","Bromley","Bron","Bronder","Bronez","Bronk","Bronnie","Bronny","Bronson","Bronwen","Bronwyn","Brook","Brooke","Brookes","Brookhouse","Brooking","Brookner","Brooks","Broome","Brose","Brosine","Brost","Brosy","Brote","Brothers","Brotherson","Brott","Brottman","Broucek","Brout","Brouwer","Brower","Brown","Browne","Browning","Brownley","Brownson","Brozak","Brubaker","Bruce","Brucie","Bruckner","Bruell","Brufsky","Bruis","Brunell","Brunella","Brunelle","Bruner","Brunhild","Brunhilda","Brunhilde","Bruni","Bruning","Brunk","Brunn","Bruno","Bruns","Bruyn","Bryan","Bryana","Bryant","Bryanty","Bryce","Bryn","Bryna","Bryner","Brynn","Brynna","Brynne","Bryon","Buatti","Bubalo","Bubb","Bucella","Buchalter","Buchanan","Buchbinder","Bucher","Buchheim","Buck","Buckden","Buckels","Buckie","Buckingham","Buckler","Buckley","Bucky","Bud","Budd","Budde","Buddie","Budding","Buddy","Buderus","Budge","Budwig","Budworth","Buehler","Buehrer","Buell","Buerger","Bueschel","Buff","Buffo","Buffum","Buffy","Buford","Bugbee","Buhler","Bui","Buine","Buiron","Buke","Bull","Bullard","Bullen","Buller","Bulley","Bullion","Bullis","Bullivant","Bullock","Bullough","Bully","Bultman","Bum","Bumgardner","Buna","Bunce","Bunch","Bunde","Bunder","Bundy","Bunker","Bunni","Bunnie","Bunns","Bunny","Bunow","Bunting","Buonomo","Buote","Burack","Burbank","Burch","Burchett","Burck","Burd","Burdelle","Burdett","Burford","Burg","Burgener","Burger","Burgess","Burget","Burgwell","Burhans","Burk","Burke","Burkhard","Burkhardt","Burkhart","Burkitt","Burkle","Burkley","Burl","Burleigh","Burley","Burlie","Burman","Burn","Burnaby","Burnard","Burne","Burner","Burnett","Burney","Burnham","Burnie","Burnight","Burnley","Burns","Burnsed","Burnside","Burny","Buroker","Burr","Burra","Burrell","Burrill","Burris","Burroughs","Burrow","Burrows","Burrton","Burrus","Burt","Burta","Burtie","Burtis","Burton","Burty","Burwell","Bury","Busby","Busch","Buschi","Buseck","Busey","Bush","Bushey","Bushore","Bushweller","Busiek","Buskirk","Buskus","Bussey","Bussy","Bust","Butch","Butcher","Butler","Butta","Buttaro","Butte","Butterfield","Butterworth","Button","Buxton","Buyer","Buyers","Buyse","Buzz","Buzzell","Byers","Byler","Byram","Byran","Byrann","Byrd","Byrdie","Byrle","Byrn","Byrne","Byrom","Byron","Bysshe","Bywaters","Bywoods","Cacia","Cacie","Cacilia","Cacilie","Cacka","Cad","Cadal","Caddaric","Caddric","Cade","Cadel","Cadell","Cadman","Cadmann","Cadmar","Cadmarr","Caesar","Caesaria","Caffrey","Cagle","Cahan","Cahilly","Cahn","Cahra","Cai","Caia","Caiaphas","Cailean","Cailly","Cain","Caine","Caines","Cairistiona","Cairns","Caitlin","Caitrin","Cal","Calabrese","Calabresi","Calan","Calandra","Calandria","Calbert","Caldeira","Calder","Caldera","Calderon","Caldwell","Cale","Caleb","Calen","Calendra","Calendre","Calesta","Calhoun","Calia","Calica","Calida","Calie","Calisa","Calise","Calista","Call","Calla","Callahan","Callan","Callas","Calle","Callean","Callery","Calley","Calli","Callida","Callie","Callista","Calloway","Callum","Cally","Calmas","Calondra","Calore","Calv","Calva","Calvano","Calvert","Calvin","Calvina","Calvinna","Calvo","Calypso","Calysta","Cam","Camala","Camarata","Camden","Camel","Camella","Camellia","Cameron","Camey","Camfort","Cami","Camila","Camile","Camilia","Camilla","Camille","Camilo","Camm","Cammi","Cammie","Cammy","Camp","Campagna","Campball","Campbell","Campman","Campney","Campos","Campy","Camus","Can","Canada","Canale","Cand","Candace","Candi","Candice","Candida","Candide","Candie","Candis","Candless","Candra","Candy","Candyce","Caneghem","Canfield","Canica","Canice","Caniff","Cann","Cannell","Cannice","Canning","Cannon","Canon","Canotas","Canter","Cantlon","Cantone","Cantu","Canty","Canute","Capello","Caplan","Capon","Capone","Capp","Cappella","Cappello","Capps","Caprice","Capriola","Caputo","Caputto","Capwell","Car","Cara","Caralie","Caras","Caravette","Caraviello","Carberry","Carbo","Carbone","Carboni","Carbrey","Carce","Card","Carder","Cardew","Cardie","Cardinal","Cardon","Cardwell","Care","Careaga","Caren","Carena","Caresa","Caressa","Caresse","Carew","Carey","Cargian","Carhart","Cari","Caria","Carie","Caril","Carilla","Carilyn","Carin","Carina","Carine","Cariotta","Carisa","Carissa","Carita","Caritta","Carl","Carla","Carlee","Carleen","Carlen","Carlene","Carleton","Carley","Carli","Carlick","Carlie","Carlile","Carlin","Carlina","Carline","Carling","Carlisle","Carlita","Carlo","Carlock","Carlos","Carlota","Carlotta","Carlson","Carlstrom","Carlton","Carly","Carlye","Carlyle","Carlyn","Carlynn","Carlynne","Carma","Carman","Carmel","Carmela","Carmelia","Carmelina","Carmelita","Carmella","Carmelle","Carmelo","Carmen","Carmena","Carmencita","Carmina","Carmine","Carmita","Carmon","Carn","Carnahan","Carnay","Carnes","Carney","Carny","Caro","Carol","Carol-Jean","Carola","Carolan","Carolann","Carole","Carolee","Carolin","Carolina","Caroline","Carolle","Carolus","Carolyn","Carolyne","Carolynn","Carolynne","Caron","Carothers","Carpenter","Carper","Carpet","Carpio","Carr","Carree","Carrel","Carrelli","Carrew","Carri","Carrick","Carrie","Carrillo","Carrington","Carrissa","Carrnan","Carrol","Carroll","Carry","Carson","Cart","Cartan","Carter","Carthy","Cartie","Cartwell","Cartwright","Caruso","Carver","Carvey","Cary","Caryl","Caryn","Cas","Casabonne","Casady","Casaleggio","Casandra","Casanova","Casar","Casavant","Case","Casey","Cash","Casi","Casia","Casie","Casilda","Casilde","Casimir","Casimire","Casmey","Caspar","Casper","Cass","Cassady","Cassandra","Cassandre","Cassandry","Cassaundra","Cassell","Cassella","Cassey","Cassi","Cassiani","Cassidy","Cassie","Cassil","Cassilda","Cassius","Cassondra","Cassy","Casta","Castara","Casteel","Castera","Castillo","Castle","Castor","Castora","Castorina","Castra","Castro","Caswell","Cataldo","Catarina","Cate","Caterina","Cates","Cath","Catha","Catharina","Catharine","Cathe","Cathee","Catherin","Catherina","Catherine","Cathey","Cathi","Cathie","Cathleen","Cathlene","Cathrin","Cathrine","Cathryn","Cathy","Cathyleen","Cati","Catie","Catima","Catina","Catlaina","Catlee","Catlin","Cato","Caton","Catrina","Catriona","Catt","Cattan","Cattier","Cattima","Catto","Catton","Caty","Caughey","Caundra","Cavallaro","Cavan","Cavanagh","Cavanaugh","Cave","Caves","Cavil","Cavill","Cavit","Cavuoto","Cawley","Caye","Cayla","Caylor","Cayser","Caz","Cazzie","Cchaddie","Cece","Cecelia","Cecil","Cecile","Ceciley","Cecilia","Cecilio","Cecilius","Cecilla","Cecily","Ced","Cedar","Cedell","Cedric","Ceevah","Ceil","Cele","Celene","Celeski","Celesta","Celeste","Celestia","Celestina","Celestine","Celestyn","Celestyna","Celia","Celie","Celik","Celin","Celina","Celinda","Celine","Celinka","Celio","Celisse","Celka","Celle","Cello","Celtic","Cenac","Cence","Centeno","Center","Centonze","Ceporah","Cerallua","Cerelia","Cerell","Cerellia","Cerelly","Cerf","Cerracchio","Certie","Cerveny","Cerys","Cesar","Cesare","Cesaria","Cesaro","Cestar","Cesya","Cha","Chabot","Chace","Chad","Chadabe","Chadbourne","Chadburn","Chadd","Chaddie","Chaddy","Chader","Chadwick","Chae","Chafee","Chaffee","Chaffin","Chaffinch","Chaiken","Chaille","Chaim","Chainey","Chaing","Chak","Chaker","Chally","Chalmer","Chalmers","Chamberlain","Chamberlin","Chambers","Chamkis","Champ","Champagne","Champaigne","Chan","Chance","Chancellor","Chancelor","Chancey","Chanda","Chandal","Chandler","Chandless","Chandos","Chandra","Chane","Chaney","Chang","Changaris","Channa","Channing","Chansoo","Chantal","Chantalle","Chao","Chap","Chapa","Chapel","Chapell","Chapen","Chapin","Chapland","Chapman","Chapnick","Chappelka","Chappell","Chappie","Chappy","Chara","Charbonneau","Charbonnier","Chard","Chari","Charie","Charil","Charin","Chariot","Charis","Charissa","Charisse","Charita","Charity","Charla","Charlean","Charleen","Charlena","Charlene","Charles","Charlet","Charleton","Charley","Charlie","Charline","Charlot","Charlotta","Charlotte","Charlton","Charmain","Charmaine","Charmane","Charmian","Charmine","Charmion","Charo","Charpentier","Charron","Charry","Charteris","Charters","Charyl","Chas","Chase","Chasse","Chassin","Chastain","Chastity","Chatav","Chatterjee","Chatwin","Chaudoin","Chaunce","Chauncey","Chavaree","Chaves","Chavey","Chavez","Chaworth","Che","Cheadle","Cheatham","Checani","Chee","Cheffetz","Cheke","Chellman","Chelsae","Chelsea","Chelsey","Chelsie","Chelsy","Chelton","Chem","Chema","Chemar","Chemaram","Chemarin","Chemash","Chemesh","Chemosh","Chemush","Chen","Chenay","Chenee","Cheney","Cheng","Cher","Chere","Cherey","Cheri","Cheria","Cherian","Cherianne","Cherice","Cherida","Cherie","Cherilyn","Cherilynn","Cherin","Cherise","Cherish","Cherlyn","Chernow","Cherri","Cherrita","Cherry","Chery","Cherye","Cheryl","Ches","Cheshire","Cheslie","Chesna","Chesney","Chesnut","Chessa","Chessy","Chester","Cheston","Chet","Cheung","Chev","Chevalier","Chevy","Chew","Cheyne","Cheyney","Chi","Chiaki","Chiang","Chiarra","Chic","Chick","Chickie","Chicky","Chico","Chicoine","Chien","Chil","Chilcote","Child","Childers","Childs","Chiles","Chill","Chilson","Chilt","Chilton","Chimene","Chin","China","Ching","Chinua","Chiou","Chip","Chipman","Chiquia","Chiquita","Chirlin","Chisholm","Chita","Chitkara","Chivers","Chladek","Chlo","Chloe","Chloette","Chloras","Chlores","Chlori","Chloris","Cho","Chobot","Chon","Chong","Choo","Choong","Chor","Chouest","Chow","Chretien","Chris","Chrisman","Chrisoula","Chrissa","Chrisse","Chrissie","Chrissy","Christa","Christabel","Christabella","Christabelle","Christal","Christalle","Christan","Christean","Christel","Christen","Christensen","Christenson","Christi","Christian","Christiana","Christiane","Christianity","Christianna","Christiano","Christiansen","Christianson","Christie","Christin","Christina","Christine","Christis","Christmann","Christmas","Christoffer","Christoforo","Christoper","Christoph","Christophe","Christopher","Christos","Christy","Christye","Christyna","Chrisy","Chrotoem","Chrysa","Chrysler","Chrystal","Chryste","Chrystel","Chu","Chuah","Chubb","Chuch","Chucho","Chuck","Chud","Chui","Chuipek","Chun","Chung","Chura","Church","Churchill","Chute","Chuu","Chyou","Cia","Cianca","Ciapas","Ciapha","Ciaphus","Cibis","Ciccia","Cicely","Cicenia","Cicero","Cichocki","Cicily","Cid","Cida","Ciel","Cila","Cilka","Cilla","Cilo","Cilurzo","Cima","Cimah","Cimbura","Cinda","Cindee","Cindelyn","Cinderella","Cindi","Cindie","Cindra","Cindy","Cinelli","Cini","Cinnamon","Cioban","Cioffred","Ciprian","Circosta","Ciri","Cirilla","Cirillo","Cirilo","Ciro","Cirone","Cirri","Cis","Cissie","Cissiee","Cissy","Cita","Citarella","Citron","Clabo","Claiborn","Claiborne","Clair","Claire","Claman","Clance","Clancy","Clapp","Clapper","Clara","Clarabelle","Clarance","Clardy","Clare","Clarence","Claresta","Clareta","Claretta","Clarette","Clarey","Clarhe","Clari","Claribel","Clarice","Clarie","Clarinda","Clarine","Clarisa","Clarise","Clarissa","Clarisse","Clarita","Clark","Clarke","Clarkin","Clarkson","Clary","Claud","Clauddetta","Claude","Claudell","Claudelle","Claudetta","Claudette","Claudia","Claudian","Claudianus","Claudie","Claudina","Claudine","Claudio","Claudius","Claudy","Claus","Clausen","Clava","Clawson","Clay","Clayberg","Clayborn","Clayborne","Claybourne","Clayson","Clayton","Clea","Cleary","Cleasta","Cleave","Cleaves","Cleavland","Clein","Cleland","Clellan","Clem","Clemen","Clemence","Clemens","Clement","Clementas","Clemente","Clementi","Clementia","Clementina","Clementine","Clementis","Clementius","Clements","Clemmie","Clemmy","Cleo","Cleodal","Cleodel","Cleodell","Cleon","Cleopatra","Cleopatre","Clerc","Clercq","Clere","Cleres","Clerissa","Clerk","Cleti","Cletis","Cletus","Cleve","Cleveland","Clevey","Clevie","Clie","Cliff","Cliffes","Clifford","Clift","Clifton","Clim","Cline","Clint","Clintock","Clinton","Clio","Clippard","Clite","Clive","Clo","Cloe","Cloots","Clorinda","Clorinde","Cloris","Close","Clothilde","Clotilda","Clotilde","Clough","Clougher","Cloutman","Clova","Clovah","Clover","Clovis","Clower","Clute","Cly","Clyde","Clymer","Clynes","Clyte","Clyve","Clywd","Cnut","Coad","Coady","Coates","Coats","Cob","Cobb","Cobbie","Cobby","Coben","Cochard","Cochran","Cochrane","Cock","Cockburn","Cocke","Cocks","Coco","Codd","Codding","Codee","Codel","Codi","Codie","Cody","Coe","Coffee","Coffeng","Coffey","Coffin","Cofsky","Cogan","Cogen","Cogswell","Coh","Cohbath","Cohberg","Cohbert","Cohby","Cohdwell","Cohe","Coheman","Cohen","Cohette","Cohin","Cohl","Cohla","Cohleen","Cohlette","Cohlier","Cohligan","Cohn","Cointon","Coit","Coke","Col","Colan","Colas","Colb","Colbert","Colburn","Colby","Colbye","Cole","Coleen","Coleman","Colene","Colet","Coletta","Colette","Coleville","Colfin","Colier","Colin","Colinson","Colis","Collar","Collayer","Collbaith","Colleen","Collen","Collete","Collette","Colley","Collie","Collier","Colligan","Collimore","Collin","Colline","Collins","Collis","Collum","Colly","Collyer","Colman","Colner","Colombi","Colon","Colp","Colpin","Colson","Colston","Colt","Coltin","Colton","Coltson","Coltun","Columba","Columbine","Columbus","Columbyne","Colver","Colvert","Colville","Colvin","Colwell","Colwen","Colwin","Colyer","Combe","Combes","Combs","Comfort","Compte","Comptom","Compton","Comras","Comstock","Comyns","Con","Conah","Conal","Conall","Conan","Conant","Conard","Concepcion","Concettina","Concha","Conchita","Concoff","Concordia","Condon","Coney","Congdon","Conger","Coniah","Conias","Conlan","Conlee","Conlen","Conley","Conlin","Conlon","Conn","Connel","Connell","Connelley","Connelly","Conner","Conners","Connett","Conney","Conni","Connie","Connolly","Connor","Connors","Conny","Conover","Conrad","Conrade","Conrado","Conroy","Consalve","Consolata","Constance","Constancia","Constancy","Constant","Constanta","Constantia","Constantin","Constantina","Constantine","Constantino","Consuela","Consuelo","Conte","Conti","Converse","Convery","Conway","Cony","Conyers","Cooe","Cook","Cooke","Cookie","Cooley","Coombs","Coonan","Coop","Cooper","Cooperman","Coopersmith","Cooperstein","Cope","Copeland","Copland","Coplin","Copp","Coppinger","Coppins","Coppock","Coppola","Cora","Corabel","Corabella","Corabelle","Coral","Coralie","Coraline","Coralyn","Coray","Corbet","Corbett","Corbie","Corbin","Corby","Cord","Cordalia","Cordeelia","Cordelia","Cordelie","Cordell","Corder","Cordey","Cordi","Cordie","Cordier","Cordle","Cordova","Cordula","Cordy","Coreen","Corel","Corell","Corella","Corena","Corenda","Corene","Coretta","Corette","Corey","Cori","Coridon","Corie","Corilla","Corin","Corina","Corine","Corinna","Corinne","Coriss","Corissa","Corkhill","Corley","Corliss","Corly","Cormac","Cormack","Cormick","Cormier","Cornall","Corneille","Cornel","Cornela","Cornelia","Cornelie","Cornelius","Cornell","Cornelle","Cornew","Corney","Cornia","Cornie","Cornish","Cornwall","Cornwell","Corny","Corotto","Correna","Correy","Corri","Corrianne","Corrie","Corrina","Corrine","Corrinne","Corron","Corry","Corsetti","Corsiglia","Corso","Corson","Cort","Cortie","Cortney","Corty","Corvese","Corvin","Corwin","Corwun","Cory","Coryden","Corydon","Cos","Cosenza","Cosetta","Cosette","Coshow","Cosimo","Cosma","Cosme","Cosmo","Cost","Costa","Costanza","Costanzia","Costello","Coster","Costin","Cote","Cotsen","Cott","Cotter","Cotterell","Cottle","Cottrell","Coucher","Couchman","Coughlin","Coulombe","Coulson","Coulter","Coumas","Countess","Courcy","Court","Courtenay","Courtland","Courtnay","Courtney","Courtund","Cousin","Cousins","Coussoule","Couture","Covell","Coveney","Cowan","Coward","Cowden","Cowen","Cower","Cowey","Cowie","Cowles","Cowley","Cown","Cox","Coy","Coyle","Cozmo","Cozza","Crabb","Craddock","Craggie","Craggy","Craig","Crain","Cralg","Cram","Cramer","Cran","Crandale","Crandall","Crandell","Crane","Craner","Cranford","Cranston","Crary","Craven","Craw","Crawford","Crawley","Creamer","Crean","Creath","Creedon","Creigh","Creight","Creighton","Crelin","Crellen","Crenshaw","Cresa","Crescantia","Crescen","Crescentia","Crescin","Crescint","Cresida","Crespi","Crespo","Cressi","Cressida","Cressler","Cressy","Crichton","Crifasi","Crim","Crin","Cris","Crisey","Crispa","Crispas","Crispen","Crispin","Crissie","Crissy","Crist","Crista","Cristabel","Cristal","Cristen","Cristi","Cristian","Cristiano","Cristie","Cristin","Cristina","Cristine","Cristiona","Cristionna","Cristobal","Cristoforo","Cristy","Criswell","Critchfield","Critta","Crocker","Crockett","Crofoot","Croft","Crofton","Croix","Crompton","Cromwell","Croner","Cronin","Crooks","Croom","Crosby","Crosley","Cross","Crosse","Croteau","Crotty","Crow","Crowe","Crowell","Crowley","Crowns","Croydon","Cruce","Crudden","Cruickshank","Crutcher","Cruz","Cryan","Crysta","Crystal","Crystie","Cthrine","Cuda","Cudlip","Culberson","Culbert","Culbertson","Culhert","Cull","Cullan","Cullen","Culley","Cullie","Cullin","Culliton","Cully","Culosio","Culver","Cumine","Cumings","Cummine","Cummings","Cummins","Cung","Cunningham","Cupo","Curcio","Curhan","Curkell","Curley","Curnin","Curr","Curran","Curren","Currey","Currie","Currier","Curry","Curson","Curt","Curtice","Curtis","Curzon","Cusack","Cusick","Custer","Cut","Cutcheon","Cutcliffe","Cuthbert","Cuthbertson","Cuthburt","Cutler","Cutlerr","Cutlip","Cutlor","Cutter","Cuttie","Cuttler","Cutty","Cuyler","Cy","Cyb","Cybil","Cybill","Cychosz","Cyd","Cykana","Cyler","Cyma","Cymbre","Cyn","Cyna","Cynar","Cynara","Cynarra","Cynde","Cyndi","Cyndia","Cyndie","Cyndy","Cynera","Cynth","Cynthea","Cynthia","Cynthie","Cynthla","Cynthy","Cyprian","Cyprio","Cypro","Cyprus","Cyrano","Cyrie","Cyril","Cyrill","Cyrilla","Cyrille","Cyrillus","Cyrus","Czarra","D'Arcy","Dabbs","Daberath","Dabney","Dace","Dacey","Dachi","Dachia","Dachy","Dacia","Dacie","Dacy","Daegal","Dael","Daffi","Daffie","Daffodil","Daffy","Dafna","Dafodil","Dag","Dagall","Daggett","Daggna","Dagley","Dagmar","Dagna","Dagnah","Dagney","Dagny","Dahl","Dahle","Dahlia","Dahlstrom","Daigle","Dail","Daile","Dailey","Daisey","Daisi","Daisie","Daisy","Daitzman","Dal","Dale","Dalenna","Daley","Dalia","Dalila","Dalis","Dall","Dallas","Dalli","Dallis","Dallman","Dallon","Daloris","Dalpe","Dalston","Dalt","Dalton","Dalury","Daly","Dam","Damal","Damalas","Damales","Damali","Damalis","Damalus","Damara","Damaris","Damarra","Dambro","Dame","Damek","Damian","Damiani","Damiano","Damick","Damicke","Damien","Damita","Damle","Damon","Damour","Dan","Dana","Danae","Danaher","Danais","Danas","Danby","Danczyk","Dane","Danell","Danella","Danelle","Danete","Danette","Daney","Danforth","Dang","Dani","Dania","Daniala","Danialah","Danica","Danice","Danie","Daniel","Daniela","Daniele","Daniell","Daniella","Danielle","Daniels","Danielson","Danieu","Danika","Danila","Danit","Danita","Daniyal","Dann","Danna","Dannel","Danni","Dannica","Dannie","Dannon","Danny","Dannye","Dante","Danuloff","Danya","Danyelle","Danyette","Danyluk","Danzig","Danziger","Dao","Daph","Daphene","Daphie","Daphna","Daphne","Dar","Dara","Darach","Darb","Darbee","Darbie","Darby","Darce","Darcee","Darcey","Darci","Darcia","Darcie","Darcy","Darda","Dardani","Dare","Dareece","Dareen","Darees","Darell","Darelle","Daren","Dari","Daria","Darian","Darice","Darill","Darin","Dario","Darius","Darken","Darla","Darleen","Darlene","Darline","Darlleen","Darmit","Darn","Darnall","Darnell","Daron","Darooge","Darra","Darrel","Darrell","Darrelle","Darren","Darrey","Darrick","Darrill","Darrin","Darrow","Darryl","Darryn","Darsey","Darsie","Dart","Darton","Darwen","Darwin","Darya","Daryl","Daryle","Daryn","Dash","Dasha","Dasi","Dasie","Dasteel","Dasya","Datha","Datnow","Daub","Daugherty","Daughtry","Daukas","Daune","Dav","Dave","Daveda","Daveen","Daven","Davena","Davenport","Daveta","Davey","David","Davida","Davidde","Davide","Davidoff","Davidson","Davie","Davies","Davilman","Davin","Davina","Davine","Davis","Davison","Davita","Davon","Davy","Dawes","Dawkins","Dawn","Dawna","Dawson","Day","Daye","Dayle","Dayna","Ddene","De","De Witt","Deach","Deacon","Deadman","Dean","Deana","Deane","Deaner","Deanna","Deanne","Dearborn","Dearden","Dearman","Dearr","Deb","Debarath","Debbee","Debbi","Debbie","Debbra","Debby","Debee","Debera","Debi","Debor","Debora","Deborah","Deborath","Debra","Decamp","Decato","Decca","December","Decima","Deck","Decker","Deckert","Declan","Dede","Deden","Dedie","Dedra","Dedric","Dedrick","Dee","Dee Dee","DeeAnn","Deeann","Deeanne","Deedee","Deegan","Deena","Deenya","Deer","Deerdre","Deering","Deery","Deeyn","Defant","Dehlia","Dehnel","Deibel","Deidre","Deina","Deirdra","Deirdre","Dekeles","Dekow","Del","Dela","Delacourt","Delaine","Delainey","Delamare","Deland","Delaney","Delanie","Delano","Delanos","Delanty","Delaryd","Delastre","Delbert","Delcina","Delcine","Delfeena","Delfine","Delgado","Delia","Delija","Delila","Delilah","Delinda","Delisle","Dell","Della","Delle","Dellora","Delly","Delmar","Delmer","Delmor","Delmore","Delogu","Delora","Delorenzo","Delores","Deloria","Deloris","Delos","Delp","Delphina","Delphine","Delphinia","Delsman","Delwin","Delwyn","Demaggio","Demakis","Demaria","Demb","Demeter","Demetra","Demetre","Demetri","Demetria","Demetris","Demetrius","Demeyer","Deming","Demitria","Demmer","Demmy","Demodena","Demona","Demott","Demp","Dempsey","Dempster","Dempstor","Demy","Den","Dena","Denae","Denbrook","Denby","Dene","Deni","Denice","Denie","Denis","Denise","Denison","Denman","Denn","Denna","Dennard","Dennet","Dennett","Denney","Denni","Dennie","Dennis","Dennison","Denny","Denoting","Dent","Denten","Denton","Denver","Deny","Denys","Denyse","Denzil","Deonne","Depoliti","Deppy","Der","Deragon","Derayne","Derby","Dercy","Derek","Derian","Derick","Derina","Derinna","Derk","Derman","Dermot","Dermott","Derna","Deron","Deroo","Derr","Derrek","Derrick","Derriey","Derrik","Derril","Derron","Derry","Derte","Derward","Derwin","Derwon","Derwood","Deryl","Derzon","Des","Desai","Desberg","Descombes","Desdamona","Desdamonna","Desdee","Desdemona","Desi","Desimone","Desirae","Desirea","Desireah","Desiree","Desiri","Desma","Desmond","Desmund","Dessma","Desta","Deste","Destinee","Deth","Dett","Detta","Dettmer","Deuno","Deutsch","Dev","Deva","Devan","Devaney","Dever","Devi","Devin","Devina","Devine","Devinna","Devinne","Devitt","Devland","Devlen","Devlin","Devol","Devon","Devona","Devondra","Devonna","Devonne","Devora","Devy","Dew","Dewain","Dewar","Dewayne","Dewees","Dewey","Dewhirst","Dewhurst","Dewie","Dewitt","Dex","Dexter","Dey","Dhar","Dhiman","Dhiren","Dhruv","Dhu","Dhumma","Di","Diahann","Diamante","Diamond","Dian","Diana","Diandra","Diandre","Diane","Diane-Marie","Dianemarie","Diann","Dianna","Dianne","Diannne","Diantha","Dianthe","Diao","Diarmid","Diarmit","Diarmuid","Diaz","Dib","Diba","Dibb","Dibbell","Dibbrun","Dibri","Dibrin","Dibru","Dich","Dichy","Dick","Dickens","Dickenson","Dickerson","Dickey","Dickie","Dickinson","Dickman","Dicks","Dickson","Dicky","Didi","Didier","Dido","Dieball","Diego","Diehl","Diella","Dielle","Dielu","Diena","Dierdre","Dierolf","Diet","Dieter","Dieterich","Dietrich","Dietsche","Dietz","Dikmen","Dilan","Diley","Dilisio","Dilks","Dill","Dillie","Dillon","Dilly","Dimitri","Dimitris","Dimitry","Dimmick","Dimond","Dimphia","Dina","Dinah","Dinan","Dincolo","Dine","Dinerman","Dinesh","Dinin","Dinnage","Dinnie","Dinny","Dino","Dinsdale","Dinse","Dinsmore","Diogenes","Dion","Dione","Dionis","Dionisio","Dionne","Dionysus","Dippold","Dira","Dirk","Disario","Disharoon","Disini","Diskin","Diskson","Disraeli","Dita","Ditmore","Ditter","Dittman","Dituri","Ditzel","Diver","Divine","Dix","Dixie","Dixil","Dixon","Dmitri","Dniren","Doak","Doane","Dobb","Dobbins","Doble","Dobrinsky","Dobson","Docia","Docila","Docile","Docilla","Docilu","Dodd","Dodds","Dode","Dodge","Dodi","Dodie","Dodson","Dodwell","Dody","Doe","Doehne","Doelling","Doerrer","Doersten","Doggett","Dogs","Doherty","Doi","Doig","Dola","Dolan","Dole","Doley","Dolf","Dolhenty","Doll","Dollar","Dolley","Dolli","Dollie","Dolloff","Dolly","Dolora","Dolores","Dolorita","Doloritas","Dolph","Dolphin","Dom","Domash","Dombrowski","Domel","Domela","Domella","Domenech","Domenic","Domenico","Domeniga","Domineca","Dominga","Domingo","Domini","Dominic","Dominica","Dominick","Dominik","Dominique","Dominus","Dominy","Domonic","Domph","Don","Dona","Donadee","Donaghue","Donahoe","Donahue","Donal","Donald","Donaldson","Donall","Donalt","Donata","Donatelli","Donaugh","Donavon","Donegan","Donela","Donell","Donella","Donelle","Donelson","Donelu","Doner","Donetta","Dong","Donia","Donica","Donielle","Donn","Donna","Donnamarie","Donnell","Donnelly","Donnenfeld","Donni","Donnie","Donny","Donoghue","Donoho","Donohue","Donough","Donovan","Doolittle","Doone","Dopp","Dora","Doralia","Doralin","Doralyn","Doralynn","Doralynne","Doran","Dorca","Dorcas","Dorcea","Dorcia","Dorcus","Dorcy","Dore","Doreen","Dorelia","Dorella","Dorelle","Dorena","Dorene","Doretta","Dorette","Dorey","Dorfman","Dori","Doria","Dorian","Dorice","Dorie","Dorin","Dorina","Dorinda","Dorine","Dorion","Doris","Dorisa","Dorise","Dorison","Dorita","Dorkas","Dorkus","Dorlisa","Dorman","Dorn","Doro","Dorolice","Dorolisa","Dorotea","Doroteya","Dorothea","Dorothee","Dorothi","Dorothy","Dorr","Dorran","Dorree","Dorren","Dorri","Dorrie","Dorris","Dorry","Dorsey","Dorsman","Dorsy","Dorthea","Dorthy","Dorweiler","Dorwin","Dory","Doscher","Dosh","Dosi","Dosia","Doss","Dot","Doti","Dotson","Dott","Dotti","Dottie","Dotty","Doty","Doubler","Doug","Dougal","Dougald","Dougall","Dougherty","Doughman","Doughty","Dougie","Douglas","Douglass","Dougy","Douty","Douville","Dov","Dove","Dovev","Dow","Dowd","Dowdell","Dowell","Dowlen","Dowling","Down","Downall","Downe","Downes","Downey","Downing","Downs","Dowski","Dowzall","Doxia","Doy","Doykos","Doyle","Drabeck","Dragelin","Dragon","Dragone","Dragoon","Drain","Drais","Drake","Drandell","Drape","Draper","Dray","Dre","Dream","Dreda","Dreddy","Dredi","Dreeda","Dreher","Dremann","Drescher","Dressel","Dressler","Drew","Drewett","Drews","Drexler","Dreyer","Dric","Drice","Drida","Dripps","Driscoll","Driskill","Drisko","Drislane","Drobman","Drogin","Drolet","Drona","Dronski","Drooff","Dru","Druce","Druci","Drucie","Drucill","Drucilla","Drucy","Drud","Drue","Drugge","Drugi","Drummond","Drus","Drusi","Drusie","Drusilla","Drusus","Drusy","Dry","Dryden","Drye","Dryfoos","DuBois","Duane","Duarte","Duax","Dubenko","Dublin","Ducan","Duck","Dud","Dudden","Dudley","Duer","Duester","Duff","Duffie","Duffy","Dugaid","Dugald","Dugan","Dugas","Duggan","Duhl","Duke","Dukey","Dukie","Duky","Dulce","Dulcea","Dulci","Dulcia","Dulciana","Dulcie","Dulcine","Dulcinea","Dulcle","Dulcy","Duleba","Dulla","Dulsea","Duma","Dumah","Dumanian","Dumas","Dumm","Dumond","Dun","Dunaville","Dunc","Duncan","Dunham","Dunkin","Dunlavy","Dunn","Dunning","Dunseath","Dunson","Dunstan","Dunston","Dunton","Duntson","Duong","Dupaix","Dupin","Dupre","Dupuis","Dupuy","Duquette","Dur","Durand","Durant","Durante","Durarte","Durer","Durgy","Durham","Durkee","Durkin","Durman","Durnan","Durning","Durno","Durr","Durrace","Durrell","Durrett","Durst","Durstin","Durston","Durtschi","Durward","Durware","Durwin","Durwood","Durwyn","Dusa","Dusen","Dust","Dustan","Duster","Dustie","Dustin","Dustman","Duston","Dusty","Dusza","Dutch","Dutchman","Duthie","Duval","Duvall","Duwalt","Duwe","Duyne","Dwain","Dwaine","Dwan","Dwane","Dwayne","Dweck","Dwight","Dwinnell","Dworman","Dwyer","Dyal","Dyan","Dyana","Dyane","Dyann","Dyanna","Dyanne","Dyche","Dyer","Dygal","Dygall","Dygert","Dyke","Dyl","Dylan","Dylana","Dylane","Dymoke","Dympha","Dymphia","Dyna","Dynah","Dysart","Dyson","Dyun","Dzoba","Eachelle","Eachern","Eada","Eade","Eadie","Eadith","Eadmund","Eads","Eadwina","Eadwine","Eagle","Eal","Ealasaid","Eamon","Eanore","Earl","Earla","Earle","Earleen","Earlene","Earley","Earlie","Early","Eartha","Earvin","East","Easter","Eastlake","Eastman","Easton","Eaton","Eatton","Eaves","Eb","Eba","Ebarta","Ebba","Ebbarta","Ebberta","Ebbie","Ebby","Eben","Ebeneser","Ebenezer","Eberhard","Eberhart","Eberle","Eberly","Ebert","Eberta","Eberto","Ebner","Ebneter","Eboh","Ebonee","Ebony","Ebsen","Echikson","Echo","Eckardt","Eckart","Eckblad","Eckel","Eckhardt","Eckmann","Econah","Ed","Eda","Edan","Edana","Edbert","Edd","Edda","Eddana","Eddi","Eddie","Eddina","Eddra","Eddy","Ede","Edea","Edee","Edeline","Edelman","Edelson","Edelstein","Edelsten","Eden","Edette","Edgar","Edgard","Edgardo","Edge","Edgell","Edgerton","Edholm","Edi","Edie","Edik","Edin","Edina","Edison","Edita","Edith","Editha","Edithe","Ediva","Edla","Edlin","Edlun","Edlyn","Edmanda","Edme","Edmea","Edmead","Edmee","Edmon","Edmond","Edmonda","Edmondo","Edmonds","Edmund","Edmunda","Edna","Edny","Edora","Edouard","Edra","Edrea","Edrei","Edric","Edrick","Edris","Edrock","Edroi","Edsel","Edson","Eduard","Eduardo","Eduino","Edva","Edvard","Edveh","Edward","Edwards","Edwin","Edwina","Edwine","Edwyna","Edy","Edyth","Edythe","Effie","Effy","Efram","Efrem","Efren","Efron","Efthim","Egan","Egarton","Egbert","Egerton","Eggett","Eggleston","Egide","Egidio","Egidius","Egin","Eglanteen","Eglantine","Egon","Egor","Egwan","Egwin","Ehling","Ehlke","Ehman","Ehr","Ehrenberg","Ehrlich","Ehrman","Ehrsam","Ehud","Ehudd","Eichman","Eidson","Eiger","Eileen","Eilis","Eimile","Einberger","Einhorn","Eipper","Eirena","Eirene","Eisele","Eisen","Eisenberg","Eisenhart","Eisenstark","Eiser","Eisinger","Eisler","Eiten","Ekaterina","El","Ela","Elah","Elaina","Elaine","Elana","Elane","Elata","Elatia","Elayne","Elazaro","Elbart","Elberfeld","Elbert","Elberta","Elbertina","Elbertine","Elboa","Elbring","Elburr","Elburt","Elconin","Elda","Elden","Elder","Eldin","Eldon","Eldora","Eldorado","Eldoree","Eldoria","Eldred","Eldreda","Eldredge","Eldreeda","Eldrid","Eldrida","Eldridge","Eldwen","Eldwin","Eldwon","Eldwun","Eleanor","Eleanora","Eleanore","Eleazar","Electra","Eleen","Elena","Elene","Eleni","Elenore","Eleonora","Eleonore","Eleph","Elephus","Elery","Elexa","Elfie","Elfont","Elfreda","Elfrida","Elfrieda","Elfstan","Elga","Elgar","Eli","Elia","Eliades","Elianora","Elianore","Elias","Eliason","Eliath","Eliathan","Eliathas","Elicia","Elidad","Elie","Eliezer","Eliga","Elihu","Elijah","Elinor","Elinore","Eliot","Eliott","Elisa","Elisabet","Elisabeth","Elisabetta","Elise","Elisee","Eliseo","Elish","Elisha","Elison","Elissa","Elita","Eliza","Elizabet","Elizabeth","Elka","Elke","Elkin","Ella","Elladine","Ellan","Ellard","Ellary","Ellata","Elle","Ellen","Ellene","Ellerd","Ellerey","Ellersick","Ellery","Ellett","Ellette","Ellga","Elli","Ellicott","Ellie","Ellinger","Ellingston","Elliot","Elliott","Ellis","Ellison","Ellissa","Ellita","Ellmyer","Ellon","Ellora","Ellord","Ellswerth","Ellsworth","Ellwood","Elly","Ellyn","Ellynn","Elma","Elmajian","Elmaleh","Elman","Elmer","Elmina","Elmira","Elmo","Elmore","Elna","Elnar","Elnora","Elnore","Elo","Elodea","Elodia","Elodie","Eloisa","Eloise","Elon","Elonore","Elora","Elreath","Elrod","Elroy","Els","Elsa","Elsbeth","Else","Elset","Elsey","Elsi","Elsie","Elsinore","Elson","Elspet","Elspeth","Elstan","Elston","Elsworth","Elsy","Elton","Elum","Elurd","Elva","Elvah","Elvera","Elvia","Elvie","Elvin","Elvina","Elvira","Elvis","Elvyn","Elwaine","Elwee","Elwin","Elwina","Elwira","Elwood","Elwyn","Ely","Elyn","Elyse","Elysee","Elysha","Elysia","Elyssa","Em","Ema","Emad","Emalee","Emalia","Emanuel","Emanuela","Emanuele","Emarie","Embry","Emee","Emelda","Emelen","Emelia","Emelin","Emelina","Emeline","Emelita","Emelun","Emelyne","Emera","Emerald","Emeric","Emerick","Emersen","Emerson","Emery","Emie","Emil","Emile","Emilee","Emili","Emilia","Emilie","Emiline","Emilio","Emily","Emina","Emlen","Emlin","Emlyn","Emlynn","Emlynne","Emma","Emmalee","Emmaline","Emmalyn","Emmalynn","Emmalynne","Emmanuel","Emmeline","Emmer","Emmeram","Emmerich","Emmerie","Emmery","Emmet","Emmett","Emmey","Emmi","Emmie","Emmit","Emmons","Emmott","Emmuela","Emmy","Emmye","Emogene","Emory","Emrich","Emsmus","Emyle","Emylee","Enalda","Encrata","Encratia","Encratis","End","Ender","Endo","Endor","Endora","Endres","Enenstein","Eng","Engdahl","Engeddi","Engedi","Engedus","Engel","Engelbert","Engelhart","Engen","Engenia","England","Engle","Englebert","Engleman","Englis","English","Engracia","Engud","Engvall","Enid","Ennis","Eno","Enoch","Enos","Enrica","Enrichetta","Enrico","Enrika","Enrique","Enriqueta","Ensign","Ensoll","Entwistle","Enyedy","Eoin","Eolanda","Eolande","Eph","Ephraim","Ephram","Ephrayim","Ephrem","Epifano","Epner","Epp","Epperson","Eppes","Eppie","Epps","Epstein","Er","Eradis","Eran","Eras","Erasme","Erasmo","Erasmus","Erastatus","Eraste","Erastes","Erastus","Erb","Erbe","Erbes","Erda","Erdah","Erdda","Erde","Erdei","Erdman","Erdrich","Erek","Erelia","Erena","Erfert","Ergener","Erhard","Erhart","Eri","Eric","Erica","Erich","Ericha","Erick","Ericka","Ericksen","Erickson","Erida","Erie","Eriha","Erik","Erika","Erikson","Erin","Erina","Erine","Erinn","Erinna","Erkan","Erl","Erland","Erlandson","Erle","Erleena","Erlene","Erlewine","Erlin","Erlina","Erline","Erlinna","Erlond","Erma","Ermanno","Erme","Ermeena","Ermengarde","Ermentrude","Ermey","Ermin","Ermina","Ermine","Erminia","Erminie","Erminna","Ern","Erna","Ernald","Ernaldus","Ernaline","Ernest","Ernesta","Ernestine","Ernesto","Ernestus","Ernie","Ernst","Erny","Errecart","Errick","Errol","Erroll","Erskine","Ertha","Erund","Erv","ErvIn","Ervin","Ervine","Erving","Erwin","Eryn","Esau","Esbensen","Esbenshade","Esch","Esdras","Eshelman","Eshman","Eskil","Eskill","Esma","Esmaria","Esme","Esmeralda","Esmerelda","Esmerolda","Esmond","Espy","Esra","Essa","Essam","Essex","Essie","Essinger","Essy","Esta","Estas","Esteban","Estel","Estele","Estell","Estella","Estelle","Esten","Ester","Estes","Estevan","Estey","Esther","Estis","Estrella","Estrellita","Estren","Estrin","Estus","Eta","Etam","Etan","Etana","Etem","Ethan","Ethban","Ethben","Ethbin","Ethbinium","Ethbun","Ethe","Ethel","Ethelbert","Ethelda","Ethelin","Ethelind","Ethelinda","Etheline","Ethelred","Ethelstan","Ethelyn","Ethyl","Etienne","Etka","Etoile","Etom","Etra","Etrem","Etta","Ettari","Etti","Ettie","Ettinger","Ettore","Etty","Etz","Eudo","Eudoca","Eudocia","Eudora","Eudosia","Eudoxia","Euell","Eugen","Eugene","Eugenia","Eugenides","Eugenie","Eugenio","Eugenius","Eugeniusz","Eugenle","Eugine","Euh","Eula","Eulalee","Eulalia","Eulaliah","Eulalie","Eulau","Eunice","Eupheemia","Euphemia","Euphemiah","Euphemie","Euridice","Eurydice","Eusebio","Eustace","Eustache","Eustacia","Eustashe","Eustasius","Eustatius","Eustazio","Eustis","Euton","Ev","Eva","Evadne","Evadnee","Evaleen","Evalyn","Evan","Evander","Evangelia","Evangelin","Evangelina","Evangeline","Evangelist","Evania","Evanne","Evannia","Evans","Evante","Evanthe","Evars","Eve","Eveleen","Evelin","Evelina","Eveline","Evelinn","Evelunn","Evelyn","Even","Everara","Everard","Evered","Everest","Everett","Everick","Everrs","Evers","Eversole","Everson","Evetta","Evette","Evey","Evie","Evin","Evita","Evonne","Evoy","Evslin","Evvie","Evvy","Evy","Evyn","Ewald","Ewall","Ewan","Eward","Ewart","Ewell","Ewen","Ewens","Ewer","Ewold","Eyde","Eydie","Eyeleen","Eyla","Ez","Ezana","Ezar","Ezara","Ezaria","Ezarra","Ezarras","Ezechiel","Ezekiel","Ezequiel","Eziechiele","Ezmeralda","Ezra","Ezri","Ezzo","Fabe","Faber","Fabi","Fabian","Fabiano","Fabien","Fabio","Fabiola","Fabiolas","Fablan","Fabozzi","Fabri","Fabria","Fabriane","Fabrianna","Fabrianne","Fabrice","Fabrienne","Fabrin","Fabron","Fabyola","Fachan","Fachanan","Fachini","Fadden","Faden","Fadil","Fadiman","Fae","Fagaly","Fagan","Fagen","Fagin","Fahey","Fahland","Fahy","Fai","Faina","Fair","Fairbanks","Faires","Fairfax","Fairfield","Fairleigh","Fairley","Fairlie","Fairman","Fairweather","Faith","Fakieh","Falcone","Falconer","Falda","Faletti","Faline","Falito","Falk","Falkner","Fallon","Faludi","Falzetta","Fan","Fanchan","Fanchet","Fanchette","Fanchie","Fanchon","Fancie","Fancy","Fanechka","Fanestil","Fang","Fania","Fanni","Fannie","Fanning","Fanny","Fantasia","Fante","Fanya","Far","Fara","Farah","Farand","Farant","Farhi","Fari","Faria","Farica","Farika","Fariss","Farkas","Farl","Farland","Farlay","Farlee","Farleigh","Farley","Farlie","Farly","Farman","Farmann","Farmelo","Farmer","Farnham","Farnsworth","Farny","Faro","Farr","Farra","Farrah","Farrand","Farrar","Farrel","Farrell","Farrica","Farrington","Farris","Farrish","Farrison","Farro","Farron","Farrow","Faruq","Farver","Farwell","Fasano","Faso","Fassold","Fast","Fasta","Fasto","Fates","Fatima","Fatimah","Fatma","Fattal","Faubert","Faubion","Fauch","Faucher","Faulkner","Fauman","Faun","Faunia","Faunie","Faus","Faust","Fausta","Faustena","Faustina","Faustine","Faustus","Fauver","Faux","Favata","Favian","Favianus","Favien","Favin","Favrot","Fawcett","Fawcette","Fawn","Fawna","Fawne","Fawnia","Fax","Faxan","Faxen","Faxon","Faxun","Fay","Faydra","Faye","Fayette","Fayina","Fayola","Fayre","Fayth","Faythe","Fazeli","Fe","Featherstone","February","Fechter","Fedak","Federica","Federico","Fedirko","Fedora","Fee","Feeley","Feeney","Feer","Feigin","Feil","Fein","Feinberg","Feingold","Feinleib","Feinstein","Feld","Felder","Feldman","Feldstein","Feldt","Felecia","Feledy","Felic","Felicdad","Felice","Felicia","Felicidad","Felicie","Felicio","Felicity","Felicle","Felike","Feliks","Felipa","Felipe","Felise","Felisha","Felita","Felix","Feliza","Felizio","Fellner","Fellows","Felske","Felt","Felten","Feltie","Felton","Felty","Fem","Femi","Femmine","Fen","Fendig","Fenelia","Fenella","Fenn","Fennell","Fennelly","Fenner","Fennessy","Fennie","Fenny","Fenton","Fenwick","Feodor","Feodora","Feodore","Feola","Ferd","Ferde","Ferdie","Ferdinana","Ferdinand","Ferdinanda","Ferdinande","Ferdy","Fergus","Ferguson","Feriga","Ferino","Fermin","Fern","Ferna","Fernald","Fernand","Fernanda","Fernande","Fernandes","Fernandez","Fernandina","Fernando","Fernas","Ferne","Ferneau","Fernyak","Ferrand","Ferreby","Ferree","Ferrel","Ferrell","Ferren","Ferretti","Ferri","Ferrick","Ferrigno","Ferris","Ferriter","Ferro","Ferullo","Ferwerda","Festa","Festatus","Festus","Feucht","Feune","Fevre","Fey","Fi","Fia","Fiann","Fianna","Fidel","Fidela","Fidelas","Fidele","Fidelia","Fidelio","Fidelis","Fidelity","Fidellas","Fidellia","Fiden","Fidole","Fiedler","Fiedling","Field","Fielding","Fields","Fiertz","Fiester","Fife","Fifi","Fifine","Figge","Figone","Figueroa","Filbert","Filberte","Filberto","Filemon","Files","Filia","Filiano","Filide","Filip","Filipe","Filippa","Filippo","Fillander","Fillbert","Fillender","Filler","Fillian","Filmer","Filmore","Filomena","Fin","Fina","Finbar","Finbur","Findlay","Findley","Fine","Fineberg","Finegan","Finella","Fineman","Finer","Fini","Fink","Finkelstein","Finlay","Finley","Finn","Finnegan","Finnie","Finnigan","Finny","Finstad","Finzer","Fiona","Fionna","Fionnula","Fiora","Fiore","Fiorenza","Fiorenze","Firestone","Firman","Firmin","Firooc","Fisch","Fischer","Fish","Fishback","Fishbein","Fisher","Fishman","Fisk","Fiske","Fisken","Fitting","Fitton","Fitts","Fitz","Fitzger","Fitzgerald","Fitzhugh","Fitzpatrick","Fitzsimmons","Flagler","Flaherty","Flam","Flan","Flanagan","Flanders","Flanigan","Flann","Flanna","Flannery","Flatto","Flavia","Flavian","Flavio","Flavius","Fleck","Fleda","Fleece","Fleeman","Fleeta","Fleischer","Fleisher","Fleisig","Flem","Fleming","Flemings","Flemming","Flessel","Fleta","Fletch","Fletcher","Fleur","Fleurette","Flieger","Flight","Flin","Flinn","Flint","Flip","Flita","Flo","Floeter","Flor","Flora","Florance","Flore","Florella","Florence","Florencia","Florentia","Florenza","Florette","Flori","Floria","Florian","Florida","Floridia","Florie","Florin","Florina","Florinda","Florine","Florio","Floris","Floro","Florri","Florrie","Florry","Flory","Flosi","Floss","Flosser","Flossi","Flossie","Flossy","Flower","Flowers","Floyd","Flss","Flyn","Flynn","Foah","Fogarty","Fogel","Fogg","Fokos","Folberth","Foley","Folger","Follansbee","Follmer","Folly","Folsom","Fonda","Fondea","Fong","Fons","Fonseca","Fonsie","Fontana","Fontes","Fonville","Fonz","Fonzie","Foote","Forbes","Forcier","Ford","Fording","Forelli","Forest","Forester","Forkey","Forland","Forlini","Formenti","Formica","Fornof","Forras","Forrer","Forrest","Forrester","Forsta","Forster","Forsyth","Forta","Fortier","Fortin","Fortna","Fortuna","Fortunato","Fortune","Fortunia","Fortunio","Fortunna","Forward","Foscalina","Fosdick","Foskett","Fosque","Foss","Foster","Fotina","Fotinas","Fougere","Foulk","Four","Foushee","Fowkes","Fowle","Fowler","Fox","Foy","Fraase","Fradin","Frager","Frame","Fran","France","Francene","Frances","Francesca","Francesco","Franchot","Franci","Francie","Francine","Francis","Francisca","Franciscka","Francisco","Franciska","Franciskus","Franck","Francklin","Francklyn","Franckot","Francois","Francoise","Francyne","Franek","Frangos","Frank","Frankel","Frankhouse","Frankie","Franklin","Franklyn","Franky","Franni","Frannie","Franny","Frans","Fransen","Fransis","Fransisco","Frants","Frantz","Franz","Franza","Franzen","Franzoni","Frasch","Frasco","Fraser","Frasier","Frasquito","Fraya","Frayda","Frayne","Fraze","Frazer","Frazier","Frear","Freberg","Frech","Frechette","Fred","Freda","Freddi","Freddie","Freddy","Fredek","Fredel","Fredela","Fredelia","Fredella","Fredenburg","Frederic","Frederica","Frederich","Frederick","Fredericka","Frederico","Frederigo","Frederik","Frederiksen","Frederique","Fredette","Fredi","Fredia","Fredie","Fredkin","Fredra","Fredric","Fredrick","Fredrika","Free","Freeborn","Freed","Freedman","Freeland","Freeman","Freemon","Fregger","Freida","Freiman","Fremont","French","Frendel","Frentz","Frere","Frerichs","Fretwell","Freud","Freudberg","Frey","Freya","Freyah","Freytag","Frick","Fricke","Frida","Friday","Fridell","Fridlund","Fried","Frieda","Friedberg","Friede","Frieder","Friederike","Friedland","Friedlander","Friedly","Friedman","Friedrich","Friedrick","Friend","Frierson","Fries","Frisse","Frissell","Fritts","Fritz","Fritze","Fritzie","Fritzsche","Frodeen","Frodi","Frodin","Frodina","Frodine","Froehlich","Froemming","Froh","Frohman","Frohne","Frolick","Froma","Fromma","Fronia","Fronnia","Fronniah","Frost","Fruin","Frulla","Frum","Fruma","Fry","Fryd","Frydman","Frye","Frymire","Fu","Fuchs","Fugate","Fugazy","Fugere","Fuhrman","Fujio","Ful","Fulbert","Fulbright","Fulcher","Fuld","Fulks","Fuller","Fullerton","Fulmer","Fulmis","Fulton","Fulvi","Fulvia","Fulviah","Funch","Funda","Funk","Furey","Furgeson","Furie","Furiya","Furlani","Furlong","Furmark","Furnary","Furr","Furtek","Fusco","Gaal","Gabbert","Gabbey","Gabbi","Gabbie","Gabby","Gabe","Gabel","Gabey","Gabi","Gabie","Gable","Gabler","Gabor","Gabriel","Gabriela","Gabriele","Gabriell","Gabriella","Gabrielle","Gabrielli","Gabriellia","Gabriello","Gabrielson","Gabrila","Gaby","Gad","Gaddi","Gader","Gadmann","Gadmon","Gae","Gael","Gaelan","Gaeta","Gage","Gagliano","Gagne","Gagnon","Gahan","Gahl","Gaidano","Gaige","Gail","Gaile","Gaillard","Gainer","Gainor","Gaiser","Gaither","Gaivn","Gal","Gala","Galan","Galang","Galanti","Galasyn","Galatea","Galateah","Galatia","Gale","Galen","Galer","Galina","Galitea","Gall","Gallager","Gallagher","Gallard","Gallenz","Galliett","Galligan","Galloway","Gally","Galvan","Galven","Galvin","Gamages","Gamal","Gamali","Gamaliel","Gambell","Gamber","Gambrell","Gambrill","Gamin","Gan","Ganiats","Ganley","Gannes","Gannie","Gannon","Ganny","Gans","Gant","Gapin","Gar","Garald","Garate","Garaway","Garbe","Garber","Garbers","Garceau","Garcia","Garcon","Gard","Garda","Gardal","Gardas","Gardel","Gardell","Gardener","Gardia","Gardie","Gardiner","Gardner","Gardol","Gardy","Gare","Garek","Gareri","Gareth","Garett","Garey","Garfield","Garfinkel","Gargan","Garges","Garibald","Garibold","Garibull","Gariepy","Garik","Garin","Garlaand","Garlan","Garland","Garlanda","Garlen","Garlinda","Garling","Garmaise","Garneau","Garner","Garnes","Garnet","Garnett","Garnette","Garold","Garrard","Garratt","Garrek","Garret","Garreth","Garretson","Garrett","Garrick","Garrik","Garris","Garrison","Garrity","Garrot","Garrott","Garry","Garson","Garth","Garv","Garvey","Garvin","Garvy","Garwin","Garwood","Gary","Garzon","Gascony","Gaskill","Gaskin","Gaskins","Gaspar","Gaspard","Gasparo","Gasper","Gasperoni","Gass","Gasser","Gassman","Gastineau","Gaston","Gates","Gathard","Gathers","Gati","Gatian","Gatias","Gaudet","Gaudette","Gaughan","Gaul","Gauldin","Gaulin","Gault","Gaultiero","Gauntlett","Gausman","Gaut","Gautea","Gauthier","Gautier","Gautious","Gav","Gavan","Gaven","Gavette","Gavin","Gavini","Gavra","Gavrah","Gavriella","Gavrielle","Gavrila","Gavrilla","Gaw","Gawain","Gawen","Gawlas","Gay","Gaye","Gayel","Gayelord","Gayl","Gayla","Gayle","Gayleen","Gaylene","Gayler","Gaylor","Gaylord","Gayn","Gayner","Gaynor","Gazo","Gazzo","Geaghan","Gean","Geanine","Gearalt","Gearard","Gearhart","Gebelein","Gebhardt","Gebler","Geddes","Gee","Geehan","Geer","Geerts","Geesey","Gefell","Gefen","Geffner","Gehlbach","Gehman","Geibel","Geier","Geiger","Geilich","Geis","Geiss","Geithner","Gelasias","Gelasius","Gelb","Geldens","Gelhar","Geller","Gellman","Gelman","Gelya","Gemina","Gemini","Geminian","Geminius","Gemma","Gemmell","Gemoets","Gemperle","Gen","Gena","Genaro","Gene","Genesa","Genesia","Genet","Geneva","Genevieve","Genevra","Genia","Genie","Genisia","Genna","Gennaro","Genni","Gennie","Gennifer","Genny","Geno","Genovera","Gensler","Gensmer","Gent","Gentes","Gentilis","Gentille","Gentry","Genvieve","Geof","Geoff","Geoffrey","Geoffry","Georas","Geordie","Georg","George","Georgeanna","Georgeanne","Georgena","Georges","Georgeta","Georgetta","Georgette","Georgi","Georgia","Georgiana","Georgianna","Georgianne","Georgie","Georgina","Georgine","Georglana","Georgy","Ger","Geraint","Gerald","Geralda","Geraldina","Geraldine","Gerard","Gerardo","Geraud","Gerbold","Gerda","Gerdeen","Gerdi","Gerdy","Gere","Gerek","Gereld","Gereron","Gerfen","Gerge","Gerger","Gerhan","Gerhard","Gerhardine","Gerhardt","Geri","Gerianna","Gerianne","Gerick","Gerik","Gerita","Gerius","Gerkman","Gerlac","Gerladina","Germain","Germaine","German","Germana","Germann","Germano","Germaun","Germayne","Germin","Gernhard","Gerome","Gerrald","Gerrard","Gerri","Gerrie","Gerrilee","Gerrit","Gerry","Gersham","Gershom","Gershon","Gerson","Gerstein","Gerstner","Gert","Gerta","Gerti","Gertie","Gertrud","Gertruda","Gertrude","Gertrudis","Gerty","Gervais","Gervase","Gery","Gesner","Gessner","Getraer","Getter","Gettings","Gewirtz","Ghassan","Gherardi","Gherardo","Gherlein","Ghiselin","Giacamo","Giacinta","Giacobo","Giacomo","Giacopo","Giaimo","Giamo","Gian","Giana","Gianina","Gianna","Gianni","Giannini","Giarla","Giavani","Gib","Gibb","Gibbeon","Gibbie","Gibbon","Gibbons","Gibbs","Gibby","Gibe","Gibeon","Gibert","Gibrian","Gibson","Gibun","Giddings","Gide","Gideon","Giefer","Gies","Giesecke","Giess","Giesser","Giff","Giffard","Giffer","Gifferd","Giffie","Gifford","Giffy","Gigi","Giglio","Gignac","Giguere","Gil","Gilba","Gilbart","Gilbert","Gilberta","Gilberte","Gilbertina","Gilbertine","Gilberto","Gilbertson","Gilboa","Gilburt","Gilbye","Gilchrist","Gilcrest","Gilda","Gildas","Gildea","Gilder","Gildus","Gile","Gilead","Gilemette","Giles","Gilford","Gilges","Giliana","Giliane","Gill","Gillan","Gillead","Gilleod","Gilles","Gillespie","Gillett","Gilletta","Gillette","Gilli","Gilliam","Gillian","Gillie","Gilliette","Gilligan","Gillman","Gillmore","Gilly","Gilman","Gilmer","Gilmore","Gilmour","Gilpin","Gilroy","Gilson","Giltzow","Gilud","Gilus","Gimble","Gimpel","Gina","Ginder","Gine","Ginelle","Ginevra","Ginger","Gingras","Ginni","Ginnie","Ginnifer","Ginny","Gino","Ginsberg","Ginsburg","Gintz","Ginzburg","Gio","Giordano","Giorgi","Giorgia","Giorgio","Giovanna","Giovanni","Gipps","Gipson","Gipsy","Giralda","Giraldo","Girand","Girard","Girardi","Girardo","Giraud","Girhiny","Girish","Girovard","Girvin","Gisela","Giselbert","Gisele","Gisella","Giselle","Gish","Gisser","Gitel","Githens","Gitlow","Gitt","Gittel","Gittle","Giuditta","Giule","Giulia","Giuliana","Giulietta","Giulio","Giuseppe","Giustina","Giustino","Giusto","Given","Giverin","Giza","Gizela","Glaab","Glad","Gladdie","Gladdy","Gladi","Gladine","Gladis","Gladstone","Gladwin","Gladys","Glanti","Glantz","Glanville","Glarum","Glaser","Glasgo","Glass","Glassco","Glassman","Glaudia","Glavin","Gleason","Gleda","Gleeson","Gleich","Glen","Glenda","Glenden","Glendon","Glenine","Glenn","Glenna","Glennie","Glennis","Glennon","Glialentn","Glick","Glimp","Glinys","Glogau","Glori","Gloria","Gloriana","Gloriane","Glorianna","Glory","Glover","Glovsky","Gluck","Glyn","Glynas","Glynda","Glynias","Glynis","Glynn","Glynnis","Gmur","Gnni","Goar","Goat","Gobert","God","Goda","Godard","Godart","Godbeare","Godber","Goddard","Goddart","Godden","Godderd","Godding","Goddord","Godewyn","Godfree","Godfrey","Godfry","Godiva","Godliman","Godred","Godric","Godrich","Godspeed","Godwin","Goebel","Goeger","Goer","Goerke","Goeselt","Goetz","Goff","Goggin","Goines","Gokey","Golanka","Gold","Golda","Goldarina","Goldberg","Golden","Goldenberg","Goldfarb","Goldfinch","Goldi","Goldia","Goldie","Goldin","Goldina","Golding","Goldman","Goldner","Goldshell","Goldshlag","Goldsmith","Goldstein","Goldston","Goldsworthy","Goldwin","Goldy","Goles","Golightly","Gollin","Golliner","Golter","Goltz","Golub","Gomar","Gombach","Gombosi","Gomer","Gomez","Gona","Gonagle","Gone","Gonick","Gonnella","Gonroff","Gonsalve","Gonta","Gonyea","Gonzales","Gonzalez","Gonzalo","Goober","Good","Goodard","Goodden","Goode","Goodhen","Goodill","Goodkin","Goodman","Goodrich","Goodrow","Goodson","Goodspeed","Goodwin","Goody","Goodyear","Googins","Gora","Goran","Goraud","Gord","Gordan","Gorden","Gordie","Gordon","Gordy","Gore","Goren","Gorey","Gorga","Gorges","Gorlicki","Gorlin","Gorman","Gorrian","Gorrono","Gorski","Gorton","Gosnell","Gosney","Goss","Gosselin","Gosser","Gotcher","Goth","Gothar","Gothard","Gothart","Gothurd","Goto","Gottfried","Gotthard","Gotthelf","Gottlieb","Gottuard","Gottwald","Gough","Gould","Goulden","Goulder","Goulet","Goulette","Gove","Gow","Gower","Gowon","Gowrie","Graaf","Grace","Graces","Gracia","Gracie","Gracye","Gradeigh","Gradey","Grados","Grady","Grae","Graehl","Graehme","Graeme","Graf","Graff","Graham","Graig","Grail","Gram","Gran","Grand","Grane","Graner","Granese","Grange","Granger","Grani","Grania","Graniah","Graniela","Granlund","Grannia","Granniah","Grannias","Grannie","Granny","Granoff","Grant","Grantham","Granthem","Grantland","Grantley","Granville","Grassi","Grata","Grath","Grati","Gratia","Gratiana","Gratianna","Gratt","Graubert","Gravante","Graves","Gray","Graybill","Grayce","Grayson","Grazia","Greabe","Grearson","Gredel","Greeley","Green","Greenberg","Greenburg","Greene","Greenebaum","Greenes","Greenfield","Greenland","Greenleaf","Greenlee","Greenman","Greenquist","Greenstein","Greenwald","Greenwell","Greenwood","Greer","Greerson","Greeson","Grefe","Grefer","Greff","Greg","Grega","Gregg","Greggory","Greggs","Gregoire","Gregoor","Gregor","Gregorio","Gregorius","Gregory","Gregrory","Gregson","Greiner","Grekin","Grenier","Grenville","Gresham","Greta","Gretal","Gretchen","Grete","Gretel","Grethel","Gretna","Gretta","Grevera","Grew","Grewitz","Grey","Greyso","Greyson","Greysun","Grider","Gridley","Grier","Grieve","Griff","Griffie","Griffin","Griffis","Griffith","Griffiths","Griffy","Griggs","Grigson","Grim","Grimaldi","Grimaud","Grimbal","Grimbald","Grimbly","Grimes","Grimona","Grimonia","Grindlay","Grindle","Grinnell","Gris","Griselda","Griseldis","Grishilda","Grishilde","Grissel","Grissom","Gristede","Griswold","Griz","Grizel","Grizelda","Groark","Grobe","Grochow","Grodin","Grof","Grogan","Groh","Gromme","Grondin","Gronseth","Groome","Groos","Groot","Grory","Grosberg","Groscr","Grose","Grosmark","Gross","Grossman","Grosvenor","Grosz","Grote","Grounds","Grous","Grove","Groveman","Grover","Groves","Grubb","Grube","Gruber","Grubman","Gruchot","Grunberg","Grunenwald","Grussing","Gruver","Gschu","Guadalupe","Gualterio","Gualtiero","Guarino","Gudren","Gudrin","Gudrun","Guendolen","Guenevere","Guenna","Guenzi","Guerin","Guerra","Guevara","Guglielma","Guglielmo","Gui","Guibert","Guido","Guidotti","Guilbert","Guild","Guildroy","Guillaume","Guillema","Guillemette","Guillermo","Guimar","Guimond","Guinevere","Guinn","Guinna","Guise","Gujral","Gula","Gulgee","Gulick","Gun","Gunar","Gunas","Gundry","Gunilla","Gunn","Gunnar","Gunner","Gunning","Guntar","Gunter","Gunthar","Gunther","Gunzburg","Gupta","Gurango","Gurevich","Guria","Gurias","Gurl","Gurney","Gurolinick","Gurtner","Gus","Gusba","Gusella","Guss","Gussi","Gussie","Gussman","Gussy","Gusta","Gustaf","Gustafson","Gustafsson","Gustav","Gustave","Gustavo","Gustavus","Gusti","Gustie","Gustin","Gusty","Gut","Guthrey","Guthrie","Guthry","Gutow","Guttery","Guy","Guyer","Guyon","Guzel","Gwen","Gwendolen","Gwendolin","Gwendolyn","Gweneth","Gwenette","Gwenn","Gwenneth","Gwenni","Gwennie","Gwenny","Gwenora","Gwenore","Gwyn","Gwyneth","Gwynne","Gyasi","Gyatt","Gyimah","Gylys","Gypsie","Gypsy","Gytle","Ha","Haag","Haakon","Haas","Haase","Haberman","Hach","Hachman","Hachmann","Hachmin","Hackathorn","Hacker","Hackett","Hackney","Had","Haddad","Hadden","Haden","Hadik","Hadlee","Hadleigh","Hadley","Hadria","Hadrian","Hadsall","Hadwin","Hadwyn","Haeckel","Haerle","Haerr","Haff","Hafler","Hagai","Hagan","Hagar","Hagen","Hagerman","Haggai","Haggar","Haggerty","Haggi","Hagi","Hagood","Hahn","Hahnert","Hahnke","Haida","Haig","Haile","Hailee","Hailey","Haily","Haim","Haimes","Haines","Hak","Hakan","Hake","Hakeem","Hakim","Hako","Hakon","Hal","Haland","Halbeib","Halbert","Halda","Haldan","Haldane","Haldas","Haldeman","Halden","Haldes","Haldi","Haldis","Hale","Haleigh","Haletky","Haletta","Halette","Haley","Halfdan","Halfon","Halford","Hali","Halie","Halima","Halimeda","Hall","Halla","Hallagan","Hallam","Halland","Halle","Hallee","Hallerson","Hallett","Hallette","Halley","Halli","Halliday","Hallie","Hallock","Hallsy","Hallvard","Hally","Halona","Halonna","Halpern","Halsey","Halstead","Halsted","Halsy","Halvaard","Halverson","Ham","Hama","Hamachi","Hamal","Haman","Hamann","Hambley","Hamburger","Hamel","Hamer","Hamford","Hamforrd","Hamfurd","Hamid","Hamil","Hamilton","Hamish","Hamlani","Hamlen","Hamlet","Hamlin","Hammad","Hammel","Hammer","Hammerskjold","Hammock","Hammond","Hamner","Hamnet","Hamo","Hamon","Hampton","Hamrah","Hamrnand","Han","Hana","Hanae","Hanafee","Hanako","Hanan","Hance","Hancock","Handal","Handbook","Handel","Handler","Hands","Handy","Haney","Hanford","Hanforrd","Hanfurd","Hank","Hankins","Hanleigh","Hanley","Hanna","Hannah","Hannan","Hanni","Hannibal","Hannie","Hannis","Hannon","Hannover","Hannus","Hanny","Hanover","Hans","Hanschen","Hansel","Hanselka","Hansen","Hanser","Hanshaw","Hansiain","Hanson","Hanus","Hanway","Hanzelin","Happ","Happy","Hapte","Hara","Harald","Harbard","Harberd","Harbert","Harbird","Harbison","Harbot","Harbour","Harcourt","Hardan","Harday","Hardden","Hardej","Harden","Hardi","Hardie","Hardigg","Hardin","Harding","Hardman","Hardner","Hardunn","Hardwick","Hardy","Hare","Harelda","Harewood","Harhay","Harilda","Harim","Harl","Harlamert","Harlan","Harland","Harle","Harleigh","Harlen","Harlene","Harley","Harli","Harlie","Harlin","Harlow","Harman","Harmaning","Harmon","Harmonia","Harmonie","Harmony","Harms","Harned","Harneen","Harness","Harod","Harold","Harolda","Haroldson","Haroun","Harp","Harper","Harpole","Harpp","Harragan","Harrell","Harri","Harrie","Harriet","Harriett","Harrietta","Harriette","Harriman","Harrington","Harriot","Harriott","Harris","Harrison","Harrod","Harrow","Harrus","Harry","Harshman","Harsho","Hart","Harte","Hartfield","Hartill","Hartley","Hartman","Hartmann","Hartmunn","Hartnett","Harts","Hartwell","Harty","Hartzel","Hartzell","Hartzke","Harv","Harvard","Harve","Harvey","Harvie","Harvison","Harwell","Harwill","Harwilll","Harwin","Hasan","Hasen","Hasheem","Hashim","Hashimoto","Hashum","Hasin","Haskel","Haskell","Haskins","Haslam","Haslett","Hasseman","Hassett","Hassi","Hassin","Hastie","Hastings","Hasty","Haswell","Hatch","Hatcher","Hatfield","Hathaway","Hathcock","Hatti","Hattie","Hatty","Hau","Hauck","Hauge","Haugen","Hauger","Haughay","Haukom","Hauser","Hausmann","Hausner","Havard","Havelock","Haveman","Haven","Havener","Havens","Havstad","Hawger","Hawk","Hawken","Hawker","Hawkie","Hawkins","Hawley","Hawthorn","Hax","Hay","Haya","Hayashi","Hayden","Haydon","Haye","Hayes","Hayley","Hayman","Haymes","Haymo","Hayne","Haynes","Haynor","Hayott","Hays","Hayse","Hayton","Hayward","Haywood","Hayyim","Hazaki","Hazard","Haze","Hazeghi","Hazel","Hazelton","Hazem","Hazen","Hazlett","Hazlip","Head","Heady","Healey","Healion","Heall","Healy","Heaps","Hearn","Hearsh","Heater","Heath","Heathcote","Heather","Hebbe","Hebe","Hebel","Heber","Hebert","Hebner","Hebrew","Hecht","Heck","Hecker","Hecklau","Hector","Heda","Hedberg","Hedda","Heddi","Heddie","Heddy","Hedelman","Hedgcock","Hedges","Hedi","Hedley","Hedva","Hedvah","Hedve","Hedveh","Hedvig","Hedvige","Hedwig","Hedwiga","Hedy","Heeley","Heer","Heffron","Hefter","Hegarty","Hege","Heger","Hegyera","Hehre","Heid","Heida","Heidi","Heidie","Heidt","Heidy","Heigho","Heigl","Heilman","Heilner","Heim","Heimer","Heimlich","Hein","Heindrick","Heiner","Heiney","Heinrich","Heinrick","Heinrik","Heinrike","Heins","Heintz","Heise","Heisel","Heiskell","Heisser","Hekker","Hekking","Helaina","Helaine","Helali","Helban","Helbon","Helbona","Helbonia","Helbonna","Helbonnah","Helbonnas","Held","Helen","Helena","Helene","Helenka","Helfand","Helfant","Helga","Helge","Helgeson","Hellene","Heller","Helli","Hellman","Helm","Helman","Helmer","Helms","Helmut","Heloise","Helprin","Helsa","Helse","Helsell","Helsie","Helve","Helyn","Heman","Hembree","Hemingway","Hemminger","Hemphill","Hen","Hendel","Henden","Henderson","Hendon","Hendren","Hendrick","Hendricks","Hendrickson","Hendrik","Hendrika","Hendrix","Hendry","Henebry","Heng","Hengel","Henghold","Henig","Henigman","Henka","Henke","Henleigh","Henley","Henn","Hennahane","Hennebery","Hennessey","Hennessy","Henni","Hennie","Henning","Henri","Henricks","Henrie","Henrieta","Henrietta","Henriette","Henriha","Henrik","Henrion","Henrique","Henriques","Henry","Henryetta","Henryk","Henryson","Henson","Hentrich","Hephzibah","Hephzipa","Hephzipah","Heppman","Hepsiba","Hepsibah","Hepza","Hepzi","Hera","Herald","Herb","Herbert","Herbie","Herbst","Herby","Herc","Hercule","Hercules","Herculie","Hereld","Heriberto","Heringer","Herm","Herman","Hermann","Hermes","Hermia","Hermie","Hermina","Hermine","Herminia","Hermione","Hermon","Hermosa","Hermy","Hernandez","Hernando","Hernardo","Herod","Herodias","Herold","Heron","Herr","Herra","Herrah","Herrera","Herrick","Herries","Herring","Herrington","Herriott","Herrle","Herrmann","Herrod","Hersch","Herschel","Hersh","Hershel","Hershell","Herson","Herstein","Herta","Hertberg","Hertha","Hertz","Hertzfeld","Hertzog","Herv","Herve","Hervey","Herwick","Herwig","Herwin","Herzberg","Herzel","Herzen","Herzig","Herzog","Hescock","Heshum","Hesketh","Hesky","Hesler","Hesper","Hess","Hessler","Hessney","Hesta","Hester","Hesther","Hestia","Heti","Hett","Hetti","Hettie","Hetty","Heurlin","Heuser","Hew","Hewart","Hewe","Hewes","Hewet","Hewett","Hewie","Hewitt","Hey","Heyde","Heydon","Heyer","Heyes","Heyman","Heymann","Heyward","Heywood","Hezekiah","Hi","Hibben","Hibbert","Hibbitts","Hibbs","Hickey","Hickie","Hicks","Hidie","Hieronymus","Hiett","Higbee","Higginbotham","Higgins","Higginson","Higgs","High","Highams","Hightower","Higinbotham","Higley","Hijoung","Hike","Hilaire","Hilar","Hilaria","Hilario","Hilarius","Hilary","Hilbert","Hild","Hilda","Hildagard","Hildagarde","Hilde","Hildebrandt","Hildegaard","Hildegard","Hildegarde","Hildick","Hildie","Hildy","Hilel","Hill","Hillard","Hillari","Hillary","Hilleary","Hillegass","Hillel","Hillell","Hiller","Hillery","Hillhouse","Hilliard","Hilliary","Hillie","Hillier","Hillinck","Hillman","Hills","Hilly","Hillyer","Hiltan","Hilten","Hiltner","Hilton","Him","Hime","Himelman","Hinch","Hinckley","Hinda","Hindorff","Hindu","Hines","Hinkel","Hinkle","Hinman","Hinson","Hintze","Hinze","Hippel","Hirai","Hiram","Hirasuna","Hiro","Hiroko","Hiroshi","Hirsch","Hirschfeld","Hirsh","Hirst","Hirz","Hirza","Hisbe","Hitchcock","Hite","Hitoshi","Hitt","Hittel","Hizar","Hjerpe","Hluchy","Ho","Hoag","Hoagland","Hoang","Hoashis","Hoban","Hobard","Hobart","Hobbie","Hobbs","Hobey","Hobie","Hochman","Hock","Hocker","Hodess","Hodge","Hodges","Hodgkinson","Hodgson","Hodosh","Hoebart","Hoeg","Hoehne","Hoem","Hoenack","Hoes","Hoeve","Hoffarth","Hoffer","Hoffert","Hoffman","Hoffmann","Hofmann","Hofstetter","Hogan","Hogarth","Hogen","Hogg","Hogle","Hogue","Hoi","Hoisch","Hokanson","Hola","Holbrook","Holbrooke","Holcman","Holcomb","Holden","Holder","Holds","Hole","Holey","Holladay","Hollah","Holland","Hollander","Holle","Hollenbeck","Holleran","Hollerman","Holli","Hollie","Hollinger","Hollingsworth","Hollington","Hollis","Hollister","Holloway","Holly","Holly-Anne","Hollyanne","Holman","Holmann","Holmen","Holmes","Holms","Holmun","Holna","Holofernes","Holsworth","Holt","Holton","Holtorf","Holtz","Holub","Holzman","Homans","Home","Homer","Homere","Homerus","Homovec","Honan","Honebein","Honey","Honeyman","Honeywell","Hong","Honig","Honna","Honniball","Honor","Honora","Honoria","Honorine","Hoo","Hooge","Hook","Hooke","Hooker","Hoon","Hoopen","Hooper","Hoopes","Hootman","Hoover","Hope","Hopfinger","Hopkins","Hoppe","Hopper","Horace","Horacio","Horan","Horatia","Horatio","Horatius","Horbal","Horgan","Horick","Horlacher","Horn","Horne","Horner","Hornstein","Horodko","Horowitz","Horsey","Horst","Hort","Horten","Hortensa","Hortense","Hortensia","Horter","Horton","Horvitz","Horwath","Horwitz","Hosbein","Hose","Hosea","Hoseia","Hosfmann","Hoshi","Hoskinson","Hospers","Hotchkiss","Hotze","Hough","Houghton","Houlberg","Hound","Hourigan","Hourihan","Housen","Houser","Houston","Housum","Hovey","How","Howard","Howarth","Howe","Howell","Howenstein","Howes","Howey","Howie","Howlan","Howland","Howlend","Howlond","Howlyn","Howund","Howzell","Hoxie","Hoxsie","Hoy","Hoye","Hoyt","Hrutkay","Hsu","Hu","Huai","Huan","Huang","Huba","Hubbard","Hubble","Hube","Huber","Huberman","Hubert","Huberto","Huberty","Hubey","Hubie","Hubing","Hubsher","Huckaby","Huda","Hudgens","Hudis","Hudnut","Hudson","Huebner","Huei","Huesman","Hueston","Huey","Huff","Hufnagel","Huggins","Hugh","Hughes","Hughett","Hughie","Hughmanick","Hugibert","Hugo","Hugon","Hugues","Hui","Hujsak","Hukill","Hulbard","Hulbert","Hulbig","Hulburt","Hulda","Huldah","Hulen","Hull","Hullda","Hultgren","Hultin","Hulton","Hum","Humbert","Humberto","Humble","Hume","Humfrey","Humfrid","Humfried","Hummel","Humo","Hump","Humpage","Humph","Humphrey","Hun","Hunfredo","Hung","Hungarian","Hunger","Hunley","Hunsinger","Hunt","Hunter","Huntingdon","Huntington","Huntlee","Huntley","Huoh","Huppert","Hurd","Hurff","Hurlbut","Hurlee","Hurleigh","Hurless","Hurley","Hurlow","Hurst","Hurty","Hurwit","Hurwitz","Husain","Husch","Husein","Husha","Huskamp","Huskey","Hussar","Hussein","Hussey","Huston","Hut","Hutchings","Hutchins","Hutchinson","Hutchison","Hutner","Hutson","Hutt","Huttan","Hutton","Hux","Huxham","Huxley","Hwang","Hwu","Hy","Hyacinth","Hyacintha","Hyacinthe","Hyacinthia","Hyacinthie","Hyams","Hyatt","Hyde","Hylan","Hyland","Hylton","Hyman","Hymen","Hymie","Hynda","Hynes","Hyo","Hyozo","Hyps","Hyrup","Iago","Iain","Iams","Ian","Iand","Ianteen","Ianthe","Iaria","Iaverne","Ib","Ibbetson","Ibbie","Ibbison","Ibby","Ibrahim","Ibson","Ichabod","Icken","Id","Ida","Idalia","Idalina","Idaline","Idalla","Idden","Iddo","Ide","Idel","Idelia","Idell","Idelle","Idelson","Iden","Idette","Idleman","Idola","Idolah","Idolla","Idona","Idonah","Idonna","Idou","Idoux","Idzik","Iene","Ier","Ierna","Ieso","Ietta","Iey","Ifill","Igal","Igenia","Iggie","Iggy","Iglesias","Ignace","Ignacia","Ignacio","Ignacius","Ignatia","Ignatius","Ignatz","Ignatzia","Ignaz","Ignazio","Igor","Ihab","Iiette","Iila","Iinde","Iinden","Iives","Ike","Ikeda","Ikey","Ikkela","Ilaire","Ilan","Ilana","Ilario","Ilarrold","Ilbert","Ileana","Ileane","Ilene","Iline","Ilise","Ilka","Ilke","Illa","Illene","Illona","Illyes","Ilona","Ilonka","Ilowell","Ilsa","Ilse","Ilwain","Ilysa","Ilyse","Ilyssa","Im","Ima","Imalda","Iman","Imelda","Imelida","Imena","Immanuel","Imogen","Imogene","Imojean","Imray","Imre","Imtiaz","Ina","Incrocci","Indihar","Indira","Inerney","Ines","Inesita","Ineslta","Inessa","Inez","Infeld","Infield","Ing","Inga","Ingaberg","Ingaborg","Ingalls","Ingamar","Ingar","Inge","Ingeberg","Ingeborg","Ingelbert","Ingemar","Inger","Ingham","Inglebert","Ingles","Inglis","Ingmar","Ingold","Ingra","Ingraham","Ingram","Ingrid","Ingrim","Ingunna","Ingvar","Inigo","Inkster","Inman","Inna","Innes","Inness","Innis","Inoue","Intisar","Intosh","Intyre","Inverson","Iny","Ioab","Iolande","Iolanthe","Iolenta","Ion","Iona","Iong","Iorgo","Iorgos","Iorio","Iormina","Iosep","Ioved","Iover","Ioves","Iow","Ioyal","Iphagenia","Iphigenia","Iphigeniah","Iphlgenia","Ira","Iran","Irby","Iredale","Ireland","Irena","Irene","Irfan","Iridis","Iridissa","Irina","Iris","Irisa","Irish","Irita","Irma","Irme","Irmgard","Irmina","Irmine","Irra","Irv","Irvin","Irvine","Irving","Irwin","Irwinn","Isa","Isaac","Isaacs","Isaacson","Isaak","Isabea","Isabeau","Isabel","Isabelita","Isabella","Isabelle","Isac","Isacco","Isador","Isadora","Isadore","Isahella","Isaiah","Isak","Isbel","Isbella","Isborne","Iseabal","Isherwood","Ishii","Ishmael","Ishmul","Isia","Isiah","Isiahi","Isidor","Isidora","Isidore","Isidoro","Isidro","Isis","Isla","Islaen","Island","Isle","Islean","Isleana","Isleen","Islek","Isma","Isman","Isobel","Isola","Isolda","Isolde","Isolt","Israel","Israeli","Issi","Issiah","Issie","Issy","Ita","Itagaki","Itch","Ithaman","Ithnan","Itin","Iva","Ivah","Ivan","Ivana","Ivanah","Ivanna","Ivar","Ivatts","Ive","Ivens","Iver","Ivers","Iverson","Ives","Iveson","Ivett","Ivette","Ivetts","Ivey","Ivie","Ivo","Ivon","Ivonne","Ivor","Ivory","Ivy","Iy","Iyre","Iz","Izaak","Izabel","Izak","Izawa","Izy","Izzy","Ja","Jaal","Jaala","Jaan","Jaban","Jabe","Jabez","Jabin","Jablon","Jabon","Jac","Jacenta","Jacey","Jacie","Jacinda","Jacinta","Jacintha","Jacinthe","Jacinto","Jack","Jackelyn","Jacki","Jackie","Jacklin","Jacklyn","Jackquelin","Jackqueline","Jackson","Jacky","Jaclin","Jaclyn","Jaco","Jacob","Jacoba","Jacobah","Jacobba","Jacobina","Jacobine","Jacobo","Jacobs","Jacobsen","Jacobsohn","Jacobson","Jacoby","Jacquelin","Jacqueline","Jacquelyn","Jacquelynn","Jacquenetta","Jacquenette","Jacques","Jacquet","Jacquetta","Jacquette","Jacqui","Jacquie","Jacy","Jacynth","Jada","Jadd","Jadda","Jaddan","Jaddo","Jade","Jadwiga","Jae","Jaeger","Jaehne","Jael","Jaela","Jaella","Jaenicke","Jaf","Jaffe","Jagir","Jago","Jahdai","Jahdal","Jahdiel","Jahdol","Jahn","Jahncke","Jaime","Jaime ","Jaimie","Jain","Jaine","Jair","Jairia","Jake","Jakie","Jakob","Jakoba","Jala","Jalbert","Jallier","Jamaal","Jamal","Jamel","James","Jameson","Jamesy","Jamey","Jami","Jamie","Jamieson","Jamil","Jamila","Jamill","Jamilla","Jamille","Jamima","Jamin","Jamison","Jammal","Jammie","Jammin","Jamnes","Jamnis","Jan","Jana","Janaya","Janaye","Jandel","Jandy","Jane","Janean","Janeczka","Janeen","Janek","Janel","Janela","Janella","Janelle","Janene","Janenna","Janerich","Janessa","Janet","Janeta","Janetta","Janette","Janeva","Janey","Jangro","Jania","Janice","Janicki","Janie","Janifer","Janik","Janina","Janine","Janis","Janith","Janiuszck","Janka","Jankell","Jankey","Jann","Janna","Jannel","Jannelle","Jannery","Janos","Janot","Jansen","Jansson","Januarius","January","Januisz","Janus","Jany","Janyte","Japeth","Japha","Japheth","Jaqitsch","Jaquelin","Jaquelyn","Jaquenetta","Jaquenette","Jaquiss","Jaquith","Jara","Jarad","Jard","Jardena","Jareb","Jared","Jarek","Jaret","Jari","Jariah","Jarib","Jarid","Jarietta","Jarita","Jarl","Jarlath","Jarlathus","Jarlen","Jarnagin","Jarrad","Jarred","Jarrell","Jarret","Jarrett","Jarrid","Jarrod","Jarrow","Jarv","Jarvey","Jarvis","Jary","Jase","Jasen","Jasik","Jasisa","Jasmin","Jasmina","Jasmine","Jason","Jasper","Jasun","Jauch","Jaunita","Javed","Javier","Javler","Jaworski","Jay","Jaycee","Jaye","Jaylene","Jayme","Jaymee","Jaymie","Jayne","Jaynell","Jaynes","Jayson","Jazmin","Jdavie","Jea","Jean","Jean-Claude","Jeana","Jeane","Jeanelle","Jeanette","Jeanie","Jeanine","Jeanna","Jeanne","Jeannette","Jeannie","Jeannine","Jeavons","Jeaz","Jeb","Jecho","Jecoa","Jecon","Jeconiah","Jed","Jedd","Jeddy","Jedediah","Jedidiah","Jedlicka","Jedthus","Jeff","Jeffcott","Jefferey","Jeffers","Jefferson","Jeffery","Jeffie","Jeffrey","Jeffries","Jeffry","Jeffy","Jegar","Jeggar","Jegger","Jehanna","Jehiah","Jehial","Jehias","Jehiel","Jehius","Jehoash","Jehovah","Jehu","Jelena","Jelene","Jelks","Jelle","Jelsma","Jem","Jemena","Jemie","Jemima","Jemimah","Jemina","Jeminah","Jemine","Jemma","Jemmie","Jemmy","Jempty","Jemy","Jen","Jena","Jenda","Jenei","Jenelle","Jenesia","Jenette","Jeni","Jenica","Jeniece","Jenifer","Jeniffer","Jenilee","Jenine","Jenkel","Jenkins","Jenks","Jenn","Jenna","Jenne","Jennee","Jenness","Jennette","Jenni","Jennica","Jennie","Jennifer","Jennilee","Jennine","Jennings","Jenny","Jeno","Jens","Jensen","Jentoft","Jephthah","Jephum","Jepson","Jepum","Jer","Jerad","Jerald","Jeraldine","Jeralee","Jeramey","Jeramie","Jere","Jereld","Jereme","Jeremiah","Jeremias","Jeremie","Jeremy","Jeri","Jeritah","Jermain","Jermaine","Jerman","Jermayne","Jermyn","Jerol","Jerold","Jeroma","Jerome","Jeromy","Jerri","Jerrie","Jerrilee","Jerrilyn","Jerrine","Jerrol","Jerrold","Jerroll","Jerrome","Jerry","Jerrylee","Jerusalem","Jervis","Jerz","Jesh","Jesher","Jess","Jessa","Jessabell","Jessalin","Jessalyn","Jessamine","Jessamyn","Jesse","Jessee","Jesselyn","Jessen","Jessey","Jessi","Jessica","Jessie","Jessika","Jessy","Jestude","Jesus","Jeth","Jethro","Jeu","Jeunesse","Jeuz","Jevon","Jew","Jewel","Jewell","Jewelle","Jewett","Jews","Jez","Jezabel","Jezabella","Jezabelle","Jezebel","Jezreel","Ji","Jill","Jillana","Jillane","Jillayne","Jilleen","Jillene","Jilli","Jillian","Jillie","Jilly","Jim","Jimmie","Jimmy","Jinny","Jit","Jo","Jo Ann","Jo-Ann","Jo-Anne","JoAnn","JoAnne","Joab","Joachim","Joachima","Joacima","Joacimah","Joan","Joana","Joane","Joanie","Joann","Joanna","Joanne","Joannes","Joao","Joappa","Joaquin","Joash","Joashus","Job","Jobe","Jobey","Jobi","Jobie","Jobina","Joby","Jobye","Jobyna","Jocelin","Joceline","Jocelyn","Jocelyne","Jochbed","Jochebed","Jock","Jocko","Jodee","Jodi","Jodie","Jodoin","Jody","Joe","Joeann","Joed","Joel","Joela","Joelie","Joell","Joella","Joelle","Joellen","Joelly","Joellyn","Joelynn","Joerg","Joete","Joette","Joey","Joh","Johan","Johanan","Johann","Johanna","Johannah","Johannes","Johannessen","Johansen","Johathan","Johen","Johiah","Johm","John","Johna","Johnath","Johnathan","Johnathon","Johnette","Johnna","Johnnie","Johnny","Johns","Johnson","Johnsson","Johnsten","Johnston","Johnstone","Johny","Johppa","Johppah","Johst","Joice","Joiner","Jojo","Joktan","Jola","Jolanta","Jolda","Jolee","Joleen","Jolene","Jolenta","Joletta","Joli","Jolie","Joliet","Joline","Jollanta","Jollenta","Joly","Jolyn","Jolynn","Jon","Jona","Jonah","Jonas","Jonathan","Jonathon","Jonati","Jone","Jonell","Jones","Jonette","Joni","Jonie","Jonina","Jonis","Jonme","Jonna","Jonny","Joo","Joon","Joost","Jopa","Jordain","Jordan","Jordana","Jordanna","Jordans","Jordanson","Jordison","Jordon","Jorey","Jorgan","Jorge","Jorgensen","Jorgenson","Jori","Jorie","Jorin","Joris","Jorrie","Jorry","Jory","Jos","Joscelin","Jose","Josee","Josefa","Josefina","Joseito","Joselow","Joselyn","Joseph","Josepha","Josephina","Josephine","Josephson","Joses","Josey","Josh","Joshi","Joshia","Joshua","Joshuah","Josi","Josiah","Josias","Josie","Josler","Joslyn","Josselyn","Josy","Jotham","Joub","Joung","Jourdain","Jourdan","Jovi","Jovia","Jovita","Jovitah","Jovitta","Jowett","Joy","Joya","Joyan","Joyann","Joyce","Joycelin","Joye","Jozef","Jsandye","Juan","Juana","Juanita","Juanne","Juback","Jud","Judah","Judas","Judd","Jude","Judenberg","Judi","Judie","Judith","Juditha","Judon","Judsen","Judson","Judus","Judy","Judye","Jueta","Juetta","Juieta","Jule","Julee","Jules","Juley","Juli","Julia","Julian","Juliana","Juliane","Juliann","Julianna","Julianne","Juliano","Julide","Julie","Julienne","Juliet","Julieta","Julietta","Juliette","Julina","Juline","Julio","Julis","Julissa","Julita","Julius","Jumbala","Jump","Jun","Juna","June","Junette","Jung","Juni","Junia","Junie","Junieta","Junina","Junius","Junji","Junko","Junna","Junno","Juno","Jurdi","Jurgen","Jurkoic","Just","Justen","Juster","Justicz","Justin","Justina","Justine","Justinian","Justinn","Justino","Justis","Justus","Juta","Jutta","Juxon","Jyoti","Kablesh","Kacerek","Kacey","Kachine","Kacie","Kacy","Kaczer","Kaden","Kadner","Kado","Kaela","Kaenel","Kaete","Kafka","Kahaleel","Kahl","Kahle","Kahler","Kahlil","Kahn","Kai","Kaia","Kaila","Kaile","Kailey","Kain","Kaine","Kaiser","Kaitlin","Kaitlyn","Kaitlynn","Kaiulani","Kaja","Kajdan","Kakalina","Kal","Kala","Kalagher","Kalasky","Kalb","Kalbli","Kale","Kaleb","Kaleena","Kalfas","Kali","Kalie","Kalikow","Kalil","Kalila","Kalin","Kalina","Kalinda","Kalindi","Kaliope","Kaliski","Kalk","Kall","Kalle","Kalli","Kallick","Kallista","Kallman","Kally","Kalman","Kalmick","Kaltman","Kalvin","Kalvn","Kam","Kama","Kamal","Kamaria","Kamat","Kameko","Kamerman","Kamila","Kamilah","Kamillah","Kamin","Kammerer","Kamp","Kampmann","Kampmeier","Kan","Kanal","Kancler","Kandace","Kandy","Kane","Kania","Kannan","Kannry","Kano","Kant","Kanter","Kantor","Kantos","Kanya","Kape","Kaplan","Kapoor","Kapor","Kappel","Kappenne","Kara","Kara-Lynn","Karalee","Karalynn","Karame","Karas","Karb","Kare","Karee","Kareem","Karel","Karen","Karena","Kari","Karia","Karie","Karil","Karilla","Karilynn","Karim","Karin","Karina","Karine","Kariotta","Karisa","Karissa","Karita","Karl","Karla","Karlan","Karlee","Karleen","Karlen","Karlene","Karlens","Karli","Karlie","Karlik","Karlin","Karlis","Karlise","Karlotta","Karlotte","Karlow","Karly","Karlyn","Karmen","Karna","Karney","Karol","Karola","Karole","Karolina","Karoline","Karoly","Karolyn","Karon","Karp","Karr","Karrah","Karrie","Karry","Karsten","Kartis","Karwan","Kary","Karyl","Karylin","Karyn","Kasevich","Kasey","Kashden","Kask","Kaslik","Kaspar","Kasper","Kass","Kassab","Kassandra","Kassaraba","Kassel","Kassey","Kassi","Kassia","Kassie","Kassity","Kast","Kat","Kata","Katalin","Kataway","Kate","Katee","Katerina","Katerine","Katey","Kath","Katha","Katharina","Katharine","Katharyn","Kathe","Katherin","Katherina","Katherine","Katheryn","Kathi","Kathie","Kathleen","Kathlene","Kathlin","Kathrine","Kathryn","Kathryne","Kathy","Kathye","Kati","Katie","Katina","Katine","Katinka","Katlaps","Katleen","Katlin","Kato","Katonah","Katrina","Katrine","Katrinka","Katsuyama","Katt","Katti","Kattie","Katuscha","Katusha","Katushka","Katy","Katya","Katz","Katzen","Katzir","Katzman","Kauffman","Kauffmann","Kaufman","Kaufmann","Kaule","Kauppi","Kauslick","Kavanagh","Kavanaugh","Kavita","Kawai","Kawasaki","Kay","Kaya","Kaycee","Kaye","Kayla","Kayle","Kaylee","Kayley","Kaylil","Kaylyn","Kayne","Kaz","Kazim","Kazimir","Kazmirci","Kazue","Kealey","Kean","Keane","Keare","Kearney","Keary","Keating","Keavy","Kee","Keefe","Keefer","Keegan","Keel","Keelby","Keele","Keeler","Keeley","Keelia","Keelin","Keely","Keen","Keenan","Keene","Keener","Keese","Keeton","Keever","Keffer","Keg","Kegan","Keheley","Kehoe","Kehr","Kei","Keifer","Keiko","Keil","Keily","Keir","Keisling","Keith","Keithley","Kela","Kelbee","Kelby","Kelcey","Kelci","Kelcie","Kelcy","Kelda","Keldah","Keldon","Kele","Keli","Keligot","Kelila","Kella","Kellby","Kellda","Kelleher","Kellen","Kellene","Keller","Kelley","Kelli","Kellia","Kellie","Kellina","Kellsie","Kelly","Kellyann","Kellyn","Kelsey","Kelsi","Kelson","Kelsy","Kelton","Kelula","Kelvin","Kelwen","Kelwin","Kelwunn","Kemble","Kemeny","Kemme","Kemp","Kempe","Kemppe","Ken","Kenay","Kenaz","Kendal","Kendall","Kendell","Kendra","Kendrah","Kendre","Kendrick","Kendricks","Kendry","Kendy","Kendyl","Kenelm","Kenison","Kenji","Kenlay","Kenlee","Kenleigh","Kenley","Kenn","Kenna","Kennan","Kennard","Kennedy","Kennet","Kenneth","Kennett","Kenney","Kennie","Kennith","Kenny","Kenon","Kenric","Kenrick","Kensell","Kent","Kenta","Kenti","Kentiga","Kentigera","Kentigerma","Kentiggerma","Kenton","Kenward","Kenway","Kenwee","Kenweigh","Kenwood","Kenwrick","Kenyon","Kenzi","Kenzie","Keon","Kepner","Keppel","Ker","Kerby","Kerek","Kerekes","Kerge","Keri","Keriann","Kerianne","Kerin","Kerk","Kerman","Kermie","Kermit","Kermy","Kern","Kernan","Kerns","Kerr","Kerri","Kerrie","Kerril","Kerrill","Kerrin","Kerrison","Kerry","Kersten","Kerstin","Kerwin","Kerwinn","Kerwon","Kery","Kesia","Kesley","Keslie","Kessel","Kessia","Kessiah","Kessler","Kester","Ketchan","Ketchum","Ketti","Kettie","Ketty","Keung","Kev","Kevan","Keven","Keverian","Keverne","Kevin","Kevina","Kevon","Kevyn","Key","Keyek","Keyes","Keynes","Keyser","Keyte","Kezer","Khai","Khajeh","Khalid","Khalil","Khalin","Khalsa","Khan","Khanna","Khano","Khichabia","Kho","Khorma","Khosrow","Khoury","Khudari","Ki","Kiah","Kial","Kidd","Kidder","Kiefer","Kieffer","Kieger","Kiehl","Kiel","Kiele","Kielty","Kienan","Kier","Kieran","Kiernan","Kiersten","Kikelia","Kiker","Kiki","Kila","Kilah","Kilan","Kilar","Kilbride","Kilby","Kile","Kiley","Kilgore","Kilian","Kilk","Killam","Killarney","Killen","Killian","Killie","Killigrew","Killion","Killoran","Killy","Kilmarx","Kilroy","Kim","Kimball","Kimbell","Kimber","Kimberlee","Kimberley","Kimberli","Kimberly","Kimberlyn","Kimble","Kimbra","Kimitri","Kimmel","Kimmi","Kimmie","Kimmy","Kimon","Kimura","Kin","Kinata","Kincaid","Kinch","Kinchen","Kind","Kindig","Kinelski","King","Kingdon","Kinghorn","Kingsbury","Kingsley","Kingsly","Kingston","Kinna","Kinnard","Kinney","Kinnie","Kinnon","Kinny","Kinsler","Kinsley","Kinsman","Kinson","Kinzer","Kiona","Kip","Kipp","Kippar","Kipper","Kippie","Kippy","Kipton","Kira","Kiran","Kirbee","Kirbie","Kirby","Kirch","Kirchner","Kiri","Kirima","Kirimia","Kirit","Kirk","Kirkpatrick","Kirkwood","Kironde","Kirsch","Kirschner","Kirshbaum","Kirst","Kirsten","Kirsteni","Kirsti","Kirstin","Kirstyn","Kirt","Kirtley","Kirven","Kirwin","Kisor","Kissee","Kissel","Kissiah","Kissie","Kissner","Kistner","Kisung","Kit","Kitchen","Kitti","Kittie","Kitty","Kiyohara","Kiyoshi","Kizzee","Kizzie","Kjersti","Klapp","Klara","Klarika","Klarrisa","Klatt","Klaus","Klayman","Klecka","Kleeman","Klehm","Kleiman","Klein","Kleinstein","Klemens","Klement","Klemm","Klemperer","Klenk","Kleon","Klepac","Kleper","Kletter","Kliber","Kliman","Kliment","Klimesh","Klina","Kline","Kling","Klingel","Klinger","Klinges","Klockau","Kloman","Klos","Kloster","Klotz","Klug","Kluge","Klump","Klusek","Klute","Knapp","Kneeland","Knepper","Knick","Knight","Knighton","Knipe","Knitter","Knobloch","Knoll","Knorring","Knowland","Knowle","Knowles","Knowling","Knowlton","Knox","Knudson","Knut","Knute","Knuth","Knutson","Ko","Koa","Koah","Koal","Koball","Kobe","Kobi","Koblas","Koblick","Koby","Kobylak","Koch","Koehler","Koenig","Koeninger","Koenraad","Koeppel","Koerlin","Koerner","Koetke","Koffler","Koffman","Koh","Kohl","Kohler","Kohn","Kokaras","Kokoruda","Kolb","Kolivas","Kolk","Koller","Kolnick","Kolnos","Kolodgie","Kolosick","Koloski","Kolva","Komara","Komarek","Komsa","Kondon","Kone","Kong","Konikow","Kono","Konopka","Konrad","Konstance","Konstantin","Konstantine","Konstanze","Konyn","Koo","Kooima","Koosis","Kopans","Kopaz","Kopp","Koppel","Kopple","Kora","Koral","Koralie","Koralle","Koran","Kordula","Kore","Korella","Koren","Korenblat","Koressa","Korey","Korff","Korfonta","Kori","Korie","Korman","Korney","Kornher","Korns","Korrie","Korry","Kort","Korten","Korwin","Korwun","Kory","Kosak","Kosaka","Kosel","Koser","Kosey","Kosiur","Koslo","Koss","Kosse","Kostival","Kostman","Kotick","Kotta","Kotto","Kotz","Kovacev","Kovacs","Koval","Kovar","Kowal","Kowalski","Kowatch","Kowtko","Koy","Koziara","Koziarz","Koziel","Kozloski","Kraft","Kragh","Krahling","Krahmer","Krakow","Krall","Kramer","Kramlich","Krantz","Kraska","Krasner","Krasnoff","Kraul","Kraus","Krause","Krauss","Kravits","Krawczyk","Kreager","Krebs","Kreda","Kreegar","Krefetz","Kreg","Kreiker","Krein","Kreindler","Kreiner","Kreis","Kreit","Kreitman","Krell","Kremer","Krenek","Krenn","Kresic","Kress","Krever","Kries","Krigsman","Krilov","Kris","Krischer","Krisha","Krishna","Krishnah","Krispin","Kriss","Krissie","Krissy","Krista","Kristal","Kristan","Kriste","Kristel","Kristen","Kristi","Kristian","Kristianson","Kristie","Kristien","Kristin","Kristina","Kristine","Kristo","Kristof","Kristofer","Kristoffer","Kristofor","Kristoforo","Kristopher","Kristos","Kristy","Kristyn","Krock","Kroll","Kronfeld","Krongold","Kronick","Kroo","Krucik","Krueger","Krug","Kruger","Krum","Krusche","Kruse","Krute","Kruter","Krutz","Krys","Kryska","Krysta","Krystal","Krystalle","Krystin","Krystle","Krystyna","Ku","Kubetz","Kubiak","Kubis","Kucik","Kudva","Kuebbing","Kuehn","Kuehnel","Kuhlman","Kuhn","Kulda","Kulseth","Kulsrud","Kumagai","Kumar","Kumler","Kung","Kunin","Kunkle","Kunz","Kuo","Kurland","Kurman","Kurr","Kursh","Kurt","Kurth","Kurtis","Kurtz","Kurtzig","Kurtzman","Kurys","Kurzawa","Kus","Kushner","Kusin","Kuska","Kussell","Kuster","Kutchins","Kuth","Kutzenco","Kutzer","Kwabena","Kwan","Kwang","Kwapong","Kwarteng","Kwasi","Kwei","Kwok","Kwon","Ky","Kyd","Kyl","Kyla","Kylah","Kylander","Kyle","Kylen","Kylie","Kylila","Kylstra","Kylynn","Kym","Kynan","Kyne","Kynthia","Kyriako","Kyrstin","Kyte","La","La Verne","LaBaw","LaMee","LaMonica","LaMori","LaRue","LaSorella","Laaspere","Laban","Labana","Laband","Labanna","Labannah","Labors","Lacagnia","Lacee","Lacefield","Lacey","Lach","Lachance","Lachish","Lachlan","Lachman","Lachus","Lacie","Lacombe","Lacy","Lad","Ladd","Laddie","Laddy","Laden","Ladew","Ladonna","Lady","Lael","Laetitia","Laflam","Lafleur","Laforge","Lagas","Lagasse","Lahey","Lai","Laidlaw","Lail","Laina","Laine","Lainey","Laing","Laird","Lais","Laise","Lait","Laith","Laius","Lakin","Laks","Laktasic","Lal","Lala","Lalage","Lali","Lalise","Lalita","Lalitta","Lalittah","Lalla","Lallage","Lally","Lalo","Lam","Lamar","Lamarre","Lamb","Lambard","Lambart","Lambert","Lamberto","Lambertson","Lambrecht","Lamdin","Lammond","Lamond","Lamont","Lamoree","Lamoureux","Lamp","Lampert","Lamphere","Lamprey","Lamrert","Lamrouex","Lamson","Lan","Lana","Lanae","Lanam","Lananna","Lancaster","Lance","Lancelle","Lancelot","Lancey","Lanctot","Land","Landa","Landahl","Landan","Landau","Landbert","Landel","Lander","Landers","Landes","Landing","Landis","Landmeier","Landon","Landre","Landri","Landrum","Landry","Landsman","Landy","Lane","Lanette","Laney","Lanford","Lanfri","Lang","Langan","Langbehn","Langdon","Lange","Langelo","Langer","Langham","Langill","Langille","Langley","Langsdon","Langston","Lani","Lanie","Lanita","Lankton","Lanna","Lanni","Lannie","Lanny","Lansing","Lanta","Lantha","Lanti","Lantz","Lanza","Lapham","Lapides","Lapointe","Lapotin","Lara","Laraine","Larcher","Lardner","Lareena","Lareine","Larena","Larentia","Laresa","Largent","Lari","Larianna","Larimer","Larimor","Larimore","Larina","Larine","Laris","Larisa","Larissa","Lark","Larkin","Larkins","Larner","Larochelle","Laroy","Larrabee","Larrie","Larrisa","Larry","Lars","Larsen","Larson","Laryssa","Lasala","Lash","Lashar","Lashoh","Lashond","Lashonda","Lashonde","Lashondra","Lasko","Lasky","Lasley","Lasonde","Laspisa","Lasser","Lassiter","Laszlo","Lat","Latashia","Latea","Latham","Lathan","Lathe","Lathrop","Lathrope","Lati","Latia","Latif","Latimer","Latimore","Latin","Latini","Latisha","Latona","Latonia","Latoniah","Latouche","Latoya","Latoye","Latoyia","Latreece","Latreese","Latrell","Latrena","Latreshia","Latrice","Latricia","Latrina","Latt","Latta","Latterll","Lattie","Lattimer","Latton","Lattonia","Latty","Latvina","Lau","Lauber","Laubin","Laud","Lauder","Lauer","Laufer","Laughlin","Laughry","Laughton","Launce","Launcelot","Laundes","Laura","Lauraine","Laural","Lauralee","Laurance","Laure","Lauree","Laureen","Laurel","Laurella","Lauren","Laurena","Laurence","Laurene","Laurens","Laurent","Laurentia","Laurentium","Lauretta","Laurette","Lauri","Laurianne","Laurice","Laurie","Laurin","Laurinda","Laurita","Lauritz","Lauro","Lauryn","Lauter","Laux","Lauzon","Laval","Laveen","Lavella","Lavelle","Laven","Lavena","Lavern","Laverna","Laverne","Lavery","Lavina","Lavine","Lavinia","Lavinie","Lavoie","Lavona","Law","Lawford","Lawler","Lawley","Lawlor","Lawrence","Lawrenson","Lawry","Laws","Lawson","Lawton","Lawtun","Lay","Layla","Layman","Layne","Layney","Layton","Lazar","Lazare","Lazaro","Lazaruk","Lazarus","Lazes","Lazor","Lazos","Le","LeCroy","LeDoux","LeMay","LeRoy","LeVitus","Lea","Leach","Leacock","Leah","Leahey","Leake","Leal","Lean","Leanard","Leander","Leandra","Leandre","Leandro","Leann","Leanna","Leanne","Leanor","Leanora","Leaper","Lear","Leary","Leasia","Leatri","Leatrice","Leavelle","Leavitt","Leavy","Leban","Lebar","Lebaron","Lebbie","Leblanc","Lebna","Leboff","Lechner","Lecia","Leckie","Leclair","Lectra","Leda","Ledah","Ledda","Leddy","Ledeen","Lederer","Lee","LeeAnn","Leeann","Leeanne","Leede","Leeke","Leela","Leelah","Leeland","Leena","Leesa","Leese","Leesen","Leeth","Leff","Leffen","Leffert","Lefkowitz","Lefton","Leftwich","Lefty","Leggat","Legge","Leggett","Legra","Lehet","Lehman","Lehmann","Lehrer","Leia","Leibman","Leicester","Leid","Leif","Leifer","Leifeste","Leigh","Leigha","Leighland","Leighton","Leila","Leilah","Leilani","Leipzig","Leis","Leiser","Leisha","Leitao","Leith","Leitman","Lejeune","Lek","Lela","Lelah","Leland","Leler","Lelia","Lelith","Lello","Lem","Lema","Lemaceon","Lemal","Lemar","Lemcke","Lemieux","Lemire","Lemkul","Lemmie","Lemmuela","Lemmueu","Lemmy","Lemon","Lempres","Lemuel","Lemuela","Lemuelah","Len","Lena","Lenard","Lenci","Lenee","Lenes","Lenette","Lengel","Lenhard","Lenhart","Lenka","Lenna","Lennard","Lenni","Lennie","Lenno","Lennon","Lennox","Lenny","Leno","Lenora","Lenore","Lenox","Lenrow","Lenssen","Lentha","Lenwood","Lenz","Lenzi","Leo","Leod","Leodora","Leoine","Leola","Leoline","Leon","Leona","Leonanie","Leonard","Leonardi","Leonardo","Leone","Leonelle","Leonerd","Leong","Leonhard","Leoni","Leonid","Leonidas","Leonie","Leonor","Leonora","Leonore","Leonsis","Leonteen","Leontina","Leontine","Leontyne","Leopold","Leopoldeen","Leopoldine","Leor","Leora","Leotie","Lepine","Lepley","Lepp","Lepper","Lerner","Leroi","Leroy","Les","Lesak","Leschen","Lesh","Leshia","Lesko","Leslee","Lesley","Lesli","Leslie","Lesly","Lessard","Lesser","Lesslie","Lester","Lesya","Let","Leta","Letch","Letha","Lethia","Leticia","Letisha","Letitia","Letizia","Letreece","Letrice","Letsou","Letta","Lette","Letti","Lettie","Letty","Leund","Leupold","Lev","Levan","Levana","Levania","Levenson","Leventhal","Leventis","Leverett","Leverick","Leveridge","Leveroni","Levesque","Levey","Levi","Levin","Levina","Levine","Levins","Levinson","Levison","Levitan","Levitt","Levon","Levona","Levy","Lew","Lewak","Lewan","Lewanna","Lewellen","Lewendal","Lewert","Lewes","Lewie","Lewin","Lewis","Lewison","Lewiss","Lewls","Lewse","Lexi","Lexie","Lexine","Lexis","Lexy","Ley","Leyes","Leyla","Lezley","Lezlie","Lhary","Li","Lia","Liam","Lian","Liana","Liane","Lianna","Lianne","Lias","Liatrice","Liatris","Lib","Liba","Libb","Libbey","Libbi","Libbie","Libbna","Libby","Libenson","Liberati","Libna","Libnah","Liborio","Libove","Libre","Licastro","Licha","Licht","Lichtenfeld","Lichter","Licko","Lida","Lidah","Lidda","Liddie","Liddle","Liddy","Lidia","Lidstone","Lieberman","Liebermann","Liebman","Liebowitz","Liederman","Lief","Lienhard","Liesa","Lietman","Liew","Lifton","Ligetti","Liggett","Liggitt","Light","Lightfoot","Lightman","Lil","Lila","Lilac","Lilah","Lilas","Lili","Lilia","Lilian","Liliane","Lilias","Lilith","Lilithe","Lilla","Lilli","Lillian","Lillie","Lillis","Lillith","Lilllie","Lilly","Lillywhite","Lily","Lilyan","Lilybel","Lilybelle","Lim","Liman","Limann","Limber","Limbert","Limemann","Limoli","Lin","Lina","Linc","Lincoln","Lind","Linda","Lindahl","Lindberg","Lindblad","Lindbom","Lindeberg","Lindell","Lindemann","Linden","Linder","Linders","Lindgren","Lindholm","Lindi","Lindie","Lindley","Lindly","Lindner","Lindo","Lindon","Lindsay","Lindsey","Lindsley","Lindsy","Lindy","Line","Linea","Linehan","Linell","Linet","Linetta","Linette","Ling","Lingwood","Linis","Link","Linker","Linkoski","Linn","Linnea","Linnell","Linneman","Linnet","Linnette","Linnie","Linoel","Linsk","Linskey","Linson","Linus","Linzer","Linzy","Lion","Lionel","Lionello","Lipcombe","Lipfert","Lipinski","Lipkin","Lipman","Liponis","Lipp","Lippold","Lipps","Lipscomb","Lipsey","Lipski","Lipson","Lira","Liris","Lisa","Lisabet","Lisabeth","Lisan","Lisandra","Lisbeth","Liscomb","Lise","Lisetta","Lisette","Lisha","Lishe","Lisk","Lisle","Liss","Lissa","Lissak","Lissi","Lissie","Lissner","Lissy","Lister","Lita","Litch","Litha","Lithea","Litman","Litt","Litta","Littell","Little","Littlejohn","Littman","Litton","Liu","Liuka","Liv","Liva","Livesay","Livi","Livia","Livingston","Livingstone","Livvi","Livvie","Livvy","Livvyy","Livy","Liz","Liza","Lizabeth","Lizbeth","Lizette","Lizzie","Lizzy","Ljoka","Llewellyn","Llovera","Lloyd","Llywellyn","Loar","Loats","Lobel","Lobell","Lochner","Lock","Locke","Lockhart","Locklin","Lockwood","Lodge","Lodhia","Lodi","Lodie","Lodmilla","Lodovico","Lody","Loeb","Loella","Loesceke","Loferski","Loftis","Loftus","Logan","Loggia","Loggins","Loginov","Lohman","Lohner","Lohrman","Lohse","Lois","Loise","Lola","Lolande","Lolanthe","Lole","Loleta","Lolita","Lolly","Loma","Lomasi","Lomax","Lombard","Lombardi","Lombardo","Lombardy","Lon","Lona","London","Londoner","Lonee","Lonergan","Long","Longan","Longawa","Longerich","Longfellow","Longley","Longmire","Longo","Longtin","Longwood","Loni","Lonier","Lonna","Lonnard","Lonne","Lonni","Lonnie","Lonny","Lontson","Loomis","Loos","Lopes","Lopez","Lora","Lorain","Loraine","Loralee","Loralie","Loralyn","Loram","Lorant","Lord","Lordan","Loredana","Loredo","Loree","Loreen","Lorelei","Lorelie","Lorelle","Loren","Lorena","Lorene","Lorens","Lorenz","Lorenza","Lorenzana","Lorenzo","Loresz","Loretta","Lorette","Lori","Loria","Lorianna","Lorianne","Lorie","Lorien","Lorilee","Lorilyn","Lorimer","Lorin","Lorinda","Lorine","Loriner","Loring","Loris","Lorita","Lorn","Lorna","Lorne","Lorola","Lorolla","Lorollas","Lorou","Lorraine","Lorrayne","Lorri","Lorrie","Lorrimer","Lorrimor","Lorrin","Lorry","Lorsung","Lorusso","Lory","Lose","Loseff","Loss","Lossa","Losse","Lot","Lothair","Lothaire","Lothar","Lothario","Lotson","Lotta","Lotte","Lotti","Lottie","Lotty","Lotus","Lotz","Lou","Louanna","Louanne","Louella","Lough","Lougheed","Loughlin","Louie","Louis","Louisa","Louise","Louisette","Louls","Lounge","Lourdes","Lourie","Louth","Loutitia","Loux","Lovash","Lovato","Love","Lovel","Lovell","Loveridge","Lovering","Lovett","Lovich","Lovmilla","Low","Lowe","Lowell","Lowenstein","Lowenstern","Lower","Lowery","Lowis","Lowndes","Lowney","Lowrance","Lowrie","Lowry","Lowson","Loy","Loyce","Loydie","Lozano","Lozar","Lu","Luana","Luane","Luann","Luanne","Luanni","Luba","Lubba","Lubbi","Lubbock","Lubeck","Luben","Lubet","Lubin","Lubow","Luby","Luca","Lucais","Lucania","Lucas","Lucchesi","Luce","Lucey","Lucho","Luci","Lucia","Lucian","Luciana","Luciano","Lucias","Lucic","Lucie","Lucien","Lucienne","Lucier","Lucila","Lucilia","Lucilla","Lucille","Lucina","Lucinda","Lucine","Lucio","Lucita","Lucius","Luckett","Luckin","Lucky","Lucrece","Lucretia","Lucy","Lud","Ludeman","Ludewig","Ludie","Ludlew","Ludlow","Ludly","Ludmilla","Ludovick","Ludovico","Ludovika","Ludvig","Ludwig","Ludwigg","Ludwog","Luebke","Luedtke","Luehrmann","Luella","Luelle","Lugar","Lugo","Luhe","Luhey","Luht","Luigi","Luigino","Luing","Luis","Luisa","Luise","Luiza","Lukas","Lukash","Lukasz","Luke","Lukey","Lukin","Lula","Lulita","Lull","Lulu","Lumbard","Lumbye","Lumpkin","Luna","Lund","Lundberg","Lundeen","Lundell","Lundgren","Lundin","Lundquist","Lundt","Lune","Lunetta","Lunette","Lunn","Lunna","Lunneta","Lunnete","Lunseth","Lunsford","Lunt","Luo","Lupe","Lupee","Lupien","Lupita","Lura","Lurette","Lurie","Lurleen","Lurlene","Lurline","Lusa","Lussi","Lussier","Lust","Lustick","Lustig","Lusty","Lutero","Luthanen","Luther","Luttrell","Luwana","Lux","Luz","Luzader","Ly","Lyall","Lyckman","Lyda","Lydell","Lydia","Lydie","Lydon","Lyell","Lyford","Lyle","Lyman","Lymann","Lymn","Lyn","Lynch","Lynd","Lynda","Lynde","Lyndel","Lyndell","Lynden","Lyndes","Lyndon","Lyndsay","Lyndsey","Lyndsie","Lyndy","Lynea","Lynelle","Lynett","Lynette","Lynn","Lynna","Lynne","Lynnea","Lynnell","Lynnelle","Lynnet","Lynnett","Lynnette","Lynnworth","Lyns","Lynsey","Lynus","Lyon","Lyons","Lyontine","Lyris","Lysander","Lyssa","Lytle","Lytton","Lyudmila","Ma","Maag","Mab","Mabel","Mabelle","Mable","Mac","MacCarthy","MacDermot","MacDonald","MacDonell","MacDougall","MacEgan","MacFadyn","MacFarlane","MacGregor","MacGuiness","MacIlroy","MacIntosh","MacIntyre","MacKay","MacKenzie","MacLaine","MacLay","MacLean","MacLeod","MacMahon","MacMillan","MacMullin","MacNair","MacNamara","MacPherson","MacRae","MacSwan","Macario","Maccarone","Mace","Macegan","Macey","Machos","Machute","Machutte","Mack","Mackenie","Mackenzie","Mackey","Mackie","Mackintosh","Mackler","Macknair","Mackoff","Macnair","Macomber","Macri","Macur","Macy","Mada","Madai","Madaih","Madalena","Madalyn","Madancy","Madaras","Maddalena","Madden","Maddeu","Maddi","Maddie","Maddis","Maddock","Maddocks","Maddox","Maddy","Madea","Madel","Madelaine","Madeleine","Madelena","Madelene","Madelin","Madelina","Madeline","Madella","Madelle","Madelon","Madelyn","Madge","Madi","Madian","Madid","Madigan","Madison","Madlen","Madlin","Madoc","Madonia","Madonna","Madora","Madox","Madra","Madriene","Madson","Mady","Mae","Maegan","Maeve","Mafala","Mafalda","Maffa","Maffei","Mag","Magan","Magas","Magavern","Magbie","Magda","Magdaia","Magdala","Magdalen","Magdalena","Magdalene","Magdau","Magee","Magel","Magen","Magena","Mages","Maggee","Maggi","Maggie","Maggio","Maggs","Maggy","Maghutte","Magill","Magna","Magner","Magnien","Magnolia","Magnum","Magnus","Magnuson","Magnusson","Magocsi","Magree","Maguire","Magulac","Mahala","Mahalia","Mahan","Mahau","Maher","Mahla","Mahmoud","Mahmud","Mahon","Mahoney","Maia","Maiah","Maibach","Maible","Maice","Maida","Maidel","Maidie","Maidy","Maier","Maiga","Maighdiln","Maighdlin","Mailand","Main","Mainis","Maiocco","Mair","Maire","Maise","Maisel","Maisey","Maisie","Maison","Maite","Maitilde","Maitland","Maitund","Maje","Majka","Major","Mak","Makell","Maker","Mal","Mala","Malachi","Malachy","Malamud","Malamut","Malan","Malanie","Malarkey","Malaspina","Malca","Malcah","Malchus","Malchy","Malcolm","Malcom","Malda","Maleeny","Malek","Maleki","Malena","Malet","Maletta","Mali","Malia","Malik","Malin","Malina","Malinda","Malinde","Malinin","Malinowski","Malissa","Malissia","Malita","Malka","Malkah","Malkin","Mall","Mallen","Maller","Malley","Mallin","Mallina","Mallis","Mallissa","Malloch","Mallon","Mallorie","Mallory","Malloy","Malo","Malone","Maloney","Malonis","Malony","Malorie","Malory","Maloy","Malti","Maltz","Maltzman","Malva","Malvia","Malvie","Malvin","Malvina","Malvino","Malynda","Mame","Mamie","Mamoun","Man","Manaker","Manara","Manard","Manchester","Mancino","Manda","Mandal","Mandel","Mandelbaum","Mandell","Mandeville","Mandi","Mandie","Mandle","Mandler","Mandy","Mandych","Manella","Manfred","Manheim","Mani","Manley","Manlove","Manly","Mann","Mannes","Mannie","Manning","Manno","Mannos","Mannuela","Manny","Mano","Manoff","Manolo","Manon","Manouch","Mansfield","Manson","Mansoor","Mansur","Manthei","Manton","Manuel","Manuela","Manus","Manvel","Manvell","Manvil","Manville","Manwell","Manya","Mapel","Mapes","Maples","Mar","Mara","Marabel","Marabelle","Marala","Marasco","Marashio","Marbut","Marc","Marceau","Marcel","Marcela","Marcelia","Marcell","Marcella","Marcelle","Marcellina","Marcelline","Marcello","Marcellus","Marcelo","March","Marchak","Marchal","Marchall","Marchelle","Marchese","Marci","Marcia","Marciano","Marcie","Marcile","Marcille","Marcin","Marco","Marcos","Marcoux","Marcus","Marcy","Marden","Marder","Marduk","Mareah","Marek","Marela","Mareld","Marelda","Marella","Marelya","Maren","Marena","Marentic","Maressa","Maretz","Marga","Margalit","Margalo","Margaret","Margareta","Margarete","Margaretha","Margarethe","Margaretta","Margarette","Margarida","Margarita","Margaux","Marge","Margeaux","Margery","Marget","Margette","Margetts","Margherita","Margi","Margie","Margit","Margo","Margot","Margret","Margreta","Marguerie","Marguerita","Marguerite","Margy","Mari","Maria","Mariam","Marian","Mariana","Mariand","Mariande","Mariandi","Mariann","Marianna","Marianne","Mariano","Maribel","Maribelle","Maribeth","Marice","Maridel","Marie","Marie-Ann","Marie-Jeanne","Marieann","Mariejeanne","Mariel","Mariele","Marielle","Mariellen","Marienthal","Marietta","Mariette","Marigold","Marigolda","Marigolde","Marijane","Marijn","Marijo","Marika","Mariken","Mariko","Maril","Marilee","Marilin","Marilla","Marillin","Marilou","Marilyn","Marin","Marina","Marinelli","Marinna","Marino","Mario","Marion","Mariquilla","Maris","Marisa","Mariska","Marissa","Marita","Maritsa","Marius","Mariya","Marj","Marja","Marjana","Marje","Marji","Marjie","Marjorie","Marjory","Marjy","Mark","Market","Marketa","Markland","Markman","Marko","Markos","Markowitz","Marks","Markson","Markus","Marl","Marla","Marlane","Marlea","Marleah","Marlee","Marleen","Marlen","Marlena","Marlene","Marler","Marlette","Marley","Marlie","Marlin","Marline","Marlo","Marlon","Marlow","Marlowe","Marlyn","Marmaduke","Marmawke","Marmion","Marna","Marne","Marney","Marni","Marnia","Marnie","Maro","Marola","Marolda","Maroney","Marou","Marozas","Marozik","Marpet","Marquardt","Marquet","Marquez","Marquis","Marquita","Marr","Marra","Marras","Marrilee","Marrin","Marriott","Marris","Marrissa","Marron","Mars","Marsden","Marsh","Marsha","Marshal","Marshall","Marsiella","Marsland","Marston","Mart","Marta","Martainn","Marte","Marteena","Martel","Martell","Martella","Martelle","Martelli","Marten","Martens","Martguerita","Martha","Marthe","Marthena","Marti","Martica","Martie","Martijn","Martin","Martina","Martine","Martineau","Martinelli","Martinez","Martinic","Martino","Martinsen","Martinson","Martita","Martres","Martsen","Marty","Martyn","Martynne","Martz","Marucci","Marutani","Marv","Marva","Marve","Marvel","Marvella","Marven","Marvin","Marwin","Marx","Mary","Marya","Maryann","Maryanna","Maryanne","Marybella","Marybelle","Marybeth","Maryellen","Maryjane","Maryjo","Maryl","Marylee","Marylin","Marylinda","Marylou","Maryly","Marylynne","Maryn","Maryrose","Marys","Marysa","Marzi","Mas","Masao","Mascia","Masera","Masha","Mashe","Mason","Masry","Massarelli","Massey","Massie","Massimiliano","Massimo","Massingill","Masson","Mast","Mastat","Masterson","Mastic","Mastrianni","Mat","Mata","Matazzoni","Matejka","Matelda","Mateo","Materi","Materse","Mateusz","Mateya","Mathe","Matheny","Mather","Matheson","Mathew","Mathews","Mathi","Mathia","Mathian","Mathias","Mathilda","Mathilde","Mathis","Mathre","Mathur","Matias","Matilda","Matilde","Matland","Matless","Matlick","Matrona","Matronna","Matt","Matta","Mattah","Matteo","Matthaeus","Matthaus","Matthei","Mattheus","Matthew","Matthews","Matthia","Matthias","Matthieu","Matthiew","Matthus","Matti","Mattias","Mattie","Mattland","Mattox","Mattson","Matty","Matusow","Mauceri","Mauchi","Maud","Maude","Maudie","Mauer","Mauldon","Maunsell","Maupin","Maura","Mauralia","Maure","Maureen","Maureene","Maurene","Maurer","Mauretta","Maurey","Mauri","Maurice","Mauricio","Maurie","Maurili","Maurilia","Maurilla","Maurine","Maurise","Maurita","Maurits","Maurizia","Maurizio","Mauro","Maurreen","Maury","Mauve","Mavilia","Mavis","Mavra","Max","Maxa","Maxama","Maxantia","Maxentia","Maxey","Maxfield","Maxi","Maxia","Maxie","Maxim","Maxima","Maximilian","Maximilianus","Maximilien","Maximo","Maxine","Maxma","Maxwell","Maxy","May","Maya","Maybelle","Mayberry","Mayce","Mayda","Maye","Mayeda","Mayer","Mayes","Mayfield","Mayhew","Mayman","Maynard","Mayne","Maynord","Mayor","Mays","Mayworm","Maze","Mazel","Maziar","Mazlack","Mazman","Mazonson","Mazur","Mazurek","McAdams","McAfee","McAllister","McArthur","McBride","McCafferty","McCahill","McCall","McCallion","McCallum","McCandless","McCartan","McCarthy","McCarty","McClain","McClary","McClees","McClelland","McClenaghan","McClenon","McClimans","McClish","McClure","McCollum","McComb","McConaghy","McConnell","McCord","McCormac","McCormick","McCourt","McCowyn","McCoy","McCready","McCreary","McCreery","McCulloch","McCullough","McCully","McCurdy","McCutcheon","McDade","McDermott","McDonald","McDougall","McDowell","McEvoy","McFadden","McFarland","McFerren","McGannon","McGaw","McGean","McGee","McGill","McGinnis","McGrath","McGraw","McGray","McGregor","McGrody","McGruter","McGuire","McGurn","McHail","McHale","McHenry","McHugh","McIlroy","McIntosh","McIntyre","McKale","McKay","McKee","McKenna","McKenzie","McKeon","McKinney","McKnight","McLain","McLaughlin","McLaurin","McLeod","McLeroy","McLoughlin","McLyman","McMahon","McMaster","McMath","McMillan","McMullan","McMurry","McNair","McNalley","McNally","McNamara","McNamee","McNeely","McNeil","McNelly","McNully","McNutt","McQuade","McQuillin","McQuoid","McRipley","McRoberts","McSpadden","McTyre","McWherter","McWilliams","Mead","Meade","Meador","Meadow","Meadows","Meagan","Meaghan","Meagher","Meakem","Means","Meara","Meares","Mears","Meave","Mechelle","Mechling","Mecke","Meda","Medarda","Medardas","Medea","Medeah","Medin","Medina","Medlin","Medor","Medora","Medorra","Medovich","Medrek","Medwin","Meece","Meehan","Meek","Meeker","Meeks","Meenen","Meg","Megan","Megargee","Megdal","Megen","Meggi","Meggie","Meggs","Meggy","Meghan","Meghann","Mehala","Mehalek","Mehalick","Mehetabel","Mehitable","Mehta","Mei","Meibers","Meier","Meijer","Meilen","Meill","Meingolda","Meingoldas","Meir","Meisel","Meit","Mel","Mela","Melamed","Melamie","Melan","Melania","Melanie","Melantha","Melany","Melar","Melba","Melborn","Melbourne","Melburn","Melcher","Melda","Meldoh","Meldon","Melena","Melentha","Melesa","Melessa","Meletius","Melgar","Meli","Melia","Melicent","Melina","Melinda","Melinde","Melisa","Melisande","Melisandra","Melise","Melisenda","Melisent","Melissa","Melisse","Melita","Melitta","Mell","Mella","Mellar","Mellen","Melleta","Mellette","Melli","Mellicent","Mellie","Mellins","Mellisa","Mellisent","Mellitz","Mellman","Mello","Melloney","Melly","Melmon","Melnick","Melodee","Melodie","Melody","Melone","Melonie","Melony","Melosa","Melquist","Melton","Melva","Melvena","Melville","Melvin","Melvina","Melvyn","Memberg","Memory","Mena","Menard","Menashem","Mencher","Mendel","Mendelsohn","Mendelson","Mendes","Mendez","Mendie","Mendive","Mendoza","Mendy","Meneau","Menedez","Menell","Menendez","Meng","Menides","Menis","Menken","Menon","Mensch","Menzies","Mera","Meraree","Merari","Meras","Merat","Merc","Mercado","Merce","Mercedes","Merceer","Mercer","Merchant","Merci","Mercie","Mercier","Mercola","Mercorr","Mercuri","Mercy","Merdith","Meredeth","Meredi","Meredith","Meredithe","Merell","Merete","Meri","Meridel","Merideth","Meridith","Meriel","Merilee","Merill","Merilyn","Meris","Merissa","Merkle","Merkley","Merl","Merla","Merle","Merlin","Merlina","Merline","Merna","Merola","Merow","Merralee","Merras","Merrel","Merrell","Merri","Merriam","Merrick","Merridie","Merrie","Merrielle","Merril","Merrile","Merrilee","Merrili","Merrill","Merrily","Merriman","Merriott","Merritt","Merrow","Merry","Mersey","Mert","Merta","Merth","Merton","Merv","Mervin","Merwin","Merwyn","Meryl","Mesics","Messere","Messing","Meta","Metabel","Metcalf","Meter","Methuselah","Metsky","Mettah","Metts","Metzgar","Metzger","Meunier","Meurer","Meuse","Meuser","Meyer","Meyeroff","Meyers","Mezoff","Mia","Mic","Micaela","Micah","Micco","Mich","Michael","Michaela","Michaele","Michaelina","Michaeline","Michaella","Michaeu","Michail","Michal","Michale","Michaud","Miche","Micheal","Micheil","Michel","Michele","Michelina","Micheline","Michell","Michella","Michelle","Michelsen","Michey","Michi","Michigan","Michiko","Michon","Mick","Mickelson","Mickey","Micki","Mickie","Micky","Micro","Miculek","Midas","Middendorf","Middle","Middlesworth","Middleton","Mide","Midge","Midian","Midis","Mientao","Miett","Migeon","Mighell","Mignon","Mignonne","Miguel","Miguela","Miguelita","Mihalco","Mihe","Mika","Mikael","Mikaela","Mikal","Mike","Mikel","Mikes","Mikey","Miki","Mikihisa","Mikiso","Mikkanen","Mikkel","Miko","Mikol","Miksen","Mil","Mila","Milan","Milano","Milburn","Milburr","Milburt","Milda","Milde","Mildred","Mildrid","Mile","Milena","Miles","Milewski","Milford","Milicent","Milinda","Milissa","Milissent","Milka","Milks","Mill","Milla","Millan","Millar","Millard","Millburn","Millda","Miller","Millford","Millham","Millhon","Milli","Millian","Millicent","Millie","Millisent","Millman","Mills","Millur","Millwater","Milly","Milman","Milo","Milon","Milone","Milore","Milson","Milstone","Milt","Miltie","Milton","Milty","Milurd","Milzie","Mima","Mimi","Min","Mina","Minabe","Minardi","Minda","Mindi","Mindy","Miner","Minerva","Mines","Minetta","Minette","Ming","Mingche","Mini","Minica","Minier","Minna","Minnaminnie","Minne","Minni","Minnie","Minnnie","Minny","Minor","Minoru","Minsk","Minta","Minton","Mintun","Mintz","Miof Mela","Miquela","Mir","Mira","Mirabel","Mirabella","Mirabelle","Miran","Miranda","Mireielle","Mireille","Mirella","Mirelle","Miriam","Mirielle","Mirilla","Mirisola","Mirna","Mirth","Miru","Mischa","Misha","Mishaan","Missi","Missie","Missy","Misti","Mistrot","Misty","Mita","Mitch","Mitchael","Mitchel","Mitchell","Mitchiner","Mitinger","Mitman","Mitran","Mittel","Mitzi","Mitzie","Mitzl","Miun","Mixie","Miyasawa","Mizuki","Mlawsky","Mllly","Moazami","Moberg","Mobley","Mochun","Mode","Modern","Modesta","Modeste","Modestia","Modestine","Modesty","Modie","Modla","Moe","Moersch","Moffat","Moffit","Moffitt","Mogerly","Moguel","Mohamed","Mohammad","Mohammed","Mohandas","Mohandis","Mohl","Mohn","Mohr","Mohsen","Mohun","Moia","Moina","Moir","Moira","Moise","Moises","Moishe","Moitoso","Mojgan","Mok","Mokas","Molini","Moll","Mollee","Molli","Mollie","Molloy","Molly","Molton","Mommy","Mona","Monaco","Monafo","Monagan","Monah","Monahan","Monahon","Monarski","Moncear","Mond","Monda","Moneta","Monetta","Mongeau","Monia","Monica","Monie","Monika","Monique","Monjan","Monjo","Monk","Monney","Monreal","Monro","Monroe","Monroy","Monson","Monsour","Mont","Montagna","Montagu","Montague","Montana","Montanez","Montano","Monte","Monteith","Monteria","Montford","Montfort","Montgomery","Monti","Monto","Monty","Moody","Mook","Moon","Mooney","Moonier","Moor","Moore","Moorefield","Moorish","Mor","Mora","Moran","Mord","Mordecai","Mordy","Moreen","Morehouse","Morel","Moreland","Morell","Morena","Moreno","Morentz","Moreta","Moretta","Morette","Moreville","Morey","Morez","Morgan","Morgana","Morganica","Morganne","Morganstein","Morgen","Morgenthaler","Morgun","Mori","Moria","Moriah","Moriarty","Morice","Morie","Morissa","Morita","Moritz","Moriyama","Morlee","Morley","Morly","Morna","Morocco","Morra","Morrell","Morrie","Morril","Morrill","Morris","Morrison","Morrissey","Morry","Morse","Mort","Morten","Mortensen","Mortie","Mortimer","Morton","Morty","Morven","Morville","Morvin","Mosa","Mosby","Moscow","Mose","Moseley","Moselle","Mosenthal","Moser","Mosera","Moses","Moshe","Moshell","Mosier","Mosira","Moskow","Mosley","Mosora","Mosra","Moss","Mossberg","Mossman","Most","Motch","Moth","Mott","Motteo","Mou","Moulden","Mouldon","Moule","Moulton","Mount","Mountford","Mountfort","Mourant","Moureaux","Mowbray","Moya","Moyer","Moyers","Moyna","Moynahan","Moyra","Mozart","Mozelle","Mozes","Mozza","Mraz","Mroz","Mueller","Muffin","Mufi","Mufinella","Muhammad","Muir","Muire","Muirhead","Mukerji","Mukul","Mukund","Mulcahy","Mulderig","Muldon","Mulford","Mullane","Mullen","Muller","Mulligan","Mullins","Mulloy","Mulry","Mulvihill","Mumford","Mun","Muna","Munafo","Muncey","Mundford","Mundt","Mundy","Munford","Mungo","Mungovan","Munmro","Munn","Munniks","Munro","Munroe","Muns","Munsey","Munshi","Munson","Munster","Munt","Mur","Murage","Muraida","Murat","Murdocca","Murdoch","Murdock","Mureil","Muriah","Murial","Muriel","Murielle","Murphy","Murrah","Murray","Murrell","Murry","Murtagh","Murtha","Murton","Murvyn","Musa","Muscolo","Musetta","Musette","Mushro","Muslim","Musser","Mussman","Mutz","My","Mya","Myca","Mycah","Mychael","Mychal","Myer","Myers","Myke","Mylan","Mylander","Myles","Mylo","Mylor","Myna","Myo","Myra","Myrah","Myranda","Myriam","Myrilla","Myrle","Myrlene","Myrna","Myron","Myrt","Myrta","Myrtia","Myrtice","Myrtie","Myrtle","Myrvyn","Myrwyn","Na","Naam","Naaman","Naamana","Naamann","Naara","Naarah","Naashom","Nabal","Nabala","Nabalas","Nabila","Nace","Nachison","Nada","Nadab","Nadaba","Nadabas","Nadabb","Nadabus","Nadaha","Nadbus","Nadda","Nadean","Nadeau","Nadeen","Nader","Nadia","Nadine","Nadiya","Nadler","Nador","Nady","Nadya","Nafis","Naga","Nagel","Nagey","Nagle","Nagy","Nahama","Nahamas","Nahshon","Nahshu","Nahshun","Nahshunn","Nahtanha","Nahum","Naiditch","Naima","Naji","Nakada","Nakashima","Nakasuji","Nalani","Nalda","Naldo","Nalepka","Nally","Nalor","Nam","Naman","Namara","Names","Nan","Nana","Nananne","Nance","Nancee","Nancey","Nanci","Nancie","Nancy","Nandor","Nanete","Nanette","Nani","Nanice","Nanine","Nanji","Nannette","Nanni","Nannie","Nanny","Nanon","Naoma","Naomi","Naor","Nap","Napier","Naples","Napoleon","Nappie","Nappy","Naquin","Nara","Narah","Narayan","Narcho","Narcis","Narcissus","Narda","Naresh","Nari","Nariko","Narine","Narra","Narton","Nary","Nash","Nashbar","Nashner","Nasho","Nashom","Nashoma","Nasia","Nason","Nassi","Nassir","Nastassia","Nasya","Nat","Nata","Natal","Natala","Natale","Natalee","Natalia","Natalie","Natalina","Nataline","Natalya","Nataniel","Natascha","Natasha","Natassia","Nate","Natelson","Nath","Nathalia","Nathalie","Nathan","Nathanael","Nathanial","Nathaniel","Nathanil","Nathanson","Natica","Natie","Natiha","Natika","Nations","Natividad","Natka","Nattie","Natty","Nava","Navada","Naval","Navarro","Nawrocki","Nay","Naylor","Nazar","Nazario","Nazarius","Nazler","Nea","Neal","Neala","Nealah","Neale","Nealey","Neall","Nealon","Nealson","Nealy","Neau","Ned","Neda","Nedda","Neddie","Neddra","Neddy","Nedi","Nedra","Nedrah","Nedrud","Nedry","Nee","Neel","Neela","Neelon","Neely","Neeoma","Nefen","Neff","Negris","Nehemiah","Neibart","Neidhardt","Neil","Neila","Neile","Neill","Neilla","Neille","Neils","Neilson","Neiman","Neisa","Nel","Nela","Nelan","Nelda","Nelia","Nelie","Nell","Nella","Nellda","Nelle","Nelli","Nellie","Nellir","Nelly","Nelrsa","Nels","Nelsen","Nelson","Nema","Nemhauser","Nena","Nenney","Neo","Neom","Neoma","Neomah","Neona","Nepean","Nepil","Nereen","Nereids","Nereus","Neri","Nerin","Nerine","Nerissa","Nerita","Nerland","Nero","Neron","Nert","Nerta","Nerte","Nerti","Nertie","Nerty","Nesbitt","Nesline","Neslund","Ness","Nessa","Nessi","Nessie","Nessim","Nessy","Nesta","Nester","Nesto","Nestor","Nett","Netta","Nette","Netti","Nettie","Nettle","Netty","Neu","Neuberger","Neuburger","Neufer","Neukam","Neumann","Neumark","Neumeyer","Neurath","Nev","Neva","Nevada","Nevai","Neve","Neveda","Nevil","Nevile","Neville","Nevin","Nevins","Nevlin","Nevsa","New","Newberry","Newbill","Newbold","Newby","Newcomb","Newcomer","Newel","Newell","Newfeld","Newhall","Newkirk","Newlin","Newman","Newmann","Newmark","Newsom","Newton","Neysa","Ng","Ngo","Nguyen","Niabi","Nial","Niall","Nibbs","Nic","Nica","Niccolo","Nich","Nichani","Nichol","Nichola","Nicholas","Nichole","Nicholl","Nicholle","Nichols","Nicholson","Nichy","Nick","Nickelsen","Nickerson","Nickey","Nicki","Nickie","Nickles","Nicko","Nickola","Nickolai","Nickolas","Nickolaus","Nicks","Nicky","Nico","Nicodemus","Nicol","Nicola","Nicolai","Nicolais","Nicolas","Nicolau","Nicole","Nicolea","Nicolella","Nicolette","Nicoli","Nicolina","Nicoline","Nicolis","Nicolle","Nidia","Nidorf","Nieberg","Niehaus","Niel","Niela","Niels","Nielsen","Nielson","Nierman","Nies","Nievelt","Nigel","Nightingale","Nihhi","Nihi","Nika","Nikaniki","Nike","Niki","Nikita","Nikki","Nikkie","Niklaus","Niko","Nikola","Nikolai","Nikolaos","Nikolas","Nikolaus","Nikoletta","Nikolia","Nikolos","Nikos","Nil","Nila","Nile","Niles","Nilla","Nils","Nilson","Nimesh","Nimocks","Nims","Nina","Nine","Ninetta","Ninette","Ninnetta","Ninnette","Nino","Ninon","Ninos","Niobe","Nipha","Niple","Nisa","Nisbet","Nisen","Nishi","Nissa","Nisse","Nissensohn","Nissie","Nissy","Nita","Nitin","Nitz","Nitza","Niu","Niven","Nixie","Nixon","Noach","Noah","Noak","Noakes","Noam","Noami","Nobe","Nobel","Nobell","Nobie","Nobile","Noble","Noby","Nochur","Nodab","Nodababus","Nodarse","Noe","Noel","Noelani","Noell","Noella","Noelle","Noellyn","Noelyn","Noemi","Nogas","Noguchi","Nola","Nolan","Nolana","Noland","Nole","Noleta","Noletta","Noli","Nolie","Nolita","Nolitta","Noll","Nollie","Nolly","Nolte","Noma","Noman","Nomi","Nona","Nonah","Noni","Nonie","Nonna","Nonnah","Noonan","Noonberg","Nor","Nora","Norah","Norbert","Norbie","Norby","Nord","Nordgren","Nordin","Nordine","Nore","Norean","Noreen","Norene","Norford","Norina","Norine","Norita","Nork","Norling","Norm","Norma","Normalie","Norman","Normand","Normandy","Normi","Normie","Normy","Norri","Norrie","Norris","Norrv","Norry","Norse","North","Northey","Northington","Northrop","Northrup","Northway","Norton","Norty","Norval","Norvall","Norvan","Norvell","Norven","Norvil","Norvin","Norvol","Norvun","Norward","Norwood","Norword","Nottage","Nova","Novah","Novak","Novelia","Novello","Novia","Novick","Novikoff","Nowell","Noyes","Nozicka","Nudd","Nugent","Nuli","Nunci","Nuncia","Nunciata","Nunes","Nunnery","Nur","Nuri","Nuriel","Nuris","Nurse","Nussbaum","Nutter","Nuzzi","Nyberg","Nydia","Nye","Nyhagen","Nysa","Nyssa","O'Hara","O'Neill","Oak","Oakes","Oakie","Oakleil","Oakley","Oakman","Oaks","Oates","Oatis","Oba","Obadiah","Obadias","Obala","Oballa","Obara","Obau","Obaza","Obbard","Obe","Obed","Obeded","Obediah","Obel","Obelia","Obellia","Obeng","Ober","Oberg","Oberheim","Oberon","Oberstone","Obidiah","Obie","Obla","Obola","Obrien","Oby","Oca","Ocana","Ochs","Ocker","Ocko","Oconnor","Octave","Octavia","Octavian","Octavie","Octavius","Octavla","Octavus","Odab","Odawa","Ode","Odeen","Odel","Odele","Odelet","Odelia","Odelinda","Odell","Odella","Odelle","Odericus","Odessa","Odetta","Odette","Odey","Odie","Odilia","Odille","Odilo","Odin","Odine","Odlo","Odo","Odom","Odoric","Odrick","Ody","Odysseus","Odyssey","Oech","Oeflein","Oehsen","Ofelia","Ofella","Offen","Ofilia","Ofori","Og","Ogata","Ogawa","Ogdan","Ogden","Ogdon","Ogg","Ogilvie","Ogilvy","Oglesby","Ogren","Ohara","Ohare","Ohaus","Ohl","Oilla","Oina","Oira","Okajima","Okechuku","Okubo","Okun","Okwu","Ola","Olaf","Olag","Olatha","Olathe","Olav","Olcott","Old","Older","Olds","Ole","Oleg","Olen","Olenka","Olenolin","Olenta","Oler","Oleta","Oletha","Olethea","Oletta","Olette","Olfe","Olga","Olia","Oliana","Olimpia","Olin","Olinde","Oliva","Olivann","Olive","Oliver","Olivero","Olivette","Olivia","Olivie","Olivier","Oliviero","Oliy","Ollayos","Olli","Ollie","Olly","Olmstead","Olmsted","Olnay","Olnee","Olnek","Olney","Olnton","Olodort","Olpe","Olsen","Olsewski","Olshausen","Olson","Olsson","Olva","Olvan","Olwen","Olwena","Oly","Olympe","Olympia","Olympias","Olympie","Olympium","Om","Oman","Omar","Omari","Omarr","Omer","Omero","Omidyar","Omland","Omor","Omora","Omura","On","Ona","Onder","Ondine","Ondrea","Ondrej","Oneal","Oneida","Oneil","Oneill","Onfre","Onfroi","Ong","Ongun","Oni","Onia","Onida","Oniskey","Onofredo","Onstad","Ontina","Ontine","Onyx","Oona","Opal","Opalina","Opaline","Ophelia","Ophelie","Oppen","Opportina","Opportuna","Ora","Orabel","Orabelle","Oralee","Oralia","Oralie","Oralla","Oralle","Oram","Oran","Orazio","Orbadiah","Orban","Ordway","Orel","Orelee","Orelia","Orelie","Orella","Orelle","Orelu","Oren","Orest","Oreste","Orestes","Orferd","Orfield","Orfinger","Orford","Orfurd","Orgel","Orgell","Ori","Oria","Orian","Oriana","Oriane","Orianna","Oribel","Oribella","Oribelle","Oriel","Orin","Oringa","Oringas","Oriole","Orion","Orit","Orji","Orlan","Orland","Orlando","Orlanta","Orlantha","Orlena","Orlene","Orlina","Orling","Orlosky","Orlov","Orly","Orman","Ormand","Orme","Ormiston","Ormond","Orms","Ormsby","Orna","Ornas","Ornie","Ornstead","Orola","Orose","Orozco","Orpah","Orpha","Orpheus","Orr","Orran","Orren","Orrin","Orsa","Orsay","Orsini","Orsino","Orsola","Orson","Orten","Ortensia","Orth","Orthman","Ortiz","Orton","Ortrud","Ortrude","Oruntha","Orv","Orva","Orvah","Orvan","Orvas","Orvie","Orvil","Orville","Orwin","Os","Osana","Osanna","Osber","Osbert","Osborn","Osborne","Osbourn","Osbourne","Oscar","Osei","Osgood","Osher","Oshinski","Osi","Osithe","Oskar","Osman","Osmen","Osmo","Osmond","Osmund","Osric","Osrick","Osrock","Ossie","Osswald","Ossy","Ostap","Oster","Osterhus","Ostler","Ostraw","Osugi","Oswal","Oswald","Oswell","Oswin","Osy","Osyth","Ot","Otero","Otes","Otha","Othe","Othelia","Othella","Othello","Other","Othilia","Othilie","Otho","Otila","Otilia","Otina","Otis","Ott","Ottavia","Otte","Otter","Otti","Ottie","Ottilie","Ottillia","Ottinger","Otto","Oulman","Outhe","Outlaw","Ovid","Ovida","Owades","Owain","Owen","Owena","Owens","Oxford","Oxley","Oys","Oz","Oza","Ozan","Ozen","Ozkum","Ozmo","Ozzie","Ozzy","O'Brien","O'Callaghan","O'Carroll","O'Connell","O'Conner","O'Connor","O'Dell","O'Doneven","O'Donnell","O'Donoghue","O'Donovan","O'Driscoll","O'Gowan","O'Grady","O'Hara","O'Kelly","O'Mahony","O'Malley","O'Meara","O'Neil","O'Neill","O'Reilly","O'Rourke","O'Shee","O'Toole","Paapanen","Pablo","Pace","Pacheco","Pachston","Pachton","Pacian","Pacien","Pacifa","Pacifica","Pacificas","Pacificia","Pack","Packer","Packston","Packton","Paco","Pacorro","Paddie","Paddy","Padegs","Paderna","Padget","Padgett","Padraic","Padraig","Padriac","Paff","Pagas","Page","Pages","Paget","Pahl","Paige","Paik","Pail","Pain","Paine","Painter","Palecek","Palermo","Palestine","Paley","Palgrave","Palila","Pall","Palla","Palladin","Pallas","Pallaten","Pallaton","Pallua","Palm","Palma","Palmer","Palmira","Palmore","Palocz","Paloma","Pals","Palua","Paluas","Palumbo","Pam","Pamela","Pamelina","Pamella","Pammi","Pammie","Pammy","Pampuch","Pan","Panaggio","Panayiotis","Panchito","Pancho","Pandich","Pandolfi","Pandora","Pang","Pangaro","Pani","Pansie","Pansir","Pansy","Panta","Panter","Panthea","Pantheas","Panther","Panthia","Pantia","Pantin","Paola","Paolina","Paolo","Papagena","Papageno","Pape","Papert","Papke","Papotto","Papp","Pappano","Pappas","Papst","Paquito","Par","Paradies","Parcel","Pardew","Pardner","Pardo","Pardoes","Pare","Parent","Paresh","Parette","Parfitt","Parhe","Parik","Paris","Parish","Park","Parke","Parker","Parks","Parlin","Parnas","Parnell","Parrie","Parris","Parrisch","Parrish","Parrnell","Parrott","Parry","Parsaye","Parshall","Parsifal","Parsons","Partan","Parthen","Parthena","Parthenia","Parthinia","Particia","Partridge","Paryavi","Pas","Pasadis","Pasahow","Pascal","Pascale","Pascasia","Pascha","Paschasia","Pascia","Pasco","Pascoe","Pasho","Pasia","Paske","Pasol","Pasquale","Pass","Past","Pastelki","Pat","Pate","Paten","Paterson","Pathe","Patience","Patin","Patman","Patnode","Paton","Patric","Patrica","Patrice","Patrich","Patricia","Patricio","Patrick","Patrizia","Patrizio","Patrizius","Patsis","Patsy","Patt","Pattani","Patten","Patterman","Patterson","Patti","Pattie","Pattin","Pattison","Patton","Patty","Paucker","Paugh","Pauiie","Paul","Paula","Paule","Pauletta","Paulette","Pauli","Paulie","Paulina","Pauline","Paulita","Paulo","Paulsen","Paulson","Pauly","Pauwles","Pavel","Paver","Pavia","Pavier","Pavior","Paviour","Pavkovic","Pavla","Pavlish","Pavlov","Pavyer","Pawsner","Pax","Paxon","Paxton","Paymar","Payne","Paynter","Payson","Payton","Paz","Paza","Pazia","Pazice","Pazit","Peace","Peacock","Peadar","Peale","Pearce","Pearl","Pearla","Pearle","Pearline","Pearlman","Pearlstein","Pearman","Pears","Pearse","Pearson","Pease","Peatroy","Pebrook","Peck","Peckham","Pedaiah","Pedaias","Peddada","Peder","Pedersen","Pederson","Pedrick","Pedro","Pedrotti","Pedroza","Peer","Peers","Peery","Peg","Pega","Pegasus","Pegeen","Pegg","Peggi","Peggie","Peggir","Peggy","Pegma","Peh","Peirce","Peirsen","Peisch","Pejsach","Pelag","Pelaga","Pelage","Pelagi","Pelagia","Pelagias","Pell","Pellegrini","Pellet","Pelletier","Pelligrini","Pellikka","Pelmas","Pelpel","Pelson","Peltier","Peltz","Pember","Pembroke","Pembrook","Pen","Pena","Pence","Pendergast","Pendleton","Penelopa","Penelope","Pengelly","Penhall","Penland","Penman","Penn","Pennebaker","Penney","Penni","Pennie","Pennington","Penny","Penoyer","Penrod","Penrose","Pentha","Penthea","Pentheam","Pentheas","Peonir","Peony","Peoples","Pepe","Peper","Pepi","Pepillo","Pepin","Pepita","Pepito","Peppard","Peppel","Pepper","Peppi","Peppie","Peppy","Per","Perce","Perceval","Percival","Percy","Perdita","Peregrine","Pergrim","Peri","Peria","Perice","Perkin","Perkins","Perkoff","Perl","Perla","Perle","Perlie","Perlis","Perlman","Perloff","Pernas","Pernell","Perni","Pernick","Pero","Perot","Perpetua","Perr","Perreault","Perren","Perretta","Perri","Perrie","Perrin","Perrine","Perrins","Perron","Perry","Persas","Perseus","Persian","Persis","Persons","Persse","Persson","Perusse","Perzan","Pesek","Peskoff","Pessa","Pestana","Pet","Peta","Pete","Peter","Peterec","Peterman","Peters","Petersen","Peterson","Peterus","Petes","Petey","Peti","Petie","Petigny","Petit","Petite","Petr","Petra","Petracca","Petras","Petrick","Petrie","Petrina","Petrine","Petromilli","Petronella","Petronia","Petronilla","Petronille","Petta","Pettifer","Pettiford","Pettit","Petty","Petua","Petula","Petulah","Petulia","Petunia","Petuu","Peugia","Peursem","Pevzner","Peyter","Peyton","Pfaff","Pfeffer","Pfeifer","Pfister","Pfosi","Phaedra","Phaidra","Phaih","Phail","Phalan","Pharaoh","Phare","Phares","Phebe","Phedra","Phelan","Phelgen","Phelgon","Phelia","Phelips","Phelps","Phemia","Phene","Pheni","Phenica","Phenice","Phi","Phia","Phil","Phila","Philan","Philana","Philander","Philbert","Philbin","Philbo","Philbrook","Philcox","Philemol","Philemon","Philender","Philina","Philine","Philip","Philipa","Philipines","Philipp","Philippa","Philippe","Philippine","Philipps","Philips","Philipson","Philis","Phillada","Phillane","Phillida","Phillie","Phillip","Phillipe","Phillipp","Phillips","Phillis","Philly","Philo","Philomena","Philoo","Philpot","Philps","Phina","Phineas","Phio","Phiona","Phionna","Phip","Phippen","Phipps","Phira","Phoebe","Phonsa","Photima","Photina","Phox","Phyl","Phylis","Phyllida","Phyllis","Phyllys","Phylys","Pia","Piane","Picardi","Picco","Pich","Pickar","Pickard","Pickens","Picker","Pickering","Pickett","Pickford","Piderit","Piefer","Piegari","Pier","Pierce","Pierette","Piero","Pierpont","Pierre","Pierrepont","Pierrette","Pierro","Piers","Pierson","Pieter","Pietje","Pietra","Pietrek","Pietro","Pigeon","Piggy","Pike","Pilar","Pilloff","Pillow","Pillsbury","Pimbley","Pincas","Pinchas","Pincince","Pinckney","Pincus","Pine","Pinebrook","Pineda","Pinelli","Pinette","Ping","Pinkerton","Pinkham","Pinsky","Pinter","Pinto","Pinzler","Piotr","Pip","Piper","Pippa","Pippas","Pippo","Pippy","Pirali","Pirbhai","Pirnot","Pironi","Pirozzo","Pirri","Pirzada","Pisano","Pisarik","Piscatelli","Piselli","Pish","Pitarys","Pitchford","Pitt","Pittel","Pittman","Pitts","Pitzer","Pius","Piwowar","Pizor","Placeeda","Placia","Placida","Placidia","Placido","Plafker","Plank","Plantagenet","Plante","Platas","Plate","Plath","Plato","Platon","Platt","Platto","Platus","Player","Pleasant","Pleione","Plerre","Pliam","Pliner","Pliske","Ploch","Ploss","Plossl","Plotkin","Plumbo","Plume","Plunkett","Plusch","Podvin","Pogue","Poirer","Pokorny","Pol","Polad","Polak","Poland","Polard","Polash","Poler","Poliard","Polik","Polinski","Polish","Politi","Polito","Polivy","Polk","Polky","Poll","Pollack","Pollak","Pollard","Pollerd","Pollie","Pollitt","Polloch","Pollock","Pollux","Polly","Pollyanna","Pomcroy","Pomeroy","Pomfret","Pomfrey","Pomona","Pompea","Pompei","Ponce","Pond","Pontias","Pontius","Ponton","Pontone","Pontus","Ponzo","Poock","Pooh","Pooi","Pool","Poole","Pooley","Poore","Pope","Popele","Popelka","Poppas","Popper","Poppo","Poppy","Porche","Porcia","Poree","Porett","Port","Porta","Porte","Porter","Portia","Portie","Portingale","Portland","Portugal","Portuna","Portwin","Portwine","Porty","Porush","Posehn","Posner","Possing","Post","Postman","Potash","Potter","Potts","Poucher","Poul","Poulter","Pouncey","Pournaras","Powder","Powe","Powel","Powell","Power","Powers","Pownall","Poyssick","Pozzy","Pradeep","Prader","Prady","Prager","Prakash","Prasad","Pratt","Pratte","Pravit","Prebo","Preciosa","Preiser","Prem","Premer","Pren","Prendergast","Prent","Prentice","Prentiss","Presber","Prescott","Presley","Press","Pressey","Pressman","Prestige","Preston","Pretrice","Preuss","Previdi","Prevot","Price","Prichard","Pricilla","Pride","Priebe","Priest","Priestley","Prima","Primalia","Primavera","Primaveras","Primaveria","Primo","Primrosa","Primrose","Prince","Princess","Prinz","Prior","Pris","Prisca","Priscella","Priscilla","Prisilla","Prissie","Prissy","Pritchard","Pritchett","Prober","Prochora","Prochoras","Procora","Procter","Procto","Proctor","Profant","Proffitt","Pronty","Pros","Prosper","Prospero","Prosperus","Prosser","Proud","Proudfoot","Proudlove","Proudman","Proulx","Prouty","Prowel","Pru","Pruchno","Prud","Prudence","Prudhoe","Prudi","Prudie","Prudy","Prue","Prunella","Prussian","Pruter","Pry","Pryce","Pryor","Psyche","Pubilis","Publea","Publia","Publias","Publius","Publus","Pucida","Pudendas","Pudens","Puduns","Puett","Pufahl","Puff","Pugh","Puglia","Puiia","Puklich","Pul","Pulcheria","Pulchi","Pulchia","Pulling","Pulsifer","Pump","Punak","Punke","Purcell","Purdum","Purdy","Puri","Purington","Puritan","Purity","Purpura","Purse","Purvis","Putnam","Putnem","Puto","Putscher","Puttergill","Py","Pyle","Pylle","Pyne","Pyotr","Pyszka","Pytlik","Quackenbush","Quar","Quarta","Quartana","Quartas","Quartet","Quartis","Quartus","Queen","Queena","Queenie","Quenby","Quenna","Quennie","Quent","Quentin","Queri","Querida","Queridas","Questa","Queston","Quick","Quickel","Quickman","Quigley","Quill","Quillan","Quillon","Quin","Quinby","Quince","Quincey","Quincy","Quinlan","Quinn","Quint","Quinta","Quintana","Quintessa","Quintie","Quintilla","Quintin","Quintina","Quinton","Quintus","Quirita","Quirk","Quita","Quiteri","Quiteria","Quiteris","Quitt","Qulllon","Raab","Raama","Raasch","Rab","Rabah","Rabassa","Rabbi","Rabelais","Rabi","Rabiah","Rabin","Rabjohn","Rabkin","Rabush","Race","Rachaba","Rachael","Rachel","Rachele","Rachelle","Racklin","Rad","Radack","Radborne","Radbourne","Radbun","Radburn","Radcliffe","Raddatz","Raddi","Raddie","Raddy","Radferd","Radford","Radie","Radke","Radley","Radloff","Radman","Radmen","Radmilla","Radu","Rae","Raeann","Raf","Rafa","Rafael","Rafaela","Rafaelia","Rafaelita","Rafaelle","Rafaellle","Rafaello","Rafaelof","Rafat","Rafe","Raff","Raffaello","Raffarty","Rafferty","Raffin","Raffo","Rafi","Rafiq","Rafter","Ragan","Ragen","Ragg","Ragland","Ragnar","Ragouzis","Ragucci","Rahal","Rahel","Rahm","Rahman","Rahmann","Rahr","Rai","Raila","Raimes","Raimondo","Raimund","Raimundo","Raina","Rainah","Raine","Rainer","Raines","Rainger","Rainie","Rains","Rainwater","Rajewski","Raji","Rajiv","Rakel","Rakia","Ralaigh","Raleigh","Ralf","Ralfston","Ralina","Ralleigh","Ralli","Ralph","Ralston","Ram","Rama","Ramah","Raman","Ramberg","Rambert","Rambort","Rambow","Ramburt","Rame","Ramey","Ramiah","Ramin","Ramon","Ramona","Ramonda","Ramos","Ramsay","Ramsdell","Ramsden","Ramses","Ramsey","Ramunni","Ran","Rana","Rance","Rancell","Ranchod","Rand","Randa","Randal","Randall","Randee","Randell","Randene","Randi","Randie","Randolf","Randolph","Randy","Ranee","Raney","Range","Rangel","Ranger","Rani","Rania","Ranice","Ranie","Ranique","Ranit","Ranita","Ranite","Ranitta","Ranjiv","Rankin","Rann","Ranna","Ransell","Ransom","Ransome","Ranson","Ranzini","Rao","Raouf","Raoul","Rap","Rape","Raphael","Raphaela","Rapp","Raquel","Raquela","Ras","Raseda","Raseta","Rashida","Rashidi","Rasia","Rask","Raskin","Raskind","Rasla","Rasmussen","Rastus","Rasure","Ratcliff","Ratcliffe","Ratha","Rather","Ratib","Rattan","Rattray","Rauch","Raul","Rausch","Rauscher","Raveaux","Raven","Ravens","Ravi","Ravid","Raviv","Ravo","Rawdan","Rawden","Rawdin","Rawdon","Rawley","Rawlinson","Ray","Raybin","Raybourne","Rayburn","Raychel","Raycher","Raye","Rayford","Rayle","Raymond","Raymonds","Raymund","Rayna","Raynah","Raynard","Raynata","Raynell","Rayner","Raynold","Raynor","Rayshell","Razid","Rea","Reace","Read","Reade","Readus","Ready","Reagan","Reagen","Reahard","Reames","Reamonn","Reamy","Reave","Reba","Rebah","Rebak","Rebane","Rebba","Rebbecca","Rebe","Rebeca","Rebecca","Rebecka","Rebeka","Rebekah","Rebekkah","Rebel","Rebhun","Rech","Recha","Rechaba","Reckford","Recor","Rector","Red","Redd","Reddin","Reddy","Redfield","Redford","Redman","Redmer","Redmond","Redmund","Redvers","Redwine","Ree","Reeba","Reece","Reed","Reede","Reedy","Reeher","Reel","Reena","Rees","Reese","Reeta","Reeva","Reeve","Reeves","Reg","Regan","Regazzi","Regen","Reger","Reggi","Reggie","Reggis","Reggy","Regina","Reginald","Reginauld","Regine","Rego","Rehm","Rehnberg","Reich","Reiche","Reichel","Reichert","Reid","Reidar","Reider","Reifel","Reiko","Reilly","Reimer","Rein","Reina","Reinald","Reinaldo","Reinaldos","Reine","Reiner","Reiners","Reinert","Reinertson","Reinhard","Reinhardt","Reinhart","Reinhold","Reinke","Reinold","Reinwald","Reis","Reisch","Reiser","Reisfield","Reisinger","Reisman","Reiss","Reiter","Reitman","Reld","Rella","Rellia","Relly","Rem","Rema","Remde","Remington","Remmer","Rempe","Remsen","Remus","Remy","Rena","Renado","Renae","Renaldo","Renard","Renata","Renate","Renato","Renaud","Renault","Renckens","Rene","Renee","Renell","Renelle","Reneta","Renferd","Renfred","Reni","Renick","Renie","Renita","Reniti","Rennane","Renner","Rennie","Rennold","Renny","Rento","Rentsch","Rentschler","Renwick","Renzo","Reo","Resa","Rese","Reseda","Resee","Reseta","Resor","Ress","Ressler","Reste","Restivo","Reta","Retha","Rett","Rettig","Rettke","Reube","Reuben","Reuven","Revell","Reviel","Reviere","Revkah","Rew","Rex","Rexana","Rexanna","Rexanne","Rexer","Rexferd","Rexford","Rexfourd","Rey","Reyna","Reynard","Reynold","Reynolds","Rezzani","Rhea","Rheba","Rhee","Rheims","Rheingold","Rheinlander","Rheta","Rhett","Rhetta","Rhiamon","Rhiana","Rhianna","Rhianon","Rhine","Rhines","Rhoades","Rhoads","Rhoda","Rhodes","Rhodia","Rhodie","Rhody","Rhona","Rhonda","Rhu","Rhynd","Rhyne","Rhyner","Rhys","Ri","Ria","Riana","Riancho","Riane","Rianna","Riannon","Rianon","Riba","Ribal","Ribaudo","Ribble","Ric","Rica","Ricard","Ricarda","Ricardama","Ricardo","Ricca","Riccardo","Riccio","Rice","Rich","Richara","Richard","Richarda","Richardo","Richards","Richardson","Richart","Richel","Richela","Richella","Richelle","Richer","Richers","Richey","Richia","Richie","Richlad","Richma","Richmal","Richman","Richmond","Richmound","Richter","Richy","Rici","Rick","Rickard","Rickart","Ricker","Rickert","Ricketts","Rickey","Ricki","Rickie","Ricky","Rico","Ricoriki","Rida","Riddle","Rider","Ridglea","Ridglee","Ridgley","Ridinger","Ridley","Rie","Riebling","Riedel","Riegel","Rieger","Riehl","Riella","Ries","Riesman","Riess","Rieth","Riffle","Rifkin","Rigby","Rigdon","Riggall","Riggins","Riggs","Riha","Rihana","Rik","Rika","Riker","Riki","Rikki","Rilda","Riley","Rillings","Rillis","Rima","Rimas","Rimma","Rimola","Rina","Rinaldo","Rind","Rinee","Ring","Ringe","Ringler","Ringo","Ringsmuth","Rinna","Rintoul","Riobard","Riocard","Rior","Riordan","Riorsson","Rip","Ripleigh","Riplex","Ripley","Ripp","Risa","Rise","Risley","Rissa","Risser","Rist","Risteau","Rita","Ritch","Ritchie","Riti","Ritter","Ritz","Riva","Rivalee","Rivard","River","Rivera","Rivers","Rives","Rivi","Rivkah","Rivy","Rizas","Rizika","Rizzi","Rizzo","Ro","Roach","Roana","Roane","Roanna","Roanne","Roarke","Roath","Rob","Robaina","Robb","Robbert","Robbi","Robbie","Robbin","Robbins","Robby","Robbyn","Robena","Robenia","Robers","Roberson","Robert","Roberta","Roberto","Roberts","Robertson","Robet","Robi","Robillard","Robin","Robina","Robinet","Robinett","Robinetta","Robinette","Robinia","Robins","Robinson","Robison","Robson","Roby","Robyn","Rocca","Rocco","Roch","Roche","Rochell","Rochella","Rochelle","Rochemont","Rocher","Rochester","Rochette","Rochkind","Rochus","Rock","Rockafellow","Rockefeller","Rockel","Rocker","Rockey","Rockie","Rockwell","Rockwood","Rocky","Rocray","Rod","Roda","Rodd","Roddie","Roddy","Rodenhouse","Roderic","Roderica","Roderich","Roderick","Roderigo","Rodge","Rodger","Rodgers","Rodi","Rodie","Rodina","Rodl","Rodman","Rodmann","Rodmun","Rodmur","Rodney","Rodolfo","Rodolph","Rodolphe","Rodrich","Rodrick","Rodrigo","Rodriguez","Rodrique","Roe","Roede","Roee","Roehm","Roer","Roeser","Rog","Roger","Rogerio","Rogers","Rogerson","Rogovy","Rogozen","Rohn","Roi","Roice","Roid","Rois","Rojas","Rokach","Rola","Rolan","Roland","Rolanda","Rolando","Rolandson","Roldan","Roley","Rolf","Rolfe","Rolfston","Rolland","Rollet","Rollie","Rollin","Rollins","Rollo","Rolo","Rolph","Roma","Romain","Romaine","Romalda","Roman","Romanas","Romano","Rombert","Rome","Romelda","Romelle","Romeo","Romeon","Romeu","Romeyn","Romie","Romilda","Romilly","Romina","Romine","Romito","Romney","Romo","Romola","Romona","Romonda","Romulus","Romy","Ron","Rona","Ronal","Ronald","Ronalda","Ronda","Rondi","Rondon","Ronel","Ronen","Ronica","Ronn","Ronna","Ronnholm","Ronni","Ronnica","Ronnie","Ronny","Roobbie","Rooke","Rooker","Rooney","Roos","Roose","Roosevelt","Root","Roots","Roper","Roque","Rora","Rori","Rorie","Rorke","Rorry","Rorrys","Rory","Ros","Rosa","Rosabel","Rosabella","Rosabelle","Rosalba","Rosalee","Rosaleen","Rosalia","Rosalie","Rosalind","Rosalinda","Rosalinde","Rosaline","Rosalyn","Rosalynd","Rosamond","Rosamund","Rosana","Rosane","Rosanna","Rosanne","Rosario","Rosati","Rosco","Roscoe","Rose","Roseann","Roseanna","Roseanne","Rosecan","Rosel","Roselane","Roselani","Roselba","Roselia","Roselin","Roseline","Rosella","Roselle","Roselyn","Rosemare","Rosemari","Rosemaria","Rosemarie","Rosemary","Rosemonde","Rosen","Rosena","Rosenbaum","Rosenberg","Rosenberger","Rosenblast","Rosenblatt","Rosenblum","Rosene","Rosenfeld","Rosenkrantz","Rosenkranz","Rosenquist","Rosenstein","Rosenthal","Rosenwald","Rosenzweig","Rosetta","Rosette","Roshan","Roshelle","Rosie","Rosina","Rosinski","Rosio","Rosita","Roskes","Roslyn","Rosmarin","Rosmunda","Rosner","Rosol","Ross","Rosse","Rossen","Rossi","Rossie","Rossing","Rossner","Rossuck","Rossy","Rostand","Roswald","Roswell","Rosy","Rotberg","Roter","Roth","Rothberg","Rothenberg","Rother","Rothmuller","Rothschild","Rothstein","Rothwell","Roti","Rotman","Rotow","Roumell","Rourke","Routh","Rouvin","Roux","Rovelli","Rovit","Rovner","Row","Rowan","Rowe","Rowell","Rowen","Rowena","Rowland","Rowley","Rowney","Rox","Roxana","Roxane","Roxanna","Roxanne","Roxi","Roxie","Roxine","Roxy","Roy","Royal","Royall","Roybn","Royce","Royd","Roydd","Royden","Roye","Royo","Roz","Rozalie","Rozalin","Rozamond","Rozanna","Rozanne","Roze","Rozek","Rozele","Rozella","Rozelle","Rozina","Rriocard","Ru","Rubbico","Rube","Rubel","Ruben","Rubens","Rubenstein","Ruberta","Rubetta","Rubi","Rubia","Rubie","Rubin","Rubina","Rubinstein","Rubio","Ruby","Rucker","Ruckman","Rudd","Ruddie","Ruddy","Rudelson","Ruder","Rudich","Rudie","Rudiger","Rudin","Rudman","Rudolf","Rudolfo","Rudolph","Rudwik","Rudy","Rudyard","Rue","Ruel","Ruella","Ruelle","Ruelu","Rufe","Rufena","Ruff","Ruffi","Ruffin","Ruffina","Ruffo","Rufford","Rufina","Ruford","Rufus","Rugen","Rugg","Ruggiero","Ruhl","Ruhnke","Ruiz","Rumery","Rumilly","Rumney","Rumpf","Runck","Rundgren","Runkel","Runkle","Runstadler","Rupert","Ruperta","Ruperto","Ruphina","Ruprecht","Rurik","Rus","Ruscher","Ruscio","Rusel","Rusell","Rusert","Rush","Rushing","Ruskin","Russ","Russel","Russell","Russi","Russia","Russian","Russo","Russom","Russon","Rust","Rustice","Rusticus","Rustie","Rustin","Rusty","Rutan","Rutger","Ruth","Ruthann","Ruthanne","Ruthe","Rutherford","Rutherfurd","Ruthi","Ruthie","Ruthven","Ruthy","Rutledge","Rutter","Ruttger","Ruvolo","Ruy","Ruyle","Ruzich","Ryan","Ryann","Rycca","Rydder","Ryder","Rye","Ryle","Ryley","Ryon","Rysler","Ryter","Ryun","Saba","Sabah","Sabba","Sabec","Sabella","Sabelle","Saber","Saberhagen","Saberio","Sabian","Sabina","Sabine","Sabino","Sabir","Sabra","Sabrina","Sabsay","Sabu","Sacci","Sacha","Sachi","Sachiko","Sachs","Sachsse","Sacken","Sackey","Sackman","Sacks","Sacksen","Sackville","Sacttler","Sad","Sada","Saddler","Sadella","Sadick","Sadie","Sadira","Sadirah","Sadiras","Sadler","Sadoc","Sadoff","Sadonia","Sadowski","Sadye","Saeger","Saffian","Saffier","Saffren","Safier","Safir","Safire","Safko","Sage","Sager","Sagerman","Saidee","Saidel","Saideman","Saied","Saiff","Sailesh","Saimon","Saint","Sair","Saire","Saito","Sajovich","Sakhuja","Sakmar","Sakovich","Saks","Sal","Salahi","Salaidh","Salamanca","Salamone","Salangi","Salangia","Salas","Salazar","Salba","Salbu","Salchunas","Sale","Saleem","Salem","Salema","Saleme","Salena","Salene","Salesin","Salim","Salina","Salinas","Salisbarry","Salisbury","Salita","Sall","Sallee","Salli","Sallie","Sally","Sallyann","Sallyanne","Salman","Salmon","Saloma","Salome","Salomi","Salomie","Salomo","Salomon","Salomone","Salot","Salsbury","Salter","Saltsman","Saltzman","Salvador","Salvadore","Salvatore","Salvay","Salvidor","Salvucci","Salzhauer","Sam","Sama","Samal","Samala","Samale","Samalla","Samantha","Samanthia","Samara","Samaria","Samau","Samella","Samford","Sami","Samira","Sammer","Sammie","Sammons","Sammy","Samp","Sampson","Sams","Samson","Samuel","Samuela","Samuele","Samuella","Samuelson","Samul","Samy","Sanalda","Sanbo","Sanborn","Sanborne","Sanburn","Sancha","Sanchez","Sancho","Sand","Sandberg","Sande","Sandeep","Sandell","Sander","Sanders","Sanderson","Sandi","Sandie","Sandler","Sandon","Sandor","Sandra","Sandro","Sandry","Sands","Sandstrom","Sandy","Sandye","Sanferd","Sanfo","Sanford","Sanfourd","Sanfred","Sang","Sanger","Sanjay","Sanjiv","Sankaran","Sankey","Sansbury","Sansen","Sanson","Sansone","Santa","Santana","Santiago","Santini","Santoro","Santos","Sanyu","Sapers","Saphra","Sapienza","Sapowith","Sapphera","Sapphira","Sapphire","Sara","Sara-Ann","Saraann","Sarad","Sarah","Saraiya","Sarajane","Sarazen","Sarchet","Sardella","Saree","Sarena","Sarene","Saretta","Sarette","Sarge","Sargent","Sari","Sarid","Sarilda","Sarina","Sarine","Sarita","Sarkaria","Sarnoff","Sarson","Sartin","Sascha","Sasha","Sashenka","Sasnett","Sass","Sassan","Sateia","Sathrum","Sato","Satterfield","Satterlee","Saturday","Saucy","Sauder","Saudra","Sauer","Sauers","Saul","Sauls","Saum","Sauncho","Saunder","Saunders","Saunderson","Saundra","Sausa","Sauveur","Savadove","Savage","Saval","Savanna","Savannah","Savdeep","Savell","Savick","Savil","Savill","Saville","Savina","Savior","Savitt","Savory","Saw","Sawtelle","Sawyer","Sawyere","Sawyor","Sax","Saxe","Saxen","Saxena","Saxon","Say","Sayce","Sayed","Sayer","Sayers","Sayette","Sayles","Saylor","Sayre","Sayres","Scales","Scammon","Scandura","Scarface","Scarito","Scarlet","Scarlett","Scarrow","Scever","Scevo","Scevor","Scevour","Schaab","Schaaff","Schach","Schacker","Schaefer","Schaeffer","Schafer","Schaffel","Schaffer","Schalles","Schaper","Schapira","Scharaga","Scharf","Scharff","Schargel","Schatz","Schaumberger","Schear","Schechinger","Schechter","Scheck","Schecter","Scheer","Scheers","Scheider","Scheld","Schell","Schellens","Schenck","Scherle","Scherman","Schertz","Schick","Schiff","Schiffman","Schifra","Schild","Schilit","Schilling","Schilt","Schindler","Schinica","Schiro","Schlenger","Schlesinger","Schlessel","Schlessinger","Schlicher","Schlosser","Schluter","Schmeltzer","Schmidt","Schmitt","Schmitz","Schnabel","Schnapp","Schnell","Schnorr","Schnur","Schnurr","Schober","Schoenberg","Schoenburg","Schoenfelder","Schoening","Schofield","Scholem","Scholz","Schonfeld","Schonfield","Schonthal","Schoof","Schott","Schou","Schouten","Schrader","Schram","Schramke","Schreck","Schreib","Schreibe","Schreiber","Schreibman","Schrick","Schriever","Schroder","Schroeder","Schroer","Schroth","Schubert","Schug","Schuh","Schulein","Schuler","Schulman","Schultz","Schulz","Schulze","Schuman","Schumer","Schurman","Schuster","Schuyler","Schwab","Schwartz","Schwarz","Schweiker","Schweitzer","Schwejda","Schwenk","Schwerin","Schwing","Schwinn","Schwitzer","Scibert","Sclar","Sclater","Scoles","Scopp","Scornik","Scot","Scoter","Scotney","Scott","Scotti","Scottie","Scotty","Scoville","Screens","Scribner","Scriven","Scrivenor","Scrivens","Scrivings","Scrogan","Scrope","Sculley","Scully","Scurlock","Scutt","Seabrook","Seabrooke","Seabury","Seaddon","Seaden","Seadon","Seafowl","Seagrave","Seagraves","Seale","Seaman","Seamus","Sean","Seana","Searby","Searcy","Searle","Sears","Season","Seaton","Seaver","Seavey","Seavir","Sebastian","Sebastiano","Sebastien","Sebbie","Secor","Secrest","Secunda","Secundas","Seda","Sedberry","Sedda","Sedgewake","Sedgewick","Sedgewinn","Sedlik","See","Seebeck","Seed","Seedman","Seel","Seely","Seem","Seema","Seen","Seena","Seessel","Seeto","Seften","Sefton","Seftton","Segal","Segalman","Seiber","Seibold","Seidel","Seiden","Seidler","Seidule","Seif","Seigel","Seigler","Seiter","Seitz","Seka","Seko","Sekofski","Sekyere","Sela","Selassie","Selby","Selda","Seldan","Selden","Seldon","Seldun","Selemas","Selena","Selene","Selestina","Seleta","Selfridge","Selhorst","Selia","Selie","Selig","Seligman","Seligmann","Selima","Selimah","Selina","Selinda","Seline","Selinski","Sell","Sella","Selle","Sellers","Sellma","Sello","Sells","Selma","Selmner","Selmore","Selry","Seltzer","Selway","Selwin","Selwyn","Semela","Semele","Semmes","Sena","Senalda","Sender","Senecal","Senhauser","Senior","Senn","Sension","Senskell","Senzer","Seow","Sephira","Seppala","September","Septima","Sera","Serafina","Serafine","Seraphim","Seraphina","Seraphine","Serena","Serene","Serg","Serge","Sergeant","Sergei","Sergent","Sergias","Sergio","Sergius","Sergo","Sergu","Serica","Serilda","Serle","Serles","Seroka","Serra","Serrano","Serrell","Servais","Server","Servetnick","Service","Sessler","Seta","Seth","Sethi","Sethrida","Seto","Seton","Settera","Settle","Seumas","Sev","Seve","Severen","Severin","Severn","Severson","Sevik","Seward","Sewel","Sewell","Sewellyn","Sewole","Sewoll","Sexton","Seyler","Seymour","Seys","Sezen","Shabbir","Shaddock","Shadow","Shae","Shaefer","Shaeffer","Shaer","Shafer","Shaff","Shaffer","Shaffert","Shah","Shaia","Shaikh","Shaina","Shaine","Shakespeare","Shakti","Shalna","Shalne","Shalom","Shama","Shamma","Shamrao","Shamus","Shana","Shanahan","Shanan","Shanda","Shandee","Shandeigh","Shandie","Shandra","Shandy","Shane","Shaner","Shani","Shanie","Shank","Shanks","Shanleigh","Shanley","Shanly","Shanna","Shannah","Shannan","Shannen","Shanney","Shannon","Shanon","Shanta","Shantee","Shantha","Shaper","Shapiro","Shara","Sharai","Shargel","Shari","Sharia","Sharity","Sharl","Sharla","Sharleen","Sharlene","Sharline","Sharma","Sharman","Sharon","Sharona","Sharos","Sharp","Sharpe","Sharron","Sharyl","Shatzer","Shaughn","Shaughnessy","Shaum","Shaun","Shauna","Shaver","Shaw","Shawn","Shawna","Shawnee","Shay","Shaya","Shayla","Shaylah","Shaylyn","Shaylynn","Shayn","Shayna","Shayne","Shea","Sheaff","Shear","Sheba","Shedd","Sheeb","Sheedy","Sheehan","Sheela","Sheelagh","Sheelah","Sheena","Sheepshanks","Sheeran","Sheeree","Sheets","Sheff","Sheffie","Sheffield","Sheffy","Sheila","Sheilah","Shel","Shela","Shelagh","Shelah","Shelba","Shelbi","Shelburne","Shelby","Shelden","Sheldon","Sheley","Shelia","Sheline","Shell","Shellans","Shelley","Shelli","Shellie","Shelly","Shelman","Shelton","Shem","Shena","Shenan","Sheng","Shep","Shepard","Shepherd","Shepley","Sheply","Shepp","Sheppard","Shepperd","Sher","Sherar","Sherard","Sherborn","Sherborne","Sherburn","Sherburne","Shere","Sheree","Sherer","Shererd","Sherfield","Sheri","Sheridan","Sherie","Sherill","Sherilyn","Sherj","Sherl","Sherline","Sherlock","Sherlocke","Sherm","Sherman","Shermie","Shermy","Sherourd","Sherr","Sherrard","Sherrer","Sherri","Sherrie","Sherrill","Sherris","Sherrod","Sherry","Sherurd","Sherwin","Sherwood","Sherwynd","Sherye","Sheryl","Sheryle","Shetrit","Shevlo","Shewchuk","Shewmaker","Sheya","Shiau","Shieh","Shiekh","Shields","Shien","Shiff","Shifra","Shifrah","Shig","Shih","Shiller","Shimberg","Shimkus","Shina","Shinberg","Shing","Shipley","Shipman","Shipp","Shippee","Shir","Shira","Shirah","Shirberg","Shiri","Shirk","Shirl","Shirlee","Shirleen","Shirlene","Shirley","Shirlie","Shirline","Shiroma","Shishko","Shiverick","Shivers","Shlomo","Shoemaker","Shoifet","Sholeen","Sholem","Sholes","Sholley","Sholom","Shore","Shornick","Short","Shorter","Shoshana","Shoshanna","Shotton","Showker","Shreeves","Shreve","Shrier","Shriner","Shriver","Shu","Shue","Shugart","Shulamith","Shulem","Shuler","Shulins","Shull","Shulman","Shulock","Shult","Shultz","Shum","Shuma","Shuman","Shumway","Shuping","Shurlock","Shurlocke","Shurwood","Shushan","Shute","Shutz","Shwalb","Shyamal","Si","Siana","Sianna","Sib","Sibbie","Sibby","Sibeal","Sibel","Sibell","Sibella","Sibelle","Siberson","Sibie","Sibilla","Sible","Siblee","Sibley","Sibyl","Sibylla","Sibylle","Sibyls","Sicard","Sices","Siclari","Sicular","Sid","Sida","Siddon","Siddra","Sidell","Sidhu","Sidky","Sidman","Sidnee","Sidney","Sidoma","Sidon","Sidoney","Sidonia","Sidonie","Sidonius","Sidonnie","Sidoon","Sidra","Sidran","Sidras","Sidwel","Sidwell","Sidwohl","Sieber","Siegel","Siegfried","Siegler","Sielen","Sieracki","Sierra","Siesser","Sievert","Siffre","Sig","Sigfrid","Sigfried","Sigismond","Sigismondo","Sigismund","Sigismundo","Sigler","Sigmund","Signe","Sigrid","Sigsmond","Sigvard","Sihon","Sihonn","Sihun","Sihunn","Sik","Sikata","Sikes","Sikko","Sikorski","Sil","Silas","Silber","Silberman","Silda","Silden","Sile","Sileas","Silin","Sill","Sillsby","Silma","Siloa","Siloam","Siloum","Silsby","Silsbye","Silva","Silvain","Silvan","Silvana","Silvano","Silvanus","Silver","Silverman","Silvers","Silverstein","Silverts","Silvester","Silvestro","Silvia","Silvie","Silvio","Sim","Sima","Simah","Simdars","Simeon","Simmie","Simmonds","Simmons","Simon","Simona","Simone","Simonetta","Simonette","Simonne","Simons","Simonsen","Simpkins","Simpson","Sims","Simsar","Simson","Sinai","Sinclair","Sinclare","Sindee","Sine","Sinegold","Singband","Singer","Singh","Singhal","Singleton","Sink","Sinnard","Siobhan","Sion","Sioux","Siouxie","Sipple","Sirkin","Sirmons","Sirois","Sirotek","Sisak","Sisco","Sisely","Sisile","Siskind","Sissel","Sissie","Sisson","Sissy","Sisto","Sitarski","Sitnik","Sitra","Siubhan","Siusan","Sivia","Sivie","Siward","Sjoberg","Skantze","Skardol","Skees","Skeie","Skell","Skelly","Skelton","Skerl","Skiba","Skier","Skiest","Skilken","Skill","Skillern","Skinner","Skip","Skipp","Skipper","Skippie","Skippy","Skipton","Sklar","Skolnik","Skricki","Skurnik","Skutchan","Skvorak","Sky","Skye","Skyla","Skylar","Skyler","Slaby","Slack","Slade","Sladen","Slater","Slaughter","Slavic","Slavin","Slayton","Sldney","Slemmer","Sletten","Slifka","Slinkman","Sliwa","Sloan","Sloane","Sloatman","Slocum","Slosberg","Slotnick","Sluiter","Sly","Slyke","Smail","Small","Smalley","Smallman","Smart","Smiga","Smiley","Smith","Smitt","Smitty","Smoot","Smukler","Snapp","Snashall","Sneed","Snell","Snider","Snoddy","Snodgrass","Snook","Snow","Snowber","Snowman","Snyder","So","Soane","Sobel","Soble","Socha","Socher","Sochor","Socrates","Soelch","Sofer","Sofia","Sofie","Sofko","Soinski","Sokil","Sokul","Sol","Sola","Solana","Solange","Solberg","Solenne","Solis","Solita","Solitta","Soll","Sollars","Solley","Sollie","Sollows","Solly","Solnit","Soloma","Soloman","Solomon","Solon","Soluk","Som","Somerset","Somerville","Sommer","Sommers","Son","Sondra","Soneson","Song","Soni","Sonia","Sonja","Sonni","Sonnie","Sonnnie","Sonny","Sonstrom","Sontag","Sontich","Sonya","Soo","Soph","Sopher","Sophey","Sophi","Sophia","Sophie","Sophronia","Sophy","Soracco","Soraya","Sorce","Sorcha","Sorci","Sorcim","Sorel","Soren","Sorensen","Sorenson","Sorilda","Sorkin","Sorrows","Sosanna","Sosna","Sosthena","Sosthenna","Sosthina","Sothena","Sotos","Sou","Soule","Soulier","Sousa","Southard","Southworth","Soutor","Souvaine","Souza","Sowell","Sower","Spada","Spain","Spalding","Spalla","Spancake","Spanjian","Spanos","Sparhawk","Spark","Sparke","Sparkie","Sparks","Sparky","Sparrow","Spatola","Spatz","Spaulding","Spear","Spearing","Spearman","Spears","Specht","Spector","Spence","Spencer","Spense","Spenser","Sperling","Speroni","Sperry","Spevek","Spiegel","Spiegelman","Spiegleman","Spieler","Spielman","Spiers","Spike","Spillar","Spindell","Spiro","Spiros","Spitzer","Spohr","Spooner","Spoor","Spracklen","Sprage","Spragens","Sprague","Spratt","Spring","Springer","Sproul","Sprung","Spurgeon","Squier","Squire","Squires","Srini","Staal","Stace","Stacee","Stacey","Staci","Stacia","Stacie","Stacy","Stafani","Staffan","Staffard","Stafford","Staford","Stag","Stagg","Stahl","Stalder","Staley","Stalk","Stalker","Stallworth","Stamata","Stambaugh","Stan","Stander","Standford","Standice","Standing","Standish","Standley","Standush","Stanfield","Stanfill","Stanford","Stanhope","Stanislas","Stanislaus","Stanislaw","Stanleigh","Stanley","Stanly","Stannfield","Stannwood","Stanton","Stanway","Stanwin","Stanwinn","Stanwood","Stanzel","Star","Starbuck","Stargell","Starinsky","Stark","Starkey","Starks","Starla","Starlene","Starlin","Starling","Starobin","Starr","Stasny","Staten","Statis","Stauder","Stauffer","Stav","Stavro","Stavros","Staw","Stclair","Stead","Steady","Stearn","Stearne","Stearns","Steck","Steddman","Stedman","Stedmann","Stedt","Steel","Steele","Steen","Steep","Steere","Stefa","Stefan","Stefanac","Stefania","Stefanie","Stefano","Steffane","Steffen","Steffi","Steffie","Steffin","Steffy","Stegman","Stein","Steinberg","Steiner","Steinke","Steinman","Steinway","Stella","Stelle","Stelmach","Stelu","Stempien","Stempson","Stenger","Stent","Stepha","Stephan","Stephana","Stephani","Stephania","Stephanie","Stephannie","Stephanus","Stephen","Stephenie","Stephens","Stephenson","Stephi","Stephie","Stephine","Sterling","Stern","Sternberg","Sterne","Sterner","Sternick","Sternlight","Sterrett","Stesha","Stets","Stetson","Stevana","Steve","Steven","Stevena","Stevens","Stevenson","Stevie","Stevy","Stew","Steward","Stewardson","Stewart","Stich","Stichter","Stickney","Stiegler","Stieglitz","Stier","Stig","Stila","Stiles","Still","Stilla","Stillas","Stillman","Stillmann","Stilu","Stilwell","Stimson","Stine","Stinky","Stinson","Stirling","Stoat","Stochmal","Stock","Stockmon","Stockton","Stockwell","Stoddard","Stoddart","Stodder","Stoeber","Stoecker","Stoffel","Stokes","Stoll","Stoller","Stolzer","Stone","Stoneham","Stoneman","Stonwin","Stoops","Storer","Storfer","Storm","Stormi","Stormie","Stormy","Stortz","Story","Storz","Stouffer","Stoughton","Stout","Stovall","Stover","Strade","Strader","Strage","Strain","Strait","Stralka","Strander","Strang","Stranger","Stratton","Straub","Straus","Strauss","Strawn","Streeter","Streetman","Streeto","Strenta","Strep","Strephon","Strephonn","Strepphon","Stretch","Stricklan","Strickland","Strickler","Strickman","Stringer","Strohbehn","Strohben","Strohl","Stromberg","Strong","Stronski","Stroud","Stroup","Struve","Stryker","Stu","Stuart","Stubbs","Stubstad","Stucker","Stuckey","Studdard","Studley","Studner","Studnia","Stulin","Stultz","Stuppy","Sturdivant","Sturges","Sturrock","Stutman","Stutsman","Stutzman","Styles","Su","Suanne","Subak","Subir","Sublett","Suchta","Suckow","Sucy","Sudbury","Sudderth","Sudhir","Sudnor","Sue","Suellen","Suelo","Sugar","Sugden","Sugihara","Suh","Suhail","Suilmann","Suk","Sukey","Sukhum","Suki","Sukin","Sula","Sulamith","Sullivan","Sully","Sum","Sumer","Sumerlin","Summer","Summers","Summons","Sumner","Sunda","Sunday","Sundberg","Sunderland","Sundin","Sundstrom","Suneya","Sung","Sunil","Sunny","Sunshine","Sup","Supat","Supen","Supple","Sura","Surbeck","Surovy","Survance","Susan","Susana","Susanetta","Susann","Susanna","Susannah","Susanne","Susette","Susi","Susie","Sussi","Sussman","Sussna","Susumu","Susy","Suter","Sutherlan","Sutherland","Sutphin","Sutton","Suu","Suzan","Suzann","Suzanna","Suzanne","Suzetta","Suzette","Suzi","Suzie","Suzy","Suzzy","Sven","Svend","Svensen","Sverre","Svetlana","Svoboda","Swagerty","Swain","Swaine","Swainson","Swamy","Swan","Swane","Swanhilda","Swanhildas","Swann","Swanson","Swart","Swarts","Swartz","Swayder","Swayne","Sweatt","Swec","Swee","Sweeney","Sweet","Swen","Swenson","Swetiana","Swetlana","Sweyn","Swiercz","Swift","Swigart","Swihart","Swinton","Swirsky","Swisher","Swithbart","Swithbert","Swithin","Switzer","Swope","Swor","Swords","Sy","Sybil","Sybila","Sybilla","Sybille","Sybley","Sybyl","Syck","Syd","Sydel","Sydelle","Sydney","Sykes","Syl","Sylas","Sylvan","Sylvanus","Sylvester","Sylvia","Sylvie","Syman","Symer","Symon","Symons","Synn","Syst","Syverson","TEirtza","Taam","Tab","Tabatha","Tabb","Tabbatha","Tabber","Tabbi","Tabbie","Tabbitha","Tabby","Taber","Tabib","Tabina","Tabitha","Tabor","Tabshey","Tace","Tacita","Tacklind","Tacy","Tacye","Tad","Tada","Tadashi","Tadd","Taddeo","Taddeusz","Tade","Tadeas","Tadeo","Tades","Tadich","Tadio","Taffy","Taft","Tager","Taggart","Tahmosh","Tai","Tailor","Taima","Taimi","Tait","Taite","Tak","Taka","Takakura","Takara","Takashi","Takeo","Takeshi","Takken","Tal","Tala","Talanian","Talanta","Talbert","Talbot","Talbott","Tali","Talia","Talich","Talie","Tallbot","Tallbott","Talley","Tallia","Tallie","Tallou","Tallu","Tallula","Tallulah","Tally","Talmud","Talya","Talyah","Tam","Tama","Tamah","Tamanaha","Tamar","Tamara","Tamarah","Tamarra","Tamaru","Tamas","Tamberg","Tamer","Tamera","Tami","Tamiko","Tamis","Tamma","Tammany","Tammara","Tammi","Tammie","Tammy","Tamqrah","Tamra","Tamsky","Tan","Tana","Tanah","Tanaka","Tanberg","Tandi","Tandie","Tandy","Tanhya","Tani","Tania","Tanitansy","Tankoos","Tann","Tannen","Tannenbaum","Tannenwald","Tanner","Tanney","Tannie","Tanny","Tansey","Tansy","Tanya","Tapes","Tara","Tarabar","Tarah","Taran","Tarazi","Tare","Tareyn","Targett","Tarkany","Taro","Tarr","Tarra","Tarrah","Tarrance","Tarrant","Tarrel","Tarrsus","Tarryn","Tarsus","Tarsuss","Tartaglia","Tartan","Tarton","Tarttan","Taryn","Taryne","Tasha","Tasia","Tasiana","Tat","Tate","Tati","Tatia","Tatiana","Tatianas","Tatiania","Tatianna","Tatman","Tattan","Tatum","Taub","Tav","Taveda","Tavey","Tavi","Tavia","Tavie","Tavis","Tavish","Tavy","Tawney","Tawnya","Tawsha","Tay","Tayib","Tayler","Taylor","Tayyebeb","Tchao","Teador","Teagan","Teage","Teague","Teahan","Teak","Tearle","Tecla","Tecu","Ted","Tedd","Tedda","Tedder","Teddi","Teddie","Teddman","Teddy","Tedi","Tedie","Tedman","Tedmann","Tedmund","Tedra","Tedric","Teece","Teena","Teerell","Teeter","Teevens","Teferi","Tega","Tegan","Teillo","Teilo","Tekla","Telfer","Telford","Telfore","Tella","Tellford","Tem","Tema","Temp","Tempa","Tempest","Templa","Templas","Temple","Templer","Templeton","Templia","Ten","Tena","Tench","Tenenbaum","Tengdin","Tengler","Tenn","Tenner","Tennes","Tenney","Tennies","Teodoor","Teodor","Teodora","Teodorico","Teodoro","Teplica","Teplitz","Tepper","Tera","Terbecki","Terchie","Terena","Terence","Terencio","Teresa","Terese","Teresina","Teresita","Teressa","Terhune","Teri","Teria","Teriann","Terina","Terle","Ternan","Terpstra","Terr","Terra","Terrance","Terrel","Terrell","Terrena","Terrence","Terrene","Terri","Terrie","Terrijo","Terrill","Terrilyn","Terris","Terriss","Territus","Terry","Terrye","Terryl","Terryn","Tersina","Terti","Tertia","Tertias","Tertius","Teryl","Teryn","Terza","Terzas","Tesler","Tess","Tessa","Tessi","Tessie","Tessler","Tessy","Teteak","Teufert","Teuton","Tevis","Tewell","Tewfik","Tews","Thacher","Thacker","Thackeray","Thad","Thaddaus","Thaddeus","Thaddus","Thadeus","Thagard","Thain","Thaine","Thais","Thalassa","Thalia","Tham","Thamora","Thamos","Thanasi","Thane","Thanh","Thanos","Thant","Thapa","Thar","Tharp","Thatch","Thatcher","Thaxter","Thay","Thayer","Thayne","The","Thea","Theadora","Theall","Thebault","Thecla","Theda","Thedric","Thedrick","Theis","Thekla","Thelma","Thema","Themis","Thenna","Theo","Theobald","Theodor","Theodora","Theodore","Theodoric","Theodosia","Theola","Theona","Theone","Thera","Theran","Theresa","Therese","Theresina","Theresita","Theressa","Therine","Theron","Therron","Thesda","Thessa","Theta","Thetes","Thetis","Thetisa","Thetos","Theurer","Theurich","Thevenot","Thia","Thibaud","Thibault","Thibaut","Thielen","Thier","Thierry","Thilda","Thilde","Thill","Thin","Thinia","Thirion","Thirza","Thirzi","Thirzia","Thisbe","Thisbee","Thissa","Thistle","Thoer","Thom","Thoma","Thomajan","Thomas","Thomasa","Thomasin","Thomasina","Thomasine","Thomey","Thompson","Thomsen","Thomson","Thor","Thora","Thorbert","Thordia","Thordis","Thorfinn","Thorin","Thorlay","Thorley","Thorlie","Thorma","Thorman","Thormora","Thorn","Thornburg","Thorncombe","Thorndike","Thorne","Thorner","Thornie","Thornton","Thorny","Thorpe","Thorr","Thorrlow","Thorstein","Thorsten","Thorvald","Thorwald","Thrasher","Three","Threlkeld","Thrift","Thun","Thunell","Thurber","Thurlough","Thurlow","Thurman","Thurmann","Thurmond","Thurnau","Thursby","Thurstan","Thurston","Thury","Thynne","Tia","Tiana","Tibbetts","Tibbitts","Tibbs","Tibold","Tica","Tice","Tichon","Tichonn","Ticknor","Ticon","Tidwell","Tiebold","Tiebout","Tiedeman","Tiemroth","Tien","Tiena","Tierell","Tiernan","Tierney","Tiersten","Tiertza","Tierza","Tifanie","Tiff","Tiffa","Tiffani","Tiffanie","Tiffanle","Tiffany","Tiffi","Tiffie","Tiffy","Tiga","Tigges","Tila","Tilda","Tilden","Tildi","Tildie","Tildy","Tiler","Tilford","Till","Tilla","Tillford","Tillfourd","Tillie","Tillinger","Tillio","Tillion","Tillman","Tillo","Tilly","Tilney","Tiloine","Tim","Tima","Timi","Timmi","Timmie","Timmons","Timms","Timmy","Timofei","Timon","Timoteo","Timothea","Timothee","Timotheus","Timothy","Tina","Tinaret","Tindall","Tine","Tingey","Tingley","Tini","Tiny","Tinya","Tiossem","Tiphane","Tiphani","Tiphanie","Tiphany","Tippets","Tips","Tipton","Tirrell","Tirza","Tirzah","Tisbe","Tisbee","Tisdale","Tish","Tisha","Tisman","Tita","Titania","Tito","Titos","Titus","Tizes","Tjaden","Tjader","Tjon","Tletski","Toback","Tobe","Tobey","Tobi","Tobiah","Tobias","Tobie","Tobin","Tobit","Toby","Tobye","Tocci","Tod","Todd","Toddie","Toddy","Todhunter","Toffey","Toffic","Toft","Toh","Toiboid","Toinette","Tol","Toland","Tolkan","Toll","Tolland","Tolley","Tolliver","Tollman","Tollmann","Tolmach","Tolman","Tolmann","Tom","Toma","Tomas","Tomasina","Tomasine","Tomaso","Tomasz","Tombaugh","Tomchay","Tome","Tomi","Tomkiel","Tomkin","Tomkins","Tomlin","Tomlinson","Tommi","Tommie","Tommy","Tompkins","Toms","Toney","Tongue","Toni","Tonia","Tonie","Tonina","Tonjes","Tonkin","Tonl","Tonneson","Tonnie","Tonry","Tony","Tonya","Tonye","Toogood","Toole","Tooley","Toolis","Toomay","Toombs","Toomin","Toor","Tootsie","Topliffe","Topper","Topping","Tor","Torbart","Torbert","Tore","Torey","Torhert","Tori","Torie","Torin","Tormoria","Torosian","Torp","Torr","Torrance","Torras","Torray","Torre","Torrell","Torrence","Torres","Torrey","Torrie","Torrin","Torrlow","Torruella","Torry","Torto","Tortosa","Tory","Toscano","Tosch","Toshiko","Toth","Touber","Toulon","Tound","Tova","Tove","Towbin","Tower","Towers","Towill","Towland","Town","Towne","Towney","Townie","Townsend","Townshend","Towny","Towrey","Towroy","Toy","Trabue","Tracay","Trace","Tracee","Tracey","Traci","Tracie","Tracy","Trager","Trahern","Trahurn","Trainer","Trainor","Trakas","Trammel","Tran","Tranquada","Trant","Trask","Tratner","Trauner","Trautman","Travax","Traver","Travers","Travis","Travus","Traweek","Tray","Treacy","Treat","Trefler","Trefor","Treharne","Treiber","Trela","Trella","Trellas","Trelu","Tremain","Tremaine","Tremann","Tremayne","Trembly","Tremml","Trenna","Trent","Trenton","Tresa","Trescha","Trescott","Tressa","Tressia","Treulich","Trev","Treva","Trevah","Trevar","Trever","Trevethick","Trevor","Trevorr","Trey","Tri","Trici","Tricia","Trilbee","Trilbi","Trilbie","Trilby","Triley","Trill","Trillbee","Trillby","Trilley","Trilly","Trimble","Trimmer","Trin","Trina","Trinatte","Trinee","Trinetta","Trinette","Trini","Trinia","Trinidad","Trinity","Trinl","Triny","Trip","Triplett","Tripp","Tris","Trisa","Trish","Trisha","Trista","Tristam","Tristan","Tristas","Tristis","Tristram","Trix","Trixi","Trixie","Trixy","Trocki","Trojan","Trometer","Tronna","Troth","Trotta","Trotter","Trout","Trovillion","Trow","Troxell","Troy","Troyes","Trstram","Trubow","Truc","Truda","Trude","Trudey","Trudi","Trudie","Trudnak","Trudy","True","Trueblood","Truelove","Trueman","Truitt","Trula","Trumaine","Truman","Trumann","Truscott","Trust","Trutko","Tryck","Trygve","Tsai","Tsan","Tse","Tseng","Tshombe","Tsuda","Tsui","Tu","Tubb","Tuchman","Tuck","Tucker","Tuckie","Tucky","Tuddor","Tudela","Tudor","Tuesday","Tufts","Tugman","Tuinenga","Tull","Tulley","Tullius","Tullus","Tullusus","Tully","Tumer","Tuneberg","Tung","Tunnell","Tupler","Tuppeny","Turino","Turk","Turley","Turmel","Turnbull","Turne","Turner","Turnheim","Turoff","Turpin","Turrell","Turro","Turtle","Tut","Tutankhamen","Tutt","Tuttle","Tutto","Twedy","Twelve","Twila","Twitt","Twum","Twyla","Ty","Tybald","Tybalt","Tybi","Tybie","Tychon","Tychonn","Tye","Tyika","Tyler","Tymes","Tymon","Tymothy","Tynan","Tyne","Tyra","Tyre","Tyree","Tyrone","Tyrrell","Tyrus","Tyson","Tzong","Ubald","Uball","Ubana","Ube","Uchida","Uchish","Uda","Udale","Udall","Udela","Udele","Udell","Udella","Udelle","Uel","Uela","Uella","Ugo","Uird","Uis","Uke","Ul","Ula","Ulah","Ulane","Ulani","Ulberto","Ulda","Ule","Ulick","Ulises","Ulita","Ulla","Ulland","Ullman","Ullund","Ullyot","Ulphi","Ulphia","Ulphiah","Ulric","Ulrica","Ulrich","Ulrick","Ulrika","Ulrikaumeko","Ulrike","Ultan","Ultann","Ultima","Ultun","Ulu","Ulund","Ulysses","Umberto","Ume","Umeh","Umeko","Ummersen","Umont","Un","Una","Unders","Underwood","Undine","Undis","Undry","Une","Ungley","Uni","Unity","Unni","Uno","Upali","Uphemia","Upshaw","Upton","Urana","Urania","Uranie","Urata","Urba","Urbai","Urbain","Urban","Urbana","Urbani","Urbanna","Urbannai","Urbannal","Urbano","Urbanus","Urbas","Uri","Uria","Uriah","Urial","Urian","Urias","Uriel","Urien","Uriia","Uriiah","Uriisa","Urina","Urion","Urissa","Urita","Urquhart","Ursa","Ursal","Ursala","Ursas","Ursel","Ursi","Ursola","Urson","Ursula","Ursulette","Ursulina","Ursuline","Ury","Usanis","Ushijima","Uta","Utas","Ute","Utham","Uthrop","Utica","Uticas","Utimer","Utley","Utta","Uttasta","Utter","Uttica","Uuge","Uund","Uwton","Uyekawa","Uzia","Uzial","Uziel","Uzzi","Uzzia","Uzzial","Uzziel","Va","Vaas","Vaasta","Vachel","Vachell","Vachil","Vachill","Vacla","Vaclav","Vaclava","Vacuva","Vada","Vaden","Vadim","Vadnee","Vaenfila","Vahe","Vaientina","Vail","Vaios","Vaish","Val","Vala","Valaree","Valaria","Valda","Valdas","Valdemar","Valdes","Valdis","Vale","Valeda","Valenba","Valencia","Valene","Valenka","Valenta","Valente","Valentia","Valentijn","Valentin","Valentina","Valentine","Valentino","Valenza","Valer","Valera","Valeria","Valerian","Valerie","Valerio","Valerlan","Valerle","Valery","Valerye","Valeta","Valiant","Valida","Valina","Valle","Valleau","Vallery","Valley","Valli","Vallie","Vallo","Vallonia","Vally","Valma","Valonia","Valoniah","Valora","Valorie","Valry","Valtin","Van","VanHook","Vance","Vanda","Vanden","Vander","Vanderhoek","Vandervelde","Vandyke","Vanessa","Vange","Vanhomrigh","Vani","Vania","Vanna","Vanni","Vannie","Vanny","Vano","Vanthe","Vanya","Vanzant","Varden","Vardon","Vareck","Vargas","Varhol","Varian","Varick","Varien","Varini","Varion","Varipapa","Varney","Varrian","Vary","Vas","Vashtee","Vashti","Vashtia","Vasileior","Vasilek","Vasili","Vasiliki","Vasilis","Vasiliu","Vasily","Vasos","Vasquez","Vassar","Vassaux","Vassell","Vassili","Vassily","Vasta","Vastah","Vastha","Vasti","Vasya","Vasyuta","Vaughan","Vaughn","Vaules","Veal","Veator","Veats","Veda","Vedetta","Vedette","Vedi","Vedis","Veedis","Velasco","Velda","Veleda","Velick","Veljkov","Velleman","Velma","Velvet","Vena","Venable","Venator","Venditti","Veneaux","Venetia","Venetis","Venezia","Venice","Venita","Venn","Veno","Venola","Venterea","Vento","Ventre","Ventura","Venu","Venus","Venuti","Ver","Vera","Verada","Veradi","Veradia","Veradis","Verbenia","Verda","Verdha","Verdi","Verdie","Vere","Verena","Verene","Verge","Verger","Vergil","Vergne","Vergos","Veriee","Verile","Verina","Verine","Verity","Verla","Verlee","Verlie","Vern","Verna","Verne","Vernen","Verner","Verneuil","Verney","Vernice","Vernier","Vernita","Vernon","Vernor","Veron","Veronica","Veronika","Veronike","Veronique","Verras","Vershen","Vescuso","Vesta","Veta","Vetter","Vevay","Vevina","Vevine","Vey","Vezza","Vharat","Vi","Viafore","Vial","Vic","Viccora","Vick","Vickey","Vicki","Vickie","Vicky","Victoir","Victor","Victoria","Victorie","Victorine","Victory","Vida","Vidal","Vidda","Viddah","Vidovic","Vidovik","Viehmann","Viens","Vierno","Vieva","Vig","Vigen","Viglione","Vigor","Viguerie","Viki","Viking","Vikki","Vikky","Vilberg","Vilhelmina","Villada","Villiers","Vilma","Vin","Vina","Vinaya","Vince","Vincelette","Vincent","Vincenta","Vincentia","Vincents","Vincenty","Vincenz","Vine","Vinia","Vinita","Vinn","Vinna","Vinni","Vinnie","Vinny","Vins","Vinson","Viola","Violante","Viole","Violet","Violeta","Violetta","Violette","Vipul","Viquelia","Viradis","Virendra","Virg","Virge","Virgel","Virgie","Virgil","Virgilia","Virgilio","Virgin","Virgina","Virginia","Virginie","Virgy","Viridi","Viridis","Viridissa","Virnelli","Viscardi","Vish","Vita","Vitale","Vitalis","Vite","Vitek","Vitia","Vitkun","Vito","Vitoria","Vittoria","Vittorio","Vitus","Viv","Viva","Viveca","Vivi","Vivia","Vivian","Viviana","Viviane","Vivianna","Vivianne","Vivica","Vivie","Vivien","Viviene","Vivienne","Viviyan","Vivl","Vivle","Vivyan","Vivyanne","Vizza","Vizzone","Vlad","Vlada","Vladamar","Vladamir","Vladi","Vladimar","Vladimir","Voccola","Voe","Vogel","Vogele","Vogeley","Vola","Volding","Voleta","Voletta","Volin","Volkan","Volnak","Volnay","Volney","Volny","Volotta","Volpe","Voltmer","Voltz","Von","Vona","Vonni","Vonnie","Vonny","Vookles","Voorhis","Vorfeld","Vories","Vorster","Voss","Votaw","Vowel","Vrablik","Vtarj","Vtehsta","Vudimir","Vullo","Vyky","Vyner","Vyse","Waal","Wachtel","Wachter","Wack","Waddell","Waddington","Waddle","Wade","Wadell","Wadesworth","Wadleigh","Wadlinger","Wadsworth","Waechter","Waers","Wager","Wagner","Wagoner","Wagshul","Wagstaff","Wahkuna","Wahl","Wahlstrom","Wailoo","Wain","Waine","Wainwright","Wait","Waite","Waiter","Wake","Wakeen","Wakefield","Wakerly","Waki","Walburga","Walcoff","Walcott","Walczak","Wald","Waldack","Waldemar","Walden","Waldman","Waldner","Waldo","Waldon","Waldos","Waldron","Wales","Walford","Waligore","Walke","Walker","Walkling","Wall","Wallace","Wallach","Wallache","Wallack","Wallas","Waller","Walley","Wallford","Walli","Wallie","Walling","Wallinga","Wallis","Walliw","Wallraff","Walls","Wally","Walrath","Walsh","Walston","Walt","Walter","Walters","Walther","Waltner","Walton","Walworth","Waly","Wampler","Wamsley","Wan","Wanda","Wandie","Wandis","Wandy","Wane","Waneta","Wanfried","Wang","Wanids","Wanonah","Wanyen","Wappes","Warchaw","Ward","Warde","Warden","Warder","Wardieu","Wardlaw","Wardle","Ware","Wareing","Warenne","Warfeld","Warfield","Warfold","Warford","Warfore","Warfourd","Warga","Warila","Waring","Warms","Warner","Warp","Warram","Warren","Warrenne","Warrick","Warrin","Warring","Warthman","Warton","Wartow","Warwick","Wash","Washburn","Washington","Washko","Wasserman","Wasson","Wassyngton","Wat","Watanabe","Waterer","Waterman","Waters","Watkin","Watkins","Watson","Watt","Wattenberg","Watters","Watts","Waugh","Wauters","Wavell","Waverley","Waverly","Wawro","Waxler","Waxman","Way","Waylan","Wayland","Waylen","Waylin","Waylon","Waynant","Wayne","Wayolle","Weaks","Wearing","Weasner","Weatherby","Weatherley","Weathers","Weaver","Web","Webb","Webber","Weber","Webster","Wedurn","Weed","Weeks","Wehner","Wehrle","Wei","Weibel","Weidar","Weide","Weider","Weidman","Weidner","Weig","Weight","Weigle","Weihs","Weikert","Weil","Weiler","Weiman","Wein","Weinberg","Weiner","Weinert","Weingarten","Weingartner","Weinhardt","Weinman","Weinreb","Weinrich","Weinshienk","Weinstein","Weinstock","Weintrob","Weir","Weirick","Weisbart","Weisberg","Weisbrodt","Weisburgh","Weiser","Weisler","Weisman","Weismann","Weiss","Weissberg","Weissman","Weissmann","Weitman","Weitzman","Weixel","Weksler","Welbie","Welby","Welch","Welcher","Welcome","Welcy","Weld","Weldon","Welford","Welker","Welles","Wellesley","Wellington","Wells","Welsh","Welton","Wenda","Wendall","Wendalyn","Wende","Wendel","Wendelin","Wendelina","Wendeline","Wendell","Wendi","Wendie","Wendin","Wendolyn","Wendt","Wendy","Wendye","Wenger","Wengert","Wenn","Wennerholn","Wenoa","Wenona","Wenonah","Wentworth","Wenz","Wera","Werbel","Werby","Werner","Wernher","Wernick","Wernsman","Werra","Wershba","Wertheimer","Wertz","Wes","Wesa","Wescott","Wesla","Wesle","Weslee","Wesley","Wessling","West","Westberg","Westbrook","Westbrooke","Wester","Westerfield","Westfahl","Westfall","Westhead","Westland","Westleigh","Westley","Westlund","Westmoreland","Westney","Weston","Westphal","Wetzel","Wetzell","Wexler","Wey","Weyermann","Weylin","Weywadt","Whale","Whalen","Whall","Whallon","Whang","Wharton","Whatley","Wheaton","Wheeler","Wheelwright","Whelan","Whetstone","Whiffen","Whiney","Whipple","Whit","Whitaker","Whitby","Whitcher","Whitcomb","White","Whitebook","Whitehouse","Whitehurst","Whitelaw","Whiteley","Whitford","Whiting","Whitman","Whitnell","Whitney","Whitson","Whittaker","Whittemore","Whitten","Whitver","Whorton","Whyte","Wiatt","Wiburg","Wichern","Wichman","Wickham","Wickman","Wickner","Wicks","Widera","Wie","Wiebmer","Wieche","Wiedmann","Wiencke","Wiener","Wier","Wieren","Wiersma","Wiese","Wiggins","Wight","Wightman","Wil","Wilber","Wilbert","Wilbur","Wilburn","Wilburt","Wilcox","Wilda","Wilde","Wildee","Wilden","Wilder","Wildermuth","Wildon","Wileen","Wilek","Wilen","Wiles","Wiley","Wilfred","Wilfreda","Wilfrid","Wilhelm","Wilhelmina","Wilhelmine","Wilhide","Wilie","Wilinski","Wilkens","Wilkey","Wilkie","Wilkins","Wilkinson","Wilkison","Will","Willa","Willabella","Willamina","Willard","Willcox","Willdon","Willem","Willet","Willett","Willetta","Willette","Willey","Willi","William","Williams","Williamsen","Williamson","Willie","Willin","Willing","Willis","Willman","Willmert","Willms","Willner","Willock","Willow","Wills","Willtrude","Willumsen","Willy","Willyt","Wilma","Wilmar","Wilmer","Wilmette","Wilmott","Wilona","Wilonah","Wilone","Wilow","Wilscam","Wilser","Wilsey","Wilson","Wilt","Wilterdink","Wilton","Wiltsey","Wiltshire","Wiltz","Wimsatt","Win","Wina","Wincer","Winchell","Winchester","Wind","Windham","Windsor","Windy","Windzer","Winebaum","Winer","Winfield","Winfred","Winfrid","Wing","Wini","Winifield","Winifred","Winikka","Winn","Winna","Winnah","Winne","Winni","Winnick","Winnie","Winnifred","Winny","Winograd","Winola","Winona","Winonah","Winou","Winser","Winshell","Winslow","Winson","Winsor","Winston","Winstonn","Winter","Winterbottom","Winters","Winther","Winthorpe","Winthrop","Winton","Winwaloe","Winzler","Wira","Wirth","Wise","Wiseman","Wiskind","Wisnicki","Wistrup","Wit","Witcher","Witha","Witherspoon","Witkin","Witt","Witte","Wittenburg","Wittie","Witty","Wivestad","Wivina","Wivinah","Wivinia","Wixted","Woehick","Woermer","Wohlen","Wohlert","Wojak","Wojcik","Wolbrom","Wolcott","Wolenik","Wolf","Wolfe","Wolff","Wolfgang","Wolfgram","Wolfie","Wolford","Wolfort","Wolfram","Wolfson","Wolfy","Wolgast","Wolk","Woll","Wollis","Wolpert","Wolsky","Womack","Won","Wonacott","Wong","Woo","Wood","Woodall","Woodberry","Woodcock","Woodford","Woodhead","Woodhouse","Woodie","Woodley","Woodman","Woodring","Woodrow","Woodruff","Woods","Woodson","Woodsum","Woodward","Woody","Woolcott","Wooldridge","Woolley","Woolson","Wooster","Wootan","Woothen","Wootten","Worden","Worl","Worlock","Worrell","Worsham","Worth","Worthington","Worthy","Wrand","Wren","Wrench","Wrennie","Wright","Wrightson","Wrigley","Wsan","Wu","Wulf","Wulfe","Wun","Wunder","Wurst","Wurster","Wurtz","Wyatan","Wyatt","Wyck","Wycoff","Wye","Wylde","Wylen","Wyler","Wylie","Wylma","Wyly","Wymore","Wyn","Wyndham","Wyne","Wynn","Wynne","Wynnie","Wynny","Wyon","Wystand","Xantha","Xanthe","Xanthus","Xavier","Xaviera","Xavler","Xena","Xenia","Xeno","Xenophon","Xenos","Xerxes","Xever","Ximena","Ximenes","Ximenez","Xylia","Xylina","Xylon","Xymenes","Yaakov","Yablon","Yacano","Yacov","Yaeger","Yael","Yager","Yahiya","Yaker","Yale","Yalonda","Yam","Yamauchi","Yanaton","Yance","Yancey","Yancy","Yand","Yank","Yankee","Yann","Yarak","Yard","Yardley","Yaron","Yarvis","Yasmeen","Yasmin","Yasmine","Yasu","Yasui","Yate","Yates","Yatzeck","Yaya","Yazbak","Yeargain","Yearwood","Yeaton","Yecies","Yee","Yeh","Yehudi","Yehudit","Yelena","Yelich","Yelmene","Yemane","Yeo","Yeorgi","Yerga","Yerkovich","Yerxa","Yesima","Yeta","Yetac","Yetah","Yetta","Yetti","Yettie","Yetty","Yeung","Yevette","Yi","Yila","Yim","Yirinec","Ylla","Ynes","Ynez","Yoho","Yoko","Yokoyama","Yokum","Yolanda","Yolande","Yolane","Yolanthe","Yona","Yonah","Yonatan","Yong","Yonina","Yonit","Yonita","Yoo","Yoong","Yordan","Yorgen","Yorgo","Yorgos","Yorick","York","Yorke","Yorker","Yoshi","Yoshiko","Yoshio","Youlton","Young","Younger","Younglove","Youngman","Youngran","Yousuf","Yovonnda","Ysabel","Yseult","Yseulta","Yseulte","Yuhas","Yuille","Yuji","Yuk","Yukio","Yul","Yule","Yulma","Yuma","Yumuk","Yun","Yunfei","Yung","Yunick","Yup","Yuri","Yuria","Yurik","Yursa","Yurt","Yusem","Yusuk","Yuu","Yuzik","Yves","Yvette","Yvon","Yvonne","Yvonner","Yvor","Zabrina","Zabrine","Zacarias","Zaccaria","Zacek","Zach","Zachar","Zacharia","Zachariah","Zacharias","Zacharie","Zachary","Zacherie","Zachery","Zack","Zackariah","Zacks","Zadack","Zadoc","Zahara","Zahavi","Zaid","Zailer","Zak","Zakaria","Zakarias","Zalea","Zales","Zaller","Zalucki","Zamir","Zamora","Zampardi","Zampino","Zandra","Zandt","Zane","Zaneski","Zaneta","Zannini","Zantos","Zanze","Zara","Zaragoza","Zarah","Zared","Zaremski","Zarger","Zaria","Zarla","Zashin","Zaslow","Zasuwa","Zavala","Zavras","Zawde","Zea","Zealand","Zeb","Zeba","Zebada","Zebadiah","Zebapda","Zebe","Zebedee","Zebulen","Zebulon","Zechariah","Zeculon","Zed","Zedekiah","Zeeba","Zeena","Zehe","Zeidman","Zeiger","Zeiler","Zeitler","Zeke","Zel","Zela","Zelazny","Zelda","Zelde","Zelig","Zelikow","Zelle","Zellner","Zelma","Zelten","Zena","Zenas","Zenda","Zendah","Zenger","Zenia","Zennas","Zennie","Zenobia","Zeph","Zephan","Zephaniah","Zeralda","Zerelda","Zerk","Zerla","Zerlina","Zerline","Zeta","Zetana","Zetes","Zetta","Zeus","Zhang","Zia","Ziagos","Zicarelli","Ziegler","Zielsdorf","Zigmund","Zigrang","Ziguard","Zilber","Zildjian","Zilla","Zillah","Zilvia","Zima","Zimmer","Zimmerman","Zimmermann","Zina","Zinah","Zinck","Zindman","Zingale","Zingg","Zink","Zinn","Zinnes","Zins","Zipah","Zipnick","Zippel","Zippora","Zipporah","Zirkle","Zischke","Zita","Zitah","Zitella","Zitvaa","Ziwot","Zoa","Zoara","Zoarah","Zoba","Zobe","Zobias","Zobkiw","Zoe","Zoeller","Zoellick","Zoes","Zoha","Zohar","Zohara","Zoi","Zoie","Zoila","Zoilla","Zola","Zoldi","Zoller","Zollie","Zolly","Zolnay","Zolner","Zoltai","Zonda","Zondra","Zonnya","Zora","Zorah","Zorana","Zorina","Zorine","Zosema","Zosi","Zosima","Zoubek","Zrike","Zsa","Zsa Zsa","Zsazsa","Zsolway","Zubkoff","Zucker","Zuckerman","Zug","Zulch","Zuleika","Zulema","Zullo","Zumstein","Zumwalt","Zurek","Zurheide","Zurkow","Zurn","Zusman","Zuzana","Zwart","Zweig","Zwick","Zwiebel","Zysk"];
\ No newline at end of file
@@ -1,7 +1,12 @@
 pub mod country_code;
+pub mod domain;
 pub mod first_name;
+pub mod host_prefix;
 pub mod last_name;
 pub mod middle_name;
 pub mod place;
 pub mod street_type;
-pub mod street;
\ No newline at end of file
+pub mod street;
+pub mod url_path;
+pub mod user_agent;
+pub mod word;
@@ -1 +1 @@
-pub const PLACE: [&'static str; 10196] = ["Aaronsburg","Abbeville","Abbotsford","Abbottstown","Abbyville","Abell","Abercrombie","Abernant","Abilene","Abingdon","Abington","Abiquiu","Abrams","Absaraka","Absarokee","Absecon","Acampo","Accokeek","Accomac","Accoville","Achille","Ackerly","Ackermanville","Ackworth","Acosta","Acra","Acushnet","Acworth","Adah","Adairsville","Adairville","Adamsbasin","Adamsburg","Adamstown","Adamsville","Addieville","Addington","Addy","Addyston","Adel","Adelanto","Adell","Adelphi","Adelphia","Adena","Adger","Adin","Adjuntas","Adna","Adona","Aflex","Afton","Agana","Agar","Agawam","Agness","Agra","Aguada","Aguadilla","Aguadulce","Aguanga","Aguila","Aguilar","Aguirre","Ahgwahching","Ahmeek","Ahoskie","Ahsahka","Ahwahnee","Aibonito","Aiea","Ailey","Aimwell","Ainsworth","Airville","Aitkin","Ajo","Akaska","Akeley","Akiachak","Akiak","Akutan","Alachua","Aladdin","Alakanuk","Alamance","Alamogordo","Alamosa","Alamota","Alanreed","Alanson","Alapaha","Albee","Albemarle","Albers","Albertlea","Alberton","Albertson","Albertville","Albia","Albin","Albion","Alborn","Alburg","Alburnett","Alburtis","Alcalde","Alcester","Alco","Alcolu","Alcova","Alda","Aldenville","Alderson","Aldie","Aledo","Aleknagik","Aleppo","Alford","Algoma","Algona","Algonac","Aliceville","Alief","Aline","Aliquippa","Alix","Alkabo","Alkol","Allamuchy","Allardt","Alledonia","Alleene","Allegan","Allegany","Alleghany","Allegre","Alleman","Allendale","Allendorf","Allenhurst","Allenport","Allensville","Allenton","Allenwood","Allerton","Allgood","Allock","Allons","Allouez","Alloway","Allport","Allred","Almena","Almira","Almo","Almont","Almyra","Alna","Alpaugh","Alpena","Alpharetta","Alpoca","Alsea","Alsen","Alsey","Alstead","Alston","Alta","Altadena","Altaloma","Altamahaw","Altamont","Altenburg","Altha","Altheimer","Altmar","Altona","Altoona","Altro","Altura","Alturas","Altus","Alumbank","Alvada","Alvadore","Alvarado","Alvaton","Alverda","Alverton","Alviso","Alvo","Alvord","Alvordton","Alvy","Alzada","Amado","Amagansett","Amagon","Amalia","Amana","Amanda","Amasa","Amawalk","Amazonia","Amberg","Amberson","Ambia","Ambler","Amboy","Amburgey","Ameagle","Amenia","Americus","Amery","Amesbury","Amesville","Amherstdale","Amidon","Amiret","Amissville","Amistad","Amite","Amityville","Amlin","Amma","Amo","Amonate","Amoret","Amorita","Amory","Amsden","Amston","Anacoco","Anacortes","Anadarko","Anahola","Anahuac","Analomink","Anamoose","Anamosa","Anasco","Anatone","Anawalt","Anchorville","Anco","Ancona","Ancram","Ancramdale","Andale","Andalusia","Andersonville","Andreas","Aneta","Aneth","Angier","Angleinlet","Angleton","Anguilla","Angwin","Aniak","Animas","Aniwa","Ankeny","Anmoore","Annada","Annamaria","Annandale","Annarbor","Annawan","Annemanie","Anniston","Annona","Annville","Anoka","Ansley","Anson","Ansonia","Ansonville","Ansted","Antesfort","Anthon","Antigo","Antlers","Antonchico","Antonito","Antrim","Anvik","Anza","Apalachicola","Apalachin","Apison","Aplington","Apopka","Appling","Appomattox","Aptos","Aquasco","Aquashicola","Aquebogue","Aquilla","Aquone","Arabi","Aragon","Arapaho","Arapahoe","Ararat","Arbela","Arboles","Arbon","Arbovale","Arbuckle","Arbyrd","Arcanum","Arcata","Archbald","Archbold","Archcape","Archie","Arco","Arcola","Ardara","Ardenvoir","Ardmore","Ardoch","Ardsley","Arecibo","Aredale","Arendtsville","Arenzville","Argenta","Argillite","Argo","Argonia","Argos","Argusville","Argyle","Ariel","Arimo","Arion","Aripeka","Arispe","Aristes","Ariton","Arivaca","Arjay","Arkabutla","Arkadelphia","Arkansaw","Arkdale","Arkoma","Arkport","Arkville","Arlee","Arley","Arma","Armagh","Armbrust","Armington","Armona","Armorel","Armuchee","Arnaudville","Arnegard","Arnett","Arnoldsburg","Arnoldsville","Arnot","Arock","Aroda","Aromas","Arona","Arp","Arpin","Arrey","Arriba","Arrington","Arrowsmith","Artas","Artemas","Artemus","Artesia","Artesian","Arthurdale","Artois","Arvada","Arvilla","Arvin","Arvonia","Ary","Asbury","Asco","Ascutney","Ashaway","Ashburn","Ashburnham","Ashby","Ashdown","Asheboro","Asherton","Ashfield","Ashford","Ashippun","Ashkum","Ashmore","Ashtabula","Ashton","Ashuelot","Ashville","Ashwood","Askov","Asotin","Aspermont","Aspers","Assaria","Assawoman","Assonet","Astatula","Atalissa","Atascadero","Atascosa","Atco","Atglen","Athelstane","Athol","Atlasburg","Atmore","Atoka","Attalla","Attapulgus","Attleboro","Auberry","Auburndale","Auburntown","Augres","Aulander","Ault","Aultman","Aumsville","Aurelia","Austell","Austerlitz","Austinburg","Austinville","Austwell","Autaugaville","Autrain","Autryville","Auxier","Auxvasse","Ava","Avalon","Avant","Avawam","Avella","Avenal","Avenel","Avera","Avilla","Avinger","Aviston","Avoca","Avondale","Avonmore","Awendaw","Axson","Axtel","Axtell","Axton","Ayden","Aydlett","Ayer","Aylett","Aynor","Ayr","Ayrshire","Azalia","Azle","Azusa","Babb","Bache","Backus","Bacliff","Baconton","Bacova","Badaxe","Badger","Badin","Bagdad","Baggs","Bagwell","Baileyton","Baileyville","Bains","Bainville","Bairdford","Bairoil","Baisden","Bajadero","Baker","Bakerstown","Bakersville","Bakerton","Bakewell","Bala","Balaton","Balch","Baldwinsville","Baldwinville","Baldwyn","Balko","Ballantine","Ballengee","Ballentine","Ballico","Ballinger","Ballouville","Ballwin","Bally","Balmat","Balmorhea","Balta","Bamberg","Banco","Bancroft","Bandana","Bandera","Bandon","Bandytown","Bangall","Bangs","Bankston","Banner","Banning","Bannister","Bannock","Banquete","Bantry","Baptistown","Baraboo","Baraga","Barataria","Barbeau","Barberton","Barberville","Barboursville","Barbourville","Barceloneta","Barco","Bardolph","Bardstown","Bardwell","Bargersville","Barhamsville","Baring","Barker","Barksdale","Barling","Barnardsville","Barnegat","Barnesboro","Barneston","Barnesville","Barneveld","Barnhart","Barnsdall","Barnstable","Barnstead","Barnum","Barnwell","Baroda","Barrackville","Barranquitas","Barree","Barron","Barronett","Barryton","Barrytown","Barryville","Bartelso","Bartlesville","Bartley","Barto","Bartonsville","Bartow","Barwick","Basco","Bascom","Basehor","Basile","Baskerville","Baskett","Baskin","Basom","Bassfield","Bastian","Bastrop","Basye","Batchelor","Batchtown","Batesburg","Batesland","Batesville","Batson","Battiest","Battleboro","Battletown","Baudette","Bausman","Bavon","Baxley","Bayamon","Bayard","Bayboro","Bayfield","Baylis","Bayminette","Bayougoula","Baypines","Bays","Bayshore","Bayside","Baytown","Bayview","Bayville","Bazine","Beachwood","Beaconsfield","Bealeton","Beallsville","Beals","Beaman","Bearden","Beardstown","Bearsville","Beasley","Beason","Beattie","Beatty","Beattyville","Beaufort","Beaverdale","Beaverlett","Beaverton","Beavertown","Beaverville","Bebe","Beccaria","Bechtelsville","Beckemeyer","Beckley","Beckville","Beckwith","Bedias","Bedminster","Beechbottom","Beecher","Beechmont","Beedeville","Beehouse","Beeler","Beemer","Beeson","Beetown","Beeville","Beggs","Beirne","Bejou","Belair","Belalton","Belcher","Belchertown","Belcourt","Belden","Beldenville","Belding","Belen","Belfair","Belfield","Belford","Belington","Belk","Belknap","Bellaire","Bellarthur","Bellbrook","Bellbuckle","Bellechasse","Bellefonte","Bellemead","Bellemina","Belleplaine","Bellerive","Bellerose","Bellevernon","Belleview","Belleville","Bellevue","Bellmont","Bellmore","Bellona","Bellport","Bells","Bellvale","Bellville","Bellvue","Bellwood","Belmar","Belmond","Belpre","Belsano","Belton","Beltrami","Belva","Belvedere","Belview","Belvue","Belzoni","Bement","Bemidji","Bena","Benarnold","Benavides","Bendena","Bendersville","Benedicta","Benezett","Benge","Benham","Benhur","Benicia","Benkelman","Benld","Benlomond","Bennet","Bennettsville","Benoit","Bensalem","Bensenville","Bentleyville","Bentonia","Bentonville","Bentree","Benwood","Benzonia","Beowawe","Berclair","Bergenfield","Berger","Bergholz","Bergoo","Bergton","Berkey","Berkley","Bernalillo","Bernardston","Bernardsville","Bernville","Beroun","Berrysburg","Berryton","Berryville","Berthold","Berthoud","Berwind","Berwyn","Bethalto","Bethania","Bethanna","Bethany","Bethera","Bethpage","Bethune","Bettendorf","Betterton","Bettsville","Beulah","Beulaville","Bevier","Bevington","Bevinsville","Bexar","Beyer","Bickleton","Bickmore","Bicknell","Biddeford","Biddle","Bidwell","Bieber","Bienville","Billerica","Billingsley","Biloxi","Bim","Bimble","Binford","Bingen","Binger","Biola","Bippus","Birchdale","Birchleaf","Birchrunville","Birchtree","Birchwood","Birdeye","Birdinhand","Birds","Birdsboro","Birdseye","Birnamwood","Birney","Birome","Bisbee","Biscoe","Bishopville","Bitely","Bittinger","Bivins","Biwabik","Bixby","Blachly","Blackduck","Blackey","Blackfoot","Blackford","Blackhawk","Blacklick","Blacksburg","Blackshear","Blackstock","Blacksville","Blackville","Blackwater","Blackwood","Bladen","Bladenboro","Bladensburg","Blain","Blairs","Blairsburg","Blairsden","Blairstown","Blairsville","Blaisdell","Blakely","Blakesburg","Blakeslee","Blanca","Blanchester","Blanco","Blandburg","Blandford","Blandinsville","Blandon","Blandville","Blanford","Blanks","Blauvelt","Blawenburg","Bledsoe","Bleiblerville","Blencoe","Blenker","Blessing","Blevins","Blissfield","Blocker","Blocksburg","Blockton","Blodgett","Blomkest","Bloomburg","Bloomdale","Bloomer","Bloomery","Bloomingburg","Bloomingdale","Bloomingrose","Bloomsburg","Bloomsbury","Bloomsdale","Bloomville","Blossburg","Blossvale","Blount","Blountstown","Blountsville","Blountville","Bloxom","Blueball","Bluebell","Blueeye","Bluefield","Bluehole","Bluejay","Bluemont","Bluewater","Bluffdale","Bluffs","Bluffton","Bluford","Bly","Blythedale","Blytheville","Blythewood","Boalsburg","Boardman","Boaz","Bobtown","Bobwhite","Bodega","Bodfish","Boelus","Boerne","Bogalusa","Bogard","Bogart","Bogata","Boggs","Boggstown","Bogue","Boguechitto","Bohannon","Boiceville","Boisdarc","Boissevain","Bokchito","Bokeelia","Bokoshe","Bolckow","Boles","Boley","Boligee","Bolinas","Boling","Bolingbroke","Bolinger","Bomont","Bomoseen","Bonair","Bonaire","Bonaqua","Boncarbo","Bondsville","Bonduel","Bondurant","Bondville","Bonesteel","Boneville","Bonfield","Bonham","Bonifay","Bonita","Bonlee","Bonneau","Bonner","Bonnerdale","Bonneterre","Bonnieville","Bonnyman","Bono","Bonsall","Bonsecour","Bonwier","Boody","Booker","Boomer","Booneville","Boonsboro","Boonton","Boonville","Boothville","Boqueron","Bordelonville","Bordentown","Bordulac","Borger","Boring","Borup","Boscobel","Bosler","Bosque","Bostic","Bostwick","Bosworth","Bothell","Botkins","Botsford","Bottineau","Bouckville","Boundbrook","Bountiful","Bourbonnais","Bourg","Bourneville","Bouse","Bouton","Boutte","Bovard","Bovey","Bovill","Bovina","Bowbells","Bowden","Bowdle","Bowdoinham","Bowdon","Bowers","Bowerston","Bowersville","Bowlegs","Bowler","Bowlus","Bowmansdale","Bowmanstown","Bowmansville","Boxelder","Boxford","Boxholm","Boyceville","Boyden","Boyds","Boydton","Boyers","Boyertown","Boyes","Boykin","Boykins","Boynton","Boystown","Bozeman","Bozman","Bozoo","Bozrah","Braceville","Bracey","Brackettville","Brackney","Braddock","Braddyville","Braden","Bradenton","Bradenville","Bradleyville","Bradner","Bradyville","Braggadocio","Braggs","Braham","Braidwood","Brainerd","Braintree","Braithwaite","Braman","Bramwell","Branchdale","Branchland","Branchport","Branchton","Branchville","Brandamore","Brandonville","Brandsville","Branford","Branson","Brantingham","Brantley","Brantwood","Braselton","Brashear","Brasstown","Brattleboro","Brawley","Braxton","Braymer","Brayton","Brazeau","Brazoria","Brea","Breaks","Breda","Breeden","Breeding","Breedsville","Breese","Breesport","Breezewood","Breinigsville","Bremerton","Bremond","Brenham","Brentford","Brenton","Brentwood","Bretz","Brevard","Brewer","Brewerton","Brewton","Brianhead","Bricelyn","Briceville","Brickeys","Bridgehampton","Bridgeland","Bridger","Bridgeton","Bridgeville","Bridgman","Bridgton","Bridport","Brielle","Brierfield","Briggsdale","Briggsville","Brightwood","Brill","Brillion","Brimfield","Brimhall","Brimley","Brimson","Bringhurst","Brinkley","Brinklow","Brinktown","Brinnon","Brinsmade","Brinson","Brisbin","Briscoe","Bristolville","Bristow","Britt","Britton","Brixey","Broadalbin","Broadbent","Broadbrook","Broaddus","Broadford","Broadlands","Broadrun","Broadus","Broadview","Broadwater","Broadwell","Brocket","Brockport","Brockton","Brockway","Brockwell","Brocton","Broderick","Brodhead","Brodnax","Brogan","Brogue","Brohard","Brohman","Brokaw","Brokenbow","Bronaugh","Bronson","Bronston","Bronte","Bronwood","Brookdale","Brookeland","Brooker","Brookesmith","Brookeville","Brookfield","Brookings","Brookland","Brooklet","Brooklin","Brookneal","Brookport","Brooks","Brookshire","Brookston","Brooksville","Brookton","Brooktondale","Brookview","Brookville","Brookwood","Broomall","Broomfield","Brooten","Broseley","Brothers","Broughton","Broussard","Browder","Browerville","Brownfield","Browning","Brownlee","Browns","Brownsboro","Brownsburg","Brownsdale","Brownstown","Brownsville","Brownton","Browntown","Brownville","Brownwood","Broxton","Bruceton","Brucetown","Bruceville","Bruin","Bruington","Brule","Brumley","Brundidge","Bruneau","Bruner","Bruni","Bruning","Brunson","Brunsville","Brusett","Brushton","Brusly","Brutus","Bryantown","Bryantsville","Bryantville","Bryceland","Bryceville","Brynathyn","Brynmawr","Bryson","Buchtel","Buckatunna","Buckeystown","Buckfield","Buckhannon","Buckhead","Buckholts","Buckingham","Buckland","Bucklin","Buckman","Buckner","Bucks","Bucksport","Bucoda","Bucyrus","Buda","Bude","Bueche","Buellton","Bueyeros","Buford","Buhl","Buhler","Bula","Bulan","Bulger","Bullard","Bullshoals","Bullville","Bulpitt","Buna","Bunceton","Buncombe","Bunker","Bunkerville","Bunkie","Bunn","Bunnell","Bunola","Buras","Burchard","Burdett","Burdette","Burdick","Burdine","Burfordville","Burgaw","Burgettstown","Burgin","Burgoon","Burkburnett","Burkesville","Burket","Burkett","Burkettsville","Burkeville","Burkhart","Burkittsville","Burkville","Burleson","Burlingame","Burlingham","Burlison","Burna","Burnet","Burnettsville","Burney","Burneyville","Burnips","Burns","Burnsville","Burntcorn","Burnwell","Burrows","Burrton","Burson","Burtonsville","Burtrum","Burwell","Busby","Bushkill","Bushland","Bushton","Bushwood","Buskirk","Bussey","Butlerville","Butner","Butters","Buttonwillow","Buttzville","Byars","Bybee","Byesville","Byfield","Byhalia","Bylas","Bynum","Bypro","Byrdstown","Byrnedale","Byromville","Caballo","Cabazon","Cabery","Cabins","Cabool","Caborojo","Caddo","Cade","Cades","Cadiz","Cadmus","Cadogan","Cadott","Cadwell","Cadyville","Caguas","Cahokia","Cahone","Cainsville","Cairnbrook","Calabasas","Cale","Caledonia","Calera","Calexico","Calhan","Caliente","Califon","Calio","Calion","Calipatria","Calistoga","Callands","Callao","Callaway","Callender","Callensburg","Callery","Callicoon","Calliham","Calmar","Calverton","Camak","Camanche","Camargo","Camarillo","Camas","Cambra","Cambria","Cambridgeport","Camby","Camden","Camdenton","Camillus","Cammal","Campbellsburg","Campbellton","Campbelltown","Campo","Campobello","Campti","Campton","Camptonville","Camptown","Campwood","Camuy","Cana","Canadensis","Canadys","Canajoharie","Canalou","Canandaigua","Canaseraga","Canastota","Canby","Candia","Candler","Cando","Candor","Caneadea","Canebrake","Caney","Caneyville","Canisteo","Canistota","Canjilon","Canmer","Cannelburg","Cannelton","Cannonsburg","Cannonville","Canones","Canonsburg","Canova","Canovanas","Cantil","Cantonment","Cantrall","Cantril","Canute","Canutillo","Canyonville","Capac","Capefair","Capels","Capemay","Capeneddick","Capeville","Capitan","Capitola","Capron","Capshaw","Captaincook","Captiva","Capulin","Caputa","Caratunk","Carbonado","Carboncliff","Cardale","Cardin","Cardington","Cardville","Cardwell","Carencro","Caretta","Careywood","Carland","Carlile","Carlinville","Carlock","Carlos","Carlotta","Carlsbad","Carlsborg","Carman","Carmel","Carmi","Carmichaels","Carnarvon","Carnesville","Caro","Caroleen","Carona","Carpinteria","Carpio","Carrabelle","Carrboro","Carrier","Carriere","Carrington","Carrizozo","Carrolls","Carrollton","Carrolltown","Carrothers","Carrsville","Carsonville","Carter","Carteret","Cartersburg","Cartersville","Carterville","Cartwright","Caruthers","Carver","Carversville","Carville","Cary","Caryville","Casa","Casar","Cascadia","Cascilla","Casco","Caseville","Caseyville","Cashiers","Cashion","Cashton","Cashtown","Casmalia","Casnovia","Cason","Casper","Cass","Cassadaga","Cassatt","Casscoe","Cassel","Casselberry","Casselton","Cassoday","Cassopolis","Casstown","Cassville","Castalia","Castana","Castanea","Castell","Castella","Castile","Castine","Castleberry","Castledale","Castleford","Castleton","Castlewood","Castorland","Castroville","Cataldo","Catano","Catarina","Catasauqua","Cataula","Cataumet","Catawissa","Catharine","Catharpin","Cathay","Cathlamet","Catlett","Catlettsburg","Catlin","Cato","Catoosa","Catron","Cattaraugus","Caulfield","Causey","Cauthornville","Cavetown","Cavour","Cawood","Cayey","Cayucos","Cayuse","Cayuta","Cazadero","Cazenovia","Cebolla","Cecilton","Cedarbrook","Cedarburg","Cedarcrest","Cedaredge","Cedarhurst","Cedarkey","Cedarlane","Cedars","Cedartown","Cedarvale","Cedarville","Ceevee","Ceiba","Celestine","Celina","Celoron","Cementon","Centrahoma","Centralia","Centre","Centrehall","Centreville","Centuria","Ceredo","Ceresco","Cerrillos","Cerritos","Cerro","Cerrogordo","Chacon","Chadbourn","Chaddsford","Chadron","Chadwicks","Chaffee","Chalfont","Challis","Chalmette","Chama","Chamberino","Chambersburg","Chambersville","Chamisal","Champlin","Chana","Chandlerville","Changewater","Chanhassen","Channahon","Channing","Chanute","Chapin","Chapmansboro","Chapmanville","Chappaqua","Chappell","Chappells","Chaptico","Chardon","Charenton","Chariton","Charlemont","Charleroi","Charlestown","Charlevoix","Charlo","Charlton","Charmco","Chartley","Chaseburg","Chaseley","Chaska","Chassell","Chataignier","Chatawa","Chateaugay","Chatfield","Chatom","Chatsworth","Chattahoochee","Chattaroy","Chaumont","Chauvin","Chavies","Chazy","Cheapside","Chebanse","Cheboygan","Checotah","Chefornak","Chehalis","Chelan","Chelmsford","Chelsea","Cheltenham","Chemult","Chemung","Cheneyville","Chenoa","Chepachet","Cheraw","Cheriton","Cherryfield","Cherrylog","Cherrytree","Cherryville","Chesaning","Chesnee","Chesterfield","Chesterland","Chestertown","Chesterville","Cheswick","Cheswold","Chetek","Chetopa","Chevak","Chewalla","Chewelah","Chewsville","Cheyney","Chichester","Chickamauga","Chickasha","Chico","Chicopee","Chicora","Chicota","Chidester","Chiefland","Chignik","Chilcoot","Childersburg","Childress","Childs","Childwold","Chilhowee","Chilhowie","Chillicothe","Chilmark","Chilo","Chiloquin","Chilton","Chimacum","Chimayo","Chincoteague","Chinle","Chino","Chipley","Chireno","Chitina","Chittenango","Chittenden","Chivington","Chloe","Choccolocco","Chocorua","Chocowinity","Chokio","Chokoloskee","Cholame","Choteau","Choudrant","Chouteau","Chowchilla","Chriesman","Chrisman","Chrisney","Christiansted","Christoval","Chromo","Chualar","Chuckey","Chugiak","Chugwater","Chula","Chunchula","Churchton","Churchville","Churdan","Churubusco","Ciales","Cibolo","Cidra","Cima","Cimarron","Cincinnatus","Cinda","Cinebar","Circleville","Cisco","Cisne","Citra","Citronelle","Clackamas","Claflin","Clairfield","Clairton","Clancy","Clanton","Clarcona","Claremore","Clarinda","Clarington","Clarion","Clarissa","Clarita","Clarkdale","Clarkedale","Clarkesville","Clarkfield","Clarkia","Clarks","Clarksboro","Clarksburg","Clarksdale","Clarkson","Clarkston","Clarksville","Clarkton","Claryville","Clatonia","Clatskanie","Claudville","Claunch","Claverack","Clawson","Claxton","Clayhole","Claymont","Claypool","Claysburg","Claysville","Claytonville","Clayville","Clearbrook","Clearfield","Clearmont","Clearview","Clearville","Cleaton","Cleburne","Cleelum","Cleghorn","Clementon","Clements","Clemmons","Clemons","Clendenin","Clermont","Cleverdale","Cleves","Clewiston","Cliffwood","Clifty","Clinchco","Clinchfield","Clintondale","Clintonville","Clintwood","Clitherall","Clockville","Clontarf","Clopton","Cloquet","Closplint","Closter","Cloudcroft","Cloutierville","Clover","Cloverdale","Cloverport","Clovis","Clubb","Clune","Clute","Clutier","Clyman","Clymer","Clyo","Coachella","Coahoma","Coaldale","Coalfield","Coalgood","Coaling","Coalinga","Coalmont","Coalport","Coalton","Coalville","Coalwood","Coamo","Coarsegold","Coatesville","Coats","Coatsburg","Coatsville","Cobbtown","Cobden","Cobleskill","Coburn","Cochecton","Cochise","Cochranton","Cochranville","Cockeysville","Cocolalla","Cocolamus","Codell","Coden","Codorus","Coeburn","Coello","Coeymans","Coffeen","Coffeeville","Coffeyville","Cofield","Coggon","Cogswell","Cohagen","Cohasset","Cohoctah","Cohocton","Cohoes","Cohutta","Coila","Coinjock","Cokato","Cokeburg","Cokedale","Coker","Cokeville","Colbert","Colburn","Colchester","Colcord","Coldbrook","Colden","Coldwater","Colebrook","Colerain","Coleraine","Colesburg","Coleta","Coleville","Colfax","Collbran","Collettsville","Colleyville","Colliers","Colliersville","Collierville","Collingswood","Collinston","Collinsville","Collinwood","Collison","Collyer","Colman","Colmar","Colmesneil","Colo","Coloma","Colome","Colona","Colora","Colp","Colquitt","Colrain","Colstrip","Colton","Columbiana","Columbiaville","Colusa","Colver","Colville","Colwich","Combes","Combs","Comer","Comerio","Comfrey","Comins","Commack","Commiskey","Como","Comptche","Comstock","Conasauga","Conaway","Concan","Concepcion","Concho","Conconully","Concordia","Concordville","Conda","Conde","Condon","Conehatta","Conejos","Conestee","Conesus","Conesville","Conetoe","Confluence","Conger","Congers","Congerville","Conneaut","Conneautville","Connell","Connellsville","Connelly","Connersville","Connerville","Conover","Conowingo","Conran","Conrath","Conroe","Conroy","Conshohocken","Constable","Constantia","Contoocook","Conyers","Conyngham","Cookeville","Cooks","Cooksburg","Cookson","Cookstown","Cooksville","Cookville","Cooleemee","Coolin","Coolville","Cooper","Coopersburg","Cooperstown","Coopersville","Coosa","Coosada","Cooter","Copake","Copan","Copemish","Copeville","Copiague","Coplay","Coppell","Copperopolis","Coquille","Cora","Coralville","Coram","Coraopolis","Corapeake","Corbettsville","Corbin","Cordele","Cordell","Corder","Cordesville","Cordova","Corea","Corfu","Corinna","Corinne","Corley","Cornersville","Cornettsville","Corning","Cornland","Cornlea","Cornville","Cornwallville","Corolla","Corozal","Corrales","Correll","Corrigan","Corriganville","Corry","Corryton","Corsica","Corsicana","Corson","Cortaro","Cortemadera","Cortez","Corton","Corunna","Corwith","Cory","Corydon","Cosby","Coscob","Coshocton","Cosmopolis","Cossayuna","Costigan","Costilla","Cotati","Coteau","Cotesfield","Cotolaurel","Cotopaxi","Cottageville","Cottekill","Cotter","Cottle","Cottleville","Cottondale","Cottonport","Cottonton","Cottontown","Cotuit","Cotulla","Couderay","Coudersport","Coulters","Coulterville","Counce","Coupeville","Coupland","Courtenay","Courtland","Courtois","Coushatta","Covel","Covelo","Covena","Covesville","Covina","Covington","Cowanesque","Cowansville","Cowarts","Cowden","Cowdrey","Cowen","Coweta","Cowgill","Cowiche","Cowlesville","Cowley","Coxsackie","Coyanosa","Coyle","Coyville","Cozad","Craborchard","Crabtree","Craddockville","Craftsbury","Cragford","Cragsmoor","Craigmont","Craigsville","Craigville","Craley","Cramerton","Cranbury","Crandon","Cranesville","Cranks","Crapo","Crary","Craryville","Crawfordville","Crawley","Crayne","Craynor","Creede","Creedmoor","Creighton","Crenshaw","Creola","Cresbard","Cresco","Cressey","Cresskill","Cresson","Cressona","Crestline","Creston","Crestone","Crestwood","Creswell","Crewe","Crichton","Criders","Crimora","Crisfield","Crittenden","Critz","Crivitz","Crocheron","Crocker","Crocketville","Crofton","Croghan","Cromona","Crooks","Crookston","Crooksville","Cropsey","Cropseyville","Cropwell","Crosbyton","Crossett","Crossnore","Crossroads","Crossville","Crosswicks","Croswell","Crothersville","Croton","Crouse","Crouseville","Crowder","Crowell","Crowheart","Crownsville","Crowville","Crozet","Crozier","Cruger","Crum","Crumpler","Crumpton","Crumrod","Cubage","Cubero","Cubrun","Cuchillo","Cudahy","Cuddy","Cuero","Cuervo","Culberson","Culdesac","Culebra","Cullen","Culleoka","Cullman","Culloden","Cullom","Cullowhee","Culpeper","Cumbola","Cumby","Cummaquid","Cumming","Cummington","Cundiff","Cuney","Cupertino","Curdsville","Curllsville","Currie","Currituck","Curryville","Curtice","Curtin","Curtiss","Curtisville","Curwensville","Cusick","Cusseta","Custar","Cutbank","Cutchogue","Cuthbert","Cutshin","Cuttingsville","Cuttyhunk","Cutuno","Cuyama","Cuyler","Cuzzart","Cygnet","Cynthiana","Cynwyd","Cypressinn","Dabneys","Dabolt","Dacoma","Dacono","Dacula","Dadeville","Dafter","Daggett","Dagmar","Dagsboro","Dagusmines","Dahinda","Dahlgren","Dahlonega","Daingerfield","Daisetta","Daisy","Daisytown","Dalbo","Daleville","Dalhart","Dallardsville","Dalmatia","Damar","Damariscotta","Dameron","Danboro","Danby","Danciger","Danese","Danevang","Danforth","Dania","Daniels","Danielsville","Dannebrog","Dannemora","Dansville","Danvers","Danville","Darby","Dardanelle","Darden","Darfur","Darien","Darlington","Darragh","Darrington","Darrouzett","Darrow","Dassel","Datil","Datto","Davant","Davey","Davidsonville","Davidsville","Davilla","Davin","Davisboro","Davisburg","Daviston","Davisville","Dawes","Dawmont","Dawsonville","Dayhoit","Daykin","Dayville","Dazey","DeKalb","Deale","Deansboro","Deanville","Dearing","Dearmanville","Deary","Deatsville","Deaver","Debary","Debeque","Deberry","Debord","Decaturville","Decherd","Deckerville","Declo","Decorah","Dedham","Deedsville","Deepwater","Deerbrook","Deerfield","Deering","Deersville","Deerton","Deerwood","Deeth","Deferiet","Defiance","Defoe","Deford","Degraff","Dehue","Delafield","Delancey","Deland","Delanson","Delaplaine","Delaplane","Delavan","Delbarton","Delcambre","Delco","Deleon","Delevan","Dellroy","Dellslow","Delmar","Delmita","Delmont","Delnorte","Deloit","Delong","Delphia","Delphos","Delray","Delrey","Delrio","Deltaville","Delton","Delvalle","Dema","Demarest","Deming","Demopolis","Demorest","Demossville","Demotte","Dempster","Denair","Denbigh","Denbo","Dendron","Denham","Denhoff","Denio","Denison","Dennard","Dennison","Dennisport","Denniston","Dennisville","Dennysville","Densmore","Denville","Depauville","Depauw","Depere","Depew","Depeyster","Depoy","Depue","Dequeen","Derbyline","Derma","Dermott","Derry","Deruyter","Derwent","Desarc","Descanso","Desdemona","Desha","Deshler","Deslacs","Desmet","Desmoines","Desoto","Destin","Destrehan","Devault","Devers","Deville","Devine","Devol","Dewart","Deweese","Deweyville","Dewittville","Dewyrose","Deyoung","Dhanis","Diablo","Diamondville","Diaz","Diboll","Dickeyville","Dierks","Dieterich","Diggins","Diggs","Dighton","Digiorgio","Dike","Dillard","Dille","Diller","Dilley","Dilliner","Dillingham","Dillonvale","Dillsboro","Dillsburg","Dilltown","Dillwyn","Dilworth","Dimebox","Dimmitt","Dimock","Dimondale","Dingess","Dingle","Dingus","Dinuba","Dinwiddie","Disputanta","Dittmer","Divernon","Dix","Dixfield","Dixiana","Dixmont","Dixonville","Dizney","Dlo","Dobbins","Docena","Doddsville","Dodgeville","Doerun","Dogpatch","Dogue","Dola","Doland","Dolgeville","Dolliver","Dolph","Dolton","Donaana","Donalds","Donalsonville","Donegal","Dongola","Donie","Doniphan","Donnellson","Donnelsville","Donora","Doole","Doon","Doran","Dorena","Dorloo","Dornsife","Dorr","Dorrance","Dorris","Dorsey","Dorton","Dospalos","Doss","Doswell","Dothan","Doty","Doucette","Douds","Douglassville","Douglasville","Dousman","Dover","Dovray","Dowagiac","Dowell","Dowelltown","Downieville","Downingtown","Downsville","Dows","Doylestown","Doyline","Doyon","Dozier","Dracut","Draffin","Drakesboro","Drakesville","Draper","Drasco","Dravosburg","Drayden","Drayton","Drennen","Dresden","Dresser","Drewryville","Drewsey","Dreyfus","Drifting","Drifton","Driftwood","Driggs","Drummonds","Drumore","Drumright","Drums","Dryprong","Duanesburg","Duarte","Dubach","Dubberly","Dubois","Dubre","Dubuque","Duchesne","Ducktown","Duckwater","Ducor","Duenweg","Duewest","Duffau","Duffield","Dufur","Dugger","Dugspur","Dugway","Dulac","Dulce","Dulzura","Dumas","Dumfries","Dumont","Duncannon","Duncansville","Duncanville","Duncombe","Dundas","Dundee","Dunellen","Dunfermline","Dungannon","Dunkerton","Dunlevy","Dunlo","Dunlow","Dunmor","Dunmore","Dunnegan","Dunnell","Dunnellon","Dunnigan","Dunning","Dunnsville","Dunnville","Dunreith","Dunseith","Dunsmuir","Dunstable","Dunwoody","Duplessis","Dupo","Dupree","Dupuyer","Duquoin","Duran","Durand","Durant","Durbin","Durhamville","Dushore","Duson","Dustin","Dutchtown","Dutzow","Duvall","Duxbury","Dwale","Dycusburg","Dyersburg","Dyersville","Dyess","Dysart","Eads","Eagar","Eagarville","Eaglesmere","Eagletown","Eagleville","Eakly","Earle","Earleton","Earleville","Earlham","Earlimart","Earling","Earlington","Earlsboro","Earlton","Earlville","Early","Earlysville","Earp","Easley","Eatonton","Eatontown","Eatonville","Eauclaire","Eaugalle","Ebenezer","Ebensburg","Ebervale","Ebeye","Ebro","Echola","Echols","Eckelson","Eckerman","Eckert","Eckerty","Eckley","Eckman","Ecorse","Ecru","Ector","Edcouch","Eddyville","Edelstein","Edenton","Edenville","Edgard","Edgarton","Edgartown","Edgefield","Edgeley","Edgemont","Edgemoor","Edgewater","Edgewood","Edina","Edinboro","Edinburg","Edmeston","Edmon","Edmond","Edmonson","Edmore","Edneyville","Edon","Edroy","Edson","Edwall","Edwardsburg","Edwardsport","Edwardsville","Eek","Effingham","Efland","Egegik","Egeland","Eggleston","Eglon","Egnar","Ehrenberg","Ehrhardt","Eidson","Eitzen","Ekalaka","Ekron","Ekwok","Eland","Elbe","Elberfeld","Elberon","Elbert","Elberta","Elberton","Elbing","Elburn","Elcho","Elco","Eldena","Elderon","Eldersville","Elderton","Eldred","Eleele","Eleroy","Eleva","Elfers","Eliasville","Elida","Elizabethton","Elizabethtown","Elizaville","Elkader","Elkhorn","Elkin","Elkins","Elkland","Elkmont","Elkmound","Elko","Elkport","Elkton","Elkview","Elkville","Elkwood","Ellabell","Ellamore","Ellaville","Ellenboro","Ellenburg","Ellendale","Ellensburg","Ellenton","Ellenville","Ellenwood","Ellerbe","Ellerslie","Ellery","Ellettsville","Ellicottville","Ellijay","Ellinger","Ellington","Ellinwood","Elliottsburg","Elliottville","Ellisburg","Elliston","Ellisville","Elloree","Ellsinore","Ellston","Elma","Elmaton","Elmdale","Elmendorf","Elmhall","Elmmott","Elmo","Elmonte","Elmora","Elmore","Elmwood","Elnora","Elora","Eloy","Elrod","Elroy","Elsa","Elsah","Elsberry","Elsmere","Elsmore","Elvaston","Elverson","Elverta","Elwell","Elwin","Elwood","Elyria","Elysburg","Embudo","Emden","Emeigh","Emelle","Emerado","Emigsville","Eminence","Emington","Emison","Emlenton","Emlyn","Emmalena","Emmaus","Emmet","Emmetsburg","Emmitsburg","Emmonak","Emmons","Emporia","Encampment","Encinal","Encinitas","Encino","Endeavor","Enderlin","Enders","Engadine","Engelhard","Englishtown","Enka","Enloe","Ennice","Enning","Ennis","Enochs","Enola","Enon","Enoree","Ensenada","Ensign","Entiat","Entriken","Enumclaw","Enville","Eola","Eolia","Epes","Ephrata","Epping","Epps","Epworth","Equality","Equinunk","Erath","Erbacon","Erhard","Erick","Ericson","Erieville","Eriline","Erin","Ermine","Ernul","Erving","Erwinna","Erwinville","Esbon","Escalante","Escalon","Escanaba","Escatawpa","Escoheag","Escondido","Eskdale","Esko","Esmond","Esmont","Esopus","Espanola","Esparto","Esperance","Essexfells","Essexville","Essie","Essig","Essington","Estacada","Estancia","Estelline","Estero","Estherville","Estherwood","Estill","Ethelsville","Etiwanda","Etlan","Etna","Etoile","Eton","Etowah","Etta","Etters","Etterville","Ettrick","Etty","Eubank","Eucha","Eudora","Eufaula","Euless","Eupora","Eure","Eustace","Eustis","Eutaw","Eutawville","Evadale","Evan","Evansport","Evant","Evart","Evarts","Eveleth","Eveningshade","Evensville","Everest","Everetts","Everettville","Everly","Everson","Everton","Evington","Evinston","Ewan","Ewell","Ewen","Excello","Exeland","Exira","Exline","Exmore","Exton","Eyota","Ezel","Fabens","Fabius","Fabyan","Fackler","Factoryville","Fagus","Fairbank","Fairbanks","Fairborn","Fairburn","Fairbury","Fairchance","Fairchild","Fairdale","Fairhope","Fairland","Fairlawn","Fairlee","Fairmont","Fairoaks","Fairplay","Fairton","Fairview","Fairwater","Faison","Fajardo","Falconer","Falfurrias","Falkland","Falkner","Falkville","Fallbrook","Fallon","Falls","Fallsburg","Fallston","Falun","Fancher","Fannettsburg","Fannin","Fanshawe","Fanwood","Faribault","Farisita","Farler","Farlington","Farmdale","Farmer","Farmers","Farmersburg","Farmersville","Farmerville","Farmingdale","Farmingville","Farmville","Farnam","Farner","Farnham","Farnhamville","Farragut","Farrandsville","Farrar","Farson","Farwell","Fashing","Faubush","Faucett","Faulkton","Faunsdale","Fawnskin","Faxon","Faywood","Federalsburg","Feesburg","Felch","Felda","Fellows","Fellsmere","Felton","Fenelton","Fennimore","Fennville","Fentress","Fenwick","Ferndale","Ferney","Fernley","Fernwood","Ferrellsburg","Ferriday","Ferrisburg","Ferron","Ferrum","Ferrysburg","Ferryville","Fessenden","Festina","Festus","Feurabush","Fiatt","Fiddletown","Fieldale","Fielding","Fieldon","Fieldton","Fifield","Filer","Filion","Filley","Fillmore","Finchville","Findlay","Fineview","Fingal","Fingerville","Finksburg","Finlayson","Finleyville","Finly","Firebaugh","Firebrick","Firesteel","Firth","Fisher","Fishers","Fishersville","Fishertown","Fisherville","Fishkill","Fishtail","Fishtrap","Fiskdale","Fiskeville","Fisty","Fithian","Fittstown","Fitzhugh","Flagtown","Flandreau","Flasher","Flatlick","Flatonia","Flatwoods","Flaxton","Flaxville","Fleetville","Fleetwood","Fleischmanns","Flemingsburg","Flemington","Flensburg","Flicksville","Flinton","Flintstone","Flintville","Flippin","Flom","Flomaton","Flomot","Floodwood","Florala","Floresville","Florien","Floris","Florissant","Floriston","Flossmoor","Flourtown","Flovilla","Floydada","Floyddale","Fluker","Flushing","Fluvanna","Flyingh","Fogelsville","Fogertown","Fola","Folcroft","Folkston","Follansbee","Follett","Folsom","Folsomville","Fombell","Fonda","Fonddulac","Foneswood","Fontana","Fontanelle","Fontanet","Foosland","Footville","Foraker","Forbestown","Fordcliff","Fordland","Fordoche","Fords","Fordsville","Fordville","Fordyce","Foreman","Forestburg","Forestburgh","Forestdale","Foreston","Forestport","Forestville","Forgan","Foristell","Forkland","Forks","Forksville","Forkunion","Forkville","Forman","Formoso","Forney","Forreston","Forsan","Forsyth","Fosston","Fosters","Fosterville","Fostoria","Fouke","Fountaintown","Fountainville","Fourmile","Fouroaks","Fowler","Fowlerton","Fowlerville","Fowlkes","Fowlstown","Foxboro","Foxburg","Foxcroft","Foxholm","Foxton","Foxtown","Foxworth","Foyil","Frackville","Frakes","Frametown","Framingham","Francestown","Francesville","Francitas","Franconia","Frankclay","Frankenmuth","Frankewing","Frankford","Franklinton","Franklintown","Franklinville","Frankston","Franksville","Frankton","Franktown","Frankville","Frannie","Frazee","Frazer","Frazeysburg","Frederica","Fredericktown","Frederika","Frederiksted","Fredonia","Fredville","Freeborn","Freeburg","Freeburn","Freeland","Freelandville","Freemanspur","Freesoil","Freeunion","Freeville","Freewater","Freistatt","Fremont","Frenchboro","Frenchburg","Frenchlick","Frenchtown","Frenchville","Frewsburg","Friant","Friedens","Friedensburg","Friedheim","Friendly","Friendship","Friendsville","Friendswood","Frierson","Fries","Friesland","Friona","Frisco","Fritch","Frogmore","Frohna","Froid","Fromberg","Frontenac","Frontroyal","Frostburg","Fruita","Fruitdale","Fruithurst","Fruitland","Fruitport","Fruitvale","Fryburg","Fryeburg","Fuget","Fulda","Fulshear","Fultondale","Fultonham","Fultonville","Fults","Funkstown","Funston","Fuquay","Fyffe","Gaastra","Gabbs","Gabriels","Gackle","Gadsden","Gaffney","Gagetown","Gainesboro","Gainestown","Gaither","Gakona","Galata","Galax","Galesburg","Galesville","Galeton","Galien","Galion","Gallatin","Gallaway","Galliano","Gallina","Gallion","Gallipolis","Gallitzin","Gallman","Gallupville","Galva","Galvin","Gamaliel","Gambier","Gambrills","Ganado","Gandeeville","Gans","Gansevoort","Gantt","Gapland","Gapville","Garardsfort","Garber","Garberville","Garciasville","Gardena","Gardendale","Gardenville","Gardiner","Gardners","Gardnerville","Garita","Garnavillo","Garnerville","Garnett","Garrard","Garrattsville","Garretson","Garrettsville","Garrisonville","Garvin","Garwin","Garwood","Garysburg","Garyville","Gasburg","Gasconade","Gasport","Gasquet","Gassaway","Gassville","Gastonia","Gastonville","Gatesville","Gatewood","Gattman","Gatzke","Gause","Gautier","Gaylesville","Gaylordsville","Gays","Gaysville","Gayville","Geary","Geddes","Geff","Geigertown","Geismar","Geneautry","Genesee","Geneseo","Gentryville","Georgiana","Gepp","Gering","Gerlach","Gerlaw","Germansville","Germanton","Geronimo","Gerrardstown","Gerton","Gervais","Getzville","Geyserville","Gheen","Gheens","Gibbonsville","Gibbsboro","Gibbstown","Gibsland","Gibsonburg","Gibsonia","Gibsonton","Gibsonville","Giddings","Gilberton","Gilbertown","Gilberts","Gilbertsville","Gilbertville","Gilboa","Gilby","Gilcrest","Gildford","Gile","Gilford","Gillett","Gilley","Gillham","Gilliam","Gillmore","Gillsville","Gilman","Gilmanton","Gilmer","Gilroy","Gilson","Gilsum","Giltner","Gipsy","Girard","Girdler","Girdletree","Girdwood","Girvin","Glace","Gladbrook","Gladeville","Gladewater","Gladwin","Gladwyne","Glady","Glandorf","Glasco","Glasford","Glasgo","Glassboro","Glasser","Glassport","Glasston","Glastonbury","Glenallan","Glenallen","Glenarbor","Glenarm","Glenaubrey","Glenbeulah","Glenbrook","Glenburn","Glenburnie","Glencarbon","Glencliff","Glencoe","Glencross","Glendaniel","Glendean","Glendive","Glendo","Glendon","Glendora","Gleneaston","Glenecho","Glenelder","Glenellen","Glenellyn","Glenferris","Glenfield","Glenflora","Glenford","Glengary","Glenham","Glenhayes","Glenhead","Glenjean","Glenlyn","Glenmont","Glenmoore","Glenmora","Glenmorgan","Glennallen","Glenndale","Glennie","Glennville","Glenolden","Glenoma","Glenpool","Glenrio","Glenrose","Glenshaw","Glenside","Glenspey","Glentana","Glenullin","Glenview","Glenvil","Glenville","Glenwhite","Glenwild","Glenwillard","Glenwilton","Glenwood","Glorieta","Gloster","Glouster","Glover","Gloversville","Gloverville","Glyndon","Glynn","Gober","Gobler","Gobles","Godeffroy","Godley","Goehner","Goessel","Goetzville","Goffstown","Golconda","Goldbar","Goldbond","Goldendale","Goldfield","Goldonna","Goldrun","Goldsboro","Goldston","Goldthwaite","Goldvein","Goliad","Goltry","Golts","Golva","Gonvick","Goochland","Goodell","Goodfield","Goodhope","Goodhue","Gooding","Goodland","Goodson","Goodview","Goodville","Goodwater","Goodway","Goodwell","Goodwine","Goodyear","Gordo","Gordonsville","Gordonville","Goree","Goreville","Gorin","Gorman","Gormania","Gorum","Goshen","Gosport","Gotebo","Gotha","Gothenburg","Goudeau","Gough","Gouldbusk","Gouldsboro","Gouverneur","Gove","Gowanda","Gowen","Gower","Gowrie","Grabill","Gracemont","Graceville","Gracewood","Gracey","Gradyville","Graettinger","Graford","Grafton","Grahamsville","Grahn","Grainfield","Grambling","Gramercy","Gramling","Grampian","Granada","Granbury","Granby","Granger","Grangeville","Graniteville","Grannis","Grantham","Granton","Grants","Grantsboro","Grantsburg","Grantsdale","Grantsville","Granttown","Grantville","Grapeland","Grapeview","Grapeville","Grasonville","Grasston","Gratiot","Graton","Gratz","Gravelly","Gravette","Gravity","Grawn","Graycourt","Grayland","Grayling","Graymont","Graysville","Graytown","Grayville","Greeley","Greeleyville","Greenback","Greenbank","Greenbrier","Greenbush","Greendale","Greendell","Greeneville","Greenford","Greenhall","Greenhurst","Greenlane","Greenlawn","Greenleaf","Greenock","Greenport","Greensburg","Greensea","Greentown","Greenup","Greenvale","Greenview","Greenville","Greenwald","Greenway","Greig","Grenada","Grenloch","Grenola","Grenora","Grenville","Grethel","Gretna","Greybull","Greycliff","Gridley","Griffithville","Grifton","Griggsville","Grimesland","Grimsley","Grimstead","Grinnell","Grissom","Groesbeck","Grosseile","Grossetete","Grosvenordale","Grottoes","Groveland","Grovertown","Groves","Groveton","Grovetown","Grubbs","Grubville","Gruetli","Grulla","Grundy","Gruver","Grygla","Guadalupe","Guadalupita","Guage","Gualala","Guanica","Guasti","Guayama","Guayanilla","Guaynabo","Guerneville","Guerra","Guerrant","Gueydan","Guffey","Guiderock","Guilderland","Guin","Guinda","Guion","Gulfhammock","Gulfport","Gulliver","Gulston","Gumberry","Gunlock","Gunnison","Gunter","Guntersville","Guntown","Gurabo","Gurdon","Gurley","Gurnee","Gurney","Gusher","Gustine","Guston","Guttenberg","Guymon","Guys","Guysville","Guyton","Gwinn","Gwinner","Gwynedd","Gwynn","Gwynneville","Hachita","Hackensack","Hackettstown","Hackleburg","Haddam","Haddix","Haddonfield","Hadensville","Hadlock","Hadlyme","Hagaman","Hagan","Hagarstown","Hagarville","Hagerman","Hagerstown","Hahira","Hahnville","Haigler","Hailesboro","Hailey","Haileyville","Hainesport","Hakalau","Halbur","Halcottsville","Haldeman","Haledon","Haleiwa","Haleyville","Hallam","Hallandale","Halleck","Hallett","Hallettsville","Halliday","Hallie","Hallieford","Hallock","Hallowell","Halls","Hallsboro","Hallstead","Hallsville","Halltown","Hallwood","Halstad","Hamberg","Hambleton","Hamden","Hamel","Hamer","Hamersville","Hamler","Hamletsburg","Hammett","Hammon","Hammondsport","Hammondsville","Hammonton","Hampden","Hampstead","Hamptonville","Hamshire","Hana","Hanalei","Hanapepe","Hanceville","Handley","Handsom","Hankamer","Hankins","Hankinson","Hanksville","Hanlontown","Hannacroix","Hannaford","Hannastown","Hanoverton","Hansboro","Hansell","Hansford","Hanska","Hanston","Hansville","Haralson","Harbert","Harbeson","Harborside","Harborton","Harco","Hardaway","Hardburly","Hardeeville","Hardenville","Hardesty","Hardinsburg","Hardtner","Hardwick","Hardyville","Harford","Hargill","Harleigh","Harleton","Harleysville","Harleyville","Harlingen","Harlowton","Harman","Harmans","Harmonsburg","Harned","Harper","Harpersfield","Harpersville","Harperville","Harpster","Harpursville","Harrah","Harrell","Harrells","Harrellsville","Harrietta","Harrisonburg","Harrisonville","Harriston","Harristown","Harrisville","Harrod","Harrodsburg","Harrold","Harshaw","Hartfield","Hartington","Hartland","Hartleton","Hartline","Hartly","Harts","Hartsburg","Hartsdale","Hartsel","Hartselle","Hartsfield","Hartshorn","Hartshorne","Hartstown","Hartsville","Hartville","Hartwell","Hartwick","Hartwood","Harvel","Harveysburg","Harveyville","Harviell","Harwich","Harwichport","Harwick","Harwood","Haskell","Haskins","Haslet","Haslett","Hasse","Hassell","Haswell","Hatboro","Hatchechubbee","Hathorne","Hatillo","Hatley","Hattieville","Hatton","Haubstadt","Haughton","Hauppauge","Hauula","Havaco","Havelock","Havensville","Haverford","Haverstraw","Haviland","Havre","Hawarden","Hawesville","Hawi","Hawick","Hawkeye","Hawkinsville","Hawks","Hawleyville","Haworth","Haxtun","Haydenville","Hayesville","Haymarket","Haynesville","Hayneville","Haysi","Haysville","Hayti","Haywood","Hazelcrest","Hazelhurst","Hazelton","Hazelwood","Hazen","Hazlehurst","Hazlet","Hazleton","Headrick","Healdsburg","Healdton","Hearne","Heartwell","Heaters","Heathsville","Heaton","Heavener","Hebbronville","Heber","Hebert","Hebo","Hebron","Hecker","Hecla","Hedgesville","Hedley","Hedrick","Heflin","Hegins","Heidenheimer","Heidrick","Heilwood","Heimdal","Heiskell","Heislerville","Heisson","Helechawa","Helendale","Helenville","Helenwood","Helfenstein","Hellertown","Hellier","Helmer","Helmetta","Helmsburg","Helmuth","Helmville","Helotes","Helper","Heltonville","Hemet","Hemingford","Henagar","Hendley","Hendrix","Hendrum","Henefer","Henlawson","Hennepin","Hennessey","Henniker","Henning","Henrico","Henrieville","Henryetta","Henryton","Henryville","Hensel","Hensler","Hensley","Hensonville","Hephzibah","Hepler","Heppner","Hepzibah","Herbster","Herculaneum","Herington","Herlong","Hermansville","Hermanville","Herminie","Hermiston","Hermitage","Hermleigh","Hermon","Hernando","Herndon","Hernshaw","Herod","Herreid","Herrick","Herrin","Herrings","Herron","Herscher","Hersey","Hertel","Hertford","Hesperia","Hessel","Hessmer","Hesston","Hestand","Heth","Hetland","Hettick","Hettinger","Heuvelton","Hext","Heyburn","Heyworth","Hialeah","Hiawassee","Hibbing","Hibbs","Hickorywithe","Hicksville","Hico","Hiddenite","Higbee","Higden","Higdon","Higganum","Higginson","Higginsport","Higginsville","Highlands","Highlandville","Highmore","Highshoals","Highspire","Hightown","Hightstown","Highview","Highwood","Higley","Hihat","Hiko","Hiland","Hilda","Hildebran","Hildreth","Hilger","Hilham","Hill","Hillburn","Hiller","Hilliard","Hilliards","Hillisburg","Hillister","Hillrose","Hills","Hillsboro","Hillsborough","Hillsdale","Hillsville","Hilltown","Hillview","Hilmar","Hilo","Hiltons","Hima","Himrod","Hinckley","Hindman","Hindsboro","Hindsville","Hinesburg","Hineston","Hinesville","Hingham","Hinkle","Hinkley","Hinsdale","Hinton","Hiseville","Hissop","Hitchins","Hitchita","Hitterdal","Hiwasse","Hiwassee","Hixson","Hixton","Hobbsville","Hobgood","Hobson","Hobucken","Hochheim","Hockessin","Hockingport","Hockley","Hode","Hodgen","Hodgenville","Hoehne","Hoffmeister","Hogansburg","Hogansville","Hogeland","Hohenwald","Hohokus","Hoisington","Hokah","Holabird","Holcombe","Holdenville","Holder","Holderness","Holdingford","Holdrege","Holicong","Holladay","Hollandale","Hollansburg","Hollenberg","Holley","Holliday","Hollidaysburg","Hollins","Hollis","Holliston","Holloman","Hollowville","Hollsopple","Hollybush","Hollytree","Holmen","Holmesville","Holmsville","Holton","Holtsville","Holtville","Holtwood","Holyrood","Homedale","Homer","Homerville","Hometown","Homewood","Homeworth","Hominy","Homosassa","Honaker","Honaunau","Honeapath","Honeoye","Honesdale","Honeybrook","Honeyville","Honobia","Honokaa","Honomu","Honor","Honoraville","Hoodsport","Hooker","Hookerton","Hooks","Hookstown","Hoolehua","Hoopa","Hooper","Hoopeston","Hoople","Hooppole","Hoosick","Hooven","Hooversville","Hopatcong","Hopbottom","Hopedale","Hopehull","Hopeland","Hopeton","Hopewell","Hopkinsville","Hopkinton","Hopland","Hopwood","Hoquiam","Hordville","Horicon","Hormigueros","Hornbeak","Hornbeck","Hornbrook","Hornell","Horner","Hornersville","Hornick","Hornitos","Hornsby","Horntown","Horseheads","Horsepen","Horsham","Hortense","Hortonville","Hoschton","Hosford","Hoskins","Hoskinston","Hosmer","Hospers","Hosston","Hostetter","Hotchkiss","Hotevilla","Houck","Houlka","Houlton","Houma","Housatonic","Houstonia","Houtzdale","Hoven","Hovland","Howardstown","Howells","Howertons","Howes","Howland","Hoxeyville","Hoxie","Hoyleton","Hoytville","Hubbardston","Hubbardsville","Hubertus","Huddleston","Huddy","Hudgins","Hudsonville","Huey","Hueysville","Huger","Hugheston","Hughesville","Hughson","Hughsonville","Hugoton","Huguenot","Hulbert","Hulen","Hulett","Humacao","Humansville","Humarock","Humbird","Hume","Humeston","Hummelstown","Humnoke","Humphreys","Humptulips","Hungerford","Hunker","Hunnewell","Hunters","Huntersville","Huntertown","Huntingburg","Huntingdon","Huntingtown","Huntland","Huntly","Huntsburg","Hurdland","Hurdsfield","Hurleyville","Hurlock","Hurtsboro","Husk","Husser","Hustisford","Hustontown","Hustonville","Husum","Hutsonville","Huttig","Hutto","Huttonsville","Huxford","Hyampom","Hyannisport","Hyattsville","Hyattville","Hyden","Hydes","Hydesville","Hydetown","Hydeville","Hye","Hymera","Hyndman","Hyrum","Hysham","Iaeger","Ibapah","Icard","Ickesburg","Idabel","Idalia","Idalou","Idamay","Idanha","Idaville","Ider","Idledale","Idlewild","Idyllwild","Ignacio","Igo","Ihlen","Ijamsville","Ila","Iliamna","Iliff","Ilion","Illiopolis","Ilwaco","Imbler","Imboden","Imlay","Imlaystown","Imler","Immaculata","Immokalee","Imnaha","Imogene","Ina","Inavale","Inchelium","Independence","Indiahoma","Indianhead","Indianola","Indiantown","Indio","Indore","Inez","Ingalls","Ingle","Inglefield","Ingleside","Inglewood","Inglis","Ingold","Ingomar","Ingraham","Inkom","Inkster","Innis","Inola","Insko","Intercourse","Interlachen","Interlaken","Interlochen","Intervale","Inwood","Inyokern","Iola","Iona","Ione","Ionia","Ipava","Ipswich","Iraan","Irasburg","Iredell","Ireton","Irmo","Ironbelt","Irondale","Ironia","Irons","Ironsides","Ironton","Irrigon","Irvington","Irvona","Irwinville","Isaban","Isabela","Isanti","Iselin","Ishpeming","Islandton","Islesboro","Islesford","Isleta","Isleton","Islip","Ismay","Isola","Isom","Isonville","Issaquah","Istachatta","Itasca","Itmann","Ittabena","Iuka","Iva","Ivel","Ivesdale","Ivins","Ivor","Ivoryton","Ivydale","Ivyton","Ixonia","Jachin","Jackhorn","Jacksboro","Jacksonboro","Jacksonburg","Jacksonport","Jacksontown","Jacobsburg","Jacumba","Jadwin","Jaffrey","Jakin","Jal","Jamesburg","Jameson","Jamesport","Jamesstore","Jamesville","Jamieson","Jamison","Jamul","Janelew","Janesville","Jansen","Jarales","Jarbidge","Jaroso","Jarratt","Jarreau","Jarrell","Jarrettsville","Jarvisburg","Jasonville","Jayem","Jayess","Jayton","Jayuya","Jeanerette","Jeannette","Jeddo","Jeffers","Jeffersonton","Jellico","Jelm","Jemison","Jena","Jenison","Jenkinsburg","Jenkinsville","Jenkintown","Jenks","Jenner","Jennerstown","Jermyn","Jeromesville","Jerseyville","Jessieville","Jessup","Jesup","Jetersville","Jetmore","Jetson","Jigger","Jobstown","Jodie","Joelton","Joes","Joffre","Johnday","Johnsburg","Johnsonburg","Johnsonville","Joice","Joiner","Joinerville","Joliette","Jolley","Jolo","Jolon","Jonancy","Jonben","Jonesboro","Jonesborough","Jonesburg","Jonesport","Jonestown","Jonesville","Joplin","Joppa","Jordanville","Jourdanton","Juanadiaz","Jud","Juda","Judsonia","Julesburg","Juliaetta","Julian","Juliette","Juliustown","Juncos","Junedale","Juniata","Juntura","Justiceburg","Justin","Kaaawa","Kadoka","Kahlotus","Kahoka","Kahuku","Kahului","Kailua","Kailuakona","Kalaheo","Kalama","Kalaupapa","Kaleva","Kalida","Kalispell","Kalkaska","Kalona","Kalskag","Kalvesta","Kamas","Kamay","Kamiah","Kampsville","Kamrar","Kamuela","Kanab","Kanaranzi","Kanarraville","Kanawha","Kandiyohi","Kaneohe","Kaneville","Kannapolis","Kanona","Kanopolis","Kanorado","Kanosh","Kansasville","Kantner","Kapaa","Kapaau","Kapowsin","Karlin","Karlsruhe","Karlstad","Karluk","Karnack","Karnak","Karthaus","Karval","Kasbeer","Kasigluk","Kasilof","Kasota","Kasson","Katemcy","Kathryn","Katonah","Katy","Kaukauna","Kaumakani","Kaunakakai","Kaweah","Kawkawlin","Kaycee","Kayenta","Kaylor","Kaysville","Keaau","Kealakekua","Kealia","Keansburg","Kearney","Kearneysville","Kearny","Kearsarge","Keasbey","Keatchie","Keavy","Kechi","Keddie","Keedysville","Keeler","Keeline","Keene","Keenes","Keenesburg","Keensburg","Keeseville","Keewatin","Keezletown","Kegley","Keiser","Keisterville","Keithsburg","Keithville","Keizer","Kekaha","Kelayres","Keldron","Kelford","Kell","Kellerman","Kellerton","Kelliher","Kellnersville","Kellysville","Kellyton","Kellyville","Kelseyville","Kelso","Kelton","Kemah","Kemblesville","Kemmerer","Kempner","Kempster","Kempton","Kenai","Kenansville","Kendalia","Kendallville","Kendleton","Kendrick","Kenduskeag","Kenedy","Kenefic","Kenesaw","Kenilworth","Kenly","Kenmare","Kenna","Kennard","Kennebec","Kennebunk","Kennebunkport","Kennedale","Kennedyville","Kenner","Kennerdell","Kennesaw","Kennett","Kennewick","Kenosha","Kenova","Kensal","Kensett","Kentland","Kentuck","Kentwood","Kenvil","Kenvir","Kenwood","Keo","Keokee","Keokuk","Keosauqua","Keota","Kerens","Kerhonkson","Kerkhoven","Kerman","Kernersville","Kernville","Kerrick","Kerrville","Kersey","Kershaw","Keshena","Kesley","Keswick","Ketchum","Kettlersville","Kevil","Kewadin","Kewanee","Kewanna","Kewaskum","Kewaunee","Keyapaha","Keyesport","Keylargo","Keymar","Keyport","Keyser","Keysville","Keytesville","Kiahsville","Kidder","Kidron","Kief","Kiefer","Kiel","Kieler","Kiester","Kihei","Kila","Kilauea","Kilbourne","Kildare","Kilkenny","Killarney","Killawog","Killbuck","Killduff","Killeen","Killen","Killington","Killona","Kilmarnock","Kilmichael","Kiln","Kilsyth","Kimballton","Kimberton","Kimbolton","Kimmell","Kimmswick","Kimper","Kinards","Kincaid","Kincheloe","Kinde","Kinder","Kinderhook","Kingfield","Kingman","Kingmont","Kings","Kingsburg","Kingsdown","Kingsland","Kingsport","Kingstree","Kingsville","Kingwood","Kinmundy","Kinnear","Kinross","Kinsale","Kinsey","Kinsley","Kinsman","Kinston","Kinta","Kintnersville","Kintyre","Kinzers","Kipnuk","Kipton","Kirbyville","Kirkersville","Kirklin","Kirkman","Kirksey","Kirksville","Kirkville","Kirkwood","Kiron","Kirtland","Kirvin","Kirwin","Kismet","Kissimmee","Kistler","Kittanning","Kittery","Kittitas","Kittredge","Kittrell","Kitzmiller","Klamath","Klemme","Klickitat","Klingerstown","Klondike","Klossner","Kneeland","Knierim","Knifley","Knightdale","Knightsen","Knightstown","Knightsville","Knippa","Knobel","Knoblick","Knobnoster","Knoke","Knowlesville","Knoxboro","Knoxdale","Koeltztown","Kohler","Kokomo","Koleen","Koloa","Kona","Konawa","Koosharem","Kooskia","Koppel","Kopperl","Kopperston","Korbel","Koror","Kosciusko","Koshkonong","Kosrae","Kosse","Kossuth","Kotlik","Kountze","Kouts","Kraemer","Kranzburg","Kreamer","Kremmling","Kresgeville","Kress","Krum","Kualapuu","Kula","Kulm","Kulpmont","Kulpsville","Kuna","Kunia","Kunkle","Kunkletown","Kurten","Kurthwood","Kurtistown","Kurtz","Kuttawa","Kutztown","Kwethluk","Kwigillingok","Kyburz","Kylertown","Laager","Labadie","Labadieville","Labarge","LaBarre","LaBelle","Labolt","Lacamp","Lacarne","Lacassine","Laceyville","Lachine","Lackawaxen","Laclede","Lacombe","Lacon","Lacona","Laconia","Lacoochee","Lacoste","Lacota","Lacrescent","Lacygne","Ladd","Laddonia","Ladiesburg","Ladonia","Ladora","Ladson","Ladysmith","Lafarge","Lafargeville","Laferia","Lafferty","Lafitte","Lafollette","Lafontaine","Lafox","Lafrance","Lagrangeville","Lagro","Laguna","Lagunitas","Lahabra","Lahaina","Laharpe","Lahaska","Lahmansville","Lahoma","Laie","Laings","Laingsburg","Laird","Lairdsville","Lajas","Lajose","Lakin","Lakota","Laloma","Laluz","Lamadera","Lamarque","Lamartine","Lamberton","Lambertville","Lambric","Lambrook","Lambsburg","Lamero","Lamesa","Lamison","Lamoille","Lamoni","Lamont","Lamonte","Lamotte","Lamoure","Lampasas","Lampe","Lampeter","Lanagan","Lanark","Lancing","Landa","Landenberg","Lander","Landess","Landing","Landingville","Landisburg","Landisville","Lando","Landrum","Landville","Laneburg","Lanesboro","Lanesville","Lanett","Laneview","Laneville","Lanexa","Langdon","Langeloth","Langford","Langhorne","Langlois","Langston","Langsville","Langtry","Langworthy","Lanham","Lankin","Lannon","Lansdale","Lansdowne","Lanse","Lansford","Lantry","Laona","Laotto","Lapaz","Lapeer","Lapine","Lapryor","Lapwai","Laquey","Larchmont","Larchwood","Largo","Larimer","Larimore","Larned","Larose","Larrabee","Larslan","Larto","Larue","Larussell","Larwill","Lasal","Lasalle","Lascassas","Lashmeet","Lasker","Lasmarias","Lastrup","Latah","Latexo","Latham","Latimer","Laton","Latour","Latta","Lattimore","Latty","Laughlintown","Laupahoehoe","Laurelton","Laurelville","Laurens","Laurier","Laurinburg","Lavalette","Lavalle","Lavallette","Laveen","Lavelle","Lavergne","Laverkin","Laverne","Lavernia","Laveta","Lavilla","Lavina","Lavinia","Lavon","Lavonia","Lawai","Laward","Lawen","Lawler","Lawley","Lawndale","Lawnside","Lawrenceburg","Lawrenceville","Lawsonville","Lawtell","Lawtey","Lawton","Lawtons","Lawyersville","Layland","Laytonville","Lazbuddie","Lazear","Leachville","Leaday","Leadore","Leadville","Leadwood","Leakesville","Leakey","Leamington","Leary","Leasburg","Leatherwood","Leavittsburg","Lebam","Lebeau","Lebec","Lebo","Leburn","Lecanto","Leckie","Leckkill","Leckrone","Leclaire","Lecoma","Lecompton","Ledbetter","Lederach","Ledger","Ledgewood","Ledoux","Ledyard","Leechburg","Leeco","Leedey","Leemont","Leeper","Leesburg","Leesport","Leesville","Leet","Leeton","Leetonia","Leetsdale","Leevining","Leewood","Lefor","Lefors","Leggett","Legrand","Lehi","Lehighton","Lehr","Leicester","Leipsic","Leisenring","Leitchfield","Leiter","Leitersford","Leith","Leivasy","Lemars","Lemasters","Lemhi","Leming","Lemitar","Lemmon","Lemont","Lemoore","Lemoyen","Lemoyne","Lempster","Lenapah","Lengby","Lenhartsville","Lenni","Lennon","Lenoir","Lenora","Lenorah","Lenox","Lenoxdale","Lenoxville","Lentner","Lenzburg","Leola","Leoma","Leominster","Leonardsville","Leonardtown","Leonardville","Leonidas","Leonore","Leonville","Leopolis","Leota","Leoti","Lepanto","Lequire","Leraysville","Lerna","Lerona","Lerose","Lesage","Lesterville","Lesueur","Letart","Letcher","Letha","Letohatchee","Letona","Letts","Lettsworth","Leupp","Levan","Levant","Levasy","Levelland","Levelock","Leverett","Levering","Levittown","Lewellen","Lewes","Lewisberry","Lewisburg","Lewisetta","Lewisport","Lewiston","Lewistown","Lewisville","Lexa","Libby","Libertytown","Libertyville","Libuse","Licking","Lickingville","Lidderdale","Lidgerwood","Liebenthal","Lightfoot","Ligon","Ligonier","Liguori","Lihue","Likely","Lilbourn","Lilburn","Lilesville","Lille","Lillie","Lillington","Lilliwaup","Lilydale","Limaville","Limeport","Limington","Limon","Linch","Lincolndale","Lincolnton","Lincolnville","Lincroft","Lindale","Lindenhurst","Lindenwood","Lindley","Lindon","Lindrith","Lindsborg","Lindseyville","Lindside","Linesville","Lineville","Lingle","Lingleville","Linkwood","Linn","Linneus","Linton","Linville","Linwood","Lipan","Lisco","Liscomb","Lisman","Lismore","Lissie","Listie","Litchfield","Litchville","Literberry","Lithia","Lithonia","Lithopolis","Lititz","Littcarr","Littlefield","Littleport","Littlestown","Lively","Livonia","Lizella","Lizemores","Lizton","Llano","Llewellyn","Loa","Loachapoka","Loami","Lobata","Lobeco","Lobelville","Lochgelly","Lochloosa","Lochmere","Lockbourne","Lockeford","Lockesburg","Lockney","Lockport","Loco","Locustdale","Locustville","Loda","Lodgegrass","Lodi","Logandale","Logansport","Loganton","Loganville","Lohman","Lohn","Lohrville","Loiza","Loleta","Lolita","Lolo","Loma","Lomalinda","Lomamar","Loman","Lomax","Lometa","Lomira","Lomita","Lompoc","Lonaconing","Londonderry","Lonedell","Lonejack","Lonestar","Lonetree","Longbottom","Longdale","Longford","Longkey","Longlane","Longleaf","Longmeadow","Longmont","Longport","Longs","Longton","Longview","Longville","Longwood","Lonoke","Lonsdale","Loogootee","Lookeba","Looneyville","Lopeno","Lorado","Lorain","Loraine","Lorane","Loranger","Lordsburg","Loreauville","Lorena","Lorentz","Lorenzo","Loretto","Lorida","Lorimor","Loring","Loris","Lorman","Lorton","Lostant","Lostine","Lothair","Lothian","Lott","Lottsburg","Louann","Loudon","Loudonville","Louellen","Loughman","Louin","Louisburg","Louvale","Louviers","Lovejoy","Lovelaceville","Lovelady","Lovell","Lovelock","Lovely","Lovettsville","Loveville","Lovilia","Loving","Lovingston","Lovington","Lowake","Lowber","Lowden","Lowder","Lowellville","Lowes","Lowesville","Lowman","Lowmansville","Lowmoor","Lowndes","Lowndesboro","Lowndesville","Lowville","Loxahatchee","Loxley","Loyall","Loyalton","Loysburg","Loysville","Lozano","Luana","Lubec","Lublin","Lucama","Lucan","Lucasville","Lucedale","Lucien","Lucile","Lucinda","Luckey","Ludell","Ludington","Ludowici","Luebbering","Lueders","Lufkin","Lugoff","Lukeville","Lula","Luling","Lumberport","Lumberton","Lumpkin","Luna","Lundale","Lunenburg","Luning","Lupton","Luquillo","Luray","Lurgan","Lusby","Lusk","Lutcher","Lutesville","Luthersburg","Luthersville","Lutherville","Lutsen","Luttrell","Lutts","Luverne","Luxemburg","Luxor","Luxora","Luzerne","Lyburn","Lycoming","Lyerly","Lyford","Lykens","Lyles","Lyme","Lynbrook","Lynco","Lynd","Lyndeborough","Lyndell","Lynden","Lyndhurst","Lyndon","Lyndonville","Lyndora","Lynndyl","Lynnfield","Lynnville","Lynnwood","Lynwood","Lysander","Lysite","Lytle","Lytten","Lytton","Mabank","Mabelvale","Maben","Mabie","Mableton","Mabscott","Mabton","MacClenny","MacClesfield","MacDoel","MacDona","MacFarlan","MacKay","MacKeyville","Macatawa","Maceo","Machias","Machiasport","Machipongo","Macksburg","Macksinn","Macksville","Mackville","Macomb","Macungie","Macy","Madawaska","Maddock","Madelia","Madera","Madill","Madisonburg","Madisonville","Maeystown","Magalia","Magdalena","Magee","Magness","Mahaffey","Mahan","Mahaska","Maher","Mahnomen","Mahomet","Mahopac","Mahto","Mahtowa","Mahwah","Maida","Maidens","Maidsville","Mainesburg","Maineville","Maitland","Maize","Majuro","Makanda","Makawao","Makaweli","Makinen","Makoti","Malaga","Malakoff","Malcom","Malibu","Malin","Malinta","Maljamar","Mallie","Malmo","Malo","Maloneton","Malott","Maloy","Malvern","Malverne","Mamers","Mamou","Manahawkin","Manakinsabot","Manasquan","Manassa","Manassas","Manati","Manawa","Mancelona","Manchaca","Manchaug","Mancos","Mandan","Mandaree","Manderson","Mandeville","Mangham","Mango","Mangohick","Mangum","Manhasset","Manheim","Manilla","Manistee","Manistique","Manito","Manitou","Manitowoc","Mankato","Manlius","Manly","Mannboro","Mannford","Manning","Mannington","Mannschoice","Mannsville","Manokin","Manokotak","Manomet","Manorville","Manquin","Mansfield","Manson","Mansura","Mantachie","Mantador","Manteca","Mantee","Manteno","Manteo","Manter","Manti","Mantoloking","Manton","Mantorville","Mantua","Manvel","Manzanola","Mapaville","Maplecrest","Mapleshade","Maplesville","Mapleton","Mapleview","Mapleville","Maplewood","Mappsville","Maquoketa","Maquon","Maramec","Marana","Marblehead","Marbury","Marceline","Marcell","Marcella","Marcellus","Marchand","Marcola","Marcushook","Marengo","Marenisco","Marfa","Marfrance","Margaretville","Marianna","Mariastein","Mariba","Maribel","Maricao","Maricopa","Marienthal","Marienville","Marilla","Marinette","Maringouin","Marionville","Mariposa","Marissa","Markesan","Markle","Markleeville","Markleton","Markleville","Markleysburg","Marksville","Markville","Marland","Marlette","Marlinton","Marlow","Marlton","Marmaduke","Marmarth","Marmora","Marne","Maroa","Marquand","Marquez","Marrero","Marshallberg","Marshalltown","Marshallville","Marshessiding","Marshfield","Marshville","Marsing","Marsland","Marsteller","Marston","Martel","Martell","Martelle","Martensdale","Marthasville","Marthaville","Martindale","Martinsburg","Martinsdale","Martinsville","Martinton","Martville","Marvell","Maryalice","Maryd","Marydel","Marydell","Maryknoll","Marylhurst","Maryneal","Marysvale","Marysville","Maryus","Maryville","Mascot","Mascotte","Mascoutah","Mashpee","Maskell","Masontown","Masonville","Massapequa","Massena","Massillon","Masury","Matador","Matagorda","Matamoras","Matawan","Matewan","Matheny","Mather","Matherville","Matheson","Mathis","Mathiston","Matinicus","Matlock","Matoaka","Mattapoisett","Mattaponi","Mattawamkeag","Mattawan","Mattawana","Matteson","Mattituck","Mattoon","Mauckport","Maud","Maugansville","Mauk","Mauldin","Maumee","Maunabo","Maunie","Maupin","Maurepas","Maurertown","Mauricetown","Mauriceville","Maury","Mauston","Mavisdale","Maxatawny","Maxbass","Maxie","Maximo","Maxton","Maxwelton","Mayaguez","Maybee","Maybell","Maybeury","Maybrook","Maydelle","Mayersville","Mayesville","Mayetta","Mayfield","Mayhew","Mayking","Maylene","Maynardville","Mayodan","Maypearl","Mayport","Mays","Maysel","Maysfield","Mayslick","Maysville","Maytown","Mayview","Mayville","Maywood","Mazeppa","Mazie","Mazomanie","Mazon","McAdenville","McAdoo","McAfee","McAlester","McAlister","McAllen","McAlpin","McAndrews","McArthur","McBain","McBee","McBrides","McCalla","McCallsburg","McCamey","McCammon","McCanna","McCarley","McCarr","McCaskill","McCaulley","McCausland","McCaysville","McClave","McCleary","McClelland","McCloud","McClurg","McClusky","McColl","McComas","McComb","McCombs","McCondy","McConnells","McCook","McCool","McCordsville","McCrory","McCune","McCurtain","McDade","McDaniels","McDavid","McDermitt","McDonough","McDougal","McElhattan","McEwen","McEwensville","McFaddin","McFall","McFarlan","McGaheysville","McGehee","McGirk","McGrady","McGrann","McGraws","McGrew","McGuffey","McHenry","McIntire","McKean","McKenney","McKinnon","McKittrick","McKnightstown","McLain","McLeansboro","McLeansville","McLouth","McMechen","McMillin","McMinnville","McNabb","McNary","McNeal","McNeill","McQuady","McQueeney","McRae","McRoberts","McShan","McSherrystown","McVeigh","McVeytown","McVille","McWhorter","McWilliams","McKeesport","Meade","Meador","Meadowbrook","Meadows","Meadville","Meally","Means","Meansville","Mears","Mebane","Mechanicsburg","Mechanicstown","Mechanicville","Mecklenburg","Meckling","Mecosta","Medanales","Medaryville","Medfield","Mediapolis","Medina","Medinah","Medomak","Medon","Medora","Medway","Meeker","Meers","Meeteetse","Megargel","Meherrin","Mehoopany","Meigs","Mekinock","Mekoryuk","Melba","Melber","Melbeta","Melcroft","Melder","Meldrim","Melfa","Mellen","Mellenville","Mellette","Mellott","Mellwood","Melmore","Melrose","Melstone","Melvern","Melvindale","Mena","Menahga","Menan","Menard","Menasha","Mendenhall","Mendham","Mendocino","Mendon","Mendota","Menemsha","Menfro","Menifee","Menno","Meno","Menoken","Menominee","Menomonie","Mentcle","Mentmore","Mentone","Meppen","Meraux","Merced","Mercedita","Mercersburg","Merchantville","Meredithville","Meredosia","Mereta","Meridale","Meriden","Meridianville","Merigold","Merino","Merkel","Mermentau","Merna","Merom","Merrick","Merricourt","Merrifield","Merrillan","Merrimac","Merriman","Merrittstown","Merrouge","Merryville","Mershon","Mertens","Merton","Mertzon","Mertztown","Mesaverde","Mescalero","Mesena","Meservey","Meshoppen","Mesick","Mesilla","Mesita","Meta","Metairie","Metaline","Metamora","Metcalfe","Methow","Metter","Metuchen","Metz","Mexia","Meyersdale","Meyersville","Miamisburg","Miamitown","Miamiville","Micanopy","Micaville","Miccosukee","Michie","Michigamme","Mickleton","Middlebass","Middleboro","Middlebourne","Middlebrook","Middleburg","Middleburgh","Middlefield","Middleport","Middlesboro","Middleville","Midfield","Midkiff","Midlothian","Midpines","Midvale","Midville","Mifflin","Mifflinburg","Mifflintown","Mifflinville","Mikado","Mikana","Milaca","Milam","Milano","Milanville","Milbank","Milburn","Milesburg","Milesville","Miley","Milfay","Milford","Milladore","Millboro","Millbrae","Millbrook","Millburn","Millbury","Milldale","Milledgeville","Millen","Millers","Millersburg","Millersport","Millerstown","Millersville","Millerton","Millerville","Millfield","Millhall","Millheim","Millhousen","Millican","Milligan","Milliken","Millington","Millinocket","Millis","Millmont","Millport","Millrift","Millry","Millsap","Millsboro","Millshoals","Millstadt","Millston","Milltown","Millville","Millwood","Milmay","Milmine","Milner","Milnesand","Milnesville","Milnor","Milo","Milpitas","Milroy","Milton","Miltona","Miltonvale","Mima","Mimbres","Mims","Mina","Minatare","Minburn","Minco","Minden","Mindoro","Minelamotte","Mineola","Minersville","Minetto","Mineville","Minford","Mingo","Mingoville","Mingus","Minier","Minneola","Minneota","Minnetonka","Minnewaukan","Minoa","Minocqua","Minong","Minonk","Minooka","Minotola","Minster","Minter","Minto","Minturn","Mio","Miquon","Miraloma","Miramonte","Misenheimer","Mishawaka","Mishicot","Miston","Mitchells","Mitchellsburg","Mitchellville","Mittie","Mize","Mizpah","Moab","Moapa","Moatsville","Mobeetie","Moberly","Mobjack","Moca","Mocksville","Moclips","Modale","Modena","Modeste","Modesttown","Modoc","Moffat","Moffett","Moffit","Mogadore","Mohall","Mohnton","Mohrsville","Moira","Mojave","Mokane","Mokena","Moko","Molalla","Molena","Molina","Molino","Momence","Monaca","Monahans","Monango","Monaville","Monclova","Moncure","Mondamin","Mondovi","Monee","Monessen","Moneta","Monett","Monetta","Monette","Mongo","Monhegan","Monico","Monkton","Monon","Monona","Monongah","Monoville","Monponsett","Monroeton","Monroeville","Monsey","Monson","Moodus","Moodys","Mooers","Moorcroft","Moorefield","Mooreland","Mooresboro","Mooresburg","Moorestown","Mooresville","Mooreton","Mooreville","Moorhead","Moorland","Moorman","Mooseheart","Moosup","Mora","Moraga","Morann","Morattico","Moreauville","Morehead","Morehouse","Morenci","Moretown","Morganfield","Morganton","Morgantown","Morganville","Morganza","Moriah","Moriches","Morland","Moro","Moroni","Morovis","Morral","Morrice","Morrilton","Morrisdale","Morrisonville","Morriston","Morrisville","Morrowville","Morven","Morvin","Mosby","Mosca","Moseley","Moselle","Moshannon","Mosheim","Mosherville","Mosier","Mosinee","Mosquero","Mossville","Mossyrock","Mott","Mottville","Moultonboro","Moultrie","Mounds","Moundsville","Moundville","Mousie","Mouthcard","Moville","Moweaqua","Mowrystown","Moxahala","Moxee","Moyers","Moyock","Mozelle","Mozier","Mtbaldy","Muenster","Mukilteo","Mukwonago","Muldoon","Muldraugh","Muldrow","Muleshoe","Mulga","Mulhall","Mulino","Mulkeytown","Mullan","Mullens","Mulliken","Mullin","Mullins","Mullinville","Mulvane","Muncy","Munday","Mundelein","Munden","Munford","Munfordville","Munger","Munising","Munith","Munnsville","Munsonville","Murchison","Murdo","Murdock","Murfreesboro","Murphys","Murphysboro","Murraysville","Murrayville","Murrieta","Murrysville","Murtaugh","Muscadine","Muscatine","Muscoda","Muscotah","Musella","Muskego","Muskogee","Mustoe","Myerstown","Myersville","Mylo","Myrtlewood","Myton","Naalehu","Nabb","Naches","Nachusa","Naco","Nacoochee","Nada","Nadeau","Nageezi","Nagshead","Naguabo","Nahant","Nahma","Nahunta","Nakina","Naknek","Nallen","Nampa","Nanafalia","Nanjemoy","Nankin","Nanticoke","Nantyglo","Nanuet","Naoma","Napa","Napakiak","Napanoch","Napavine","Naper","Naperville","Napier","Napoleonville","Naponee","Nappanee","Naranjito","Naravisa","Narberth","Nardin","Narka","Narrows","Narrowsburg","Naruna","Narvon","Naselle","Nashoba","Nashotah","Nashport","Nashwauk","Nason","Nassawadox","Natalbany","Natalia","Nathalie","Nathrop","Natick","Natoma","Naturita","Naubinway","Naugatuck","Nauvoo","Navarre","Navasota","Navesink","Naxera","Naylor","Naytahwaush","Nazlini","Neafus","Neapolis","Neavitt","Nebo","Necedah","Neche","Neches","Nederland","Nedrow","Needles","Needmore","Needville","Neely","Neelyton","Neelyville","Neenah","Neeses","Neffs","Negaunee","Negley","Negreet","Nehalem","Nehawka","Neihart","Neillsville","Neilton","Nekoma","Nekoosa","Neligh","Nellis","Nelliston","Nellysford","Nelse","Nelsonia","Nelsonville","Nemacolin","Nemaha","Nemo","Nemours","Nenzel","Neodesha","Neoga","Neola","Neopit","Neosho","Neotsu","Nephi","Neponset","Nerinx","Nerstrand","Nesbit","Nesconset","Nescopeck","Neshkoro","Nesmith","Nespelem","Nesquehoning","Netawaka","Netcong","Nettie","Nettleton","Neversink","Neville","Nevis","Nevisdale","Ney","Niangua","Niantic","Niarada","Nicasio","Niceville","Nicholasville","Nicholville","Nickelsville","Nickerson","Nicktown","Nicolaus","Nicollet","Nicut","Nielsville","Nikep","Nikiski","Nikolski","Niland","Niles","Nilwood","Nimitz","Ninde","Ninilchik","Ninnekah","Niobrara","Niota","Niotaze","Nipomo","Nisbet","Nisswa","Nisula","Nitro","Nittayuma","Niverville","Niwot","Nixa","Nobleboro","Noblesville","Nobleton","Nocatee","Nocona","Noctor","Nodaway","Nogal","Nogales","Nokesville","Nokomis","Nolanville","Nolensville","Noma","Nome","Nonantum","Nondalton","Nooksack","Noonan","Norborne","Norcatur","Norco","Norcross","Norden","Nordheim","Nordland","Nordman","Norene","Norge","Norlina","Normalville","Normangee","Normanna","Normantown","Norphlet","Norridgewock","Norristown","Nortonville","Norvell","Norvelt","Norwell","Norwood","Notasulga","Noti","Notrees","Nottawa","Nottoway","Notus","Novato","Novi","Novinger","Nowata","Noxapater","Noxen","Noxon","Noyes","Nuangola","Nubieber","Nucla","Nuevo","Numa","Numidia","Numine","Nunapitchuk","Nunda","Nunez","Nunica","Nunn","Nunnelly","Nuremberg","Nutrioso","Nuttsville","Nyac","Nyack","Nyssa","Oacoma","Oakbluffs","Oakboro","Oakdale","Oakes","Oakesdale","Oakfield","Oakford","Oakhall","Oakham","Oakhurst","Oaklawn","Oaklyn","Oakman","Oakmont","Oaks","Oakton","Oaktown","Oakvale","Oakview","Oakville","Oark","Oatman","Obernburg","Oberon","Obert","Obion","Obrien","Ocala","Ocate","Occoquan","Oceana","Oceano","Oceanport","Oceanview","Oceanville","Oceola","Ochelata","Ocheyedan","Ochopee","Ocilla","Ocoee","Oconee","Oconomowoc","Oconto","Ocotillo","Ocracoke","Odanah","Odebolt","Odell","Odem","Oden","Odenton","Odenville","Odon","Odonnell","Odum","Oelrichs","Oelwein","Ofallon","Offerle","Offerman","Offutt","Ogallah","Ogallala","Ogdensburg","Ogema","Ogilvie","Oglala","Oglesby","Oglethorpe","Ogunquit","Ohatchee","Ohiopyle","Ohiowa","Ohley","Ohlman","Oilmont","Oilton","Oiltrough","Oilville","Ojai","Okabena","Okahumpka","Okanogan","Okarche","Okaton","Okauchee","Okawville","Okean","Okeana","Okeechobee","Okeene","Okemah","Okemos","Oketo","Oklaunion","Oklawaha","Oklee","Okmulgee","Okoboji","Okolona","Okreek","Oktaha","Ola","Olalla","Olamon","Olancha","Olanta","Olar","Olathe","Olaton","Olcott","Oldham","Olds","Olean","Olema","Oley","Oliveburg","Olivehurst","Oliverea","Olivet","Olla","Ollie","Olmito","Olmitz","Olmstead","Olmsted","Olmstedville","Olney","Olpe","Olsburg","Olton","Olustee","Olyphant","Omak","Omar","Omena","Omer","Omro","Ona","Onaga","Onaka","Onalaska","Onamia","Onancock","Onarga","Onawa","Onaway","Onchiota","Oneals","Oneco","Onego","Oneill","Onekama","Onemo","Oneonta","Ong","Onia","Onida","Onley","Ono","Onslow","Onsted","Ontonagon","Ookala","Oolitic","Oologah","Ooltewah","Oostburg","Opalocka","Opdyke","Opelika","Opelousas","Opheim","Ophelia","Ophir","Opolis","Opp","Oquawka","Oquossoc","Ora","Oradell","Oran","Orangeburg","Orangefield","Orangevale","Orangeville","Oraville","Orbisonia","Orcas","Ord","Orderville","Ordway","Oreana","Orefield","Oregonia","Oreland","Orem","Orford","Orfordville","Orgas","Orick","Orinda","Oriska","Oriskany","Orla","Orland","Orlean","Orlinda","Orma","Ormsby","Orocovis","Orondo","Oronoco","Oronogo","Orosi","Orovada","Oroville","Orrick","Orrin","Orrington","Orrstown","Orrtanna","Orrum","Orrville","Orson","Orting","Ortley","Ortonville","Orwigsburg","Osage","Osakis","Osawatomie","Osburn","Osceola","Osco","Oscoda","Oshoto","Oshtemo","Oskaloosa","Osmond","Osnabrock","Osseo","Ossian","Ossineke","Ossining","Ossipee","Osteen","Osterburg","Osterville","Oswegatchie","Oswego","Osyka","Otego","Otho","Otisco","Otisville","Otley","Oto","Otoe","Otsego","Otterbein","Ottertail","Otterville","Ottine","Ottosen","Ottoville","Ottsville","Ottumwa","Otway","Otwell","Ouaquaga","Ouray","Outing","Outlook","Ouzinkie","Ovalo","Ovando","Ovapa","Overbrook","Overgaard","Overly","Overpeck","Overton","Ovett","Oviedo","Owaneco","Owanka","Owasco","Owasso","Owatonna","Owego","Owen","Owendale","Owensboro","Owensburg","Owensville","Owenton","Owings","Owingsville","Owlshead","Owosso","Owyhee","Oxbow","Oxly","Oyens","Oysterville","Ozan","Ozawkie","Ozona","Paauhau","Paauilo","Pachuta","Pacifica","Packwaukee","Packwood","Pacoima","Pacolet","Paden","Padroni","Paducah","Pageland","Pageton","Paguate","Pahala","Pahoa","Pahokee","Pahrump","Paia","Paicines","Paige","Painesdale","Painesville","Paintbank","Painter","Paintlick","Painton","Paintsville","Paisley","Pala","Palacios","Palatka","Palco","Palenville","Palisades","Pallmall","Palmcoast","Palmdale","Palmdesert","Palmer","Palmerdale","Palmersville","Palmerton","Paloalto","Palocedro","Paloma","Palopinto","Palouse","Paloverde","Paluxy","Pamplico","Pamplin","Pana","Panaca","Pangburn","Panguitch","Pannamaria","Panola","Panora","Pansey","Pantego","Paola","Paonia","Papaikou","Papineau","Paradis","Paragonah","Paragould","Parcoal","Pardeesville","Pardeeville","Parishville","Parkdale","Parker","Parkerford","Parkersburg","Parkesburg","Parkhall","Parkin","Parkman","Parksley","Parkston","Parksville","Parkton","Parkville","Parlier","Parlin","Parma","Parmele","Parmelee","Parnell","Paron","Parowan","Parrott","Parrottsville","Parryville","Parshall","Parsippany","Parsonsburg","Partlow","Pascagoula","Pasco","Pascoag","Pascola","Paskenta","Passadumkeag","Passumpsic","Pataskala","Patchogue","Pateros","Patillas","Patoka","Paton","Patricksburg","Patten","Pattison","Pattonsburg","Pattonville","Paulden","Paulding","Paulina","Paullina","Paulsboro","Paupack","Pavillion","Pavo","Pawhuska","Pawlet","Pawling","Pawnee","Pawneerock","Pawpaw","Paxico","Paxinos","Paxton","Paxtonville","Payette","Paynesville","Payneville","Payson","Peacham","Peachbottom","Peachland","Peapack","Pearblossom","Pearcy","Pearisburg","Pearland","Pearlington","Pearsall","Peaster","Pebworth","Pecatonica","Peckville","Peconic","Pedricktown","Peebles","Peedee","Peekskill","Peell","Peerless","Peetz","Peever","Peggs","Pegram","Pejepscot","Pekin","Pelahatchie","Pelion","Pelkie","Pella","Pellston","Pellville","Pelsor","Pelzer","Pemaquid","Pemberton","Pemberville","Pembina","Pembine","Penalosa","Penargyl","Penasco","Pender","Pendergrass","Pendleton","Pendroy","Penfield","Pengilly","Penhook","Penitas","Penland","Pennellville","Pennington","Pennlaird","Pennock","Pennsauken","Pennsboro","Pennsburg","Pennsville","Pennville","Pennyan","Penobscot","Penokee","Penrod","Penryn","Pentress","Pentwater","Penuelas","Penwell","Peoples","Peosta","Peotone","Pepeekeo","Pepin","Pepperell","Pequabuck","Pequannock","Pequea","Peralta","Perdido","Perham","Peridot","Perkasie","Perkinston","Perkinsville","Perks","Perley","Pernell","Perrin","Perrineville","Perrinton","Perris","Perronville","Perryhall","Perryman","Perryopolis","Perrysburg","Perrysville","Perryton","Perryville","Pescadero","Peshastin","Peshtigo","Pesotum","Petaca","Petaluma","Peterboro","Peterborough","Peterman","Petersham","Peterstown","Petoskey","Petrey","Petrolia","Petros","Pettibone","Pettigrew","Pettisville","Pettit","Pettus","Pevely","Pewamo","Pewaukee","Peyton","Peytona","Peytonsburg","Pfafftown","Pfeifer","Pflugerville","Pharoah","Pharr","Pheba","Phelan","Phenix","Philadelphia","Philipp","Philippi","Philipsburg","Phillipsburg","Phillipsville","Philmont","Philo","Philomath","Philomont","Philpot","Phippsburg","Phoenixville","Piasa","Picacho","Picher","Pickens","Pickerington","Pickrell","Pickstown","Pickton","Picorivera","Piedra","Piercefield","Pierceton","Pierceville","Piercy","Piermont","Pierpont","Pierron","Pierz","Pietown","Piffard","Piggott","Piketon","Pikeville","Pilger","Pillager","Pilottown","Pima","Pimento","Pinckard","Pinckney","Pinckneyville","Pinconning","Pindall","Pinebank","Pinebluffs","Pinebrook","Pinebush","Pinecliffe","Pinecrest","Pinedale","Pinehall","Pineknot","Pineland","Pineola","Pinetops","Pinetown","Pinetta","Pineview","Pineville","Pinewood","Pineywoods","Pingree","Pinola","Pinole","Pinon","Pinopolis","Pinson","Pioche","Pioneertown","Pipersville","Pipestem","Pipestone","Pippapasses","Piqua","Pirtleville","Piru","Piseco","Pisek","Pisgah","Pitcher","Pitkin","Pitsburg","Pitts","Pittsboro","Pittsburg","Pittsford","Pittstown","Pittsview","Pittsville","Pixley","Placedo","Placentia","Placerville","Placida","Placitas","Plains","Plainsboro","Plainview","Plainville","Plainwell","Plaisted","Plaistow","Planada","Plankinton","Plano","Plantersville","Plantsville","Platina","Plattekill","Plattenville","Platter","Platteville","Plattsburg","Plattsburgh","Plattsmouth","Plaucheville","Pleasantdale","Pleasanton","Pleasantville","Pleasureville","Pledger","Plentywood","Plessis","Plevna","Pluckemin","Plumerville","Plummer","Plumtree","Plumville","Plympton","Poca","Pocahontas","Pocasset","Pocatello","Pocola","Pocopson","Poestenkill","Polacca","Polkton","Polkville","Pollocksville","Pollok","Polson","Polvadera","Pomaria","Pomerene","Pomeroy","Pomeroyton","Pomfret","Ponape","Ponca","Ponchatoula","Ponderay","Ponderosa","Ponemah","Poneto","Ponsford","Pontotoc","Pooler","Poolesville","Poolville","Popejoy","Poplarville","Poquonock","Porum","Posen","Poseyville","Poskin","Postelle","Poston","Postville","Poteau","Potecasi","Poteet","Poth","Potosi","Potrero","Potsdam","Potter","Pottersville","Potterville","Pottsboro","Pottstown","Pottsville","Potwin","Poughquag","Poulan","Poulsbo","Poultney","Poway","Powderhorn","Powderly","Powellsville","Powellton","Powellville","Powersite","Powersville","Powhatan","Powhattan","Pownal","Poyen","Poynette","Poynor","Poyntelle","Poysippi","Prather","Pratts","Prattsburg","Prattshollow","Prattsville","Prattville","Preble","Premont","Prenter","Prentiss","Presho","Presidio","Prestonsburg","Prewitt","Pricedale","Prichard","Priddy","Primghar","Princeville","Princewick","Prineville","Pringle","Prinsburg","Printer","Pritchett","Proberta","Procious","Proctorsville","Proctorville","Progreso","Prole","Prompton","Prophetstown","Prosperity","Prosser","Protection","Protem","Protivin","Provencal","Providence","Provo","Pruden","Prudenville","Prue","Pryor","Pryse","Puckett","Puertoreal","Pukwana","Pulcifer","Pulteney","Pultneyville","Puncheon","Pungoteague","Punxsutawney","Puposky","Purcellville","Purdin","Purdon","Purdum","Purdy","Purdys","Purgitsville","Purlear","Purling","Purmela","Pursglove","Purvis","Puryear","Putnamville","Putney","Puunene","Puxico","Puyallup","Pyatt","Pylesville","Pyote","Pyrites","Quakake","Quakerstreet","Quakertown","Quanah","Quapaw","Quarryville","Quartzsite","Quasqueton","Quebeck","Quebradillas","Quechee","Queenanne","Queenstown","Quemado","Quenemo","Questa","Quicksburg","Quilcene","Quimby","Quinault","Quinby","Quincy","Quinebaug","Quinlan","Quinnesec","Quinnimont","Quinque","Quinter","Quinton","Quinwood","Quitaque","Quitman","Qulin","Quogue","Raceland","Racine","Rackerby","Radcliff","Radford","Radisson","Radnor","Radom","Raeford","Ragland","Ragley","Rago","Ragsdale","Rahway","Raiford","Rainelle","Rainier","Rains","Rainsville","Ralls","Ramage","Ramah","Ramer","Ramey","Ramona","Ramsay","Ramseur","Ranburne","Ranchester","Rancocas","Randalia","Randallstown","Randle","Randleman","Randlett","Randsburg","Rangeley","Rangely","Ranger","Ransomville","Ranson","Rantoul","Rapelje","Raphine","Rapidan","Rarden","Ratcliff","Rathdrum","Raton","Rattan","Ravena","Ravencliff","Ravendale","Ravenden","Ravenel","Ravenna","Ravensdale","Ravenswood","Ravenwood","Ravia","Ravinia","Rawl","Rawlings","Rawlins","Rawson","Raybrook","Rayland","Rayle","Raymondville","Raymore","Rayne","Raynesford","Raynham","Raysal","Rayville","Raywick","Raywood","Rea","Reader","Readfield","Reading","Readington","Readlyn","Readsboro","Readstown","Readyville","Realitos","Reamstown","Reardan","Reasnor","Rebersburg","Rebuck","Rectortown","Redan","Redart","Redash","Redbank","Redbanks","Redby","Redcliff","Redcrest","Reddell","Reddick","Redding","Redfield","Redford","Redfox","Redhook","Redig","Redkey","Redlands","Redlion","Redmon","Redondo","Redowl","Redrock","Redstar","Redvale","Redwater","Redway","Redwing","Reeder","Reeders","Reedley","Reeds","Reedsburg","Reedsport","Reedsville","Reedville","Reelsville","Reeseville","Reesville","Reevesville","Reform","Refton","Refugio","Regan","Register","Rehobeth","Rehoboth","Rehrersburg","Reidsville","Reidville","Reinbeck","Reinholds","Reisterstown","Reklaw","Reliance","Rembert","Remer","Remlap","Remsen","Remsenburg","Renalara","Renfrew","Renick","Renner","Reno","Renovo","Rentiesville","Renton","Rentz","Renville","Renwick","Repton","Resaca","Reseda","Retsof","Reva","Revelo","Revillo","Revloc","Rew","Rewey","Rexburg","Rexford","Rexmont","Rexville","Reydell","Reydon","Reyno","Reynoldsburg","Reynoldsville","Rhame","Rheems","Rhinebeck","Rhinecliff","Rhinehart","Rhineland","Rhinelander","Rhoadesville","Rhodelia","Rhodell","Rhodesdale","Rhodhiss","Rialto","Ribera","Riceboro","Ricetown","Riceville","Richardsville","Richardton","Richburg","Richey","Richeyville","Richford","Richland","Richlands","Richmonddale","Richmondville","Richton","Richvale","Richview","Richville","Richwood","Richwoods","Ricketts","Rickman","Rickreall","Riddlesburg","Riddleton","Riderwood","Ridgecrest","Ridgedale","Ridgefield","Ridgeland","Ridgeley","Ridgely","Ridgeview","Ridgeville","Ridgeway","Ridgewood","Ridott","Riegelsville","Riegelwood","Rienzi","Riesel","Rifton","Rigby","Riggins","Rileyville","Rillito","Rillton","Rimersburg","Rimini","Rinard","Rincon","Rindge","Riner","Rineyville","Ringgold","Ringle","Ringling","Ringoes","Ringold","Ringsted","Ringtown","Ringwood","Rion","Riparius","Ripon","Rippey","Ripplemead","Rippon","Ririe","Risco","Rison","Ritner","Rittman","Ritzville","Riva","Riverdale","Riveredge","Riverhead","Riverton","Rivervale","Riverview","Rives","Rivesville","Rixeyville","Rixford","Roachdale","Roann","Roanoke","Roark","Robards","Robbinston","Robbinsville","Robeline","Robersonville","Robertlee","Robertsburg","Robertsdale","Robertsville","Robesonia","Robinette","Robins","Robinsonville","Robson","Robstown","Roby","Roca","Rochdale","Rochelle","Rocheport","Rochert","Rochford","Rociada","Rockdale","Rockfall","Rockfield","Rockhall","Rockham","Rockholds","Rockhouse","Rockingham","Rocklin","Rockmart","Rockport","Rockton","Rockvale","Rockview","Rockville","Rockwall","Rockwood","Rodanthe","Roderfield","Rodessa","Rodman","Roduco","Roebling","Roff","Rogerson","Rogersville","Roggen","Rohrersville","Rolesville","Rolette","Rolfe","Rolla","Rollin","Rollingstone","Rollinsford","Rollinsville","Roma","Romayor","Rombauer","Romney","Ronan","Ronceverte","Ronco","Ronda","Ronkonkoma","Ronks","Roopville","Rootstown","Roper","Ropesville","Rosalia","Rosamond","Rosanky","Rosario","Rosburg","Roscoe","Roscommon","Roseau","Roseboom","Roseboro","Roseburg","Rosedale","Roselawn","Roselle","Rosemead","Rosemont","Rosendale","Rosenhayn","Roseville","Rosewood","Rosharon","Rosholt","Rosiclare","Rosie","Rosine","Roslyn","Rosman","Rossburg","Rosser","Rossiter","Rosslyn","Rossmore","Rosston","Rossville","Roswell","Rota","Rotan","Rothbury","Rothsay","Rothville","Rougemont","Rougon","Roundo","Roundrock","Rouseville","Rouzerville","Rover","Rowan","Rowesville","Rowlesburg","Rowlett","Rowletts","Roxana","Roxboro","Roxie","Roxobel","Roxton","Royalton","Royersford","Royston","Rozel","Rozet","Rubicon","Ruckersville","Rudd","Rueter","Rufe","Ruffin","Ruffsdale","Rugby","Ruidoso","Ruleville","Rulo","Rumely","Rumney","Rumsey","Rumson","Runa","Runnells","Runnemede","Rupert","Ruralhall","Rushford","Rushland","Rushsylvania","Rushville","Ruskin","Ruso","Russellton","Russellville","Russiaville","Rustburg","Ruston","Rutherfordton","Rutheron","Ruthton","Ruthven","Ruthville","Rydal","Ryde","Ryderwood","Ryland","Sabael","Sabanahoyos","Sabanaseca","Sabattus","Sabetha","Sabillasville","Sabin","Sabinal","Sabinsville","Sabula","Sacaton","Saco","Sacul","Sadieville","Sadorus","Sadsburyville","Saegertown","Saffell","Safford","Sagamore","Sagaponack","Sagle","Sagola","Saguache","Sahuarita","Saipan","Salado","Salamanca","Saldee","Salem","Salemburg","Salesville","Salford","Salfordville","Salida","Salinas","Salineno","Salineville","Salitpa","Salix","Salkum","Salley","Sallis","Sallisaw","Salol","Salome","Salterpath","Salters","Saltgum","Saltillo","Saltlick","Saltsburg","Saltville","Saluda","Salvisa","Salyer","Salyersville","Samantha","Samaria","Samburg","Samnorwood","Sapulpa","Saragosa","Sarahann","Sarahsville","Saraland","Saranac","Sarcoxie","Sardinia","Sardis","Sarepta","Sargeant","Sargents","Sargentville","Sarita","Sarles","Sarona","Saronville","Sartell","Sarton","Sarver","Sasabe","Sasakwa","Saspamco","Sasser","Satanta","Satartia","Satsop","Satsuma","Sattley","Saucier","Saugatuck","Saugerties","Saugus","Saukville","Saulsbury","Saum","Saunderstown","Saunemin","Sauquoit","Sausalito","Sautee","Savanna","Saverton","Savery","Savona","Savonburg","Sawyerville","Saxapahaw","Saxe","Saxeville","Saxis","Saxonburg","Saxton","Saybrook","Saylorsburg","Sayner","Sayre","Sayreville","Sayville","Scalf","Scammon","Scandia","Scappoose","Scarbro","Scarville","Schaghticoke","Schaller","Schaumburg","Scheller","Schellsburg","Schenevus","Schenley","Schererville","Schertz","Schlater","Schleswig","Schley","Schnecksville","Schoharie","Schriever","Schulenburg","Schulter","Schurz","Schuylerville","Schwertner","Scio","Sciota","Scipio","Scituate","Scobey","Scooba","Scottdale","Scottown","Scotts","Scottsboro","Scottsburg","Scottsmoor","Scottsville","Scottville","Screven","Scribner","Scroggins","Scuddy","Seabeck","Seabrook","Seacliff","Seadrift","Seaford","Seaforth","Seagirt","Seagoville","Seahurst","Seale","Sealston","Sealy","Seanor","Searcy","Searles","Searsboro","Searsmont","Searsport","Seaton","Seatonville","Seaview","Sebastopol","Sebec","Sebeka","Sebewaing","Seboeis","Seboyeta","Sebree","Sebring","Secaucus","Seco","Secor","Sedalia","Sedgwick","Sedley","Sedona","Seekonk","Seeley","Seelyville","Seffner","Seguin","Seibert","Seiling","Seitz","Sekiu","Selah","Selby","Selbyville","Selden","Seligman","Sellers","Sellersburg","Sellersville","Sells","Selmer","Selz","Semmes","Semora","Sena","Senath","Senatobia","Senecaville","Seney","Sennett","Senoia","Sepulveda","Sequatchie","Sequim","Serafina","Seree","Serena","Servia","Sesser","Sevenmile","Severance","Severy","Sevierville","Sewanee","Sewaren","Sewell","Sewickley","Sextonville","Shabbona","Shacklefords","Shadydale","Shadyside","Shafter","Shaftsburg","Shaftsbury","Shakopee","Shalimar","Shallotte","Shallowater","Shambaugh","Shamokin","Shandaken","Shandon","Shaniko","Shanks","Shanksville","Shannock","Shapleigh","Sharpes","Sharples","Sharps","Sharpsburg","Sharpsville","Sharptown","Shartlesville","Shattuc","Shattuckville","Shauck","Shawanee","Shawanese","Shawano","Shawboro","Shawmut","Shawneetown","Shawsville","Shawville","Sheakleyville","Sheboygan","Shedd","Sheds","Shelbiana","Shelbina","Shelburn","Shelburne","Shelbyville","Sheldahl","Sheldonville","Shellman","Shellsburg","Shelly","Shelocta","Shepardsville","Shepherdstown","Sheppton","Sherard","Sherborn","Sherburn","Sherburne","Sherrard","Sherrodsville","Shevlin","Sheyenne","Shickley","Shickshinny","Shidler","Shiner","Shingleton","Shingletown","Shinhopple","Shinnston","Shiocton","Shippensburg","Shippenville","Shipshewana","Shirland","Shirleysburg","Shiro","Shoals","Shobonier","Shohola","Shokan","Shongaloo","Shopville","Shoreham","Shorter","Shorterville","Shortsville","Shoshone","Shoshoni","Showell","Showlow","Shreve","Shrewsbury","Shubert","Shubuta","Shulerville","Shullsburg","Shumway","Shunk","Shuqualak","Shushan","Shutesbury","Sias","Sicklerville","Sidell","Sidman","Sidnaw","Sidon","Sieper","Sierraville","Sigel","Sigourney","Sigurd","Sikes","Sikeston","Siler","Silerton","Siletz","Silex","Siloam","Silsbee","Siluria","Silva","Silvana","Silverado","Silvercliff","Silverdale","Silverpeak","Silverplume","Silverstar","Silverstreet","Silverton","Silverwood","Silvis","Simla","Simmesport","Simms","Simonton","Simpsonville","Simsboro","Simsbury","Sinclairville","Singer","Sinnamahoning","Sinsinawa","Sinton","Sipesville","Sipsey","Sisseton","Sisters","Sistersville","Sitka","Sixes","Sixmile","Skamokawa","Skandia","Skanee","Skellytown","Skelton","Skene","Skiatook","Skidmore","Skillman","Skippack","Skippers","Skipperville","Skipwith","Skokie","Skowhegan","Skykomish","Skyland","Slade","Slagle","Slanesville","Slatedale","Slatersville","Slatington","Slaton","Slaughters","Slayden","Slayton","Sleepyeye","Slemp","Slickville","Slidell","Sligo","Slinger","Sloansville","Sloatsburg","Slocomb","Sloughhouse","Slovan","Smackover","Smallwood","Smarr","Smartt","Smartville","Smelterville","Smethport","Smicksburg","Smilax","Smiley","Smithboro","Smithburg","Smithdale","Smithers","Smithland","Smiths","Smithsburg","Smithshire","Smithton","Smithtown","Smithville","Smithwick","Smoaks","Smock","Smoketown","Smolan","Smoot","Smyer","Sneads","Sneedville","Snelling","Snellville","Snohomish","Snoqualmie","Snover","Snowmass","Snowville","Snydersburg","Socorro","Soddy","Sodus","Solano","Solebury","Soledad","Solen","Solgohachia","Solomons","Solsberry","Solsville","Solvang","Solway","Somerdale","Somersville","Somersworth","Somerton","Somis","Somonauk","Sondheimer","Sonoita","Sontag","Sonyea","Sopchoppy","Soper","Soperton","Soquel","Sorento","Sorrento","Soso","Soudan","Souder","Soudersburg","Souderton","Soulsbyville","Souris","Spanaway","Spangler","Spanishburg","Sparkill","Sparks","Sparland","Sparr","Sparrowbush","Spartanburg","Spartansburg","Spavinaw","Speaks","Spearfish","Spearman","Spearsville","Spearville","Speculator","Speer","Speight","Spelter","Spencerport","Spencertown","Spencerville","Speonk","Sperryville","Spiceland","Spicer","Spicewood","Spickard","Spillville","Spindale","Spinnerstown","Spiritwood","Spivey","Splendora","Spofford","Spooner","Spotswood","Spotsylvania","Spottsville","Spottswood","Spraggs","Spragueville","Sprakers","Sprigg","Springboro","Springbrook","Springdale","Springer","Springerton","Springerville","Springhope","Springhouse","Springlick","Springport","Springs","Springtown","Springvale","Springville","Springwater","Sprott","Spurgeon","Spurger","Spurlock","Spurlockville","Squires","Staatsburg","Stacyville","Staffordville","Stahlstown","Stambaugh","Stamps","Stanaford","Stanardsville","Stanberry","Stanchfield","Stanfield","Stanfordville","Stanleytown","Stantonsburg","Stantonville","Stanville","Stanwood","Staplehurst","Staples","Starbuck","Starford","Starke","Starks","Starksboro","Starkville","Starkweather","Starrucca","Startex","Statenville","Statesboro","Statesville","Statham","Stayton","Steamburg","Stedman","Steedman","Steeleville","Steelville","Steens","Steff","Steffenville","Steger","Stehekin","Steinauer","Steinhatchee","Stendal","Stephan","Stephensburg","Stephentown","Stephenville","Steptoe","Sterlington","Sterrett","Stetsonville","Steubenville","Stevensburg","Stevensville","Stevinson","Stewardson","Stewartstown","Stewartsville","Stewartville","Sthelena","Stickney","Stidham","Stigler","Stilesville","Stillmore","Stillwell","Stilwell","Stinesville","Stinnett","Stirrat","Stites","Stittville","Stitzer","Stockdale","Stockertown","Stockett","Stockland","Stockport","Stockville","Stockwell","Stoddard","Stokesdale","Stollings","Stoneboro","Stonefort","Stonega","Stoneham","Stoneville","Stonington","Stonybottom","Stonybrook","Stonyford","Storden","Stormville","Storrie","Storrs","Stottville","Stoughton","Stoutland","Stoutsville","Stovall","Stover","Stowe","Stowell","Stoy","Stoystown","Strabane","Strafford","Strandburg","Strandquist","Strang","Strasburg","Stratham","Strathcona","Strathmere","Strathmore","Strattanville","Straughn","Strausstown","Strawn","Streamwood","Streator","Streeter","Streetman","Streetsboro","Stringer","Stringtown","Stroh","Stromsburg","Stronghurst","Stroud","Stroudsburg","Strunk","Struthers","Stryker","Strykersville","Studley","Stumptown","Sturdivant","Sturgis","Sturkie","Sturtevant","Suamico","Subiaco","Sublette","Sublime","Sublimity","Succasunna","Suches","Sudbury","Sudith","Sudlersville","Suffern","Suffield","Sugarland","Sugarloaf","Sugartown","Sugartree","Sula","Sulligent","Sultana","Sumas","Sumerco","Sumerduck","Sumiton","Summerdale","Summerfield","Summerland","Summerlee","Summershade","Summersville","Summerton","Summertown","Summerville","Summit","Summitville","Sumneytown","Sumpter","Sumrall","Sumterville","Sunapee","Sunbright","Sunburg","Sunburst","Sunbury","Suncook","Sundance","Sunderland","Sunfield","Sunland","Sunman","Sunnyside","Sunnysouth","Sunol","Sunray","Supai","Suplee","Suquamish","Surgoinsville","Suring","Surrency","Surry","Susank","Susanville","Susquehanna","Sutersville","Sutherlin","Sutter","Suwanee","Suwannee","Svea","Swainsboro","Swaledale","Swampscott","Swannanoa","Swansboro","Swansea","Swanton","Swanville","Swanwick","Swartswood","Swartz","Swayzee","Swedeborg","Swedesboro","Swedesburg","Sweeden","Sweeny","Sweetbriar","Sweetgrass","Sweetland","Sweetser","Sweetwater","Swengel","Swepsonville","Swifton","Swiftown","Swiftwater","Swink","Swisher","Switchback","Swoope","Sybertsville","Sykeston","Sykesville","Sylacauga","Sylmar","Sylva","Symsonia","Syosset","Taberg","Tabernash","Tabiona","Tabor","Tacna","Tacoma","Taconic","Taconite","Tafton","Taftsville","Taftville","Tahlequah","Tahoka","Taholah","Tahuya","Taiban","Taintor","Talala","Talbert","Talbot","Talbott","Talbotton","Talco","Talcott","Talihina","Talisheek","Talladega","Tallapoosa","Tallassee","Tallega","Tallevast","Tallmadge","Tallman","Tallmansville","Tallula","Tallulah","Talmage","Talmo","Taloga","Talpa","Tama","Tamaqua","Tamaroa","Tamassee","Tamiment","Tamms","Tampico","Tams","Tamworth","Taneytown","Taneyville","Tangier","Tangipahoa","Tanner","Tannersville","Taopi","Taplin","Tapoco","Tappahannock","Tappan","Tappen","Tarboro","Tarentum","Tarheel","Tariffville","Tarkio","Tarpley","Tarrs","Tarzana","Tasley","Taswell","Tatamy","Tateville","Tatum","Tatums","Taunton","Tavares","Tavernier","Taylors","Taylorstown","Taylorsville","Taylorville","Tazewell","Tchula","Teaberry","Teachey","Teague","Teaneck","Teasdale","Teays","Tebbetts","Tecate","Techny","Tecopa","Tecumseh","Tefft","Tehachapi","Tehama","Tehuacana","Tekamah","Tekoa","Tekonsha","Telferner","Telford","Telluride","Telogia","Temecula","Tempe","Templeville","Tenafly","Tenaha","Tendoy","Tenino","Tenmile","Tennant","Tennent","Tennga","Tennille","Tensed","Tenstrike","Tererro","Teresita","Terlingua","Terlton","Termo","Terraalta","Terraceia","Terral","Terrebonne","Terrell","Terreton","Terril","Terryville","Tescott","Tesla","Tesuque","Teton","Tetonia","Teutopolis","Tewksbury","Texarkana","Texhoma","Texico","Texline","Texola","Texon","Thacker","Thackerville","Thatcher","Thawville","Thaxton","Thayne","Thedford","Theilman","Thendara","Theodosia","Theriot","Thermopolis","Therock","Thetford","Thibodaux","Thida","Thiells","Thiensville","Thomasboro","Thomaston","Thomastown","Thomasville","Thompsons","Thompsontown","Thompsonville","Thonotosassa","Thornburg","Thorndale","Thorndike","Thornfield","Thorntown","Thornville","Thornwood","Thorp","Thorsby","Throckmorton","Thurmond","Thurmont","Thurston","Tibbie","Tiburon","Tichnor","Tickfaw","Ticonderoga","Tidioute","Tiesiding","Tieton","Tiff","Tiffin","Tifton","Tigerton","Tigerville","Tignall","Tigrett","Tijeras","Tilden","Tilghman","Tiline","Tillamook","Tillar","Tillatoba","Tilleda","Tiller","Tillery","Tillman","Tillson","Tilly","Tilton","Tiltonsville","Timberville","Timblin","Timbo","Timewell","Timken","Timmonsville","Timnath","Timonium","Timpson","Tingley","Tinnie","Tinsley","Tintah","Tiona","Tionesta","Tiplersville","Tippecanoe","Tippo","Tipton","Tiptonville","Tiro","Tishomingo","Tiskilwa","Titonka","Titusville","Tiverton","Tivoli","Toaalta","Toabaja","Toano","Tobaccoville","Tobias","Tobinsport","Tobyhanna","Toccoa","Toccopola","Tocsin","Toddville","Tofte","Tohatchi","Toivola","Tokeland","Tokio","Tolar","Toler","Tolland","Tollesboro","Tolleson","Tolley","Tolna","Tolono","Tolu","Toluca","Tomah","Tomahawk","Tomales","Tomball","Tombean","Tompkinsville","Tomsbrook","Tonalea","Tonasket","Tonawanda","Toney","Tonganoxie","Tonica","Tonkawa","Tonopah","Tontitown","Tontobasin","Tontogany","Tooele","Toomsboro","Toomsuba","Toone","Topanga","Topawa","Topinabee","Topock","Toponas","Toppenish","Topping","Topsfield","Topsham","Topton","Toquerville","Torbert","Tornillo","Torreon","Torrey","Torrington","Toston","Totowa","Totz","Touchet","Tougaloo","Toughkenamon","Toulon","Toutle","Tovey","Towaco","Towanda","Towaoc","Towner","Townley","Townshend","Townsville","Townville","Toxey","Toyah","Toyahvale","Tracyton","Traer","Trafalgar","Trafford","Trampas","Tranquility","Trappe","Traskwood","Traunik","Traver","Treadway","Treadwell","Trebloc","Treece","Trego","Treichlers","Treloar","Trementina","Tremont","Tremonton","Trempealeau","Trenary","Trent","Tresckow","Trespiedras","Trespinos","Trevett","Trevor","Trevorton","Trexlertown","Treynor","Trezevant","Triadelphia","Tribbett","Tridell","Trilby","Trilla","Trimble","Trimont","Trinchera","Trinway","Trion","Tripp","Trivoli","Trona","Trosky","Trosper","Trotters","Troup","Troupsburg","Troutdale","Troutville","Troxelville","Truckee","Truesdale","Trufant","Truk","Trumann","Trumansburg","Truro","Truscott","Trussville","Truxton","Tryon","Tualatin","Tubac","Tuckahoe","Tuckasegee","Tuckerman","Tuckerton","Tucumcari","Tujunga","Tula","Tulare","Tularosa","Tuleta","Tulia","Tullahassee","Tullahoma","Tullos","Tully","Tumacacori","Tunas","Tunica","Tunkhannock","Tunnelton","Tuolumne","Tupman","Turbeville","Turbotville","Turlock","Turner","Turners","Turnersburg","Turnersville","Turnerville","Turney","Turon","Turpin","Turrell","Turtletown","Turton","Tuscarawas","Tuscola","Tuscumbia","Tuskahoma","Tussy","Tustin","Tutorkey","Tutwiler","Twinbrooks","Twining","Twinoaks","Twinsburg","Twisp","Tyaskin","Tye","Tylersburg","Tylersport","Tylersville","Tylerton","Tylertown","Tynan","Tyner","Tyngsboro","Tyro","Tyrone","Tyronza","Tyty","Ubly","Ucon","Udall","Udell","Uehling","Uhrichsville","Ukiah","Uledi","Ulen","Ullin","Ulm","Ulman","Ulmer","Umatilla","Umbarger","Umpqua","Una","Unadilla","Uncasville","Underwood","Uneeda","Unicoi","Uniondale","Unionhall","Unionport","Uniontown","Unionville","Uniopolis","United","Unityhouse","Unityville","University","Upham","Upperco","Upperville","Upsala","Upson","Uravan","Urbanna","Uriah","Urich","Ursina","Ute","Utuado","Uvalda","Uvalde","Uwchland","Vacaville","Vacherie","Vada","Vader","Vadis","Vadito","Vaiden","Valatie","Valders","Valdese","Valdez","Valdosta","Valencia","Valentines","Valera","Valier","Vallecito","Vallecitos","Vallejo","Valliant","Vallonia","Valmeyer","Valmy","Valona","Valrico","Valyermo","Vanalstyne","Vanceboro","Vanceburg","Vancourt","Vandalia","Vandemere","Vandergrift","Vanderpool","Vandervoort","Vandiver","Vanduser","Vandyne","Vanetten","Vanhorn","Vanna","Vanndale","Vannuys","Vanorin","Vansant","Vantassell","Vanvleck","Vanwert","Vanwyck","Vanzant","Vardaman","Varina","Varna","Varnell","Varney","Varnville","Varysburg","Vashon","Vass","Vassalboro","Vaucluse","Vaughnsville","Vauxhall","Veblen","Veedersburg","Vegabaja","Veguita","Velarde","Velma","Velpen","Velva","Venango","Venedocia","Venedy","Veneta","Venetia","Ventress","Ventura","Venturia","Veradale","Verbank","Verda","Verdel","Verden","Verdigre","Verdon","Verdunville","Vergas","Vergennes","Veribest","Vermillion","Vermontville","Verndale","Verner","Vernon","Vernonia","Verplanck","Vershire","Vertrees","Vesta","Vestaburg","Vesuvius","Vevay","Vian","Viborg","Vicco","Vici","Vick","Vickery","Victorville","Vidalia","Vidor","Vieques","Viewtown","Vilas","Villalba","Villamaria","Villamont","Villanova","Villanueva","Villard","Villarica","Villas","Villisca","Vilonia","Vina","Vincennes","Vincentown","Vineburg","Vineland","Vinemont","Vining","Vinita","Vinton","Vintondale","Viper","Virden","Virgie","Virgilina","Virginville","Viroqua","Visalia","Vliets","Voca","Volant","Volborg","Volga","Volin","Volney","Voluntown","Vona","Vonore","Vonormy","Voorheesville","Vossburg","Votaw","Vowinckel","Vredenburgh","Waban","Wabasha","Wabasso","Wabbaseka","Wabeno","Waccabuc","Wachapreague","Wacissa","Waconia","Waddell","Waddington","Waddy","Wadena","Wadesboro","Wadestown","Wadesville","Wadhams","Wadley","Waelder","Wagarville","Wagener","Waggoner","Wagoner","Wagontown","Wagram","Wahiawa","Wahkiacus","Wahkon","Wahoo","Wahpeton","Waialua","Waianae","Wailuku","Waimanalo","Waimea","Wainscott","Waipahu","Waiteville","Waitsburg","Waitsfield","Waka","Wakarusa","Wakeeney","Wakeman","Wakenda","Wakita","Wakonda","Wakpala","Wakulla","Walburg","Waldenburg","Waldoboro","Waldport","Waldwick","Wales","Waleska","Walford","Walhalla","Walhonding","Walker","Walkersville","Walkerton","Walkertown","Walkerville","Wallaceton","Walland","Wallback","Wallburg","Walling","Wallingford","Wallisville","Wallkill","Wallowa","Wallsburg","Wallula","Walnutshade","Walsenburg","Walshville","Walston","Walstonburg","Walterboro","Waltersburg","Walterville","Walthall","Walthourville","Waltonville","Walworth","Wamego","Wampsville","Wampum","Wamsutter","Wana","Wanakena","Wanamingo","Wanaque","Wanatah","Wanblee","Wanchese","Wanda","Wando","Waneta","Wanette","Wann","Wannaska","Wantagh","Wapakoneta","Wapanucka","Wapella","Wapello","Wappapello","Wapwallopen","Warba","Warda","Wardell","Wardensville","Wardsboro","Wardtown","Wardville","Wareham","Waresboro","Wareshoals","Waretown","Warfield","Warfordsburg","Warminster","Warne","Warner","Warners","Warnerville","Warnock","Warrendale","Warrens","Warrensburg","Warrensville","Warrenton","Warrenville","Warrington","Wartburg","Warthen","Wartrace","Wasco","Wascott","Waseca","Washita","Washougal","Washta","Washtucna","Waskish","Waskom","Wasola","Wassaic","Wasta","Wataga","Watauga","Waterboro","Waterflow","Waterford","Waterport","Watersmeet","Waterview","Waterville","Watervliet","Watha","Wathena","Watkinsville","Watonga","Watrous","Watseka","Watsontown","Watsonville","Watton","Wattsburg","Wattsville","Waubun","Wauchula","Waucoma","Wauconda","Waukau","Waukee","Waukegan","Waukesha","Waukomis","Waukon","Wauna","Waunakee","Wauneta","Waupaca","Waupun","Wauregan","Waurika","Wausa","Wausau","Wausaukee","Wauseon","Wautoma","Wauzeka","Waveland","Waverley","Waverly","Waves","Wawaka","Wawarsing","Wawina","Waxahachie","Waxhaw","Wayan","Waycross","Wayland","Waymart","Waynesboro","Waynesburg","Waynesfield","Waynesville","Waynetown","Waynoka","Wayzata","Weare","Weatherby","Weatherford","Weatherly","Weatogue","Weaubleau","Weaver","Weaverville","Webber","Webberville","Webbville","Websterville","Wedderburn","Wedgefield","Wedowee","Wedron","Weedsport","Weedville","Weeksbury","Weems","Weepingwater","Weesatche","Weidman","Weikert","Weimar","Weiner","Weinert","Weippe","Weirsdale","Weirton","Weirwood","Weiser","Weissert","Welaka","Welches","Welda","Weldona","Weleetka","Wellborn","Wellford","Welling","Wellman","Wellpinit","Wellsboro","Wellsburg","Wellston","Wellsville","Wellton","Welton","Welty","Wenatchee","Wendel","Wenden","Wendover","Wenham","Wenona","Wenonah","Wentworth","Wentzville","Weogufka","Weott","Wernersville","Wesco","Weskan","Weslaco","Wessington","Wesson","Westby","Wethersfield","Wetmore","Wetumka","Wetumpka","Wever","Wevertown","Wewahitchka","Wewela","Wewoka","Wexford","Weyanoke","Weyauwega","Weyerhaeuser","Weymouth","Whalan","Whaleysville","Whallonsburg","Wharncliffe","Whately","Wheatcroft","Wheatfield","Wheatland","Wheatley","Wheaton","Wheeler","Wheelersburg","Wheeling","Wheelock","Wheelwright","Whick","Whigham","Whipholt","Whippleville","Whiskeytown","Whitakers","Whitby","Whitebird","Whiteclay","Whitefield","Whitefish","Whiteford","Whiteheath","Whitehouse","Whiteland","Whitelaw","Whiteowl","Whitepost","Whitesboro","Whitesburg","Whiteside","Whitestone","Whitestown","Whitesville","Whitethorn","Whiteville","Whitewater","Whitewood","Whitewright","Whitfield","Whitharral","Whiting","Whitingham","Whitinsville","Whitlash","Whitleyville","Whitmer","Whitmire","Whitmore","Whitneyville","Whitsett","Whitt","Whittemore","Whitten","Whittington","Whitwell","Whon","Wibaux","Wiborg","Wickatunk","Wickenburg","Wickes","Wickett","Wickliffe","Wicomico","Wiconisco","Wideman","Widener","Widnoon","Wikieup","Wilbar","Wilber","Wilberforce","Wilbraham","Wilburn","Wilburton","Wilcoe","Wilder","Wildersville","Wildhorse","Wildie","Wildomar","Wildorado","Wildrose","Wildsville","Wildwood","Wileyville","Wilkesbarre","Wilkesboro","Wilkeson","Wilkesville","Willacoochee","Willamina","Willards","Willcox","Willernie","Willet","Williamsfield","Williamsport","Williamston","Williamstown","Williamsville","Williford","Willimantic","Willingboro","Willisburg","Williston","Willisville","Willits","Willmar","Willows","Willowshade","Willowstreet","Willowwood","Willsboro","Willseyville","Willshire","Wilmar","Wilmer","Wilmerding","Wilmette","Wilmont","Wilmore","Wilmot","Wilsall","Wilsey","Wilseyville","Wilsie","Wilsonburg","Wilsondale","Wilsons","Wilsonville","Wilton","Wimauma","Wimberley","Wimbledon","Winamac","Winburne","Winchendon","Windber","Winder","Windermere","Windham","Windom","Windthorst","Windyville","Winesburg","Winfall","Winfred","Wingdale","Winger","Wingina","Wingo","Winifrede","Winigan","Winkelman","Winlock","Winn","Winnabow","Winnebago","Winneconne","Winnemucca","Winner","Winnetoon","Winnett","Winnfield","Winnisquam","Winnsboro","Winona","Winside","Winsted","Winston","Winstonville","Winterport","Winterset","Winterthur","Winterville","Winton","Wiota","Wirtz","Wisacky","Wiscasset","Wiseman","Wishek","Wishram","Wisner","Wister","Withams","Withee","Witherbee","Witmer","Witten","Wittenberg","Wittensville","Witter","Wittman","Wittmann","Wixom","Woburn","Woden","Wolbach","Wolcottville","Wolfcoal","Wolfeboro","Wolfforth","Wolford","Wolfpen","Wolftown","Wollaston","Wolsey","Wolverine","Wolverton","Womelsdorf","Wonalancet","Wonewoc","Wonnie","Woodacre","Woodbine","Woodbourne","Woodburn","Wooddale","Woodfield","Woodford","Woodhull","Woodinville","Woodleaf","Woodlyn","Woodman","Woodmere","Woodsboro","Woodscross","Woodsfield","Woodshole","Woodson","Woodstock","Woodston","Woodstown","Woodsville","Woodville","Woodworth","Woolford","Woollum","Woolrich","Woolstock","Woolwich","Woolwine","Woonsocket","Woosung","Wooton","Worden","Worland","Worley","Woronoco","Wortham","Worthing","Worthville","Worton","Woxall","Wray","Wren","Wrens","Wrenshall","Wrentham","Wrights","Wrightsboro","Wrightstown","Wrightsville","Wrightwood","Wurtsboro","Wyaconda","Wyalusing","Wyanet","Wyano","Wyarno","Wyckoff","Wyco","Wycombe","Wyeville","Wykoff","Wylliesburg","Wymer","Wymore","Wynantskill","Wyncote","Wyndmere","Wynnburg","Wynne","Wynnewood","Wynona","Wynot","Wyocena","Wyola","Wyoming","Wysox","Wytheville","Wytopitlock","Xenia","Yabucoa","Yachats","Yacolt","Yadkinville","Yalaha","Yampa","Yancey","Yanceyville","Yankeetown","Yantic","Yantis","Yaphank","Yards","Yarnell","Yatesboro","Yatesville","Yauco","Yawkey","Yeaddiss","Yeagertown","Yellowstone","Yellville","Yelm","Yemassee","Yerington","Yermo","Yeso","Yettem","Yoakum","Yolo","Yolyn","Yoncalla","York","Yorklyn","Yorkshire","Yorkville","Yosemite","Youngsville","Youngtown","Youngwood","Yountville","Yreka","Yucaipa","Yulan","Yulee","Yuma","Yutan","Zacata","Zachariah","Zachow","Zahl","Zaleski","Zalma","Zamora","Zanesfield","Zanesville","Zanoni","Zapata","Zavalla","Zearing","Zebulon","Zeeland","Zeigler","Zelienople","Zell","Zellwood","Zenda","Zenia","Zeona","Zephyr","Zieglerville","Zillah","Zim","Zionsville","Zionville","Zirconia","Zoar","Zortman","Zullinger","Zumbrota","Zuni","Zwingle","Zwolle"];
\ No newline at end of file
+pub static PLACE: [&str; 10196] = ["Aaronsburg","Abbeville","Abbotsford","Abbottstown","Abbyville","Abell","Abercrombie","Abernant","Abilene","Abingdon","Abington","Abiquiu","Abrams","Absaraka","Absarokee","Absecon","Acampo","Accokeek","Accomac","Accoville","Achille","Ackerly","Ackermanville","Ackworth","Acosta","Acra","Acushnet","Acworth","Adah","Adairsville","Adairville","Adamsbasin","Adamsburg","Adamstown","Adamsville","Addieville","Addington","Addy","Addyston","Adel","Adelanto","Adell","Adelphi","Adelphia","Adena","Adger","Adin","Adjuntas","Adna","Adona","Aflex","Afton","Agana","Agar","Agawam","Agness","Agra","Aguada","Aguadilla","Aguadulce","Aguanga","Aguila","Aguilar","Aguirre","Ahgwahching","Ahmeek","Ahoskie","Ahsahka","Ahwahnee","Aibonito","Aiea","Ailey","Aimwell","Ainsworth","Airville","Aitkin","Ajo","Akaska","Akeley","Akiachak","Akiak","Akutan","Alachua","Aladdin","Alakanuk","Alamance","Alamogordo","Alamosa","Alamota","Alanreed","Alanson","Alapaha","Albee","Albemarle","Albers","Albertlea","Alberton","Albertson","Albertville","Albia","Albin","Albion","Alborn","Alburg","Alburnett","Alburtis","Alcalde","Alcester","Alco","Alcolu","Alcova","Alda","Aldenville","Alderson","Aldie","Aledo","Aleknagik","Aleppo","Alford","Algoma","Algona","Algonac","Aliceville","Alief","Aline","Aliquippa","Alix","Alkabo","Alkol","Allamuchy","Allardt","Alledonia","Alleene","Allegan","Allegany","Alleghany","Allegre","Alleman","Allendale","Allendorf","Allenhurst","Allenport","Allensville","Allenton","Allenwood","Allerton","Allgood","Allock","Allons","Allouez","Alloway","Allport","Allred","Almena","Almira","Almo","Almont","Almyra","Alna","Alpaugh","Alpena","Alpharetta","Alpoca","Alsea","Alsen","Alsey","Alstead","Alston","Alta","Altadena","Altaloma","Altamahaw","Altamont","Altenburg","Altha","Altheimer","Altmar","Altona","Altoona","Altro","Altura","Alturas","Altus","Alumbank","Alvada","Alvadore","Alvarado","Alvaton","Alverda","Alverton","Alviso","Alvo","Alvord","Alvordton","Alvy","Alzada","Amado","Amagansett","Amagon","Amalia","Amana","Amanda","Amasa","Amawalk","Amazonia","Amberg","Amberson","Ambia","Ambler","Amboy","Amburgey","Ameagle","Amenia","Americus","Amery","Amesbury","Amesville","Amherstdale","Amidon","Amiret","Amissville","Amistad","Amite","Amityville","Amlin","Amma","Amo","Amonate","Amoret","Amorita","Amory","Amsden","Amston","Anacoco","Anacortes","Anadarko","Anahola","Anahuac","Analomink","Anamoose","Anamosa","Anasco","Anatone","Anawalt","Anchorville","Anco","Ancona","Ancram","Ancramdale","Andale","Andalusia","Andersonville","Andreas","Aneta","Aneth","Angier","Angleinlet","Angleton","Anguilla","Angwin","Aniak","Animas","Aniwa","Ankeny","Anmoore","Annada","Annamaria","Annandale","Annarbor","Annawan","Annemanie","Anniston","Annona","Annville","Anoka","Ansley","Anson","Ansonia","Ansonville","Ansted","Antesfort","Anthon","Antigo","Antlers","Antonchico","Antonito","Antrim","Anvik","Anza","Apalachicola","Apalachin","Apison","Aplington","Apopka","Appling","Appomattox","Aptos","Aquasco","Aquashicola","Aquebogue","Aquilla","Aquone","Arabi","Aragon","Arapaho","Arapahoe","Ararat","Arbela","Arboles","Arbon","Arbovale","Arbuckle","Arbyrd","Arcanum","Arcata","Archbald","Archbold","Archcape","Archie","Arco","Arcola","Ardara","Ardenvoir","Ardmore","Ardoch","Ardsley","Arecibo","Aredale","Arendtsville","Arenzville","Argenta","Argillite","Argo","Argonia","Argos","Argusville","Argyle","Ariel","Arimo","Arion","Aripeka","Arispe","Aristes","Ariton","Arivaca","Arjay","Arkabutla","Arkadelphia","Arkansaw","Arkdale","Arkoma","Arkport","Arkville","Arlee","Arley","Arma","Armagh","Armbrust","Armington","Armona","Armorel","Armuchee","Arnaudville","Arnegard","Arnett","Arnoldsburg","Arnoldsville","Arnot","Arock","Aroda","Aromas","Arona","Arp","Arpin","Arrey","Arriba","Arrington","Arrowsmith","Artas","Artemas","Artemus","Artesia","Artesian","Arthurdale","Artois","Arvada","Arvilla","Arvin","Arvonia","Ary","Asbury","Asco","Ascutney","Ashaway","Ashburn","Ashburnham","Ashby","Ashdown","Asheboro","Asherton","Ashfield","Ashford","Ashippun","Ashkum","Ashmore","Ashtabula","Ashton","Ashuelot","Ashville","Ashwood","Askov","Asotin","Aspermont","Aspers","Assaria","Assawoman","Assonet","Astatula","Atalissa","Atascadero","Atascosa","Atco","Atglen","Athelstane","Athol","Atlasburg","Atmore","Atoka","Attalla","Attapulgus","Attleboro","Auberry","Auburndale","Auburntown","Augres","Aulander","Ault","Aultman","Aumsville","Aurelia","Austell","Austerlitz","Austinburg","Austinville","Austwell","Autaugaville","Autrain","Autryville","Auxier","Auxvasse","Ava","Avalon","Avant","Avawam","Avella","Avenal","Avenel","Avera","Avilla","Avinger","Aviston","Avoca","Avondale","Avonmore","Awendaw","Axson","Axtel","Axtell","Axton","Ayden","Aydlett","Ayer","Aylett","Aynor","Ayr","Ayrshire","Azalia","Azle","Azusa","Babb","Bache","Backus","Bacliff","Baconton","Bacova","Badaxe","Badger","Badin","Bagdad","Baggs","Bagwell","Baileyton","Baileyville","Bains","Bainville","Bairdford","Bairoil","Baisden","Bajadero","Baker","Bakerstown","Bakersville","Bakerton","Bakewell","Bala","Balaton","Balch","Baldwinsville","Baldwinville","Baldwyn","Balko","Ballantine","Ballengee","Ballentine","Ballico","Ballinger","Ballouville","Ballwin","Bally","Balmat","Balmorhea","Balta","Bamberg","Banco","Bancroft","Bandana","Bandera","Bandon","Bandytown","Bangall","Bangs","Bankston","Banner","Banning","Bannister","Bannock","Banquete","Bantry","Baptistown","Baraboo","Baraga","Barataria","Barbeau","Barberton","Barberville","Barboursville","Barbourville","Barceloneta","Barco","Bardolph","Bardstown","Bardwell","Bargersville","Barhamsville","Baring","Barker","Barksdale","Barling","Barnardsville","Barnegat","Barnesboro","Barneston","Barnesville","Barneveld","Barnhart","Barnsdall","Barnstable","Barnstead","Barnum","Barnwell","Baroda","Barrackville","Barranquitas","Barree","Barron","Barronett","Barryton","Barrytown","Barryville","Bartelso","Bartlesville","Bartley","Barto","Bartonsville","Bartow","Barwick","Basco","Bascom","Basehor","Basile","Baskerville","Baskett","Baskin","Basom","Bassfield","Bastian","Bastrop","Basye","Batchelor","Batchtown","Batesburg","Batesland","Batesville","Batson","Battiest","Battleboro","Battletown","Baudette","Bausman","Bavon","Baxley","Bayamon","Bayard","Bayboro","Bayfield","Baylis","Bayminette","Bayougoula","Baypines","Bays","Bayshore","Bayside","Baytown","Bayview","Bayville","Bazine","Beachwood","Beaconsfield","Bealeton","Beallsville","Beals","Beaman","Bearden","Beardstown","Bearsville","Beasley","Beason","Beattie","Beatty","Beattyville","Beaufort","Beaverdale","Beaverlett","Beaverton","Beavertown","Beaverville","Bebe","Beccaria","Bechtelsville","Beckemeyer","Beckley","Beckville","Beckwith","Bedias","Bedminster","Beechbottom","Beecher","Beechmont","Beedeville","Beehouse","Beeler","Beemer","Beeson","Beetown","Beeville","Beggs","Beirne","Bejou","Belair","Belalton","Belcher","Belchertown","Belcourt","Belden","Beldenville","Belding","Belen","Belfair","Belfield","Belford","Belington","Belk","Belknap","Bellaire","Bellarthur","Bellbrook","Bellbuckle","Bellechasse","Bellefonte","Bellemead","Bellemina","Belleplaine","Bellerive","Bellerose","Bellevernon","Belleview","Belleville","Bellevue","Bellmont","Bellmore","Bellona","Bellport","Bells","Bellvale","Bellville","Bellvue","Bellwood","Belmar","Belmond","Belpre","Belsano","Belton","Beltrami","Belva","Belvedere","Belview","Belvue","Belzoni","Bement","Bemidji","Bena","Benarnold","Benavides","Bendena","Bendersville","Benedicta","Benezett","Benge","Benham","Benhur","Benicia","Benkelman","Benld","Benlomond","Bennet","Bennettsville","Benoit","Bensalem","Bensenville","Bentleyville","Bentonia","Bentonville","Bentree","Benwood","Benzonia","Beowawe","Berclair","Bergenfield","Berger","Bergholz","Bergoo","Bergton","Berkey","Berkley","Bernalillo","Bernardston","Bernardsville","Bernville","Beroun","Berrysburg","Berryton","Berryville","Berthold","Berthoud","Berwind","Berwyn","Bethalto","Bethania","Bethanna","Bethany","Bethera","Bethpage","Bethune","Bettendorf","Betterton","Bettsville","Beulah","Beulaville","Bevier","Bevington","Bevinsville","Bexar","Beyer","Bickleton","Bickmore","Bicknell","Biddeford","Biddle","Bidwell","Bieber","Bienville","Billerica","Billingsley","Biloxi","Bim","Bimble","Binford","Bingen","Binger","Biola","Bippus","Birchdale","Birchleaf","Birchrunville","Birchtree","Birchwood","Birdeye","Birdinhand","Birds","Birdsboro","Birdseye","Birnamwood","Birney","Birome","Bisbee","Biscoe","Bishopville","Bitely","Bittinger","Bivins","Biwabik","Bixby","Blachly","Blackduck","Blackey","Blackfoot","Blackford","Blackhawk","Blacklick","Blacksburg","Blackshear","Blackstock","Blacksville","Blackville","Blackwater","Blackwood","Bladen","Bladenboro","Bladensburg","Blain","Blairs","Blairsburg","Blairsden","Blairstown","Blairsville","Blaisdell","Blakely","Blakesburg","Blakeslee","Blanca","Blanchester","Blanco","Blandburg","Blandford","Blandinsville","Blandon","Blandville","Blanford","Blanks","Blauvelt","Blawenburg","Bledsoe","Bleiblerville","Blencoe","Blenker","Blessing","Blevins","Blissfield","Blocker","Blocksburg","Blockton","Blodgett","Blomkest","Bloomburg","Bloomdale","Bloomer","Bloomery","Bloomingburg","Bloomingdale","Bloomingrose","Bloomsburg","Bloomsbury","Bloomsdale","Bloomville","Blossburg","Blossvale","Blount","Blountstown","Blountsville","Blountville","Bloxom","Blueball","Bluebell","Blueeye","Bluefield","Bluehole","Bluejay","Bluemont","Bluewater","Bluffdale","Bluffs","Bluffton","Bluford","Bly","Blythedale","Blytheville","Blythewood","Boalsburg","Boardman","Boaz","Bobtown","Bobwhite","Bodega","Bodfish","Boelus","Boerne","Bogalusa","Bogard","Bogart","Bogata","Boggs","Boggstown","Bogue","Boguechitto","Bohannon","Boiceville","Boisdarc","Boissevain","Bokchito","Bokeelia","Bokoshe","Bolckow","Boles","Boley","Boligee","Bolinas","Boling","Bolingbroke","Bolinger","Bomont","Bomoseen","Bonair","Bonaire","Bonaqua","Boncarbo","Bondsville","Bonduel","Bondurant","Bondville","Bonesteel","Boneville","Bonfield","Bonham","Bonifay","Bonita","Bonlee","Bonneau","Bonner","Bonnerdale","Bonneterre","Bonnieville","Bonnyman","Bono","Bonsall","Bonsecour","Bonwier","Boody","Booker","Boomer","Booneville","Boonsboro","Boonton","Boonville","Boothville","Boqueron","Bordelonville","Bordentown","Bordulac","Borger","Boring","Borup","Boscobel","Bosler","Bosque","Bostic","Bostwick","Bosworth","Bothell","Botkins","Botsford","Bottineau","Bouckville","Boundbrook","Bountiful","Bourbonnais","Bourg","Bourneville","Bouse","Bouton","Boutte","Bovard","Bovey","Bovill","Bovina","Bowbells","Bowden","Bowdle","Bowdoinham","Bowdon","Bowers","Bowerston","Bowersville","Bowlegs","Bowler","Bowlus","Bowmansdale","Bowmanstown","Bowmansville","Boxelder","Boxford","Boxholm","Boyceville","Boyden","Boyds","Boydton","Boyers","Boyertown","Boyes","Boykin","Boykins","Boynton","Boystown","Bozeman","Bozman","Bozoo","Bozrah","Braceville","Bracey","Brackettville","Brackney","Braddock","Braddyville","Braden","Bradenton","Bradenville","Bradleyville","Bradner","Bradyville","Braggadocio","Braggs","Braham","Braidwood","Brainerd","Braintree","Braithwaite","Braman","Bramwell","Branchdale","Branchland","Branchport","Branchton","Branchville","Brandamore","Brandonville","Brandsville","Branford","Branson","Brantingham","Brantley","Brantwood","Braselton","Brashear","Brasstown","Brattleboro","Brawley","Braxton","Braymer","Brayton","Brazeau","Brazoria","Brea","Breaks","Breda","Breeden","Breeding","Breedsville","Breese","Breesport","Breezewood","Breinigsville","Bremerton","Bremond","Brenham","Brentford","Brenton","Brentwood","Bretz","Brevard","Brewer","Brewerton","Brewton","Brianhead","Bricelyn","Briceville","Brickeys","Bridgehampton","Bridgeland","Bridger","Bridgeton","Bridgeville","Bridgman","Bridgton","Bridport","Brielle","Brierfield","Briggsdale","Briggsville","Brightwood","Brill","Brillion","Brimfield","Brimhall","Brimley","Brimson","Bringhurst","Brinkley","Brinklow","Brinktown","Brinnon","Brinsmade","Brinson","Brisbin","Briscoe","Bristolville","Bristow","Britt","Britton","Brixey","Broadalbin","Broadbent","Broadbrook","Broaddus","Broadford","Broadlands","Broadrun","Broadus","Broadview","Broadwater","Broadwell","Brocket","Brockport","Brockton","Brockway","Brockwell","Brocton","Broderick","Brodhead","Brodnax","Brogan","Brogue","Brohard","Brohman","Brokaw","Brokenbow","Bronaugh","Bronson","Bronston","Bronte","Bronwood","Brookdale","Brookeland","Brooker","Brookesmith","Brookeville","Brookfield","Brookings","Brookland","Brooklet","Brooklin","Brookneal","Brookport","Brooks","Brookshire","Brookston","Brooksville","Brookton","Brooktondale","Brookview","Brookville","Brookwood","Broomall","Broomfield","Brooten","Broseley","Brothers","Broughton","Broussard","Browder","Browerville","Brownfield","Browning","Brownlee","Browns","Brownsboro","Brownsburg","Brownsdale","Brownstown","Brownsville","Brownton","Browntown","Brownville","Brownwood","Broxton","Bruceton","Brucetown","Bruceville","Bruin","Bruington","Brule","Brumley","Brundidge","Bruneau","Bruner","Bruni","Bruning","Brunson","Brunsville","Brusett","Brushton","Brusly","Brutus","Bryantown","Bryantsville","Bryantville","Bryceland","Bryceville","Brynathyn","Brynmawr","Bryson","Buchtel","Buckatunna","Buckeystown","Buckfield","Buckhannon","Buckhead","Buckholts","Buckingham","Buckland","Bucklin","Buckman","Buckner","Bucks","Bucksport","Bucoda","Bucyrus","Buda","Bude","Bueche","Buellton","Bueyeros","Buford","Buhl","Buhler","Bula","Bulan","Bulger","Bullard","Bullshoals","Bullville","Bulpitt","Buna","Bunceton","Buncombe","Bunker","Bunkerville","Bunkie","Bunn","Bunnell","Bunola","Buras","Burchard","Burdett","Burdette","Burdick","Burdine","Burfordville","Burgaw","Burgettstown","Burgin","Burgoon","Burkburnett","Burkesville","Burket","Burkett","Burkettsville","Burkeville","Burkhart","Burkittsville","Burkville","Burleson","Burlingame","Burlingham","Burlison","Burna","Burnet","Burnettsville","Burney","Burneyville","Burnips","Burns","Burnsville","Burntcorn","Burnwell","Burrows","Burrton","Burson","Burtonsville","Burtrum","Burwell","Busby","Bushkill","Bushland","Bushton","Bushwood","Buskirk","Bussey","Butlerville","Butner","Butters","Buttonwillow","Buttzville","Byars","Bybee","Byesville","Byfield","Byhalia","Bylas","Bynum","Bypro","Byrdstown","Byrnedale","Byromville","Caballo","Cabazon","Cabery","Cabins","Cabool","Caborojo","Caddo","Cade","Cades","Cadiz","Cadmus","Cadogan","Cadott","Cadwell","Cadyville","Caguas","Cahokia","Cahone","Cainsville","Cairnbrook","Calabasas","Cale","Caledonia","Calera","Calexico","Calhan","Caliente","Califon","Calio","Calion","Calipatria","Calistoga","Callands","Callao","Callaway","Callender","Callensburg","Callery","Callicoon","Calliham","Calmar","Calverton","Camak","Camanche","Camargo","Camarillo","Camas","Cambra","Cambria","Cambridgeport","Camby","Camden","Camdenton","Camillus","Cammal","Campbellsburg","Campbellton","Campbelltown","Campo","Campobello","Campti","Campton","Camptonville","Camptown","Campwood","Camuy","Cana","Canadensis","Canadys","Canajoharie","Canalou","Canandaigua","Canaseraga","Canastota","Canby","Candia","Candler","Cando","Candor","Caneadea","Canebrake","Caney","Caneyville","Canisteo","Canistota","Canjilon","Canmer","Cannelburg","Cannelton","Cannonsburg","Cannonville","Canones","Canonsburg","Canova","Canovanas","Cantil","Cantonment","Cantrall","Cantril","Canute","Canutillo","Canyonville","Capac","Capefair","Capels","Capemay","Capeneddick","Capeville","Capitan","Capitola","Capron","Capshaw","Captaincook","Captiva","Capulin","Caputa","Caratunk","Carbonado","Carboncliff","Cardale","Cardin","Cardington","Cardville","Cardwell","Carencro","Caretta","Careywood","Carland","Carlile","Carlinville","Carlock","Carlos","Carlotta","Carlsbad","Carlsborg","Carman","Carmel","Carmi","Carmichaels","Carnarvon","Carnesville","Caro","Caroleen","Carona","Carpinteria","Carpio","Carrabelle","Carrboro","Carrier","Carriere","Carrington","Carrizozo","Carrolls","Carrollton","Carrolltown","Carrothers","Carrsville","Carsonville","Carter","Carteret","Cartersburg","Cartersville","Carterville","Cartwright","Caruthers","Carver","Carversville","Carville","Cary","Caryville","Casa","Casar","Cascadia","Cascilla","Casco","Caseville","Caseyville","Cashiers","Cashion","Cashton","Cashtown","Casmalia","Casnovia","Cason","Casper","Cass","Cassadaga","Cassatt","Casscoe","Cassel","Casselberry","Casselton","Cassoday","Cassopolis","Casstown","Cassville","Castalia","Castana","Castanea","Castell","Castella","Castile","Castine","Castleberry","Castledale","Castleford","Castleton","Castlewood","Castorland","Castroville","Cataldo","Catano","Catarina","Catasauqua","Cataula","Cataumet","Catawissa","Catharine","Catharpin","Cathay","Cathlamet","Catlett","Catlettsburg","Catlin","Cato","Catoosa","Catron","Cattaraugus","Caulfield","Causey","Cauthornville","Cavetown","Cavour","Cawood","Cayey","Cayucos","Cayuse","Cayuta","Cazadero","Cazenovia","Cebolla","Cecilton","Cedarbrook","Cedarburg","Cedarcrest","Cedaredge","Cedarhurst","Cedarkey","Cedarlane","Cedars","Cedartown","Cedarvale","Cedarville","Ceevee","Ceiba","Celestine","Celina","Celoron","Cementon","Centrahoma","Centralia","Centre","Centrehall","Centreville","Centuria","Ceredo","Ceresco","Cerrillos","Cerritos","Cerro","Cerrogordo","Chacon","Chadbourn","Chaddsford","Chadron","Chadwicks","Chaffee","Chalfont","Challis","Chalmette","Chama","Chamberino","Chambersburg","Chambersville","Chamisal","Champlin","Chana","Chandlerville","Changewater","Chanhassen","Channahon","Channing","Chanute","Chapin","Chapmansboro","Chapmanville","Chappaqua","Chappell","Chappells","Chaptico","Chardon","Charenton","Chariton","Charlemont","Charleroi","Charlestown","Charlevoix","Charlo","Charlton","Charmco","Chartley","Chaseburg","Chaseley","Chaska","Chassell","Chataignier","Chatawa","Chateaugay","Chatfield","Chatom","Chatsworth","Chattahoochee","Chattaroy","Chaumont","Chauvin","Chavies","Chazy","Cheapside","Chebanse","Cheboygan","Checotah","Chefornak","Chehalis","Chelan","Chelmsford","Chelsea","Cheltenham","Chemult","Chemung","Cheneyville","Chenoa","Chepachet","Cheraw","Cheriton","Cherryfield","Cherrylog","Cherrytree","Cherryville","Chesaning","Chesnee","Chesterfield","Chesterland","Chestertown","Chesterville","Cheswick","Cheswold","Chetek","Chetopa","Chevak","Chewalla","Chewelah","Chewsville","Cheyney","Chichester","Chickamauga","Chickasha","Chico","Chicopee","Chicora","Chicota","Chidester","Chiefland","Chignik","Chilcoot","Childersburg","Childress","Childs","Childwold","Chilhowee","Chilhowie","Chillicothe","Chilmark","Chilo","Chiloquin","Chilton","Chimacum","Chimayo","Chincoteague","Chinle","Chino","Chipley","Chireno","Chitina","Chittenango","Chittenden","Chivington","Chloe","Choccolocco","Chocorua","Chocowinity","Chokio","Chokoloskee","Cholame","Choteau","Choudrant","Chouteau","Chowchilla","Chriesman","Chrisman","Chrisney","Christiansted","Christoval","Chromo","Chualar","Chuckey","Chugiak","Chugwater","Chula","Chunchula","Churchton","Churchville","Churdan","Churubusco","Ciales","Cibolo","Cidra","Cima","Cimarron","Cincinnatus","Cinda","Cinebar","Circleville","Cisco","Cisne","Citra","Citronelle","Clackamas","Claflin","Clairfield","Clairton","Clancy","Clanton","Clarcona","Claremore","Clarinda","Clarington","Clarion","Clarissa","Clarita","Clarkdale","Clarkedale","Clarkesville","Clarkfield","Clarkia","Clarks","Clarksboro","Clarksburg","Clarksdale","Clarkson","Clarkston","Clarksville","Clarkton","Claryville","Clatonia","Clatskanie","Claudville","Claunch","Claverack","Clawson","Claxton","Clayhole","Claymont","Claypool","Claysburg","Claysville","Claytonville","Clayville","Clearbrook","Clearfield","Clearmont","Clearview","Clearville","Cleaton","Cleburne","Cleelum","Cleghorn","Clementon","Clements","Clemmons","Clemons","Clendenin","Clermont","Cleverdale","Cleves","Clewiston","Cliffwood","Clifty","Clinchco","Clinchfield","Clintondale","Clintonville","Clintwood","Clitherall","Clockville","Clontarf","Clopton","Cloquet","Closplint","Closter","Cloudcroft","Cloutierville","Clover","Cloverdale","Cloverport","Clovis","Clubb","Clune","Clute","Clutier","Clyman","Clymer","Clyo","Coachella","Coahoma","Coaldale","Coalfield","Coalgood","Coaling","Coalinga","Coalmont","Coalport","Coalton","Coalville","Coalwood","Coamo","Coarsegold","Coatesville","Coats","Coatsburg","Coatsville","Cobbtown","Cobden","Cobleskill","Coburn","Cochecton","Cochise","Cochranton","Cochranville","Cockeysville","Cocolalla","Cocolamus","Codell","Coden","Codorus","Coeburn","Coello","Coeymans","Coffeen","Coffeeville","Coffeyville","Cofield","Coggon","Cogswell","Cohagen","Cohasset","Cohoctah","Cohocton","Cohoes","Cohutta","Coila","Coinjock","Cokato","Cokeburg","Cokedale","Coker","Cokeville","Colbert","Colburn","Colchester","Colcord","Coldbrook","Colden","Coldwater","Colebrook","Colerain","Coleraine","Colesburg","Coleta","Coleville","Colfax","Collbran","Collettsville","Colleyville","Colliers","Colliersville","Collierville","Collingswood","Collinston","Collinsville","Collinwood","Collison","Collyer","Colman","Colmar","Colmesneil","Colo","Coloma","Colome","Colona","Colora","Colp","Colquitt","Colrain","Colstrip","Colton","Columbiana","Columbiaville","Colusa","Colver","Colville","Colwich","Combes","Combs","Comer","Comerio","Comfrey","Comins","Commack","Commiskey","Como","Comptche","Comstock","Conasauga","Conaway","Concan","Concepcion","Concho","Conconully","Concordia","Concordville","Conda","Conde","Condon","Conehatta","Conejos","Conestee","Conesus","Conesville","Conetoe","Confluence","Conger","Congers","Congerville","Conneaut","Conneautville","Connell","Connellsville","Connelly","Connersville","Connerville","Conover","Conowingo","Conran","Conrath","Conroe","Conroy","Conshohocken","Constable","Constantia","Contoocook","Conyers","Conyngham","Cookeville","Cooks","Cooksburg","Cookson","Cookstown","Cooksville","Cookville","Cooleemee","Coolin","Coolville","Cooper","Coopersburg","Cooperstown","Coopersville","Coosa","Coosada","Cooter","Copake","Copan","Copemish","Copeville","Copiague","Coplay","Coppell","Copperopolis","Coquille","Cora","Coralville","Coram","Coraopolis","Corapeake","Corbettsville","Corbin","Cordele","Cordell","Corder","Cordesville","Cordova","Corea","Corfu","Corinna","Corinne","Corley","Cornersville","Cornettsville","Corning","Cornland","Cornlea","Cornville","Cornwallville","Corolla","Corozal","Corrales","Correll","Corrigan","Corriganville","Corry","Corryton","Corsica","Corsicana","Corson","Cortaro","Cortemadera","Cortez","Corton","Corunna","Corwith","Cory","Corydon","Cosby","Coscob","Coshocton","Cosmopolis","Cossayuna","Costigan","Costilla","Cotati","Coteau","Cotesfield","Cotolaurel","Cotopaxi","Cottageville","Cottekill","Cotter","Cottle","Cottleville","Cottondale","Cottonport","Cottonton","Cottontown","Cotuit","Cotulla","Couderay","Coudersport","Coulters","Coulterville","Counce","Coupeville","Coupland","Courtenay","Courtland","Courtois","Coushatta","Covel","Covelo","Covena","Covesville","Covina","Covington","Cowanesque","Cowansville","Cowarts","Cowden","Cowdrey","Cowen","Coweta","Cowgill","Cowiche","Cowlesville","Cowley","Coxsackie","Coyanosa","Coyle","Coyville","Cozad","Craborchard","Crabtree","Craddockville","Craftsbury","Cragford","Cragsmoor","Craigmont","Craigsville","Craigville","Craley","Cramerton","Cranbury","Crandon","Cranesville","Cranks","Crapo","Crary","Craryville","Crawfordville","Crawley","Crayne","Craynor","Creede","Creedmoor","Creighton","Crenshaw","Creola","Cresbard","Cresco","Cressey","Cresskill","Cresson","Cressona","Crestline","Creston","Crestone","Crestwood","Creswell","Crewe","Crichton","Criders","Crimora","Crisfield","Crittenden","Critz","Crivitz","Crocheron","Crocker","Crocketville","Crofton","Croghan","Cromona","Crooks","Crookston","Crooksville","Cropsey","Cropseyville","Cropwell","Crosbyton","Crossett","Crossnore","Crossroads","Crossville","Crosswicks","Croswell","Crothersville","Croton","Crouse","Crouseville","Crowder","Crowell","Crowheart","Crownsville","Crowville","Crozet","Crozier","Cruger","Crum","Crumpler","Crumpton","Crumrod","Cubage","Cubero","Cubrun","Cuchillo","Cudahy","Cuddy","Cuero","Cuervo","Culberson","Culdesac","Culebra","Cullen","Culleoka","Cullman","Culloden","Cullom","Cullowhee","Culpeper","Cumbola","Cumby","Cummaquid","Cumming","Cummington","Cundiff","Cuney","Cupertino","Curdsville","Curllsville","Currie","Currituck","Curryville","Curtice","Curtin","Curtiss","Curtisville","Curwensville","Cusick","Cusseta","Custar","Cutbank","Cutchogue","Cuthbert","Cutshin","Cuttingsville","Cuttyhunk","Cutuno","Cuyama","Cuyler","Cuzzart","Cygnet","Cynthiana","Cynwyd","Cypressinn","Dabneys","Dabolt","Dacoma","Dacono","Dacula","Dadeville","Dafter","Daggett","Dagmar","Dagsboro","Dagusmines","Dahinda","Dahlgren","Dahlonega","Daingerfield","Daisetta","Daisy","Daisytown","Dalbo","Daleville","Dalhart","Dallardsville","Dalmatia","Damar","Damariscotta","Dameron","Danboro","Danby","Danciger","Danese","Danevang","Danforth","Dania","Daniels","Danielsville","Dannebrog","Dannemora","Dansville","Danvers","Danville","Darby","Dardanelle","Darden","Darfur","Darien","Darlington","Darragh","Darrington","Darrouzett","Darrow","Dassel","Datil","Datto","Davant","Davey","Davidsonville","Davidsville","Davilla","Davin","Davisboro","Davisburg","Daviston","Davisville","Dawes","Dawmont","Dawsonville","Dayhoit","Daykin","Dayville","Dazey","DeKalb","Deale","Deansboro","Deanville","Dearing","Dearmanville","Deary","Deatsville","Deaver","Debary","Debeque","Deberry","Debord","Decaturville","Decherd","Deckerville","Declo","Decorah","Dedham","Deedsville","Deepwater","Deerbrook","Deerfield","Deering","Deersville","Deerton","Deerwood","Deeth","Deferiet","Defiance","Defoe","Deford","Degraff","Dehue","Delafield","Delancey","Deland","Delanson","Delaplaine","Delaplane","Delavan","Delbarton","Delcambre","Delco","Deleon","Delevan","Dellroy","Dellslow","Delmar","Delmita","Delmont","Delnorte","Deloit","Delong","Delphia","Delphos","Delray","Delrey","Delrio","Deltaville","Delton","Delvalle","Dema","Demarest","Deming","Demopolis","Demorest","Demossville","Demotte","Dempster","Denair","Denbigh","Denbo","Dendron","Denham","Denhoff","Denio","Denison","Dennard","Dennison","Dennisport","Denniston","Dennisville","Dennysville","Densmore","Denville","Depauville","Depauw","Depere","Depew","Depeyster","Depoy","Depue","Dequeen","Derbyline","Derma","Dermott","Derry","Deruyter","Derwent","Desarc","Descanso","Desdemona","Desha","Deshler","Deslacs","Desmet","Desmoines","Desoto","Destin","Destrehan","Devault","Devers","Deville","Devine","Devol","Dewart","Deweese","Deweyville","Dewittville","Dewyrose","Deyoung","Dhanis","Diablo","Diamondville","Diaz","Diboll","Dickeyville","Dierks","Dieterich","Diggins","Diggs","Dighton","Digiorgio","Dike","Dillard","Dille","Diller","Dilley","Dilliner","Dillingham","Dillonvale","Dillsboro","Dillsburg","Dilltown","Dillwyn","Dilworth","Dimebox","Dimmitt","Dimock","Dimondale","Dingess","Dingle","Dingus","Dinuba","Dinwiddie","Disputanta","Dittmer","Divernon","Dix","Dixfield","Dixiana","Dixmont","Dixonville","Dizney","Dlo","Dobbins","Docena","Doddsville","Dodgeville","Doerun","Dogpatch","Dogue","Dola","Doland","Dolgeville","Dolliver","Dolph","Dolton","Donaana","Donalds","Donalsonville","Donegal","Dongola","Donie","Doniphan","Donnellson","Donnelsville","Donora","Doole","Doon","Doran","Dorena","Dorloo","Dornsife","Dorr","Dorrance","Dorris","Dorsey","Dorton","Dospalos","Doss","Doswell","Dothan","Doty","Doucette","Douds","Douglassville","Douglasville","Dousman","Dover","Dovray","Dowagiac","Dowell","Dowelltown","Downieville","Downingtown","Downsville","Dows","Doylestown","Doyline","Doyon","Dozier","Dracut","Draffin","Drakesboro","Drakesville","Draper","Drasco","Dravosburg","Drayden","Drayton","Drennen","Dresden","Dresser","Drewryville","Drewsey","Dreyfus","Drifting","Drifton","Driftwood","Driggs","Drummonds","Drumore","Drumright","Drums","Dryprong","Duanesburg","Duarte","Dubach","Dubberly","Dubois","Dubre","Dubuque","Duchesne","Ducktown","Duckwater","Ducor","Duenweg","Duewest","Duffau","Duffield","Dufur","Dugger","Dugspur","Dugway","Dulac","Dulce","Dulzura","Dumas","Dumfries","Dumont","Duncannon","Duncansville","Duncanville","Duncombe","Dundas","Dundee","Dunellen","Dunfermline","Dungannon","Dunkerton","Dunlevy","Dunlo","Dunlow","Dunmor","Dunmore","Dunnegan","Dunnell","Dunnellon","Dunnigan","Dunning","Dunnsville","Dunnville","Dunreith","Dunseith","Dunsmuir","Dunstable","Dunwoody","Duplessis","Dupo","Dupree","Dupuyer","Duquoin","Duran","Durand","Durant","Durbin","Durhamville","Dushore","Duson","Dustin","Dutchtown","Dutzow","Duvall","Duxbury","Dwale","Dycusburg","Dyersburg","Dyersville","Dyess","Dysart","Eads","Eagar","Eagarville","Eaglesmere","Eagletown","Eagleville","Eakly","Earle","Earleton","Earleville","Earlham","Earlimart","Earling","Earlington","Earlsboro","Earlton","Earlville","Early","Earlysville","Earp","Easley","Eatonton","Eatontown","Eatonville","Eauclaire","Eaugalle","Ebenezer","Ebensburg","Ebervale","Ebeye","Ebro","Echola","Echols","Eckelson","Eckerman","Eckert","Eckerty","Eckley","Eckman","Ecorse","Ecru","Ector","Edcouch","Eddyville","Edelstein","Edenton","Edenville","Edgard","Edgarton","Edgartown","Edgefield","Edgeley","Edgemont","Edgemoor","Edgewater","Edgewood","Edina","Edinboro","Edinburg","Edmeston","Edmon","Edmond","Edmonson","Edmore","Edneyville","Edon","Edroy","Edson","Edwall","Edwardsburg","Edwardsport","Edwardsville","Eek","Effingham","Efland","Egegik","Egeland","Eggleston","Eglon","Egnar","Ehrenberg","Ehrhardt","Eidson","Eitzen","Ekalaka","Ekron","Ekwok","Eland","Elbe","Elberfeld","Elberon","Elbert","Elberta","Elberton","Elbing","Elburn","Elcho","Elco","Eldena","Elderon","Eldersville","Elderton","Eldred","Eleele","Eleroy","Eleva","Elfers","Eliasville","Elida","Elizabethton","Elizabethtown","Elizaville","Elkader","Elkhorn","Elkin","Elkins","Elkland","Elkmont","Elkmound","Elko","Elkport","Elkton","Elkview","Elkville","Elkwood","Ellabell","Ellamore","Ellaville","Ellenboro","Ellenburg","Ellendale","Ellensburg","Ellenton","Ellenville","Ellenwood","Ellerbe","Ellerslie","Ellery","Ellettsville","Ellicottville","Ellijay","Ellinger","Ellington","Ellinwood","Elliottsburg","Elliottville","Ellisburg","Elliston","Ellisville","Elloree","Ellsinore","Ellston","Elma","Elmaton","Elmdale","Elmendorf","Elmhall","Elmmott","Elmo","Elmonte","Elmora","Elmore","Elmwood","Elnora","Elora","Eloy","Elrod","Elroy","Elsa","Elsah","Elsberry","Elsmere","Elsmore","Elvaston","Elverson","Elverta","Elwell","Elwin","Elwood","Elyria","Elysburg","Embudo","Emden","Emeigh","Emelle","Emerado","Emigsville","Eminence","Emington","Emison","Emlenton","Emlyn","Emmalena","Emmaus","Emmet","Emmetsburg","Emmitsburg","Emmonak","Emmons","Emporia","Encampment","Encinal","Encinitas","Encino","Endeavor","Enderlin","Enders","Engadine","Engelhard","Englishtown","Enka","Enloe","Ennice","Enning","Ennis","Enochs","Enola","Enon","Enoree","Ensenada","Ensign","Entiat","Entriken","Enumclaw","Enville","Eola","Eolia","Epes","Ephrata","Epping","Epps","Epworth","Equality","Equinunk","Erath","Erbacon","Erhard","Erick","Ericson","Erieville","Eriline","Erin","Ermine","Ernul","Erving","Erwinna","Erwinville","Esbon","Escalante","Escalon","Escanaba","Escatawpa","Escoheag","Escondido","Eskdale","Esko","Esmond","Esmont","Esopus","Espanola","Esparto","Esperance","Essexfells","Essexville","Essie","Essig","Essington","Estacada","Estancia","Estelline","Estero","Estherville","Estherwood","Estill","Ethelsville","Etiwanda","Etlan","Etna","Etoile","Eton","Etowah","Etta","Etters","Etterville","Ettrick","Etty","Eubank","Eucha","Eudora","Eufaula","Euless","Eupora","Eure","Eustace","Eustis","Eutaw","Eutawville","Evadale","Evan","Evansport","Evant","Evart","Evarts","Eveleth","Eveningshade","Evensville","Everest","Everetts","Everettville","Everly","Everson","Everton","Evington","Evinston","Ewan","Ewell","Ewen","Excello","Exeland","Exira","Exline","Exmore","Exton","Eyota","Ezel","Fabens","Fabius","Fabyan","Fackler","Factoryville","Fagus","Fairbank","Fairbanks","Fairborn","Fairburn","Fairbury","Fairchance","Fairchild","Fairdale","Fairhope","Fairland","Fairlawn","Fairlee","Fairmont","Fairoaks","Fairplay","Fairton","Fairview","Fairwater","Faison","Fajardo","Falconer","Falfurrias","Falkland","Falkner","Falkville","Fallbrook","Fallon","Falls","Fallsburg","Fallston","Falun","Fancher","Fannettsburg","Fannin","Fanshawe","Fanwood","Faribault","Farisita","Farler","Farlington","Farmdale","Farmer","Farmers","Farmersburg","Farmersville","Farmerville","Farmingdale","Farmingville","Farmville","Farnam","Farner","Farnham","Farnhamville","Farragut","Farrandsville","Farrar","Farson","Farwell","Fashing","Faubush","Faucett","Faulkton","Faunsdale","Fawnskin","Faxon","Faywood","Federalsburg","Feesburg","Felch","Felda","Fellows","Fellsmere","Felton","Fenelton","Fennimore","Fennville","Fentress","Fenwick","Ferndale","Ferney","Fernley","Fernwood","Ferrellsburg","Ferriday","Ferrisburg","Ferron","Ferrum","Ferrysburg","Ferryville","Fessenden","Festina","Festus","Feurabush","Fiatt","Fiddletown","Fieldale","Fielding","Fieldon","Fieldton","Fifield","Filer","Filion","Filley","Fillmore","Finchville","Findlay","Fineview","Fingal","Fingerville","Finksburg","Finlayson","Finleyville","Finly","Firebaugh","Firebrick","Firesteel","Firth","Fisher","Fishers","Fishersville","Fishertown","Fisherville","Fishkill","Fishtail","Fishtrap","Fiskdale","Fiskeville","Fisty","Fithian","Fittstown","Fitzhugh","Flagtown","Flandreau","Flasher","Flatlick","Flatonia","Flatwoods","Flaxton","Flaxville","Fleetville","Fleetwood","Fleischmanns","Flemingsburg","Flemington","Flensburg","Flicksville","Flinton","Flintstone","Flintville","Flippin","Flom","Flomaton","Flomot","Floodwood","Florala","Floresville","Florien","Floris","Florissant","Floriston","Flossmoor","Flourtown","Flovilla","Floydada","Floyddale","Fluker","Flushing","Fluvanna","Flyingh","Fogelsville","Fogertown","Fola","Folcroft","Folkston","Follansbee","Follett","Folsom","Folsomville","Fombell","Fonda","Fonddulac","Foneswood","Fontana","Fontanelle","Fontanet","Foosland","Footville","Foraker","Forbestown","Fordcliff","Fordland","Fordoche","Fords","Fordsville","Fordville","Fordyce","Foreman","Forestburg","Forestburgh","Forestdale","Foreston","Forestport","Forestville","Forgan","Foristell","Forkland","Forks","Forksville","Forkunion","Forkville","Forman","Formoso","Forney","Forreston","Forsan","Forsyth","Fosston","Fosters","Fosterville","Fostoria","Fouke","Fountaintown","Fountainville","Fourmile","Fouroaks","Fowler","Fowlerton","Fowlerville","Fowlkes","Fowlstown","Foxboro","Foxburg","Foxcroft","Foxholm","Foxton","Foxtown","Foxworth","Foyil","Frackville","Frakes","Frametown","Framingham","Francestown","Francesville","Francitas","Franconia","Frankclay","Frankenmuth","Frankewing","Frankford","Franklinton","Franklintown","Franklinville","Frankston","Franksville","Frankton","Franktown","Frankville","Frannie","Frazee","Frazer","Frazeysburg","Frederica","Fredericktown","Frederika","Frederiksted","Fredonia","Fredville","Freeborn","Freeburg","Freeburn","Freeland","Freelandville","Freemanspur","Freesoil","Freeunion","Freeville","Freewater","Freistatt","Fremont","Frenchboro","Frenchburg","Frenchlick","Frenchtown","Frenchville","Frewsburg","Friant","Friedens","Friedensburg","Friedheim","Friendly","Friendship","Friendsville","Friendswood","Frierson","Fries","Friesland","Friona","Frisco","Fritch","Frogmore","Frohna","Froid","Fromberg","Frontenac","Frontroyal","Frostburg","Fruita","Fruitdale","Fruithurst","Fruitland","Fruitport","Fruitvale","Fryburg","Fryeburg","Fuget","Fulda","Fulshear","Fultondale","Fultonham","Fultonville","Fults","Funkstown","Funston","Fuquay","Fyffe","Gaastra","Gabbs","Gabriels","Gackle","Gadsden","Gaffney","Gagetown","Gainesboro","Gainestown","Gaither","Gakona","Galata","Galax","Galesburg","Galesville","Galeton","Galien","Galion","Gallatin","Gallaway","Galliano","Gallina","Gallion","Gallipolis","Gallitzin","Gallman","Gallupville","Galva","Galvin","Gamaliel","Gambier","Gambrills","Ganado","Gandeeville","Gans","Gansevoort","Gantt","Gapland","Gapville","Garardsfort","Garber","Garberville","Garciasville","Gardena","Gardendale","Gardenville","Gardiner","Gardners","Gardnerville","Garita","Garnavillo","Garnerville","Garnett","Garrard","Garrattsville","Garretson","Garrettsville","Garrisonville","Garvin","Garwin","Garwood","Garysburg","Garyville","Gasburg","Gasconade","Gasport","Gasquet","Gassaway","Gassville","Gastonia","Gastonville","Gatesville","Gatewood","Gattman","Gatzke","Gause","Gautier","Gaylesville","Gaylordsville","Gays","Gaysville","Gayville","Geary","Geddes","Geff","Geigertown","Geismar","Geneautry","Genesee","Geneseo","Gentryville","Georgiana","Gepp","Gering","Gerlach","Gerlaw","Germansville","Germanton","Geronimo","Gerrardstown","Gerton","Gervais","Getzville","Geyserville","Gheen","Gheens","Gibbonsville","Gibbsboro","Gibbstown","Gibsland","Gibsonburg","Gibsonia","Gibsonton","Gibsonville","Giddings","Gilberton","Gilbertown","Gilberts","Gilbertsville","Gilbertville","Gilboa","Gilby","Gilcrest","Gildford","Gile","Gilford","Gillett","Gilley","Gillham","Gilliam","Gillmore","Gillsville","Gilman","Gilmanton","Gilmer","Gilroy","Gilson","Gilsum","Giltner","Gipsy","Girard","Girdler","Girdletree","Girdwood","Girvin","Glace","Gladbrook","Gladeville","Gladewater","Gladwin","Gladwyne","Glady","Glandorf","Glasco","Glasford","Glasgo","Glassboro","Glasser","Glassport","Glasston","Glastonbury","Glenallan","Glenallen","Glenarbor","Glenarm","Glenaubrey","Glenbeulah","Glenbrook","Glenburn","Glenburnie","Glencarbon","Glencliff","Glencoe","Glencross","Glendaniel","Glendean","Glendive","Glendo","Glendon","Glendora","Gleneaston","Glenecho","Glenelder","Glenellen","Glenellyn","Glenferris","Glenfield","Glenflora","Glenford","Glengary","Glenham","Glenhayes","Glenhead","Glenjean","Glenlyn","Glenmont","Glenmoore","Glenmora","Glenmorgan","Glennallen","Glenndale","Glennie","Glennville","Glenolden","Glenoma","Glenpool","Glenrio","Glenrose","Glenshaw","Glenside","Glenspey","Glentana","Glenullin","Glenview","Glenvil","Glenville","Glenwhite","Glenwild","Glenwillard","Glenwilton","Glenwood","Glorieta","Gloster","Glouster","Glover","Gloversville","Gloverville","Glyndon","Glynn","Gober","Gobler","Gobles","Godeffroy","Godley","Goehner","Goessel","Goetzville","Goffstown","Golconda","Goldbar","Goldbond","Goldendale","Goldfield","Goldonna","Goldrun","Goldsboro","Goldston","Goldthwaite","Goldvein","Goliad","Goltry","Golts","Golva","Gonvick","Goochland","Goodell","Goodfield","Goodhope","Goodhue","Gooding","Goodland","Goodson","Goodview","Goodville","Goodwater","Goodway","Goodwell","Goodwine","Goodyear","Gordo","Gordonsville","Gordonville","Goree","Goreville","Gorin","Gorman","Gormania","Gorum","Goshen","Gosport","Gotebo","Gotha","Gothenburg","Goudeau","Gough","Gouldbusk","Gouldsboro","Gouverneur","Gove","Gowanda","Gowen","Gower","Gowrie","Grabill","Gracemont","Graceville","Gracewood","Gracey","Gradyville","Graettinger","Graford","Grafton","Grahamsville","Grahn","Grainfield","Grambling","Gramercy","Gramling","Grampian","Granada","Granbury","Granby","Granger","Grangeville","Graniteville","Grannis","Grantham","Granton","Grants","Grantsboro","Grantsburg","Grantsdale","Grantsville","Granttown","Grantville","Grapeland","Grapeview","Grapeville","Grasonville","Grasston","Gratiot","Graton","Gratz","Gravelly","Gravette","Gravity","Grawn","Graycourt","Grayland","Grayling","Graymont","Graysville","Graytown","Grayville","Greeley","Greeleyville","Greenback","Greenbank","Greenbrier","Greenbush","Greendale","Greendell","Greeneville","Greenford","Greenhall","Greenhurst","Greenlane","Greenlawn","Greenleaf","Greenock","Greenport","Greensburg","Greensea","Greentown","Greenup","Greenvale","Greenview","Greenville","Greenwald","Greenway","Greig","Grenada","Grenloch","Grenola","Grenora","Grenville","Grethel","Gretna","Greybull","Greycliff","Gridley","Griffithville","Grifton","Griggsville","Grimesland","Grimsley","Grimstead","Grinnell","Grissom","Groesbeck","Grosseile","Grossetete","Grosvenordale","Grottoes","Groveland","Grovertown","Groves","Groveton","Grovetown","Grubbs","Grubville","Gruetli","Grulla","Grundy","Gruver","Grygla","Guadalupe","Guadalupita","Guage","Gualala","Guanica","Guasti","Guayama","Guayanilla","Guaynabo","Guerneville","Guerra","Guerrant","Gueydan","Guffey","Guiderock","Guilderland","Guin","Guinda","Guion","Gulfhammock","Gulfport","Gulliver","Gulston","Gumberry","Gunlock","Gunnison","Gunter","Guntersville","Guntown","Gurabo","Gurdon","Gurley","Gurnee","Gurney","Gusher","Gustine","Guston","Guttenberg","Guymon","Guys","Guysville","Guyton","Gwinn","Gwinner","Gwynedd","Gwynn","Gwynneville","Hachita","Hackensack","Hackettstown","Hackleburg","Haddam","Haddix","Haddonfield","Hadensville","Hadlock","Hadlyme","Hagaman","Hagan","Hagarstown","Hagarville","Hagerman","Hagerstown","Hahira","Hahnville","Haigler","Hailesboro","Hailey","Haileyville","Hainesport","Hakalau","Halbur","Halcottsville","Haldeman","Haledon","Haleiwa","Haleyville","Hallam","Hallandale","Halleck","Hallett","Hallettsville","Halliday","Hallie","Hallieford","Hallock","Hallowell","Halls","Hallsboro","Hallstead","Hallsville","Halltown","Hallwood","Halstad","Hamberg","Hambleton","Hamden","Hamel","Hamer","Hamersville","Hamler","Hamletsburg","Hammett","Hammon","Hammondsport","Hammondsville","Hammonton","Hampden","Hampstead","Hamptonville","Hamshire","Hana","Hanalei","Hanapepe","Hanceville","Handley","Handsom","Hankamer","Hankins","Hankinson","Hanksville","Hanlontown","Hannacroix","Hannaford","Hannastown","Hanoverton","Hansboro","Hansell","Hansford","Hanska","Hanston","Hansville","Haralson","Harbert","Harbeson","Harborside","Harborton","Harco","Hardaway","Hardburly","Hardeeville","Hardenville","Hardesty","Hardinsburg","Hardtner","Hardwick","Hardyville","Harford","Hargill","Harleigh","Harleton","Harleysville","Harleyville","Harlingen","Harlowton","Harman","Harmans","Harmonsburg","Harned","Harper","Harpersfield","Harpersville","Harperville","Harpster","Harpursville","Harrah","Harrell","Harrells","Harrellsville","Harrietta","Harrisonburg","Harrisonville","Harriston","Harristown","Harrisville","Harrod","Harrodsburg","Harrold","Harshaw","Hartfield","Hartington","Hartland","Hartleton","Hartline","Hartly","Harts","Hartsburg","Hartsdale","Hartsel","Hartselle","Hartsfield","Hartshorn","Hartshorne","Hartstown","Hartsville","Hartville","Hartwell","Hartwick","Hartwood","Harvel","Harveysburg","Harveyville","Harviell","Harwich","Harwichport","Harwick","Harwood","Haskell","Haskins","Haslet","Haslett","Hasse","Hassell","Haswell","Hatboro","Hatchechubbee","Hathorne","Hatillo","Hatley","Hattieville","Hatton","Haubstadt","Haughton","Hauppauge","Hauula","Havaco","Havelock","Havensville","Haverford","Haverstraw","Haviland","Havre","Hawarden","Hawesville","Hawi","Hawick","Hawkeye","Hawkinsville","Hawks","Hawleyville","Haworth","Haxtun","Haydenville","Hayesville","Haymarket","Haynesville","Hayneville","Haysi","Haysville","Hayti","Haywood","Hazelcrest","Hazelhurst","Hazelton","Hazelwood","Hazen","Hazlehurst","Hazlet","Hazleton","Headrick","Healdsburg","Healdton","Hearne","Heartwell","Heaters","Heathsville","Heaton","Heavener","Hebbronville","Heber","Hebert","Hebo","Hebron","Hecker","Hecla","Hedgesville","Hedley","Hedrick","Heflin","Hegins","Heidenheimer","Heidrick","Heilwood","Heimdal","Heiskell","Heislerville","Heisson","Helechawa","Helendale","Helenville","Helenwood","Helfenstein","Hellertown","Hellier","Helmer","Helmetta","Helmsburg","Helmuth","Helmville","Helotes","Helper","Heltonville","Hemet","Hemingford","Henagar","Hendley","Hendrix","Hendrum","Henefer","Henlawson","Hennepin","Hennessey","Henniker","Henning","Henrico","Henrieville","Henryetta","Henryton","Henryville","Hensel","Hensler","Hensley","Hensonville","Hephzibah","Hepler","Heppner","Hepzibah","Herbster","Herculaneum","Herington","Herlong","Hermansville","Hermanville","Herminie","Hermiston","Hermitage","Hermleigh","Hermon","Hernando","Herndon","Hernshaw","Herod","Herreid","Herrick","Herrin","Herrings","Herron","Herscher","Hersey","Hertel","Hertford","Hesperia","Hessel","Hessmer","Hesston","Hestand","Heth","Hetland","Hettick","Hettinger","Heuvelton","Hext","Heyburn","Heyworth","Hialeah","Hiawassee","Hibbing","Hibbs","Hickorywithe","Hicksville","Hico","Hiddenite","Higbee","Higden","Higdon","Higganum","Higginson","Higginsport","Higginsville","Highlands","Highlandville","Highmore","Highshoals","Highspire","Hightown","Hightstown","Highview","Highwood","Higley","Hihat","Hiko","Hiland","Hilda","Hildebran","Hildreth","Hilger","Hilham","Hill","Hillburn","Hiller","Hilliard","Hilliards","Hillisburg","Hillister","Hillrose","Hills","Hillsboro","Hillsborough","Hillsdale","Hillsville","Hilltown","Hillview","Hilmar","Hilo","Hiltons","Hima","Himrod","Hinckley","Hindman","Hindsboro","Hindsville","Hinesburg","Hineston","Hinesville","Hingham","Hinkle","Hinkley","Hinsdale","Hinton","Hiseville","Hissop","Hitchins","Hitchita","Hitterdal","Hiwasse","Hiwassee","Hixson","Hixton","Hobbsville","Hobgood","Hobson","Hobucken","Hochheim","Hockessin","Hockingport","Hockley","Hode","Hodgen","Hodgenville","Hoehne","Hoffmeister","Hogansburg","Hogansville","Hogeland","Hohenwald","Hohokus","Hoisington","Hokah","Holabird","Holcombe","Holdenville","Holder","Holderness","Holdingford","Holdrege","Holicong","Holladay","Hollandale","Hollansburg","Hollenberg","Holley","Holliday","Hollidaysburg","Hollins","Hollis","Holliston","Holloman","Hollowville","Hollsopple","Hollybush","Hollytree","Holmen","Holmesville","Holmsville","Holton","Holtsville","Holtville","Holtwood","Holyrood","Homedale","Homer","Homerville","Hometown","Homewood","Homeworth","Hominy","Homosassa","Honaker","Honaunau","Honeapath","Honeoye","Honesdale","Honeybrook","Honeyville","Honobia","Honokaa","Honomu","Honor","Honoraville","Hoodsport","Hooker","Hookerton","Hooks","Hookstown","Hoolehua","Hoopa","Hooper","Hoopeston","Hoople","Hooppole","Hoosick","Hooven","Hooversville","Hopatcong","Hopbottom","Hopedale","Hopehull","Hopeland","Hopeton","Hopewell","Hopkinsville","Hopkinton","Hopland","Hopwood","Hoquiam","Hordville","Horicon","Hormigueros","Hornbeak","Hornbeck","Hornbrook","Hornell","Horner","Hornersville","Hornick","Hornitos","Hornsby","Horntown","Horseheads","Horsepen","Horsham","Hortense","Hortonville","Hoschton","Hosford","Hoskins","Hoskinston","Hosmer","Hospers","Hosston","Hostetter","Hotchkiss","Hotevilla","Houck","Houlka","Houlton","Houma","Housatonic","Houstonia","Houtzdale","Hoven","Hovland","Howardstown","Howells","Howertons","Howes","Howland","Hoxeyville","Hoxie","Hoyleton","Hoytville","Hubbardston","Hubbardsville","Hubertus","Huddleston","Huddy","Hudgins","Hudsonville","Huey","Hueysville","Huger","Hugheston","Hughesville","Hughson","Hughsonville","Hugoton","Huguenot","Hulbert","Hulen","Hulett","Humacao","Humansville","Humarock","Humbird","Hume","Humeston","Hummelstown","Humnoke","Humphreys","Humptulips","Hungerford","Hunker","Hunnewell","Hunters","Huntersville","Huntertown","Huntingburg","Huntingdon","Huntingtown","Huntland","Huntly","Huntsburg","Hurdland","Hurdsfield","Hurleyville","Hurlock","Hurtsboro","Husk","Husser","Hustisford","Hustontown","Hustonville","Husum","Hutsonville","Huttig","Hutto","Huttonsville","Huxford","Hyampom","Hyannisport","Hyattsville","Hyattville","Hyden","Hydes","Hydesville","Hydetown","Hydeville","Hye","Hymera","Hyndman","Hyrum","Hysham","Iaeger","Ibapah","Icard","Ickesburg","Idabel","Idalia","Idalou","Idamay","Idanha","Idaville","Ider","Idledale","Idlewild","Idyllwild","Ignacio","Igo","Ihlen","Ijamsville","Ila","Iliamna","Iliff","Ilion","Illiopolis","Ilwaco","Imbler","Imboden","Imlay","Imlaystown","Imler","Immaculata","Immokalee","Imnaha","Imogene","Ina","Inavale","Inchelium","Independence","Indiahoma","Indianhead","Indianola","Indiantown","Indio","Indore","Inez","Ingalls","Ingle","Inglefield","Ingleside","Inglewood","Inglis","Ingold","Ingomar","Ingraham","Inkom","Inkster","Innis","Inola","Insko","Intercourse","Interlachen","Interlaken","Interlochen","Intervale","Inwood","Inyokern","Iola","Iona","Ione","Ionia","Ipava","Ipswich","Iraan","Irasburg","Iredell","Ireton","Irmo","Ironbelt","Irondale","Ironia","Irons","Ironsides","Ironton","Irrigon","Irvington","Irvona","Irwinville","Isaban","Isabela","Isanti","Iselin","Ishpeming","Islandton","Islesboro","Islesford","Isleta","Isleton","Islip","Ismay","Isola","Isom","Isonville","Issaquah","Istachatta","Itasca","Itmann","Ittabena","Iuka","Iva","Ivel","Ivesdale","Ivins","Ivor","Ivoryton","Ivydale","Ivyton","Ixonia","Jachin","Jackhorn","Jacksboro","Jacksonboro","Jacksonburg","Jacksonport","Jacksontown","Jacobsburg","Jacumba","Jadwin","Jaffrey","Jakin","Jal","Jamesburg","Jameson","Jamesport","Jamesstore","Jamesville","Jamieson","Jamison","Jamul","Janelew","Janesville","Jansen","Jarales","Jarbidge","Jaroso","Jarratt","Jarreau","Jarrell","Jarrettsville","Jarvisburg","Jasonville","Jayem","Jayess","Jayton","Jayuya","Jeanerette","Jeannette","Jeddo","Jeffers","Jeffersonton","Jellico","Jelm","Jemison","Jena","Jenison","Jenkinsburg","Jenkinsville","Jenkintown","Jenks","Jenner","Jennerstown","Jermyn","Jeromesville","Jerseyville","Jessieville","Jessup","Jesup","Jetersville","Jetmore","Jetson","Jigger","Jobstown","Jodie","Joelton","Joes","Joffre","Johnday","Johnsburg","Johnsonburg","Johnsonville","Joice","Joiner","Joinerville","Joliette","Jolley","Jolo","Jolon","Jonancy","Jonben","Jonesboro","Jonesborough","Jonesburg","Jonesport","Jonestown","Jonesville","Joplin","Joppa","Jordanville","Jourdanton","Juanadiaz","Jud","Juda","Judsonia","Julesburg","Juliaetta","Julian","Juliette","Juliustown","Juncos","Junedale","Juniata","Juntura","Justiceburg","Justin","Kaaawa","Kadoka","Kahlotus","Kahoka","Kahuku","Kahului","Kailua","Kailuakona","Kalaheo","Kalama","Kalaupapa","Kaleva","Kalida","Kalispell","Kalkaska","Kalona","Kalskag","Kalvesta","Kamas","Kamay","Kamiah","Kampsville","Kamrar","Kamuela","Kanab","Kanaranzi","Kanarraville","Kanawha","Kandiyohi","Kaneohe","Kaneville","Kannapolis","Kanona","Kanopolis","Kanorado","Kanosh","Kansasville","Kantner","Kapaa","Kapaau","Kapowsin","Karlin","Karlsruhe","Karlstad","Karluk","Karnack","Karnak","Karthaus","Karval","Kasbeer","Kasigluk","Kasilof","Kasota","Kasson","Katemcy","Kathryn","Katonah","Katy","Kaukauna","Kaumakani","Kaunakakai","Kaweah","Kawkawlin","Kaycee","Kayenta","Kaylor","Kaysville","Keaau","Kealakekua","Kealia","Keansburg","Kearney","Kearneysville","Kearny","Kearsarge","Keasbey","Keatchie","Keavy","Kechi","Keddie","Keedysville","Keeler","Keeline","Keene","Keenes","Keenesburg","Keensburg","Keeseville","Keewatin","Keezletown","Kegley","Keiser","Keisterville","Keithsburg","Keithville","Keizer","Kekaha","Kelayres","Keldron","Kelford","Kell","Kellerman","Kellerton","Kelliher","Kellnersville","Kellysville","Kellyton","Kellyville","Kelseyville","Kelso","Kelton","Kemah","Kemblesville","Kemmerer","Kempner","Kempster","Kempton","Kenai","Kenansville","Kendalia","Kendallville","Kendleton","Kendrick","Kenduskeag","Kenedy","Kenefic","Kenesaw","Kenilworth","Kenly","Kenmare","Kenna","Kennard","Kennebec","Kennebunk","Kennebunkport","Kennedale","Kennedyville","Kenner","Kennerdell","Kennesaw","Kennett","Kennewick","Kenosha","Kenova","Kensal","Kensett","Kentland","Kentuck","Kentwood","Kenvil","Kenvir","Kenwood","Keo","Keokee","Keokuk","Keosauqua","Keota","Kerens","Kerhonkson","Kerkhoven","Kerman","Kernersville","Kernville","Kerrick","Kerrville","Kersey","Kershaw","Keshena","Kesley","Keswick","Ketchum","Kettlersville","Kevil","Kewadin","Kewanee","Kewanna","Kewaskum","Kewaunee","Keyapaha","Keyesport","Keylargo","Keymar","Keyport","Keyser","Keysville","Keytesville","Kiahsville","Kidder","Kidron","Kief","Kiefer","Kiel","Kieler","Kiester","Kihei","Kila","Kilauea","Kilbourne","Kildare","Kilkenny","Killarney","Killawog","Killbuck","Killduff","Killeen","Killen","Killington","Killona","Kilmarnock","Kilmichael","Kiln","Kilsyth","Kimballton","Kimberton","Kimbolton","Kimmell","Kimmswick","Kimper","Kinards","Kincaid","Kincheloe","Kinde","Kinder","Kinderhook","Kingfield","Kingman","Kingmont","Kings","Kingsburg","Kingsdown","Kingsland","Kingsport","Kingstree","Kingsville","Kingwood","Kinmundy","Kinnear","Kinross","Kinsale","Kinsey","Kinsley","Kinsman","Kinston","Kinta","Kintnersville","Kintyre","Kinzers","Kipnuk","Kipton","Kirbyville","Kirkersville","Kirklin","Kirkman","Kirksey","Kirksville","Kirkville","Kirkwood","Kiron","Kirtland","Kirvin","Kirwin","Kismet","Kissimmee","Kistler","Kittanning","Kittery","Kittitas","Kittredge","Kittrell","Kitzmiller","Klamath","Klemme","Klickitat","Klingerstown","Klondike","Klossner","Kneeland","Knierim","Knifley","Knightdale","Knightsen","Knightstown","Knightsville","Knippa","Knobel","Knoblick","Knobnoster","Knoke","Knowlesville","Knoxboro","Knoxdale","Koeltztown","Kohler","Kokomo","Koleen","Koloa","Kona","Konawa","Koosharem","Kooskia","Koppel","Kopperl","Kopperston","Korbel","Koror","Kosciusko","Koshkonong","Kosrae","Kosse","Kossuth","Kotlik","Kountze","Kouts","Kraemer","Kranzburg","Kreamer","Kremmling","Kresgeville","Kress","Krum","Kualapuu","Kula","Kulm","Kulpmont","Kulpsville","Kuna","Kunia","Kunkle","Kunkletown","Kurten","Kurthwood","Kurtistown","Kurtz","Kuttawa","Kutztown","Kwethluk","Kwigillingok","Kyburz","Kylertown","Laager","Labadie","Labadieville","Labarge","LaBarre","LaBelle","Labolt","Lacamp","Lacarne","Lacassine","Laceyville","Lachine","Lackawaxen","Laclede","Lacombe","Lacon","Lacona","Laconia","Lacoochee","Lacoste","Lacota","Lacrescent","Lacygne","Ladd","Laddonia","Ladiesburg","Ladonia","Ladora","Ladson","Ladysmith","Lafarge","Lafargeville","Laferia","Lafferty","Lafitte","Lafollette","Lafontaine","Lafox","Lafrance","Lagrangeville","Lagro","Laguna","Lagunitas","Lahabra","Lahaina","Laharpe","Lahaska","Lahmansville","Lahoma","Laie","Laings","Laingsburg","Laird","Lairdsville","Lajas","Lajose","Lakin","Lakota","Laloma","Laluz","Lamadera","Lamarque","Lamartine","Lamberton","Lambertville","Lambric","Lambrook","Lambsburg","Lamero","Lamesa","Lamison","Lamoille","Lamoni","Lamont","Lamonte","Lamotte","Lamoure","Lampasas","Lampe","Lampeter","Lanagan","Lanark","Lancing","Landa","Landenberg","Lander","Landess","Landing","Landingville","Landisburg","Landisville","Lando","Landrum","Landville","Laneburg","Lanesboro","Lanesville","Lanett","Laneview","Laneville","Lanexa","Langdon","Langeloth","Langford","Langhorne","Langlois","Langston","Langsville","Langtry","Langworthy","Lanham","Lankin","Lannon","Lansdale","Lansdowne","Lanse","Lansford","Lantry","Laona","Laotto","Lapaz","Lapeer","Lapine","Lapryor","Lapwai","Laquey","Larchmont","Larchwood","Largo","Larimer","Larimore","Larned","Larose","Larrabee","Larslan","Larto","Larue","Larussell","Larwill","Lasal","Lasalle","Lascassas","Lashmeet","Lasker","Lasmarias","Lastrup","Latah","Latexo","Latham","Latimer","Laton","Latour","Latta","Lattimore","Latty","Laughlintown","Laupahoehoe","Laurelton","Laurelville","Laurens","Laurier","Laurinburg","Lavalette","Lavalle","Lavallette","Laveen","Lavelle","Lavergne","Laverkin","Laverne","Lavernia","Laveta","Lavilla","Lavina","Lavinia","Lavon","Lavonia","Lawai","Laward","Lawen","Lawler","Lawley","Lawndale","Lawnside","Lawrenceburg","Lawrenceville","Lawsonville","Lawtell","Lawtey","Lawton","Lawtons","Lawyersville","Layland","Laytonville","Lazbuddie","Lazear","Leachville","Leaday","Leadore","Leadville","Leadwood","Leakesville","Leakey","Leamington","Leary","Leasburg","Leatherwood","Leavittsburg","Lebam","Lebeau","Lebec","Lebo","Leburn","Lecanto","Leckie","Leckkill","Leckrone","Leclaire","Lecoma","Lecompton","Ledbetter","Lederach","Ledger","Ledgewood","Ledoux","Ledyard","Leechburg","Leeco","Leedey","Leemont","Leeper","Leesburg","Leesport","Leesville","Leet","Leeton","Leetonia","Leetsdale","Leevining","Leewood","Lefor","Lefors","Leggett","Legrand","Lehi","Lehighton","Lehr","Leicester","Leipsic","Leisenring","Leitchfield","Leiter","Leitersford","Leith","Leivasy","Lemars","Lemasters","Lemhi","Leming","Lemitar","Lemmon","Lemont","Lemoore","Lemoyen","Lemoyne","Lempster","Lenapah","Lengby","Lenhartsville","Lenni","Lennon","Lenoir","Lenora","Lenorah","Lenox","Lenoxdale","Lenoxville","Lentner","Lenzburg","Leola","Leoma","Leominster","Leonardsville","Leonardtown","Leonardville","Leonidas","Leonore","Leonville","Leopolis","Leota","Leoti","Lepanto","Lequire","Leraysville","Lerna","Lerona","Lerose","Lesage","Lesterville","Lesueur","Letart","Letcher","Letha","Letohatchee","Letona","Letts","Lettsworth","Leupp","Levan","Levant","Levasy","Levelland","Levelock","Leverett","Levering","Levittown","Lewellen","Lewes","Lewisberry","Lewisburg","Lewisetta","Lewisport","Lewiston","Lewistown","Lewisville","Lexa","Libby","Libertytown","Libertyville","Libuse","Licking","Lickingville","Lidderdale","Lidgerwood","Liebenthal","Lightfoot","Ligon","Ligonier","Liguori","Lihue","Likely","Lilbourn","Lilburn","Lilesville","Lille","Lillie","Lillington","Lilliwaup","Lilydale","Limaville","Limeport","Limington","Limon","Linch","Lincolndale","Lincolnton","Lincolnville","Lincroft","Lindale","Lindenhurst","Lindenwood","Lindley","Lindon","Lindrith","Lindsborg","Lindseyville","Lindside","Linesville","Lineville","Lingle","Lingleville","Linkwood","Linn","Linneus","Linton","Linville","Linwood","Lipan","Lisco","Liscomb","Lisman","Lismore","Lissie","Listie","Litchfield","Litchville","Literberry","Lithia","Lithonia","Lithopolis","Lititz","Littcarr","Littlefield","Littleport","Littlestown","Lively","Livonia","Lizella","Lizemores","Lizton","Llano","Llewellyn","Loa","Loachapoka","Loami","Lobata","Lobeco","Lobelville","Lochgelly","Lochloosa","Lochmere","Lockbourne","Lockeford","Lockesburg","Lockney","Lockport","Loco","Locustdale","Locustville","Loda","Lodgegrass","Lodi","Logandale","Logansport","Loganton","Loganville","Lohman","Lohn","Lohrville","Loiza","Loleta","Lolita","Lolo","Loma","Lomalinda","Lomamar","Loman","Lomax","Lometa","Lomira","Lomita","Lompoc","Lonaconing","Londonderry","Lonedell","Lonejack","Lonestar","Lonetree","Longbottom","Longdale","Longford","Longkey","Longlane","Longleaf","Longmeadow","Longmont","Longport","Longs","Longton","Longview","Longville","Longwood","Lonoke","Lonsdale","Loogootee","Lookeba","Looneyville","Lopeno","Lorado","Lorain","Loraine","Lorane","Loranger","Lordsburg","Loreauville","Lorena","Lorentz","Lorenzo","Loretto","Lorida","Lorimor","Loring","Loris","Lorman","Lorton","Lostant","Lostine","Lothair","Lothian","Lott","Lottsburg","Louann","Loudon","Loudonville","Louellen","Loughman","Louin","Louisburg","Louvale","Louviers","Lovejoy","Lovelaceville","Lovelady","Lovell","Lovelock","Lovely","Lovettsville","Loveville","Lovilia","Loving","Lovingston","Lovington","Lowake","Lowber","Lowden","Lowder","Lowellville","Lowes","Lowesville","Lowman","Lowmansville","Lowmoor","Lowndes","Lowndesboro","Lowndesville","Lowville","Loxahatchee","Loxley","Loyall","Loyalton","Loysburg","Loysville","Lozano","Luana","Lubec","Lublin","Lucama","Lucan","Lucasville","Lucedale","Lucien","Lucile","Lucinda","Luckey","Ludell","Ludington","Ludowici","Luebbering","Lueders","Lufkin","Lugoff","Lukeville","Lula","Luling","Lumberport","Lumberton","Lumpkin","Luna","Lundale","Lunenburg","Luning","Lupton","Luquillo","Luray","Lurgan","Lusby","Lusk","Lutcher","Lutesville","Luthersburg","Luthersville","Lutherville","Lutsen","Luttrell","Lutts","Luverne","Luxemburg","Luxor","Luxora","Luzerne","Lyburn","Lycoming","Lyerly","Lyford","Lykens","Lyles","Lyme","Lynbrook","Lynco","Lynd","Lyndeborough","Lyndell","Lynden","Lyndhurst","Lyndon","Lyndonville","Lyndora","Lynndyl","Lynnfield","Lynnville","Lynnwood","Lynwood","Lysander","Lysite","Lytle","Lytten","Lytton","Mabank","Mabelvale","Maben","Mabie","Mableton","Mabscott","Mabton","MacClenny","MacClesfield","MacDoel","MacDona","MacFarlan","MacKay","MacKeyville","Macatawa","Maceo","Machias","Machiasport","Machipongo","Macksburg","Macksinn","Macksville","Mackville","Macomb","Macungie","Macy","Madawaska","Maddock","Madelia","Madera","Madill","Madisonburg","Madisonville","Maeystown","Magalia","Magdalena","Magee","Magness","Mahaffey","Mahan","Mahaska","Maher","Mahnomen","Mahomet","Mahopac","Mahto","Mahtowa","Mahwah","Maida","Maidens","Maidsville","Mainesburg","Maineville","Maitland","Maize","Majuro","Makanda","Makawao","Makaweli","Makinen","Makoti","Malaga","Malakoff","Malcom","Malibu","Malin","Malinta","Maljamar","Mallie","Malmo","Malo","Maloneton","Malott","Maloy","Malvern","Malverne","Mamers","Mamou","Manahawkin","Manakinsabot","Manasquan","Manassa","Manassas","Manati","Manawa","Mancelona","Manchaca","Manchaug","Mancos","Mandan","Mandaree","Manderson","Mandeville","Mangham","Mango","Mangohick","Mangum","Manhasset","Manheim","Manilla","Manistee","Manistique","Manito","Manitou","Manitowoc","Mankato","Manlius","Manly","Mannboro","Mannford","Manning","Mannington","Mannschoice","Mannsville","Manokin","Manokotak","Manomet","Manorville","Manquin","Mansfield","Manson","Mansura","Mantachie","Mantador","Manteca","Mantee","Manteno","Manteo","Manter","Manti","Mantoloking","Manton","Mantorville","Mantua","Manvel","Manzanola","Mapaville","Maplecrest","Mapleshade","Maplesville","Mapleton","Mapleview","Mapleville","Maplewood","Mappsville","Maquoketa","Maquon","Maramec","Marana","Marblehead","Marbury","Marceline","Marcell","Marcella","Marcellus","Marchand","Marcola","Marcushook","Marengo","Marenisco","Marfa","Marfrance","Margaretville","Marianna","Mariastein","Mariba","Maribel","Maricao","Maricopa","Marienthal","Marienville","Marilla","Marinette","Maringouin","Marionville","Mariposa","Marissa","Markesan","Markle","Markleeville","Markleton","Markleville","Markleysburg","Marksville","Markville","Marland","Marlette","Marlinton","Marlow","Marlton","Marmaduke","Marmarth","Marmora","Marne","Maroa","Marquand","Marquez","Marrero","Marshallberg","Marshalltown","Marshallville","Marshessiding","Marshfield","Marshville","Marsing","Marsland","Marsteller","Marston","Martel","Martell","Martelle","Martensdale","Marthasville","Marthaville","Martindale","Martinsburg","Martinsdale","Martinsville","Martinton","Martville","Marvell","Maryalice","Maryd","Marydel","Marydell","Maryknoll","Marylhurst","Maryneal","Marysvale","Marysville","Maryus","Maryville","Mascot","Mascotte","Mascoutah","Mashpee","Maskell","Masontown","Masonville","Massapequa","Massena","Massillon","Masury","Matador","Matagorda","Matamoras","Matawan","Matewan","Matheny","Mather","Matherville","Matheson","Mathis","Mathiston","Matinicus","Matlock","Matoaka","Mattapoisett","Mattaponi","Mattawamkeag","Mattawan","Mattawana","Matteson","Mattituck","Mattoon","Mauckport","Maud","Maugansville","Mauk","Mauldin","Maumee","Maunabo","Maunie","Maupin","Maurepas","Maurertown","Mauricetown","Mauriceville","Maury","Mauston","Mavisdale","Maxatawny","Maxbass","Maxie","Maximo","Maxton","Maxwelton","Mayaguez","Maybee","Maybell","Maybeury","Maybrook","Maydelle","Mayersville","Mayesville","Mayetta","Mayfield","Mayhew","Mayking","Maylene","Maynardville","Mayodan","Maypearl","Mayport","Mays","Maysel","Maysfield","Mayslick","Maysville","Maytown","Mayview","Mayville","Maywood","Mazeppa","Mazie","Mazomanie","Mazon","McAdenville","McAdoo","McAfee","McAlester","McAlister","McAllen","McAlpin","McAndrews","McArthur","McBain","McBee","McBrides","McCalla","McCallsburg","McCamey","McCammon","McCanna","McCarley","McCarr","McCaskill","McCaulley","McCausland","McCaysville","McClave","McCleary","McClelland","McCloud","McClurg","McClusky","McColl","McComas","McComb","McCombs","McCondy","McConnells","McCook","McCool","McCordsville","McCrory","McCune","McCurtain","McDade","McDaniels","McDavid","McDermitt","McDonough","McDougal","McElhattan","McEwen","McEwensville","McFaddin","McFall","McFarlan","McGaheysville","McGehee","McGirk","McGrady","McGrann","McGraws","McGrew","McGuffey","McHenry","McIntire","McKean","McKenney","McKinnon","McKittrick","McKnightstown","McLain","McLeansboro","McLeansville","McLouth","McMechen","McMillin","McMinnville","McNabb","McNary","McNeal","McNeill","McQuady","McQueeney","McRae","McRoberts","McShan","McSherrystown","McVeigh","McVeytown","McVille","McWhorter","McWilliams","McKeesport","Meade","Meador","Meadowbrook","Meadows","Meadville","Meally","Means","Meansville","Mears","Mebane","Mechanicsburg","Mechanicstown","Mechanicville","Mecklenburg","Meckling","Mecosta","Medanales","Medaryville","Medfield","Mediapolis","Medina","Medinah","Medomak","Medon","Medora","Medway","Meeker","Meers","Meeteetse","Megargel","Meherrin","Mehoopany","Meigs","Mekinock","Mekoryuk","Melba","Melber","Melbeta","Melcroft","Melder","Meldrim","Melfa","Mellen","Mellenville","Mellette","Mellott","Mellwood","Melmore","Melrose","Melstone","Melvern","Melvindale","Mena","Menahga","Menan","Menard","Menasha","Mendenhall","Mendham","Mendocino","Mendon","Mendota","Menemsha","Menfro","Menifee","Menno","Meno","Menoken","Menominee","Menomonie","Mentcle","Mentmore","Mentone","Meppen","Meraux","Merced","Mercedita","Mercersburg","Merchantville","Meredithville","Meredosia","Mereta","Meridale","Meriden","Meridianville","Merigold","Merino","Merkel","Mermentau","Merna","Merom","Merrick","Merricourt","Merrifield","Merrillan","Merrimac","Merriman","Merrittstown","Merrouge","Merryville","Mershon","Mertens","Merton","Mertzon","Mertztown","Mesaverde","Mescalero","Mesena","Meservey","Meshoppen","Mesick","Mesilla","Mesita","Meta","Metairie","Metaline","Metamora","Metcalfe","Methow","Metter","Metuchen","Metz","Mexia","Meyersdale","Meyersville","Miamisburg","Miamitown","Miamiville","Micanopy","Micaville","Miccosukee","Michie","Michigamme","Mickleton","Middlebass","Middleboro","Middlebourne","Middlebrook","Middleburg","Middleburgh","Middlefield","Middleport","Middlesboro","Middleville","Midfield","Midkiff","Midlothian","Midpines","Midvale","Midville","Mifflin","Mifflinburg","Mifflintown","Mifflinville","Mikado","Mikana","Milaca","Milam","Milano","Milanville","Milbank","Milburn","Milesburg","Milesville","Miley","Milfay","Milford","Milladore","Millboro","Millbrae","Millbrook","Millburn","Millbury","Milldale","Milledgeville","Millen","Millers","Millersburg","Millersport","Millerstown","Millersville","Millerton","Millerville","Millfield","Millhall","Millheim","Millhousen","Millican","Milligan","Milliken","Millington","Millinocket","Millis","Millmont","Millport","Millrift","Millry","Millsap","Millsboro","Millshoals","Millstadt","Millston","Milltown","Millville","Millwood","Milmay","Milmine","Milner","Milnesand","Milnesville","Milnor","Milo","Milpitas","Milroy","Milton","Miltona","Miltonvale","Mima","Mimbres","Mims","Mina","Minatare","Minburn","Minco","Minden","Mindoro","Minelamotte","Mineola","Minersville","Minetto","Mineville","Minford","Mingo","Mingoville","Mingus","Minier","Minneola","Minneota","Minnetonka","Minnewaukan","Minoa","Minocqua","Minong","Minonk","Minooka","Minotola","Minster","Minter","Minto","Minturn","Mio","Miquon","Miraloma","Miramonte","Misenheimer","Mishawaka","Mishicot","Miston","Mitchells","Mitchellsburg","Mitchellville","Mittie","Mize","Mizpah","Moab","Moapa","Moatsville","Mobeetie","Moberly","Mobjack","Moca","Mocksville","Moclips","Modale","Modena","Modeste","Modesttown","Modoc","Moffat","Moffett","Moffit","Mogadore","Mohall","Mohnton","Mohrsville","Moira","Mojave","Mokane","Mokena","Moko","Molalla","Molena","Molina","Molino","Momence","Monaca","Monahans","Monango","Monaville","Monclova","Moncure","Mondamin","Mondovi","Monee","Monessen","Moneta","Monett","Monetta","Monette","Mongo","Monhegan","Monico","Monkton","Monon","Monona","Monongah","Monoville","Monponsett","Monroeton","Monroeville","Monsey","Monson","Moodus","Moodys","Mooers","Moorcroft","Moorefield","Mooreland","Mooresboro","Mooresburg","Moorestown","Mooresville","Mooreton","Mooreville","Moorhead","Moorland","Moorman","Mooseheart","Moosup","Mora","Moraga","Morann","Morattico","Moreauville","Morehead","Morehouse","Morenci","Moretown","Morganfield","Morganton","Morgantown","Morganville","Morganza","Moriah","Moriches","Morland","Moro","Moroni","Morovis","Morral","Morrice","Morrilton","Morrisdale","Morrisonville","Morriston","Morrisville","Morrowville","Morven","Morvin","Mosby","Mosca","Moseley","Moselle","Moshannon","Mosheim","Mosherville","Mosier","Mosinee","Mosquero","Mossville","Mossyrock","Mott","Mottville","Moultonboro","Moultrie","Mounds","Moundsville","Moundville","Mousie","Mouthcard","Moville","Moweaqua","Mowrystown","Moxahala","Moxee","Moyers","Moyock","Mozelle","Mozier","Mtbaldy","Muenster","Mukilteo","Mukwonago","Muldoon","Muldraugh","Muldrow","Muleshoe","Mulga","Mulhall","Mulino","Mulkeytown","Mullan","Mullens","Mulliken","Mullin","Mullins","Mullinville","Mulvane","Muncy","Munday","Mundelein","Munden","Munford","Munfordville","Munger","Munising","Munith","Munnsville","Munsonville","Murchison","Murdo","Murdock","Murfreesboro","Murphys","Murphysboro","Murraysville","Murrayville","Murrieta","Murrysville","Murtaugh","Muscadine","Muscatine","Muscoda","Muscotah","Musella","Muskego","Muskogee","Mustoe","Myerstown","Myersville","Mylo","Myrtlewood","Myton","Naalehu","Nabb","Naches","Nachusa","Naco","Nacoochee","Nada","Nadeau","Nageezi","Nagshead","Naguabo","Nahant","Nahma","Nahunta","Nakina","Naknek","Nallen","Nampa","Nanafalia","Nanjemoy","Nankin","Nanticoke","Nantyglo","Nanuet","Naoma","Napa","Napakiak","Napanoch","Napavine","Naper","Naperville","Napier","Napoleonville","Naponee","Nappanee","Naranjito","Naravisa","Narberth","Nardin","Narka","Narrows","Narrowsburg","Naruna","Narvon","Naselle","Nashoba","Nashotah","Nashport","Nashwauk","Nason","Nassawadox","Natalbany","Natalia","Nathalie","Nathrop","Natick","Natoma","Naturita","Naubinway","Naugatuck","Nauvoo","Navarre","Navasota","Navesink","Naxera","Naylor","Naytahwaush","Nazlini","Neafus","Neapolis","Neavitt","Nebo","Necedah","Neche","Neches","Nederland","Nedrow","Needles","Needmore","Needville","Neely","Neelyton","Neelyville","Neenah","Neeses","Neffs","Negaunee","Negley","Negreet","Nehalem","Nehawka","Neihart","Neillsville","Neilton","Nekoma","Nekoosa","Neligh","Nellis","Nelliston","Nellysford","Nelse","Nelsonia","Nelsonville","Nemacolin","Nemaha","Nemo","Nemours","Nenzel","Neodesha","Neoga","Neola","Neopit","Neosho","Neotsu","Nephi","Neponset","Nerinx","Nerstrand","Nesbit","Nesconset","Nescopeck","Neshkoro","Nesmith","Nespelem","Nesquehoning","Netawaka","Netcong","Nettie","Nettleton","Neversink","Neville","Nevis","Nevisdale","Ney","Niangua","Niantic","Niarada","Nicasio","Niceville","Nicholasville","Nicholville","Nickelsville","Nickerson","Nicktown","Nicolaus","Nicollet","Nicut","Nielsville","Nikep","Nikiski","Nikolski","Niland","Niles","Nilwood","Nimitz","Ninde","Ninilchik","Ninnekah","Niobrara","Niota","Niotaze","Nipomo","Nisbet","Nisswa","Nisula","Nitro","Nittayuma","Niverville","Niwot","Nixa","Nobleboro","Noblesville","Nobleton","Nocatee","Nocona","Noctor","Nodaway","Nogal","Nogales","Nokesville","Nokomis","Nolanville","Nolensville","Noma","Nome","Nonantum","Nondalton","Nooksack","Noonan","Norborne","Norcatur","Norco","Norcross","Norden","Nordheim","Nordland","Nordman","Norene","Norge","Norlina","Normalville","Normangee","Normanna","Normantown","Norphlet","Norridgewock","Norristown","Nortonville","Norvell","Norvelt","Norwell","Norwood","Notasulga","Noti","Notrees","Nottawa","Nottoway","Notus","Novato","Novi","Novinger","Nowata","Noxapater","Noxen","Noxon","Noyes","Nuangola","Nubieber","Nucla","Nuevo","Numa","Numidia","Numine","Nunapitchuk","Nunda","Nunez","Nunica","Nunn","Nunnelly","Nuremberg","Nutrioso","Nuttsville","Nyac","Nyack","Nyssa","Oacoma","Oakbluffs","Oakboro","Oakdale","Oakes","Oakesdale","Oakfield","Oakford","Oakhall","Oakham","Oakhurst","Oaklawn","Oaklyn","Oakman","Oakmont","Oaks","Oakton","Oaktown","Oakvale","Oakview","Oakville","Oark","Oatman","Obernburg","Oberon","Obert","Obion","Obrien","Ocala","Ocate","Occoquan","Oceana","Oceano","Oceanport","Oceanview","Oceanville","Oceola","Ochelata","Ocheyedan","Ochopee","Ocilla","Ocoee","Oconee","Oconomowoc","Oconto","Ocotillo","Ocracoke","Odanah","Odebolt","Odell","Odem","Oden","Odenton","Odenville","Odon","Odonnell","Odum","Oelrichs","Oelwein","Ofallon","Offerle","Offerman","Offutt","Ogallah","Ogallala","Ogdensburg","Ogema","Ogilvie","Oglala","Oglesby","Oglethorpe","Ogunquit","Ohatchee","Ohiopyle","Ohiowa","Ohley","Ohlman","Oilmont","Oilton","Oiltrough","Oilville","Ojai","Okabena","Okahumpka","Okanogan","Okarche","Okaton","Okauchee","Okawville","Okean","Okeana","Okeechobee","Okeene","Okemah","Okemos","Oketo","Oklaunion","Oklawaha","Oklee","Okmulgee","Okoboji","Okolona","Okreek","Oktaha","Ola","Olalla","Olamon","Olancha","Olanta","Olar","Olathe","Olaton","Olcott","Oldham","Olds","Olean","Olema","Oley","Oliveburg","Olivehurst","Oliverea","Olivet","Olla","Ollie","Olmito","Olmitz","Olmstead","Olmsted","Olmstedville","Olney","Olpe","Olsburg","Olton","Olustee","Olyphant","Omak","Omar","Omena","Omer","Omro","Ona","Onaga","Onaka","Onalaska","Onamia","Onancock","Onarga","Onawa","Onaway","Onchiota","Oneals","Oneco","Onego","Oneill","Onekama","Onemo","Oneonta","Ong","Onia","Onida","Onley","Ono","Onslow","Onsted","Ontonagon","Ookala","Oolitic","Oologah","Ooltewah","Oostburg","Opalocka","Opdyke","Opelika","Opelousas","Opheim","Ophelia","Ophir","Opolis","Opp","Oquawka","Oquossoc","Ora","Oradell","Oran","Orangeburg","Orangefield","Orangevale","Orangeville","Oraville","Orbisonia","Orcas","Ord","Orderville","Ordway","Oreana","Orefield","Oregonia","Oreland","Orem","Orford","Orfordville","Orgas","Orick","Orinda","Oriska","Oriskany","Orla","Orland","Orlean","Orlinda","Orma","Ormsby","Orocovis","Orondo","Oronoco","Oronogo","Orosi","Orovada","Oroville","Orrick","Orrin","Orrington","Orrstown","Orrtanna","Orrum","Orrville","Orson","Orting","Ortley","Ortonville","Orwigsburg","Osage","Osakis","Osawatomie","Osburn","Osceola","Osco","Oscoda","Oshoto","Oshtemo","Oskaloosa","Osmond","Osnabrock","Osseo","Ossian","Ossineke","Ossining","Ossipee","Osteen","Osterburg","Osterville","Oswegatchie","Oswego","Osyka","Otego","Otho","Otisco","Otisville","Otley","Oto","Otoe","Otsego","Otterbein","Ottertail","Otterville","Ottine","Ottosen","Ottoville","Ottsville","Ottumwa","Otway","Otwell","Ouaquaga","Ouray","Outing","Outlook","Ouzinkie","Ovalo","Ovando","Ovapa","Overbrook","Overgaard","Overly","Overpeck","Overton","Ovett","Oviedo","Owaneco","Owanka","Owasco","Owasso","Owatonna","Owego","Owen","Owendale","Owensboro","Owensburg","Owensville","Owenton","Owings","Owingsville","Owlshead","Owosso","Owyhee","Oxbow","Oxly","Oyens","Oysterville","Ozan","Ozawkie","Ozona","Paauhau","Paauilo","Pachuta","Pacifica","Packwaukee","Packwood","Pacoima","Pacolet","Paden","Padroni","Paducah","Pageland","Pageton","Paguate","Pahala","Pahoa","Pahokee","Pahrump","Paia","Paicines","Paige","Painesdale","Painesville","Paintbank","Painter","Paintlick","Painton","Paintsville","Paisley","Pala","Palacios","Palatka","Palco","Palenville","Palisades","Pallmall","Palmcoast","Palmdale","Palmdesert","Palmer","Palmerdale","Palmersville","Palmerton","Paloalto","Palocedro","Paloma","Palopinto","Palouse","Paloverde","Paluxy","Pamplico","Pamplin","Pana","Panaca","Pangburn","Panguitch","Pannamaria","Panola","Panora","Pansey","Pantego","Paola","Paonia","Papaikou","Papineau","Paradis","Paragonah","Paragould","Parcoal","Pardeesville","Pardeeville","Parishville","Parkdale","Parker","Parkerford","Parkersburg","Parkesburg","Parkhall","Parkin","Parkman","Parksley","Parkston","Parksville","Parkton","Parkville","Parlier","Parlin","Parma","Parmele","Parmelee","Parnell","Paron","Parowan","Parrott","Parrottsville","Parryville","Parshall","Parsippany","Parsonsburg","Partlow","Pascagoula","Pasco","Pascoag","Pascola","Paskenta","Passadumkeag","Passumpsic","Pataskala","Patchogue","Pateros","Patillas","Patoka","Paton","Patricksburg","Patten","Pattison","Pattonsburg","Pattonville","Paulden","Paulding","Paulina","Paullina","Paulsboro","Paupack","Pavillion","Pavo","Pawhuska","Pawlet","Pawling","Pawnee","Pawneerock","Pawpaw","Paxico","Paxinos","Paxton","Paxtonville","Payette","Paynesville","Payneville","Payson","Peacham","Peachbottom","Peachland","Peapack","Pearblossom","Pearcy","Pearisburg","Pearland","Pearlington","Pearsall","Peaster","Pebworth","Pecatonica","Peckville","Peconic","Pedricktown","Peebles","Peedee","Peekskill","Peell","Peerless","Peetz","Peever","Peggs","Pegram","Pejepscot","Pekin","Pelahatchie","Pelion","Pelkie","Pella","Pellston","Pellville","Pelsor","Pelzer","Pemaquid","Pemberton","Pemberville","Pembina","Pembine","Penalosa","Penargyl","Penasco","Pender","Pendergrass","Pendleton","Pendroy","Penfield","Pengilly","Penhook","Penitas","Penland","Pennellville","Pennington","Pennlaird","Pennock","Pennsauken","Pennsboro","Pennsburg","Pennsville","Pennville","Pennyan","Penobscot","Penokee","Penrod","Penryn","Pentress","Pentwater","Penuelas","Penwell","Peoples","Peosta","Peotone","Pepeekeo","Pepin","Pepperell","Pequabuck","Pequannock","Pequea","Peralta","Perdido","Perham","Peridot","Perkasie","Perkinston","Perkinsville","Perks","Perley","Pernell","Perrin","Perrineville","Perrinton","Perris","Perronville","Perryhall","Perryman","Perryopolis","Perrysburg","Perrysville","Perryton","Perryville","Pescadero","Peshastin","Peshtigo","Pesotum","Petaca","Petaluma","Peterboro","Peterborough","Peterman","Petersham","Peterstown","Petoskey","Petrey","Petrolia","Petros","Pettibone","Pettigrew","Pettisville","Pettit","Pettus","Pevely","Pewamo","Pewaukee","Peyton","Peytona","Peytonsburg","Pfafftown","Pfeifer","Pflugerville","Pharoah","Pharr","Pheba","Phelan","Phenix","Philadelphia","Philipp","Philippi","Philipsburg","Phillipsburg","Phillipsville","Philmont","Philo","Philomath","Philomont","Philpot","Phippsburg","Phoenixville","Piasa","Picacho","Picher","Pickens","Pickerington","Pickrell","Pickstown","Pickton","Picorivera","Piedra","Piercefield","Pierceton","Pierceville","Piercy","Piermont","Pierpont","Pierron","Pierz","Pietown","Piffard","Piggott","Piketon","Pikeville","Pilger","Pillager","Pilottown","Pima","Pimento","Pinckard","Pinckney","Pinckneyville","Pinconning","Pindall","Pinebank","Pinebluffs","Pinebrook","Pinebush","Pinecliffe","Pinecrest","Pinedale","Pinehall","Pineknot","Pineland","Pineola","Pinetops","Pinetown","Pinetta","Pineview","Pineville","Pinewood","Pineywoods","Pingree","Pinola","Pinole","Pinon","Pinopolis","Pinson","Pioche","Pioneertown","Pipersville","Pipestem","Pipestone","Pippapasses","Piqua","Pirtleville","Piru","Piseco","Pisek","Pisgah","Pitcher","Pitkin","Pitsburg","Pitts","Pittsboro","Pittsburg","Pittsford","Pittstown","Pittsview","Pittsville","Pixley","Placedo","Placentia","Placerville","Placida","Placitas","Plains","Plainsboro","Plainview","Plainville","Plainwell","Plaisted","Plaistow","Planada","Plankinton","Plano","Plantersville","Plantsville","Platina","Plattekill","Plattenville","Platter","Platteville","Plattsburg","Plattsburgh","Plattsmouth","Plaucheville","Pleasantdale","Pleasanton","Pleasantville","Pleasureville","Pledger","Plentywood","Plessis","Plevna","Pluckemin","Plumerville","Plummer","Plumtree","Plumville","Plympton","Poca","Pocahontas","Pocasset","Pocatello","Pocola","Pocopson","Poestenkill","Polacca","Polkton","Polkville","Pollocksville","Pollok","Polson","Polvadera","Pomaria","Pomerene","Pomeroy","Pomeroyton","Pomfret","Ponape","Ponca","Ponchatoula","Ponderay","Ponderosa","Ponemah","Poneto","Ponsford","Pontotoc","Pooler","Poolesville","Poolville","Popejoy","Poplarville","Poquonock","Porum","Posen","Poseyville","Poskin","Postelle","Poston","Postville","Poteau","Potecasi","Poteet","Poth","Potosi","Potrero","Potsdam","Potter","Pottersville","Potterville","Pottsboro","Pottstown","Pottsville","Potwin","Poughquag","Poulan","Poulsbo","Poultney","Poway","Powderhorn","Powderly","Powellsville","Powellton","Powellville","Powersite","Powersville","Powhatan","Powhattan","Pownal","Poyen","Poynette","Poynor","Poyntelle","Poysippi","Prather","Pratts","Prattsburg","Prattshollow","Prattsville","Prattville","Preble","Premont","Prenter","Prentiss","Presho","Presidio","Prestonsburg","Prewitt","Pricedale","Prichard","Priddy","Primghar","Princeville","Princewick","Prineville","Pringle","Prinsburg","Printer","Pritchett","Proberta","Procious","Proctorsville","Proctorville","Progreso","Prole","Prompton","Prophetstown","Prosperity","Prosser","Protection","Protem","Protivin","Provencal","Providence","Provo","Pruden","Prudenville","Prue","Pryor","Pryse","Puckett","Puertoreal","Pukwana","Pulcifer","Pulteney","Pultneyville","Puncheon","Pungoteague","Punxsutawney","Puposky","Purcellville","Purdin","Purdon","Purdum","Purdy","Purdys","Purgitsville","Purlear","Purling","Purmela","Pursglove","Purvis","Puryear","Putnamville","Putney","Puunene","Puxico","Puyallup","Pyatt","Pylesville","Pyote","Pyrites","Quakake","Quakerstreet","Quakertown","Quanah","Quapaw","Quarryville","Quartzsite","Quasqueton","Quebeck","Quebradillas","Quechee","Queenanne","Queenstown","Quemado","Quenemo","Questa","Quicksburg","Quilcene","Quimby","Quinault","Quinby","Quincy","Quinebaug","Quinlan","Quinnesec","Quinnimont","Quinque","Quinter","Quinton","Quinwood","Quitaque","Quitman","Qulin","Quogue","Raceland","Racine","Rackerby","Radcliff","Radford","Radisson","Radnor","Radom","Raeford","Ragland","Ragley","Rago","Ragsdale","Rahway","Raiford","Rainelle","Rainier","Rains","Rainsville","Ralls","Ramage","Ramah","Ramer","Ramey","Ramona","Ramsay","Ramseur","Ranburne","Ranchester","Rancocas","Randalia","Randallstown","Randle","Randleman","Randlett","Randsburg","Rangeley","Rangely","Ranger","Ransomville","Ranson","Rantoul","Rapelje","Raphine","Rapidan","Rarden","Ratcliff","Rathdrum","Raton","Rattan","Ravena","Ravencliff","Ravendale","Ravenden","Ravenel","Ravenna","Ravensdale","Ravenswood","Ravenwood","Ravia","Ravinia","Rawl","Rawlings","Rawlins","Rawson","Raybrook","Rayland","Rayle","Raymondville","Raymore","Rayne","Raynesford","Raynham","Raysal","Rayville","Raywick","Raywood","Rea","Reader","Readfield","Reading","Readington","Readlyn","Readsboro","Readstown","Readyville","Realitos","Reamstown","Reardan","Reasnor","Rebersburg","Rebuck","Rectortown","Redan","Redart","Redash","Redbank","Redbanks","Redby","Redcliff","Redcrest","Reddell","Reddick","Redding","Redfield","Redford","Redfox","Redhook","Redig","Redkey","Redlands","Redlion","Redmon","Redondo","Redowl","Redrock","Redstar","Redvale","Redwater","Redway","Redwing","Reeder","Reeders","Reedley","Reeds","Reedsburg","Reedsport","Reedsville","Reedville","Reelsville","Reeseville","Reesville","Reevesville","Reform","Refton","Refugio","Regan","Register","Rehobeth","Rehoboth","Rehrersburg","Reidsville","Reidville","Reinbeck","Reinholds","Reisterstown","Reklaw","Reliance","Rembert","Remer","Remlap","Remsen","Remsenburg","Renalara","Renfrew","Renick","Renner","Reno","Renovo","Rentiesville","Renton","Rentz","Renville","Renwick","Repton","Resaca","Reseda","Retsof","Reva","Revelo","Revillo","Revloc","Rew","Rewey","Rexburg","Rexford","Rexmont","Rexville","Reydell","Reydon","Reyno","Reynoldsburg","Reynoldsville","Rhame","Rheems","Rhinebeck","Rhinecliff","Rhinehart","Rhineland","Rhinelander","Rhoadesville","Rhodelia","Rhodell","Rhodesdale","Rhodhiss","Rialto","Ribera","Riceboro","Ricetown","Riceville","Richardsville","Richardton","Richburg","Richey","Richeyville","Richford","Richland","Richlands","Richmonddale","Richmondville","Richton","Richvale","Richview","Richville","Richwood","Richwoods","Ricketts","Rickman","Rickreall","Riddlesburg","Riddleton","Riderwood","Ridgecrest","Ridgedale","Ridgefield","Ridgeland","Ridgeley","Ridgely","Ridgeview","Ridgeville","Ridgeway","Ridgewood","Ridott","Riegelsville","Riegelwood","Rienzi","Riesel","Rifton","Rigby","Riggins","Rileyville","Rillito","Rillton","Rimersburg","Rimini","Rinard","Rincon","Rindge","Riner","Rineyville","Ringgold","Ringle","Ringling","Ringoes","Ringold","Ringsted","Ringtown","Ringwood","Rion","Riparius","Ripon","Rippey","Ripplemead","Rippon","Ririe","Risco","Rison","Ritner","Rittman","Ritzville","Riva","Riverdale","Riveredge","Riverhead","Riverton","Rivervale","Riverview","Rives","Rivesville","Rixeyville","Rixford","Roachdale","Roann","Roanoke","Roark","Robards","Robbinston","Robbinsville","Robeline","Robersonville","Robertlee","Robertsburg","Robertsdale","Robertsville","Robesonia","Robinette","Robins","Robinsonville","Robson","Robstown","Roby","Roca","Rochdale","Rochelle","Rocheport","Rochert","Rochford","Rociada","Rockdale","Rockfall","Rockfield","Rockhall","Rockham","Rockholds","Rockhouse","Rockingham","Rocklin","Rockmart","Rockport","Rockton","Rockvale","Rockview","Rockville","Rockwall","Rockwood","Rodanthe","Roderfield","Rodessa","Rodman","Roduco","Roebling","Roff","Rogerson","Rogersville","Roggen","Rohrersville","Rolesville","Rolette","Rolfe","Rolla","Rollin","Rollingstone","Rollinsford","Rollinsville","Roma","Romayor","Rombauer","Romney","Ronan","Ronceverte","Ronco","Ronda","Ronkonkoma","Ronks","Roopville","Rootstown","Roper","Ropesville","Rosalia","Rosamond","Rosanky","Rosario","Rosburg","Roscoe","Roscommon","Roseau","Roseboom","Roseboro","Roseburg","Rosedale","Roselawn","Roselle","Rosemead","Rosemont","Rosendale","Rosenhayn","Roseville","Rosewood","Rosharon","Rosholt","Rosiclare","Rosie","Rosine","Roslyn","Rosman","Rossburg","Rosser","Rossiter","Rosslyn","Rossmore","Rosston","Rossville","Roswell","Rota","Rotan","Rothbury","Rothsay","Rothville","Rougemont","Rougon","Roundo","Roundrock","Rouseville","Rouzerville","Rover","Rowan","Rowesville","Rowlesburg","Rowlett","Rowletts","Roxana","Roxboro","Roxie","Roxobel","Roxton","Royalton","Royersford","Royston","Rozel","Rozet","Rubicon","Ruckersville","Rudd","Rueter","Rufe","Ruffin","Ruffsdale","Rugby","Ruidoso","Ruleville","Rulo","Rumely","Rumney","Rumsey","Rumson","Runa","Runnells","Runnemede","Rupert","Ruralhall","Rushford","Rushland","Rushsylvania","Rushville","Ruskin","Ruso","Russellton","Russellville","Russiaville","Rustburg","Ruston","Rutherfordton","Rutheron","Ruthton","Ruthven","Ruthville","Rydal","Ryde","Ryderwood","Ryland","Sabael","Sabanahoyos","Sabanaseca","Sabattus","Sabetha","Sabillasville","Sabin","Sabinal","Sabinsville","Sabula","Sacaton","Saco","Sacul","Sadieville","Sadorus","Sadsburyville","Saegertown","Saffell","Safford","Sagamore","Sagaponack","Sagle","Sagola","Saguache","Sahuarita","Saipan","Salado","Salamanca","Saldee","Salem","Salemburg","Salesville","Salford","Salfordville","Salida","Salinas","Salineno","Salineville","Salitpa","Salix","Salkum","Salley","Sallis","Sallisaw","Salol","Salome","Salterpath","Salters","Saltgum","Saltillo","Saltlick","Saltsburg","Saltville","Saluda","Salvisa","Salyer","Salyersville","Samantha","Samaria","Samburg","Samnorwood","Sapulpa","Saragosa","Sarahann","Sarahsville","Saraland","Saranac","Sarcoxie","Sardinia","Sardis","Sarepta","Sargeant","Sargents","Sargentville","Sarita","Sarles","Sarona","Saronville","Sartell","Sarton","Sarver","Sasabe","Sasakwa","Saspamco","Sasser","Satanta","Satartia","Satsop","Satsuma","Sattley","Saucier","Saugatuck","Saugerties","Saugus","Saukville","Saulsbury","Saum","Saunderstown","Saunemin","Sauquoit","Sausalito","Sautee","Savanna","Saverton","Savery","Savona","Savonburg","Sawyerville","Saxapahaw","Saxe","Saxeville","Saxis","Saxonburg","Saxton","Saybrook","Saylorsburg","Sayner","Sayre","Sayreville","Sayville","Scalf","Scammon","Scandia","Scappoose","Scarbro","Scarville","Schaghticoke","Schaller","Schaumburg","Scheller","Schellsburg","Schenevus","Schenley","Schererville","Schertz","Schlater","Schleswig","Schley","Schnecksville","Schoharie","Schriever","Schulenburg","Schulter","Schurz","Schuylerville","Schwertner","Scio","Sciota","Scipio","Scituate","Scobey","Scooba","Scottdale","Scottown","Scotts","Scottsboro","Scottsburg","Scottsmoor","Scottsville","Scottville","Screven","Scribner","Scroggins","Scuddy","Seabeck","Seabrook","Seacliff","Seadrift","Seaford","Seaforth","Seagirt","Seagoville","Seahurst","Seale","Sealston","Sealy","Seanor","Searcy","Searles","Searsboro","Searsmont","Searsport","Seaton","Seatonville","Seaview","Sebastopol","Sebec","Sebeka","Sebewaing","Seboeis","Seboyeta","Sebree","Sebring","Secaucus","Seco","Secor","Sedalia","Sedgwick","Sedley","Sedona","Seekonk","Seeley","Seelyville","Seffner","Seguin","Seibert","Seiling","Seitz","Sekiu","Selah","Selby","Selbyville","Selden","Seligman","Sellers","Sellersburg","Sellersville","Sells","Selmer","Selz","Semmes","Semora","Sena","Senath","Senatobia","Senecaville","Seney","Sennett","Senoia","Sepulveda","Sequatchie","Sequim","Serafina","Seree","Serena","Servia","Sesser","Sevenmile","Severance","Severy","Sevierville","Sewanee","Sewaren","Sewell","Sewickley","Sextonville","Shabbona","Shacklefords","Shadydale","Shadyside","Shafter","Shaftsburg","Shaftsbury","Shakopee","Shalimar","Shallotte","Shallowater","Shambaugh","Shamokin","Shandaken","Shandon","Shaniko","Shanks","Shanksville","Shannock","Shapleigh","Sharpes","Sharples","Sharps","Sharpsburg","Sharpsville","Sharptown","Shartlesville","Shattuc","Shattuckville","Shauck","Shawanee","Shawanese","Shawano","Shawboro","Shawmut","Shawneetown","Shawsville","Shawville","Sheakleyville","Sheboygan","Shedd","Sheds","Shelbiana","Shelbina","Shelburn","Shelburne","Shelbyville","Sheldahl","Sheldonville","Shellman","Shellsburg","Shelly","Shelocta","Shepardsville","Shepherdstown","Sheppton","Sherard","Sherborn","Sherburn","Sherburne","Sherrard","Sherrodsville","Shevlin","Sheyenne","Shickley","Shickshinny","Shidler","Shiner","Shingleton","Shingletown","Shinhopple","Shinnston","Shiocton","Shippensburg","Shippenville","Shipshewana","Shirland","Shirleysburg","Shiro","Shoals","Shobonier","Shohola","Shokan","Shongaloo","Shopville","Shoreham","Shorter","Shorterville","Shortsville","Shoshone","Shoshoni","Showell","Showlow","Shreve","Shrewsbury","Shubert","Shubuta","Shulerville","Shullsburg","Shumway","Shunk","Shuqualak","Shushan","Shutesbury","Sias","Sicklerville","Sidell","Sidman","Sidnaw","Sidon","Sieper","Sierraville","Sigel","Sigourney","Sigurd","Sikes","Sikeston","Siler","Silerton","Siletz","Silex","Siloam","Silsbee","Siluria","Silva","Silvana","Silverado","Silvercliff","Silverdale","Silverpeak","Silverplume","Silverstar","Silverstreet","Silverton","Silverwood","Silvis","Simla","Simmesport","Simms","Simonton","Simpsonville","Simsboro","Simsbury","Sinclairville","Singer","Sinnamahoning","Sinsinawa","Sinton","Sipesville","Sipsey","Sisseton","Sisters","Sistersville","Sitka","Sixes","Sixmile","Skamokawa","Skandia","Skanee","Skellytown","Skelton","Skene","Skiatook","Skidmore","Skillman","Skippack","Skippers","Skipperville","Skipwith","Skokie","Skowhegan","Skykomish","Skyland","Slade","Slagle","Slanesville","Slatedale","Slatersville","Slatington","Slaton","Slaughters","Slayden","Slayton","Sleepyeye","Slemp","Slickville","Slidell","Sligo","Slinger","Sloansville","Sloatsburg","Slocomb","Sloughhouse","Slovan","Smackover","Smallwood","Smarr","Smartt","Smartville","Smelterville","Smethport","Smicksburg","Smilax","Smiley","Smithboro","Smithburg","Smithdale","Smithers","Smithland","Smiths","Smithsburg","Smithshire","Smithton","Smithtown","Smithville","Smithwick","Smoaks","Smock","Smoketown","Smolan","Smoot","Smyer","Sneads","Sneedville","Snelling","Snellville","Snohomish","Snoqualmie","Snover","Snowmass","Snowville","Snydersburg","Socorro","Soddy","Sodus","Solano","Solebury","Soledad","Solen","Solgohachia","Solomons","Solsberry","Solsville","Solvang","Solway","Somerdale","Somersville","Somersworth","Somerton","Somis","Somonauk","Sondheimer","Sonoita","Sontag","Sonyea","Sopchoppy","Soper","Soperton","Soquel","Sorento","Sorrento","Soso","Soudan","Souder","Soudersburg","Souderton","Soulsbyville","Souris","Spanaway","Spangler","Spanishburg","Sparkill","Sparks","Sparland","Sparr","Sparrowbush","Spartanburg","Spartansburg","Spavinaw","Speaks","Spearfish","Spearman","Spearsville","Spearville","Speculator","Speer","Speight","Spelter","Spencerport","Spencertown","Spencerville","Speonk","Sperryville","Spiceland","Spicer","Spicewood","Spickard","Spillville","Spindale","Spinnerstown","Spiritwood","Spivey","Splendora","Spofford","Spooner","Spotswood","Spotsylvania","Spottsville","Spottswood","Spraggs","Spragueville","Sprakers","Sprigg","Springboro","Springbrook","Springdale","Springer","Springerton","Springerville","Springhope","Springhouse","Springlick","Springport","Springs","Springtown","Springvale","Springville","Springwater","Sprott","Spurgeon","Spurger","Spurlock","Spurlockville","Squires","Staatsburg","Stacyville","Staffordville","Stahlstown","Stambaugh","Stamps","Stanaford","Stanardsville","Stanberry","Stanchfield","Stanfield","Stanfordville","Stanleytown","Stantonsburg","Stantonville","Stanville","Stanwood","Staplehurst","Staples","Starbuck","Starford","Starke","Starks","Starksboro","Starkville","Starkweather","Starrucca","Startex","Statenville","Statesboro","Statesville","Statham","Stayton","Steamburg","Stedman","Steedman","Steeleville","Steelville","Steens","Steff","Steffenville","Steger","Stehekin","Steinauer","Steinhatchee","Stendal","Stephan","Stephensburg","Stephentown","Stephenville","Steptoe","Sterlington","Sterrett","Stetsonville","Steubenville","Stevensburg","Stevensville","Stevinson","Stewardson","Stewartstown","Stewartsville","Stewartville","Sthelena","Stickney","Stidham","Stigler","Stilesville","Stillmore","Stillwell","Stilwell","Stinesville","Stinnett","Stirrat","Stites","Stittville","Stitzer","Stockdale","Stockertown","Stockett","Stockland","Stockport","Stockville","Stockwell","Stoddard","Stokesdale","Stollings","Stoneboro","Stonefort","Stonega","Stoneham","Stoneville","Stonington","Stonybottom","Stonybrook","Stonyford","Storden","Stormville","Storrie","Storrs","Stottville","Stoughton","Stoutland","Stoutsville","Stovall","Stover","Stowe","Stowell","Stoy","Stoystown","Strabane","Strafford","Strandburg","Strandquist","Strang","Strasburg","Stratham","Strathcona","Strathmere","Strathmore","Strattanville","Straughn","Strausstown","Strawn","Streamwood","Streator","Streeter","Streetman","Streetsboro","Stringer","Stringtown","Stroh","Stromsburg","Stronghurst","Stroud","Stroudsburg","Strunk","Struthers","Stryker","Strykersville","Studley","Stumptown","Sturdivant","Sturgis","Sturkie","Sturtevant","Suamico","Subiaco","Sublette","Sublime","Sublimity","Succasunna","Suches","Sudbury","Sudith","Sudlersville","Suffern","Suffield","Sugarland","Sugarloaf","Sugartown","Sugartree","Sula","Sulligent","Sultana","Sumas","Sumerco","Sumerduck","Sumiton","Summerdale","Summerfield","Summerland","Summerlee","Summershade","Summersville","Summerton","Summertown","Summerville","Summit","Summitville","Sumneytown","Sumpter","Sumrall","Sumterville","Sunapee","Sunbright","Sunburg","Sunburst","Sunbury","Suncook","Sundance","Sunderland","Sunfield","Sunland","Sunman","Sunnyside","Sunnysouth","Sunol","Sunray","Supai","Suplee","Suquamish","Surgoinsville","Suring","Surrency","Surry","Susank","Susanville","Susquehanna","Sutersville","Sutherlin","Sutter","Suwanee","Suwannee","Svea","Swainsboro","Swaledale","Swampscott","Swannanoa","Swansboro","Swansea","Swanton","Swanville","Swanwick","Swartswood","Swartz","Swayzee","Swedeborg","Swedesboro","Swedesburg","Sweeden","Sweeny","Sweetbriar","Sweetgrass","Sweetland","Sweetser","Sweetwater","Swengel","Swepsonville","Swifton","Swiftown","Swiftwater","Swink","Swisher","Switchback","Swoope","Sybertsville","Sykeston","Sykesville","Sylacauga","Sylmar","Sylva","Symsonia","Syosset","Taberg","Tabernash","Tabiona","Tabor","Tacna","Tacoma","Taconic","Taconite","Tafton","Taftsville","Taftville","Tahlequah","Tahoka","Taholah","Tahuya","Taiban","Taintor","Talala","Talbert","Talbot","Talbott","Talbotton","Talco","Talcott","Talihina","Talisheek","Talladega","Tallapoosa","Tallassee","Tallega","Tallevast","Tallmadge","Tallman","Tallmansville","Tallula","Tallulah","Talmage","Talmo","Taloga","Talpa","Tama","Tamaqua","Tamaroa","Tamassee","Tamiment","Tamms","Tampico","Tams","Tamworth","Taneytown","Taneyville","Tangier","Tangipahoa","Tanner","Tannersville","Taopi","Taplin","Tapoco","Tappahannock","Tappan","Tappen","Tarboro","Tarentum","Tarheel","Tariffville","Tarkio","Tarpley","Tarrs","Tarzana","Tasley","Taswell","Tatamy","Tateville","Tatum","Tatums","Taunton","Tavares","Tavernier","Taylors","Taylorstown","Taylorsville","Taylorville","Tazewell","Tchula","Teaberry","Teachey","Teague","Teaneck","Teasdale","Teays","Tebbetts","Tecate","Techny","Tecopa","Tecumseh","Tefft","Tehachapi","Tehama","Tehuacana","Tekamah","Tekoa","Tekonsha","Telferner","Telford","Telluride","Telogia","Temecula","Tempe","Templeville","Tenafly","Tenaha","Tendoy","Tenino","Tenmile","Tennant","Tennent","Tennga","Tennille","Tensed","Tenstrike","Tererro","Teresita","Terlingua","Terlton","Termo","Terraalta","Terraceia","Terral","Terrebonne","Terrell","Terreton","Terril","Terryville","Tescott","Tesla","Tesuque","Teton","Tetonia","Teutopolis","Tewksbury","Texarkana","Texhoma","Texico","Texline","Texola","Texon","Thacker","Thackerville","Thatcher","Thawville","Thaxton","Thayne","Thedford","Theilman","Thendara","Theodosia","Theriot","Thermopolis","Therock","Thetford","Thibodaux","Thida","Thiells","Thiensville","Thomasboro","Thomaston","Thomastown","Thomasville","Thompsons","Thompsontown","Thompsonville","Thonotosassa","Thornburg","Thorndale","Thorndike","Thornfield","Thorntown","Thornville","Thornwood","Thorp","Thorsby","Throckmorton","Thurmond","Thurmont","Thurston","Tibbie","Tiburon","Tichnor","Tickfaw","Ticonderoga","Tidioute","Tiesiding","Tieton","Tiff","Tiffin","Tifton","Tigerton","Tigerville","Tignall","Tigrett","Tijeras","Tilden","Tilghman","Tiline","Tillamook","Tillar","Tillatoba","Tilleda","Tiller","Tillery","Tillman","Tillson","Tilly","Tilton","Tiltonsville","Timberville","Timblin","Timbo","Timewell","Timken","Timmonsville","Timnath","Timonium","Timpson","Tingley","Tinnie","Tinsley","Tintah","Tiona","Tionesta","Tiplersville","Tippecanoe","Tippo","Tipton","Tiptonville","Tiro","Tishomingo","Tiskilwa","Titonka","Titusville","Tiverton","Tivoli","Toaalta","Toabaja","Toano","Tobaccoville","Tobias","Tobinsport","Tobyhanna","Toccoa","Toccopola","Tocsin","Toddville","Tofte","Tohatchi","Toivola","Tokeland","Tokio","Tolar","Toler","Tolland","Tollesboro","Tolleson","Tolley","Tolna","Tolono","Tolu","Toluca","Tomah","Tomahawk","Tomales","Tomball","Tombean","Tompkinsville","Tomsbrook","Tonalea","Tonasket","Tonawanda","Toney","Tonganoxie","Tonica","Tonkawa","Tonopah","Tontitown","Tontobasin","Tontogany","Tooele","Toomsboro","Toomsuba","Toone","Topanga","Topawa","Topinabee","Topock","Toponas","Toppenish","Topping","Topsfield","Topsham","Topton","Toquerville","Torbert","Tornillo","Torreon","Torrey","Torrington","Toston","Totowa","Totz","Touchet","Tougaloo","Toughkenamon","Toulon","Toutle","Tovey","Towaco","Towanda","Towaoc","Towner","Townley","Townshend","Townsville","Townville","Toxey","Toyah","Toyahvale","Tracyton","Traer","Trafalgar","Trafford","Trampas","Tranquility","Trappe","Traskwood","Traunik","Traver","Treadway","Treadwell","Trebloc","Treece","Trego","Treichlers","Treloar","Trementina","Tremont","Tremonton","Trempealeau","Trenary","Trent","Tresckow","Trespiedras","Trespinos","Trevett","Trevor","Trevorton","Trexlertown","Treynor","Trezevant","Triadelphia","Tribbett","Tridell","Trilby","Trilla","Trimble","Trimont","Trinchera","Trinway","Trion","Tripp","Trivoli","Trona","Trosky","Trosper","Trotters","Troup","Troupsburg","Troutdale","Troutville","Troxelville","Truckee","Truesdale","Trufant","Truk","Trumann","Trumansburg","Truro","Truscott","Trussville","Truxton","Tryon","Tualatin","Tubac","Tuckahoe","Tuckasegee","Tuckerman","Tuckerton","Tucumcari","Tujunga","Tula","Tulare","Tularosa","Tuleta","Tulia","Tullahassee","Tullahoma","Tullos","Tully","Tumacacori","Tunas","Tunica","Tunkhannock","Tunnelton","Tuolumne","Tupman","Turbeville","Turbotville","Turlock","Turner","Turners","Turnersburg","Turnersville","Turnerville","Turney","Turon","Turpin","Turrell","Turtletown","Turton","Tuscarawas","Tuscola","Tuscumbia","Tuskahoma","Tussy","Tustin","Tutorkey","Tutwiler","Twinbrooks","Twining","Twinoaks","Twinsburg","Twisp","Tyaskin","Tye","Tylersburg","Tylersport","Tylersville","Tylerton","Tylertown","Tynan","Tyner","Tyngsboro","Tyro","Tyrone","Tyronza","Tyty","Ubly","Ucon","Udall","Udell","Uehling","Uhrichsville","Ukiah","Uledi","Ulen","Ullin","Ulm","Ulman","Ulmer","Umatilla","Umbarger","Umpqua","Una","Unadilla","Uncasville","Underwood","Uneeda","Unicoi","Uniondale","Unionhall","Unionport","Uniontown","Unionville","Uniopolis","United","Unityhouse","Unityville","University","Upham","Upperco","Upperville","Upsala","Upson","Uravan","Urbanna","Uriah","Urich","Ursina","Ute","Utuado","Uvalda","Uvalde","Uwchland","Vacaville","Vacherie","Vada","Vader","Vadis","Vadito","Vaiden","Valatie","Valders","Valdese","Valdez","Valdosta","Valencia","Valentines","Valera","Valier","Vallecito","Vallecitos","Vallejo","Valliant","Vallonia","Valmeyer","Valmy","Valona","Valrico","Valyermo","Vanalstyne","Vanceboro","Vanceburg","Vancourt","Vandalia","Vandemere","Vandergrift","Vanderpool","Vandervoort","Vandiver","Vanduser","Vandyne","Vanetten","Vanhorn","Vanna","Vanndale","Vannuys","Vanorin","Vansant","Vantassell","Vanvleck","Vanwert","Vanwyck","Vanzant","Vardaman","Varina","Varna","Varnell","Varney","Varnville","Varysburg","Vashon","Vass","Vassalboro","Vaucluse","Vaughnsville","Vauxhall","Veblen","Veedersburg","Vegabaja","Veguita","Velarde","Velma","Velpen","Velva","Venango","Venedocia","Venedy","Veneta","Venetia","Ventress","Ventura","Venturia","Veradale","Verbank","Verda","Verdel","Verden","Verdigre","Verdon","Verdunville","Vergas","Vergennes","Veribest","Vermillion","Vermontville","Verndale","Verner","Vernon","Vernonia","Verplanck","Vershire","Vertrees","Vesta","Vestaburg","Vesuvius","Vevay","Vian","Viborg","Vicco","Vici","Vick","Vickery","Victorville","Vidalia","Vidor","Vieques","Viewtown","Vilas","Villalba","Villamaria","Villamont","Villanova","Villanueva","Villard","Villarica","Villas","Villisca","Vilonia","Vina","Vincennes","Vincentown","Vineburg","Vineland","Vinemont","Vining","Vinita","Vinton","Vintondale","Viper","Virden","Virgie","Virgilina","Virginville","Viroqua","Visalia","Vliets","Voca","Volant","Volborg","Volga","Volin","Volney","Voluntown","Vona","Vonore","Vonormy","Voorheesville","Vossburg","Votaw","Vowinckel","Vredenburgh","Waban","Wabasha","Wabasso","Wabbaseka","Wabeno","Waccabuc","Wachapreague","Wacissa","Waconia","Waddell","Waddington","Waddy","Wadena","Wadesboro","Wadestown","Wadesville","Wadhams","Wadley","Waelder","Wagarville","Wagener","Waggoner","Wagoner","Wagontown","Wagram","Wahiawa","Wahkiacus","Wahkon","Wahoo","Wahpeton","Waialua","Waianae","Wailuku","Waimanalo","Waimea","Wainscott","Waipahu","Waiteville","Waitsburg","Waitsfield","Waka","Wakarusa","Wakeeney","Wakeman","Wakenda","Wakita","Wakonda","Wakpala","Wakulla","Walburg","Waldenburg","Waldoboro","Waldport","Waldwick","Wales","Waleska","Walford","Walhalla","Walhonding","Walker","Walkersville","Walkerton","Walkertown","Walkerville","Wallaceton","Walland","Wallback","Wallburg","Walling","Wallingford","Wallisville","Wallkill","Wallowa","Wallsburg","Wallula","Walnutshade","Walsenburg","Walshville","Walston","Walstonburg","Walterboro","Waltersburg","Walterville","Walthall","Walthourville","Waltonville","Walworth","Wamego","Wampsville","Wampum","Wamsutter","Wana","Wanakena","Wanamingo","Wanaque","Wanatah","Wanblee","Wanchese","Wanda","Wando","Waneta","Wanette","Wann","Wannaska","Wantagh","Wapakoneta","Wapanucka","Wapella","Wapello","Wappapello","Wapwallopen","Warba","Warda","Wardell","Wardensville","Wardsboro","Wardtown","Wardville","Wareham","Waresboro","Wareshoals","Waretown","Warfield","Warfordsburg","Warminster","Warne","Warner","Warners","Warnerville","Warnock","Warrendale","Warrens","Warrensburg","Warrensville","Warrenton","Warrenville","Warrington","Wartburg","Warthen","Wartrace","Wasco","Wascott","Waseca","Washita","Washougal","Washta","Washtucna","Waskish","Waskom","Wasola","Wassaic","Wasta","Wataga","Watauga","Waterboro","Waterflow","Waterford","Waterport","Watersmeet","Waterview","Waterville","Watervliet","Watha","Wathena","Watkinsville","Watonga","Watrous","Watseka","Watsontown","Watsonville","Watton","Wattsburg","Wattsville","Waubun","Wauchula","Waucoma","Wauconda","Waukau","Waukee","Waukegan","Waukesha","Waukomis","Waukon","Wauna","Waunakee","Wauneta","Waupaca","Waupun","Wauregan","Waurika","Wausa","Wausau","Wausaukee","Wauseon","Wautoma","Wauzeka","Waveland","Waverley","Waverly","Waves","Wawaka","Wawarsing","Wawina","Waxahachie","Waxhaw","Wayan","Waycross","Wayland","Waymart","Waynesboro","Waynesburg","Waynesfield","Waynesville","Waynetown","Waynoka","Wayzata","Weare","Weatherby","Weatherford","Weatherly","Weatogue","Weaubleau","Weaver","Weaverville","Webber","Webberville","Webbville","Websterville","Wedderburn","Wedgefield","Wedowee","Wedron","Weedsport","Weedville","Weeksbury","Weems","Weepingwater","Weesatche","Weidman","Weikert","Weimar","Weiner","Weinert","Weippe","Weirsdale","Weirton","Weirwood","Weiser","Weissert","Welaka","Welches","Welda","Weldona","Weleetka","Wellborn","Wellford","Welling","Wellman","Wellpinit","Wellsboro","Wellsburg","Wellston","Wellsville","Wellton","Welton","Welty","Wenatchee","Wendel","Wenden","Wendover","Wenham","Wenona","Wenonah","Wentworth","Wentzville","Weogufka","Weott","Wernersville","Wesco","Weskan","Weslaco","Wessington","Wesson","Westby","Wethersfield","Wetmore","Wetumka","Wetumpka","Wever","Wevertown","Wewahitchka","Wewela","Wewoka","Wexford","Weyanoke","Weyauwega","Weyerhaeuser","Weymouth","Whalan","Whaleysville","Whallonsburg","Wharncliffe","Whately","Wheatcroft","Wheatfield","Wheatland","Wheatley","Wheaton","Wheeler","Wheelersburg","Wheeling","Wheelock","Wheelwright","Whick","Whigham","Whipholt","Whippleville","Whiskeytown","Whitakers","Whitby","Whitebird","Whiteclay","Whitefield","Whitefish","Whiteford","Whiteheath","Whitehouse","Whiteland","Whitelaw","Whiteowl","Whitepost","Whitesboro","Whitesburg","Whiteside","Whitestone","Whitestown","Whitesville","Whitethorn","Whiteville","Whitewater","Whitewood","Whitewright","Whitfield","Whitharral","Whiting","Whitingham","Whitinsville","Whitlash","Whitleyville","Whitmer","Whitmire","Whitmore","Whitneyville","Whitsett","Whitt","Whittemore","Whitten","Whittington","Whitwell","Whon","Wibaux","Wiborg","Wickatunk","Wickenburg","Wickes","Wickett","Wickliffe","Wicomico","Wiconisco","Wideman","Widener","Widnoon","Wikieup","Wilbar","Wilber","Wilberforce","Wilbraham","Wilburn","Wilburton","Wilcoe","Wilder","Wildersville","Wildhorse","Wildie","Wildomar","Wildorado","Wildrose","Wildsville","Wildwood","Wileyville","Wilkesbarre","Wilkesboro","Wilkeson","Wilkesville","Willacoochee","Willamina","Willards","Willcox","Willernie","Willet","Williamsfield","Williamsport","Williamston","Williamstown","Williamsville","Williford","Willimantic","Willingboro","Willisburg","Williston","Willisville","Willits","Willmar","Willows","Willowshade","Willowstreet","Willowwood","Willsboro","Willseyville","Willshire","Wilmar","Wilmer","Wilmerding","Wilmette","Wilmont","Wilmore","Wilmot","Wilsall","Wilsey","Wilseyville","Wilsie","Wilsonburg","Wilsondale","Wilsons","Wilsonville","Wilton","Wimauma","Wimberley","Wimbledon","Winamac","Winburne","Winchendon","Windber","Winder","Windermere","Windham","Windom","Windthorst","Windyville","Winesburg","Winfall","Winfred","Wingdale","Winger","Wingina","Wingo","Winifrede","Winigan","Winkelman","Winlock","Winn","Winnabow","Winnebago","Winneconne","Winnemucca","Winner","Winnetoon","Winnett","Winnfield","Winnisquam","Winnsboro","Winona","Winside","Winsted","Winston","Winstonville","Winterport","Winterset","Winterthur","Winterville","Winton","Wiota","Wirtz","Wisacky","Wiscasset","Wiseman","Wishek","Wishram","Wisner","Wister","Withams","Withee","Witherbee","Witmer","Witten","Wittenberg","Wittensville","Witter","Wittman","Wittmann","Wixom","Woburn","Woden","Wolbach","Wolcottville","Wolfcoal","Wolfeboro","Wolfforth","Wolford","Wolfpen","Wolftown","Wollaston","Wolsey","Wolverine","Wolverton","Womelsdorf","Wonalancet","Wonewoc","Wonnie","Woodacre","Woodbine","Woodbourne","Woodburn","Wooddale","Woodfield","Woodford","Woodhull","Woodinville","Woodleaf","Woodlyn","Woodman","Woodmere","Woodsboro","Woodscross","Woodsfield","Woodshole","Woodson","Woodstock","Woodston","Woodstown","Woodsville","Woodville","Woodworth","Woolford","Woollum","Woolrich","Woolstock","Woolwich","Woolwine","Woonsocket","Woosung","Wooton","Worden","Worland","Worley","Woronoco","Wortham","Worthing","Worthville","Worton","Woxall","Wray","Wren","Wrens","Wrenshall","Wrentham","Wrights","Wrightsboro","Wrightstown","Wrightsville","Wrightwood","Wurtsboro","Wyaconda","Wyalusing","Wyanet","Wyano","Wyarno","Wyckoff","Wyco","Wycombe","Wyeville","Wykoff","Wylliesburg","Wymer","Wymore","Wynantskill","Wyncote","Wyndmere","Wynnburg","Wynne","Wynnewood","Wynona","Wynot","Wyocena","Wyola","Wyoming","Wysox","Wytheville","Wytopitlock","Xenia","Yabucoa","Yachats","Yacolt","Yadkinville","Yalaha","Yampa","Yancey","Yanceyville","Yankeetown","Yantic","Yantis","Yaphank","Yards","Yarnell","Yatesboro","Yatesville","Yauco","Yawkey","Yeaddiss","Yeagertown","Yellowstone","Yellville","Yelm","Yemassee","Yerington","Yermo","Yeso","Yettem","Yoakum","Yolo","Yolyn","Yoncalla","York","Yorklyn","Yorkshire","Yorkville","Yosemite","Youngsville","Youngtown","Youngwood","Yountville","Yreka","Yucaipa","Yulan","Yulee","Yuma","Yutan","Zacata","Zachariah","Zachow","Zahl","Zaleski","Zalma","Zamora","Zanesfield","Zanesville","Zanoni","Zapata","Zavalla","Zearing","Zebulon","Zeeland","Zeigler","Zelienople","Zell","Zellwood","Zenda","Zenia","Zeona","Zephyr","Zieglerville","Zillah","Zim","Zionsville","Zionville","Zirconia","Zoar","Zortman","Zullinger","Zumbrota","Zuni","Zwingle","Zwolle"];
\ No newline at end of file
@@ -1 +1 @@
-pub const STREET: [&'static str; 11456] = ["A. Alfred Lombardi","Aaron Park","Abberton","Abbey Glen","Abbey Orchard","Abbeyview","Abbots","Abbotsford Cove","Abbott Valley View","Abbywood","Abeel","Abend","Abercorn","Aberfoil","Abernathy","Abilene","Abirdge","Abney Court","Abraham Kazan","Absalom","Academic","Acampo","Accokeek","Accord Park","Acerra","Achillies","Ackers","Ackmar","Aconbury","Acorn Wharf","Acregate","Actinotus","Actriz","Adahmore","Adam Albright","Adams Park","Adanac","Adclare","Adderstone","Addison","Adel","Adelia","Aden","Adhara","Adj.","Admin Service","Admiralty","Adolphus","Adria","Adshall","Advent","Aegean","Aero","Af","Afternoon","Agate","Agdon","Agius","Agnon","Agricultural Farm","Ah","Ahneita","Aidan","Ailsworth","Ainsbury","Ainsty","Air Force","Airdsley","Airmen","Airport Plaza","Aislibie","Ak","Akerman","Akira","Al Ventura","Alaga","Alameda Park","Alamo Ranch","Alan Crest","Alanhurst","Alastair","Albany","Albermale","Albert Einstein","Alberti","Albezzia","Albot","Albuera","Alcalde","Alcinda","Alconbury","Alda","Aldcroft","Alden Glen","Alder Creek","Aldercar","Alderford","Alderminster","Aldersgate","Alderstone","Alderwood Mall","Aldine","Aldred","Aldro","Aldwych","Alegre","Alene","Alessio","Alewife","Alexander Hamilton","Alexendria","Alfalfa","Alford","Alfreg","Algen","Algonkian","Alhambra Creek","Alicante","Alida","Alington","Alissa","Alkamont","Alkire","Alladin","Allandale","Allard","Allday","Allegany","Allemong","Allen Robert","Allensby","Allerton","Allevard","Allgrove","Alling","Allister","Allocco","Allred","Allward","Alma Bridge","Almadera","Almar","Almendral","Almon","Almonds","Almroth","Alnwood","Alorn","Alph","Alphonsus","Alpita","Alridge","Alschuler","Alston","Alta Glen","Alta Sierra","Altacrest","Altamont Pass","Altessa","Althorpe","Alto","Altoona","Altura","Alumrock","Alveley","Alverton","Alvina","Alward","Alwoodley The","Alysheba","Amado","Amalia","Amaranta","Amaro","Amax","Amber Creek","Amberg","Amberleaze","Amberwood","Ambley","Ambrooke","Ambush","Amenbury","America","American Holly","Amerigo","Ames Hill","Amherst Bank","Amiens","Amisse","Amli","Ammunition","Amory","Ampeg","Amsbury","Amundsen","Amyruth","Anaconda","Anamosa","Anatolia","Anchor","Ancon","Andalusian","Anders","Anderson Scout Camp","Andora","Andre","Andres","Andrews Farm","Andsbury","Anerley Park","Angel Falls","Angelica","Angelo","Angie","Anglers","Angley","Angouleme","Animbo","Anka","Ann Arbor","Anna","Annable","Annamarie","Annapolitan","Anne Arundel","Annella","Annete","Annico","Anning","Annmar","Annursnac Hill","Anon","Ansdell","Ansley","Anstone","Antell","Antholl","Antile","Antlands","Antonette","Antrican","Anza","Apara","Apfel","Aplomado","Appamatox","Apple","Apple Glen","Apple Ridge","Appleberry","Applecross","Applegreen","Appleton","Appollo","April Journey","Apthrop","Aptos Hill","Aqua Vista","Aqueduct","Arab","Araglen","Aralluen","Aranda","Arate","Arbella","Arbor","Arbor Hills","Arbor Vine","Arborfield Walden","Arborwood","Arbuckle","Arcadia","Arch","Archdale","Arches","Archway","Arcturus","Ardaugh","Arden Forest","Ardenmore","Ardfern","Ardilla Canyon","Ardley","Ardra","Ardwell","Arends","Argall","Argo","Arguimbau","Ariana","Aries","Arista","Arkansas","Arkland","Arlee","Arleta","Arlies","Arlmont","Arm","Armat","Armett","Armington","Armitree","Armley Ridge","Armour","Armstead","Arncliff","Arner","Arnheim","Arnold Park","Arnould","Aromas","Aroostook","Arran","Arrol","Arrowhead Farm","Arrowwood","Arroyo Sierra","Art Schultz","Artery","Arthur","Arthur Woods","Artillery","Artlett","Arun","Arundel Gateway","Arvale","Arye","Ascadilla","Ascol","Asford","Ash Park","Ashbel","Ashbrook Hey","Ashburton","Ashcott","Ashdown","Ashendene","Ashfield Farm","Ashington","Ashlawn","Ashley Manor","Ashlon","Ashmill","Ashness","Ashton Clough","Ashtree","Ashwells","Askam","Askill","Asmus","Aspen","Aspen Valley","Aspenwood","Aspinwall","Ass House","Assets","Assonet","Aste","Astin","Aston Clinton","Astra","Astron","Asylum","Atchison","Athel","Athena","Atherfold","Athlon","Atilda","Atlantic Hills","Atleigh","Atria","Atteentee","Attimore","Attow","Atwill","Aubreen","Auburn Lakes","Auciello","Audenshaw","Audmar","Audubon","Augusta Point","Auld","Auman","Aurelia Sylvia","Auriol Park","Austhorpe","Australis","Authors","Autocenter","Autrey","Autumn Fields","Autumn Meadow","Autumn Trail","Autumnwood","Avalli","Avard","Aveline","Avenel","Avenleigh","Averenches","Avery","Aviator","Aviston","Avona","Avonmore","Awaba","Awbrey Patent","Axdell","Axletree","Aycliffe","Aylands","Aylesworth","Aylsham","Aynor","Ayreshire","Ayrton Senna","AzTec","Azara","Azimuth","Azure","B V French","BP Davies","Babbe","Babe Ruth Park","Babetta","Babula","Bacchus","Bachelors","Back Albert","Back Arnold","Back Aviary","Back Baythorpe","Back Bowling Green","Back Brudenell","Back Castle","Back Chestnut","Back Colenso","Back Crawford","Back Darcy","Back Duncan","Back Eden","Back Fletcher","Back Gaythorne","Back Green","Back Halstead","Back High Bank","Back Hope","Back James","Back Knowl","Back Lindley","Back Lytton","Back Massie","Back Milner","Back Norris","Back Palm","Back Portugal","Back Rawson","Back Ryefield","Back Seymour","Back Sunnyside","Back Thorpe","Back Uttley","Back Wapping","Back Westfield","Back Woodgate","Backlick","Backwater","Bad Munstereifel","Baddow Hall","Baden Westwood","Badger Pass","Badian","Badsworth","Bafp","Baggins","Bags","Baguley","Baigents","Baileys","Bain Bridge","Bainter","Baiting Place","Bajor","Bakersmill","Balaam","Balantine","Balceta","Balcombe","Bald Pate","Baldock","Baldwin Lake","Baleri Ranch","Balgonie","Bali","Ball Hill","Ballantine","Ballard","Ballena Bay","Ballina","Balloch","Balls Head","Bally Bunion","Balm","Balmes","Balmy","Balsamo","Balstonia","Baltimore Washington","Bambara","Bambridge","Banana Grove","Bancker","Banderra","Bandy Run","Bangalay","Banister","Bank Hey Bottom","Banker","Banksfield","Banleigh","Banner Farm","Bannockburn","Bansom","Bantle Farm","Banyon","Bar Harbour","Baranga","Barbara","Barbel","Barbers Wood","Barbieri","Barcaly","Barclay","Barcroft","Bardet","Bardolier","Bardsley Gate","Bare Cove Park","Barell","Barforth","Bargmann","Barina","Bark","Barker","Barking","Barkway","Barletta","Barleycastle","Barling","Barlowe","Barn Hill","Barnabe","Barnard","Barndance","Barnes Cray","Barnesville","Barnet High","Barneveld","Barnheisel","Barnsboro","Barnstable","Barnwell","Baron Cameron","Baronnel","Baroque","Barra","Barras","Barremma","Barretto","Barrick","Barrington Point","Barron Park","Barrow Hill","Barrs Fold","Barrypoint","Barson","Bartel","Bartha","Barthorpe","Bartletts","Barton Creek","Bartram","Barway","Basalt Rock Company","Basewood","Basildon","Basing","Basket Ring","Basmore","Bass Rock","Bassett Creek","Bassler","Bastille","Basuto","Batchelor","Bateman","Batesole","Batham Gate","Bathurst","Batridge","Batten Hollow","Battery","Battery Safford","Battle Creek","Battlefield","Battock","Baugher","Baumbach","Bautista","Bawn","Bay Cliff","Bay Farms","Bay Head","Bay Park","Bay To Bay","Bay Water","Baybriar","Baydon","Bayham","Baylee","Bayliss","Baynton","Baypoint","Bayside","Baytomac Farms","Bayville","Baywood Shores","Bea Kay","Beach Park","Beachamwell","Beachler","Beachview Creek","Beacon Bay","Beacon Pond","Beaconsfield Terrace","Beadlow","Beal","Bealle Hill","Beaminster","Bean Field","Bear","Bear Glen","Bear Ridge","Beardall","Beardsley","Bearing","Bearwood","Beatrix","Beau Brummel","Beauchamps","Beaufoy","Beaumont","Beauteau","Beaven","Beaver Hill","Beaver Run","Beavers","Becado","Bechtold","Beckerle","Beckfield","Beckler","Beckwith","Becontree","Beddoo","Bedford","Bedloes","Bedwell","Bee Oak","Beech Forest","Beech Hyde","Beechbrook","Beechenlea","Beechhill","Beechmore","Beechway","Beedell","Beehrle","Beeleigh","Beemra","Beeth","Beggars Hill","Beharrell","Behrendt","Beira","Bel Aire","Bel Pre","Belar","Belcher Farm","Beldam","Belding","Belfast","Belgaro","Belgrave","Belinda","Bell Air","Bell Executive","Bell Meadow","Bell Vue","Bella Tuscany","Bellaire Hills","Bellantoni","Bellbird","Belle Ami","Belle Grove","Belle Point","Belle of Georgia","Bellefield","Bellemaine","Belleterre","Bellevue","Bellfields","Bellina","Bellman","Bello","Bellows","Bells","Bellthorne","Bellvista","Belmohr","Belmont Landing","Belnel","Belrose","Belt","Beltrami","Belvale","Belvoir Woods","Bement","Bemuth","Ben Ledi","Ben Oaks","Benaud","Bench","Bencroft","Bendemere","Bendmore","Benedictine","Benetfield","Benforest","Benham","Benicia","Benito","Benledi","Bennel","Bennett Hill","Bennetts Grove","Bennion","Benris","Bensley","Bent Bough","Bent Oak","Bentay","Bentink","Bento","Bentridge","Bentwaters","Benwell","Bepler","Berberis Walk  Laurel","Berdina","Berengrave","Berg","Bergenline","Bergin","Berith","Berkhampstead","Berkshire","Bermar","Bernacci","Bernard Ashley","Berne","Bernie","Bernstein","Berrian","Berriman","Berry Cove","Berrycroft","Berryleaf","Bersano","Berteau","Berthoud","Bertling","Berton","Berwick","Beryl","Besler","Bessels Green","Bessmer","Bestobell","Betabel","Beth Lee","Bethel Island","Bethnall","Betlo","Betsy Brown","Bettescombe","Bettowynd","Betty Grove","Beumont","Bevanwood","Beverly Hills","Beverstone","Bevis","Bexhill","Beza","Biara","Bibeau","Bicester","Bickerton","Bicknoller","Biddle","Bieber","Bienville","Bifrost","Big Branch","Big Foot","Big Peninsula","Big Rock Ridge","Big Tree","Biggers","Bighorn","Bigney","Bikini","Bilgola","Billa","Billarong","Billie Smith Memorial","Billington","Billow","Billy Diehl","Bilpin","Bimburra","Bindea","Bingen","Bingle","Binn","Binnie","Binton","Birbetts","Birch Green","Birch Pond","Birchard","Birchdale","Birches","Birchill","Birchmore","Birchwood","Bird In Hand","Birdcroft","Birdsall","Birdwood","Birken","Birkhead","Birkwood","Birney","Birriwa","Birtlespool","Bisbee","Bishoff","Bishopgate","Bishops Hall","Bishopton","Bisner","Bisshop","Bittacy","Bitternut","Bivona","Bizzibe","Black Alder","Black Briar","Black Duck","Black Hawk","Black Kite","Black Oak","Black Pond","Black Tree","Blackbank","Blackbird Hill","Blackbridge","Blackcap","Blacket","Blackford","Blackhawk Meadow","Blackington","Blacklock","Blackness","Blackpowder","Blackshaw","Blackstock","Blackthorne","Blackwater","Blackwood","Bladindon","Blain","Blairderry","Blais Farm","Blakehall","Blakemore End","Blakeslee","Blamey","Blanche Dell","Blanding","Blanken","Blarney","Blauss","Blazer","Bleasby","Bledlow Ridge","Blendall","Blenkinsop","Blewbury","Blinco","Bliss","Block House","Blom","Blood","Bloomburg","Bloomington Frwy","Blossom Cove","Blossom River","Blossomwood","Bloxhall","Blue Anchor","Blue Bird","Blue Crane","Blue Goose","Blue Hill River","Blue Lake","Blue Mill","Blue Post","Blue Rock Hill","Blue Spring","Blue Waters Farm","Bluebill","Bluefield","Bluegum","Bluestem","Bluewillow","Bluff Pointe","Blume","Blunts","Bly","Blythorn","Boar","Boardschool","Boarshead","Boatclub","Bob Reed","Bobbi","Bobby Spencer","Bobolink","Bocana","Bodalla","Bodega Bay","Bodkin","Bodrick","Boehmer","Boeske","Bogarin","Bogey","Bogny","Bohemia","Bohn","Bois","Bokelman","Bolberry","Boldman","Boleyns","Boling","Bollate","Bollinger","Bolney Chapel","Bolshaw Farm","Bolter","Boltro","Bombadier","Bomish","Bon Terre","Bonaire","Bonavesta","Bond Hollow","Bonds Retreat","Bonetti","Bonheur","Bonita","Bonneau","Bonnet","Bonnie","Bonnie Dale","Bonnie View","Bonny","Bonnys","Bonseys","Bonus Hill","Boodle","Bookhurst","Boomerang","Boones Hill","Booralie","Boot","Booth Tarkington","Boothfield","Bootjack","Boraga","Bordars","Border Hill","Bordona","Boren","Borgins","Borina","Borley","Boronia","Borregas","Borrowdale","Borthwick","Boscastle","Boscow","Bosk","Bossa","Bost","Boston Manor","Bostwick","Botanical","Bothelo","Botolph","Bottle Brush","Bottom Pond","Bou","Bougainville","Boula","Boulder Canyon","Boulder Run","Boult","Boundary","Boundstone","Bourbon","Bourne","Bournehall","Bournville","Boutemain","Bouve","Boveney New","Bow Arrow","Bowbell","Bowditch","Bower Heath","Bowerman","Bowes Creek","Bowie","Bowlen","Bowling","Bowmans Folly","Bowral","Bowstone Hill","Box Elder","Boxberry","Boxgrove","Boxted Church","Boyard","Boyden","Boyland","Boyne","Boysea","Bozen Green","Brabyn","Bracher","Brackenhurst","Brackleys","Bracton","Bradburys","Braddock Springs","Bradey","Bradgate","Bradish Farm","Bradley Forest","Bradman","Bradshad","Bradview","Brae","Braeleigh","Braeside","Bragbury","Brahma","Brain Ridge","Brairwood","Braken","Braman","Bramblebush","Brambletye Park","Bramfield","Bramingham","Brammay","Bramston","Brancaster","Branchaw","Brand","Branderburgh","Brandle","Brandon Green","Brands Hatch","Brandy Hill","Branfield","Branko","Brannan Island","Bransdale","Branstone","Brantwood","Brasero","Brassie","Brattice","Braunecker","Braverton","Braxted Park","Braycourt","Brayshaw","Braziers","Breadlands","Breakneck","Bream","Breaults Landing","Brecknock","Bredon","Breedon","Breeze Knoll","Breezy Hill","Brehaut","Breitwert","Bremerton","Brencon","Brenford","Brenner","Brent View","Brentlands","Brentsville","Breslau","Bretland","Brettenham","Breuner","Brevoort","Brewers Hill","Brewster","Brian","Briant","Briar Hill","Briar Rock","Briarchip","Briarford","Briarmont","Briarwood South","Bricin","Brickenden","Brickhouse","Brickwall","Bridalsmith","Bridewain","Bridge Farm","Bridgedale","Bridgeland","Bridgeport","Bridgeton","Bridgford","Bridle Cross","Bridlefield","Bridport","Brier","Brierhill","Brig","Brigate","Bright","Bright Sun","Brightling","Brightside","Brigid Flanigan","Brimblecomb","Brimrod","Brindabella","Brinef","Brinkerhoff","Brinley","Brinsop Hall","Briony","Briscoe Turn","Brisson","Bristol Downs","Bristolwood","Britannic","Briton","Brittany Parc","Brittney","Brixham","Broad Branch","Broad Green","Broadacres","Broadcarr","Broadgate","Broadhollow","Broadley","Broadmeadows Sheridan","Broadoaks","Broadwalk","Broadway Feather Bank","Brocade","Brock Hill","Brockham","Brockley","Brockswood","Brodia","Broe","Broken Arrow","Broken Twig","Bromehead","Bromley Cross","Bromshill","Brondsbury","Bronte","Bronxville Glen","Brook Bottom","Brook Haven","Brook Mar","Brook Village","Brookcroft","Brooke Knolls","Brookfall","Brookgreen","Brookhurst","Brooklee","Brookman","Brookmont","Brooks Terrace","Brookshade","Brookside Glen","Brooksweld","Brookway","Broom Farm","Broomers","Broomhill Park","Broomshaw","Brophy","Brotherton","Broughville","Brouwerij","Browertown","Brown Gables","Brown Ranch","Brownfield","Brownley","Browns Farm","Brownshade","Brownville","Broxholm","Brubeck","Brucewood","Bruell","Brumley","Brunell","Bruning","Brunswick","Bruntwood","Brush Prairie","Brussels","Bryan Point","Bryants Nursery","Brydon","Brynden","Brynton","Bubb","Buchal Heights","Buck Cavey","Buckbee","Bucketmill","Buckhill","Buckingham","Buckle","Buckleys","Buckmeadow","Buckout","Bucksfield","Bucktail","Buckwoods","Budds","Budgerigar","Budoch","Buell","Bueno","Buffalo Creek","Buffins","Bugbee","Bugong","Builders","Bulb","Bulfinch","Bulkeley","Bull Pine","Bullbrook","Bullers","Bullfrog Fire","Bullneck","Bulls Eye","Bullwood","Bulrush Farm","Bulwara","Bumfords","Bunarba","Buncefield","Bundanoon","Bundeson","Bungal","Bungendore","Bunker","Bunn","Bunsen","Bunts","Buono","Burbanks","Burch Hill","Burchfield","Burdeck","Burdett","Burdocks","Burford","Burgener","Burghclere","Burgos","Burich","Burke Commons","Burkeside","Burkitts","Burlescoombe","Burleys","Burlington","Burmah","Burnbrae","Burned Chimney","Burnet Hill School","Burngarten","Burnie","Burning Tree","Burns Crossing","Burnside Landing","Burnt Ember","Burnt Pollard","Burntlodge","Burpee","Burr Ridge Club","Burrage","Burrawong","Burricks Hill","Burritt","Burrows","Burshire","Burston","Burton Glen","Burtonwood","Burwood Park","Buryfield","Busch","Bush Elms","Bushby","Bushfield","Bushmead","Bushy","Buskirk","Bussey","Butano","Butchers","Butlers","Butt Field","Butter Cup","Butterfield Frontage","Buttermere","Butterside","Buttner","Buttonwood","Buttway","Buxton Old","By","Bycullah","Byerley","Bying","Byne","Byram Dock","Byrne Park","Byrons","Bywater","C Fernwood","Caballeros","Cabbel","Cabin","Cabot","Cabrillo","Cache Peak","Caddell","Cader","Cadle","Cadogan","Cadwallader","Caenshill","Cafeto","Cages Wood","Cahir","Cainfield","Cairnwell","Caithness","Calabar","Caladium","Calamo","Calaveras","Calcaterra","Caldarra","Calderbrook","Caldran","Caledonia","Caleta","Calfas","Calibria","Calif Pacific","Calista","Callabone","Callander","Callen","Callington","Callow","Calmor","Calpella","Calthrope","Calumet Access","Calverley","Calvery","Calyer","Camarena","Cambell","Camberwell","Cambon","Cambrian","Cambridgepark","Camden High","Camel Hollow","Camenson","Cameron Grove","Camilla","Camino Hermoso","Camino del Sol","Camlot","Cammerer","Camp Bluefields","Camp Grove","Camp Pearson","Campagnoli","Campbell","Campden","Campfield","Cample","Campora","Campton Crossings","Campus Green","Canacum","Canal","Canard","Canbury Park","Canden","Candle Ridge","Candlestick","Candy Apple","Canessa","Canham","Cann Hall","Cannici","Cannon Ball","Cannon Industrial","Cannondown","Canoe River","Canon Vista","Canoona","Cantalier","Canter","Cantiague","Canton","Canuden","Canyon Creek","Canyon Lake","Canyon Six","Canyon Woods","Capatin Hunter","Cape Buffalo","Cape Kennedy","Capehart","Capen Hill","Capewood","Capital Hill","Capitol Hill","Caples","Cappell","Capriconus","Capstone","Captain Duval","Captain Joshua","Captain Torrey","Captiva","Cara","Caran","Carawatha","Carbon","Carbury","Carden","Cardigan","Cardinal Estate","Cardoso","Cardwell","Careo","Carey School","Cargill","Cariage","Carill","Carindale","Carisa","Carl Sandburg","Carle","Carley","Carline","Carlisle on Duxbury","Carlos","Carlsbrook","Carlton","Carly Creek","Carman","Carmela","Carmelwood","Carmichael","Carmona","Carnation","Carneros","Carnoustie","Carol Anne","Carolian","Caroline Farms","Carolynn","Carousel","Carpenters","Carpino","Carr Brook","Carr Manor","Carramarr","Carreau","Carrgate","Carriage Hill","Carriage Way","Carrie Litchfield","Carrillo","Carrisbrook","Carroll","Carrona","Carrs Wharf","Carry Back","Carshalton Park","Cart Path","Carter House","Carthage","Cartisian","Cartridge","Carvel Beach","Carvers","Cary Geights","Cas","Casa Loma","Casa de Vida","Casavan","Casco","Caselman","Casher","Casillas","Caslocke","Caspian","Casseday","Casserly","Cassilis","Cassiopia","Castaldi","Castelli","Casterson","Castillon","Castle Cove","Castle Glen","Castle Knoll","Castle Pine","Castlebar","Castledon","Castlemain","Castleraegh","Castleshaw","Caston","Casurina","Cataldi","Catamount","Catchpenny","Caterpillar","Cathall","Cathead","Catherine Glen","Cathlow","Catlins","Caton Ridge","Catskill","Catteshall","Catts Tavern","Caulfield","Causton","Cavalletti","Cave Gulch","Cavern","Cavitt","Cawdor","Cayden","Cayser","Cazenove","Cebra","Cecil Newman","Cedar Bridge","Cedar Dell","Cedar Glen","Cedar Hollow","Cedar Oaks","Cedar River Park","Cedar Tree","Cedarbrook","Cedarforest","Cedarmeadow","Cedarvillage","Cedrus","Celebrar","Celia","Cellar","Cemetary","Centech","Center Bay","Center Flats","Center for the Arts","Centerwood","Central Square","Centre Park","Centrum","Century Ridge","Cerchio","Cereza","Cerne","Cerreta","Cerruti","Cessford","Cevu","Chabot","Chadacre","Chadds Ford","Chadwell","Chaffer","Chagford","Chaingate","Chalcombe","Chalet Clothilde","Chalice","Chalkenden","Chalkwell Park","Challin","Chalomar","Chamber","Chambers Green","Chaminade","Champagne","Champney","Chancell","Chandler","Changebridge","Channel Islands","Chansory","Chantilley","Chantry","Chapel","Chapel Gate","Chapel Springs","Chapeltown Carlisle","Chapman Mill","Chappell of Bond","Charandy","Chardon","Chargeable","Charing School","Charkers","Charlecote","Charles Anna","Charles Dean","Charles Halle","Charles Lake","Charles Sevright","Charlesford","Charlesworth","Charline","Charlottesburg","Charlwoods","Charmeran","Charnock","Charred Oak","Chart House","Charteris","Chartreux","Charville","Chase Hill","Chasemill","Chasselas","Chateau Bluff","Chatfield","Chathlake","Chattanooga","Chattswood","Chauntry","Chaves","Chaytor","Cheapside","Checker Berry","Cheda Knolls","Cheekwood","Cheesequake","Cheever","Chelbourne","Chelmar","Chelsea  Beaufort","Chelshire","Chelwood","Chemolite","Chenies","Chequers","Cherie","Cherokee","Cherry Brook","Cherry Hill","Cherry Orchard","Cherry Valley","Cherryfield","Cherryton","Chertsey Bridge","Cheryl","Chesapeake Bay","Cheseapeake","Chesham Fold","Chesline","Chessholme","Chester Brook","Chesterlee","Chestnut Cove","Chestnut Leaf","Cheston","Chetwyn","Cheverly","Cheviot","Chew Valley","Cheyne Park","Chicatabut","Chick Evans","Chicken Shack Fire","Chicoine","Chidley Cross","Chieveley","Chilaw","Child","Childs","Chiles Pope Valley","Chillington","Chilmead","Chiltern Park","Chimalus","Chimney Pot","China Wall","Chinewood","Chinn","Chinthurst","Chiplou","Chipper","Chippingstone","Chircan","Chisledon","Chiswell Green","Chitty","Chobham Park","Choke","Cholmley","Chorley Hall","Chovan","Chris Mar","Chrismar","Christel","Christian Fields","Christie Hill","Christmas","Christoper","Christs Hospital","Chrysanthemum","Chubbs Brook","Chudleigh","Chumleigh","Church Elm","Church Hill Kirkfield","Churchfields","Churchills","Churchville","Churston","Chuzzlewit","Cibrian","Cicerone","Cidermill","Cima","Cincinnatus","Cindy","Cinnamon Creek","Cippenham","Circle Court","Circle Ridge","Cirolero","Cissbury","Citation","Citruswood","City Island","Civic","Clacket","Clafton","Claim","Clairvale","Clamshell","Clanville","Clapham Park","Clara","Clarden","Claremont Park","Clarenden","Clarevale","Clarice","Clarita","Clarke","Clarkin","Clarksburg","Clarkwood","Clason Point","Claudare","Claughton","Clavadal","Claverhambury","Claxfield","Clay Hammond","Clayborn","Claycord","Clayholes","Claypitt","Clayton Hall","Cleall","Clear Shot","Clearfield","Clearwater","Cleave","Cleeve","Clelland","Clemente","Clements Hall","Clenches Farm","Cleo Rand","Clerihew","Cleveland Park","Clewborough","Cleworth Hall","Cliff Edge","Cliff View","Cliffhouse","Cliffside","Clifton Court","Clifton Point","Climbhill","Clink","Clintwood","Clipston","Clive","Cloberry","Clohesey","Clonmel","Close","Clothworkers","Cloudview","Clouston","Clover","Clover Patch","Clovergrass","Clovernook","Clow International","Club Circle","Club View","Clubway","Clunbury","Clyburn","Clydesdale","Clyston","Coachella","Coachmans","Coal Pit","Coalport","Coast Oak","Coastwise","Coats Hutton","Cobb Hill","Cobbitee","Cobble Field","Cobblerock","Cobbold","Cobham","Cobra","Coccio","Cochran Mill","Cockbush","Cockett","Cockrell","Cockshott","Cocos","Coddington","Codjer","Codrington","Coey","Coffin","Coggins","Cohancy","Cohn","Coit","Cola","Colbera","Colby Hewitt","Cold Arbor","Cold Spring","Coldalhurst","Colder","Coldnailhurst","Cole","Coleby","Colegrave","Coleman Glen","Colemansmoor","Coleridege","Coles Orchard","Colesville","Coley","Colgrove","Colin Blythe","Colindeep","Collamore","Colleen Garden","College Loop","College View","Colless","Colley Manor","Colliers Row","Collingbourne","Collington","Collinwood","Collyhurst","Colnbrook","Colo","Colona","Colonel Hunt","Colonia","Colonial Oaks","Colonna","Colony Hill","Colorado","Colridge","Coltash","Colts Neck","Columbia Creek","Columbus","Colwell","Colywn","Combe","Combs","Comet","Comly","Commerce Center","Commissioners","Common Wood","Commonside Wood","Community Hall","Compass Point","Compton Parc","Comstock Mill","Concanon","Conch","Concordia","Conder","Condron","Conerty","Coney Warren","Conference Center","Conghurst","Congress Springs","Conifer","Conington","Conkling","Conlogue","Connawarra","Connell","Connemara","Conningham","Conomo Point","Conrads","Conselyea","Consolo","Constitution","Conte","Conti Square","Contractors","Conver","Conyerd","Coogee Bay","Cookhill","Cookson","Coolangatta","Coolgardie","Coolidge Hill","Coolwood","Coombehurst","Coomes","Coonamble","Coonley","Cooper School","Coopers Hill","Coorilla","Copas","Copen Meadow","Copford","Coppage","Copper Beech","Copper Penny","Copperdale","Coppermine","Copperwynd","Coppleridge","Copsewood","Coptfold","Copyhold","Coraki","Coral Sea","Corall Hollow","Corang","Corbets","Corby","Cordale","Corden","Cordilleras","Cordoza","Coree","Corey","Coriander","Corinthian","Corkran","Corlear","Cormar","Corn Point","Cornehlsen","Cornells","Cornfield","Cornine","Cornstalk","Cornworthy","Coronation Tree Main","Corporate","Corporate Yard","Corralitos View","Correnden","Corrielle","Corron","Corseley","Corso","Corte Vista","Corthell","Cortright","Corvin","Coryell","Cosgrave","Coso","Costa Rica","Coster","Cotall","Cotentin","Cotluss","Cotswold","Cottage Garden","Cottam","Cotters","Cottington","Cotton Tail","Cottontail","Couching","Coughlan","Coulsdon  Ridgemount","Councillor","Countess","Country Club","Country Fair","Country Hollows","Country Meadow","Country Run","Country Woods","Countryvale","County Court House","County Quarry","Courland","Court Downs","Courtauld","Courthouse Oaks","Courtleigh","Courtoak","Courville","Coval","Cove Ridge","Coventry","Coverhill","Covey","Cow Hill","Cowbridge","Cowdroy","Cowens","Cowl","Cowlishaw","Cowper","Cowsted","Coxs","Coyle","Coyote Ranch","Cozy Glen","Crabapple","Cracow","Cradley","Craftsman","Cragun","Craigerne","Craiglawn","Craigwell","Crammaville","Cranberry","Cranbrooke","Crandor","Cranes","Cranford","Cranlington","Cranshaw","Cranwells","Craston","Craven","Crawshaw","Crayford","Crealock","Creaville","Crediton","Creek Bed","Creek Park","Creek Water","Creekpaum","Creekside Oaks","Creely","Cregier","Cremen","Crenna","Crescent Cove","Cresci","Cresleigh","Cressbrook","Cresskill","Crest Hill","Crest View Hill","Crested","Crestlan","Creston","Crestway","Creukhorne","Cribari","Cricket Hill","Cricketwood","Crighton","Crimmins","Crimsworth","Crippen","Cris","Crispen","Crist","Cristopher","Critten","Croasdaile","Crocker Grove","Crockford","Croff","Croftdown","Crofton Park","Croham Park","Crom","Cromer Hyde","Crompton","Cronks Hill","Crooked Crow","Crooked Tree","Crookston","Cropp","Crosfell","Cross Bay","Cross Burley Lodge","Cross Elford","Cross Hartley","Cross Maude","Cross Park","Cross Roundhay","Cross York","Crosscreek","Crossgates","Crosslet","Crossover","Crossroads","Crossview","Crossways Lake","Croton","Crouchley","Crow Haven","Crow Wood","Crowe","Crowhill","Crown Colony","Crown Oaks","Crown View","Crownfield","Crownstone","Crowsheath","Croxall","Croyden","Croyton","Crudge","Cruiser","Crunson","Cruz","Crypt","Crystal Grove","Crystal Point","Crystal View","Cuba","Cucamonga","Cuckoos","Cudham","Cuff","Culcheth Hall","Culin","Cullinane","Cullom","Culotta","Culver","Culworth","Cumberland","Cumbrae","Cummings Point","Cumston","Cuneo","Cunnington","Cupola","Curie","Curling","Curragh Downs","Currawang","Currie","Curry Ford","Curtice","Curtisden Green","Curvers","Cusick","Customs House","Cutcombe","Cutlass","Cutmore","Cutter Ridge","Cuttings","Cuyahoga","Cygnet","Cypress Bay","Cypress Hill","Cypress Ranch","Cyprus Cedar","Cyrus Field","D C Training School","D Leeds Infirmary","Dabbert","Dace","Dadant","Dagenham","Dagmar","Dahill","Dahlonega","Daimler","Daiquiri","Dairyherd","Daisy Farms","Daisyley","Daking","Dalamar","Dalcross","Dale View","Dalehead","Dalesford","Dalgety","Dalke","Dalles","Dallon","Dalmation","Dalphin","Daltons","Dalziel","Dame","Damian","Damon Park","Damsel","Dan Mason","Danberry","Dancer","Dancy","Dane","Danecourt","Danel","Danescroft","Daneville","Dangar","Daniel Adamson","Daniel Shays","Daniels","Danlee","Danns","Dans","Danthonia","Danville","Daphne","Dara James","Darcy","Dareen","Dares Beach","Darghan","Darien Lakes","Dark Forest","Darlands","Darlings","Darmuid Green","Darnet","Darras","Darrigo","Darsow","Darting Bird","Darton","Darwell","Dashia","Dassell","Dateleaf","Daugherty","Dauphin","Davane","Davenhill","Daves","David Brainerd","Davida","Davidsonville","Davis","Davisson","Davos","Dawes","Dawn Harbor","Dawnlee","Dawson Beach","Day Hill","Daycroft","Daylop","Dayton","De Anza","De Bord","De Carli","De Fillipo","De Guigne","De Koven","De Laval","De Mandeville","De Montfort","De Ponti","De Sellum","De Vere","De la Costa","DeCosta","Deacon Haynes","Dead End","Deadwood","Deale Beach","Dean Bank","Dean Moor","Deancross","Deangelo","Deans Hill","Deanville","Dearing","Debaun","Debellevue","Debnams","Deborah Sampson","Debston","Decelle","Decker","Decoverly","Dedswell","Deems","Deep Glen","Deep Spring","Deepcar","Deeping","Deer Bay","Deer Cross","Deer Hill","Deer Pack Fire","Deer Ridge","Deerbrook","Deerfield","Deering","Deerpark","Deerton","Deeside","Defoe","Degema","Dehaven","Deichmann","Dekay","Del Campo","Del Franco","Del Miller","Del Otero","Del Rio","Del Vista","Delafield Island","Delamer","Delando","Delat","Delaware","Delcombe","Deleo","Delford","Delia","Delivery","Dellabrooke","Deller","Dellos","Dellwood","Delmonico","Delnor Glen","Delorme","Delphia","Delside","Delta Ranch","Delve","Demaine","Demars","Demerrit","Deming","Demorest","Den Helder","Denali Ridge","Denbrook","Deneholm","Denham Court","Denicola","Denisen","Denlyn","Denmont","Dennetts","Dennis F. Ryan","Denny","Denshaw","Denton","Denwood","Deodor","Depew","Depraitre","Dequincey","Derby Glen","Derfuss","Dermont","Dernier","Derrick","Derry","Derwentwater","Des Peres","Descanso","Deseret","Desert Willow","Desiree","Desnoyer","Desrosiers","Detjen","Detwiller","Developers","Devereaux","Deviar","Devils Reach","Devitt","Devon","Devonshire","Devries","Dewberry","Dewey","Dewitt","Dewsbury","Deyncour","Di Giulio","Diablo Grande","Diagonal","Diameter","Diamond K","Diamond Pointe","Diana Maria","Dianthus","Dib","Diblee","Dick","Dickerman","Dickins","Dicksons Mill","Dieckman","Dieman","Diessner","Digger Pine","Diggs Park","Dignon","Dike","Dilisio","Dille","Dillon Point","Dilston","Dimm","Dimple","Dinapoli","Dingle Bank","Dinkel Spiel","Dinosaur Point","Diogenes","Dipper","Dirksen","Disbrowe","Disepo","Dispensary","Distler","Ditchfield","Dittman","Ditty","Dividence","Divisional","Dixfield","Dixon Landing","Dnieper","Dobbies","Dobe","Dobree","Dock","Dockers Tanner","Dockser","Doctor David Cline","Doctors Park","Doddsfield","Dodhurst","Doe","Doesgate","Dog","Doggetts","Dogue Run","Dohertys","Dolan","Doleful Pond","Dollar","Dollis","Dolma","Dolphin Lake","Dome","Dominic","Dominion Ridge","Domitian","Don Juan","Dona","Donald Curtis","Donazetti","Donegal","Doneva","Donkey","Donmaur","Donnan","Donner","Donnybridge","Donovan","Doods","Dooligah","Doone","Dopping Brook","Doral Farms","Doray","Dordans","Dorena","Dori","Dorincourt","Dorlcote","Dormans Park","Dormity","Dorney Wood","Dorothy","Dorrells","Dorritt","Dorsey Run","Dorwood","Doscher","Dots","Doty","Doubleday","Doucette","Douglane","Douglynn","Dove","Dovecoate","Dover","Doves","Dowanhill","Dowell","Dowlands","Down","Downer","Downham","Downing","Downs Bridge","Downsfield","Downsview","Dowsett","Doyer","Dr Johnson","Draeger","Dragoon","Drake Beach","Drakes Beach","Dransfield","Drawbridge","Drea","Dreier","Dressage","Drewery","Drexelgate","Drifters","Driprock","Driveway","Dromore","Droughts","Droylsden","Druid","Drumard","Drummer","Dry","Dry Hill Park","Dryad","Dryhill","Drystraw","Dual Wide","Dubbo","Dublin","Dubonet","Duchaine","Duchy","Duck Mill","Ducket","Duckling","Duclos","Duddy","Due","Duffer","Duffys","Dugald","Duglas Fir","Dukane","Dukefield","Dukesthorpe","Dulcie","Dulles","Dulsie","Dumaine","Dumble","Dumke","Dun Horse","Dunbar Oaks","Dunbrin","Duncklee","Dundar","Duneba","Dunes Meadows","Dungells","Dunhams Corner","Dunisch","Dunkirk","Dunlay","Dunlin","Dunmaston","Dunnel","Dunniwood","Dunran","Dunrovin","Dunshee","Dunspring","Dunster","Duntroon","Dunwoodie","Dupont","Duquesne","Durants","Durbin","Durgess","Durie","Durleston Park","Durnford","Durrell","Durward","Dustan","Dusty Willow","Dutch Mill","Dutcher Creek","Dutruc","Duvall Parish","Duxburry","Dwars Kill","Dwinnell","Dyers Hall","Dymchurch","Dyneley","Dysart","E A Joseph","E Alden","E Alpine","E Appletree","E Artisan","E Aztec","E Baltusrol","E Barret","E Beam","E Bell","E Bend","E Bethpage","E Bissell","E Bode","E Brandis","E Brightway","E Brooklawn","E Bryn Mawr","E Business Center","E Cambridge","E Carl","E Cascade","E Centennial","E Charles","E Chinkapin Oak","E Clark","E Clinton","E College","E Commercial","E Cook","E Cottage","E Crabtree","E Crystal","E Custer","E Darryl","E Delos","E Dewey","E Donegal Bay","E Dunslow","E Edwards","E Elm","E Everett","E Falcon","E Fish Lake","E Foreman","E Francis","E Front","E Garden","E Gateway","E Gilfillan","E Goebel","E Goodrich","E Grant","E Greenfield","E Grissom","E Ham Lake","E Harbor","E Harrison","E Hawley","E Hendricks","E High","E Hintz","E Homestead","E Hudson","E Hyde Park","E Inman","E Isabella","E Jefferson","E Joffre","E Julius","E Kennedy","E Kinzie","E Lafayette","E Lake Rebecca","E Laurel","E Lemoyne","E Light","E Lombard","E Lower Pine Lake","E Lynnhurst","E Magnolia","E Manor","E Marquette","E Maude","E McClellan","E Medill","E Merrick","E Mill Valley","E Mineral Pond","E Monee","E Moore Lake","E Mount Ida","E Nap","E Nerge","E Nicholai","E North","E Oak","E Oakton","E Ohio","E Old Ridge","E Onwentsia","E Paddock","E Parkhill","E Payne","E Pennsylvania","E Phillips","E Pleasant Lake","E Pool","E Potomac","E Private","E Railway","E Reader","E Reichert","E Ridgewood","E Rockaway","E Rosemont","E Saddle River","E Sanders","E School","E Sedwick","E Shaw","E Sherwood","E Sioux Vista","E Soffel","E Spring","E Summit","E Susan","E Tall Oaks","E Terrace","E Thorndale","E Turtle","E Utica","E Vargo","E Villa","E Walnut","E Waverly","E West Shady Side","E William","E Windsor","E Wood","E Woodrow","E York","E. Chicago","Eads","Eager","Eagle Gap","Eagle Nest","Eagle Springs","Eagle Wharf","Eagles Mere","Eagleton","Ealand","Earl","Earle","Earlene","Earlsbrook","Earlston","Early Morning","Earnscliff","Easecrest","Eason","East Ad","East Alden","East Amhurst","East Ashland","East Bagwell","East Battery","East Beeches","East Berkley","East Blithedale","East Boxford","East Brook","East Busk","East Canyon","East Carroll","East Centry","East Chiles","East Colfax","East Como","East Court","East Cross","East Dartmouth","East Diamond","East Dunne","East Eastview","East Elbrook","East Euclid","East Fairfield","East Field Service","East Foreman","East Francis","East Fulton","East Gate","East Glencoe","East Grant Line","East H","East Handel","East Harvest","East Hedding","East Highland","East Hirsch","East Horner","East Hurd","East Ingram","East Jack Tone","East Jonathan","East Kendall","East Keystone","East Kirke","East Lake Kayak","East Las Palmas","East Lee","East Lincoln","East Lodge","East Loop","East Lousia","East Madill","East Manzanita","East Mascalls","East Mc Mullin","East Mendocino","East Middlefield","East Milton","East Montara","East Mount","East Nash","East Nilsson","East O","East Old Greenville","East Orwood","East Parkdale","East Pennsylvania","East Plain","East Portola","East Putnam","East Rancho Arroyo","East Reitze","East Riding","East Robertson","East Rosemary","East Saint James","East San Salvador","East Scenic","East Seegers","East Shea","East Shorewood","East Smith","East Southland","East Sunnyslope","East Tabor","East Temple","East Thomas Grade","East Titus","East Travis","East University","East Vernon","East Vista","East Watmaugh","East Whitehouse","East Wilmette","East Woodfield","East Wyoming","East la Chiquita","Eastbrooke","Eastcliffe","Eastdene","Easterly","Eastern Point","Eastham","Eastlake","Eastline","Eastmount","Eastridge","Eastview Farm","Eastwood","Easy","Eaton Park","Ebano","Ebbetts","Eben","Eberlin","Ebley","Ebury","Ecclesburn","Echo Barn","Echo Knolls","Echo Summit","Ecker","Eckles","Ector","Edbrooke","Eddiscombe","Ede","Eden","Eden Oaks","Eden West","Edendale","Edensor","Eder Ct","Edgars","Edge Fold","Edgebrooke","Edgecourt","Edgegrove","Edgemeade","Edgerly","Edgewater Pond","Edgington","Edin Garth","Edington","Edithna","Edman","Edmonston","Edmund Hurst","Edric","Edstan","Edward Bennett","Edward Hart","Edwards Bay","Edwin H. Land","Eel","Effingham Common","Egan","Egel","Eggar Woods","Egham","Eglinton","Egypt","Ehret","Eider","Eighteenth","Eileen","Eimer","Eisner","Ekins","El Bonita","El Camino Medio","El Cemonte","El Cortez","El Dori","El Greco","El Mercado","El Nido Ranch","El Patio","El Prado","El Rincon","El Sobrante","El Verand","Elan","Elberon","Elbridge","Elcock","Eldee","Elderberry","Eldermount","Eldor","Eldrige","Electioneer","Elegante","Elephant","Elf","Elfreda","Elgin Hosp Service","Elia","Elimatta","Eliot Hill","Elisha","Eliza Ann","Elizabeth River","Elk Hills","Elkan","Elkins","Elkstone","Elland","Ellen Webb","Ellenton","Ellerby","Ellers","Ellesemere","Ellicott Woods","Ellington","Ellis","Ellita","Ells","Ellyn","Elm Crest","Elm Park","Elman","Elmcrest","Elmer F Hagner","Elmfield","Elmire","Elmore","Elmsdale","Elmstone","Elmview","Elnoka","Elora","Elridge","Elsdale","Elsie","Elskip","Elspeth","Elswick","Eltham Palace","Elton Vale","Elveden","Elverta","Elvin","Elward","Ely","Elzer","Emaron","Embee","Embroidery","Emerad","Emerald Grove","Emerald Pool","Emerick","Emery","Emil","Emily Dickinson","Emlyn","Emmaus","Emmert","Emmitt","Emory","Empire Wharf","Emshee","Enborn","Encima","Encore","Enderby","Endlich","Endsleigh","Enes","Engel","Engineer","Englehutt","Engliff","English Oak","Englorie Park","Ennalls","Ennis","Enrica","Ensleigh","Entertainment","Entrevaux","Envill","Epaul","Epping Farms","Epsam","Equine","Erba","Erebus","Eric Green","Ericson","Eriks","Erith High","Erles","Ermine","Ernesti","Ernst Chain","Errico","Erskine Park","Erville","Escalero","Escatta","Escolta","Esdaile","Esher Place","Esler","Esparito","Esplande","Essella","Esser","Essex View","Esta","Estcots","Estella","Esterlee","Estherwood","Estonia","Estudillo","Etcheverry","Ethan","Etheldore","Ethier","Etloe","Eton on Oxford","Ettersberg","Eubank","Eucra","Eulabah","Eurabalong","Eurimbla","Euryalus","Euthella","Evangel","Evans Pond","Evanstone","Eveleth","Evemarie","Eveningside","Everd","Everett","Everglades Park","Evering","Everlyn","Eversley","Everts","Evey","Evry","Ewell Court","Ewing","Excalibur","Executive Park","Exfair","Exmore","Exploration","Extension","Eyelet","Eynford","Eyston","F Jellman","Fabens","Fabor","Fackenden","Fado","Fagnall","Faigle","Fair Greene","Fair Meadow","Fair Valley","Fairbluff","Fairbury","Faircrest","Fairfax Corner","Fairfield","Fairglen","Fairhaven","Fairhunt","Fairlands","Fairless","Fairmark","Fairmont Heights","Fairs","Fairview","Fairwater","Fairway Two","Fairwood","Faith","Falcon","Falconbridge","Fales","Falkland Park","Fallard","Fallfax","Falling Leaf","Fallow Fields","Falls Reach","Fallswood","Falmore","Falstone","Family Farm","Faner","Fanners","Fanshawe","Fanum","Far Rockaway","Farallon","Farber Hill","Farese","Farhill","Farington","Farlane","Farlie","Farm Creek","Farm Line","Farmbridge End","Farmers","Farmin","Farmside","Farnborn","Farnesdown","Farnol","Farr","Farrance","Farravet","Farrer","Farrol","Farthing Green","Farvue","Fassler","Father Hurley","Fattoria","Faulk","Faunce","Favart","Favre Ridge","Fawkham","Fawn Hill","Fawnbrake","Faxon","Faye","Fayrewood","Fearing","Fearon","Featherbed","Featherstone","Federal","Fedor","Feeder","Fehler","Felbrigge","Felder","Feldspar","Feliz","Fellner","Fellscrest","Felmingham","Felsview","Felthorpe","Femleaf","Fencepose","Fender","Fenley","Fennels Farm","Fenny","Fentem","Fenwick","Ferdinand Day","Ferland","Fermoy","Fern Hollow","Fernally","Fernbray","Ferndale","Fernedge","Ferngrove","Fernhurst","Fernmont","Fernthorpe","Fero","Ferraris","Ferrers","Ferron","Ferrybridge","Fertelli","Fesseneva","Fetherston","Fetzer","Ffinch","Fiddicroft","Fiddlesticks","Field Encampment","Field Office","Fieldbank","Fieldfair","Fieldmere","Fields First Federal","Fieldthorn","Fierro","Fifth Cross","Figueroa","Filarete","Filipe","Fillingfir","Filmorehill","Finch","Finchley","Findlays","Fingerboard","Finkbohner","Finlays","Finn S","Finnis","Finsbury Park  Rock","Finway","Fir","Firbank","Fire Barn","Firebird","Firehouse","Firethorn","Firlands","Firsby","First Parish","Firthcliffe","Fiscal","Fish House","Fisher","Fishermore","Fishmarket","Fiske Pond","Fitch View","Fitton Hill","Fitzgilbert","Fitzjohns","Fitzsimons","Five Acres","Five Hawks","Five Wounds","Flag City","Flagg Wood","Flagstone","Flame","Flamstead","Flandrau","Flash","Flatback","Flatts","Flavius","Flaxfield","Fleagle","Fleethall","Flemings Farm","Fletchall","Fletsand","Flewing","Flight","Flint Farm","Flintcrest","Flintmont","Flintwood","Float","Flood","Floradale","Florence Park","Florgate","Florido","Florio","Florsheim","Flow","Flowerfield","Flowerree","Floyd Hill","Flushcombe","Flying Fields","Foamcrest","Fog Bank","Foghorn","Foldi","Folini","Folkstone","Follows","Folsom","Fondant","Fontainbleau","Fontayne","Fontridge","Foot Hill","Foothill Glen","Foots Cray High","Forbes Glen","Force Green","Fordal","Fordhook","Fordwich","Foreland","Forest Arms","Forest Dell","Forest Green","Forest Knoll","Forest Mill","Forest Ridge","Forest Wood","Foresthill","Forestway","Forget Me Not","Forkland","Formosa","Forrest","Forrester Hill","Forshaw","Forsyth","Fort Beggs","Fort Funston","Fort Johnston","Fort Point","Fort Smallwood","Fortesque","Fortin","Fortril","Forty Acres","Fosbak","Fossdale","Fosswood","Fosters","Foulds","Founders Field","Fount","Fountain Oaks","Fountaingrove","Four Acres","Four Oaks","Fourness","Fourwents","Fowler Creek","Fox Bow","Fox Farm","Fox Grove","Fox Hollow Ridings","Fox Meadow","Fox Point","Fox Shores","Fox Vine","Foxberry Farms","Foxchase","Foxden","Foxfields","Foxhall","Foxhole","Foxlake","Foxmoor","Foxstones","Foxview","Foye","Fraga","Fram","Framley","Franceen","Francessca","Francis Kelley","Francisco","Frandsen","Frank Cox","Frank Turk","Frankfort Square","Franklands","Franklin Gibson","Franklin Park Service","Frankstowne","Franquette","Franton","Franzman","Fraters","Fray","Frean","Fred Allen","Fredana","Frederic","Frederickson","Fredonian","Free Green","Freedom Center","Freehollow","Freemantle","Freeston","Freewood","Freke","Fremontia","French Lake","Frenchmans Creek","Frensham Vale","Fresh Mill","Freshfields","Freston","Frewland","Friars Place","Fridays","Friedrich","Friends House","Friezland","Frimley Hall","Frisbie","Frith End","Fritzen","Frog","Frogmoor","Frohling","Fromondes","Frontana","Frost Creek","Frothingham","Fruit Barn","Frustuck","Frying Pan","Fuel Farm","Fuhrman","Fulbrook","Fulkerson","Fulle","Fullers Farm","Fulmead","Fulton Shipyard","Fumia","Furci","Furmanville","Furnedge","Furse","Fury","Furzeham","Fyall","Fylde","G Hall","Gabby","Gables","Gadbridge","Gaddy","Gadmore","Gae Wood","Gaga","Gahant","Gailview","Gainsford","Gaist","Galara","Galbreath","Galena","Galetown","Galitz","Gallant Fox","Gallek","Galley","Galli","Gallinelli","Gallogate","Gallows","Gallwey","Galtier","Galway","Gamble","Gambril","Game Lord","Gamlen","Gandangara","Gangurlin","Gannett","Gantner","Gaping","Garazi","Garbutt","Garda","Garden Cty","Garden Rock","Gardendell","Gardenview","Gardner Ranch","Garfield Park","Garie","Garlen","Garlisch","Garner","Garnica","Garrans","Garret Hill","Garriland","Garrow","Garside Hey","Garth Willow","Gartleman Farm","Garvan","Garway","Gary Ray","Gas Light","Gascoyne","Gaskin","Gasson Wood","Gasworks","Gate Farm","Gateforth","Gateon House","Gatesborough","Gateview","Gathering","Gaton","Gattucio","Gaudreau","Gault","Gautrey","Gavestone","Gawber","Gay Bowers","Gayford","Gaylor","Gays","Gazebo","Gearny","Gebo","Gee","Gehb","Geisler","Gelder","Gellert","Gemalla","Gen. Kennedy","General","General R. W. Berry","Generals","Genest","Genie","Genoa","Gentian","Gentlewood","Geoffrey","George","George Clauss","George Lee","George Mobbs","George V","Georgene","Georgia","Georjean","Geralind","Gerbera","Geren","Geringer","German Church","Germany","Gerpins","Gerrish","Gerstung","Gervase","Gest","Getyunga","Geyserville","Ghione","Ghyllroyd","Giannecchini","Giants","Gibbet Hill","Gibfield","Gibson","Gidea","Gidya","Giesman","Giffords Cross","Gighill","Gilba","Gilbralter","Gilcrest","Gildenhill","Gildersome Back","Gilgandra","Gillard","Gillespie","Gillian","Gilling","Gillmore","Gillwinga","Gilmore","Gilroy","Giltbrook","Gimbal","Ginden","Ginger Root","Gingham","Ginniver","Giorgano","Gipton Approach York","Girdle","Girralong","Gisbourne","Gist","Glabe","Glad","Glade Spring","Gladiator","Gladsdale","Gladwell","Glaisdale","Glandon","Glantz","Glascoe","Glass Mountain","Glasslyn","Glatton","Glazer","Gleason","Glebe House","Gledhill","Gledwood Wood","Glen","Glen Arms","Glen Byron","Glen Cross","Glen Echo","Glen Faba","Glen Gary","Glen Hannah","Glen Hook","Glen Lomond","Glen Mor","Glen Paul","Glen Sharon","Glen Valley","Glenair","Glenarms","Glenbarr","Glenbrook Crest","Glencairn","Glencorse","Glenda","Glendevie","Glendundee","Glenerye","Glenfield","Glengall","Glengary","Glenheather","Glenister","Glenlee","Glenmar","Glenmoor","Glenn Curtiss","Glenns","Glenora","Glenrise","Glenroy","Glenthorn","Glenugie","Glenwild","Glenworth","Glider","Globe","Glorietta","Gloucester","Gloxinia","Glyn","Glynwood","Goat Hill","Goatsmoor","Godbold","Godetia","Godman","Godstone","Goes","Goffe","Gogmore","Golborne","Gold Center","Gold Field","Gold Nugget","Gold Rush","Goldcoast","Golden Ball","Golden Cross","Golden Grove","Golden Leaf","Golden Poppy","Golden Russet","Golden View","Goldentree","Goldie","Goldmine","Goldsdown","Goldthwait","Golf Course","Golf Links","Golfview","Gomez","Gondola","Gonzales","Good Lion","Goodall","Goodenia","Goodhew","Goodinge","Goodmans","Goodrick","Goodview","Goodwives River","Goodyers","Goondari","Goose","Goose Pond","Gooseley","Gordan","Gordonia","Gorefield","Goring Heath","Gornall","Gorse","Gorsey","Gort","Gosbrook","Gosheff","Gosling Hill","Goss Hall","Gosset","Goswell","Gothwaite","Gottschalk","Gougham","Goulder","Gourley","Gover","Governor Bridge","Governor Peabody","Govert","Gower","Goyak","Grace Estates","Gracedale","Gracemore","Graciella","Grady","Graff","Graham","Graicious","Gralynn","Grammar School","Granada","Grand","Grand Corner","Grand Highlands","Grand Point","Grand River","Grand Valley","Grande Pines","Grandin","Grandview","Grange","Grangecourt","Grangewood","Granite Rock","Granny","Grant Mills","Granton","Grapal","Grapewood","Grass Lake","Grassingham","Grassy Knoll","Gratrix","Grau","Gravel Pit Haul","Gravelly Hill","Graves","Gray","Gray Hawk","Graybriar","Grayheaven Manor","Graymarsh","Grayrigg","Grays Park","Grayson","Graywacke","Greacen Point","Great Bank","Great Bramingham","Great Castle","Great College","Great Elms","Great Guildford","Great Horwood","Great Knollys","Great Moor","Great Notley","Great Plain","Great Republic","Great Smokey","Great Trinity","Great Winchester","Greatfields","Greatwater","Greek","Green Branch","Green Circle","Green Dory","Green Forest","Green Heron","Green Island","Green Ledge","Green Mountain","Green Pastures","Green Ridge","Green Tree","Green Way","Greenaway","Greenbelt Metro","Greenbranch","Greenbury","Greencroft  Deans","Greene Ridge","Greenfinch","Greengrove","Greenhays","Greenhouse","Greenland","Greenleach","Greenlook","Greenoak","Greenrock","Greensfield","Greenson","Greensview","Greenvale","Greenway  Longland","Greenwich Church","Greenwich Woods","Greenwood East","Greeves","Gregg","Gregorich","Gregory M Sears","Grenada","Grenfel","Grenock","Greshan","Gretchen","Greville","Grey Barn","Grey Ghost","Grey Seal","Greycaine","Greylag","Greyshiels","Greyswood","Grice","Grieves","Griffin Farm","Griffith Farm","Griglio","Grimmer","Grimsley","Grindall","Griner","Grisdale","Gritte","Grizzly Rock","Grobie Pond","Grogan","Groom","Gropius","Gross Point","Grosvenor","Groton School","Grounds","Grove Cross","Grove Mill","Grovehill","Grovenor","Groveton","Grubb","Grunauer","Grymes Hill","Guam","Guayamas","Gudrun","Guerin","Guernsey","Gueudecourt","Guido","Guildersfield","Guildown","Guillemot","Guinions","Guittard","Gulia","Gulliver","Gum Grove","Gumbuya","Gumtree","Gun Hill","Gundain","Gundrys","Gunn","Gunnerfield","Gunpowder","Gunsynd","Guntzer","Gurin","Gurnsey","Gustafson","Guston","Guthmiller","Gutters","Guyon","Guywood","Gwen","Gweneth","Gwyder","Gwynndale","Gymoty","H A Wyeth Sr","HIllside","Haan","Haben","Haciendas","Hackensack","Hackhurst","Hackney","Haddassah","Haddo","Hadham","Hadley Run","Haeg","Haff","Hagadorn","Hagel","Hagert","Haggin","Hague","Haidlen","Haile","Haining","Haiti","Halcourt","Hale Haven","Haledon","Halesowen","Haleybird","Half Moon","Halfmoon","Halibut","Halinda","Hall Brown","Hall Moss","Hallam","Hallen","Hallfields","Hallin","Hallo","Hallowell","Halls Hole","Hallwicks","Halmstad","Halsbury","Halstead","Halterman","Halvorsen","Ham Park","Hamberts","Hambletonian","Hambrook","Hamerick","Hamill","Hamlet","Hammarlee","Hammerlee","Hammertown","Hammond Branch","Hammonton","Hampermill","Hampstead High","Hampton Hill","Hampton Ridge","Hamson","Hanback","Hancock","Handcross","Hands","Hanes","Hanging Chadder","Hanken","Hanley","Hanna Park","Hannan","Hannett","Hannover","Hansard","Hansell","Hansol","Hanworth","Happy Creek","Hara","Harberson","Harbolets","Harbor Light","Harbor Side","Harboro","Harbour Cove","Harbourer","Harby","Hardcastle","Hardenburgh","Hardies","Hardman","Hardwicke","Hardywood","Harecroft","Harelands","Harewood Arms The","Harger","Hargus","Harkeith","Harkison","Harlea","Harlesden","Harlinger","Harmans Water","Harmony","Harmston","Harness Shop","Harold Court","Harolds","Harper Fold","Harpford","Harptree","Harreton","Harriman","Harris","Harrison","Harrivan","Harrop Green","Harrowdene","Harry J Rogowski","Hart Dyke","Harter","Harthill","Hartlake","Hartman","Hartnup","Harts Leap","Hartshorne","Hartswood","Hartwood","Harvard","Harvest Bend","Harvest Mills","Harvest View","Harvil","Harwell","Hasbrouck","Haselfoot","Hasketon","Haslemere","Hasluck","Hassell","Hassocks","Hastings","Hatch Gate","Hatchet Rock","Hatcliff","Hatherlow","Hatheway","Hatpat","Hattie","Hauge","Haultain","Haussler","Havant","Haven Hill","Havenner","Havenwood","Havermeyer","Havil","Havlicek","Hawbeck","Hawhorn","Hawk Hill","Hawke Park","Hawkesfield","Hawkins","Hawks Hill","Hawkshead","Hawkswood","Hawley","Hawsbrook","Hawthorne Hill","Haxby","Hay Green","Hayday","Haydon","Hayes End  Angel","Hayfield","Hayles","Hayman","Haymond","Haysbrook","Hayward Farms","HazElwood","Hazel Ridge","Hazelbrook","Hazelglen","Hazelnut","Hazelwick","Hazlemere","Hazzard","Headingly","Headley Hill","Heady Hill","Health Center","Heapworth","Hearnshaw","Hearth","Hearts Bay","Heath Farm","Heathbank","Heathdene","Heather Green","Heatherbloom","Heatherland","Heatherside","Heatherway","Heathhurst","Heathpark","Heathwalk","Heaton Grange","Heaver","Hebe","Heckel","Heckmondwike Regent","Heddings","Hedge Row","Hedgerow","Hediger","Hedwig","Heene","Hegemans","Heiden","Heidrick","Heilicia","Heindrich","Heinz","Hejka","Helen","Helfred","Helix","Helles","Helm Cottage","Helmick","Helmstetter","Helton","Hembree","Heming","Hemlock Park","Hemmer","Hemp","Hempstead Valley","Hemswell","Henconner","Hendley","Hendry","Henhurst","Henley Wood","Hennepin Town","Hennings","Henon","Henrik Ibsen Park","Henry Ford II","Henry L.","Henry Turner Bailey","Hensill","Henwood Green","Heppleton","Herb Elliot","Herbert Breclaw","Herbing","Heredia","Herfort","Heritage Crossing","Heritage Lake","Heritage Square","Herley","Hermans","Hermiston","Hermon","Herndon","Heron","Herons","Heroult","Herricks","Herring Pond","Herrmann","Hersey","Hersperger","Hertsmere","Hesket","Hesperus","Hessler","Heswall","Heton","Hetzel","Hever Court","Hewart","Hewison","Hews","Hexton Hill","Heyde","Heyheads New","Heysbank","Heyward","Hezlett","Hiawatha","Hibner","Hickmans","Hickory Hill","Hickory Ridge","Hickox","Hickton","Hidden Brook","Hidden Glade","Hidden Lake","Hidden Oaks","Hidden River View","Hiddenbrook","Hideaway","Higbie","Higgins Park South","High Ash","High Bridge","High Court","High Elms","High Grove","High Hill","High Lake","High Meads","High Pine","High Rid","High Terrace","High Valley","HighLands","Highboro","Highcliffe","Highdaun","Higher Bury","Higher Green","Higher Swan","Highfields","Highland","Highland Heights","Highland View","Highlever","Highpath","Highshore","Hightree","Highworth","Higuero Highland","Hiland","Hilbre","Hilde","Hildens","Hileman","Hiline","Hill Cot","Hill Girt Ranch","Hill Park","Hill Trail","Hillard Lake","Hillbottom","Hillcot","Hillcroome","Hillegass","Hillesley","Hillgrove","Hilliers","Hillmead","Hillplace","Hills of Claire","Hillside Park","Hillspur","Hilltop Mall","Hillwood","Hilmar","Hilsea","Hilversum","Hinchinbrook","Hind Hill","Hindleap","Hinds","Hinguar","Hinsdale","Hinton Manor","Hipwood","Hirst","History","Hitches","Hithercroft","Hitty Tom","Hllwood","Hoaglands","Hobbis","Hobby","Hobie","Hobson Hollow","Hochler","Hockett","Hoddam","Hodge Clough","Hodnett","Hoeg","Hoff","Hofstra","Hogback","Hogfair","Hogscross","Hogtrough","Hoiting","Holbeam","Holborn  Theobalds","Holcolme","Holden Clough","Holderith","Holdridge","Holeman","Holiday Hill","Holker","Holland House","Hollar","Hollett","Hollickwood","Hollin Park","Hollingswood","Hollins Green","Hollis Wood","Hollow Hill","Hollow View","Hollowfield","Holly Berry","Holly Farm","Holly Haven","Holly Lake","Holly Park","Hollybank","Hollydale","Hollyhey","Hollymount","Hollytree","Holmard","Holmdel","Holme Lea","Holmemoor","Holmespun","Holmlea","Holmsley Field","Holsclaw","Holston","Holtby","Holts","Holwell","Holy Name","Holyoake","Homann","Home Gate","Homebush","Homefield","Homemeadow","Homers Wood","Homesite","Homeview","Hommann","Honest Pleasure","Honey End","Honeybourne","Honeyhill","Honeywell","Honker","Honora","Hoo","Hooe","Hook Green","Hookhouse","Hookston","Hoopers","Hooyman","Hopatcong","Hope Park","Hopestill","Hopgarden","Hopkinson","Hoppin","Hopps","Horace Darling","Horde","HorizOn","Horlock","Hornash","Hornbuckle","Hornecastle","Hornhill","Hornsby","Horridge","Horse Ferry","Horse Prairie","Horsegrove","Horseman","Horseshoe","Horsfield","Horsnell","Horton Hill","Hory","Hosie","Hosler","Hotaling","Hothorn","Houchin","Houghend","Houlton","Hounds Ditch","Hourglass","Houselands","Houtman","Hovenden","Howard","Howard Lake","Howarth","Howden","Howerton","Howitzer","Howley Mill","Howsman","Hoxton  Baring","Hoyles","Hoyts Wharf","Hubbard School","Hubble","Huckleberry","Huddleson","Hudner","Hudson Service","Hugel Hill","Hugh Fraser","Hughesdale","Huhtala","Hull Shore","Hulmes","Humboldt","Hummer","Humphries","Hundred Oaks","Hungerford","Hunnable","Hunslet","Hunt Country","Hunt Valley","Hunter Mill","Hunterdon","Hunters Grove","Huntersend","Hunting Farms","Hunting Quarter","Huntington Bay","Huntingtown","Huntley Square","Huntover","Hunts Pond","Huntsmoor","Huntzinger","Hurds","Hurley","Hurricane","Hurst Park","Hurstfield","Hurtmore","Huskisson","Hust","Hutchingsons","Hutton","Hyacinth","Hybrid","Hyde Farms","Hydeway","Hygelund","Hyland Greens","Hyndman","Hypoint","Hythe","I U Willets","Iandra","Iberis","Icasia","Ice House","Iceton","Ickford","Ida Clayton","Idalou","Idle Creek","Idlestone","Idolstone","Idylwilde","Ifold Bridge","Ijauna","Ilchester","Ilgars","Ilka","Illawarra","Illinios","Ils","Imberhorne","Imola","Import","Inaudi","Inchon","Indelicato","India","Indian Chase","Indian Harbor","Indian Joe","Indian Oaks","Indian Ridge","Indian Tree","Indianapolis","Indus","Infantry Ridge","Ingalton","Ingersley","Ingle","Ingleby","Ingleshire","Ingoldsby","Ingrams","Inheritance","Inkerman","Inner","Innerhill","Innisbrook","Innovation","Insel","Inspiration Point","Interbay","Interlocken","Interstate","Intrieri","Invergorden","Inverray","Invicta","Iodine","Ione Michigan Bar","Ipswich","Iredine","Ireson","Irk","Irmen","Iron Gorge","Iron Point","Irondequoit","Ironside","Iroquois","Irvine","Irwell","Isaac Hull","Isador","Isbel","Isengard","Ishnala","Island Farm","Islandside","Isled","Isleton","Ismailia","Issac Miller","Itaska","Itte","Ivan","Iveley","Ives","Ivor","Ivy Bush","Ivy Green","Ivy Lodge","Ivy Wood","Ivyleaf","Ixion","J D Reading","J Smith","Jacaranda","Jack Cornwell","Jack Tone","Jackie","Jackpit","Jackson Mill","Jacksonville","Jacob Cushman","Jacobson","Jacquelyn","Jadchalm","Jaeger","Jagger","Jahn","Jake Creek","Jamaica","James Barton","James Craig","James Flynn","James Lex","James R Rakow","James W Smith","Jameston","Jamie Lee","Jan Mar","Janas","Jane Adams","Janelle","Janeway","Janna","Janocha","Jansen Farm","Japonica","Jardine","Jarnecke","Jarrow","Jasmin","Jasons","Jaunell","Jawl","Jaydine","Jayrose","Jaywood","Jean Wailes","Jeanneret","Jed","Jef","Jefferon","Jeffrey","Jefts","Jellicoe","Jencks","Jenkins","Jenna","Jenni","Jenniffer","Jennings Park","Jenny Lind","Jenvey","Jeraldo","Jereva","Jerilderie","Jernee","Jerridge","Jerrys","Jerusha","Jespersen","Jessel","Jessie Jo","Jeter","Jewelflower","Jewish War Veterans","Jf Kennedy","Jill","Jilrick","Jim Veal","Jinchilla","Jj","Joan","Joaquin Murieta","Jocare","Jockey","Jodi","Joe Borovich","Joe Pombo","Joetta","John","John Ayres","John Bradshaw","John Campbell","John Cobb","John Davey","John E Smith","John Fisher","John H Johnson","John Heywood","John J Brady","John Kirkham","John Marthens","John Milton","John Partridge","John Rezza","John Sam","John Thomas","John William","Johnny Appleseed","Johns Hollow","Johnson Grove","Johnston Crescent","Joiner","Joliet","Jolma","Jonagold","Jonathan Ridge","Jones Branch","Jones River","Jonspin","Jopling","Jordans","Jorgen","Jose","Joseph Banks","Joseph Schwab","Josephson","Josina","Jost","Journeay","Joy Lee","Joyce Lundberg","Joynt","Juarez","Judge","Judick","Judsonville","Juergens","Julep","Julian","Juliedale","Julietta","Jumel","Juna","Juneberry","Junia","Juniperberry","Juntar","Jurgens","Justamere","Justina","Jutland","Kaanapali","Kadema","Kafka","Kahn","Kaintuck","Kaitlyn","Kalarama","Kaley","Kalk","Kallista","Kaltern","Kamaur","Kameruka","Kammerer","Kanabec","Kanawha","Kanegis","Kanili","Kanoona","Kantor","Kaparia","Kaposia","Kapyong","Karangi","Kardon","Karen Anne","Karilla","Karl","Karlson","Karnell","Karoon","Karringal","Karyl","Kaski","Kassel","Katan","Kath","Kathlean","Kathrina","Katie","Katrine","Kaufman","Kavanagh","Kawai","Kaybro","Kayjay","Kayron","Kazebeer","Kealsy","Kearney","Keary","Keawe","Kedith","Keech","Keele","Keema","Keens Park","Keepsake","Keevil","Kegle","Keightley","Keinches","Keith","Keithley","Kelburn","Kelesey","Kelland","Kellerman","Kelling","Kells","Kelly Lake","Kelrose","Kelson","Kelveden","Kelwynne","Kembla","Kemmever","Kempbridge","Kemps Farm","Kempthorne","Kenalray","Kenburn","Kendall","Kendel","Kendra Hall","Kenelworth","Keniff","Kenleigh","Kenmere","Kennady","Kennel Barn","Kennerleigh","Kenneth Kostka","Kennicott","Kennworth","Kenosha","Kensellas","Kenson","Kent Fire","Kent Sq","Kentford","Kentley","Kentridge","Kentucky","Kenwar","Kenwood Isles","Kenzel","Keough","Keran","Keri Ann","Kermes","Kerner","Kerrawah","Kerrins","Kerry Beacon","Kerscott","Kerstin","Keslake","Kessler","Kestrel","Ketchum","Kettell","Kettle","Kettle Run","Kevelioc","Kevinberg","Kewadin","Key Turn","Keyner","Keyse","Keytone","Khama","Kiawah","Kickapoo","Kidd Creek","Kidlington","Kiefer","Kieper","Kiessig","Kijek","Kilbernie","Kilburne","Kildare","Kilgore","Kilkerry","Killawarra","Killian","Killingworth","Kilmarnock","Kilmoray","Kiln Pond","Kilnwood","Kilross","Kilworth","Kimball","Kimberlee","Kimberwicke","Kimcumber","Kimmel","Kinburn","Kinder","Kindler","King","King Charles","King Fisher","King Hall","King John","King Muir","Kingate","Kingery","Kingingwood","Kingridge","Kings Chapel","Kings Crossing","Kings Hall","Kings Lynn","Kings Retreat","Kings Way","Kingsbury","Kingscroft","Kingsford Smith","Kingsland","Kingsman","Kingsnorth","Kingston  Eden","Kingstream","Kingswick","Kinkade","Kinloch","Kinnear","Kinnoul","Kinsey","Kinswood","Kinzel","Kiote","Kipp","Kipps","Kirch","Kirk Farm","Kirkdale","Kirkgate Highgate","Kirkleas","Kirkman","Kirkstall","Kirkup","Kirrang","Kirstmont","Kiser","Kissel","Kit Hill","Kitching","Kitkatts","Kitson","Kittie","Kitty","Kivy","Klainert","Klaus","Kleinman","Klickitat","Klinsky","Klovstad","Knapton","Knauer","Knelle","Knife Shop","Knighton","Knights Park  Denmark","Knightwake","Knobcone","Knockholt","Knoll Creek","Knoll Ridge","Knollcrest","Knolls Pond","Knopp","Knottisford","Knowl Top","Knowlsey","Knoxville","Knutson","Kobb","Kocher","Koehling","Kofman","Kohlepp","Kohr","Kolbert","Kolmer","Koman","Kondazian","Konner","Koola","Koora","Koorine","Kootenai","Kopp","Korman","Korrel","Kosciusko","Kosmina","Kotlik","Kousa","Kovr","Koziara","Kraken","Kramme","Krech","Kreitzburg","Krestrel","Krey","Kriss","Kristine","Krochmally","Kronmeyer","Kroy","Kruse","Ku Ring Gai Chase","Kuester","Kulgoa","Kult","Kundi","Kunkundi","Kuringai","Kurrawa","Kurung","Kussoth","Kwajalein","Kylemore","Kyne","Kyverdale","L W Johnson","La Alegria","La Boheme","La Casa","La Corona","La Count","La Cumbre","La Follette","La Goma","La Haigh","La Lena","La Mar","La Palm","La Plume","La Reina","La Riviere","La Salida del Sol","La Torre","La Vita","Labath","Labor","Labrott","Labworth","Lacebark","Lachlan","Lacky Dam","Laconia","Ladbroke","Laddie","Ladew","Ladonia","Lady Ann","Lady Game","Lady Somerset","Ladybridge","Ladyshore","Lafarge","Laffans","Lafond","Lafreniere","Lago De Bracciano","Lagoon View","Laguna Honda","Laguna Vista","Lahard","Lahti","Laindon","Laith","Lake Arrowhead","Lake Boone","Lake Chad","Lake Court","Lake Elmo","Lake Forrest","Lake Hazeltine","Lake House","Lake Knoll","Lake Louise","Lake McClure","Lake Nimbus","Lake Overlook","Lake Pointe","Lake Riley","Lake Susan Hills","Lake Tree","Lake Vista","Lakebird","Lakeforest","Lakeknoll","Lakeman","Lakenhurst","Lakeshire","Lakeside Village","Lakeview Fire","Lakewood Falls","Lakota","Laloki","Lamarcus","Lambden","Lambert Creek","Lambeth High","Lambourne","Lambs Farm","Lamerton","Lammers","Lamonte","Lamp","Lamping","Lampson","Lan Ark","Lanbury","Lanceley","Lancewood","Land Off Kendall","Landale","Landeros","Landfill","Landing","Landolt","Landrace","Landsberg","Landsend","Lane","Lanell","Laneview","Langaller","Langdrum","Langewood","Langhorn","Langley Canyon","Langley Platt","Langner","Langsett","Langthorne","Lanham","Lanini","Lannon","Lansbury","Lansford","Lantern Hollow","Lanvanor","Lapier","Laplata","Lapu Lapu","Laramie","Larch Hill","Larciano","Larges","Lariat","Lark","Larkard","Larkhall","Larkmead","Larkswood","Larne","Larosa","Larrlyn","Larson Farm","Las Astas","Las Dunas","Las Lomitas","Las Posadas","Las Vegas","Lash","Lasker","Lasseter","Lastingham","Latchmoor","Lathbury","Latin","Latour","Lattimore","Laub Pond","Laufall","Lauman","Laundess","Laura Mark","Laurel","Laurel Creek","Laurel Hill","Laurel Park","Laurel Woods","Laurels","Laurence Hamilton","Lauriana","Laurier","Lauritson","Laussat","Lavally","Lavender Hill","Laver","Lavidge","Lavio","Law","Lawfords Hill","Lawlor","Lawnhurst","Lawrence","Lawrie","Lawson Glen","Laxell","Layden","Layminster","Layton Hall","Lazell","Lazy Hollow","Le Britton","Le Fevres","Le Mans","Le personne","Lea Valley","Leaches","Leadale","Leadville","Leafgreen","Leafy Oak","Leal","Leamington","Leaning Oak","Leapingwell","Leary","Leasowes","Leatherbark","Leatherwood","Leavesden","Lebanon","Lebkamp","Lechford","Leclerc","Ledbury","Ledge Brook","Ledgemere","Ledgewood","Ledston","Lee Acres","Lee Deforest","Lee Lee","Lee School Cross","Leech Brook","Leeds Aire","Leeds Hall","Leeds Victoria","Leeke","Leemon","Lees Farm","Leeside","Leeta Cornus","Leewood","Lefont","Legana","Legdewood","Leggatt","Leggs Hill","Legregni","Lehn","Leibert","Leick","Leigh Mill","Leighfield","Leighwood","Leishear","Leitches Wharf","Lekoday","Leland Farm","Lemans","Lemcrow","Lemocks","Lemons Bridge","Lemoyne","Lenah Farm","Lenburg","Lendrum","Lenfell","Lenham Heath","Lenmore","Lennoco","Lenox","Lent Rise","Lentz","Leo Slyvious","Leona","Leonardo","Leonhardt","Lepine","Leritz","Lerwick","Lesher","Leslie Gilbert","Lessar","Lester Grey","Leswin","Letendre","Letter","Lettsom","Leupold","Levato","Leven","Lever","Leverich","Leveson","Levington","Levy","Lewid","Lewis Court","Lewisburg","Lewitt","Lexden","Ley Field","Leycroft","Leyfield  The Manor","Leys","Leyte","Leytte","Liana","Liberata","Liberty Hill","Liberty School","Libs","Lick","Liddell","Liddle","Lidgett Park","Liebig","Lierly","Liffre","Light Guard","Lightbowne","Lighthouse","Lightning View","Ligman","Likely","Lilah","Lilienthal","Lillehei","Lillie","Lilly Pilly","Lily Dhu","Lilybrook","Limar","Lime Green","Limebank","Limekiln","Limesfield","Limits","Limpsfield","Linberg","Lincoln Centre","Lincoln Mall","Lincolns","Lincroft","Linda Marie","Lindal","Lindberg","Lindelof","Linden Hills","Linden Tree","Lindenoaks","Lindeth","Lindisfarm","Lindor","Lindron","Lindsell","Lindview","Linefield","Linfield","Lingfield","Lingwell Nook","Linkmead","Linkswood","Linmouth","Linneman","Linquist","Linslade","Linton Hall","Linville","Lion Fold","Lions Field","Liparita","Lippi","Liptraps","Lisa Ann","Lisborough","Lisdowney","Liskeard","Lisso","Listmas","Litchenberg","Lithos","Littell","Little Argyll","Little Bear Creek","Little Bluestem","Little Bury","Little City","Little Cove","Little Dorrit","Little Eveleigh","Little Fox","Little Grove","Little Hill","Little Johns","Little London","Little Melody","Little Mountain","Little Norton","Little Patuxent","Little Portland","Little Ridge","Little Seneca","Little Totham","Little Valley","Little Whaleneck","Littlebourne","Littledales","Littlehurst","Littlethorpe","Littleworth Common","Liv","Liversidge","Livingston Terrace","Livsey","Lizotte","Llama","Llangollan","Llewellyn Manor","Lloyd Haven","Lloydminster","Loampit Vale  Jerrard","Lobata","Lobo","Loch Glen","Loch Sloy Service","Lochat","Lochinvar","Lochner","Lochwan","Locke King","Lockesley","Lockhart","Lockinger","Lockney","Locksly","Locomotive","Locust Hill","Lodato","Lodestone","Lodgehill","Loeb","Lofthouse Jumbles","Log Bridge","Log Teal","Loganview","Logic","Logwood","Loines","Lokus","Lolleywood","Loma Alta Fire","Loma Verde","Lombard","Lommel","Loncin Mead","London Bridge","Londonderry","Lone Pine","Long Acre","Long Bow","Long Cove","Long Grove","Long Marl","Long Pine","Long River","Long Valley","Longbeach","Longcroft","Longdon","Longest","Longhedge","Longlane","Longlook","Longmoore","Longport","Longshaw Ford","Longstomps","Longvale","Longwick","Lonna","Loobert","Lookout","Loon Hill","Lopez","Loradale","Lorane","Lord Cecil","Lord Mayors","Lordings","Loree","Lorene","Lorete","Lori Anne","Lorillard","Lorion","Lornadel","Lorreta","Lorton Market","Los Amigos","Los Cerritos","Los Felice","Los Lagos","Los Palos","Los Rios","Loseberry","Lost Colony","Lost Oak","Lostock Park","Lothenbach","Lotten","Lotus","Louart","Loudhams Wood","Loudwater","Loughran","Louis Holstrom","Louisana","Lounga","Louth","Lovat","Love Joy","Loveland","Lovering","Lovett","Lovisa","Low Hall","Low Shops","Lowcross","Lowell Davis","Lower Almora","Lower Basinghall","Lower Bloors","Lower Britwell","Lower Byrom","Lower Church","Lower Cox","Lower Dearborn Park","Lower Exchange","Lower Frenches","Lower Hall","Lower Hey","Lower Hutchinson","Lower Lake","Lower Magazine","Lower Marlboro","Lower Mortlake","Lower Oxford","Lower Plateau","Lower Richmond","Lower Sandhurst","Lower Tofts","Lower Vicarage","Lower Wokingham","Lowerhouse","Loweswater","Lowlands","Lowry","Loxford","Loyalty","Lu Anne","Lubec","Lucas Park","Luce Creek","Luchessi","Lucile","Luckenbach","Lucks","Lucretia","Luda","Ludgate","Ludlum","Luellan","Lufkin","Luger","Luisi","Lukens","Lulea","Lumar","Lumber Hill","Lumns","Lunan","Lundan","Lundstead","Lunenburg","Lunski","Lupp","Luria","Lusan","Lusk","Luten","Luton Airport","Luvian","Luxmanor","Luzena","Lybury","Lydd","Lydham","Lydig","Lye Green","Lyle","Lymbridge","Lymer","Lymmington","Lynch Hill","Lyndall","Lynderswood","Lyne","Lynfords","Lynn","Lynn Ridge","Lynncroft","Lynnmoor","Lynsted","Lynway","Lyon Park","Lyonsdown","Lysia","Lyth","Lyttel","M F Bowen","MIddleton","Mabank","Mabfield","Mabrey","Mac Farlane","Mac Queen","MacDonough","MacLachlan","Macadamia","Macara","Macbain","Maccomb","Macedon","Macfarland","Machell","Maciel","Mackall","Mackeson","Mackinnon","Mackubin","Macleay","Macmahon","Macomb","Macpherson","Macredes","Macy","Madary","Maddocks","Madeleine","Madera","Madgeways","Madingley","Madison House","Madrers","Madronawood","Maduro","Maeve","Magazine","Magdelina","Magers","Maggiora","Maginnis","Magner","Magnolia Grove","Magonko","Magothy View","Magreed","Mahar","Mahogony","Mahopac","Maida Vale","Maidenshaw","Main","Main Wharf","Mainsail","Mairmont","Majendie","Major Appleby","Majors","Makatom","Makinson","Malachite","Malapardis","Malborough","Malcolm X","Maldonado","Maley","Malicoat","Mall Access","Mallard Landing","Mallawa","Malley","Mallord","Malloy","Malmsbury","Malory","Malsbury","Maltese","Maltmans","Malvasia","Malvine","Mamie","Managers","Manatauck","Manchest","Mancini","Mandalay Beach","Mander","Mandoon","Manemet","Manfre","Mangin","Mangravet","Manhasset","Maniago","Manion","Manitou Island","Manlove","Manner","Manning","Manoff","Manor Circle","Manor Hall","Manor Park","Manor Way Maythorne","Manors","Manowie","Manseau","Manshaw","Mansur","Manter","Manton","Manuelian","Manville","Many Mind","Manzanita Fire","Map","Maple","Maple Falls","Maple Lake","Maple Run","Maplecrest","Maplehill","Maplers","Mapleton","Maquan","Maracaibo","Marana","Maranta","Marazzani","Marbi","Marble Ridge","Marblewood","Marburger","Marcel","Marcey","Marcher","Marcius","Marcshire","Mardan","Mardin","Mardol","Mareda","Mares Neck","Marfield","Margaret Curtis","Margarido","Margerita","Margin","Margret","Mari","Mariani","Marich","Marie Major","Marigold","Marilyne","Marina","Marina Shore","Marine Terminal","Mariner Green","Marinita","Mario","Marionet","Marisma","Marivista","Mark Alan","Mark Thomas","Markeley","Market Town","Markgrafs Lake","Markland Hill","Marks","Marl","Marland Hill","Marlbrough","Marler","Marley Neck","Marlis","Marlow","Marlton","Marmadon","Marmona","Marney","Maroo","Marple","Marquet","Marrang","Marrilyne","Marrow","Marsala","Marsdale","Marsh Creek","Marsh Hawk","Marsh View","Marshall Corner","Marshalls","Marshes Dock","Marshmoor","Marstan Moor","Marsworth","Marten","Martha Jane","Marti Marie","Martin Long","Martine","Martinique","Martins Pond","Martis","Martock","Martyr","Marvelle","Marwell","Mary Alice","Mary Caroline","Mary Ellen","Mary Jean","Mary Lou","Mary Scano","Maryanne","Marye","Marylake","Marylon","Marymount","Maryvale","Marzoff","Mascari","Mase","Masjid","Mason Dixon","Masonic Home","Masonville","Massapoag","Masseth","Massolo","Master Gunner","Masthead","Matadero","Matawan Green","Matchmoor","Matfair","Matheron","Mathia","Mathy","Matis","Matross","Mattakeeset","Mattawoman Beantown","Matteson","Matthews Town","Mattingley Bottle","Mattos","Matule","Maudlin","Maughan","Mauldeth","Maura","Maurland","Maves","Mawbey","Maxall","Maximfeldt","Maxwell Frye","May School","Maybank","Maybern","Maycheck","Mayday","Mayeswood","Mayfields","Mayhew","Maylands","Mayme","Mayo","Maypole","Maysent","Mayton","Mayweed","Maze","Mazoe","Mazzone","Mc Alpin","Mc Callum","Mc Ceney","Mc Closkey","Mc Cook","Mc Coy","Mc Culloch","Mc Divitt","Mc Ewen","Mc Ginness","Mc Gregor","Mc Kee","Mc Keon","Mc Lain","Mc Loughlin","Mc Murray","Mc North","Mc Veigh","McAleer","McArthur","McBrown","McCampbell","McCarthy Ranch","McCauley","McClaren","McClintock","McClure","McCone","McCormack","McCoy Creek","McCrory","McCulley","McCurley","McDiarmid","McDonnell","McDuff","McEntee","McFarlane","McGarvie","McGinn","McGowen","McGuin","McHatton","McInnis","McKee","McKenna","McKeown","McKinley","McKlintock","McLaughan","McLees","McManus","McMurdo","McNamee","McNerney","McPhee","McSherry","McWilliams","Mcavoy","Mccarthy","Mccoy","Mcdevitt","Mcendy","Mcgovern","Mcintosh","Mckinley","Mclellan","Mcpherson","Meachin","Meade","Meadhook","Meadow Brook","Meadow Farm","Meadow Hall","Meadow Oak","Meadow Run","Meadow Wood","Meadowcrest","Meadowgate","Meadowland","Meadowood","Meadowsedge","Meadowvista","Meadway  Bigwood","Meal","Meanley","Mecan","Meckes","Medawar","Meder","Median","Medieval","Medlake","Medoro","Medwin","Meeks","Meeson","Meetinghouse","Megg","Mehaffey","Meiele","Meisinger","Mel","Melba","Melch","Meldung","Melfort","Melina","Melksham","Mellersh Hill","Mellison","Mellon","Mellowstone","Melody Hill","Melsomby","Melva","Melvin","Melyncourt","Memorial School","Menard","Mendelssohn Service","Mendip","Mendota","Menhart","Menlo Oaks","Menominee","Mensching","Mento","Meon","Merc","Mercers","Mercier","Mereclough","Mereland","Mereside","Meriam","Meridian Hill","Merikoke","Merindah","Meritt","Merklin","Merlini","Merna","Merrall","Merribrook","Merrie Mill","Merrilee","Merrimac","Merrinot","Merritt Farm","Merriwether","Merry Moppet","Merryfield","Merrymeeting","Merseles","Merstham High","Merttins","Merwin","Mesa Creek","Mescalero","Messaline","Messina","Metacomett","Methilhaven","Metro","Metropolitan Church","Metten","Metzler","Mexborough","Meyers Grade","Mezzamonte","Miami","Michael Canlis","Michael Point","Michalik","Michelham","Michener","Micik","Mickleham","Microlab","Midan","Middelton","Middle Creek","Middle Harbour","Middle Neck","Middle River","Middle park","Middleby","Middlefork","Middlemead","Middlesex Center","Middleton Ridge","Middletune","Midfield","Midhill","Midland Hills","Midnight","Midstone","Midway","Mifsud","Migration","Mikayla","Miko","Milano","Milboro","Milcote","Mildura","Mile Tree","Miles Hill","Milfan","Milham","Milk Farm","Mill Bay","Mill Creek","Mill Gate","Mill Park","Mill River","Mill Vue","Millbeck","Millburne","Millenium","Miller Heights","Millers Island","Millfields","Millhead","Milliken","Milliston","Millrace","Mills Hill","Millshot","Millstream Service","Millview","Millyan","Milner","Milot","Milsop","Milton High","Milva","Milwaukee","Mimms Hall","Mina Rosa","Minas","Mindar","Mindy","Minebrook","Mineral Springs","Ming","Minimbah","Mink Meadows","Minnamurra","Minnesota Bluffs","Minnieford","Minola","Minot Light","Minstrell","Minthaven","Minuet","Mionske","Mira Mesa","Miracle Mountain","Miralo","Miranda","Mireille","Mirkwood","Mirrielees","Mirycarr","Miss Anne","Mission College","Mission Rock","Mississippi Bar","Mistic Harbour","Mistwood","Misty Hill","Misty Woods","Mitchell Canyon","Mitchler","Mitlon","Mittendorf","Miwok","Mizzen","Moat Hall","Mobley","Moccasin Hill","Mockingbird","Modaff","Modern Ice","Moe","Moffats","Moffitts","Mohawk River","Moir","Mokelumne River","Molasses Run","Molesey","Moline","Molle","Molly Millars","Molteno","Momar","Monacan","Monaltrie","Monard","Monaton","Moncton","Mondigo","Monetary","Monfarville","Monie","Monk Bridge","Monks","Monkswood","Monnens","Monogram","Monponset","Monsal","Mont Clare","Montair","Montana","Montbatten","Montcourse","Monte Cresta","Monte Sereno","Montebello","Montefiore","Montelegre","Monterery","Montery","Montevina","Montford","Montibello","Montmorenci","Montour","Montrose","Monument","Monzal","Mooki","Moon Hill","Moon Valley Ranch","Moondani","Moonlight Hill","Moonshine","Moor Green","Moora","Moordale","Moorefields","Mooreview","Moorhey","Moorings","Moorside","Moos","Mopsick","Moraine","Morants Court","Mordaunt","Mordred","Morehouse","Morelli","Moremead","Moresdale","Morewood Oaks","Morgan Territory","Morgaston","Moril","Morison","Morley Clough","Morna","Morning Field","Morning Sun","Morninglo","Moro","Morpheus","Morrie","Morris Park","Morrison","Morristown","Morry","Morsemere","Morten","Mortimer Lewis","Morton Hill","Morval","Morwood","Moseby","Moseley Wood","Mosher","Moss","Moss Grange","Moss Park","Mossbank","Mossford","Mossman","Mossy Rock","Motcombe","Motney Hill","Mottisfont","Mouldsworth","Mounce","Mounslow","Mount Annan","Mount Blue","Mount Crest","Mount Diablo","Mount Ephraim","Mount Frazier","Mount Hamilton View","Mount High","Mount Isabel","Mount Kenya","Mount Locust","Mount Morris","Mount Olney","Mount Pleasant","Mount Ridgeway","Mount Skirgo","Mount Tom","Mount Wachusett","Mount Wilson","Mountain Charlie","Mountain Lakes","Mountain Rock","Mountain View Ranch","Mountaire","Mountgrove","Mountwood","Movers","Mowden Hall","Mowry School","Moyengully","Moyser","Mt Curve","Mt. Hope","Mucking Hall","Muddy Branch","Muender","Muhlenhardt","Muirwood","Mulbring","Mule Deer","Mulhall","Mull","Mullens","Mulloy","Multiplex","Mums","Muncy","Mundays Borough","Mundy","Munich","Munnings","Munsee","Munsterburg","Muraoka","Murdoch","Murieta","Murmansk","Murphy Lake","Murray","Murray Rose","Murrin","Murry Hill","Murvey","Muscat","Musgnug","Musicmaster","Musketaquid","Musquashicut","Musto","Muth","Muttontown","My Mollies Pride","Myatt","Myer","Myler","Mylon","Myotis","Myrle","Myrtle Beach","Myrtlewood","Mystic View","N Abbey Glenn","N Adams","N Alameda","N Algonquin","N Anthon","N Arcade","N Arlington Mill","N Arundel","N Astor","N Avondale","N Bank","N Bartlett","N Beach","N Bellmore","N Beverly","N Bittner","N Bluebonnet","N Boro","N Bradley","N Brentwood","N Brightway","N Brook","N Browning","N Buell","N Burning Bush","N Calhoun","N Camp Meade","N Carll","N Cassell","N Center","N Chamlin","N Charter Point","N Chesapeake","N Christiana","N Clarice","N Clinton","N Coldspring","N Colorado","N Conrad","N Cortez","N Cowley","N Crosby","N Cutler","N Danforth","N Dato","N Dearman","N Delaware","N Denton","N Dexter","N Dittmar","N Dovington","N Dwiggins","N East","N Echo Lake","N Edison","N Elgin","N Ellyn","N Elston","N English","N Euclid","N Ewing","N Farrell","N Ferdinand","N Fillmore","N Fletcher","N Forest Garden","N Fox","N Frederick","N Frontenac","N Galena","N Garnsey","N Geneva","N Gilbert","N Glenmore","N Grace","N Grayson","N Greencastle","N Griffith","N Haig Point","N Hampshire","N Harlem","N Harvey","N Hazel Crest","N Hemlock","N Hermitage","N High","N Hill","N Hollister","N Hooker","N Howell","N Hundley","N Idaho","N International","N Ivy","N Janssen","N Jersey","N Jordan","N Kansas","N Kedzie","N Kenilworth","N Kennison","N Kerbs","N Kilbourn","N Kings","N Kittson","N Kostner","N Laflin","N Lakeshore","N Landers","N Larrabee","N Lavergne","N Leavenworth","N Leibert","N Lenwood","N Lewis","N Lillian","N Lister","N Lockwood","N Long Cove","N Loomis","N Lotus","N Luella","N Lynn","N Magnolia","N Mallard","N Manor","N Maria","N Marshfield","N Maryland","N May","N Mc Leod","N McVicker","N Melody","N Merchants","N Middle","N Military","N Minnehaha","N Moetz","N Montclair","N Moreland","N Mountain","N Nagle","N Nashotah","N Naylor","N New Britton","N Newgard","N Nickerson","N Nordica","N Northwest","N Oak Hill","N Oakmont","N Ode","N Old Creek","N Oleander","N Ontario","N Oshkosh","N Owens","N Park","N Patton","N Peary","N Pennsylvania","N Peyton","N Pima","N Pitt","N Plymouth","N Pond Shore","N Powhatan","N Prestwick","N Prosperity","N Queen","N Raddant","N Rand","N Raynor","N Reta","N Ridgeland","N Riverside","N Roberts","N Rockwell","N Rolfe","N Rosetree","N Royal Oaks","N Saddle Brook","N Sangamon","N Schletti","N Scott","N Service","N Shenandoah","N Silverlake","N Smith","N South Park","N Springfield","N Suthers","N Syracuse","N Tamarack","N Terrill","N Tonty","N Tripp","N Turtle Bay","N Upshur","N Van Buren","N Varner","N Vermont","N Village","N Wacouta","N Waller","N Warrington","N Waterman","N Weatherstone","N Wells","N Westgate","N Westwood","N White Pine","N Wildrose","N Williams","N Wilshire","N Windsor","N Wisner","N Woodgate","N Woodward","N Yale","N la Londe","NE Arthur","NE Greens Crossing","NE Jambor","NE Polk","NE Wilson","Na Wa Ta","Nabscot Brook","Naden","Nagle","Nahatan","Nairn","Nall","Namba","Namleps","Nan King","Nanapashamet","Nancy Ann","Nangana","Nanna","Nant","Nanuet","Napa River","Naples","Narada","Narbuth","Nardone","Naretha","Naroo","Narromore","Narvick","Nash","Nashenden Farm","Nasonville","Nata","Natchez","Nathaniel","Nathorst","Nations","Natomas","Natural Bridges","Naugatuck","Nauset","Navahoe","Nave","Navigator","Nawadaha","Naylon","Nazrul","Nealden","Neaptide","Neasden","Nebo","Neckinger","Nedderson","Needham","Needles","Neeley","Nees","Nehemiah","Neighbors","Neillian","Neitzel","Nell","Nellgrove","Nelmark","Nelson Grove","Nelson Short","Nemic","Neotomas","Nepicar","Nepshaw","Nerdy","Nero","Neshobe","Nestlewood","Netheravon","Netherend","Nethermont","Netteswell","Nettlewood","Neufairfield","Neustoneshire","Nevern","Nevins","New Ackertown","New Banner","New Bedford","New Bridge","New Brooklyn","New Cathedral","New Clark","New Creek","New Devon","New Durham","New Era","New Forest","New Gateway","New Harbor","New Herbert","New Hope","New Illawarra","New Kent","New Liberty","New Lots","New Mayfield","New Milford","New Mount","New Old Bridge","New Place","New Quay","New Rochelle","New Solomoms Island","New Tank Hill","New Utrecht","New Walnut","New West Townsend","New Woods","Newacre","Newarth","Newberry","Newbreak","Newby Bridge","Newcomb","Newcrossing","Newell Creek","Newfoundland","Newham Way  Colman","Newington Forest","Newland Green","Newmain","Newmarsh","Newport Cove","News Lees","Newteswell","Newton Wood","Next Day Hill","Nibbe","Nicastro","Nichold","Nicholson","Nickle Plate","Nickson","Nicole","Nicolosi","Nidva","Nieman","Nigeria","Nightingale Farm","Nike Manor","Nile","Nimbey","Nimrick","Nine Elms","Nineveh","Ninnis","Nipmuc","Nire","Nisich","Nithdale","Nixon","Noak Hill  North Hill","Nobbs","Nobile","Noble Victory","Nock","Noe","Nogales","Nola","Nolden","Nolpark","Nomini","Nonington","Noolinga","Noorang","Norbay","Norbrik","Norcia","Norcutt","Nordham","Nordstrom","Noreuil","Norfolk Farm","Norham","Noriker","Norlington","Norman","Normand","Normandy Crossing","Normanshire","Normoor","Norridge","Norroy","Norsid","North Abel","North Alamo","North Amelia","North Arlington","North Audley","North Bascom","North Bayshore","North Bella Monte","North Beulah","North Border","North Brandon","North Brook","North Butte","North Canal","North Carriage","North Chanterella","North Circular","North Clinton","North Commonwealth","North Court","North Crest","North Dale","North Delaware","North Dublin Ranch","North Eastling","North Eldorado","North End","North Fair","North Ferndale","North Folly","North Fosket","North Fuel Break","North Gary","North Glebe","North Graham","North Great","North Guild","North Hancock","North Hartley","North Hickory","North Hill","North Hooper","North Huron","North Ione","North Jefferson","North Kenmore","North Kern","North Knollwood","North Lake Shore","North Laughlin","North Lenora","North Linden","North Lockewood","North Los Angeles","North Macarthur","North Malden","North Margaret","North Martingale","North Mc Intire","North Meadow","North Micke Grove","North Milpitas","North Monroe","North Mountain","North Nassano","North North Ripon","North Old Hicks","North Orenda","North Palmer","North Patterson","North Pearson","North Pilgrim","North Plaza","North Pond","North Pythian","North Rand","North Regatta","North Ridge Vista","North River","North Rohlwing","North Russell","North San Joaquin","North Scenic","North Serven","North Shetland","North Sierra Nevada","North South","North Sun","North Sycamore","North Tenter","North Tolman","North Tully","North Vale","North Veach","North Viola","North Wall","North Water","North West Arm","North White","North Williamson","North Winston","North Woodford","North de Anza","Northall","Northbay","Northbrook Court","Northcoate","Northcross","Northeast Alder Crest","Northeast Arrowhead","Northeast Berry","Northeast Brackenwood","Northeast Carriage","Northeast Crescent","Northeast Discovery","Northeast Ewing","Northeast Glavin","Northeast Hawthorne","Northeast Husky","Northeast Joshua Tree","Northeast Kennedy","Northeast Koura","Northeast Lofgren","Northeast Manor","Northeast Meigs","Northeast Morning","Northeast Norton","Northeast Paulanna","Northeast Puget Bluff","Northeast Richardson","Northeast Seaview","Northeast Sunset","Northeast Victorian","Northeast Windermere","Northeastern","Northern Fences","Northern Spy","Northfront","Northhurst","Northlea","Northolm","Northpoint","Northstar","Northvale","Northwest","Northwest Elford","Northwest Inneswood","Northwest North Beach","Northwest Sammamish","Northwinds","Norton","Norumbega","Norwalk","Norwood High","Nostaw","Notch Hill","Nothing","Notthumberland","Nottoway River","Nova","Novello","Nowers","Noyna","Nueva","Null","Number One First","Nuneham","Nunnery","Nunthorpe","Nurney","Nurserymans","Nutberry","Nutgrove","Nutshell","Nutty","Nuxley","Nydam","Nylan","Nyngan","O Connell","O Hanneson","O Malley","Oadby","Oak Bluff","Oak Canyon","Oak End","Oak Glen","Oak Hill","Oak Lea","Oak Meadow","Oak Park","Oak Rock","Oak Shores","Oak Tree Farm","Oak Wood","Oakbrook Estates","Oakdale Farm","Oakenclough","Oakes","Oakfold","Oakhanger","Oakknoll","Oakland Terrace","Oakledge","Oakman","Oakmont Plaza","Oakridge","Oakshaw","Oakton Glen","Oakvale","Oakways","Oakwood Knoll","Oast","Oatfield","Obama","Obermueller","Obrad","Ocala","Occoquan Forest","Ocean Hill","Oceanic","Ocho Rios","Ockham","Octagonal","Oday","Odell","Odessa","Odney","Oelsner","Off Cherry","Off Musterfield","Off Westford","Offham","Offord","Ogburn","Ogla","Ohanneson","Ohlone","Ojibway","Okehurst","Okst","Old","Old Altos","Old Archer","Old Ayer","Old Barns","Old Battery","Old Bayside","Old Bell","Old Bethpage","Old Bix","Old Bolton","Old Branch","Old Brick","Old Brooks","Old Bury Lodge","Old Campbell","Old Carriage","Old Cañada","Old Centreville","Old Chatham","Old Chestnut","Old Cistern","Old Coach","Old Colony Cove","Old Corona","Old Cow Pasture","Old Crow Canyon","Old Davis","Old Denville","Old Doctors","Old Dublin","Old East","Old Elmdale","Old Esher","Old Falls","Old Fence","Old Fleet","Old Forge Hill","Old Frank Tippett","Old Furnace Colony","Old Georgetown","Old Glenview","Old Grand","Old Greenville","Old Half Day","Old Harter","Old Hazel Dell","Old Hickory","Old Hillary","Old Home","Old Horner","Old Hunt","Old Ironsides","Old Jericho Park","Old Keene Mill","Old Killam Hill","Old Kirker Pass","Old Lakes","Old Las Palmas","Old Leominister","Old Linslade","Old Log","Old Mac Donald","Old Mamaroneck","Old Marsh","Old McHenry","Old Merlins","Old Mill Pond","Old Montague","Old Mount","Old Muirkirk","Old Nazeing","Old Newland","Old Northport","Old Ocean","Old Ox","Old Palisade","Old Parrin","Old Pearson","Old Philadelphia","Old Pittwater","Old Plum Point","Old Post","Old Princeton","Old Quarterfield","Old Rancheria","Old Redmond","Old Reston","Old Riva","Old Rockford","Old Ruislip","Old Samuel","Old Sanford","Old Schaumburg","Old Shade","Old Shore","Old Sleigh Hill","Old Soar","Old South Highland","Old Springfield","Old Summer","Old Taren Point","Old Thieves","Old Tovil","Old Tree","Old Tyler","Old Vic Theatre","Old Wallgrove","Old Washington","Old Weiland","Old West Main","Old Wheatley","Old Wickford","Old Wilmot","Old Wokingham","Old Woolwich","OldField","Oldchurch","Olde English","Olde Lantern","Olde Towne","Oldfield","Oldmill","Oldway","Oleander","Olf Fold","Oliva","Olive Lee","Oliveleaf","Oliveswood","Olivier","Olley","Olmi Landrith","Olney Mill","Olson Farm","Olyffe","Omaban","Omaru","Omira","Onamog","One Bridge","One Palace","Oneata","Oneto","Onondaga","Ontario Bay","Oorin","Opatrny","Openshaw","Oping","Opus","Oram","Orange Grove","Orangeburgh","Oratam","Orbain","Orchard","Orchard Creek","Orchard Hill","Orchard Park","Orchard View","Orchis","Ordsall","Orehr","Oreta","Oriana","Oriental","Orinoco","Oritan","Orland Woods","Orloff","Ormbrek","Ormonde","Ormside","Oro","Orphanage","Orral","Orrishmere","Orson","Ortona","Osage","Oscecla","Osiers","Osmer","Oso","Osseo","Ostade","Osterly","Oswald","Otay","Otis","Otlinge","Ottaway","Otterbourne","Otterspool","Ottways","Oulton","Oursler Park","Outer Loop","Outing","Outrigger","Ouzlewell Green Green","Ovens","Overbeck","Overcrest","Overfield","Overion","Overleigh","Overmoor","Overview","Owasco","Owen Brown","Owens Lake","Owl Harbor Levee","Owles","Owna","Ox Hunt","Oxbow Creek","Oxenbridge","Oxenpark","Oxford Square","Oxley","Oxnard","Oxshott","Oyster Creek","Ozone","Paarl","Pacella","Pacific","Pacifiv View","Packer","Packman","Paddack","Paddle Wheel","Paddocks","Paderewski","Padre","Padwell","Page","Pagebrook","Pagitt","Paice","Paines Brook","Painted Daisy","Painters","Pajaro Hills","Palace","Paladini","Palawan","Paley","Palisades","Palladio","Palm Beach","Palm Spring","Palmdale","Palmer Ranch","Palmerston","Palmieri","Palmwood","Palo Vista","Palos","Palwaukee","Pambula","Pamper","Panama","Pandora","Panjon","Pannonia","Panshanger","Panther Ridge","Panzano","Papago","Paper Mill Creek","Papoose","Paprota","Parade","Paradise Valley","Parallel","Parbury","Parcot","Pardoner","Parfett","Paris Oaks","Park Barrington","Park Corner","Park End","Park Gardens","Park Headquarters","Park Knoll","Park Mills","Park Point","Park Sharon","Park Tree","Park Wilshire","Parkcroft","Parker Creek","Parkers Grove","Parkfield","Parkhaven","Parkington","Parklands Close  Lynn","Parkmeadow","Parkrow","Parkston","Parkview","Parkways","Parley","Parmal","Parmoor","Parnoo","Parquet","Parrett","Parrot","Parsells","Parson Hill","Part","Parting Rock","Partridge Berry","Parvin","Pasas","Pasedena","Paseo Robles","Paso Corto","Passaconaway","Passalaqua","Passy","Pastori","Pasturewood","Patch","Patemore","Pates","Pathwood","Patley","Patocchi","Patricia","Patriot Square","Patshull","Patterdale","Patterson Ranch","Patty","Patuxent Woods","Paul Gore","Paul Revere","Paula Lynn","Paulhan","Paullus","Pauly","Pauw","Pavilion","Pawlet","Paxos","Payle","Payran","Peace","Peaceful Ridge","Peach Leaf","Peachland","Peacock Farm","Peake","Peale","Pearblossom","Pearl Bay","Pearlman","Pearsall","Pearwood","Peat Bog","Pebble Canyon","Pebbleford","Pebmarsh","Peckford","Peckover","Pecos","Pederson","Pedroni","Peel","Peer","Peg","Peggy","Pegwood","Pekin","Pelham Island","Pelican Point","Pellant","Pellings","Pelorus","Pemba","Pembridge","Pembury","Pena","Penataquit","Pencroft","Pendas Way Barwick","Penderbrooke","Pendle","Pendragon","Penefield","Penfield","Penhallow","Peninsula","Penitencia Creek","Penley","Penn Crossing","Penndale","Penngrove","Pennington","Pennock","Pennsylvania","Penny Meadow","Pennyfather","Pennypacker","Penprase","Penrose","Pensbury","Penswick","Pentlow","Pentreath","Penwerris","Peony Place","Peperharow","Pepper Creek","Pepperbox","Peppermill","Pepple","Pequot","Perch Lake","Percypenny","Peregrine White","Pergola","Perigo","Perita","Perkiomen","Permanent","Perouse","Perreira","Perrie","Perrone","Perry Vale  Woolstone","Perrymead","Perrywinkle","Pershore","Personette","Perwell","Pested","Petar","Peter A McCuen","Peter Hans","Peter Parley","Peterhouse","Peters Spring","Peterson","Petite Creek","Petrell","Petrolia","Petters","Pettis","Petzold","Pevril","Peyla","Pfeiffer","Phaeton","Phar Lap","Pheasant Fields","Pheasant Run","Pheby","Phesant","Philbrick","Philip Howard","Phillimore","Phillipp","Phillips Park","Philo","Phipps","Phoenix","Phyldan","Physics Ellipse","Pibrock","Piccoli","Pickard","Pickersgill","Picketts Lock","Pickpocket","Picnic","Picton","Pidgeon Hill","Pield Heath","Pierce Hill","Pierino","Pierrepoint","Pierson Miller","Pig Rock","Pigeon Hollow","Pigs Eye Lake","Pike Ridge","Pikkney","Pilcher Park","Pilgrim Hill","Pilling","Pillowlace","Pilsen","Pimhole","Pin Cushion","Pincherry","Pindell","Pine Arden","Pine Crest","Pine Hill","Pine Lake","Pine Needles","Pine Spring","Pine Valley","Pineapple","Pinecote","Pinefield","Pineknot","Pinemont","Pineridge","Pinevale","Piney Branch","Piney Point","Pingate","Pink","Pinkneys","Pinner  Love","Pinole","Pinrock","Pinto Trail","Pioneer Hills","Piper Ridge","Pipit","Pippo","Pirton","Pissaro","Pitcairn","Pitfold","Pits Farm","Pitstock","Pittman","Pittson","Pix Farm","Pizzorni","Placer Oaks","Plaid","Plainville","Plan","Planetree","Plante","Plash","Plassy","Platform","Platt House","Plattsdale","Plaxdale Green","Players Pond","Playstool","Pleasant Chase","Pleasant Grove School","Pleasant Oaks","Pleasant View","Pleasantview","Pleasure Pit","Plenge","Plimpton","Plomer","Ploughley","Plucksbridge","Plum Grove","Plum Valley","Plumbley","Plumley","Plumpton","Plumtree","Pluth","Plympton","Pobgreen","Pochin","Poco","Podesta","Podsmead","Pogany","Poillon","Point Allerton","Point Hollow","Point Rider","Pointcross","Pointview","Poker","Polari","Pole Plain","Polehanger","Polhill","Polito","Pollards Oak","Polling House","Pollywog","Polonius","Polworth","Pombo","Pomerol","Pommeroy","Pomper","Ponce","Pond Crest","Pond Hill","Pond Ridge","Ponder","Pondholton","Pondwick","Ponkapoag","Pont","Pontos","Ponytail","Pool Bank New","Pooles","Poona","Pootings","Popeley","Popkins Farm","Poplar Grove","Poplarhollow","Poppenhusen","Poppy Glen","Poppyseed","Porcaro","Porlock","Port Capital","Port Hill","Port Reading","Port Victoria","Porte de Leau","Porter Plain","Porters Hill","Porthole","Portledge","Portmore","Porto Rico","Portola Meadows","Portsea","Portville","Posen","Possehl","Post Forest","Post Office Delce","Postmill","Potassium","Potee","Potomac Creek","Potomac Manors","Potomac Ridge","Potomack","Potsdam","Potternewton","Pottingfield","Poulet","Poultney","Poundhurst","Poverest","Powder Horn","Powdermill","Powells Creek","Power Ridge","Powis","Poydras","Poynter","Pozzan","Prado Vista","Praire","Prairie Creek","Prairie Grass","Prairie Moon","Prairie Schooner","Prairieside","Prancing Deer","Pratling","Pratts Mill","Preble","Predella","Premisy Hill","Prentis","Presdales","Presidente","Press","Prested","Preston White","Preswicke","Prey Heath","Pride","Pridham","Priest Park","Priestnall","Prime","Primrose","Prince Charles","Prince Edwards","Prince Lake","Prince of Wales","Princess","Princess May","Prince’s","Printemps","Prinys","Priors Hatch","Priory Park  Lee","Prison","Privateer","Probst","Produce","Profumo","Promintory","Propp","Prospector","Prothero","Provender","Provincetown","Pru","Prune Tree","Prybyl Pond","Pryors","Public Safety","Pudding Cake","Puddon","Puerto Vallarta","Pukwans","Pulham","Pulley","Pulsifer","Pumphrey","Punch Copse","Purbeck","Purdie","Purfield","Purissima","Purleigh","Purnell","Purpleleaf","Purton","Putah Creek","Putnams","Puttenden","Puves","Pyenot Hall","Pymmes Green","Pyott","Pyrite Mine","Pyrossia","Quaas","Quadra","Quail Canyon","Quail Lakes","Quail Vista","Quaint","Quaker Meeting House","Quality","Quanders Promise","Quantico","Quarles","Quarry Arm","Quarry Pond","Quarter Sessions","Quartermaine","Quartz","Quayle","Queen Anne","Queen Eleanors","Queenbeyan","Queens Chapel","Queens Row","Queensburg","Queensguard","Queenston","Queensway Tennyson","Quema","Quercus","Quetta","Quickedge","Quien Sabe Ranch","Quiet Oak","Quiet Woods","Quilberry","Quilters","Quince Mill","Quincy","Quinlisk","Quinsenberry","Quintard","Quintree","Quirnia","Quist","Quoitings","R. Belanger","Rabans","Rabbits Run","Rabro","Racecourse","Rachell","Rackstraw","Raday","Radclyffe","Radford","Radison","Radley","Radnor","Radwinter","Raemore","Rafferty","Rafter Ridge","Ragged Hill","Rago","Rahn","Raiff","Railroad Seekonk","Rain Cloud","Rainbow Ridge","Raines","Rainsboro","Raintree","Rak","Raleana","Rally","Ralph Mann","Ralwood","Ramapo Brae","Rambler Rose","Rambling Woods","Ramgren","Ramney","Rampayne","Ramscote","Ramsey Main","Ramstree","Rances","Ranchito","Rancho Cabeza","Rancho Laguna","Rancho Ramon","Rancho Ventura","Rancom","Randall Ridge","Randle","Random","Randwick","Ranford","Rangely","Rangeway","Rankine","Ranmore","Ransell","Rantoul","Rapid","Rapp","Raquel","Raskin","Rassani","Ratcliffe","Rathbone","Rathlin","Rattle Snake Hill","Raub","Raveley","Ravendale","Ravenoak","Ravensbourne","Ravensdon","Ravenue","Ravinia","Rawcliffe","Rawling","Rawson","Ray","Ray Moses","Rayborn Creek","Raydons","Rayleigh","Raymead","Raymundo","Raynes","Rayshire","Read Head","Reading Hill","Reagan","Reamer","Rear Wildwood","Reavis","Rebeiro","Recard","Recovery","Recycle","Red Ash","Red Branch","Red Cherry","Red Cow","Red Fall","Red Ground","Red Hills","Red Lion  Lower","Red Oak Service","Red Robin","Red Sky","Red Wood","Redbournbury","Redcar","Redcoat","Redden","Reddington","Rede Court","Redfern","Redhall","Redhouse","Redlands","Redmere","Redmyre","Redoubt","Redrose","Redstock","Redvers Buller","Redwood Gulch","Redwood Terrace","Reed Hall","Reedham","Reedsfield","Reef Point","Reeves","Refugio Valley","Regan","Regency Crest","Regency Woods","Regentwood","Regional Center","Regulus","Reichelt","Reidhaven","Reiger","Reiling","Reims","Reiners","Reinking","Reising","Reitz Lake","Reliance","Relyea","Remenham Church","Remital","Remsen","Renaissance","Rendall","Renfro","Renken","Rennell","Reno","Renshaw","Renway","Reon","Reposo","Republican","Reseau","Reservoir Heights","Resolution","Restharrow","Retford","Retta","Reubens","Reva","Revelstoke","Revere Beach","Revie","Revolutionary","Rexburg","Reycraft","Reyna","Reyner","Rfd Coach","Rhame","Rhine","Rhiwlas","Rhodenda","Rhodora","Rhosleigh","Rhyl","Ribble","Ribier","Riccardo","Rice Lake","Rich Branch","Richard House","Richardi","Richdale","Richgar","Richlee","Richmond Meech","Richton","Rickbern","Ricketts Hill","Rickmansworth","Rico","Riddings","Riden","Ridge","Ridge Croft","Ridge Hill Farm","Ridge Retreat","Ridgecrest","Ridgegate","Ridgelee","Ridgemoor","Ridgevale","Ridgewind","Ridgwell","Riding Hood","Ridley","Riedell","Rienzi","Riffams","Rifle Range","Rigden","Rigg","Righters Mill","Rigoli","Riker Hill","Rillo","Rimer","Rimrock","Rincon","Rinear","Ringcroft","Ringfield","Ringley Park","Ringo","Ringwood","Rio Altos","Rio Linda","Rio Robles","Rio del Mar","Ripleys Field","Ripple","Rippling Branch","Ripponden Nursery","Riscioni","Risel","Rishworth New","Risingholme","Rissington","Ritchie","Ritson","Rival Moor","Riven Wood","River Bay","River Creek","River Farm","River Heights","River Meadow","River Point","River Swan","River Village","Riverband","Riverby","Riverfield","Riverland","Rivermist","Rivers","Riversdown","Riverside Run","Rivertowne","Riverwood Terrace","Rivoli","Rixson","Roach","Roald","Roaring Creek","Robak","Robbern","Robby","Robert Best","Robert F Toner","Robert J Mathews","Robert Mays","Robert T Palmer","Roberts Common","Robertson","Robie Manor","Robin Hood","Robinette","Robinsbridge","Robinwood","Roble Ridge","Robley","Robscott","Robyn","Rocca","Roche","Rochford","Rock Chapel","Rock Fish","Rock Island","Rock Oak","Rock Springs","Rockaway Point","Rockcliff","Rockenbach","Rockhall","Rockie","Rockland","Rockliffe","Rockmount","Rocks","Rockstrech","Rockwin","Rocky Brook","Rocky Hills","Rocky Point","Rocky Top","Rocton","Rodbridge","Rodens","Rodiman","Rodmere","Rodrigues","Roe Downs","Roeder","Roemer","Rofay","Rogan","Roger Williams","Rogers Wood","Rohavic","Rohrer","Roke Lodge","Rokewood","Roleen","Roll","Rolling","Rolling Hill","Rolling Oak","Rolling Views","Rollingwood","Rolls Park","Rolt","Romaine","Romanhurst","Rombalds","Romero","Romilly","Romney","Romsey","Ronaele","Ronarm","Rondel","Ronek","Ronnie","Ronzheimer","Rookery","Roome","Rooster","Ropemaker","Roppolo","Rosa","Rosada","Rosalita","Rosaria","Roscoe","Rose Ann","Rose Bush","Rose Glen","Rose Marie","Rose Vine","Rosebay","Rosebridge","Rosecourt","Rosedene","Rosegarth","Roseheath","Roseleaf","Rosemar","Rosemill","Rosenau","Rosenfield","Roseridge","Roseveare","Roseway","Rosie Lee","Rosincress","Roslyn","Ross Arnold","Ross Smith","Rossefield","Rossetti","Rosskelly","Rossmore","Rostella","Rosyman","Rothbard","Rotherbridge","Rotherhithe Old","Rothiemay","Rothwell Commercial","Rotunda","Rougemont","Roughtley","Round Barn","Round Lick","Round Tree","Roundhay","Roundstone","Rounsevell","Rousebarn","Rovato","Rowalt","Rowanswood","Rowbotham","Rowe","Rowhill","Rowlett","Rowling","Rowser","Roxann","Roxbury","Roxton","Royal Ann","Royal Coach","Royal Dane","Royal Forest","Royal Heights","Royal Mint","Royal Porthcawl","Royal Tern","Royal Worlington","Royalthorn","Roycroft","Royds Farm","Roys","Rozella","Rubens","Rubino","Ruby Hill","Ruckmans","Ruddpark","Rudolf","Rueben","Ruffin","Rufus Isaacs","Rugg","Ruins","Ruland","Rumbles","Rumple","Run Common","Rundle","Runkenhage","Running Creek","Running Pump","Runswick","Rupack","Ruritan","Rusden","Rush Meadow","Rushbrooke","Rusher","Rushing Creek","Rushmoor","Rushwood","Rusland Park","Russell","Russell Zepp","Russet Wood","Russian River","Rusthall","Rustic View","Rustling Oak","Rutgers","Ruthelma","Rutherwyk","Rutledge","Rutz","Ryan","Rybrook","Rydall","Ryder Hill","Rydon","Rye Mill","Ryegate","Ryer Island","Ryfold","Rylands","Rymer","Ryrie","Rywick","S Adams","S Airlite","S Allport","S Anna Marie","S Arbor","S Arlington Mill","S Ashby","S Avon","S Balmoral","S Barton","S Bedford","S Bellows","S Benson","S Beulah Vista","S Birkhoff","S Blaisdell","S Blue Water","S Boston","S Bragg","S Brennan","S Briggs","S Brook","S Bruner","S Burnett","S Butler","S Canal","S Carillon","S Carondolet","S Cathedral","S Center","S Channing","S Chatham","S Chester","S Chippendale","S Claire","S Clifton","S Coghill","S Columbus","S Consumers","S Corliss","S Country Club","S Cowley","S Creighton","S Crystal","S Damen","S Day","S Deep Lake","S Demarest","S Des Plaines","S Dinwiddie","S Donna","S Dow","S Dunmoor","S Eagle","S Eberhardt","S Edgewood","S Ela","S Elliott","S Elodie","S Erie","S Evanslawn","S Fairfield","S Farnsworth","S Fern","S Fish Lake","S Forest","S Fox Wood","S Freemont","S Fullerton","S Gary","S Georgia","S Glasgow","S Glover","S Grace","S Great Neck","S Greenbrier","S Griffith","S Hager","S Hamlin","S Harlem","S Hartshorne","S Hawthorne","S Heathcote","S Henry","S Hickory","S Hillcrest","S Hobble Bush","S Holmdel","S Hough","S Hudson","S Ilwaco","S Inman","S Ivy","S Jeffery","S John","S Julian","S Kaspar","S Keeler","S Kenneth","S Ketay","S Kimberly","S Knight","S Komensky","S Lady Bar","S Lake Shore","S Lanza","S Lawn","S Leclaire","S Lerisa","S Liberty","S Linden","S Lloyd","S Long","S Lord","S Loucks","S Lumber","S Lytle","S Main","S Manistee","S Marathon","S Martin","S Maxon","S McCorkle","S Meader","S Melvina","S Merrion","S Middle Point","S Millard","S Minnesota","S Monaghan","S Moody","S Mount Curve","S Munn","S Nancy","S Navajo","S Newberry","S Nordica","S Noyes","S Oak River","S Oakley","S Ohio","S Old Rand","S Oregon","S Oxford","S Park Place","S Patrick","S Pearl","S Peoria","S Phelps","S Pine Grove","S Plantation","S Point","S Poplar","S Prescott","S Pulaski","S Quinn","S Randolphville","S Rea","S Regina","S Richard","S River","S Robert","S Robinson","S Root","S Rowell","S Rush","S Sage","S Sarah","S Schoolhouse","S Seebert","S Seymour","S Shields","S Skidmore","S South Chicago","S Spaulding","S Suffolk","S Susan","S Talman","S Terrace","S Thurlow","S Town Center","S Truro","S University","S Van Buren","S Varner","S Victoria","S Vincent","S Wabash","S Wallace","S Warner Bridge","S Water","S Waverly","S Weiler","S West","S Westview","S White Oak","S Will Center","S Willow Creek","S Windham","S Winthrop","S Woodbine","S Woodrow","S Wynstone Park","S la Londe","SE Delaware","SW Centennial","Sabal","Sabine","Sabre","Sacia","Saco","Sacred Palm","Saddle Creek","Saddle Ridge","Saddlebred","Sade","Sadlier","Safari","Sagamore","Sage Brush","Sagemont","Sagittarius","Saidel","Sailors Bay","Saines","Saint Alban","Saint Ann","Saint Augustine","Saint Bernard","Saint Casimir","Saint Clair","Saint David","Saint Edwards","Saint Francis","Saint Gregory","Saint Ives","Saint Joseph","Saint Lawrence","Saint Margarets","Saint Mathews","Saint Nicholas","Saint Peter","Saint Regis","Saint Thomas","Saint Winifreds","Sajak","Sakura","Salamander Canyon","Salcote","Salem Lake","Salford","Salisbury Downs","Sallaway","Salmar","Salomons","Salt Hill","Saltcoats","Salthill","Saltpan","Saltzman","Salvi","Sam Cava","Sam Smith","Samaritan","Samnatha","Sample","Samuel","Samuel Ogden","Samuels","San Andres","San Blas","San Domar","San Gabrial","San Jaun Canyon","San Juan Pass","San Luis","San Marin","San Miguel Canyon","San Pedro","San Remo","San Simeon","San Ysidro","Sanches","Sand Beach","Sand Harbour","Sand Prairie","Sandakan","Sandbed","Sandcastle","Sandell","Sanders Ranch","Sandford","Sandhills","Sandifer","Sandiway","Sandmere","Sandpiper Cove","Sandraya Heights","Sandrock Hill","Sandstone","Sandwood","Sandy Cove","Sandy Knoll","Sandy Ridge","Sandyford","Sanfoin","Sangmeister","Saning","Sano","Sanspareil","Santa Clara","Santa Juanita","Santa Paula","Santa Teresa","Santapogue","Santiago","Santorini","Sanwood","Sapphire Ridge","Saraglen","Sarah Landing","Saranap","Saratoga Sunnyvale","Sard","Sargent Roode","Sarnesfield","Sarsby","Sartwell","Satanita","Satinwood","Satuckett","Sauerbacker","Sauls","Saunder","Sausal","Savana","Saverien","Savin","Savoury","Sawell","Sawmill Brook","Sawyer Hill","Saxon","Saxons","Saybrooke Oaks","Sayles","Sayward","Scaggsville","Scallows","Scandrett","Scarab","Scardenia","Scarlet","Scarr End","Scaup","Scenic Meadow","Scenicwood","Schadeck","Schalk","Scharer","Scheffelin","Schellville","Schenk","Scherwood Greens","Schiele","Schimmel","Schlapp","Schlictman","Schmeidt","Schnecks","Schoen","Schoger","Scholer","Schomer","School House","Schoolhouse Cove","Schoonmaker","Schraalenburgh","Schriber","Schrum","Schuler Ranch","Schum","Schussler","Schwartz","Scibilia","Sciots","Scoles","Scorpion","Scotch Dam","Scotdale","Scotland Mill","Scotswood","Scott Robin","Scottish Rite","Scotts Hall","Scottsbury","Scoulding","Scouts Camp","Scrapsgate","Scriber Lake","Scriven","Scroxton","Scudder","Scutley","Sea Chase","Sea Horse","Sea Point","Sea Vista","Seaborne","Seabury","Seacrest","Seaforth","Seagrove","Seahorse","Seale","Seaman","Seamons","Searbrook","Searing","Sears Point","Seaside","Seaton","Seavy","Seawood","Sebastiani","Sebring","Secluded Oaks","Secor","Secretan","Sedding","Sedgecombe","Sedgemoor","Sedgley Park","Sedrup","Seedley","Seel","Seemore","Sefton","Segrove","Seidman","Seitz","Selborne","Seldon","Selger","Selkirk","Sells","Selmon","Selsfield","Selvyn","Semaphore","Seminole","Sempill","Senator","Seneca","Senecal","Senn","Senrab","Sephton","Sequdia","Sequoia Ridge","Seramonte","Serenite","Serge","Sergison","Serpentine","Serres","Sesame","Seth","Sette","Settlers","Seurat","Seven Crest","Seven Pines","Sevenoake","Severance","Severn River","Severus","Sevor","Sewardstone","Sexburga","Seymer","Sgt Beers","Shacklands","Shad Creek","Shaded Leaf","Shadle","Shadow Falls","Shadow Oak","Shadow Wood","Shadowridge","Shady Arbor","Shady Glenn","Shady Nook","Shady Ridge","Shady Willow","Shadyside","Shaffer","Shaftsbury","Shainy","Shakleton","Shale Peak","Shalimar","Shallow Creek","Shamley","Shand","Shane Park","Shanklyn","Shannon Oak","Shardcroft","Sharilyn","Sharman","Sharon Chapel","Sharp Park","Sharpley","Sharrington","Sharvel","Shattuck Park","Shaves Wood","Shaw William","Shawe","Shawmont","Shawno","Shea","Shean","Shears","Sheckells","Sheen Common","Sheepcoates","Sheepfold","Sheepridge","Sheering Mill","Sheets Farm","Shelard","Shelby Dale","Sheldon Oaks","Shelgate","Shell Valley","Shelley","Shellow","Shelter Bay","Shelterwood","Shenleybury","Shenwood","Shephard","Shepherds Gate","Shepperton Court","Sheraton","Sherbrooke","Sheredes","Sheridans","Sherland","Sherman Island Levee","Sherrardspark","Sherringham","Sherwin","Sherwood Park","Shetlands","Shewtan","Shienfield","Shillingford","Shin","Shinglebarn","Ship Rock","Shiplett","Shippen","Shipsview","Shira","Shiregreen","Shirlawn","Shirley Murphy","Shirra","Shoalhaven","Shoe Factory","Shogmoor","Shone","Shootersway","Shoppers World","Shore Acres","Shore View","Shorefront","Shoreland","Shoreview","Shorland","Short Cut","Shortcross","Shortland","Shortway","Shotkoski","Shottfield","Shovelstrode","Shremor","Shrive","Shrubbs","Shuck","Shumard Oak","Shurland","Shutt","Siandra","Sibley Hills","Sicily","Sicora","Siddon","Sidehill","Sidley","Siebel","Siegmond","Sienna Park","Sierra Gold","Sierra Oaks","Sierra Sunset","Siesta","Sigel","Signal","Signs","Silacci","Silbury","Silent Dell","Silerton","Silk Oak","Silktree","Silo","Silva","Silveira","Silver Brook","Silver Dollar","Silver King","Silver Linden","Silver Peak","Silver Royd","Silver Spur","Silverbend","Silverdell","Silverland","Silverod","Silverthorne","Silvervine","Silvio","Simbroco","Simla","Simmonstone","Simon Lake","Simone Weil","Simpkin","Simpson Ranch","Sinawoy","Sindle","Singers Glen","Single Foot","Singlewell","Sinvalco","Sir Alexander","Sir George Martin","Sir Richard Fairey","Siracusa","Sirius Cove","Sisk","Sister Cities","Sivert","Six Towers","Skaggs Springs","Skate","Skegsbury","Skellow","Skerry","Skiba","Skiers","Skimped Hill","Skipjack","Skokie","Sky","Sky Hill","Sky Top","Skyfarm","Skylane","Skyline Quarry","Skyswood","Skyward","Slack Fold","Slade Oak","Slagle","Slapton","Slatin","Slayton","Sleepers Farm","Sleepy Lake","Slessor","Slim","Slipe","Sloanes Beach","Sloop","Sloughgreen","Sly Fox","Small Grove","Smalldean","Smallshill","Smarts Mill","Smee","Smidmore","Smith Field","Smith Valley","Smithies Moor","Smithville","Smithy Fold","Smokehouse","Smokey Ridge","Smull","Snailswell","Snapper","Snead","Sneider","Snelson","Snoden","Snoqualmie","Snow Egret","Snowball","Snowden Pond","Snowdrift","Snowhill Estates","Snowy Owl","Snyder","Soares","Sobraon","Soda Canyon","Sofala","Softwood","Sojourn","Solar","Solbys","Soledad","Soliano","Sollers Point","Soloman","Solono","Soma","Somerford","Somerset","Somme","Somoa","Sondes Place","Sonn","Sonoma","Sonter","Sophie","Soquel Creek","Sorel","Sorich","Sorrelwood","Soscol","Sotherington","Souberie","Soult","Soundside","Souter","South Acre","South Alaska","South Americus","South Ash","South Avalon","South Bar","South Bayard","South Bedford","South Betty","South Bolingbrook","South Boylston","South Bridgepointe","South Brush","South Byron","South Carboy","South Carriage Way","South Chappell","South Circle","South Clovercrest","South Columbus","South Cooper","South Court","South Crestwood","South Dakota","South Dedham","South Dike","South Douglas","South E","South Edgewood","South Eliseo","South Emerald Oak","South Estelle","South Fairmont","South Fernandez","South Fitch Mountain","South Fox","South Fresno","South Furness","South Genesee","South Glendale","South Grant","South Grimmer","South Ham","South Harlan","South Hartson","South Helena","South Hill","South Holgate","South Horton","South Humboldt","South I Oka","South J","South Juneau","South Kennbeck","South Keppler","South Knollwood","South Lake Sarah","South Langston","South Leigh","South Liberty","South Lockhart","South Lorna","South Macarthur","South Mallard","South Marina","South Mason","South Mc Kinley","South Mead","South Michael","South Mills","South Monroe","South Morrison","South Naperville","South Netherton","South Norman","South Oleander","South Ordnance","South Palisades","South Park Victoria","South Pearl","South Peter","South Platti","South Ponderosa","South Prentice","South Queen","South Rancho","South Reeve","South Richard","South Riverside","South Rolling","South Royd","South Sacramento","South San Mateo","South Sequoia","South Shelton","South Side Three","South Southern","South Sprucewood","South Susan","South Tea Garden","South Three Oaks","South Tracy","South University","South Van Horn","South Vincennes","South Walker","South Warren","South Weller","South Whippoorwill","South Wildwood","South Willow","South Wing Levee","South Woodsbro","South del Puerto","Southam","Southboro","Southcenter","Southcreek","Southeast Allen","Southeast Carr","Southeast Culver","Southeast Fairwood","Southeast John","Southeast May Valley","Southeast Perimeter","Southeast Sedgwick","Southeast Willock","Southerland","Southern Hills","Southern Perimeter","Southfield","Southglen","Southlake","Southleigh","Southold","Southshore","Southwater Point","Southwest Andover","Southwest Bruce","Southwest Channon","Southwest Cove Point","Southwest Donovan","Southwest Englewood","Southwest Francis","Southwest Hanford","Southwest Holden","Southwest Juneau","Southwest Luana Beach","Southwest Morgan","Southwest Oregon","Southwest Seattle","Southwest Sullivan","Southwest Waite","Southwick","Southwoods","Soward","Spa","Spagnoli","Spanby","Spanish Flat Loop","Spanker","Sparhawk","Sparks","Sparrow House","Spartan","Sparvell","Spaulding","Spearing","Speckled Wood","Speedwell","Speke","Spen","Spencer Place Leopold","Spenney","Sperling","Sphinx","Spicer","Spiker","Spinale","Spinks","Spinney","Spirea","Spitfire","Split Creek","Spock Ridge","Spoil","Sponson","Spoonger","Sportsman","Spotted Gum","Sprain Brook","Spray","Spreighton","Spring Bottom","Spring Creek","Spring Garden","Spring Hill","Spring Lakes","Spring Mountain","Spring Saw","Spring Villa","Springbluff","Springdale Estates","Springfield Village","Springhollow","Springle","Springmont","Springsguth","Springvale","Springwood Hall","Sproul","Spruce Mill","Sprucewood","Spur","Spurgrove","Spurt","Spyglass Cove","Squam Hill","Squash Creek","Squire","Squires Mill","Squirrels Heath","St Alban","St Andrew Bethune","St Anselms","St Austall","St Benedict","St Brannocks","St Celcilia","St Clement","St Cuthberts","St Dunston","St Ethelbert","St Giles High","St Helier  Furness","St James","St Joseph S","St Keverne","St Lo","St Margarets","St Mary S","St Michael","St Nazaire","St Patrick","St Peter","St Regis","St Swithins","St Vincents","St. Agnes","St. Barnabas","St. Christopher","St. Dunstans","St. Helens","St. Katherine","St. Martins","St. Nazaire","St. Philip","St.Albans","St.Olaves","Stablebridge","Stacey Hills","Stackfield","Stadium","Stafford","Stagbury","Stagehand","Stags Run","Stainbeck","Stainforth","Stair Foot","Stake","Staley","Stall","Stalyhill","Stamford Park","Stanage","Stanbrook","Stancross","Standford","Standpipe","Stanford Oak","Stanhope","Stanislaus","Stanley Hall","Stannard","Stansfield","Stanton Crossing","Stanwick","Stapelton","Staples Ranch","Star Farm","Star Point","Starbrook","Starcross","Starflower","Starlight","Starmead","Starr View","Starters","Starwood","State Park","Statesman","Station House","Statute","Staverton","Stayton","Steamboat","Stearton","Stedman","Steel Creek","Steele Resort","Steep Hill","Steeplechase","Steere","Steffan","Stehlin","Steinberg","Steity","Stellman","Stemmler","Stenhouse","Stephan Marc","Stephendale","Stepney High","Sterling","Sterling Oaks","Sternhall","Stetson Shrine","Stevedore","Stevens Canyon","Stever","Stewards Green","Steynton","Stickland","Stierlin","Still","Still Water","Stillings","Stillspring","Stiltner","Stingray","Stirgess","Stites Hill","Stock","Stockburn","Stockett","Stockholm","Stockley","Stockton Tees","Stodart","Stoecker","Stoke Poges","Stokesheath","Stompond","Stone Bridge","Stone Cliff","Stone Hall","Stone House","Stone Oak","Stone Quarry","Stone Trail","Stonebriar","Stonechat","Stonecress","Stonedge","Stonehall","Stonehedge","Stonehurst","Stoneleigh Park","Stoneness","Stones","Stonesteads","Stonewall Farm","Stoney","Stoney Creek","Stoney Run","Stoneycrest","Stoneyhurst","Stonny Batter","Stony Creek","Stony Ridge","Stonyridge","Storch","Stores","Storm","Storrington","Story Acres","Stott","Stovell","Stowell","Stradella","Straight","Strand Approach","Strangman","Strata","Strathalbyn","Strathdon","Strathmere","Stratos","Stratwood","Straw","Strawberry Park","Strayfield","Stream Wood","Streatham HillTelford","Street","Streethouse","Strenger","Stride","Stringer Dam","Strobridge","Stroman","Stronghurst","Stroude","Struan","Struttmann","Stuart Kaplan","Stub Toe","Stubbs Moor","Stuckler","Student Center","Studley Grange","Stulz","Sturdy","Sturl","Sturt","Styal","Styles","Substation","Sucher","Sudbrooke","Sudley","Sueno","Sufonet","Sugar Creek","Sugar Pit","Sugarland","Sugarplum","Suisun","Sulgrave","Sullivan","Sulphur Spa","Sumbray","Summer Duck","Summer House","Summer Oaks","Summer Sky","Summerbell","Summerday","Summerhouse","Summerlin","Summersby","Summerswood","Summerwood","Summit Hall","Summit Ridge","Sumner","Sumter","Sun Court","Sun Mor","Sun River","Sunbank","Sunbreeze","Suncast","Suncrest Hill","Sunderleigh","Sundown","Sunfish","Sunhills","Sunlea","Sunmeadow","Sunnings","Sunny Brow","Sunny Hills","Sunny Side","Sunnycrest","Sunnygate","Sunnymede","Sunnyslopes","Sunpark","Sunrise Hill","Sunrise Ridge","Sunseason","Sunset Maple","Sunshadow","Sunsprite","Suntone","Sunwich","Superior","Surada","Surface","Surplus","Surrey Circle","Surrey Water","Surtess","Susan Rosemary","Susie","Sussman","Sutherland","Sutter","Sutterton","Sutton Court","Sutton Wick","Suview","Suzzane","Swaim","Swainson Hawk","Swalecliff","Swallowdale","Swamp Circle","Swan Lake","Swandale","Swanley","Swanscoe","Swanston","Swarbrick","Swasedale","Swaynesland","Sweeney","Sweet Briar","Sweet Meadow","Sweetbirch","Sweetings","Sweetmeadow","Sweetwood","Swettenham","Swift Run","Swinborne","Swinebourne","Swingle","Swinton","Swithemby","Sword","Swyncombe","Sycamore Canyon","Sychem","Sydenstricker","Syke","Sylmar","Sylvan Heights","Sylvania","Sylvestris Fire","Symmes","Symphony","Syosset Woodbury","Syvestrus Fire","Taber Hill","Tabley Hill","Tabrett","Tack","Tacoma","Tadin","Tadworth","Tafts","Tahan","Tahoe Circle","Tailor","Taintor","Takeley","Talamore","Talbots","Taleeban","Taliaferro","Tall Cedars","Tall Shadows","Tallack","Tallawong","Tallgums","Tallow Tree","Talma","Talulah","Tamal Vista","Tamarack","Tamarix","Tamboura","Tamer","Tammanny","Tampa","Tan House","Tanbark","Tanderagee","Taney","Tangier","Tanglewylde","Tanhouse","Tankerville","Tannehill","Tannery","Tanridge","Tantani","Tanyard","Taos","Taplan","Tappan","Tappingo","Tara","Taradale","Tarban","Tarboro","Tareyton","Targo","Tarkin Hill","Tarleton Corner","Tarnapoll","Tarpey","Tarragon","Tarrogana","Tartan","Tartar","Tascan","Tassi","Tate Naylor","Tatra","Tattershall","Taubert","Tavan Estates","Tavestock","Tawny","Tay","Tayloe","Taylor View","Tayman","Tchapitoulas","Teaberry","Teakwood","Teaneck","Tebworth","Technology Center","Tecumseh","Teddington Gloucester","Tee","Teemer","Teeway","Teibrook","Tejas","Telegram","Telese","Telles","Telston","Temi","Temperley","Temple Hill","Templecombe","Tempo","Ten Gate","Tenaya","Tender","Tenham","Tennessee","Tenniswood","Tentelow","Tepper","Tercentennial","Terfidia","Terman","Terne","Terra Bella","Terra Loma","Terra Villa","Terrace Lake","Terramere","Terrazzo","Terreno de Flores","Terrigal","Terryellen","Tesla","Testa","Tether","Tetterton","Tewinga","Textile","Thackery","Thame","Thamesmeade","Thatch","Thaxted","The American","The Dalles","The Great","The Grove  Danson","The Ladder","The Luton","The Mount  Grove","The Old Oaks","The Plough  Kenton","The Silk","The Villages","Thea","Theed","Thelwall","Theobalds","Theodore Green","Thermal","Thestland","Thicketford","Thiers","Thin Edge","Thirlstone","Thirza","Thistledale","Thixton","Thomas","Thomas Center","Thomas Edison","Thomas J Thorsen","Thomas Moore","Thomas Powell","Thomasin","Thompkins","Thomson","Thora","Thoria","Thorn Bush","Thornall","Thornbeck","Thornburgh","Thorncroft","Thorndown","Thornewood","Thorngate","Thornhurst","Thornmeadow","Thornton Blue Island","Thornycroft","Thorparch","Thorpedene","Thorverton","Thread Needle","Three Cherry Trees","Three Elms","Three Hurdles","Three Penny","Threepence","Threshfield","Throgmorton","Throughbred","Thrushwing","Thunder Mountain","Thurbans","Thurlby","Thurlston","Thurnby","Thurso","Thurwood","Tiana","Tibbits","Tiberon","Tices","Tickenhall","Tico","Tidewater","Tidswell","Tiemann","Tiernans","Tierra Lago","Tiffin","Tiger","Tilberg","Tilden Gill","Tile Line","Tileyard","Till Rock","Tillgate","Tillman","Tilney","Tilsworth","Timarand","Timber Branch","Timber Hill","Timber Oak","Timber Trails","Timbercrest Farm","Timberhill","Timberline","Timbers Pointe","Timble","Timi","Timmus","Timperley","Tin Can Ranch","Tindal Spring","Tindon End","Tingue","Tinley","Tinsley","Tintle","Tionesta","Tipper","Tipps Cross","Tire Swing","Tischer","Tithe","Titmus","Tivoli","Tobacco Leaf","Tobiano","Tobys","Tod","Toddy","Toftshaw New","Toiyabe","Tokeneke Ridge","Tolar","Tolenas","Toll Bar","Tollers","Tollund","Tolsford","Tolworth","Tom Jenkinson","Toma","Tomasetti","Tomcroft","Tomlin","Tommy Middleton","Tomrick","Tonacliff","Toner","Tonge Old","Tonia","Tonkaha","Tonne","Tonsor","Tonya","Tooley","Toorak","Top","Top of the Hill","Topcastle","Topinera","Topp","Topsail","Torah","Torcross","Torlai","Torney","Torque","Torrence","Torrey Pines","Torridon","Tortola","Tory Fort","Tot","Totnes","Totteridge","Touissant","Touquet","Tourney","Tovar","Towaway","Tower Court","Tower Oak","Towerfield","Towfield","Town Acres","Town Dump","Town Hall","Town Mead","Town Wells","Towne Crest","Townhall","Townscliffe","Townside","Towpath","Toy","Toysome","Tracey","Tractor","Trade","Tradewind","Trafalger","Tragan","Trail Haven","Traill","Trailwood","Tralee","Trancas","Trans World","Transport","Tranton","Trapper","Tratman","Traveller","Travilah","Trayer","Treadgold","Treatro","Trebellan","Treby","Tredwell","Tree Lawn","Treebrooke","Treehouse","Treetop Hill","Trefton","Tregony","Trelawn","Tremari","Tremlett","Trenchold","Treno","Trentino","Tres Pinos","Tresham","Tressider","Trevale","Trevenar","Trevino","Trevorton","Trewyn","Triadelphia","Tribunal","Tricross","Trigger","Trilliam Run","Trimbleford","Trina","Trinity Gate","Trip","Tripleseven","Trish","Triton Beach","Trocha","Trombas","Tropaz","Trost","Trotters Glen","Trotts","Trousdale","Trout Wine","Trouville","Trowville","Troy Meadow","Truck House","True","Truett","Truman Manor","Trumpeter Swan","Trunfio","Truss Hill","Tryall","Tschiffely Sq","Tuart Park","Tucabia","Tuckerman Heights","Tuddington","Tuenge","Tuft","Tuggle","Tulane","Tulich","Tulipwood","Tulley","Tullymore","Tumble Weed","Tumelty","Tuncurry","Tunitas","Tunnel Entrance","Tunstead","Tupolo","Turbine","Turf Hill","Turfmeadow","Turing","Turkey Foot","Turks Cap Lily","Turn Left","Turnbridge","Turner","Turners Green","Turnfield","Turnley","Turnsworth","Turpin","Turret","Turstin","Turtle Valley","Turvey","Tuscany","Tuskeegee","Tuthill","Twain","Tweedmouth","Twelve Oaks Center","Twentythird","Twigworth","Twin Bridges","Twin Creeks","Twin Harbor","Twin Lawns","Twin Park","Twin Rivers","Twinbrook","Twinlake","Twiss Green","Twitten","Two Farm","Two Rivers","Twycross","Twynersh","Tyas","Tydcombe","Tyee","Tyland","Tyler Island","Tylneys","Tynebourne","Tyngsboro","Tyram","Tyrol","Tysen","Tythe","UMBC","Udell","Ufton","Uhuru","Uline","Ulloa","Ulonga","Ulva","Umbdenstock","Un Pr","Uncatena","Underdale","Undershaw","Union","Union Landing","Union Ter","United","University","Unqua","Uop North Service","Upcroft","Uphill","Uplands Park","Upper Abbey","Upper Attunga","Upper Belgrave","Upper Briar","Upper Canyon Three","Upper Chorlton","Upper Conran","Upper Culver","Upper Dunstan","Upper Fairfield","Upper Fort","Upper Green","Upper Hale","Upper Hibernia","Upper Joclyn","Upper Lees","Upper Marlborough","Upper Monterey","Upper North Row","Upper Pindell","Upper Redlands","Upper Roman","Upper Sherborne","Upper Tachbrook","Upper Toyon","Upper Village","Upper Wilton","Uppermont","Upshaw","Upton Hills","Upwoods","Urban","Urby","Urmond","Ursiline","Usange","Uss North Carolina","Utility","Utz","Uxmore","Vaca Valley","Vadnais","Vailetti","Val Gardena","Valaire","Valdale","Valdora","Vale Top","Valence Wood","Valentina","Valeowen","Valery","Valhalla","Valla","Valle Ducale","Vallentin","Valley Bend","Valley Crest","Valley Green","Valley Lake","Valley Pines","Valley Tree","Valleybrook","Valleystream","Vallingbe","Valmor","Valor","Valroy","Van Allen","Van Blarcoms","Van Bussum","Van Cortlandt","Van Der Meulen","Van Duyne","Van Fleet","Van Horn","Van Kleeck","Van Mulen","Van Over","Van Riper","Van Schoick","Van Syckel","Van Vleck","Van White Memorial","Van de Mark","Vancouver","Vandelft","Vanderbergh","Vanderelinde","Vandervoot","Vandine","Vaneck","Vanguard","Vann Farm","Vannucci","Vansittart","Vantroba","Varcoe","Varennes","Varidel","Varney","Vashi","Vassall","Vauban","Vaugn","Vauxhall","Vecino","Veery","Velado","Velizy","Velock","Venables","Vendola","Venetion","Venndale","Ventana","Venturella","Vera Cruz","Verbena","Verdayne","Verdi","Verdosa","Verein","Veridan","Verla","Vermillion","Verna Mae","Vernel","Vernon Berry","Vernoy Hills","Verplank","Versailes","Vervais","Vespan","Vestals Gap","Veterans Foreign War","Vevers","Via Camino","Via Madero","Via Primavera","Via de Robles","Viaduct","Vicarage","Vicino","Vicki Lynn","Victor Hugo","Victoria Greens","Victorian Park","Victrola","Vidovich","Vierling","View Haven","Viewhill","Viewridge","Viking","Villa Carol","Villa Nueva","Villa Verde","Village Center","Village Gate","Village Lower","Village Shops","Villagefield","Villars","Villers","Vimy Ridge","Vincam","Vindara","Vine Hill","Vinegar Hill","Vinewood","Vineyards","Vinson","Vintage Oak","Vinyards","Violettes Lock","Viret","Virginia Denise","Virginia Pine","Virmar","Visitacion","Vista Brooke","Vista Hill","Vista Oak","Vista Verde","Vista del Rio","Vistula","Vivian Adams","Vlaardingen","Vogay","Vokoun","Volintine Farm","Vollbecht","Volt","Volver","Von Braun","Von Karman","Vonhoff","Vorlich","Voss Park","Vredenburgh","Vultee","W Abingdon","W Agatite","W Aldine","W Allison","W Amy","W Anne K","W Arch","W Armstrong","W Ash","W Autullo","W Balmoral","W Bartlett","W Bayberry","W Beechcroft","W Belleterre","W Benson","W Berwick","W Birchdale","W Blair","W Bogey","W Boundary","W Bradwell","W Breen","W Brittany","W Brookfield","W Bruns","W Burnett","W Butternut","W Cambridge","W Carl","W Carvel","W Cathy","W Central","W Channon","W Chase","W Chester","W Christopher","W Clark","W Coach","W Colorado","W Concord","W Cornelia","W Country Club","W Courtland","W Crescent","W Cuba","W Custis","W Daniels","W Deer Creek","W Dempster","W Diamond Lake","W Dominion","W Dove","W Durkee","W Eaton","W Edmund","W Elk Grove","W Elmwood","W Estes","W Everell","W Fairview","W Fenimore","W Firestone","W Flournoy","W Forest View","W Foster","W Franklin","W Frontenac","W Gabreski","W Garrett","W Geneva","W Gilbert","W Glencoe","W Goethe","W Goodrich","W Grand Lake","W Greenbrook","W Greystone","W Hackberry","W Haledon","W Hampton","W Harriet","W Hastings","W Haystack","W Hegel","W Henry","W Higgins","W Hills","W Hobart","W Holtz","W Howdy","W Hunt","W Hutchinson","W Indian Trail","W Iroquois","W Islip","W Jamaica","W Jeffery","W Joe Orr","W Joyce","W Karen","W Kelly Ann","W Kimber","W Kinzie","W Kurt","W Lake","W Lakeland","W Lancaster","W Lasalle","W Lawson","W Lester","W Line","W Lockwood","W Lookout","W Loves","W Lydia","W Magoun","W Manitoba","W Marine","W Martin","W Mathews","W Maypole","W McGowanwoods","W Meadowland","W Menna","W Messner","W Midland","W Millpage","W Minnehaha","W Montana","W Monument","W Mound","W Mulloy","W N Frontage","W Neck","W New York","W Nolcrest","W North Pond","W Norwood","W Oakhill","W Official","W Old Hideway","W Olive","W Ordnance","W Ox","W Palmer","W Parker","W Patapsco","W Peddie","W Pensacola","W Phillips","W Pinehurst","W Pleasant","W Pond","W Prairie","W Prindiville","W Quail","W Ramapo","W Rawson Bridge","W Regan","W Rich","W River Hills","W Roberts","W Rockland","W Rosalie","W Rosemont","W Running Brook","W Saddle River","W Sandpiper","W Schick","W Schweitzer","W Seamans Neck","W Shady","W Sheridan","W Shoreline","W Sioux","W Soffel","W Spring Lake","W Summerdale","W Superior","W Sycamore","W Tantallon","W Thacker","W Three Oaks","W Tow Path","W Tulip","W Union","W Van Buren","W Viking","W Walker","W Ward","W Watson","W Weed","W West End","W Wheatley","W Whiting","W Williams","W Wing","W Wirth","W Woodcrest","W Woodstock","W Wyoming","W de Saible","WIllow Ridge","Wabana","Wabler","Wackett","Waddell","Waddon Park","Wadeson","Wadleigh","Wagamon","Waggaman","Wagnon","Wagstaff","Wahly","Waiku","Waingels","Wairoa","Waitkus","Wake Island","Wakeham","Wakely","Wakestone","Walavista","Walbury","Waldemar","Walden Square","Walderton","Waldor","Waldrohe","Wales","Walhonding","Walkdens","Walker Mill","Walkerton","Walkley","Wall Pt.","Wallacks","Wallaroy","Wallen","Walleye","Walling","Wallmark Lake","Wallum Beach","Walman Buckley","Walmort","Walnut Branch","Walnut Meadows","Walnutgrove","Walraven","Walsham","Walter Adamic","Walter Morse","Walters Green","Walthery","Walton Heath","Waltuma","Wambool","Wampum","Wanbourne","Wandell","Wanders","Waner","Wangi","Wannas","Wansey","Wantagh Park","Wapiti","Wapping Dock","Warabin","Warbick","Warburton","Ward Park","Wardlebrook","Wards Chapel","Ware Park","Warehouse Creek","Warenne","Wargrave","Warinanco Park","Warley Hall","Warman","Warmscombe","Warneford","Warnham Court","Warrabina","Warrangi","Warrego","Warren House","Warreners","Warrick","Warringa","Warrung","Waruda","Warwicks Bench","Wasdale","Washall","Washington Park","Washitay","Waskow","Wassell","Watch Hill","Watchmoor","Watcombe","Water Grant","Water Pointe","Waterbank","Watercroft","Wateredge","Waterfoot","Waterham","Waterline","Watermeetings","Waters Edge","Watersedge","Watersong","Watervale","Waterwood","Wathen","Watkinson","Watrous","Watsonville","Wattle Creek","Watts Mine","Waucantuck","Waukeena","Wausau","Waveland","Waverley Oaks","Wawecus","Waxwing","Waycott","Wayfarers","Waylen","Wayne Gibson","Waynewood","Wayte","Wazir","Wealdstone","Wearden","Weasel","Weatherington","Weaver","Weavers Rock","Webbacowitt","Weberfield","Weddell","Wedge Pond","Wedhurst","Weed","Weemala","Weequahic","Weetman","Wegman","Weible","Weigel","Weimar","Weir Hall","Weirs","Weiss","Welbury","Welcome","Weldale","Welesley","Welhouse","Well Penn","Wellbeck","Wellerburn","Wellfleet","Welling Way  Sherwood","Wellington Hill","Wellmeadow","Wellsmere","Wellyhole","Welsley","Welwyn By Pass","Wembrough","Wendall","Wendling","Wengate","Wenman","Wenonah","Wentland","Wentzell","Werbe","Wernbrook","Werrington","Wescoat","Wesglen","Wesley White","Wessagusett","Wesson","West Agua Caliente","West Algonquin","West Ann","West Arthington","West Avondale","West Bare Hill","West Beach","West Bellevue","West Bird","West Bolton","West Boundary","West Briggsmore","West Brooke","West Burns Valley","West California","West Capitol","West Carroll","West Center","West Cherry","West Cintura","West Comfort","West Cotati","West Cremona","West Curtis","West Dane","West Dempster","West Dike","West Dundee","West Eaton","West Elkhorn","West End Farm","West Eugenie","West Fargo","West Field","West Forest Lake","West Franklin","West G","West Gertrude","West Go Wanda","West Grant Line","West Grover","West Hammer","West Harley","West Hawley","West Hearn","West Highland","West Hillsdale","West Homestead","West Howell","West Ike Crow","West Iris","West Jahant","West Jenness","West Julian","West Kenner","West Keystone","West Knickerbocker","West Lake Kayak","West Las Palmas","West Le Moyne","West Liberty","West Livingston","West Lomond","West Lost Lake","West Lynda","West Mall","West Mariposa","West Maude","West Meadow","West Michigan","West Miner","West Monroe","West Moreland","West Myrtle","West Nettle Tree","West Ninth","West Oak Knoll","West Ohio","West Ott","West Park","West Patterson Pass","West Peregrine","West Platti","West Portola","West Quincy","West Raye","West Rianda","West River Grove","West Rockport","West Roy","West Rutherford","West San Carlos","West Santa Fe","West Schulte","West Seventh","West Shorewood","West Sixth","West Sorrento","West Sst","West Sutton","West Temple","West Thames","West Tokay","West Trinity","West Upsala","West View","West Walnut","West Weddell","West Whitmore","West Wimbolton","West Woodbridge","West Yoke","West el Dorado","Westage","Westbard","Westboro","Westbreeze","Westcar","Westcombe Park","Wested","Westerdale","Westerleigh","Western Mine","Westerton","Westfields","Westhall","Westholme","Westings","Westlea","Westmaher","Westminster","Westmore","Weston Bay","Westospect","Westport","Westshire","Westvalley","Westwich","Westwoods","Wetherby","Wethington","Wewers","Wey Gates","Weybrook","Weyham","Weymouth","Weywood","Whalebone","Whalewood","Whann","Wharton","Whays","Wheathelds","Wheaton","Wheedon","Wheeler Peak","Wheelwright Park","Whempstead","Whetstone Hill","Whielden","Whimberry","Whingate Tong","Whip Hill","Whippletree","Whirley","Whiskin","Whispering","Whispering Leaves","Whispering Tree","Whisperwood","Whistley Mill","Whitaker Bluff","Whitchurch","White Bank","White Bread","White Chimneys","White Creek","White Ferry","White Ground","White Hill","White Island","White Lion","White Oaks","White Ridge","White Spots","White Willow","Whitebick","Whitechapel High","Whited","Whitefur","Whitehave","Whiteholme","Whiteland","Whiteley","Whitenack","Whiteriver","Whites Pond","Whitesmead","Whitethorne","Whitewillow","Whithouse","Whitkirk","Whitley Park","Whitmer","Whitnell","Whits End","Whittaker","Whitting","Whittney","Whittredge","Wholfords","Whowell","Whyteladyes","Wibird","Wichitaw","Wickell","Wickersham","Wicklands","Wickstead","Widdenham","Widegate","Widgiewa","Widnes","Wiehle","Wiese","Wiggenhall","Wiggs","Wigram","Wihtred","Wilbanks","Wilbrandt","Wilby","Wilcoxson","Wild Canyon","Wild Forest","Wild Hunt","Wild Olive","Wilda","Wildcrest","Wilderfield","Wilderton","Wildflower","Wildhurst","Wildmere","Wildridings","Wildwood","Wiley","Wilgus","Wilk","Wilkes Barre","Wilksch","Will Scarlett","Willandale","Willard Point","Willcrest","Willens","Willester","Willever","William B Terry","William Cunningham","William Fairfield","William Joseph","William Moss","William Tell","Williams Wood","Williamson Ranch","Willie","Willingdon","Williton","Willo Mar","Willoughby Newton","Willow Bridge","Willow Dale","Willow Grove","Willow Oaks","Willow Run","Willow Valley","Willoway","Willowcroft","Willowhayne","Willowmere","Willows Edge","Willrush","Willvail","Wilmarth","Wilmington Court","Wilms","Wilrose","Wilshire","Wilson Hall","Wilsondale","Wilton","Wilward","Wimbledon Park","Wimlands","Win Haven","Winborough","Winchat","Winchfield","Wincombe","Wind Crest","Wind Song","Windbridge","Windell","Windeyer","Windhaven","Winding Brook","Winding Lakes","Winding Way","Windleaf","Windmill Cove","Windong Wood","Windrock","Windshire","Windsor Court","Windsor Palms","Windstar","Windwhisper","Windy Harbour","Windy Point","Wine","Wineham","Winfell","Winfrith","Winged Cove","Wingham","Wings","Winkelman","Winkley","Winmoor","Winnecomac","Winnepurkit","Winnetuxett","Winnisimmet","Winoka","Winscombe","Winside","Winslowe","Winstead Manor","Winstree","Winter Garden","Winter Sun","Winterbourne","Wintergreen","Winterscroft","Winterstoke","Winthorpe","Wintun","Wirega","Wirraway","Wirtz","Wisdom","Wishanger","Wishon","Wissahican","Wistar","Witby","Witham","Witherington","Withey Heights","Withorn","Wits End","Witter","Witty","Wixon","Wodehouse","Wohseepee","Wolcott","Wolf","Wolf Rock","Wolfe Hill","Wolfs","Wolkow","Wollombi","Wolpers","Wolton","Wolves","Womerah","Wong","Wontford","Wood Brook","Wood Glade","Wood House","Wood Mar","Wood Sage","Wood View","Woodbanke","Woodbourne","Woodbrooke","Woodby","Woodcliff Lake","Woodcote Park","Woodcutters","Wooded Hills","Woodelf","Woodend","Woodfield Park","Woodgate Hill","Woodhail","Woodhaven","Woodholm","Woodinville","Woodland Crossing","Woodland Park","Woodlane","Woodlea","Woodley Woods","Woodlyn","Woodmeadow","Woodmore","Woodpecker","Woodredon Farm","Woodrock","Woodruff","Woods End","Woods of Arden","Woodsend Crescent","Woodside Tavern Low","Woodstock","Woodthorpe","Woodvill","Woodwaye","Woodwren","Wool Creek","Wooldridge","Woolfield","Woollard","Woolmead","Woolpack","Woolsy","Woomera","Woosley","Worbeck","Worden","Workman","Worleys","Wormstead","Woropieff","Worrobil","Worsley Bridge","Wortham","Wortley","Wote","Wraight","Wray Common","Wrekin","Wrendale","Wrentmore","Wright Brothers","Wrightwood","Wroughton","Wulff","Wuthering Heights","Wyandemere","Wyatt Park","Wybersley","Wychewood","Wyckoff","Wycombe Park","Wyena","Wykehurst","Wyldwood","Wylmar","Wymondley","Wynchgate","Wyndehurst","Wyndhurst","Wyndwood","Wynkoop","Wynns","Wynsum","Wyoming","Wyresdale","Wythenshawe","Wyvil","Xanthippe","Xenia","Y Creek","Yachthaven","Yagoona","Yalkin","Yamato","Yancy","Yankee Division","Yantacaw Brook","Yaralla","Yardley Hall","Yarmouth","Yarra Burra","Yarran","Yarrow","Yateley","Yawpo","Yealand","Yeasted","Yeldall Manor Service","Yellow Birch","Yellow Lodge","Yellowcross","Yelsted","Yeomalt","Yerba Buena","Yerrick","Yetminster","Yewbarrow","Ygnacio Valley","Yoakes","Yoho","Yolano Mills","York Gardens  Lombard","Yorknolls","Yorktown Mall","Yosemite Park","Youlden","Youngfield","Youngs Valley","Yountville Cross","Yribarren","Yukon","Yunga","Yurunga","ZEnith","Zaca","Zadig","Zambesi","Zampatti","Zanker","Zaragosa","Zato","Zayante Lakes","Zebra","Zeim","Zeliff","Zemek","Zenner","Zeppelin","Zetland","Ziggy","Zimpher","Zinran","Zisch","Zoffany","Zorah","Zouch","Zumbro","Zweber"];
\ No newline at end of file
+pub static STREET: [&str; 11456] = ["A. Alfred Lombardi","Aaron Park","Abberton","Abbey Glen","Abbey Orchard","Abbeyview","Abbots","Abbotsford Cove","Abbott Valley View","Abbywood","Abeel","Abend","Abercorn","Aberfoil","Abernathy","Abilene","Abirdge","Abney Court","Abraham Kazan","Absalom","Academic","Acampo","Accokeek","Accord Park","Acerra","Achillies","Ackers","Ackmar","Aconbury","Acorn Wharf","Acregate","Actinotus","Actriz","Adahmore","Adam Albright","Adams Park","Adanac","Adclare","Adderstone","Addison","Adel","Adelia","Aden","Adhara","Adj.","Admin Service","Admiralty","Adolphus","Adria","Adshall","Advent","Aegean","Aero","Af","Afternoon","Agate","Agdon","Agius","Agnon","Agricultural Farm","Ah","Ahneita","Aidan","Ailsworth","Ainsbury","Ainsty","Air Force","Airdsley","Airmen","Airport Plaza","Aislibie","Ak","Akerman","Akira","Al Ventura","Alaga","Alameda Park","Alamo Ranch","Alan Crest","Alanhurst","Alastair","Albany","Albermale","Albert Einstein","Alberti","Albezzia","Albot","Albuera","Alcalde","Alcinda","Alconbury","Alda","Aldcroft","Alden Glen","Alder Creek","Aldercar","Alderford","Alderminster","Aldersgate","Alderstone","Alderwood Mall","Aldine","Aldred","Aldro","Aldwych","Alegre","Alene","Alessio","Alewife","Alexander Hamilton","Alexendria","Alfalfa","Alford","Alfreg","Algen","Algonkian","Alhambra Creek","Alicante","Alida","Alington","Alissa","Alkamont","Alkire","Alladin","Allandale","Allard","Allday","Allegany","Allemong","Allen Robert","Allensby","Allerton","Allevard","Allgrove","Alling","Allister","Allocco","Allred","Allward","Alma Bridge","Almadera","Almar","Almendral","Almon","Almonds","Almroth","Alnwood","Alorn","Alph","Alphonsus","Alpita","Alridge","Alschuler","Alston","Alta Glen","Alta Sierra","Altacrest","Altamont Pass","Altessa","Althorpe","Alto","Altoona","Altura","Alumrock","Alveley","Alverton","Alvina","Alward","Alwoodley The","Alysheba","Amado","Amalia","Amaranta","Amaro","Amax","Amber Creek","Amberg","Amberleaze","Amberwood","Ambley","Ambrooke","Ambush","Amenbury","America","American Holly","Amerigo","Ames Hill","Amherst Bank","Amiens","Amisse","Amli","Ammunition","Amory","Ampeg","Amsbury","Amundsen","Amyruth","Anaconda","Anamosa","Anatolia","Anchor","Ancon","Andalusian","Anders","Anderson Scout Camp","Andora","Andre","Andres","Andrews Farm","Andsbury","Anerley Park","Angel Falls","Angelica","Angelo","Angie","Anglers","Angley","Angouleme","Animbo","Anka","Ann Arbor","Anna","Annable","Annamarie","Annapolitan","Anne Arundel","Annella","Annete","Annico","Anning","Annmar","Annursnac Hill","Anon","Ansdell","Ansley","Anstone","Antell","Antholl","Antile","Antlands","Antonette","Antrican","Anza","Apara","Apfel","Aplomado","Appamatox","Apple","Apple Glen","Apple Ridge","Appleberry","Applecross","Applegreen","Appleton","Appollo","April Journey","Apthrop","Aptos Hill","Aqua Vista","Aqueduct","Arab","Araglen","Aralluen","Aranda","Arate","Arbella","Arbor","Arbor Hills","Arbor Vine","Arborfield Walden","Arborwood","Arbuckle","Arcadia","Arch","Archdale","Arches","Archway","Arcturus","Ardaugh","Arden Forest","Ardenmore","Ardfern","Ardilla Canyon","Ardley","Ardra","Ardwell","Arends","Argall","Argo","Arguimbau","Ariana","Aries","Arista","Arkansas","Arkland","Arlee","Arleta","Arlies","Arlmont","Arm","Armat","Armett","Armington","Armitree","Armley Ridge","Armour","Armstead","Arncliff","Arner","Arnheim","Arnold Park","Arnould","Aromas","Aroostook","Arran","Arrol","Arrowhead Farm","Arrowwood","Arroyo Sierra","Art Schultz","Artery","Arthur","Arthur Woods","Artillery","Artlett","Arun","Arundel Gateway","Arvale","Arye","Ascadilla","Ascol","Asford","Ash Park","Ashbel","Ashbrook Hey","Ashburton","Ashcott","Ashdown","Ashendene","Ashfield Farm","Ashington","Ashlawn","Ashley Manor","Ashlon","Ashmill","Ashness","Ashton Clough","Ashtree","Ashwells","Askam","Askill","Asmus","Aspen","Aspen Valley","Aspenwood","Aspinwall","Ass House","Assets","Assonet","Aste","Astin","Aston Clinton","Astra","Astron","Asylum","Atchison","Athel","Athena","Atherfold","Athlon","Atilda","Atlantic Hills","Atleigh","Atria","Atteentee","Attimore","Attow","Atwill","Aubreen","Auburn Lakes","Auciello","Audenshaw","Audmar","Audubon","Augusta Point","Auld","Auman","Aurelia Sylvia","Auriol Park","Austhorpe","Australis","Authors","Autocenter","Autrey","Autumn Fields","Autumn Meadow","Autumn Trail","Autumnwood","Avalli","Avard","Aveline","Avenel","Avenleigh","Averenches","Avery","Aviator","Aviston","Avona","Avonmore","Awaba","Awbrey Patent","Axdell","Axletree","Aycliffe","Aylands","Aylesworth","Aylsham","Aynor","Ayreshire","Ayrton Senna","AzTec","Azara","Azimuth","Azure","B V French","BP Davies","Babbe","Babe Ruth Park","Babetta","Babula","Bacchus","Bachelors","Back Albert","Back Arnold","Back Aviary","Back Baythorpe","Back Bowling Green","Back Brudenell","Back Castle","Back Chestnut","Back Colenso","Back Crawford","Back Darcy","Back Duncan","Back Eden","Back Fletcher","Back Gaythorne","Back Green","Back Halstead","Back High Bank","Back Hope","Back James","Back Knowl","Back Lindley","Back Lytton","Back Massie","Back Milner","Back Norris","Back Palm","Back Portugal","Back Rawson","Back Ryefield","Back Seymour","Back Sunnyside","Back Thorpe","Back Uttley","Back Wapping","Back Westfield","Back Woodgate","Backlick","Backwater","Bad Munstereifel","Baddow Hall","Baden Westwood","Badger Pass","Badian","Badsworth","Bafp","Baggins","Bags","Baguley","Baigents","Baileys","Bain Bridge","Bainter","Baiting Place","Bajor","Bakersmill","Balaam","Balantine","Balceta","Balcombe","Bald Pate","Baldock","Baldwin Lake","Baleri Ranch","Balgonie","Bali","Ball Hill","Ballantine","Ballard","Ballena Bay","Ballina","Balloch","Balls Head","Bally Bunion","Balm","Balmes","Balmy","Balsamo","Balstonia","Baltimore Washington","Bambara","Bambridge","Banana Grove","Bancker","Banderra","Bandy Run","Bangalay","Banister","Bank Hey Bottom","Banker","Banksfield","Banleigh","Banner Farm","Bannockburn","Bansom","Bantle Farm","Banyon","Bar Harbour","Baranga","Barbara","Barbel","Barbers Wood","Barbieri","Barcaly","Barclay","Barcroft","Bardet","Bardolier","Bardsley Gate","Bare Cove Park","Barell","Barforth","Bargmann","Barina","Bark","Barker","Barking","Barkway","Barletta","Barleycastle","Barling","Barlowe","Barn Hill","Barnabe","Barnard","Barndance","Barnes Cray","Barnesville","Barnet High","Barneveld","Barnheisel","Barnsboro","Barnstable","Barnwell","Baron Cameron","Baronnel","Baroque","Barra","Barras","Barremma","Barretto","Barrick","Barrington Point","Barron Park","Barrow Hill","Barrs Fold","Barrypoint","Barson","Bartel","Bartha","Barthorpe","Bartletts","Barton Creek","Bartram","Barway","Basalt Rock Company","Basewood","Basildon","Basing","Basket Ring","Basmore","Bass Rock","Bassett Creek","Bassler","Bastille","Basuto","Batchelor","Bateman","Batesole","Batham Gate","Bathurst","Batridge","Batten Hollow","Battery","Battery Safford","Battle Creek","Battlefield","Battock","Baugher","Baumbach","Bautista","Bawn","Bay Cliff","Bay Farms","Bay Head","Bay Park","Bay To Bay","Bay Water","Baybriar","Baydon","Bayham","Baylee","Bayliss","Baynton","Baypoint","Bayside","Baytomac Farms","Bayville","Baywood Shores","Bea Kay","Beach Park","Beachamwell","Beachler","Beachview Creek","Beacon Bay","Beacon Pond","Beaconsfield Terrace","Beadlow","Beal","Bealle Hill","Beaminster","Bean Field","Bear","Bear Glen","Bear Ridge","Beardall","Beardsley","Bearing","Bearwood","Beatrix","Beau Brummel","Beauchamps","Beaufoy","Beaumont","Beauteau","Beaven","Beaver Hill","Beaver Run","Beavers","Becado","Bechtold","Beckerle","Beckfield","Beckler","Beckwith","Becontree","Beddoo","Bedford","Bedloes","Bedwell","Bee Oak","Beech Forest","Beech Hyde","Beechbrook","Beechenlea","Beechhill","Beechmore","Beechway","Beedell","Beehrle","Beeleigh","Beemra","Beeth","Beggars Hill","Beharrell","Behrendt","Beira","Bel Aire","Bel Pre","Belar","Belcher Farm","Beldam","Belding","Belfast","Belgaro","Belgrave","Belinda","Bell Air","Bell Executive","Bell Meadow","Bell Vue","Bella Tuscany","Bellaire Hills","Bellantoni","Bellbird","Belle Ami","Belle Grove","Belle Point","Belle of Georgia","Bellefield","Bellemaine","Belleterre","Bellevue","Bellfields","Bellina","Bellman","Bello","Bellows","Bells","Bellthorne","Bellvista","Belmohr","Belmont Landing","Belnel","Belrose","Belt","Beltrami","Belvale","Belvoir Woods","Bement","Bemuth","Ben Ledi","Ben Oaks","Benaud","Bench","Bencroft","Bendemere","Bendmore","Benedictine","Benetfield","Benforest","Benham","Benicia","Benito","Benledi","Bennel","Bennett Hill","Bennetts Grove","Bennion","Benris","Bensley","Bent Bough","Bent Oak","Bentay","Bentink","Bento","Bentridge","Bentwaters","Benwell","Bepler","Berberis Walk  Laurel","Berdina","Berengrave","Berg","Bergenline","Bergin","Berith","Berkhampstead","Berkshire","Bermar","Bernacci","Bernard Ashley","Berne","Bernie","Bernstein","Berrian","Berriman","Berry Cove","Berrycroft","Berryleaf","Bersano","Berteau","Berthoud","Bertling","Berton","Berwick","Beryl","Besler","Bessels Green","Bessmer","Bestobell","Betabel","Beth Lee","Bethel Island","Bethnall","Betlo","Betsy Brown","Bettescombe","Bettowynd","Betty Grove","Beumont","Bevanwood","Beverly Hills","Beverstone","Bevis","Bexhill","Beza","Biara","Bibeau","Bicester","Bickerton","Bicknoller","Biddle","Bieber","Bienville","Bifrost","Big Branch","Big Foot","Big Peninsula","Big Rock Ridge","Big Tree","Biggers","Bighorn","Bigney","Bikini","Bilgola","Billa","Billarong","Billie Smith Memorial","Billington","Billow","Billy Diehl","Bilpin","Bimburra","Bindea","Bingen","Bingle","Binn","Binnie","Binton","Birbetts","Birch Green","Birch Pond","Birchard","Birchdale","Birches","Birchill","Birchmore","Birchwood","Bird In Hand","Birdcroft","Birdsall","Birdwood","Birken","Birkhead","Birkwood","Birney","Birriwa","Birtlespool","Bisbee","Bishoff","Bishopgate","Bishops Hall","Bishopton","Bisner","Bisshop","Bittacy","Bitternut","Bivona","Bizzibe","Black Alder","Black Briar","Black Duck","Black Hawk","Black Kite","Black Oak","Black Pond","Black Tree","Blackbank","Blackbird Hill","Blackbridge","Blackcap","Blacket","Blackford","Blackhawk Meadow","Blackington","Blacklock","Blackness","Blackpowder","Blackshaw","Blackstock","Blackthorne","Blackwater","Blackwood","Bladindon","Blain","Blairderry","Blais Farm","Blakehall","Blakemore End","Blakeslee","Blamey","Blanche Dell","Blanding","Blanken","Blarney","Blauss","Blazer","Bleasby","Bledlow Ridge","Blendall","Blenkinsop","Blewbury","Blinco","Bliss","Block House","Blom","Blood","Bloomburg","Bloomington Frwy","Blossom Cove","Blossom River","Blossomwood","Bloxhall","Blue Anchor","Blue Bird","Blue Crane","Blue Goose","Blue Hill River","Blue Lake","Blue Mill","Blue Post","Blue Rock Hill","Blue Spring","Blue Waters Farm","Bluebill","Bluefield","Bluegum","Bluestem","Bluewillow","Bluff Pointe","Blume","Blunts","Bly","Blythorn","Boar","Boardschool","Boarshead","Boatclub","Bob Reed","Bobbi","Bobby Spencer","Bobolink","Bocana","Bodalla","Bodega Bay","Bodkin","Bodrick","Boehmer","Boeske","Bogarin","Bogey","Bogny","Bohemia","Bohn","Bois","Bokelman","Bolberry","Boldman","Boleyns","Boling","Bollate","Bollinger","Bolney Chapel","Bolshaw Farm","Bolter","Boltro","Bombadier","Bomish","Bon Terre","Bonaire","Bonavesta","Bond Hollow","Bonds Retreat","Bonetti","Bonheur","Bonita","Bonneau","Bonnet","Bonnie","Bonnie Dale","Bonnie View","Bonny","Bonnys","Bonseys","Bonus Hill","Boodle","Bookhurst","Boomerang","Boones Hill","Booralie","Boot","Booth Tarkington","Boothfield","Bootjack","Boraga","Bordars","Border Hill","Bordona","Boren","Borgins","Borina","Borley","Boronia","Borregas","Borrowdale","Borthwick","Boscastle","Boscow","Bosk","Bossa","Bost","Boston Manor","Bostwick","Botanical","Bothelo","Botolph","Bottle Brush","Bottom Pond","Bou","Bougainville","Boula","Boulder Canyon","Boulder Run","Boult","Boundary","Boundstone","Bourbon","Bourne","Bournehall","Bournville","Boutemain","Bouve","Boveney New","Bow Arrow","Bowbell","Bowditch","Bower Heath","Bowerman","Bowes Creek","Bowie","Bowlen","Bowling","Bowmans Folly","Bowral","Bowstone Hill","Box Elder","Boxberry","Boxgrove","Boxted Church","Boyard","Boyden","Boyland","Boyne","Boysea","Bozen Green","Brabyn","Bracher","Brackenhurst","Brackleys","Bracton","Bradburys","Braddock Springs","Bradey","Bradgate","Bradish Farm","Bradley Forest","Bradman","Bradshad","Bradview","Brae","Braeleigh","Braeside","Bragbury","Brahma","Brain Ridge","Brairwood","Braken","Braman","Bramblebush","Brambletye Park","Bramfield","Bramingham","Brammay","Bramston","Brancaster","Branchaw","Brand","Branderburgh","Brandle","Brandon Green","Brands Hatch","Brandy Hill","Branfield","Branko","Brannan Island","Bransdale","Branstone","Brantwood","Brasero","Brassie","Brattice","Braunecker","Braverton","Braxted Park","Braycourt","Brayshaw","Braziers","Breadlands","Breakneck","Bream","Breaults Landing","Brecknock","Bredon","Breedon","Breeze Knoll","Breezy Hill","Brehaut","Breitwert","Bremerton","Brencon","Brenford","Brenner","Brent View","Brentlands","Brentsville","Breslau","Bretland","Brettenham","Breuner","Brevoort","Brewers Hill","Brewster","Brian","Briant","Briar Hill","Briar Rock","Briarchip","Briarford","Briarmont","Briarwood South","Bricin","Brickenden","Brickhouse","Brickwall","Bridalsmith","Bridewain","Bridge Farm","Bridgedale","Bridgeland","Bridgeport","Bridgeton","Bridgford","Bridle Cross","Bridlefield","Bridport","Brier","Brierhill","Brig","Brigate","Bright","Bright Sun","Brightling","Brightside","Brigid Flanigan","Brimblecomb","Brimrod","Brindabella","Brinef","Brinkerhoff","Brinley","Brinsop Hall","Briony","Briscoe Turn","Brisson","Bristol Downs","Bristolwood","Britannic","Briton","Brittany Parc","Brittney","Brixham","Broad Branch","Broad Green","Broadacres","Broadcarr","Broadgate","Broadhollow","Broadley","Broadmeadows Sheridan","Broadoaks","Broadwalk","Broadway Feather Bank","Brocade","Brock Hill","Brockham","Brockley","Brockswood","Brodia","Broe","Broken Arrow","Broken Twig","Bromehead","Bromley Cross","Bromshill","Brondsbury","Bronte","Bronxville Glen","Brook Bottom","Brook Haven","Brook Mar","Brook Village","Brookcroft","Brooke Knolls","Brookfall","Brookgreen","Brookhurst","Brooklee","Brookman","Brookmont","Brooks Terrace","Brookshade","Brookside Glen","Brooksweld","Brookway","Broom Farm","Broomers","Broomhill Park","Broomshaw","Brophy","Brotherton","Broughville","Brouwerij","Browertown","Brown Gables","Brown Ranch","Brownfield","Brownley","Browns Farm","Brownshade","Brownville","Broxholm","Brubeck","Brucewood","Bruell","Brumley","Brunell","Bruning","Brunswick","Bruntwood","Brush Prairie","Brussels","Bryan Point","Bryants Nursery","Brydon","Brynden","Brynton","Bubb","Buchal Heights","Buck Cavey","Buckbee","Bucketmill","Buckhill","Buckingham","Buckle","Buckleys","Buckmeadow","Buckout","Bucksfield","Bucktail","Buckwoods","Budds","Budgerigar","Budoch","Buell","Bueno","Buffalo Creek","Buffins","Bugbee","Bugong","Builders","Bulb","Bulfinch","Bulkeley","Bull Pine","Bullbrook","Bullers","Bullfrog Fire","Bullneck","Bulls Eye","Bullwood","Bulrush Farm","Bulwara","Bumfords","Bunarba","Buncefield","Bundanoon","Bundeson","Bungal","Bungendore","Bunker","Bunn","Bunsen","Bunts","Buono","Burbanks","Burch Hill","Burchfield","Burdeck","Burdett","Burdocks","Burford","Burgener","Burghclere","Burgos","Burich","Burke Commons","Burkeside","Burkitts","Burlescoombe","Burleys","Burlington","Burmah","Burnbrae","Burned Chimney","Burnet Hill School","Burngarten","Burnie","Burning Tree","Burns Crossing","Burnside Landing","Burnt Ember","Burnt Pollard","Burntlodge","Burpee","Burr Ridge Club","Burrage","Burrawong","Burricks Hill","Burritt","Burrows","Burshire","Burston","Burton Glen","Burtonwood","Burwood Park","Buryfield","Busch","Bush Elms","Bushby","Bushfield","Bushmead","Bushy","Buskirk","Bussey","Butano","Butchers","Butlers","Butt Field","Butter Cup","Butterfield Frontage","Buttermere","Butterside","Buttner","Buttonwood","Buttway","Buxton Old","By","Bycullah","Byerley","Bying","Byne","Byram Dock","Byrne Park","Byrons","Bywater","C Fernwood","Caballeros","Cabbel","Cabin","Cabot","Cabrillo","Cache Peak","Caddell","Cader","Cadle","Cadogan","Cadwallader","Caenshill","Cafeto","Cages Wood","Cahir","Cainfield","Cairnwell","Caithness","Calabar","Caladium","Calamo","Calaveras","Calcaterra","Caldarra","Calderbrook","Caldran","Caledonia","Caleta","Calfas","Calibria","Calif Pacific","Calista","Callabone","Callander","Callen","Callington","Callow","Calmor","Calpella","Calthrope","Calumet Access","Calverley","Calvery","Calyer","Camarena","Cambell","Camberwell","Cambon","Cambrian","Cambridgepark","Camden High","Camel Hollow","Camenson","Cameron Grove","Camilla","Camino Hermoso","Camino del Sol","Camlot","Cammerer","Camp Bluefields","Camp Grove","Camp Pearson","Campagnoli","Campbell","Campden","Campfield","Cample","Campora","Campton Crossings","Campus Green","Canacum","Canal","Canard","Canbury Park","Canden","Candle Ridge","Candlestick","Candy Apple","Canessa","Canham","Cann Hall","Cannici","Cannon Ball","Cannon Industrial","Cannondown","Canoe River","Canon Vista","Canoona","Cantalier","Canter","Cantiague","Canton","Canuden","Canyon Creek","Canyon Lake","Canyon Six","Canyon Woods","Capatin Hunter","Cape Buffalo","Cape Kennedy","Capehart","Capen Hill","Capewood","Capital Hill","Capitol Hill","Caples","Cappell","Capriconus","Capstone","Captain Duval","Captain Joshua","Captain Torrey","Captiva","Cara","Caran","Carawatha","Carbon","Carbury","Carden","Cardigan","Cardinal Estate","Cardoso","Cardwell","Careo","Carey School","Cargill","Cariage","Carill","Carindale","Carisa","Carl Sandburg","Carle","Carley","Carline","Carlisle on Duxbury","Carlos","Carlsbrook","Carlton","Carly Creek","Carman","Carmela","Carmelwood","Carmichael","Carmona","Carnation","Carneros","Carnoustie","Carol Anne","Carolian","Caroline Farms","Carolynn","Carousel","Carpenters","Carpino","Carr Brook","Carr Manor","Carramarr","Carreau","Carrgate","Carriage Hill","Carriage Way","Carrie Litchfield","Carrillo","Carrisbrook","Carroll","Carrona","Carrs Wharf","Carry Back","Carshalton Park","Cart Path","Carter House","Carthage","Cartisian","Cartridge","Carvel Beach","Carvers","Cary Geights","Cas","Casa Loma","Casa de Vida","Casavan","Casco","Caselman","Casher","Casillas","Caslocke","Caspian","Casseday","Casserly","Cassilis","Cassiopia","Castaldi","Castelli","Casterson","Castillon","Castle Cove","Castle Glen","Castle Knoll","Castle Pine","Castlebar","Castledon","Castlemain","Castleraegh","Castleshaw","Caston","Casurina","Cataldi","Catamount","Catchpenny","Caterpillar","Cathall","Cathead","Catherine Glen","Cathlow","Catlins","Caton Ridge","Catskill","Catteshall","Catts Tavern","Caulfield","Causton","Cavalletti","Cave Gulch","Cavern","Cavitt","Cawdor","Cayden","Cayser","Cazenove","Cebra","Cecil Newman","Cedar Bridge","Cedar Dell","Cedar Glen","Cedar Hollow","Cedar Oaks","Cedar River Park","Cedar Tree","Cedarbrook","Cedarforest","Cedarmeadow","Cedarvillage","Cedrus","Celebrar","Celia","Cellar","Cemetary","Centech","Center Bay","Center Flats","Center for the Arts","Centerwood","Central Square","Centre Park","Centrum","Century Ridge","Cerchio","Cereza","Cerne","Cerreta","Cerruti","Cessford","Cevu","Chabot","Chadacre","Chadds Ford","Chadwell","Chaffer","Chagford","Chaingate","Chalcombe","Chalet Clothilde","Chalice","Chalkenden","Chalkwell Park","Challin","Chalomar","Chamber","Chambers Green","Chaminade","Champagne","Champney","Chancell","Chandler","Changebridge","Channel Islands","Chansory","Chantilley","Chantry","Chapel","Chapel Gate","Chapel Springs","Chapeltown Carlisle","Chapman Mill","Chappell of Bond","Charandy","Chardon","Chargeable","Charing School","Charkers","Charlecote","Charles Anna","Charles Dean","Charles Halle","Charles Lake","Charles Sevright","Charlesford","Charlesworth","Charline","Charlottesburg","Charlwoods","Charmeran","Charnock","Charred Oak","Chart House","Charteris","Chartreux","Charville","Chase Hill","Chasemill","Chasselas","Chateau Bluff","Chatfield","Chathlake","Chattanooga","Chattswood","Chauntry","Chaves","Chaytor","Cheapside","Checker Berry","Cheda Knolls","Cheekwood","Cheesequake","Cheever","Chelbourne","Chelmar","Chelsea  Beaufort","Chelshire","Chelwood","Chemolite","Chenies","Chequers","Cherie","Cherokee","Cherry Brook","Cherry Hill","Cherry Orchard","Cherry Valley","Cherryfield","Cherryton","Chertsey Bridge","Cheryl","Chesapeake Bay","Cheseapeake","Chesham Fold","Chesline","Chessholme","Chester Brook","Chesterlee","Chestnut Cove","Chestnut Leaf","Cheston","Chetwyn","Cheverly","Cheviot","Chew Valley","Cheyne Park","Chicatabut","Chick Evans","Chicken Shack Fire","Chicoine","Chidley Cross","Chieveley","Chilaw","Child","Childs","Chiles Pope Valley","Chillington","Chilmead","Chiltern Park","Chimalus","Chimney Pot","China Wall","Chinewood","Chinn","Chinthurst","Chiplou","Chipper","Chippingstone","Chircan","Chisledon","Chiswell Green","Chitty","Chobham Park","Choke","Cholmley","Chorley Hall","Chovan","Chris Mar","Chrismar","Christel","Christian Fields","Christie Hill","Christmas","Christoper","Christs Hospital","Chrysanthemum","Chubbs Brook","Chudleigh","Chumleigh","Church Elm","Church Hill Kirkfield","Churchfields","Churchills","Churchville","Churston","Chuzzlewit","Cibrian","Cicerone","Cidermill","Cima","Cincinnatus","Cindy","Cinnamon Creek","Cippenham","Circle Court","Circle Ridge","Cirolero","Cissbury","Citation","Citruswood","City Island","Civic","Clacket","Clafton","Claim","Clairvale","Clamshell","Clanville","Clapham Park","Clara","Clarden","Claremont Park","Clarenden","Clarevale","Clarice","Clarita","Clarke","Clarkin","Clarksburg","Clarkwood","Clason Point","Claudare","Claughton","Clavadal","Claverhambury","Claxfield","Clay Hammond","Clayborn","Claycord","Clayholes","Claypitt","Clayton Hall","Cleall","Clear Shot","Clearfield","Clearwater","Cleave","Cleeve","Clelland","Clemente","Clements Hall","Clenches Farm","Cleo Rand","Clerihew","Cleveland Park","Clewborough","Cleworth Hall","Cliff Edge","Cliff View","Cliffhouse","Cliffside","Clifton Court","Clifton Point","Climbhill","Clink","Clintwood","Clipston","Clive","Cloberry","Clohesey","Clonmel","Close","Clothworkers","Cloudview","Clouston","Clover","Clover Patch","Clovergrass","Clovernook","Clow International","Club Circle","Club View","Clubway","Clunbury","Clyburn","Clydesdale","Clyston","Coachella","Coachmans","Coal Pit","Coalport","Coast Oak","Coastwise","Coats Hutton","Cobb Hill","Cobbitee","Cobble Field","Cobblerock","Cobbold","Cobham","Cobra","Coccio","Cochran Mill","Cockbush","Cockett","Cockrell","Cockshott","Cocos","Coddington","Codjer","Codrington","Coey","Coffin","Coggins","Cohancy","Cohn","Coit","Cola","Colbera","Colby Hewitt","Cold Arbor","Cold Spring","Coldalhurst","Colder","Coldnailhurst","Cole","Coleby","Colegrave","Coleman Glen","Colemansmoor","Coleridege","Coles Orchard","Colesville","Coley","Colgrove","Colin Blythe","Colindeep","Collamore","Colleen Garden","College Loop","College View","Colless","Colley Manor","Colliers Row","Collingbourne","Collington","Collinwood","Collyhurst","Colnbrook","Colo","Colona","Colonel Hunt","Colonia","Colonial Oaks","Colonna","Colony Hill","Colorado","Colridge","Coltash","Colts Neck","Columbia Creek","Columbus","Colwell","Colywn","Combe","Combs","Comet","Comly","Commerce Center","Commissioners","Common Wood","Commonside Wood","Community Hall","Compass Point","Compton Parc","Comstock Mill","Concanon","Conch","Concordia","Conder","Condron","Conerty","Coney Warren","Conference Center","Conghurst","Congress Springs","Conifer","Conington","Conkling","Conlogue","Connawarra","Connell","Connemara","Conningham","Conomo Point","Conrads","Conselyea","Consolo","Constitution","Conte","Conti Square","Contractors","Conver","Conyerd","Coogee Bay","Cookhill","Cookson","Coolangatta","Coolgardie","Coolidge Hill","Coolwood","Coombehurst","Coomes","Coonamble","Coonley","Cooper School","Coopers Hill","Coorilla","Copas","Copen Meadow","Copford","Coppage","Copper Beech","Copper Penny","Copperdale","Coppermine","Copperwynd","Coppleridge","Copsewood","Coptfold","Copyhold","Coraki","Coral Sea","Corall Hollow","Corang","Corbets","Corby","Cordale","Corden","Cordilleras","Cordoza","Coree","Corey","Coriander","Corinthian","Corkran","Corlear","Cormar","Corn Point","Cornehlsen","Cornells","Cornfield","Cornine","Cornstalk","Cornworthy","Coronation Tree Main","Corporate","Corporate Yard","Corralitos View","Correnden","Corrielle","Corron","Corseley","Corso","Corte Vista","Corthell","Cortright","Corvin","Coryell","Cosgrave","Coso","Costa Rica","Coster","Cotall","Cotentin","Cotluss","Cotswold","Cottage Garden","Cottam","Cotters","Cottington","Cotton Tail","Cottontail","Couching","Coughlan","Coulsdon  Ridgemount","Councillor","Countess","Country Club","Country Fair","Country Hollows","Country Meadow","Country Run","Country Woods","Countryvale","County Court House","County Quarry","Courland","Court Downs","Courtauld","Courthouse Oaks","Courtleigh","Courtoak","Courville","Coval","Cove Ridge","Coventry","Coverhill","Covey","Cow Hill","Cowbridge","Cowdroy","Cowens","Cowl","Cowlishaw","Cowper","Cowsted","Coxs","Coyle","Coyote Ranch","Cozy Glen","Crabapple","Cracow","Cradley","Craftsman","Cragun","Craigerne","Craiglawn","Craigwell","Crammaville","Cranberry","Cranbrooke","Crandor","Cranes","Cranford","Cranlington","Cranshaw","Cranwells","Craston","Craven","Crawshaw","Crayford","Crealock","Creaville","Crediton","Creek Bed","Creek Park","Creek Water","Creekpaum","Creekside Oaks","Creely","Cregier","Cremen","Crenna","Crescent Cove","Cresci","Cresleigh","Cressbrook","Cresskill","Crest Hill","Crest View Hill","Crested","Crestlan","Creston","Crestway","Creukhorne","Cribari","Cricket Hill","Cricketwood","Crighton","Crimmins","Crimsworth","Crippen","Cris","Crispen","Crist","Cristopher","Critten","Croasdaile","Crocker Grove","Crockford","Croff","Croftdown","Crofton Park","Croham Park","Crom","Cromer Hyde","Crompton","Cronks Hill","Crooked Crow","Crooked Tree","Crookston","Cropp","Crosfell","Cross Bay","Cross Burley Lodge","Cross Elford","Cross Hartley","Cross Maude","Cross Park","Cross Roundhay","Cross York","Crosscreek","Crossgates","Crosslet","Crossover","Crossroads","Crossview","Crossways Lake","Croton","Crouchley","Crow Haven","Crow Wood","Crowe","Crowhill","Crown Colony","Crown Oaks","Crown View","Crownfield","Crownstone","Crowsheath","Croxall","Croyden","Croyton","Crudge","Cruiser","Crunson","Cruz","Crypt","Crystal Grove","Crystal Point","Crystal View","Cuba","Cucamonga","Cuckoos","Cudham","Cuff","Culcheth Hall","Culin","Cullinane","Cullom","Culotta","Culver","Culworth","Cumberland","Cumbrae","Cummings Point","Cumston","Cuneo","Cunnington","Cupola","Curie","Curling","Curragh Downs","Currawang","Currie","Curry Ford","Curtice","Curtisden Green","Curvers","Cusick","Customs House","Cutcombe","Cutlass","Cutmore","Cutter Ridge","Cuttings","Cuyahoga","Cygnet","Cypress Bay","Cypress Hill","Cypress Ranch","Cyprus Cedar","Cyrus Field","D C Training School","D Leeds Infirmary","Dabbert","Dace","Dadant","Dagenham","Dagmar","Dahill","Dahlonega","Daimler","Daiquiri","Dairyherd","Daisy Farms","Daisyley","Daking","Dalamar","Dalcross","Dale View","Dalehead","Dalesford","Dalgety","Dalke","Dalles","Dallon","Dalmation","Dalphin","Daltons","Dalziel","Dame","Damian","Damon Park","Damsel","Dan Mason","Danberry","Dancer","Dancy","Dane","Danecourt","Danel","Danescroft","Daneville","Dangar","Daniel Adamson","Daniel Shays","Daniels","Danlee","Danns","Dans","Danthonia","Danville","Daphne","Dara James","Darcy","Dareen","Dares Beach","Darghan","Darien Lakes","Dark Forest","Darlands","Darlings","Darmuid Green","Darnet","Darras","Darrigo","Darsow","Darting Bird","Darton","Darwell","Dashia","Dassell","Dateleaf","Daugherty","Dauphin","Davane","Davenhill","Daves","David Brainerd","Davida","Davidsonville","Davis","Davisson","Davos","Dawes","Dawn Harbor","Dawnlee","Dawson Beach","Day Hill","Daycroft","Daylop","Dayton","De Anza","De Bord","De Carli","De Fillipo","De Guigne","De Koven","De Laval","De Mandeville","De Montfort","De Ponti","De Sellum","De Vere","De la Costa","DeCosta","Deacon Haynes","Dead End","Deadwood","Deale Beach","Dean Bank","Dean Moor","Deancross","Deangelo","Deans Hill","Deanville","Dearing","Debaun","Debellevue","Debnams","Deborah Sampson","Debston","Decelle","Decker","Decoverly","Dedswell","Deems","Deep Glen","Deep Spring","Deepcar","Deeping","Deer Bay","Deer Cross","Deer Hill","Deer Pack Fire","Deer Ridge","Deerbrook","Deerfield","Deering","Deerpark","Deerton","Deeside","Defoe","Degema","Dehaven","Deichmann","Dekay","Del Campo","Del Franco","Del Miller","Del Otero","Del Rio","Del Vista","Delafield Island","Delamer","Delando","Delat","Delaware","Delcombe","Deleo","Delford","Delia","Delivery","Dellabrooke","Deller","Dellos","Dellwood","Delmonico","Delnor Glen","Delorme","Delphia","Delside","Delta Ranch","Delve","Demaine","Demars","Demerrit","Deming","Demorest","Den Helder","Denali Ridge","Denbrook","Deneholm","Denham Court","Denicola","Denisen","Denlyn","Denmont","Dennetts","Dennis F. Ryan","Denny","Denshaw","Denton","Denwood","Deodor","Depew","Depraitre","Dequincey","Derby Glen","Derfuss","Dermont","Dernier","Derrick","Derry","Derwentwater","Des Peres","Descanso","Deseret","Desert Willow","Desiree","Desnoyer","Desrosiers","Detjen","Detwiller","Developers","Devereaux","Deviar","Devils Reach","Devitt","Devon","Devonshire","Devries","Dewberry","Dewey","Dewitt","Dewsbury","Deyncour","Di Giulio","Diablo Grande","Diagonal","Diameter","Diamond K","Diamond Pointe","Diana Maria","Dianthus","Dib","Diblee","Dick","Dickerman","Dickins","Dicksons Mill","Dieckman","Dieman","Diessner","Digger Pine","Diggs Park","Dignon","Dike","Dilisio","Dille","Dillon Point","Dilston","Dimm","Dimple","Dinapoli","Dingle Bank","Dinkel Spiel","Dinosaur Point","Diogenes","Dipper","Dirksen","Disbrowe","Disepo","Dispensary","Distler","Ditchfield","Dittman","Ditty","Dividence","Divisional","Dixfield","Dixon Landing","Dnieper","Dobbies","Dobe","Dobree","Dock","Dockers Tanner","Dockser","Doctor David Cline","Doctors Park","Doddsfield","Dodhurst","Doe","Doesgate","Dog","Doggetts","Dogue Run","Dohertys","Dolan","Doleful Pond","Dollar","Dollis","Dolma","Dolphin Lake","Dome","Dominic","Dominion Ridge","Domitian","Don Juan","Dona","Donald Curtis","Donazetti","Donegal","Doneva","Donkey","Donmaur","Donnan","Donner","Donnybridge","Donovan","Doods","Dooligah","Doone","Dopping Brook","Doral Farms","Doray","Dordans","Dorena","Dori","Dorincourt","Dorlcote","Dormans Park","Dormity","Dorney Wood","Dorothy","Dorrells","Dorritt","Dorsey Run","Dorwood","Doscher","Dots","Doty","Doubleday","Doucette","Douglane","Douglynn","Dove","Dovecoate","Dover","Doves","Dowanhill","Dowell","Dowlands","Down","Downer","Downham","Downing","Downs Bridge","Downsfield","Downsview","Dowsett","Doyer","Dr Johnson","Draeger","Dragoon","Drake Beach","Drakes Beach","Dransfield","Drawbridge","Drea","Dreier","Dressage","Drewery","Drexelgate","Drifters","Driprock","Driveway","Dromore","Droughts","Droylsden","Druid","Drumard","Drummer","Dry","Dry Hill Park","Dryad","Dryhill","Drystraw","Dual Wide","Dubbo","Dublin","Dubonet","Duchaine","Duchy","Duck Mill","Ducket","Duckling","Duclos","Duddy","Due","Duffer","Duffys","Dugald","Duglas Fir","Dukane","Dukefield","Dukesthorpe","Dulcie","Dulles","Dulsie","Dumaine","Dumble","Dumke","Dun Horse","Dunbar Oaks","Dunbrin","Duncklee","Dundar","Duneba","Dunes Meadows","Dungells","Dunhams Corner","Dunisch","Dunkirk","Dunlay","Dunlin","Dunmaston","Dunnel","Dunniwood","Dunran","Dunrovin","Dunshee","Dunspring","Dunster","Duntroon","Dunwoodie","Dupont","Duquesne","Durants","Durbin","Durgess","Durie","Durleston Park","Durnford","Durrell","Durward","Dustan","Dusty Willow","Dutch Mill","Dutcher Creek","Dutruc","Duvall Parish","Duxburry","Dwars Kill","Dwinnell","Dyers Hall","Dymchurch","Dyneley","Dysart","E A Joseph","E Alden","E Alpine","E Appletree","E Artisan","E Aztec","E Baltusrol","E Barret","E Beam","E Bell","E Bend","E Bethpage","E Bissell","E Bode","E Brandis","E Brightway","E Brooklawn","E Bryn Mawr","E Business Center","E Cambridge","E Carl","E Cascade","E Centennial","E Charles","E Chinkapin Oak","E Clark","E Clinton","E College","E Commercial","E Cook","E Cottage","E Crabtree","E Crystal","E Custer","E Darryl","E Delos","E Dewey","E Donegal Bay","E Dunslow","E Edwards","E Elm","E Everett","E Falcon","E Fish Lake","E Foreman","E Francis","E Front","E Garden","E Gateway","E Gilfillan","E Goebel","E Goodrich","E Grant","E Greenfield","E Grissom","E Ham Lake","E Harbor","E Harrison","E Hawley","E Hendricks","E High","E Hintz","E Homestead","E Hudson","E Hyde Park","E Inman","E Isabella","E Jefferson","E Joffre","E Julius","E Kennedy","E Kinzie","E Lafayette","E Lake Rebecca","E Laurel","E Lemoyne","E Light","E Lombard","E Lower Pine Lake","E Lynnhurst","E Magnolia","E Manor","E Marquette","E Maude","E McClellan","E Medill","E Merrick","E Mill Valley","E Mineral Pond","E Monee","E Moore Lake","E Mount Ida","E Nap","E Nerge","E Nicholai","E North","E Oak","E Oakton","E Ohio","E Old Ridge","E Onwentsia","E Paddock","E Parkhill","E Payne","E Pennsylvania","E Phillips","E Pleasant Lake","E Pool","E Potomac","E Private","E Railway","E Reader","E Reichert","E Ridgewood","E Rockaway","E Rosemont","E Saddle River","E Sanders","E School","E Sedwick","E Shaw","E Sherwood","E Sioux Vista","E Soffel","E Spring","E Summit","E Susan","E Tall Oaks","E Terrace","E Thorndale","E Turtle","E Utica","E Vargo","E Villa","E Walnut","E Waverly","E West Shady Side","E William","E Windsor","E Wood","E Woodrow","E York","E. Chicago","Eads","Eager","Eagle Gap","Eagle Nest","Eagle Springs","Eagle Wharf","Eagles Mere","Eagleton","Ealand","Earl","Earle","Earlene","Earlsbrook","Earlston","Early Morning","Earnscliff","Easecrest","Eason","East Ad","East Alden","East Amhurst","East Ashland","East Bagwell","East Battery","East Beeches","East Berkley","East Blithedale","East Boxford","East Brook","East Busk","East Canyon","East Carroll","East Centry","East Chiles","East Colfax","East Como","East Court","East Cross","East Dartmouth","East Diamond","East Dunne","East Eastview","East Elbrook","East Euclid","East Fairfield","East Field Service","East Foreman","East Francis","East Fulton","East Gate","East Glencoe","East Grant Line","East H","East Handel","East Harvest","East Hedding","East Highland","East Hirsch","East Horner","East Hurd","East Ingram","East Jack Tone","East Jonathan","East Kendall","East Keystone","East Kirke","East Lake Kayak","East Las Palmas","East Lee","East Lincoln","East Lodge","East Loop","East Lousia","East Madill","East Manzanita","East Mascalls","East Mc Mullin","East Mendocino","East Middlefield","East Milton","East Montara","East Mount","East Nash","East Nilsson","East O","East Old Greenville","East Orwood","East Parkdale","East Pennsylvania","East Plain","East Portola","East Putnam","East Rancho Arroyo","East Reitze","East Riding","East Robertson","East Rosemary","East Saint James","East San Salvador","East Scenic","East Seegers","East Shea","East Shorewood","East Smith","East Southland","East Sunnyslope","East Tabor","East Temple","East Thomas Grade","East Titus","East Travis","East University","East Vernon","East Vista","East Watmaugh","East Whitehouse","East Wilmette","East Woodfield","East Wyoming","East la Chiquita","Eastbrooke","Eastcliffe","Eastdene","Easterly","Eastern Point","Eastham","Eastlake","Eastline","Eastmount","Eastridge","Eastview Farm","Eastwood","Easy","Eaton Park","Ebano","Ebbetts","Eben","Eberlin","Ebley","Ebury","Ecclesburn","Echo Barn","Echo Knolls","Echo Summit","Ecker","Eckles","Ector","Edbrooke","Eddiscombe","Ede","Eden","Eden Oaks","Eden West","Edendale","Edensor","Eder Ct","Edgars","Edge Fold","Edgebrooke","Edgecourt","Edgegrove","Edgemeade","Edgerly","Edgewater Pond","Edgington","Edin Garth","Edington","Edithna","Edman","Edmonston","Edmund Hurst","Edric","Edstan","Edward Bennett","Edward Hart","Edwards Bay","Edwin H. Land","Eel","Effingham Common","Egan","Egel","Eggar Woods","Egham","Eglinton","Egypt","Ehret","Eider","Eighteenth","Eileen","Eimer","Eisner","Ekins","El Bonita","El Camino Medio","El Cemonte","El Cortez","El Dori","El Greco","El Mercado","El Nido Ranch","El Patio","El Prado","El Rincon","El Sobrante","El Verand","Elan","Elberon","Elbridge","Elcock","Eldee","Elderberry","Eldermount","Eldor","Eldrige","Electioneer","Elegante","Elephant","Elf","Elfreda","Elgin Hosp Service","Elia","Elimatta","Eliot Hill","Elisha","Eliza Ann","Elizabeth River","Elk Hills","Elkan","Elkins","Elkstone","Elland","Ellen Webb","Ellenton","Ellerby","Ellers","Ellesemere","Ellicott Woods","Ellington","Ellis","Ellita","Ells","Ellyn","Elm Crest","Elm Park","Elman","Elmcrest","Elmer F Hagner","Elmfield","Elmire","Elmore","Elmsdale","Elmstone","Elmview","Elnoka","Elora","Elridge","Elsdale","Elsie","Elskip","Elspeth","Elswick","Eltham Palace","Elton Vale","Elveden","Elverta","Elvin","Elward","Ely","Elzer","Emaron","Embee","Embroidery","Emerad","Emerald Grove","Emerald Pool","Emerick","Emery","Emil","Emily Dickinson","Emlyn","Emmaus","Emmert","Emmitt","Emory","Empire Wharf","Emshee","Enborn","Encima","Encore","Enderby","Endlich","Endsleigh","Enes","Engel","Engineer","Englehutt","Engliff","English Oak","Englorie Park","Ennalls","Ennis","Enrica","Ensleigh","Entertainment","Entrevaux","Envill","Epaul","Epping Farms","Epsam","Equine","Erba","Erebus","Eric Green","Ericson","Eriks","Erith High","Erles","Ermine","Ernesti","Ernst Chain","Errico","Erskine Park","Erville","Escalero","Escatta","Escolta","Esdaile","Esher Place","Esler","Esparito","Esplande","Essella","Esser","Essex View","Esta","Estcots","Estella","Esterlee","Estherwood","Estonia","Estudillo","Etcheverry","Ethan","Etheldore","Ethier","Etloe","Eton on Oxford","Ettersberg","Eubank","Eucra","Eulabah","Eurabalong","Eurimbla","Euryalus","Euthella","Evangel","Evans Pond","Evanstone","Eveleth","Evemarie","Eveningside","Everd","Everett","Everglades Park","Evering","Everlyn","Eversley","Everts","Evey","Evry","Ewell Court","Ewing","Excalibur","Executive Park","Exfair","Exmore","Exploration","Extension","Eyelet","Eynford","Eyston","F Jellman","Fabens","Fabor","Fackenden","Fado","Fagnall","Faigle","Fair Greene","Fair Meadow","Fair Valley","Fairbluff","Fairbury","Faircrest","Fairfax Corner","Fairfield","Fairglen","Fairhaven","Fairhunt","Fairlands","Fairless","Fairmark","Fairmont Heights","Fairs","Fairview","Fairwater","Fairway Two","Fairwood","Faith","Falcon","Falconbridge","Fales","Falkland Park","Fallard","Fallfax","Falling Leaf","Fallow Fields","Falls Reach","Fallswood","Falmore","Falstone","Family Farm","Faner","Fanners","Fanshawe","Fanum","Far Rockaway","Farallon","Farber Hill","Farese","Farhill","Farington","Farlane","Farlie","Farm Creek","Farm Line","Farmbridge End","Farmers","Farmin","Farmside","Farnborn","Farnesdown","Farnol","Farr","Farrance","Farravet","Farrer","Farrol","Farthing Green","Farvue","Fassler","Father Hurley","Fattoria","Faulk","Faunce","Favart","Favre Ridge","Fawkham","Fawn Hill","Fawnbrake","Faxon","Faye","Fayrewood","Fearing","Fearon","Featherbed","Featherstone","Federal","Fedor","Feeder","Fehler","Felbrigge","Felder","Feldspar","Feliz","Fellner","Fellscrest","Felmingham","Felsview","Felthorpe","Femleaf","Fencepose","Fender","Fenley","Fennels Farm","Fenny","Fentem","Fenwick","Ferdinand Day","Ferland","Fermoy","Fern Hollow","Fernally","Fernbray","Ferndale","Fernedge","Ferngrove","Fernhurst","Fernmont","Fernthorpe","Fero","Ferraris","Ferrers","Ferron","Ferrybridge","Fertelli","Fesseneva","Fetherston","Fetzer","Ffinch","Fiddicroft","Fiddlesticks","Field Encampment","Field Office","Fieldbank","Fieldfair","Fieldmere","Fields First Federal","Fieldthorn","Fierro","Fifth Cross","Figueroa","Filarete","Filipe","Fillingfir","Filmorehill","Finch","Finchley","Findlays","Fingerboard","Finkbohner","Finlays","Finn S","Finnis","Finsbury Park  Rock","Finway","Fir","Firbank","Fire Barn","Firebird","Firehouse","Firethorn","Firlands","Firsby","First Parish","Firthcliffe","Fiscal","Fish House","Fisher","Fishermore","Fishmarket","Fiske Pond","Fitch View","Fitton Hill","Fitzgilbert","Fitzjohns","Fitzsimons","Five Acres","Five Hawks","Five Wounds","Flag City","Flagg Wood","Flagstone","Flame","Flamstead","Flandrau","Flash","Flatback","Flatts","Flavius","Flaxfield","Fleagle","Fleethall","Flemings Farm","Fletchall","Fletsand","Flewing","Flight","Flint Farm","Flintcrest","Flintmont","Flintwood","Float","Flood","Floradale","Florence Park","Florgate","Florido","Florio","Florsheim","Flow","Flowerfield","Flowerree","Floyd Hill","Flushcombe","Flying Fields","Foamcrest","Fog Bank","Foghorn","Foldi","Folini","Folkstone","Follows","Folsom","Fondant","Fontainbleau","Fontayne","Fontridge","Foot Hill","Foothill Glen","Foots Cray High","Forbes Glen","Force Green","Fordal","Fordhook","Fordwich","Foreland","Forest Arms","Forest Dell","Forest Green","Forest Knoll","Forest Mill","Forest Ridge","Forest Wood","Foresthill","Forestway","Forget Me Not","Forkland","Formosa","Forrest","Forrester Hill","Forshaw","Forsyth","Fort Beggs","Fort Funston","Fort Johnston","Fort Point","Fort Smallwood","Fortesque","Fortin","Fortril","Forty Acres","Fosbak","Fossdale","Fosswood","Fosters","Foulds","Founders Field","Fount","Fountain Oaks","Fountaingrove","Four Acres","Four Oaks","Fourness","Fourwents","Fowler Creek","Fox Bow","Fox Farm","Fox Grove","Fox Hollow Ridings","Fox Meadow","Fox Point","Fox Shores","Fox Vine","Foxberry Farms","Foxchase","Foxden","Foxfields","Foxhall","Foxhole","Foxlake","Foxmoor","Foxstones","Foxview","Foye","Fraga","Fram","Framley","Franceen","Francessca","Francis Kelley","Francisco","Frandsen","Frank Cox","Frank Turk","Frankfort Square","Franklands","Franklin Gibson","Franklin Park Service","Frankstowne","Franquette","Franton","Franzman","Fraters","Fray","Frean","Fred Allen","Fredana","Frederic","Frederickson","Fredonian","Free Green","Freedom Center","Freehollow","Freemantle","Freeston","Freewood","Freke","Fremontia","French Lake","Frenchmans Creek","Frensham Vale","Fresh Mill","Freshfields","Freston","Frewland","Friars Place","Fridays","Friedrich","Friends House","Friezland","Frimley Hall","Frisbie","Frith End","Fritzen","Frog","Frogmoor","Frohling","Fromondes","Frontana","Frost Creek","Frothingham","Fruit Barn","Frustuck","Frying Pan","Fuel Farm","Fuhrman","Fulbrook","Fulkerson","Fulle","Fullers Farm","Fulmead","Fulton Shipyard","Fumia","Furci","Furmanville","Furnedge","Furse","Fury","Furzeham","Fyall","Fylde","G Hall","Gabby","Gables","Gadbridge","Gaddy","Gadmore","Gae Wood","Gaga","Gahant","Gailview","Gainsford","Gaist","Galara","Galbreath","Galena","Galetown","Galitz","Gallant Fox","Gallek","Galley","Galli","Gallinelli","Gallogate","Gallows","Gallwey","Galtier","Galway","Gamble","Gambril","Game Lord","Gamlen","Gandangara","Gangurlin","Gannett","Gantner","Gaping","Garazi","Garbutt","Garda","Garden Cty","Garden Rock","Gardendell","Gardenview","Gardner Ranch","Garfield Park","Garie","Garlen","Garlisch","Garner","Garnica","Garrans","Garret Hill","Garriland","Garrow","Garside Hey","Garth Willow","Gartleman Farm","Garvan","Garway","Gary Ray","Gas Light","Gascoyne","Gaskin","Gasson Wood","Gasworks","Gate Farm","Gateforth","Gateon House","Gatesborough","Gateview","Gathering","Gaton","Gattucio","Gaudreau","Gault","Gautrey","Gavestone","Gawber","Gay Bowers","Gayford","Gaylor","Gays","Gazebo","Gearny","Gebo","Gee","Gehb","Geisler","Gelder","Gellert","Gemalla","Gen. Kennedy","General","General R. W. Berry","Generals","Genest","Genie","Genoa","Gentian","Gentlewood","Geoffrey","George","George Clauss","George Lee","George Mobbs","George V","Georgene","Georgia","Georjean","Geralind","Gerbera","Geren","Geringer","German Church","Germany","Gerpins","Gerrish","Gerstung","Gervase","Gest","Getyunga","Geyserville","Ghione","Ghyllroyd","Giannecchini","Giants","Gibbet Hill","Gibfield","Gibson","Gidea","Gidya","Giesman","Giffords Cross","Gighill","Gilba","Gilbralter","Gilcrest","Gildenhill","Gildersome Back","Gilgandra","Gillard","Gillespie","Gillian","Gilling","Gillmore","Gillwinga","Gilmore","Gilroy","Giltbrook","Gimbal","Ginden","Ginger Root","Gingham","Ginniver","Giorgano","Gipton Approach York","Girdle","Girralong","Gisbourne","Gist","Glabe","Glad","Glade Spring","Gladiator","Gladsdale","Gladwell","Glaisdale","Glandon","Glantz","Glascoe","Glass Mountain","Glasslyn","Glatton","Glazer","Gleason","Glebe House","Gledhill","Gledwood Wood","Glen","Glen Arms","Glen Byron","Glen Cross","Glen Echo","Glen Faba","Glen Gary","Glen Hannah","Glen Hook","Glen Lomond","Glen Mor","Glen Paul","Glen Sharon","Glen Valley","Glenair","Glenarms","Glenbarr","Glenbrook Crest","Glencairn","Glencorse","Glenda","Glendevie","Glendundee","Glenerye","Glenfield","Glengall","Glengary","Glenheather","Glenister","Glenlee","Glenmar","Glenmoor","Glenn Curtiss","Glenns","Glenora","Glenrise","Glenroy","Glenthorn","Glenugie","Glenwild","Glenworth","Glider","Globe","Glorietta","Gloucester","Gloxinia","Glyn","Glynwood","Goat Hill","Goatsmoor","Godbold","Godetia","Godman","Godstone","Goes","Goffe","Gogmore","Golborne","Gold Center","Gold Field","Gold Nugget","Gold Rush","Goldcoast","Golden Ball","Golden Cross","Golden Grove","Golden Leaf","Golden Poppy","Golden Russet","Golden View","Goldentree","Goldie","Goldmine","Goldsdown","Goldthwait","Golf Course","Golf Links","Golfview","Gomez","Gondola","Gonzales","Good Lion","Goodall","Goodenia","Goodhew","Goodinge","Goodmans","Goodrick","Goodview","Goodwives River","Goodyers","Goondari","Goose","Goose Pond","Gooseley","Gordan","Gordonia","Gorefield","Goring Heath","Gornall","Gorse","Gorsey","Gort","Gosbrook","Gosheff","Gosling Hill","Goss Hall","Gosset","Goswell","Gothwaite","Gottschalk","Gougham","Goulder","Gourley","Gover","Governor Bridge","Governor Peabody","Govert","Gower","Goyak","Grace Estates","Gracedale","Gracemore","Graciella","Grady","Graff","Graham","Graicious","Gralynn","Grammar School","Granada","Grand","Grand Corner","Grand Highlands","Grand Point","Grand River","Grand Valley","Grande Pines","Grandin","Grandview","Grange","Grangecourt","Grangewood","Granite Rock","Granny","Grant Mills","Granton","Grapal","Grapewood","Grass Lake","Grassingham","Grassy Knoll","Gratrix","Grau","Gravel Pit Haul","Gravelly Hill","Graves","Gray","Gray Hawk","Graybriar","Grayheaven Manor","Graymarsh","Grayrigg","Grays Park","Grayson","Graywacke","Greacen Point","Great Bank","Great Bramingham","Great Castle","Great College","Great Elms","Great Guildford","Great Horwood","Great Knollys","Great Moor","Great Notley","Great Plain","Great Republic","Great Smokey","Great Trinity","Great Winchester","Greatfields","Greatwater","Greek","Green Branch","Green Circle","Green Dory","Green Forest","Green Heron","Green Island","Green Ledge","Green Mountain","Green Pastures","Green Ridge","Green Tree","Green Way","Greenaway","Greenbelt Metro","Greenbranch","Greenbury","Greencroft  Deans","Greene Ridge","Greenfinch","Greengrove","Greenhays","Greenhouse","Greenland","Greenleach","Greenlook","Greenoak","Greenrock","Greensfield","Greenson","Greensview","Greenvale","Greenway  Longland","Greenwich Church","Greenwich Woods","Greenwood East","Greeves","Gregg","Gregorich","Gregory M Sears","Grenada","Grenfel","Grenock","Greshan","Gretchen","Greville","Grey Barn","Grey Ghost","Grey Seal","Greycaine","Greylag","Greyshiels","Greyswood","Grice","Grieves","Griffin Farm","Griffith Farm","Griglio","Grimmer","Grimsley","Grindall","Griner","Grisdale","Gritte","Grizzly Rock","Grobie Pond","Grogan","Groom","Gropius","Gross Point","Grosvenor","Groton School","Grounds","Grove Cross","Grove Mill","Grovehill","Grovenor","Groveton","Grubb","Grunauer","Grymes Hill","Guam","Guayamas","Gudrun","Guerin","Guernsey","Gueudecourt","Guido","Guildersfield","Guildown","Guillemot","Guinions","Guittard","Gulia","Gulliver","Gum Grove","Gumbuya","Gumtree","Gun Hill","Gundain","Gundrys","Gunn","Gunnerfield","Gunpowder","Gunsynd","Guntzer","Gurin","Gurnsey","Gustafson","Guston","Guthmiller","Gutters","Guyon","Guywood","Gwen","Gweneth","Gwyder","Gwynndale","Gymoty","H A Wyeth Sr","HIllside","Haan","Haben","Haciendas","Hackensack","Hackhurst","Hackney","Haddassah","Haddo","Hadham","Hadley Run","Haeg","Haff","Hagadorn","Hagel","Hagert","Haggin","Hague","Haidlen","Haile","Haining","Haiti","Halcourt","Hale Haven","Haledon","Halesowen","Haleybird","Half Moon","Halfmoon","Halibut","Halinda","Hall Brown","Hall Moss","Hallam","Hallen","Hallfields","Hallin","Hallo","Hallowell","Halls Hole","Hallwicks","Halmstad","Halsbury","Halstead","Halterman","Halvorsen","Ham Park","Hamberts","Hambletonian","Hambrook","Hamerick","Hamill","Hamlet","Hammarlee","Hammerlee","Hammertown","Hammond Branch","Hammonton","Hampermill","Hampstead High","Hampton Hill","Hampton Ridge","Hamson","Hanback","Hancock","Handcross","Hands","Hanes","Hanging Chadder","Hanken","Hanley","Hanna Park","Hannan","Hannett","Hannover","Hansard","Hansell","Hansol","Hanworth","Happy Creek","Hara","Harberson","Harbolets","Harbor Light","Harbor Side","Harboro","Harbour Cove","Harbourer","Harby","Hardcastle","Hardenburgh","Hardies","Hardman","Hardwicke","Hardywood","Harecroft","Harelands","Harewood Arms The","Harger","Hargus","Harkeith","Harkison","Harlea","Harlesden","Harlinger","Harmans Water","Harmony","Harmston","Harness Shop","Harold Court","Harolds","Harper Fold","Harpford","Harptree","Harreton","Harriman","Harris","Harrison","Harrivan","Harrop Green","Harrowdene","Harry J Rogowski","Hart Dyke","Harter","Harthill","Hartlake","Hartman","Hartnup","Harts Leap","Hartshorne","Hartswood","Hartwood","Harvard","Harvest Bend","Harvest Mills","Harvest View","Harvil","Harwell","Hasbrouck","Haselfoot","Hasketon","Haslemere","Hasluck","Hassell","Hassocks","Hastings","Hatch Gate","Hatchet Rock","Hatcliff","Hatherlow","Hatheway","Hatpat","Hattie","Hauge","Haultain","Haussler","Havant","Haven Hill","Havenner","Havenwood","Havermeyer","Havil","Havlicek","Hawbeck","Hawhorn","Hawk Hill","Hawke Park","Hawkesfield","Hawkins","Hawks Hill","Hawkshead","Hawkswood","Hawley","Hawsbrook","Hawthorne Hill","Haxby","Hay Green","Hayday","Haydon","Hayes End  Angel","Hayfield","Hayles","Hayman","Haymond","Haysbrook","Hayward Farms","HazElwood","Hazel Ridge","Hazelbrook","Hazelglen","Hazelnut","Hazelwick","Hazlemere","Hazzard","Headingly","Headley Hill","Heady Hill","Health Center","Heapworth","Hearnshaw","Hearth","Hearts Bay","Heath Farm","Heathbank","Heathdene","Heather Green","Heatherbloom","Heatherland","Heatherside","Heatherway","Heathhurst","Heathpark","Heathwalk","Heaton Grange","Heaver","Hebe","Heckel","Heckmondwike Regent","Heddings","Hedge Row","Hedgerow","Hediger","Hedwig","Heene","Hegemans","Heiden","Heidrick","Heilicia","Heindrich","Heinz","Hejka","Helen","Helfred","Helix","Helles","Helm Cottage","Helmick","Helmstetter","Helton","Hembree","Heming","Hemlock Park","Hemmer","Hemp","Hempstead Valley","Hemswell","Henconner","Hendley","Hendry","Henhurst","Henley Wood","Hennepin Town","Hennings","Henon","Henrik Ibsen Park","Henry Ford II","Henry L.","Henry Turner Bailey","Hensill","Henwood Green","Heppleton","Herb Elliot","Herbert Breclaw","Herbing","Heredia","Herfort","Heritage Crossing","Heritage Lake","Heritage Square","Herley","Hermans","Hermiston","Hermon","Herndon","Heron","Herons","Heroult","Herricks","Herring Pond","Herrmann","Hersey","Hersperger","Hertsmere","Hesket","Hesperus","Hessler","Heswall","Heton","Hetzel","Hever Court","Hewart","Hewison","Hews","Hexton Hill","Heyde","Heyheads New","Heysbank","Heyward","Hezlett","Hiawatha","Hibner","Hickmans","Hickory Hill","Hickory Ridge","Hickox","Hickton","Hidden Brook","Hidden Glade","Hidden Lake","Hidden Oaks","Hidden River View","Hiddenbrook","Hideaway","Higbie","Higgins Park South","High Ash","High Bridge","High Court","High Elms","High Grove","High Hill","High Lake","High Meads","High Pine","High Rid","High Terrace","High Valley","HighLands","Highboro","Highcliffe","Highdaun","Higher Bury","Higher Green","Higher Swan","Highfields","Highland","Highland Heights","Highland View","Highlever","Highpath","Highshore","Hightree","Highworth","Higuero Highland","Hiland","Hilbre","Hilde","Hildens","Hileman","Hiline","Hill Cot","Hill Girt Ranch","Hill Park","Hill Trail","Hillard Lake","Hillbottom","Hillcot","Hillcroome","Hillegass","Hillesley","Hillgrove","Hilliers","Hillmead","Hillplace","Hills of Claire","Hillside Park","Hillspur","Hilltop Mall","Hillwood","Hilmar","Hilsea","Hilversum","Hinchinbrook","Hind Hill","Hindleap","Hinds","Hinguar","Hinsdale","Hinton Manor","Hipwood","Hirst","History","Hitches","Hithercroft","Hitty Tom","Hllwood","Hoaglands","Hobbis","Hobby","Hobie","Hobson Hollow","Hochler","Hockett","Hoddam","Hodge Clough","Hodnett","Hoeg","Hoff","Hofstra","Hogback","Hogfair","Hogscross","Hogtrough","Hoiting","Holbeam","Holborn  Theobalds","Holcolme","Holden Clough","Holderith","Holdridge","Holeman","Holiday Hill","Holker","Holland House","Hollar","Hollett","Hollickwood","Hollin Park","Hollingswood","Hollins Green","Hollis Wood","Hollow Hill","Hollow View","Hollowfield","Holly Berry","Holly Farm","Holly Haven","Holly Lake","Holly Park","Hollybank","Hollydale","Hollyhey","Hollymount","Hollytree","Holmard","Holmdel","Holme Lea","Holmemoor","Holmespun","Holmlea","Holmsley Field","Holsclaw","Holston","Holtby","Holts","Holwell","Holy Name","Holyoake","Homann","Home Gate","Homebush","Homefield","Homemeadow","Homers Wood","Homesite","Homeview","Hommann","Honest Pleasure","Honey End","Honeybourne","Honeyhill","Honeywell","Honker","Honora","Hoo","Hooe","Hook Green","Hookhouse","Hookston","Hoopers","Hooyman","Hopatcong","Hope Park","Hopestill","Hopgarden","Hopkinson","Hoppin","Hopps","Horace Darling","Horde","HorizOn","Horlock","Hornash","Hornbuckle","Hornecastle","Hornhill","Hornsby","Horridge","Horse Ferry","Horse Prairie","Horsegrove","Horseman","Horseshoe","Horsfield","Horsnell","Horton Hill","Hory","Hosie","Hosler","Hotaling","Hothorn","Houchin","Houghend","Houlton","Hounds Ditch","Hourglass","Houselands","Houtman","Hovenden","Howard","Howard Lake","Howarth","Howden","Howerton","Howitzer","Howley Mill","Howsman","Hoxton  Baring","Hoyles","Hoyts Wharf","Hubbard School","Hubble","Huckleberry","Huddleson","Hudner","Hudson Service","Hugel Hill","Hugh Fraser","Hughesdale","Huhtala","Hull Shore","Hulmes","Humboldt","Hummer","Humphries","Hundred Oaks","Hungerford","Hunnable","Hunslet","Hunt Country","Hunt Valley","Hunter Mill","Hunterdon","Hunters Grove","Huntersend","Hunting Farms","Hunting Quarter","Huntington Bay","Huntingtown","Huntley Square","Huntover","Hunts Pond","Huntsmoor","Huntzinger","Hurds","Hurley","Hurricane","Hurst Park","Hurstfield","Hurtmore","Huskisson","Hust","Hutchingsons","Hutton","Hyacinth","Hybrid","Hyde Farms","Hydeway","Hygelund","Hyland Greens","Hyndman","Hypoint","Hythe","I U Willets","Iandra","Iberis","Icasia","Ice House","Iceton","Ickford","Ida Clayton","Idalou","Idle Creek","Idlestone","Idolstone","Idylwilde","Ifold Bridge","Ijauna","Ilchester","Ilgars","Ilka","Illawarra","Illinios","Ils","Imberhorne","Imola","Import","Inaudi","Inchon","Indelicato","India","Indian Chase","Indian Harbor","Indian Joe","Indian Oaks","Indian Ridge","Indian Tree","Indianapolis","Indus","Infantry Ridge","Ingalton","Ingersley","Ingle","Ingleby","Ingleshire","Ingoldsby","Ingrams","Inheritance","Inkerman","Inner","Innerhill","Innisbrook","Innovation","Insel","Inspiration Point","Interbay","Interlocken","Interstate","Intrieri","Invergorden","Inverray","Invicta","Iodine","Ione Michigan Bar","Ipswich","Iredine","Ireson","Irk","Irmen","Iron Gorge","Iron Point","Irondequoit","Ironside","Iroquois","Irvine","Irwell","Isaac Hull","Isador","Isbel","Isengard","Ishnala","Island Farm","Islandside","Isled","Isleton","Ismailia","Issac Miller","Itaska","Itte","Ivan","Iveley","Ives","Ivor","Ivy Bush","Ivy Green","Ivy Lodge","Ivy Wood","Ivyleaf","Ixion","J D Reading","J Smith","Jacaranda","Jack Cornwell","Jack Tone","Jackie","Jackpit","Jackson Mill","Jacksonville","Jacob Cushman","Jacobson","Jacquelyn","Jadchalm","Jaeger","Jagger","Jahn","Jake Creek","Jamaica","James Barton","James Craig","James Flynn","James Lex","James R Rakow","James W Smith","Jameston","Jamie Lee","Jan Mar","Janas","Jane Adams","Janelle","Janeway","Janna","Janocha","Jansen Farm","Japonica","Jardine","Jarnecke","Jarrow","Jasmin","Jasons","Jaunell","Jawl","Jaydine","Jayrose","Jaywood","Jean Wailes","Jeanneret","Jed","Jef","Jefferon","Jeffrey","Jefts","Jellicoe","Jencks","Jenkins","Jenna","Jenni","Jenniffer","Jennings Park","Jenny Lind","Jenvey","Jeraldo","Jereva","Jerilderie","Jernee","Jerridge","Jerrys","Jerusha","Jespersen","Jessel","Jessie Jo","Jeter","Jewelflower","Jewish War Veterans","Jf Kennedy","Jill","Jilrick","Jim Veal","Jinchilla","Jj","Joan","Joaquin Murieta","Jocare","Jockey","Jodi","Joe Borovich","Joe Pombo","Joetta","John","John Ayres","John Bradshaw","John Campbell","John Cobb","John Davey","John E Smith","John Fisher","John H Johnson","John Heywood","John J Brady","John Kirkham","John Marthens","John Milton","John Partridge","John Rezza","John Sam","John Thomas","John William","Johnny Appleseed","Johns Hollow","Johnson Grove","Johnston Crescent","Joiner","Joliet","Jolma","Jonagold","Jonathan Ridge","Jones Branch","Jones River","Jonspin","Jopling","Jordans","Jorgen","Jose","Joseph Banks","Joseph Schwab","Josephson","Josina","Jost","Journeay","Joy Lee","Joyce Lundberg","Joynt","Juarez","Judge","Judick","Judsonville","Juergens","Julep","Julian","Juliedale","Julietta","Jumel","Juna","Juneberry","Junia","Juniperberry","Juntar","Jurgens","Justamere","Justina","Jutland","Kaanapali","Kadema","Kafka","Kahn","Kaintuck","Kaitlyn","Kalarama","Kaley","Kalk","Kallista","Kaltern","Kamaur","Kameruka","Kammerer","Kanabec","Kanawha","Kanegis","Kanili","Kanoona","Kantor","Kaparia","Kaposia","Kapyong","Karangi","Kardon","Karen Anne","Karilla","Karl","Karlson","Karnell","Karoon","Karringal","Karyl","Kaski","Kassel","Katan","Kath","Kathlean","Kathrina","Katie","Katrine","Kaufman","Kavanagh","Kawai","Kaybro","Kayjay","Kayron","Kazebeer","Kealsy","Kearney","Keary","Keawe","Kedith","Keech","Keele","Keema","Keens Park","Keepsake","Keevil","Kegle","Keightley","Keinches","Keith","Keithley","Kelburn","Kelesey","Kelland","Kellerman","Kelling","Kells","Kelly Lake","Kelrose","Kelson","Kelveden","Kelwynne","Kembla","Kemmever","Kempbridge","Kemps Farm","Kempthorne","Kenalray","Kenburn","Kendall","Kendel","Kendra Hall","Kenelworth","Keniff","Kenleigh","Kenmere","Kennady","Kennel Barn","Kennerleigh","Kenneth Kostka","Kennicott","Kennworth","Kenosha","Kensellas","Kenson","Kent Fire","Kent Sq","Kentford","Kentley","Kentridge","Kentucky","Kenwar","Kenwood Isles","Kenzel","Keough","Keran","Keri Ann","Kermes","Kerner","Kerrawah","Kerrins","Kerry Beacon","Kerscott","Kerstin","Keslake","Kessler","Kestrel","Ketchum","Kettell","Kettle","Kettle Run","Kevelioc","Kevinberg","Kewadin","Key Turn","Keyner","Keyse","Keytone","Khama","Kiawah","Kickapoo","Kidd Creek","Kidlington","Kiefer","Kieper","Kiessig","Kijek","Kilbernie","Kilburne","Kildare","Kilgore","Kilkerry","Killawarra","Killian","Killingworth","Kilmarnock","Kilmoray","Kiln Pond","Kilnwood","Kilross","Kilworth","Kimball","Kimberlee","Kimberwicke","Kimcumber","Kimmel","Kinburn","Kinder","Kindler","King","King Charles","King Fisher","King Hall","King John","King Muir","Kingate","Kingery","Kingingwood","Kingridge","Kings Chapel","Kings Crossing","Kings Hall","Kings Lynn","Kings Retreat","Kings Way","Kingsbury","Kingscroft","Kingsford Smith","Kingsland","Kingsman","Kingsnorth","Kingston  Eden","Kingstream","Kingswick","Kinkade","Kinloch","Kinnear","Kinnoul","Kinsey","Kinswood","Kinzel","Kiote","Kipp","Kipps","Kirch","Kirk Farm","Kirkdale","Kirkgate Highgate","Kirkleas","Kirkman","Kirkstall","Kirkup","Kirrang","Kirstmont","Kiser","Kissel","Kit Hill","Kitching","Kitkatts","Kitson","Kittie","Kitty","Kivy","Klainert","Klaus","Kleinman","Klickitat","Klinsky","Klovstad","Knapton","Knauer","Knelle","Knife Shop","Knighton","Knights Park  Denmark","Knightwake","Knobcone","Knockholt","Knoll Creek","Knoll Ridge","Knollcrest","Knolls Pond","Knopp","Knottisford","Knowl Top","Knowlsey","Knoxville","Knutson","Kobb","Kocher","Koehling","Kofman","Kohlepp","Kohr","Kolbert","Kolmer","Koman","Kondazian","Konner","Koola","Koora","Koorine","Kootenai","Kopp","Korman","Korrel","Kosciusko","Kosmina","Kotlik","Kousa","Kovr","Koziara","Kraken","Kramme","Krech","Kreitzburg","Krestrel","Krey","Kriss","Kristine","Krochmally","Kronmeyer","Kroy","Kruse","Ku Ring Gai Chase","Kuester","Kulgoa","Kult","Kundi","Kunkundi","Kuringai","Kurrawa","Kurung","Kussoth","Kwajalein","Kylemore","Kyne","Kyverdale","L W Johnson","La Alegria","La Boheme","La Casa","La Corona","La Count","La Cumbre","La Follette","La Goma","La Haigh","La Lena","La Mar","La Palm","La Plume","La Reina","La Riviere","La Salida del Sol","La Torre","La Vita","Labath","Labor","Labrott","Labworth","Lacebark","Lachlan","Lacky Dam","Laconia","Ladbroke","Laddie","Ladew","Ladonia","Lady Ann","Lady Game","Lady Somerset","Ladybridge","Ladyshore","Lafarge","Laffans","Lafond","Lafreniere","Lago De Bracciano","Lagoon View","Laguna Honda","Laguna Vista","Lahard","Lahti","Laindon","Laith","Lake Arrowhead","Lake Boone","Lake Chad","Lake Court","Lake Elmo","Lake Forrest","Lake Hazeltine","Lake House","Lake Knoll","Lake Louise","Lake McClure","Lake Nimbus","Lake Overlook","Lake Pointe","Lake Riley","Lake Susan Hills","Lake Tree","Lake Vista","Lakebird","Lakeforest","Lakeknoll","Lakeman","Lakenhurst","Lakeshire","Lakeside Village","Lakeview Fire","Lakewood Falls","Lakota","Laloki","Lamarcus","Lambden","Lambert Creek","Lambeth High","Lambourne","Lambs Farm","Lamerton","Lammers","Lamonte","Lamp","Lamping","Lampson","Lan Ark","Lanbury","Lanceley","Lancewood","Land Off Kendall","Landale","Landeros","Landfill","Landing","Landolt","Landrace","Landsberg","Landsend","Lane","Lanell","Laneview","Langaller","Langdrum","Langewood","Langhorn","Langley Canyon","Langley Platt","Langner","Langsett","Langthorne","Lanham","Lanini","Lannon","Lansbury","Lansford","Lantern Hollow","Lanvanor","Lapier","Laplata","Lapu Lapu","Laramie","Larch Hill","Larciano","Larges","Lariat","Lark","Larkard","Larkhall","Larkmead","Larkswood","Larne","Larosa","Larrlyn","Larson Farm","Las Astas","Las Dunas","Las Lomitas","Las Posadas","Las Vegas","Lash","Lasker","Lasseter","Lastingham","Latchmoor","Lathbury","Latin","Latour","Lattimore","Laub Pond","Laufall","Lauman","Laundess","Laura Mark","Laurel","Laurel Creek","Laurel Hill","Laurel Park","Laurel Woods","Laurels","Laurence Hamilton","Lauriana","Laurier","Lauritson","Laussat","Lavally","Lavender Hill","Laver","Lavidge","Lavio","Law","Lawfords Hill","Lawlor","Lawnhurst","Lawrence","Lawrie","Lawson Glen","Laxell","Layden","Layminster","Layton Hall","Lazell","Lazy Hollow","Le Britton","Le Fevres","Le Mans","Le personne","Lea Valley","Leaches","Leadale","Leadville","Leafgreen","Leafy Oak","Leal","Leamington","Leaning Oak","Leapingwell","Leary","Leasowes","Leatherbark","Leatherwood","Leavesden","Lebanon","Lebkamp","Lechford","Leclerc","Ledbury","Ledge Brook","Ledgemere","Ledgewood","Ledston","Lee Acres","Lee Deforest","Lee Lee","Lee School Cross","Leech Brook","Leeds Aire","Leeds Hall","Leeds Victoria","Leeke","Leemon","Lees Farm","Leeside","Leeta Cornus","Leewood","Lefont","Legana","Legdewood","Leggatt","Leggs Hill","Legregni","Lehn","Leibert","Leick","Leigh Mill","Leighfield","Leighwood","Leishear","Leitches Wharf","Lekoday","Leland Farm","Lemans","Lemcrow","Lemocks","Lemons Bridge","Lemoyne","Lenah Farm","Lenburg","Lendrum","Lenfell","Lenham Heath","Lenmore","Lennoco","Lenox","Lent Rise","Lentz","Leo Slyvious","Leona","Leonardo","Leonhardt","Lepine","Leritz","Lerwick","Lesher","Leslie Gilbert","Lessar","Lester Grey","Leswin","Letendre","Letter","Lettsom","Leupold","Levato","Leven","Lever","Leverich","Leveson","Levington","Levy","Lewid","Lewis Court","Lewisburg","Lewitt","Lexden","Ley Field","Leycroft","Leyfield  The Manor","Leys","Leyte","Leytte","Liana","Liberata","Liberty Hill","Liberty School","Libs","Lick","Liddell","Liddle","Lidgett Park","Liebig","Lierly","Liffre","Light Guard","Lightbowne","Lighthouse","Lightning View","Ligman","Likely","Lilah","Lilienthal","Lillehei","Lillie","Lilly Pilly","Lily Dhu","Lilybrook","Limar","Lime Green","Limebank","Limekiln","Limesfield","Limits","Limpsfield","Linberg","Lincoln Centre","Lincoln Mall","Lincolns","Lincroft","Linda Marie","Lindal","Lindberg","Lindelof","Linden Hills","Linden Tree","Lindenoaks","Lindeth","Lindisfarm","Lindor","Lindron","Lindsell","Lindview","Linefield","Linfield","Lingfield","Lingwell Nook","Linkmead","Linkswood","Linmouth","Linneman","Linquist","Linslade","Linton Hall","Linville","Lion Fold","Lions Field","Liparita","Lippi","Liptraps","Lisa Ann","Lisborough","Lisdowney","Liskeard","Lisso","Listmas","Litchenberg","Lithos","Littell","Little Argyll","Little Bear Creek","Little Bluestem","Little Bury","Little City","Little Cove","Little Dorrit","Little Eveleigh","Little Fox","Little Grove","Little Hill","Little Johns","Little London","Little Melody","Little Mountain","Little Norton","Little Patuxent","Little Portland","Little Ridge","Little Seneca","Little Totham","Little Valley","Little Whaleneck","Littlebourne","Littledales","Littlehurst","Littlethorpe","Littleworth Common","Liv","Liversidge","Livingston Terrace","Livsey","Lizotte","Llama","Llangollan","Llewellyn Manor","Lloyd Haven","Lloydminster","Loampit Vale  Jerrard","Lobata","Lobo","Loch Glen","Loch Sloy Service","Lochat","Lochinvar","Lochner","Lochwan","Locke King","Lockesley","Lockhart","Lockinger","Lockney","Locksly","Locomotive","Locust Hill","Lodato","Lodestone","Lodgehill","Loeb","Lofthouse Jumbles","Log Bridge","Log Teal","Loganview","Logic","Logwood","Loines","Lokus","Lolleywood","Loma Alta Fire","Loma Verde","Lombard","Lommel","Loncin Mead","London Bridge","Londonderry","Lone Pine","Long Acre","Long Bow","Long Cove","Long Grove","Long Marl","Long Pine","Long River","Long Valley","Longbeach","Longcroft","Longdon","Longest","Longhedge","Longlane","Longlook","Longmoore","Longport","Longshaw Ford","Longstomps","Longvale","Longwick","Lonna","Loobert","Lookout","Loon Hill","Lopez","Loradale","Lorane","Lord Cecil","Lord Mayors","Lordings","Loree","Lorene","Lorete","Lori Anne","Lorillard","Lorion","Lornadel","Lorreta","Lorton Market","Los Amigos","Los Cerritos","Los Felice","Los Lagos","Los Palos","Los Rios","Loseberry","Lost Colony","Lost Oak","Lostock Park","Lothenbach","Lotten","Lotus","Louart","Loudhams Wood","Loudwater","Loughran","Louis Holstrom","Louisana","Lounga","Louth","Lovat","Love Joy","Loveland","Lovering","Lovett","Lovisa","Low Hall","Low Shops","Lowcross","Lowell Davis","Lower Almora","Lower Basinghall","Lower Bloors","Lower Britwell","Lower Byrom","Lower Church","Lower Cox","Lower Dearborn Park","Lower Exchange","Lower Frenches","Lower Hall","Lower Hey","Lower Hutchinson","Lower Lake","Lower Magazine","Lower Marlboro","Lower Mortlake","Lower Oxford","Lower Plateau","Lower Richmond","Lower Sandhurst","Lower Tofts","Lower Vicarage","Lower Wokingham","Lowerhouse","Loweswater","Lowlands","Lowry","Loxford","Loyalty","Lu Anne","Lubec","Lucas Park","Luce Creek","Luchessi","Lucile","Luckenbach","Lucks","Lucretia","Luda","Ludgate","Ludlum","Luellan","Lufkin","Luger","Luisi","Lukens","Lulea","Lumar","Lumber Hill","Lumns","Lunan","Lundan","Lundstead","Lunenburg","Lunski","Lupp","Luria","Lusan","Lusk","Luten","Luton Airport","Luvian","Luxmanor","Luzena","Lybury","Lydd","Lydham","Lydig","Lye Green","Lyle","Lymbridge","Lymer","Lymmington","Lynch Hill","Lyndall","Lynderswood","Lyne","Lynfords","Lynn","Lynn Ridge","Lynncroft","Lynnmoor","Lynsted","Lynway","Lyon Park","Lyonsdown","Lysia","Lyth","Lyttel","M F Bowen","MIddleton","Mabank","Mabfield","Mabrey","Mac Farlane","Mac Queen","MacDonough","MacLachlan","Macadamia","Macara","Macbain","Maccomb","Macedon","Macfarland","Machell","Maciel","Mackall","Mackeson","Mackinnon","Mackubin","Macleay","Macmahon","Macomb","Macpherson","Macredes","Macy","Madary","Maddocks","Madeleine","Madera","Madgeways","Madingley","Madison House","Madrers","Madronawood","Maduro","Maeve","Magazine","Magdelina","Magers","Maggiora","Maginnis","Magner","Magnolia Grove","Magonko","Magothy View","Magreed","Mahar","Mahogony","Mahopac","Maida Vale","Maidenshaw","Main","Main Wharf","Mainsail","Mairmont","Majendie","Major Appleby","Majors","Makatom","Makinson","Malachite","Malapardis","Malborough","Malcolm X","Maldonado","Maley","Malicoat","Mall Access","Mallard Landing","Mallawa","Malley","Mallord","Malloy","Malmsbury","Malory","Malsbury","Maltese","Maltmans","Malvasia","Malvine","Mamie","Managers","Manatauck","Manchest","Mancini","Mandalay Beach","Mander","Mandoon","Manemet","Manfre","Mangin","Mangravet","Manhasset","Maniago","Manion","Manitou Island","Manlove","Manner","Manning","Manoff","Manor Circle","Manor Hall","Manor Park","Manor Way Maythorne","Manors","Manowie","Manseau","Manshaw","Mansur","Manter","Manton","Manuelian","Manville","Many Mind","Manzanita Fire","Map","Maple","Maple Falls","Maple Lake","Maple Run","Maplecrest","Maplehill","Maplers","Mapleton","Maquan","Maracaibo","Marana","Maranta","Marazzani","Marbi","Marble Ridge","Marblewood","Marburger","Marcel","Marcey","Marcher","Marcius","Marcshire","Mardan","Mardin","Mardol","Mareda","Mares Neck","Marfield","Margaret Curtis","Margarido","Margerita","Margin","Margret","Mari","Mariani","Marich","Marie Major","Marigold","Marilyne","Marina","Marina Shore","Marine Terminal","Mariner Green","Marinita","Mario","Marionet","Marisma","Marivista","Mark Alan","Mark Thomas","Markeley","Market Town","Markgrafs Lake","Markland Hill","Marks","Marl","Marland Hill","Marlbrough","Marler","Marley Neck","Marlis","Marlow","Marlton","Marmadon","Marmona","Marney","Maroo","Marple","Marquet","Marrang","Marrilyne","Marrow","Marsala","Marsdale","Marsh Creek","Marsh Hawk","Marsh View","Marshall Corner","Marshalls","Marshes Dock","Marshmoor","Marstan Moor","Marsworth","Marten","Martha Jane","Marti Marie","Martin Long","Martine","Martinique","Martins Pond","Martis","Martock","Martyr","Marvelle","Marwell","Mary Alice","Mary Caroline","Mary Ellen","Mary Jean","Mary Lou","Mary Scano","Maryanne","Marye","Marylake","Marylon","Marymount","Maryvale","Marzoff","Mascari","Mase","Masjid","Mason Dixon","Masonic Home","Masonville","Massapoag","Masseth","Massolo","Master Gunner","Masthead","Matadero","Matawan Green","Matchmoor","Matfair","Matheron","Mathia","Mathy","Matis","Matross","Mattakeeset","Mattawoman Beantown","Matteson","Matthews Town","Mattingley Bottle","Mattos","Matule","Maudlin","Maughan","Mauldeth","Maura","Maurland","Maves","Mawbey","Maxall","Maximfeldt","Maxwell Frye","May School","Maybank","Maybern","Maycheck","Mayday","Mayeswood","Mayfields","Mayhew","Maylands","Mayme","Mayo","Maypole","Maysent","Mayton","Mayweed","Maze","Mazoe","Mazzone","Mc Alpin","Mc Callum","Mc Ceney","Mc Closkey","Mc Cook","Mc Coy","Mc Culloch","Mc Divitt","Mc Ewen","Mc Ginness","Mc Gregor","Mc Kee","Mc Keon","Mc Lain","Mc Loughlin","Mc Murray","Mc North","Mc Veigh","McAleer","McArthur","McBrown","McCampbell","McCarthy Ranch","McCauley","McClaren","McClintock","McClure","McCone","McCormack","McCoy Creek","McCrory","McCulley","McCurley","McDiarmid","McDonnell","McDuff","McEntee","McFarlane","McGarvie","McGinn","McGowen","McGuin","McHatton","McInnis","McKee","McKenna","McKeown","McKinley","McKlintock","McLaughan","McLees","McManus","McMurdo","McNamee","McNerney","McPhee","McSherry","McWilliams","Mcavoy","Mccarthy","Mccoy","Mcdevitt","Mcendy","Mcgovern","Mcintosh","Mckinley","Mclellan","Mcpherson","Meachin","Meade","Meadhook","Meadow Brook","Meadow Farm","Meadow Hall","Meadow Oak","Meadow Run","Meadow Wood","Meadowcrest","Meadowgate","Meadowland","Meadowood","Meadowsedge","Meadowvista","Meadway  Bigwood","Meal","Meanley","Mecan","Meckes","Medawar","Meder","Median","Medieval","Medlake","Medoro","Medwin","Meeks","Meeson","Meetinghouse","Megg","Mehaffey","Meiele","Meisinger","Mel","Melba","Melch","Meldung","Melfort","Melina","Melksham","Mellersh Hill","Mellison","Mellon","Mellowstone","Melody Hill","Melsomby","Melva","Melvin","Melyncourt","Memorial School","Menard","Mendelssohn Service","Mendip","Mendota","Menhart","Menlo Oaks","Menominee","Mensching","Mento","Meon","Merc","Mercers","Mercier","Mereclough","Mereland","Mereside","Meriam","Meridian Hill","Merikoke","Merindah","Meritt","Merklin","Merlini","Merna","Merrall","Merribrook","Merrie Mill","Merrilee","Merrimac","Merrinot","Merritt Farm","Merriwether","Merry Moppet","Merryfield","Merrymeeting","Merseles","Merstham High","Merttins","Merwin","Mesa Creek","Mescalero","Messaline","Messina","Metacomett","Methilhaven","Metro","Metropolitan Church","Metten","Metzler","Mexborough","Meyers Grade","Mezzamonte","Miami","Michael Canlis","Michael Point","Michalik","Michelham","Michener","Micik","Mickleham","Microlab","Midan","Middelton","Middle Creek","Middle Harbour","Middle Neck","Middle River","Middle park","Middleby","Middlefork","Middlemead","Middlesex Center","Middleton Ridge","Middletune","Midfield","Midhill","Midland Hills","Midnight","Midstone","Midway","Mifsud","Migration","Mikayla","Miko","Milano","Milboro","Milcote","Mildura","Mile Tree","Miles Hill","Milfan","Milham","Milk Farm","Mill Bay","Mill Creek","Mill Gate","Mill Park","Mill River","Mill Vue","Millbeck","Millburne","Millenium","Miller Heights","Millers Island","Millfields","Millhead","Milliken","Milliston","Millrace","Mills Hill","Millshot","Millstream Service","Millview","Millyan","Milner","Milot","Milsop","Milton High","Milva","Milwaukee","Mimms Hall","Mina Rosa","Minas","Mindar","Mindy","Minebrook","Mineral Springs","Ming","Minimbah","Mink Meadows","Minnamurra","Minnesota Bluffs","Minnieford","Minola","Minot Light","Minstrell","Minthaven","Minuet","Mionske","Mira Mesa","Miracle Mountain","Miralo","Miranda","Mireille","Mirkwood","Mirrielees","Mirycarr","Miss Anne","Mission College","Mission Rock","Mississippi Bar","Mistic Harbour","Mistwood","Misty Hill","Misty Woods","Mitchell Canyon","Mitchler","Mitlon","Mittendorf","Miwok","Mizzen","Moat Hall","Mobley","Moccasin Hill","Mockingbird","Modaff","Modern Ice","Moe","Moffats","Moffitts","Mohawk River","Moir","Mokelumne River","Molasses Run","Molesey","Moline","Molle","Molly Millars","Molteno","Momar","Monacan","Monaltrie","Monard","Monaton","Moncton","Mondigo","Monetary","Monfarville","Monie","Monk Bridge","Monks","Monkswood","Monnens","Monogram","Monponset","Monsal","Mont Clare","Montair","Montana","Montbatten","Montcourse","Monte Cresta","Monte Sereno","Montebello","Montefiore","Montelegre","Monterery","Montery","Montevina","Montford","Montibello","Montmorenci","Montour","Montrose","Monument","Monzal","Mooki","Moon Hill","Moon Valley Ranch","Moondani","Moonlight Hill","Moonshine","Moor Green","Moora","Moordale","Moorefields","Mooreview","Moorhey","Moorings","Moorside","Moos","Mopsick","Moraine","Morants Court","Mordaunt","Mordred","Morehouse","Morelli","Moremead","Moresdale","Morewood Oaks","Morgan Territory","Morgaston","Moril","Morison","Morley Clough","Morna","Morning Field","Morning Sun","Morninglo","Moro","Morpheus","Morrie","Morris Park","Morrison","Morristown","Morry","Morsemere","Morten","Mortimer Lewis","Morton Hill","Morval","Morwood","Moseby","Moseley Wood","Mosher","Moss","Moss Grange","Moss Park","Mossbank","Mossford","Mossman","Mossy Rock","Motcombe","Motney Hill","Mottisfont","Mouldsworth","Mounce","Mounslow","Mount Annan","Mount Blue","Mount Crest","Mount Diablo","Mount Ephraim","Mount Frazier","Mount Hamilton View","Mount High","Mount Isabel","Mount Kenya","Mount Locust","Mount Morris","Mount Olney","Mount Pleasant","Mount Ridgeway","Mount Skirgo","Mount Tom","Mount Wachusett","Mount Wilson","Mountain Charlie","Mountain Lakes","Mountain Rock","Mountain View Ranch","Mountaire","Mountgrove","Mountwood","Movers","Mowden Hall","Mowry School","Moyengully","Moyser","Mt Curve","Mt. Hope","Mucking Hall","Muddy Branch","Muender","Muhlenhardt","Muirwood","Mulbring","Mule Deer","Mulhall","Mull","Mullens","Mulloy","Multiplex","Mums","Muncy","Mundays Borough","Mundy","Munich","Munnings","Munsee","Munsterburg","Muraoka","Murdoch","Murieta","Murmansk","Murphy Lake","Murray","Murray Rose","Murrin","Murry Hill","Murvey","Muscat","Musgnug","Musicmaster","Musketaquid","Musquashicut","Musto","Muth","Muttontown","My Mollies Pride","Myatt","Myer","Myler","Mylon","Myotis","Myrle","Myrtle Beach","Myrtlewood","Mystic View","N Abbey Glenn","N Adams","N Alameda","N Algonquin","N Anthon","N Arcade","N Arlington Mill","N Arundel","N Astor","N Avondale","N Bank","N Bartlett","N Beach","N Bellmore","N Beverly","N Bittner","N Bluebonnet","N Boro","N Bradley","N Brentwood","N Brightway","N Brook","N Browning","N Buell","N Burning Bush","N Calhoun","N Camp Meade","N Carll","N Cassell","N Center","N Chamlin","N Charter Point","N Chesapeake","N Christiana","N Clarice","N Clinton","N Coldspring","N Colorado","N Conrad","N Cortez","N Cowley","N Crosby","N Cutler","N Danforth","N Dato","N Dearman","N Delaware","N Denton","N Dexter","N Dittmar","N Dovington","N Dwiggins","N East","N Echo Lake","N Edison","N Elgin","N Ellyn","N Elston","N English","N Euclid","N Ewing","N Farrell","N Ferdinand","N Fillmore","N Fletcher","N Forest Garden","N Fox","N Frederick","N Frontenac","N Galena","N Garnsey","N Geneva","N Gilbert","N Glenmore","N Grace","N Grayson","N Greencastle","N Griffith","N Haig Point","N Hampshire","N Harlem","N Harvey","N Hazel Crest","N Hemlock","N Hermitage","N High","N Hill","N Hollister","N Hooker","N Howell","N Hundley","N Idaho","N International","N Ivy","N Janssen","N Jersey","N Jordan","N Kansas","N Kedzie","N Kenilworth","N Kennison","N Kerbs","N Kilbourn","N Kings","N Kittson","N Kostner","N Laflin","N Lakeshore","N Landers","N Larrabee","N Lavergne","N Leavenworth","N Leibert","N Lenwood","N Lewis","N Lillian","N Lister","N Lockwood","N Long Cove","N Loomis","N Lotus","N Luella","N Lynn","N Magnolia","N Mallard","N Manor","N Maria","N Marshfield","N Maryland","N May","N Mc Leod","N McVicker","N Melody","N Merchants","N Middle","N Military","N Minnehaha","N Moetz","N Montclair","N Moreland","N Mountain","N Nagle","N Nashotah","N Naylor","N New Britton","N Newgard","N Nickerson","N Nordica","N Northwest","N Oak Hill","N Oakmont","N Ode","N Old Creek","N Oleander","N Ontario","N Oshkosh","N Owens","N Park","N Patton","N Peary","N Pennsylvania","N Peyton","N Pima","N Pitt","N Plymouth","N Pond Shore","N Powhatan","N Prestwick","N Prosperity","N Queen","N Raddant","N Rand","N Raynor","N Reta","N Ridgeland","N Riverside","N Roberts","N Rockwell","N Rolfe","N Rosetree","N Royal Oaks","N Saddle Brook","N Sangamon","N Schletti","N Scott","N Service","N Shenandoah","N Silverlake","N Smith","N South Park","N Springfield","N Suthers","N Syracuse","N Tamarack","N Terrill","N Tonty","N Tripp","N Turtle Bay","N Upshur","N Van Buren","N Varner","N Vermont","N Village","N Wacouta","N Waller","N Warrington","N Waterman","N Weatherstone","N Wells","N Westgate","N Westwood","N White Pine","N Wildrose","N Williams","N Wilshire","N Windsor","N Wisner","N Woodgate","N Woodward","N Yale","N la Londe","NE Arthur","NE Greens Crossing","NE Jambor","NE Polk","NE Wilson","Na Wa Ta","Nabscot Brook","Naden","Nagle","Nahatan","Nairn","Nall","Namba","Namleps","Nan King","Nanapashamet","Nancy Ann","Nangana","Nanna","Nant","Nanuet","Napa River","Naples","Narada","Narbuth","Nardone","Naretha","Naroo","Narromore","Narvick","Nash","Nashenden Farm","Nasonville","Nata","Natchez","Nathaniel","Nathorst","Nations","Natomas","Natural Bridges","Naugatuck","Nauset","Navahoe","Nave","Navigator","Nawadaha","Naylon","Nazrul","Nealden","Neaptide","Neasden","Nebo","Neckinger","Nedderson","Needham","Needles","Neeley","Nees","Nehemiah","Neighbors","Neillian","Neitzel","Nell","Nellgrove","Nelmark","Nelson Grove","Nelson Short","Nemic","Neotomas","Nepicar","Nepshaw","Nerdy","Nero","Neshobe","Nestlewood","Netheravon","Netherend","Nethermont","Netteswell","Nettlewood","Neufairfield","Neustoneshire","Nevern","Nevins","New Ackertown","New Banner","New Bedford","New Bridge","New Brooklyn","New Cathedral","New Clark","New Creek","New Devon","New Durham","New Era","New Forest","New Gateway","New Harbor","New Herbert","New Hope","New Illawarra","New Kent","New Liberty","New Lots","New Mayfield","New Milford","New Mount","New Old Bridge","New Place","New Quay","New Rochelle","New Solomoms Island","New Tank Hill","New Utrecht","New Walnut","New West Townsend","New Woods","Newacre","Newarth","Newberry","Newbreak","Newby Bridge","Newcomb","Newcrossing","Newell Creek","Newfoundland","Newham Way  Colman","Newington Forest","Newland Green","Newmain","Newmarsh","Newport Cove","News Lees","Newteswell","Newton Wood","Next Day Hill","Nibbe","Nicastro","Nichold","Nicholson","Nickle Plate","Nickson","Nicole","Nicolosi","Nidva","Nieman","Nigeria","Nightingale Farm","Nike Manor","Nile","Nimbey","Nimrick","Nine Elms","Nineveh","Ninnis","Nipmuc","Nire","Nisich","Nithdale","Nixon","Noak Hill  North Hill","Nobbs","Nobile","Noble Victory","Nock","Noe","Nogales","Nola","Nolden","Nolpark","Nomini","Nonington","Noolinga","Noorang","Norbay","Norbrik","Norcia","Norcutt","Nordham","Nordstrom","Noreuil","Norfolk Farm","Norham","Noriker","Norlington","Norman","Normand","Normandy Crossing","Normanshire","Normoor","Norridge","Norroy","Norsid","North Abel","North Alamo","North Amelia","North Arlington","North Audley","North Bascom","North Bayshore","North Bella Monte","North Beulah","North Border","North Brandon","North Brook","North Butte","North Canal","North Carriage","North Chanterella","North Circular","North Clinton","North Commonwealth","North Court","North Crest","North Dale","North Delaware","North Dublin Ranch","North Eastling","North Eldorado","North End","North Fair","North Ferndale","North Folly","North Fosket","North Fuel Break","North Gary","North Glebe","North Graham","North Great","North Guild","North Hancock","North Hartley","North Hickory","North Hill","North Hooper","North Huron","North Ione","North Jefferson","North Kenmore","North Kern","North Knollwood","North Lake Shore","North Laughlin","North Lenora","North Linden","North Lockewood","North Los Angeles","North Macarthur","North Malden","North Margaret","North Martingale","North Mc Intire","North Meadow","North Micke Grove","North Milpitas","North Monroe","North Mountain","North Nassano","North North Ripon","North Old Hicks","North Orenda","North Palmer","North Patterson","North Pearson","North Pilgrim","North Plaza","North Pond","North Pythian","North Rand","North Regatta","North Ridge Vista","North River","North Rohlwing","North Russell","North San Joaquin","North Scenic","North Serven","North Shetland","North Sierra Nevada","North South","North Sun","North Sycamore","North Tenter","North Tolman","North Tully","North Vale","North Veach","North Viola","North Wall","North Water","North West Arm","North White","North Williamson","North Winston","North Woodford","North de Anza","Northall","Northbay","Northbrook Court","Northcoate","Northcross","Northeast Alder Crest","Northeast Arrowhead","Northeast Berry","Northeast Brackenwood","Northeast Carriage","Northeast Crescent","Northeast Discovery","Northeast Ewing","Northeast Glavin","Northeast Hawthorne","Northeast Husky","Northeast Joshua Tree","Northeast Kennedy","Northeast Koura","Northeast Lofgren","Northeast Manor","Northeast Meigs","Northeast Morning","Northeast Norton","Northeast Paulanna","Northeast Puget Bluff","Northeast Richardson","Northeast Seaview","Northeast Sunset","Northeast Victorian","Northeast Windermere","Northeastern","Northern Fences","Northern Spy","Northfront","Northhurst","Northlea","Northolm","Northpoint","Northstar","Northvale","Northwest","Northwest Elford","Northwest Inneswood","Northwest North Beach","Northwest Sammamish","Northwinds","Norton","Norumbega","Norwalk","Norwood High","Nostaw","Notch Hill","Nothing","Notthumberland","Nottoway River","Nova","Novello","Nowers","Noyna","Nueva","Null","Number One First","Nuneham","Nunnery","Nunthorpe","Nurney","Nurserymans","Nutberry","Nutgrove","Nutshell","Nutty","Nuxley","Nydam","Nylan","Nyngan","O Connell","O Hanneson","O Malley","Oadby","Oak Bluff","Oak Canyon","Oak End","Oak Glen","Oak Hill","Oak Lea","Oak Meadow","Oak Park","Oak Rock","Oak Shores","Oak Tree Farm","Oak Wood","Oakbrook Estates","Oakdale Farm","Oakenclough","Oakes","Oakfold","Oakhanger","Oakknoll","Oakland Terrace","Oakledge","Oakman","Oakmont Plaza","Oakridge","Oakshaw","Oakton Glen","Oakvale","Oakways","Oakwood Knoll","Oast","Oatfield","Obama","Obermueller","Obrad","Ocala","Occoquan Forest","Ocean Hill","Oceanic","Ocho Rios","Ockham","Octagonal","Oday","Odell","Odessa","Odney","Oelsner","Off Cherry","Off Musterfield","Off Westford","Offham","Offord","Ogburn","Ogla","Ohanneson","Ohlone","Ojibway","Okehurst","Okst","Old","Old Altos","Old Archer","Old Ayer","Old Barns","Old Battery","Old Bayside","Old Bell","Old Bethpage","Old Bix","Old Bolton","Old Branch","Old Brick","Old Brooks","Old Bury Lodge","Old Campbell","Old Carriage","Old Cañada","Old Centreville","Old Chatham","Old Chestnut","Old Cistern","Old Coach","Old Colony Cove","Old Corona","Old Cow Pasture","Old Crow Canyon","Old Davis","Old Denville","Old Doctors","Old Dublin","Old East","Old Elmdale","Old Esher","Old Falls","Old Fence","Old Fleet","Old Forge Hill","Old Frank Tippett","Old Furnace Colony","Old Georgetown","Old Glenview","Old Grand","Old Greenville","Old Half Day","Old Harter","Old Hazel Dell","Old Hickory","Old Hillary","Old Home","Old Horner","Old Hunt","Old Ironsides","Old Jericho Park","Old Keene Mill","Old Killam Hill","Old Kirker Pass","Old Lakes","Old Las Palmas","Old Leominister","Old Linslade","Old Log","Old Mac Donald","Old Mamaroneck","Old Marsh","Old McHenry","Old Merlins","Old Mill Pond","Old Montague","Old Mount","Old Muirkirk","Old Nazeing","Old Newland","Old Northport","Old Ocean","Old Ox","Old Palisade","Old Parrin","Old Pearson","Old Philadelphia","Old Pittwater","Old Plum Point","Old Post","Old Princeton","Old Quarterfield","Old Rancheria","Old Redmond","Old Reston","Old Riva","Old Rockford","Old Ruislip","Old Samuel","Old Sanford","Old Schaumburg","Old Shade","Old Shore","Old Sleigh Hill","Old Soar","Old South Highland","Old Springfield","Old Summer","Old Taren Point","Old Thieves","Old Tovil","Old Tree","Old Tyler","Old Vic Theatre","Old Wallgrove","Old Washington","Old Weiland","Old West Main","Old Wheatley","Old Wickford","Old Wilmot","Old Wokingham","Old Woolwich","OldField","Oldchurch","Olde English","Olde Lantern","Olde Towne","Oldfield","Oldmill","Oldway","Oleander","Olf Fold","Oliva","Olive Lee","Oliveleaf","Oliveswood","Olivier","Olley","Olmi Landrith","Olney Mill","Olson Farm","Olyffe","Omaban","Omaru","Omira","Onamog","One Bridge","One Palace","Oneata","Oneto","Onondaga","Ontario Bay","Oorin","Opatrny","Openshaw","Oping","Opus","Oram","Orange Grove","Orangeburgh","Oratam","Orbain","Orchard","Orchard Creek","Orchard Hill","Orchard Park","Orchard View","Orchis","Ordsall","Orehr","Oreta","Oriana","Oriental","Orinoco","Oritan","Orland Woods","Orloff","Ormbrek","Ormonde","Ormside","Oro","Orphanage","Orral","Orrishmere","Orson","Ortona","Osage","Oscecla","Osiers","Osmer","Oso","Osseo","Ostade","Osterly","Oswald","Otay","Otis","Otlinge","Ottaway","Otterbourne","Otterspool","Ottways","Oulton","Oursler Park","Outer Loop","Outing","Outrigger","Ouzlewell Green Green","Ovens","Overbeck","Overcrest","Overfield","Overion","Overleigh","Overmoor","Overview","Owasco","Owen Brown","Owens Lake","Owl Harbor Levee","Owles","Owna","Ox Hunt","Oxbow Creek","Oxenbridge","Oxenpark","Oxford Square","Oxley","Oxnard","Oxshott","Oyster Creek","Ozone","Paarl","Pacella","Pacific","Pacifiv View","Packer","Packman","Paddack","Paddle Wheel","Paddocks","Paderewski","Padre","Padwell","Page","Pagebrook","Pagitt","Paice","Paines Brook","Painted Daisy","Painters","Pajaro Hills","Palace","Paladini","Palawan","Paley","Palisades","Palladio","Palm Beach","Palm Spring","Palmdale","Palmer Ranch","Palmerston","Palmieri","Palmwood","Palo Vista","Palos","Palwaukee","Pambula","Pamper","Panama","Pandora","Panjon","Pannonia","Panshanger","Panther Ridge","Panzano","Papago","Paper Mill Creek","Papoose","Paprota","Parade","Paradise Valley","Parallel","Parbury","Parcot","Pardoner","Parfett","Paris Oaks","Park Barrington","Park Corner","Park End","Park Gardens","Park Headquarters","Park Knoll","Park Mills","Park Point","Park Sharon","Park Tree","Park Wilshire","Parkcroft","Parker Creek","Parkers Grove","Parkfield","Parkhaven","Parkington","Parklands Close  Lynn","Parkmeadow","Parkrow","Parkston","Parkview","Parkways","Parley","Parmal","Parmoor","Parnoo","Parquet","Parrett","Parrot","Parsells","Parson Hill","Part","Parting Rock","Partridge Berry","Parvin","Pasas","Pasedena","Paseo Robles","Paso Corto","Passaconaway","Passalaqua","Passy","Pastori","Pasturewood","Patch","Patemore","Pates","Pathwood","Patley","Patocchi","Patricia","Patriot Square","Patshull","Patterdale","Patterson Ranch","Patty","Patuxent Woods","Paul Gore","Paul Revere","Paula Lynn","Paulhan","Paullus","Pauly","Pauw","Pavilion","Pawlet","Paxos","Payle","Payran","Peace","Peaceful Ridge","Peach Leaf","Peachland","Peacock Farm","Peake","Peale","Pearblossom","Pearl Bay","Pearlman","Pearsall","Pearwood","Peat Bog","Pebble Canyon","Pebbleford","Pebmarsh","Peckford","Peckover","Pecos","Pederson","Pedroni","Peel","Peer","Peg","Peggy","Pegwood","Pekin","Pelham Island","Pelican Point","Pellant","Pellings","Pelorus","Pemba","Pembridge","Pembury","Pena","Penataquit","Pencroft","Pendas Way Barwick","Penderbrooke","Pendle","Pendragon","Penefield","Penfield","Penhallow","Peninsula","Penitencia Creek","Penley","Penn Crossing","Penndale","Penngrove","Pennington","Pennock","Pennsylvania","Penny Meadow","Pennyfather","Pennypacker","Penprase","Penrose","Pensbury","Penswick","Pentlow","Pentreath","Penwerris","Peony Place","Peperharow","Pepper Creek","Pepperbox","Peppermill","Pepple","Pequot","Perch Lake","Percypenny","Peregrine White","Pergola","Perigo","Perita","Perkiomen","Permanent","Perouse","Perreira","Perrie","Perrone","Perry Vale  Woolstone","Perrymead","Perrywinkle","Pershore","Personette","Perwell","Pested","Petar","Peter A McCuen","Peter Hans","Peter Parley","Peterhouse","Peters Spring","Peterson","Petite Creek","Petrell","Petrolia","Petters","Pettis","Petzold","Pevril","Peyla","Pfeiffer","Phaeton","Phar Lap","Pheasant Fields","Pheasant Run","Pheby","Phesant","Philbrick","Philip Howard","Phillimore","Phillipp","Phillips Park","Philo","Phipps","Phoenix","Phyldan","Physics Ellipse","Pibrock","Piccoli","Pickard","Pickersgill","Picketts Lock","Pickpocket","Picnic","Picton","Pidgeon Hill","Pield Heath","Pierce Hill","Pierino","Pierrepoint","Pierson Miller","Pig Rock","Pigeon Hollow","Pigs Eye Lake","Pike Ridge","Pikkney","Pilcher Park","Pilgrim Hill","Pilling","Pillowlace","Pilsen","Pimhole","Pin Cushion","Pincherry","Pindell","Pine Arden","Pine Crest","Pine Hill","Pine Lake","Pine Needles","Pine Spring","Pine Valley","Pineapple","Pinecote","Pinefield","Pineknot","Pinemont","Pineridge","Pinevale","Piney Branch","Piney Point","Pingate","Pink","Pinkneys","Pinner  Love","Pinole","Pinrock","Pinto Trail","Pioneer Hills","Piper Ridge","Pipit","Pippo","Pirton","Pissaro","Pitcairn","Pitfold","Pits Farm","Pitstock","Pittman","Pittson","Pix Farm","Pizzorni","Placer Oaks","Plaid","Plainville","Plan","Planetree","Plante","Plash","Plassy","Platform","Platt House","Plattsdale","Plaxdale Green","Players Pond","Playstool","Pleasant Chase","Pleasant Grove School","Pleasant Oaks","Pleasant View","Pleasantview","Pleasure Pit","Plenge","Plimpton","Plomer","Ploughley","Plucksbridge","Plum Grove","Plum Valley","Plumbley","Plumley","Plumpton","Plumtree","Pluth","Plympton","Pobgreen","Pochin","Poco","Podesta","Podsmead","Pogany","Poillon","Point Allerton","Point Hollow","Point Rider","Pointcross","Pointview","Poker","Polari","Pole Plain","Polehanger","Polhill","Polito","Pollards Oak","Polling House","Pollywog","Polonius","Polworth","Pombo","Pomerol","Pommeroy","Pomper","Ponce","Pond Crest","Pond Hill","Pond Ridge","Ponder","Pondholton","Pondwick","Ponkapoag","Pont","Pontos","Ponytail","Pool Bank New","Pooles","Poona","Pootings","Popeley","Popkins Farm","Poplar Grove","Poplarhollow","Poppenhusen","Poppy Glen","Poppyseed","Porcaro","Porlock","Port Capital","Port Hill","Port Reading","Port Victoria","Porte de Leau","Porter Plain","Porters Hill","Porthole","Portledge","Portmore","Porto Rico","Portola Meadows","Portsea","Portville","Posen","Possehl","Post Forest","Post Office Delce","Postmill","Potassium","Potee","Potomac Creek","Potomac Manors","Potomac Ridge","Potomack","Potsdam","Potternewton","Pottingfield","Poulet","Poultney","Poundhurst","Poverest","Powder Horn","Powdermill","Powells Creek","Power Ridge","Powis","Poydras","Poynter","Pozzan","Prado Vista","Praire","Prairie Creek","Prairie Grass","Prairie Moon","Prairie Schooner","Prairieside","Prancing Deer","Pratling","Pratts Mill","Preble","Predella","Premisy Hill","Prentis","Presdales","Presidente","Press","Prested","Preston White","Preswicke","Prey Heath","Pride","Pridham","Priest Park","Priestnall","Prime","Primrose","Prince Charles","Prince Edwards","Prince Lake","Prince of Wales","Princess","Princess May","Prince’s","Printemps","Prinys","Priors Hatch","Priory Park  Lee","Prison","Privateer","Probst","Produce","Profumo","Promintory","Propp","Prospector","Prothero","Provender","Provincetown","Pru","Prune Tree","Prybyl Pond","Pryors","Public Safety","Pudding Cake","Puddon","Puerto Vallarta","Pukwans","Pulham","Pulley","Pulsifer","Pumphrey","Punch Copse","Purbeck","Purdie","Purfield","Purissima","Purleigh","Purnell","Purpleleaf","Purton","Putah Creek","Putnams","Puttenden","Puves","Pyenot Hall","Pymmes Green","Pyott","Pyrite Mine","Pyrossia","Quaas","Quadra","Quail Canyon","Quail Lakes","Quail Vista","Quaint","Quaker Meeting House","Quality","Quanders Promise","Quantico","Quarles","Quarry Arm","Quarry Pond","Quarter Sessions","Quartermaine","Quartz","Quayle","Queen Anne","Queen Eleanors","Queenbeyan","Queens Chapel","Queens Row","Queensburg","Queensguard","Queenston","Queensway Tennyson","Quema","Quercus","Quetta","Quickedge","Quien Sabe Ranch","Quiet Oak","Quiet Woods","Quilberry","Quilters","Quince Mill","Quincy","Quinlisk","Quinsenberry","Quintard","Quintree","Quirnia","Quist","Quoitings","R. Belanger","Rabans","Rabbits Run","Rabro","Racecourse","Rachell","Rackstraw","Raday","Radclyffe","Radford","Radison","Radley","Radnor","Radwinter","Raemore","Rafferty","Rafter Ridge","Ragged Hill","Rago","Rahn","Raiff","Railroad Seekonk","Rain Cloud","Rainbow Ridge","Raines","Rainsboro","Raintree","Rak","Raleana","Rally","Ralph Mann","Ralwood","Ramapo Brae","Rambler Rose","Rambling Woods","Ramgren","Ramney","Rampayne","Ramscote","Ramsey Main","Ramstree","Rances","Ranchito","Rancho Cabeza","Rancho Laguna","Rancho Ramon","Rancho Ventura","Rancom","Randall Ridge","Randle","Random","Randwick","Ranford","Rangely","Rangeway","Rankine","Ranmore","Ransell","Rantoul","Rapid","Rapp","Raquel","Raskin","Rassani","Ratcliffe","Rathbone","Rathlin","Rattle Snake Hill","Raub","Raveley","Ravendale","Ravenoak","Ravensbourne","Ravensdon","Ravenue","Ravinia","Rawcliffe","Rawling","Rawson","Ray","Ray Moses","Rayborn Creek","Raydons","Rayleigh","Raymead","Raymundo","Raynes","Rayshire","Read Head","Reading Hill","Reagan","Reamer","Rear Wildwood","Reavis","Rebeiro","Recard","Recovery","Recycle","Red Ash","Red Branch","Red Cherry","Red Cow","Red Fall","Red Ground","Red Hills","Red Lion  Lower","Red Oak Service","Red Robin","Red Sky","Red Wood","Redbournbury","Redcar","Redcoat","Redden","Reddington","Rede Court","Redfern","Redhall","Redhouse","Redlands","Redmere","Redmyre","Redoubt","Redrose","Redstock","Redvers Buller","Redwood Gulch","Redwood Terrace","Reed Hall","Reedham","Reedsfield","Reef Point","Reeves","Refugio Valley","Regan","Regency Crest","Regency Woods","Regentwood","Regional Center","Regulus","Reichelt","Reidhaven","Reiger","Reiling","Reims","Reiners","Reinking","Reising","Reitz Lake","Reliance","Relyea","Remenham Church","Remital","Remsen","Renaissance","Rendall","Renfro","Renken","Rennell","Reno","Renshaw","Renway","Reon","Reposo","Republican","Reseau","Reservoir Heights","Resolution","Restharrow","Retford","Retta","Reubens","Reva","Revelstoke","Revere Beach","Revie","Revolutionary","Rexburg","Reycraft","Reyna","Reyner","Rfd Coach","Rhame","Rhine","Rhiwlas","Rhodenda","Rhodora","Rhosleigh","Rhyl","Ribble","Ribier","Riccardo","Rice Lake","Rich Branch","Richard House","Richardi","Richdale","Richgar","Richlee","Richmond Meech","Richton","Rickbern","Ricketts Hill","Rickmansworth","Rico","Riddings","Riden","Ridge","Ridge Croft","Ridge Hill Farm","Ridge Retreat","Ridgecrest","Ridgegate","Ridgelee","Ridgemoor","Ridgevale","Ridgewind","Ridgwell","Riding Hood","Ridley","Riedell","Rienzi","Riffams","Rifle Range","Rigden","Rigg","Righters Mill","Rigoli","Riker Hill","Rillo","Rimer","Rimrock","Rincon","Rinear","Ringcroft","Ringfield","Ringley Park","Ringo","Ringwood","Rio Altos","Rio Linda","Rio Robles","Rio del Mar","Ripleys Field","Ripple","Rippling Branch","Ripponden Nursery","Riscioni","Risel","Rishworth New","Risingholme","Rissington","Ritchie","Ritson","Rival Moor","Riven Wood","River Bay","River Creek","River Farm","River Heights","River Meadow","River Point","River Swan","River Village","Riverband","Riverby","Riverfield","Riverland","Rivermist","Rivers","Riversdown","Riverside Run","Rivertowne","Riverwood Terrace","Rivoli","Rixson","Roach","Roald","Roaring Creek","Robak","Robbern","Robby","Robert Best","Robert F Toner","Robert J Mathews","Robert Mays","Robert T Palmer","Roberts Common","Robertson","Robie Manor","Robin Hood","Robinette","Robinsbridge","Robinwood","Roble Ridge","Robley","Robscott","Robyn","Rocca","Roche","Rochford","Rock Chapel","Rock Fish","Rock Island","Rock Oak","Rock Springs","Rockaway Point","Rockcliff","Rockenbach","Rockhall","Rockie","Rockland","Rockliffe","Rockmount","Rocks","Rockstrech","Rockwin","Rocky Brook","Rocky Hills","Rocky Point","Rocky Top","Rocton","Rodbridge","Rodens","Rodiman","Rodmere","Rodrigues","Roe Downs","Roeder","Roemer","Rofay","Rogan","Roger Williams","Rogers Wood","Rohavic","Rohrer","Roke Lodge","Rokewood","Roleen","Roll","Rolling","Rolling Hill","Rolling Oak","Rolling Views","Rollingwood","Rolls Park","Rolt","Romaine","Romanhurst","Rombalds","Romero","Romilly","Romney","Romsey","Ronaele","Ronarm","Rondel","Ronek","Ronnie","Ronzheimer","Rookery","Roome","Rooster","Ropemaker","Roppolo","Rosa","Rosada","Rosalita","Rosaria","Roscoe","Rose Ann","Rose Bush","Rose Glen","Rose Marie","Rose Vine","Rosebay","Rosebridge","Rosecourt","Rosedene","Rosegarth","Roseheath","Roseleaf","Rosemar","Rosemill","Rosenau","Rosenfield","Roseridge","Roseveare","Roseway","Rosie Lee","Rosincress","Roslyn","Ross Arnold","Ross Smith","Rossefield","Rossetti","Rosskelly","Rossmore","Rostella","Rosyman","Rothbard","Rotherbridge","Rotherhithe Old","Rothiemay","Rothwell Commercial","Rotunda","Rougemont","Roughtley","Round Barn","Round Lick","Round Tree","Roundhay","Roundstone","Rounsevell","Rousebarn","Rovato","Rowalt","Rowanswood","Rowbotham","Rowe","Rowhill","Rowlett","Rowling","Rowser","Roxann","Roxbury","Roxton","Royal Ann","Royal Coach","Royal Dane","Royal Forest","Royal Heights","Royal Mint","Royal Porthcawl","Royal Tern","Royal Worlington","Royalthorn","Roycroft","Royds Farm","Roys","Rozella","Rubens","Rubino","Ruby Hill","Ruckmans","Ruddpark","Rudolf","Rueben","Ruffin","Rufus Isaacs","Rugg","Ruins","Ruland","Rumbles","Rumple","Run Common","Rundle","Runkenhage","Running Creek","Running Pump","Runswick","Rupack","Ruritan","Rusden","Rush Meadow","Rushbrooke","Rusher","Rushing Creek","Rushmoor","Rushwood","Rusland Park","Russell","Russell Zepp","Russet Wood","Russian River","Rusthall","Rustic View","Rustling Oak","Rutgers","Ruthelma","Rutherwyk","Rutledge","Rutz","Ryan","Rybrook","Rydall","Ryder Hill","Rydon","Rye Mill","Ryegate","Ryer Island","Ryfold","Rylands","Rymer","Ryrie","Rywick","S Adams","S Airlite","S Allport","S Anna Marie","S Arbor","S Arlington Mill","S Ashby","S Avon","S Balmoral","S Barton","S Bedford","S Bellows","S Benson","S Beulah Vista","S Birkhoff","S Blaisdell","S Blue Water","S Boston","S Bragg","S Brennan","S Briggs","S Brook","S Bruner","S Burnett","S Butler","S Canal","S Carillon","S Carondolet","S Cathedral","S Center","S Channing","S Chatham","S Chester","S Chippendale","S Claire","S Clifton","S Coghill","S Columbus","S Consumers","S Corliss","S Country Club","S Cowley","S Creighton","S Crystal","S Damen","S Day","S Deep Lake","S Demarest","S Des Plaines","S Dinwiddie","S Donna","S Dow","S Dunmoor","S Eagle","S Eberhardt","S Edgewood","S Ela","S Elliott","S Elodie","S Erie","S Evanslawn","S Fairfield","S Farnsworth","S Fern","S Fish Lake","S Forest","S Fox Wood","S Freemont","S Fullerton","S Gary","S Georgia","S Glasgow","S Glover","S Grace","S Great Neck","S Greenbrier","S Griffith","S Hager","S Hamlin","S Harlem","S Hartshorne","S Hawthorne","S Heathcote","S Henry","S Hickory","S Hillcrest","S Hobble Bush","S Holmdel","S Hough","S Hudson","S Ilwaco","S Inman","S Ivy","S Jeffery","S John","S Julian","S Kaspar","S Keeler","S Kenneth","S Ketay","S Kimberly","S Knight","S Komensky","S Lady Bar","S Lake Shore","S Lanza","S Lawn","S Leclaire","S Lerisa","S Liberty","S Linden","S Lloyd","S Long","S Lord","S Loucks","S Lumber","S Lytle","S Main","S Manistee","S Marathon","S Martin","S Maxon","S McCorkle","S Meader","S Melvina","S Merrion","S Middle Point","S Millard","S Minnesota","S Monaghan","S Moody","S Mount Curve","S Munn","S Nancy","S Navajo","S Newberry","S Nordica","S Noyes","S Oak River","S Oakley","S Ohio","S Old Rand","S Oregon","S Oxford","S Park Place","S Patrick","S Pearl","S Peoria","S Phelps","S Pine Grove","S Plantation","S Point","S Poplar","S Prescott","S Pulaski","S Quinn","S Randolphville","S Rea","S Regina","S Richard","S River","S Robert","S Robinson","S Root","S Rowell","S Rush","S Sage","S Sarah","S Schoolhouse","S Seebert","S Seymour","S Shields","S Skidmore","S South Chicago","S Spaulding","S Suffolk","S Susan","S Talman","S Terrace","S Thurlow","S Town Center","S Truro","S University","S Van Buren","S Varner","S Victoria","S Vincent","S Wabash","S Wallace","S Warner Bridge","S Water","S Waverly","S Weiler","S West","S Westview","S White Oak","S Will Center","S Willow Creek","S Windham","S Winthrop","S Woodbine","S Woodrow","S Wynstone Park","S la Londe","SE Delaware","SW Centennial","Sabal","Sabine","Sabre","Sacia","Saco","Sacred Palm","Saddle Creek","Saddle Ridge","Saddlebred","Sade","Sadlier","Safari","Sagamore","Sage Brush","Sagemont","Sagittarius","Saidel","Sailors Bay","Saines","Saint Alban","Saint Ann","Saint Augustine","Saint Bernard","Saint Casimir","Saint Clair","Saint David","Saint Edwards","Saint Francis","Saint Gregory","Saint Ives","Saint Joseph","Saint Lawrence","Saint Margarets","Saint Mathews","Saint Nicholas","Saint Peter","Saint Regis","Saint Thomas","Saint Winifreds","Sajak","Sakura","Salamander Canyon","Salcote","Salem Lake","Salford","Salisbury Downs","Sallaway","Salmar","Salomons","Salt Hill","Saltcoats","Salthill","Saltpan","Saltzman","Salvi","Sam Cava","Sam Smith","Samaritan","Samnatha","Sample","Samuel","Samuel Ogden","Samuels","San Andres","San Blas","San Domar","San Gabrial","San Jaun Canyon","San Juan Pass","San Luis","San Marin","San Miguel Canyon","San Pedro","San Remo","San Simeon","San Ysidro","Sanches","Sand Beach","Sand Harbour","Sand Prairie","Sandakan","Sandbed","Sandcastle","Sandell","Sanders Ranch","Sandford","Sandhills","Sandifer","Sandiway","Sandmere","Sandpiper Cove","Sandraya Heights","Sandrock Hill","Sandstone","Sandwood","Sandy Cove","Sandy Knoll","Sandy Ridge","Sandyford","Sanfoin","Sangmeister","Saning","Sano","Sanspareil","Santa Clara","Santa Juanita","Santa Paula","Santa Teresa","Santapogue","Santiago","Santorini","Sanwood","Sapphire Ridge","Saraglen","Sarah Landing","Saranap","Saratoga Sunnyvale","Sard","Sargent Roode","Sarnesfield","Sarsby","Sartwell","Satanita","Satinwood","Satuckett","Sauerbacker","Sauls","Saunder","Sausal","Savana","Saverien","Savin","Savoury","Sawell","Sawmill Brook","Sawyer Hill","Saxon","Saxons","Saybrooke Oaks","Sayles","Sayward","Scaggsville","Scallows","Scandrett","Scarab","Scardenia","Scarlet","Scarr End","Scaup","Scenic Meadow","Scenicwood","Schadeck","Schalk","Scharer","Scheffelin","Schellville","Schenk","Scherwood Greens","Schiele","Schimmel","Schlapp","Schlictman","Schmeidt","Schnecks","Schoen","Schoger","Scholer","Schomer","School House","Schoolhouse Cove","Schoonmaker","Schraalenburgh","Schriber","Schrum","Schuler Ranch","Schum","Schussler","Schwartz","Scibilia","Sciots","Scoles","Scorpion","Scotch Dam","Scotdale","Scotland Mill","Scotswood","Scott Robin","Scottish Rite","Scotts Hall","Scottsbury","Scoulding","Scouts Camp","Scrapsgate","Scriber Lake","Scriven","Scroxton","Scudder","Scutley","Sea Chase","Sea Horse","Sea Point","Sea Vista","Seaborne","Seabury","Seacrest","Seaforth","Seagrove","Seahorse","Seale","Seaman","Seamons","Searbrook","Searing","Sears Point","Seaside","Seaton","Seavy","Seawood","Sebastiani","Sebring","Secluded Oaks","Secor","Secretan","Sedding","Sedgecombe","Sedgemoor","Sedgley Park","Sedrup","Seedley","Seel","Seemore","Sefton","Segrove","Seidman","Seitz","Selborne","Seldon","Selger","Selkirk","Sells","Selmon","Selsfield","Selvyn","Semaphore","Seminole","Sempill","Senator","Seneca","Senecal","Senn","Senrab","Sephton","Sequdia","Sequoia Ridge","Seramonte","Serenite","Serge","Sergison","Serpentine","Serres","Sesame","Seth","Sette","Settlers","Seurat","Seven Crest","Seven Pines","Sevenoake","Severance","Severn River","Severus","Sevor","Sewardstone","Sexburga","Seymer","Sgt Beers","Shacklands","Shad Creek","Shaded Leaf","Shadle","Shadow Falls","Shadow Oak","Shadow Wood","Shadowridge","Shady Arbor","Shady Glenn","Shady Nook","Shady Ridge","Shady Willow","Shadyside","Shaffer","Shaftsbury","Shainy","Shakleton","Shale Peak","Shalimar","Shallow Creek","Shamley","Shand","Shane Park","Shanklyn","Shannon Oak","Shardcroft","Sharilyn","Sharman","Sharon Chapel","Sharp Park","Sharpley","Sharrington","Sharvel","Shattuck Park","Shaves Wood","Shaw William","Shawe","Shawmont","Shawno","Shea","Shean","Shears","Sheckells","Sheen Common","Sheepcoates","Sheepfold","Sheepridge","Sheering Mill","Sheets Farm","Shelard","Shelby Dale","Sheldon Oaks","Shelgate","Shell Valley","Shelley","Shellow","Shelter Bay","Shelterwood","Shenleybury","Shenwood","Shephard","Shepherds Gate","Shepperton Court","Sheraton","Sherbrooke","Sheredes","Sheridans","Sherland","Sherman Island Levee","Sherrardspark","Sherringham","Sherwin","Sherwood Park","Shetlands","Shewtan","Shienfield","Shillingford","Shin","Shinglebarn","Ship Rock","Shiplett","Shippen","Shipsview","Shira","Shiregreen","Shirlawn","Shirley Murphy","Shirra","Shoalhaven","Shoe Factory","Shogmoor","Shone","Shootersway","Shoppers World","Shore Acres","Shore View","Shorefront","Shoreland","Shoreview","Shorland","Short Cut","Shortcross","Shortland","Shortway","Shotkoski","Shottfield","Shovelstrode","Shremor","Shrive","Shrubbs","Shuck","Shumard Oak","Shurland","Shutt","Siandra","Sibley Hills","Sicily","Sicora","Siddon","Sidehill","Sidley","Siebel","Siegmond","Sienna Park","Sierra Gold","Sierra Oaks","Sierra Sunset","Siesta","Sigel","Signal","Signs","Silacci","Silbury","Silent Dell","Silerton","Silk Oak","Silktree","Silo","Silva","Silveira","Silver Brook","Silver Dollar","Silver King","Silver Linden","Silver Peak","Silver Royd","Silver Spur","Silverbend","Silverdell","Silverland","Silverod","Silverthorne","Silvervine","Silvio","Simbroco","Simla","Simmonstone","Simon Lake","Simone Weil","Simpkin","Simpson Ranch","Sinawoy","Sindle","Singers Glen","Single Foot","Singlewell","Sinvalco","Sir Alexander","Sir George Martin","Sir Richard Fairey","Siracusa","Sirius Cove","Sisk","Sister Cities","Sivert","Six Towers","Skaggs Springs","Skate","Skegsbury","Skellow","Skerry","Skiba","Skiers","Skimped Hill","Skipjack","Skokie","Sky","Sky Hill","Sky Top","Skyfarm","Skylane","Skyline Quarry","Skyswood","Skyward","Slack Fold","Slade Oak","Slagle","Slapton","Slatin","Slayton","Sleepers Farm","Sleepy Lake","Slessor","Slim","Slipe","Sloanes Beach","Sloop","Sloughgreen","Sly Fox","Small Grove","Smalldean","Smallshill","Smarts Mill","Smee","Smidmore","Smith Field","Smith Valley","Smithies Moor","Smithville","Smithy Fold","Smokehouse","Smokey Ridge","Smull","Snailswell","Snapper","Snead","Sneider","Snelson","Snoden","Snoqualmie","Snow Egret","Snowball","Snowden Pond","Snowdrift","Snowhill Estates","Snowy Owl","Snyder","Soares","Sobraon","Soda Canyon","Sofala","Softwood","Sojourn","Solar","Solbys","Soledad","Soliano","Sollers Point","Soloman","Solono","Soma","Somerford","Somerset","Somme","Somoa","Sondes Place","Sonn","Sonoma","Sonter","Sophie","Soquel Creek","Sorel","Sorich","Sorrelwood","Soscol","Sotherington","Souberie","Soult","Soundside","Souter","South Acre","South Alaska","South Americus","South Ash","South Avalon","South Bar","South Bayard","South Bedford","South Betty","South Bolingbrook","South Boylston","South Bridgepointe","South Brush","South Byron","South Carboy","South Carriage Way","South Chappell","South Circle","South Clovercrest","South Columbus","South Cooper","South Court","South Crestwood","South Dakota","South Dedham","South Dike","South Douglas","South E","South Edgewood","South Eliseo","South Emerald Oak","South Estelle","South Fairmont","South Fernandez","South Fitch Mountain","South Fox","South Fresno","South Furness","South Genesee","South Glendale","South Grant","South Grimmer","South Ham","South Harlan","South Hartson","South Helena","South Hill","South Holgate","South Horton","South Humboldt","South I Oka","South J","South Juneau","South Kennbeck","South Keppler","South Knollwood","South Lake Sarah","South Langston","South Leigh","South Liberty","South Lockhart","South Lorna","South Macarthur","South Mallard","South Marina","South Mason","South Mc Kinley","South Mead","South Michael","South Mills","South Monroe","South Morrison","South Naperville","South Netherton","South Norman","South Oleander","South Ordnance","South Palisades","South Park Victoria","South Pearl","South Peter","South Platti","South Ponderosa","South Prentice","South Queen","South Rancho","South Reeve","South Richard","South Riverside","South Rolling","South Royd","South Sacramento","South San Mateo","South Sequoia","South Shelton","South Side Three","South Southern","South Sprucewood","South Susan","South Tea Garden","South Three Oaks","South Tracy","South University","South Van Horn","South Vincennes","South Walker","South Warren","South Weller","South Whippoorwill","South Wildwood","South Willow","South Wing Levee","South Woodsbro","South del Puerto","Southam","Southboro","Southcenter","Southcreek","Southeast Allen","Southeast Carr","Southeast Culver","Southeast Fairwood","Southeast John","Southeast May Valley","Southeast Perimeter","Southeast Sedgwick","Southeast Willock","Southerland","Southern Hills","Southern Perimeter","Southfield","Southglen","Southlake","Southleigh","Southold","Southshore","Southwater Point","Southwest Andover","Southwest Bruce","Southwest Channon","Southwest Cove Point","Southwest Donovan","Southwest Englewood","Southwest Francis","Southwest Hanford","Southwest Holden","Southwest Juneau","Southwest Luana Beach","Southwest Morgan","Southwest Oregon","Southwest Seattle","Southwest Sullivan","Southwest Waite","Southwick","Southwoods","Soward","Spa","Spagnoli","Spanby","Spanish Flat Loop","Spanker","Sparhawk","Sparks","Sparrow House","Spartan","Sparvell","Spaulding","Spearing","Speckled Wood","Speedwell","Speke","Spen","Spencer Place Leopold","Spenney","Sperling","Sphinx","Spicer","Spiker","Spinale","Spinks","Spinney","Spirea","Spitfire","Split Creek","Spock Ridge","Spoil","Sponson","Spoonger","Sportsman","Spotted Gum","Sprain Brook","Spray","Spreighton","Spring Bottom","Spring Creek","Spring Garden","Spring Hill","Spring Lakes","Spring Mountain","Spring Saw","Spring Villa","Springbluff","Springdale Estates","Springfield Village","Springhollow","Springle","Springmont","Springsguth","Springvale","Springwood Hall","Sproul","Spruce Mill","Sprucewood","Spur","Spurgrove","Spurt","Spyglass Cove","Squam Hill","Squash Creek","Squire","Squires Mill","Squirrels Heath","St Alban","St Andrew Bethune","St Anselms","St Austall","St Benedict","St Brannocks","St Celcilia","St Clement","St Cuthberts","St Dunston","St Ethelbert","St Giles High","St Helier  Furness","St James","St Joseph S","St Keverne","St Lo","St Margarets","St Mary S","St Michael","St Nazaire","St Patrick","St Peter","St Regis","St Swithins","St Vincents","St. Agnes","St. Barnabas","St. Christopher","St. Dunstans","St. Helens","St. Katherine","St. Martins","St. Nazaire","St. Philip","St.Albans","St.Olaves","Stablebridge","Stacey Hills","Stackfield","Stadium","Stafford","Stagbury","Stagehand","Stags Run","Stainbeck","Stainforth","Stair Foot","Stake","Staley","Stall","Stalyhill","Stamford Park","Stanage","Stanbrook","Stancross","Standford","Standpipe","Stanford Oak","Stanhope","Stanislaus","Stanley Hall","Stannard","Stansfield","Stanton Crossing","Stanwick","Stapelton","Staples Ranch","Star Farm","Star Point","Starbrook","Starcross","Starflower","Starlight","Starmead","Starr View","Starters","Starwood","State Park","Statesman","Station House","Statute","Staverton","Stayton","Steamboat","Stearton","Stedman","Steel Creek","Steele Resort","Steep Hill","Steeplechase","Steere","Steffan","Stehlin","Steinberg","Steity","Stellman","Stemmler","Stenhouse","Stephan Marc","Stephendale","Stepney High","Sterling","Sterling Oaks","Sternhall","Stetson Shrine","Stevedore","Stevens Canyon","Stever","Stewards Green","Steynton","Stickland","Stierlin","Still","Still Water","Stillings","Stillspring","Stiltner","Stingray","Stirgess","Stites Hill","Stock","Stockburn","Stockett","Stockholm","Stockley","Stockton Tees","Stodart","Stoecker","Stoke Poges","Stokesheath","Stompond","Stone Bridge","Stone Cliff","Stone Hall","Stone House","Stone Oak","Stone Quarry","Stone Trail","Stonebriar","Stonechat","Stonecress","Stonedge","Stonehall","Stonehedge","Stonehurst","Stoneleigh Park","Stoneness","Stones","Stonesteads","Stonewall Farm","Stoney","Stoney Creek","Stoney Run","Stoneycrest","Stoneyhurst","Stonny Batter","Stony Creek","Stony Ridge","Stonyridge","Storch","Stores","Storm","Storrington","Story Acres","Stott","Stovell","Stowell","Stradella","Straight","Strand Approach","Strangman","Strata","Strathalbyn","Strathdon","Strathmere","Stratos","Stratwood","Straw","Strawberry Park","Strayfield","Stream Wood","Streatham HillTelford","Street","Streethouse","Strenger","Stride","Stringer Dam","Strobridge","Stroman","Stronghurst","Stroude","Struan","Struttmann","Stuart Kaplan","Stub Toe","Stubbs Moor","Stuckler","Student Center","Studley Grange","Stulz","Sturdy","Sturl","Sturt","Styal","Styles","Substation","Sucher","Sudbrooke","Sudley","Sueno","Sufonet","Sugar Creek","Sugar Pit","Sugarland","Sugarplum","Suisun","Sulgrave","Sullivan","Sulphur Spa","Sumbray","Summer Duck","Summer House","Summer Oaks","Summer Sky","Summerbell","Summerday","Summerhouse","Summerlin","Summersby","Summerswood","Summerwood","Summit Hall","Summit Ridge","Sumner","Sumter","Sun Court","Sun Mor","Sun River","Sunbank","Sunbreeze","Suncast","Suncrest Hill","Sunderleigh","Sundown","Sunfish","Sunhills","Sunlea","Sunmeadow","Sunnings","Sunny Brow","Sunny Hills","Sunny Side","Sunnycrest","Sunnygate","Sunnymede","Sunnyslopes","Sunpark","Sunrise Hill","Sunrise Ridge","Sunseason","Sunset Maple","Sunshadow","Sunsprite","Suntone","Sunwich","Superior","Surada","Surface","Surplus","Surrey Circle","Surrey Water","Surtess","Susan Rosemary","Susie","Sussman","Sutherland","Sutter","Sutterton","Sutton Court","Sutton Wick","Suview","Suzzane","Swaim","Swainson Hawk","Swalecliff","Swallowdale","Swamp Circle","Swan Lake","Swandale","Swanley","Swanscoe","Swanston","Swarbrick","Swasedale","Swaynesland","Sweeney","Sweet Briar","Sweet Meadow","Sweetbirch","Sweetings","Sweetmeadow","Sweetwood","Swettenham","Swift Run","Swinborne","Swinebourne","Swingle","Swinton","Swithemby","Sword","Swyncombe","Sycamore Canyon","Sychem","Sydenstricker","Syke","Sylmar","Sylvan Heights","Sylvania","Sylvestris Fire","Symmes","Symphony","Syosset Woodbury","Syvestrus Fire","Taber Hill","Tabley Hill","Tabrett","Tack","Tacoma","Tadin","Tadworth","Tafts","Tahan","Tahoe Circle","Tailor","Taintor","Takeley","Talamore","Talbots","Taleeban","Taliaferro","Tall Cedars","Tall Shadows","Tallack","Tallawong","Tallgums","Tallow Tree","Talma","Talulah","Tamal Vista","Tamarack","Tamarix","Tamboura","Tamer","Tammanny","Tampa","Tan House","Tanbark","Tanderagee","Taney","Tangier","Tanglewylde","Tanhouse","Tankerville","Tannehill","Tannery","Tanridge","Tantani","Tanyard","Taos","Taplan","Tappan","Tappingo","Tara","Taradale","Tarban","Tarboro","Tareyton","Targo","Tarkin Hill","Tarleton Corner","Tarnapoll","Tarpey","Tarragon","Tarrogana","Tartan","Tartar","Tascan","Tassi","Tate Naylor","Tatra","Tattershall","Taubert","Tavan Estates","Tavestock","Tawny","Tay","Tayloe","Taylor View","Tayman","Tchapitoulas","Teaberry","Teakwood","Teaneck","Tebworth","Technology Center","Tecumseh","Teddington Gloucester","Tee","Teemer","Teeway","Teibrook","Tejas","Telegram","Telese","Telles","Telston","Temi","Temperley","Temple Hill","Templecombe","Tempo","Ten Gate","Tenaya","Tender","Tenham","Tennessee","Tenniswood","Tentelow","Tepper","Tercentennial","Terfidia","Terman","Terne","Terra Bella","Terra Loma","Terra Villa","Terrace Lake","Terramere","Terrazzo","Terreno de Flores","Terrigal","Terryellen","Tesla","Testa","Tether","Tetterton","Tewinga","Textile","Thackery","Thame","Thamesmeade","Thatch","Thaxted","The American","The Dalles","The Great","The Grove  Danson","The Ladder","The Luton","The Mount  Grove","The Old Oaks","The Plough  Kenton","The Silk","The Villages","Thea","Theed","Thelwall","Theobalds","Theodore Green","Thermal","Thestland","Thicketford","Thiers","Thin Edge","Thirlstone","Thirza","Thistledale","Thixton","Thomas","Thomas Center","Thomas Edison","Thomas J Thorsen","Thomas Moore","Thomas Powell","Thomasin","Thompkins","Thomson","Thora","Thoria","Thorn Bush","Thornall","Thornbeck","Thornburgh","Thorncroft","Thorndown","Thornewood","Thorngate","Thornhurst","Thornmeadow","Thornton Blue Island","Thornycroft","Thorparch","Thorpedene","Thorverton","Thread Needle","Three Cherry Trees","Three Elms","Three Hurdles","Three Penny","Threepence","Threshfield","Throgmorton","Throughbred","Thrushwing","Thunder Mountain","Thurbans","Thurlby","Thurlston","Thurnby","Thurso","Thurwood","Tiana","Tibbits","Tiberon","Tices","Tickenhall","Tico","Tidewater","Tidswell","Tiemann","Tiernans","Tierra Lago","Tiffin","Tiger","Tilberg","Tilden Gill","Tile Line","Tileyard","Till Rock","Tillgate","Tillman","Tilney","Tilsworth","Timarand","Timber Branch","Timber Hill","Timber Oak","Timber Trails","Timbercrest Farm","Timberhill","Timberline","Timbers Pointe","Timble","Timi","Timmus","Timperley","Tin Can Ranch","Tindal Spring","Tindon End","Tingue","Tinley","Tinsley","Tintle","Tionesta","Tipper","Tipps Cross","Tire Swing","Tischer","Tithe","Titmus","Tivoli","Tobacco Leaf","Tobiano","Tobys","Tod","Toddy","Toftshaw New","Toiyabe","Tokeneke Ridge","Tolar","Tolenas","Toll Bar","Tollers","Tollund","Tolsford","Tolworth","Tom Jenkinson","Toma","Tomasetti","Tomcroft","Tomlin","Tommy Middleton","Tomrick","Tonacliff","Toner","Tonge Old","Tonia","Tonkaha","Tonne","Tonsor","Tonya","Tooley","Toorak","Top","Top of the Hill","Topcastle","Topinera","Topp","Topsail","Torah","Torcross","Torlai","Torney","Torque","Torrence","Torrey Pines","Torridon","Tortola","Tory Fort","Tot","Totnes","Totteridge","Touissant","Touquet","Tourney","Tovar","Towaway","Tower Court","Tower Oak","Towerfield","Towfield","Town Acres","Town Dump","Town Hall","Town Mead","Town Wells","Towne Crest","Townhall","Townscliffe","Townside","Towpath","Toy","Toysome","Tracey","Tractor","Trade","Tradewind","Trafalger","Tragan","Trail Haven","Traill","Trailwood","Tralee","Trancas","Trans World","Transport","Tranton","Trapper","Tratman","Traveller","Travilah","Trayer","Treadgold","Treatro","Trebellan","Treby","Tredwell","Tree Lawn","Treebrooke","Treehouse","Treetop Hill","Trefton","Tregony","Trelawn","Tremari","Tremlett","Trenchold","Treno","Trentino","Tres Pinos","Tresham","Tressider","Trevale","Trevenar","Trevino","Trevorton","Trewyn","Triadelphia","Tribunal","Tricross","Trigger","Trilliam Run","Trimbleford","Trina","Trinity Gate","Trip","Tripleseven","Trish","Triton Beach","Trocha","Trombas","Tropaz","Trost","Trotters Glen","Trotts","Trousdale","Trout Wine","Trouville","Trowville","Troy Meadow","Truck House","True","Truett","Truman Manor","Trumpeter Swan","Trunfio","Truss Hill","Tryall","Tschiffely Sq","Tuart Park","Tucabia","Tuckerman Heights","Tuddington","Tuenge","Tuft","Tuggle","Tulane","Tulich","Tulipwood","Tulley","Tullymore","Tumble Weed","Tumelty","Tuncurry","Tunitas","Tunnel Entrance","Tunstead","Tupolo","Turbine","Turf Hill","Turfmeadow","Turing","Turkey Foot","Turks Cap Lily","Turn Left","Turnbridge","Turner","Turners Green","Turnfield","Turnley","Turnsworth","Turpin","Turret","Turstin","Turtle Valley","Turvey","Tuscany","Tuskeegee","Tuthill","Twain","Tweedmouth","Twelve Oaks Center","Twentythird","Twigworth","Twin Bridges","Twin Creeks","Twin Harbor","Twin Lawns","Twin Park","Twin Rivers","Twinbrook","Twinlake","Twiss Green","Twitten","Two Farm","Two Rivers","Twycross","Twynersh","Tyas","Tydcombe","Tyee","Tyland","Tyler Island","Tylneys","Tynebourne","Tyngsboro","Tyram","Tyrol","Tysen","Tythe","UMBC","Udell","Ufton","Uhuru","Uline","Ulloa","Ulonga","Ulva","Umbdenstock","Un Pr","Uncatena","Underdale","Undershaw","Union","Union Landing","Union Ter","United","University","Unqua","Uop North Service","Upcroft","Uphill","Uplands Park","Upper Abbey","Upper Attunga","Upper Belgrave","Upper Briar","Upper Canyon Three","Upper Chorlton","Upper Conran","Upper Culver","Upper Dunstan","Upper Fairfield","Upper Fort","Upper Green","Upper Hale","Upper Hibernia","Upper Joclyn","Upper Lees","Upper Marlborough","Upper Monterey","Upper North Row","Upper Pindell","Upper Redlands","Upper Roman","Upper Sherborne","Upper Tachbrook","Upper Toyon","Upper Village","Upper Wilton","Uppermont","Upshaw","Upton Hills","Upwoods","Urban","Urby","Urmond","Ursiline","Usange","Uss North Carolina","Utility","Utz","Uxmore","Vaca Valley","Vadnais","Vailetti","Val Gardena","Valaire","Valdale","Valdora","Vale Top","Valence Wood","Valentina","Valeowen","Valery","Valhalla","Valla","Valle Ducale","Vallentin","Valley Bend","Valley Crest","Valley Green","Valley Lake","Valley Pines","Valley Tree","Valleybrook","Valleystream","Vallingbe","Valmor","Valor","Valroy","Van Allen","Van Blarcoms","Van Bussum","Van Cortlandt","Van Der Meulen","Van Duyne","Van Fleet","Van Horn","Van Kleeck","Van Mulen","Van Over","Van Riper","Van Schoick","Van Syckel","Van Vleck","Van White Memorial","Van de Mark","Vancouver","Vandelft","Vanderbergh","Vanderelinde","Vandervoot","Vandine","Vaneck","Vanguard","Vann Farm","Vannucci","Vansittart","Vantroba","Varcoe","Varennes","Varidel","Varney","Vashi","Vassall","Vauban","Vaugn","Vauxhall","Vecino","Veery","Velado","Velizy","Velock","Venables","Vendola","Venetion","Venndale","Ventana","Venturella","Vera Cruz","Verbena","Verdayne","Verdi","Verdosa","Verein","Veridan","Verla","Vermillion","Verna Mae","Vernel","Vernon Berry","Vernoy Hills","Verplank","Versailes","Vervais","Vespan","Vestals Gap","Veterans Foreign War","Vevers","Via Camino","Via Madero","Via Primavera","Via de Robles","Viaduct","Vicarage","Vicino","Vicki Lynn","Victor Hugo","Victoria Greens","Victorian Park","Victrola","Vidovich","Vierling","View Haven","Viewhill","Viewridge","Viking","Villa Carol","Villa Nueva","Villa Verde","Village Center","Village Gate","Village Lower","Village Shops","Villagefield","Villars","Villers","Vimy Ridge","Vincam","Vindara","Vine Hill","Vinegar Hill","Vinewood","Vineyards","Vinson","Vintage Oak","Vinyards","Violettes Lock","Viret","Virginia Denise","Virginia Pine","Virmar","Visitacion","Vista Brooke","Vista Hill","Vista Oak","Vista Verde","Vista del Rio","Vistula","Vivian Adams","Vlaardingen","Vogay","Vokoun","Volintine Farm","Vollbecht","Volt","Volver","Von Braun","Von Karman","Vonhoff","Vorlich","Voss Park","Vredenburgh","Vultee","W Abingdon","W Agatite","W Aldine","W Allison","W Amy","W Anne K","W Arch","W Armstrong","W Ash","W Autullo","W Balmoral","W Bartlett","W Bayberry","W Beechcroft","W Belleterre","W Benson","W Berwick","W Birchdale","W Blair","W Bogey","W Boundary","W Bradwell","W Breen","W Brittany","W Brookfield","W Bruns","W Burnett","W Butternut","W Cambridge","W Carl","W Carvel","W Cathy","W Central","W Channon","W Chase","W Chester","W Christopher","W Clark","W Coach","W Colorado","W Concord","W Cornelia","W Country Club","W Courtland","W Crescent","W Cuba","W Custis","W Daniels","W Deer Creek","W Dempster","W Diamond Lake","W Dominion","W Dove","W Durkee","W Eaton","W Edmund","W Elk Grove","W Elmwood","W Estes","W Everell","W Fairview","W Fenimore","W Firestone","W Flournoy","W Forest View","W Foster","W Franklin","W Frontenac","W Gabreski","W Garrett","W Geneva","W Gilbert","W Glencoe","W Goethe","W Goodrich","W Grand Lake","W Greenbrook","W Greystone","W Hackberry","W Haledon","W Hampton","W Harriet","W Hastings","W Haystack","W Hegel","W Henry","W Higgins","W Hills","W Hobart","W Holtz","W Howdy","W Hunt","W Hutchinson","W Indian Trail","W Iroquois","W Islip","W Jamaica","W Jeffery","W Joe Orr","W Joyce","W Karen","W Kelly Ann","W Kimber","W Kinzie","W Kurt","W Lake","W Lakeland","W Lancaster","W Lasalle","W Lawson","W Lester","W Line","W Lockwood","W Lookout","W Loves","W Lydia","W Magoun","W Manitoba","W Marine","W Martin","W Mathews","W Maypole","W McGowanwoods","W Meadowland","W Menna","W Messner","W Midland","W Millpage","W Minnehaha","W Montana","W Monument","W Mound","W Mulloy","W N Frontage","W Neck","W New York","W Nolcrest","W North Pond","W Norwood","W Oakhill","W Official","W Old Hideway","W Olive","W Ordnance","W Ox","W Palmer","W Parker","W Patapsco","W Peddie","W Pensacola","W Phillips","W Pinehurst","W Pleasant","W Pond","W Prairie","W Prindiville","W Quail","W Ramapo","W Rawson Bridge","W Regan","W Rich","W River Hills","W Roberts","W Rockland","W Rosalie","W Rosemont","W Running Brook","W Saddle River","W Sandpiper","W Schick","W Schweitzer","W Seamans Neck","W Shady","W Sheridan","W Shoreline","W Sioux","W Soffel","W Spring Lake","W Summerdale","W Superior","W Sycamore","W Tantallon","W Thacker","W Three Oaks","W Tow Path","W Tulip","W Union","W Van Buren","W Viking","W Walker","W Ward","W Watson","W Weed","W West End","W Wheatley","W Whiting","W Williams","W Wing","W Wirth","W Woodcrest","W Woodstock","W Wyoming","W de Saible","WIllow Ridge","Wabana","Wabler","Wackett","Waddell","Waddon Park","Wadeson","Wadleigh","Wagamon","Waggaman","Wagnon","Wagstaff","Wahly","Waiku","Waingels","Wairoa","Waitkus","Wake Island","Wakeham","Wakely","Wakestone","Walavista","Walbury","Waldemar","Walden Square","Walderton","Waldor","Waldrohe","Wales","Walhonding","Walkdens","Walker Mill","Walkerton","Walkley","Wall Pt.","Wallacks","Wallaroy","Wallen","Walleye","Walling","Wallmark Lake","Wallum Beach","Walman Buckley","Walmort","Walnut Branch","Walnut Meadows","Walnutgrove","Walraven","Walsham","Walter Adamic","Walter Morse","Walters Green","Walthery","Walton Heath","Waltuma","Wambool","Wampum","Wanbourne","Wandell","Wanders","Waner","Wangi","Wannas","Wansey","Wantagh Park","Wapiti","Wapping Dock","Warabin","Warbick","Warburton","Ward Park","Wardlebrook","Wards Chapel","Ware Park","Warehouse Creek","Warenne","Wargrave","Warinanco Park","Warley Hall","Warman","Warmscombe","Warneford","Warnham Court","Warrabina","Warrangi","Warrego","Warren House","Warreners","Warrick","Warringa","Warrung","Waruda","Warwicks Bench","Wasdale","Washall","Washington Park","Washitay","Waskow","Wassell","Watch Hill","Watchmoor","Watcombe","Water Grant","Water Pointe","Waterbank","Watercroft","Wateredge","Waterfoot","Waterham","Waterline","Watermeetings","Waters Edge","Watersedge","Watersong","Watervale","Waterwood","Wathen","Watkinson","Watrous","Watsonville","Wattle Creek","Watts Mine","Waucantuck","Waukeena","Wausau","Waveland","Waverley Oaks","Wawecus","Waxwing","Waycott","Wayfarers","Waylen","Wayne Gibson","Waynewood","Wayte","Wazir","Wealdstone","Wearden","Weasel","Weatherington","Weaver","Weavers Rock","Webbacowitt","Weberfield","Weddell","Wedge Pond","Wedhurst","Weed","Weemala","Weequahic","Weetman","Wegman","Weible","Weigel","Weimar","Weir Hall","Weirs","Weiss","Welbury","Welcome","Weldale","Welesley","Welhouse","Well Penn","Wellbeck","Wellerburn","Wellfleet","Welling Way  Sherwood","Wellington Hill","Wellmeadow","Wellsmere","Wellyhole","Welsley","Welwyn By Pass","Wembrough","Wendall","Wendling","Wengate","Wenman","Wenonah","Wentland","Wentzell","Werbe","Wernbrook","Werrington","Wescoat","Wesglen","Wesley White","Wessagusett","Wesson","West Agua Caliente","West Algonquin","West Ann","West Arthington","West Avondale","West Bare Hill","West Beach","West Bellevue","West Bird","West Bolton","West Boundary","West Briggsmore","West Brooke","West Burns Valley","West California","West Capitol","West Carroll","West Center","West Cherry","West Cintura","West Comfort","West Cotati","West Cremona","West Curtis","West Dane","West Dempster","West Dike","West Dundee","West Eaton","West Elkhorn","West End Farm","West Eugenie","West Fargo","West Field","West Forest Lake","West Franklin","West G","West Gertrude","West Go Wanda","West Grant Line","West Grover","West Hammer","West Harley","West Hawley","West Hearn","West Highland","West Hillsdale","West Homestead","West Howell","West Ike Crow","West Iris","West Jahant","West Jenness","West Julian","West Kenner","West Keystone","West Knickerbocker","West Lake Kayak","West Las Palmas","West Le Moyne","West Liberty","West Livingston","West Lomond","West Lost Lake","West Lynda","West Mall","West Mariposa","West Maude","West Meadow","West Michigan","West Miner","West Monroe","West Moreland","West Myrtle","West Nettle Tree","West Ninth","West Oak Knoll","West Ohio","West Ott","West Park","West Patterson Pass","West Peregrine","West Platti","West Portola","West Quincy","West Raye","West Rianda","West River Grove","West Rockport","West Roy","West Rutherford","West San Carlos","West Santa Fe","West Schulte","West Seventh","West Shorewood","West Sixth","West Sorrento","West Sst","West Sutton","West Temple","West Thames","West Tokay","West Trinity","West Upsala","West View","West Walnut","West Weddell","West Whitmore","West Wimbolton","West Woodbridge","West Yoke","West el Dorado","Westage","Westbard","Westboro","Westbreeze","Westcar","Westcombe Park","Wested","Westerdale","Westerleigh","Western Mine","Westerton","Westfields","Westhall","Westholme","Westings","Westlea","Westmaher","Westminster","Westmore","Weston Bay","Westospect","Westport","Westshire","Westvalley","Westwich","Westwoods","Wetherby","Wethington","Wewers","Wey Gates","Weybrook","Weyham","Weymouth","Weywood","Whalebone","Whalewood","Whann","Wharton","Whays","Wheathelds","Wheaton","Wheedon","Wheeler Peak","Wheelwright Park","Whempstead","Whetstone Hill","Whielden","Whimberry","Whingate Tong","Whip Hill","Whippletree","Whirley","Whiskin","Whispering","Whispering Leaves","Whispering Tree","Whisperwood","Whistley Mill","Whitaker Bluff","Whitchurch","White Bank","White Bread","White Chimneys","White Creek","White Ferry","White Ground","White Hill","White Island","White Lion","White Oaks","White Ridge","White Spots","White Willow","Whitebick","Whitechapel High","Whited","Whitefur","Whitehave","Whiteholme","Whiteland","Whiteley","Whitenack","Whiteriver","Whites Pond","Whitesmead","Whitethorne","Whitewillow","Whithouse","Whitkirk","Whitley Park","Whitmer","Whitnell","Whits End","Whittaker","Whitting","Whittney","Whittredge","Wholfords","Whowell","Whyteladyes","Wibird","Wichitaw","Wickell","Wickersham","Wicklands","Wickstead","Widdenham","Widegate","Widgiewa","Widnes","Wiehle","Wiese","Wiggenhall","Wiggs","Wigram","Wihtred","Wilbanks","Wilbrandt","Wilby","Wilcoxson","Wild Canyon","Wild Forest","Wild Hunt","Wild Olive","Wilda","Wildcrest","Wilderfield","Wilderton","Wildflower","Wildhurst","Wildmere","Wildridings","Wildwood","Wiley","Wilgus","Wilk","Wilkes Barre","Wilksch","Will Scarlett","Willandale","Willard Point","Willcrest","Willens","Willester","Willever","William B Terry","William Cunningham","William Fairfield","William Joseph","William Moss","William Tell","Williams Wood","Williamson Ranch","Willie","Willingdon","Williton","Willo Mar","Willoughby Newton","Willow Bridge","Willow Dale","Willow Grove","Willow Oaks","Willow Run","Willow Valley","Willoway","Willowcroft","Willowhayne","Willowmere","Willows Edge","Willrush","Willvail","Wilmarth","Wilmington Court","Wilms","Wilrose","Wilshire","Wilson Hall","Wilsondale","Wilton","Wilward","Wimbledon Park","Wimlands","Win Haven","Winborough","Winchat","Winchfield","Wincombe","Wind Crest","Wind Song","Windbridge","Windell","Windeyer","Windhaven","Winding Brook","Winding Lakes","Winding Way","Windleaf","Windmill Cove","Windong Wood","Windrock","Windshire","Windsor Court","Windsor Palms","Windstar","Windwhisper","Windy Harbour","Windy Point","Wine","Wineham","Winfell","Winfrith","Winged Cove","Wingham","Wings","Winkelman","Winkley","Winmoor","Winnecomac","Winnepurkit","Winnetuxett","Winnisimmet","Winoka","Winscombe","Winside","Winslowe","Winstead Manor","Winstree","Winter Garden","Winter Sun","Winterbourne","Wintergreen","Winterscroft","Winterstoke","Winthorpe","Wintun","Wirega","Wirraway","Wirtz","Wisdom","Wishanger","Wishon","Wissahican","Wistar","Witby","Witham","Witherington","Withey Heights","Withorn","Wits End","Witter","Witty","Wixon","Wodehouse","Wohseepee","Wolcott","Wolf","Wolf Rock","Wolfe Hill","Wolfs","Wolkow","Wollombi","Wolpers","Wolton","Wolves","Womerah","Wong","Wontford","Wood Brook","Wood Glade","Wood House","Wood Mar","Wood Sage","Wood View","Woodbanke","Woodbourne","Woodbrooke","Woodby","Woodcliff Lake","Woodcote Park","Woodcutters","Wooded Hills","Woodelf","Woodend","Woodfield Park","Woodgate Hill","Woodhail","Woodhaven","Woodholm","Woodinville","Woodland Crossing","Woodland Park","Woodlane","Woodlea","Woodley Woods","Woodlyn","Woodmeadow","Woodmore","Woodpecker","Woodredon Farm","Woodrock","Woodruff","Woods End","Woods of Arden","Woodsend Crescent","Woodside Tavern Low","Woodstock","Woodthorpe","Woodvill","Woodwaye","Woodwren","Wool Creek","Wooldridge","Woolfield","Woollard","Woolmead","Woolpack","Woolsy","Woomera","Woosley","Worbeck","Worden","Workman","Worleys","Wormstead","Woropieff","Worrobil","Worsley Bridge","Wortham","Wortley","Wote","Wraight","Wray Common","Wrekin","Wrendale","Wrentmore","Wright Brothers","Wrightwood","Wroughton","Wulff","Wuthering Heights","Wyandemere","Wyatt Park","Wybersley","Wychewood","Wyckoff","Wycombe Park","Wyena","Wykehurst","Wyldwood","Wylmar","Wymondley","Wynchgate","Wyndehurst","Wyndhurst","Wyndwood","Wynkoop","Wynns","Wynsum","Wyoming","Wyresdale","Wythenshawe","Wyvil","Xanthippe","Xenia","Y Creek","Yachthaven","Yagoona","Yalkin","Yamato","Yancy","Yankee Division","Yantacaw Brook","Yaralla","Yardley Hall","Yarmouth","Yarra Burra","Yarran","Yarrow","Yateley","Yawpo","Yealand","Yeasted","Yeldall Manor Service","Yellow Birch","Yellow Lodge","Yellowcross","Yelsted","Yeomalt","Yerba Buena","Yerrick","Yetminster","Yewbarrow","Ygnacio Valley","Yoakes","Yoho","Yolano Mills","York Gardens  Lombard","Yorknolls","Yorktown Mall","Yosemite Park","Youlden","Youngfield","Youngs Valley","Yountville Cross","Yribarren","Yukon","Yunga","Yurunga","ZEnith","Zaca","Zadig","Zambesi","Zampatti","Zanker","Zaragosa","Zato","Zayante Lakes","Zebra","Zeim","Zeliff","Zemek","Zenner","Zeppelin","Zetland","Ziggy","Zimpher","Zinran","Zisch","Zoffany","Zorah","Zouch","Zumbro","Zweber"];
\ No newline at end of file
@@ -1 +1 @@
-pub const STREET_TYPE: [&'static str; 11] = ["Ave","Dr","Ct","Cres","Gr","Hwy","Lane","Pde","Pl","Rd","St"];
\ No newline at end of file
+pub static STREET_TYPE: [&str; 11] = ["Ave","Dr","Ct","Cres","Gr","Hwy","Lane","Pde","Pl","Rd","St"];
\ No newline at end of file
@@ -0,0 +1 @@
+pub static URL_PATH: [&str; 20] = ["account","articles","blog","cart","categories","checkout","docs","events","help","invoices","login","news","orders","products","profile","reports","search","settings","support","users"];
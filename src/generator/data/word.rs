@@ -0,0 +1 @@
+pub static WORD: [&str; 152] = ["a","ac","accumsan","adipiscing","aenean","aliquam","aliquet","amet","ante","arcu","at","auctor","augue","bibendum","blandit","commodo","condimentum","congue","consectetur","consequat","convallis","cras","cursus","dapibus","diam","dictum","dignissim","dolor","donec","dui","duis","egestas","eget","eleifend","elementum","elit","enim","erat","eros","est","et","etiam","eu","euismod","facilisis","fames","faucibus","felis","fermentum","feugiat","finibus","fringilla","fusce","gravida","habitant","hendrerit","iaculis","id","imperdiet","in","integer","interdum","ipsum","justo","lacinia","lacus","laoreet","lectus","leo","libero","ligula","lobortis","lorem","luctus","maecenas","magna","malesuada","massa","mattis","mauris","metus","mi","molestie","mollis","morbi","nam","nec","neque","netus","nibh","nisi","nisl","non","nulla","nullam","nunc","odio","orci","ornare","pellentesque","pharetra","phasellus","placerat","porta","porttitor","posuere","praesent","pretium","proin","pulvinar","purus","quam","quis","quisque","rhoncus","risus","rutrum","sagittis","sapien","scelerisque","sed","sem","semper","senectus","sit","sodales","sollicitudin","suscipit","suspendisse","tellus","tempor","tempus","tincidunt","tortor","tristique","turpis","ullamcorper","ultrices","ultricies","urna","ut","varius","vehicula","vel","velit","venenatis","vestibulum","vitae","vivamus","viverra","volutpat","vulputate"];
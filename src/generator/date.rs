@@ -0,0 +1,61 @@
+use crate::types::TimeFormat;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use rand::Rng;
+
+const DAY_MILLIS: i64 = 86_400_000;
+
+pub struct Date;
+impl Date {
+    /// A day between the days of `from` and `to`, both included, at midnight UTC.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R, from: i64, to: i64, format: &TimeFormat) -> String {
+        let day: i64 = rng.gen_range(from.div_euclid(DAY_MILLIS), to.div_euclid(DAY_MILLIS) + 1);
+        format_millis(day * DAY_MILLIS, format)
+    }
+}
+
+pub struct Time;
+impl Time {
+    /// A millisecond between `from` and `to`, both included.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R, from: i64, to: i64, format: &TimeFormat) -> String {
+        format_millis(rng.gen_range(from, to + 1), format)
+    }
+}
+
+/// Writes milliseconds since the Unix epoch in `format`, in UTC.
+pub fn format_millis(millis: i64, format: &TimeFormat) -> String {
+    let datetime: DateTime<Utc> = DateTime::from_timestamp_millis(millis).unwrap_or_default();
+    match format {
+        TimeFormat::Rfc3339 => datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        TimeFormat::Epoch => millis.div_euclid(1_000).to_string(),
+        TimeFormat::EpochMillis => millis.to_string(),
+        TimeFormat::Pattern(pattern) => datetime.format(pattern).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn dates_stay_within_bounds() {
+        let rng = &mut rng::document_rng(2, 0);
+        let (from, to): (i64, i64) = (1_546_300_800_000, 1_546_300_800_000 + 2 * DAY_MILLIS);
+        let format = TimeFormat::Pattern("%Y-%m-%d".to_owned());
+        for _ in 0..50 {
+            let date: String = Date::generate(rng, from, to, &format);
+            assert!(["2019-01-01", "2019-01-02", "2019-01-03"].contains(&date.as_str()), "{}", date);
+            let millis: i64 = Time::generate(rng, from, to, &TimeFormat::EpochMillis).parse().unwrap();
+            assert!(millis >= from && millis <= to);
+        }
+    }
+
+    #[test]
+    fn formats_times() {
+        assert_eq!(format_millis(1_546_300_800_000, &TimeFormat::Rfc3339), "2019-01-01T00:00:00Z");
+        assert_eq!(format_millis(1_546_300_800_250, &TimeFormat::Rfc3339), "2019-01-01T00:00:00.250Z");
+        assert_eq!(format_millis(1_546_300_800_250, &TimeFormat::Epoch), "1546300800");
+        assert_eq!(format_millis(-1, &TimeFormat::Epoch), "-1");
+    }
+}
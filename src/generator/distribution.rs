@@ -1,12 +1,26 @@
+use rand::Rng;
 use rand_distr::Distribution;
 
-pub struct Normal;
+/// A normal distribution, checked when it is made so that drawing from it cannot fail.
+#[derive(Clone,Copy,Debug)]
+pub struct Normal {
+    distribution: rand_distr::Normal<f64>
+}
+
 impl Normal {
     const DEFAULT_ROUNDING: i8 = 6;
 
-    pub fn generate(mean: f64, stddev: f64) -> f64 {
-        let dist = rand_distr::Normal::new(mean, stddev).unwrap();
-        let val = dist.sample(&mut rand::thread_rng());
+    pub fn new(mean: f64, stddev: f64) -> Result<Normal, String> {
+        if !mean.is_finite() || !stddev.is_finite() {
+            return Err("mean and stddev must be finite numbers".to_owned());
+        }
+        rand_distr::Normal::new(mean, stddev)
+            .map(|distribution: rand_distr::Normal<f64>| Normal { distribution })
+            .map_err(|_| "stddev < 0".to_owned())
+    }
+
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let val = self.distribution.sample(rng);
         math::round::floor(val, Normal::DEFAULT_ROUNDING)
     }
-}
\ No newline at end of file
+}
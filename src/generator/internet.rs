@@ -0,0 +1,91 @@
+use super::data::domain::DOMAIN;
+use super::name::Name;
+use super::util;
+use crate::parser::filter::fold_to_ascii;
+use crate::types::UsernamePart;
+
+use lazycell::LazyCell;
+use rand::Rng;
+
+/// The online identity of an entity, made from its `Name` so that `jane.doe84@example.com`
+/// belongs to the same person as `Jane` and `Doe`.
+#[derive(Debug, Clone, Default)]
+pub struct Internet {
+    number: LazyCell<u64>
+}
+
+impl Internet {
+    pub fn new() -> Internet {
+        Internet::default()
+    }
+
+    pub fn username<R: Rng + ?Sized>(&self, rng: &mut R, name: &mut Name, pattern: &[UsernamePart], lowercase: bool) -> String {
+        let mut username = String::new();
+        for part in pattern {
+            match part {
+                UsernamePart::Text(text) => username.push_str(text),
+                UsernamePart::First => username.push_str(&name_part(&name.first(rng))),
+                UsernamePart::Last => username.push_str(&name_part(&name.last(rng))),
+                UsernamePart::FirstInitial => username.extend(name_part(&name.first(rng)).chars().take(1)),
+                UsernamePart::LastInitial => username.extend(name_part(&name.last(rng)).chars().take(1)),
+                UsernamePart::Digits(width) => username.push_str(&self.digits(rng, *width))
+            }
+        }
+        match lowercase {
+            true => username.to_lowercase(),
+            false => username
+        }
+    }
+
+    /// An address at one of `domains`, or at one of the reserved example domains when none are given.
+    pub fn email<R: Rng + ?Sized>(&self, rng: &mut R, name: &mut Name, pattern: &[UsernamePart], lowercase: bool, domains: &[String]) -> String {
+        let username: String = self.username(rng, name, pattern, lowercase);
+        let domain: &str = match domains.is_empty() {
+            true => DOMAIN[util::rand_index(rng, DOMAIN.len())],
+            false => &domains[util::rand_index(rng, domains.len())]
+        };
+        format!("{}@{}", username, domain)
+    }
+
+    /// The last `width` digits of the entity's number, which is the same in every username of the entity.
+    fn digits<R: Rng + ?Sized>(&self, rng: &mut R, width: usize) -> String {
+        let number: u64 = *self.number.borrow_with(|| rng.gen_range(0, 1_000_000_000));
+        format!("{:0width$}", number % 10u64.pow(width as u32), width = width)
+    }
+}
+
+/// A name as it can appear in a username, e.g. `Zoë` as `Zoe` and `Anne-Marie` as `AnneMarie`.
+fn name_part(name: &str) -> String {
+    fold_to_ascii(name).chars()
+        .filter(|c: &char| c.is_ascii_alphanumeric())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn usernames_are_made_from_the_name() {
+        let rng = &mut rng::document_rng(7, 0);
+        let mut name = Name::new();
+        let internet = Internet::new();
+        let pattern = vec![UsernamePart::FirstInitial, UsernamePart::Text(".".to_owned()), UsernamePart::Last, UsernamePart::Digits(2)];
+        let username: String = internet.username(rng, &mut name, &pattern, true);
+        let expected: String = format!("{}.{}", name_part(&name.first(rng)).chars().next().unwrap(), name_part(&name.last(rng))).to_lowercase();
+        assert!(username.starts_with(&expected), "{} does not start with {}", username, expected);
+        assert_eq!(username.len(), expected.len() + 2);
+        assert!(username[expected.len()..].chars().all(|c: char| c.is_ascii_digit()));
+
+        let email: String = internet.email(rng, &mut name, &pattern, true, &["mail.example".to_owned()]);
+        assert_eq!(email, format!("{}@mail.example", username));
+    }
+
+    #[test]
+    fn name_parts_are_folded_to_ascii() {
+        assert_eq!(name_part("Zoë"), "Zoe");
+        assert_eq!(name_part("Anne-Marie"), "AnneMarie");
+        assert_eq!(name_part("Dee Dee"), "DeeDee");
+    }
+}
@@ -5,8 +5,9 @@ use data::street::STREET;
 use data::street_type::STREET_TYPE;
 
 use lazycell::LazyCell;
+use rand::Rng;
 
-#[derive(Debug,Clone)]
+#[derive(Debug,Clone, Default)]
 pub struct Location {
     street_number: LazyCell<String>,
     street: LazyCell<String>,
@@ -15,64 +16,60 @@ pub struct Location {
 
 impl Location {
     pub fn new() -> Location {
-        Location {
-            street_number: LazyCell::new(),
-            street: LazyCell::new(),
-            place: LazyCell::new()
-        }
+        Location::default()
     }
 
-    pub fn address(&self) -> String {
-        format!("{} {}, {}", self.street_number(), self.street(), self.place())
+    pub fn address<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        format!("{} {}, {}", self.street_number(rng), self.street(rng), self.place(rng))
     }
 
-    pub fn street_number(&self) -> String {
-        self.street_number.borrow_with(LocationGenerator::street_number).to_owned()
+    pub fn street_number<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.street_number.borrow_with(|| LocationGenerator::street_number(rng)).to_owned()
     }
 
-    pub fn street(&self) -> String {
-        self.street.borrow_with(LocationGenerator::street).to_owned()
+    pub fn street<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.street.borrow_with(|| LocationGenerator::street(rng)).to_owned()
     }
-    
-    pub fn place(&self) -> String {
-        self.place.borrow_with(LocationGenerator::place).to_owned()
+
+    pub fn place<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.place.borrow_with(|| LocationGenerator::place(rng)).to_owned()
     }
 }
 
 struct LocationGenerator;
 impl LocationGenerator {
-    fn place() -> String {
-        let index: usize = util::rand_index(PLACE.len());
-        return PLACE[index].to_owned();
+    fn place<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let index: usize = util::rand_index(rng, PLACE.len());
+        PLACE[index].to_owned()
     }
 
-    fn street() -> String {
-        let street_name_index: usize = util::rand_index(STREET.len());
+    fn street<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let street_name_index: usize = util::rand_index(rng, STREET.len());
         let street_name = STREET[street_name_index];
 
-        let street_type_index: usize = util::rand_index(STREET_TYPE.len());
+        let street_type_index: usize = util::rand_index(rng, STREET_TYPE.len());
         let street_type = STREET_TYPE[street_type_index];
         format!("{} {}", street_name, street_type)
     }
 
-    fn unit_number() -> String {
-        LocationGenerator::int(1, 50).to_string()
+    fn unit_number<R: Rng + ?Sized>(rng: &mut R) -> String {
+        LocationGenerator::int(rng, 1, 50).to_string()
     }
 
-    fn house_number() -> String {
-        LocationGenerator::int(1, 500).to_string()
+    fn house_number<R: Rng + ?Sized>(rng: &mut R) -> String {
+        LocationGenerator::int(rng, 1, 500).to_string()
     }
 
-    fn street_number() -> String {
-        match rand::random() {
-            true => format!("{}/{}", LocationGenerator::unit_number(), LocationGenerator::house_number()),
-            false => format!("{}", LocationGenerator::house_number())
+    fn street_number<R: Rng + ?Sized>(rng: &mut R) -> String {
+        match rng.gen() {
+            true => format!("{}/{}", LocationGenerator::unit_number(rng), LocationGenerator::house_number(rng)),
+            false => LocationGenerator::house_number(rng)
         }
     }
 
-    fn int(min: i64, max: i64) -> i64 {
+    fn int<R: Rng + ?Sized>(rng: &mut R, min: i64, max: i64) -> i64 {
         let range: i64 = max - min;
-        let rand_in_range: f64 = (range as f64) * rand::random::<f64>();
-        return min + rand_in_range as i64;
+        let rand_in_range: f64 = (range as f64) * rng.gen::<f64>();
+        min + rand_in_range as i64
     }
 }
\ No newline at end of file
@@ -5,4 +5,9 @@ pub mod location;
 pub mod primitive;
 pub mod name;
 pub mod phone;
-pub mod distribution;
\ No newline at end of file
+pub mod internet;
+pub mod network;
+pub mod text;
+pub mod distribution;
+pub mod date;
+pub mod stream;
\ No newline at end of file
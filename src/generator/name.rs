@@ -5,8 +5,9 @@ use data::last_name::LAST_NAME;
 use data::middle_name::MIDDLE_NAME;
 
 use lazycell::LazyCell;
+use rand::Rng;
 
-#[derive(Debug,Clone)]
+#[derive(Debug,Clone, Default)]
 pub struct Name {
     first: LazyCell<String>,
     middle: LazyCell<Option<String>>,
@@ -15,48 +16,44 @@ pub struct Name {
 
 impl Name {
     pub fn new() -> Name {
-        Name {
-            first: LazyCell::new(),
-            middle: LazyCell::new(),
-            last: LazyCell::new()
-        }
+        Name::default()
     }
 
-    pub fn full(&mut self) -> String {
-        match self.middle() {
-            Some(middle) => format!("{} {} {}", self.first(), middle, self.last()),
-            None => format!("{} {}", self.first(), self.last())
+    pub fn full<R: Rng + ?Sized>(&mut self, rng: &mut R) -> String {
+        match self.middle(rng) {
+            Some(middle) => format!("{} {} {}", self.first(rng), middle, self.last(rng)),
+            None => format!("{} {}", self.first(rng), self.last(rng))
         }
     }
 
-    pub fn first(&mut self) -> String {
-        self.first.borrow_with(NameGenerator::first).to_owned()
+    pub fn first<R: Rng + ?Sized>(&mut self, rng: &mut R) -> String {
+        self.first.borrow_with(|| NameGenerator::first(rng)).to_owned()
     }
 
-    pub fn middle(&mut self) -> Option<String> {
-        self.middle.borrow_with(NameGenerator::middle).to_owned()
+    pub fn middle<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<String> {
+        self.middle.borrow_with(|| NameGenerator::middle(rng)).to_owned()
     }
 
-    pub fn last(&mut self) -> String {
-        self.last.borrow_with(NameGenerator::last).to_owned()
+    pub fn last<R: Rng + ?Sized>(&mut self, rng: &mut R) -> String {
+        self.last.borrow_with(|| NameGenerator::last(rng)).to_owned()
     }
 }
 
 struct NameGenerator;
 impl NameGenerator {
-    pub fn first() -> String {
-        let index: usize = util::rand_index(FIRST_NAME.len());
-        return FIRST_NAME[index].to_owned();
+    pub fn first<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let index: usize = util::rand_index(rng, FIRST_NAME.len());
+        FIRST_NAME[index].to_owned()
     }
 
-    pub fn last() -> String {
-        let index: usize = util::rand_index(LAST_NAME.len());
-        return LAST_NAME[index].to_owned();
+    pub fn last<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let index: usize = util::rand_index(rng, LAST_NAME.len());
+        LAST_NAME[index].to_owned()
     }
 
-    fn middle() -> Option<String> {
-        if rand::random() {
-            let index: usize = util::rand_index(MIDDLE_NAME.len());
+    fn middle<R: Rng + ?Sized>(rng: &mut R) -> Option<String> {
+        if rng.gen() {
+            let index: usize = util::rand_index(rng, MIDDLE_NAME.len());
             Some(MIDDLE_NAME[index].to_owned())
         } else {
             None
@@ -70,17 +67,19 @@ mod name_tests {
 
     #[test]
     fn test_borrow_with_already_filled() {
+        let rng = &mut rand::thread_rng();
         let lazycell = LazyCell::new();
-        let name = NameGenerator::first();
+        let name = NameGenerator::first(rng);
         lazycell.fill(name.clone()).unwrap();
 
-        let value = lazycell.borrow_with(NameGenerator::first).to_owned();
+        let value = lazycell.borrow_with(|| NameGenerator::first(rng)).to_owned();
         assert_eq!(name, value);
     }
 
     #[test]
     fn retrieving_name_twice_returns_same() {
+        let rng = &mut rand::thread_rng();
         let name: &mut Name = &mut Name::new();
-        assert_eq!(name.first(), name.first());
+        assert_eq!(name.first(rng), name.first(rng));
     }
 }
\ No newline at end of file
@@ -0,0 +1,125 @@
+use super::data::domain::DOMAIN;
+use super::data::host_prefix::HOST_PREFIX;
+use super::data::url_path::URL_PATH;
+use super::data::user_agent::USER_AGENT;
+use super::util;
+
+use lazycell::LazyCell;
+use rand::Rng;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// The machine an entity uses, so that the host, MAC address and user agent of a client are
+/// the same wherever the document mentions them.
+#[derive(Debug, Clone, Default)]
+pub struct Network {
+    mac: LazyCell<String>,
+    host: LazyCell<String>,
+    domain: LazyCell<&'static str>,
+    user_agent: LazyCell<String>
+}
+
+impl Network {
+    pub fn new() -> Network {
+        Network::default()
+    }
+
+    pub fn mac<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.mac.borrow_with(|| NetworkGenerator::mac(rng)).to_owned()
+    }
+
+    /// The entity's host, in `domain` or else in one of the reserved example domains.
+    pub fn hostname<R: Rng + ?Sized>(&self, rng: &mut R, domain: Option<&str>) -> String {
+        let host: &String = self.host.borrow_with(|| NetworkGenerator::host(rng));
+        let domain: &str = match domain {
+            Some(domain) => domain,
+            None => self.domain.borrow_with(|| DOMAIN[util::rand_index(rng, DOMAIN.len())])
+        };
+        format!("{}.{}", host, domain)
+    }
+
+    /// A page on the entity's host, which differs from one URL to the next.
+    pub fn url<R: Rng + ?Sized>(&self, rng: &mut R, scheme: &str, domain: Option<&str>) -> String {
+        format!("{}://{}{}", scheme, self.hostname(rng, domain), NetworkGenerator::path(rng))
+    }
+
+    pub fn user_agent<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.user_agent.borrow_with(|| USER_AGENT[util::rand_index(rng, USER_AGENT.len())].to_owned()).to_owned()
+    }
+}
+
+pub struct Ip;
+impl Ip {
+    pub fn v4<R: Rng + ?Sized>(rng: &mut R, network: u32, prefix: u32) -> String {
+        let host: u64 = rng.gen_range(0, 1u64 << (32 - prefix));
+        Ipv4Addr::from(network | host as u32).to_string()
+    }
+
+    pub fn v6<R: Rng + ?Sized>(rng: &mut R, network: u128, prefix: u32) -> String {
+        let host: u128 = rng.gen::<u128>().checked_shr(prefix).unwrap_or(0);
+        Ipv6Addr::from(network | host).to_string()
+    }
+}
+
+pub struct Port;
+impl Port {
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R, min: u16, max: u16) -> String {
+        rng.gen_range(u32::from(min), u32::from(max) + 1).to_string()
+    }
+}
+
+struct NetworkGenerator;
+impl NetworkGenerator {
+    /// A unicast MAC address, as the low bit of the first octet marks multicast addresses.
+    fn mac<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let mut octets: [u8; 6] = rng.gen();
+        octets[0] &= 0xfe;
+        octets.iter().map(|octet: &u8| format!("{:02x}", octet)).collect::<Vec<String>>().join(":")
+    }
+
+    fn host<R: Rng + ?Sized>(rng: &mut R) -> String {
+        format!("{}-{:02}", HOST_PREFIX[util::rand_index(rng, HOST_PREFIX.len())], rng.gen_range(1, 21))
+    }
+
+    /// A path such as `/orders`, `/users/4821` or `/help/search`.
+    fn path<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let first: &str = URL_PATH[util::rand_index(rng, URL_PATH.len())];
+        match rng.gen_range(0, 3) {
+            0 => format!("/{}", first),
+            1 => format!("/{}/{}", first, rng.gen_range(1, 10_000)),
+            _ => format!("/{}/{}", first, URL_PATH[util::rand_index(rng, URL_PATH.len())])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn addresses_stay_in_their_network() {
+        let rng = &mut rng::document_rng(11, 0);
+        for _ in 0..100 {
+            let v4: Ipv4Addr = Ip::v4(rng, 0x0a01_0000, 16).parse().unwrap();
+            assert_eq!(u32::from(v4) >> 16, 0x0a01);
+            let v6: Ipv6Addr = Ip::v6(rng, 0x2001_0db8 << 96, 32).parse().unwrap();
+            assert_eq!(u128::from(v6) >> 96, 0x2001_0db8);
+            let port: u16 = Port::generate(rng, 65534, 65535).parse().unwrap();
+            assert!(port >= 65534);
+        }
+        assert_eq!(Ip::v4(rng, 0x0808_0808, 32), "8.8.8.8");
+        assert_eq!(Ip::v6(rng, 1, 128), "::1");
+    }
+
+    #[test]
+    fn a_network_keeps_its_host() {
+        let rng = &mut rng::document_rng(11, 0);
+        let network = Network::new();
+        let hostname: String = network.hostname(rng, None);
+        let url: String = network.url(rng, "https", None);
+        assert!(url.starts_with(&format!("https://{}/", hostname)), "{} is not on {}", url, hostname);
+        assert_eq!(network.hostname(rng, Some("corp.example")).split_once('.').unwrap().0, hostname.split_once('.').unwrap().0);
+        assert_eq!(network.mac(rng), network.mac(rng));
+        assert_eq!(network.mac(rng).len(), 17);
+    }
+}
@@ -1,8 +1,9 @@
 use super::data::country_code::COUNTRY_CODE;
 use super::util;
 use lazycell::LazyCell;
+use rand::Rng;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Phone {
     mobile: LazyCell<String>,
     landline: LazyCell<String>
@@ -10,55 +11,52 @@ pub struct Phone {
 
 impl Phone {
     pub fn new() -> Phone {
-        Phone {
-            mobile: LazyCell::new(),
-            landline: LazyCell::new()
-        }
+        Phone::default()
     }
 
-    pub fn mobile(&self) -> String {
-        self.mobile.borrow_with(|| PhoneGenerator::mobile(false)).to_owned()
+    pub fn mobile<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.mobile.borrow_with(|| PhoneGenerator::mobile(rng, false)).to_owned()
     }
 
-    pub fn landline(&self) -> String {
-        self.landline.borrow_with(|| PhoneGenerator::landline(false)).to_owned()
+    pub fn landline<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.landline.borrow_with(|| PhoneGenerator::landline(rng, false)).to_owned()
     }
 
-    pub fn phone(&self) -> String {
-        match rand::random() {
-            true => self.mobile(),
-            false => self.landline()
+    pub fn phone<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        match rng.gen() {
+            true => self.mobile(rng),
+            false => self.landline(rng)
         }
     }
 }
 
 struct PhoneGenerator;
 impl PhoneGenerator {
-    fn country_code() -> &'static str {
-        let index: usize = util::rand_index(COUNTRY_CODE.len());
-        return COUNTRY_CODE[index];
+    fn country_code<R: Rng + ?Sized>(rng: &mut R) -> &'static str {
+        let index: usize = util::rand_index(rng, COUNTRY_CODE.len());
+        COUNTRY_CODE[index]
     }
 
-    fn mobile(use_country_code: bool) -> String {
+    fn mobile<R: Rng + ?Sized>(rng: &mut R, use_country_code: bool) -> String {
         let code: String = match use_country_code {
-            true => format!("({})", PhoneGenerator::country_code()),
+            true => format!("({})", PhoneGenerator::country_code(rng)),
             false => String::from("0")
         };
-        format!("{}4{:02} {:03} {:03}", code, PhoneGenerator::int(0, 10e1 as i64), PhoneGenerator::int(0, 10e2 as i64), PhoneGenerator::int(0, 10e2 as i64))
+        format!("{}4{:02} {:03} {:03}", code, PhoneGenerator::int(rng, 0, 10e1 as i64), PhoneGenerator::int(rng, 0, 10e2 as i64), PhoneGenerator::int(rng, 0, 10e2 as i64))
     }
 
-    fn landline(use_country_code: bool) -> String {
-        let number: String = format!("9{:03} {:04}", PhoneGenerator::int(0, 10e2 as i64), PhoneGenerator::int(0, 10e3 as i64));
+    fn landline<R: Rng + ?Sized>(rng: &mut R, use_country_code: bool) -> String {
+        let number: String = format!("9{:03} {:04}", PhoneGenerator::int(rng, 0, 10e2 as i64), PhoneGenerator::int(rng, 0, 10e3 as i64));
         match use_country_code {
-            true => format!("({}) {}", PhoneGenerator::country_code(), number),
+            true => format!("({}) {}", PhoneGenerator::country_code(rng), number),
             false => number
         }
     }
 
-    fn int(min: i64, max: i64) -> i64 {
+    fn int<R: Rng + ?Sized>(rng: &mut R, min: i64, max: i64) -> i64 {
         let range: i64 = max - min;
-        let rand_in_range: f64 = (range as f64) * rand::random::<f64>();
-        return min + rand_in_range as i64;
+        let rand_in_range: f64 = (range as f64) * rng.gen::<f64>();
+        min + rand_in_range as i64
     }
 }
 
@@ -70,8 +68,9 @@ mod tests {
     #[test]
     fn mobile_with_country_code() {
         let regex: Regex = Regex::new(r"^\(\+[0-9]{1,3}\)4[0-9]{2} [0-9]{3} [0-9]{3}$").unwrap();
+        let rng = &mut rand::thread_rng();
         for _ in 1..20 {
-            let generated_mobile = PhoneGenerator::mobile(true);
+            let generated_mobile = PhoneGenerator::mobile(rng, true);
             assert!(regex.is_match(&generated_mobile), "{} did not match mobile with country code regex", generated_mobile);
         }
     }
@@ -79,8 +78,9 @@ mod tests {
     #[test]
     fn mobile_without_country_code() {
         let regex: Regex = Regex::new(r"^04[0-9]{2} [0-9]{3} [0-9]{3}$").unwrap();
+        let rng = &mut rand::thread_rng();
         for _ in 1..20 {
-            let generated_mobile = PhoneGenerator::mobile(false);
+            let generated_mobile = PhoneGenerator::mobile(rng, false);
             assert!(regex.is_match(&generated_mobile), "{} did not match mobile without country code regex", generated_mobile);
         }
     }
@@ -88,8 +88,9 @@ mod tests {
     #[test]
     fn landline_with_country_code() {
         let regex: Regex = Regex::new(r"^\(\+[0-9]{1,3}\) [0-9]{4} [0-9]{4}$").unwrap();
+        let rng = &mut rand::thread_rng();
         for _ in 1..20 {
-            let generated_landline = PhoneGenerator::landline(true);
+            let generated_landline = PhoneGenerator::landline(rng, true);
             assert!(regex.is_match(&generated_landline), "{} did not match landline with country code regex", generated_landline);
         }
     }
@@ -97,8 +98,9 @@ mod tests {
     #[test]
     fn landline_without_country_code() {
         let regex: Regex = Regex::new(r"^[0-9]{4} [0-9]{4}$").unwrap();
+        let rng = &mut rand::thread_rng();
         for _ in 1..20 {
-            let generated_landline = PhoneGenerator::landline(false);
+            let generated_landline = PhoneGenerator::landline(rng, false);
             assert!(regex.is_match(&generated_landline), "{} did not match landline without country code regex", generated_landline);
         }
     }
@@ -1,38 +1,60 @@
 use super::util;
-use uuid::Uuid;
+use rand::Rng;
+use uuid::{Builder, Variant, Version};
 
 pub struct Float;
 impl Float {
     const DEFAULT_ROUNDING: i8 = 6;
 
-    pub fn generate(min: f64, max: f64) -> f64 {
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R, min: f64, max: f64) -> f64 {
         let range: f64 = max - min;
-        let rand_in_range: f64 = range * rand::random::<f64>();
+        let rand_in_range: f64 = range * rng.gen::<f64>();
         math::round::floor(rand_in_range, Float::DEFAULT_ROUNDING)
     }
 }
 
 pub struct Guid;
 impl Guid {
-    pub fn generate() -> String {
-        Uuid::new_v4().to_hyphenated().to_string()
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> String {
+        Builder::from_bytes(rng.gen())
+            .set_variant(Variant::RFC4122)
+            .set_version(Version::Random)
+            .build()
+            .to_hyphenated()
+            .to_string()
     }
 }
 
 pub struct Int;
 impl Int {
-    pub fn generate(min: i64, max: i64) -> i64 {
-        let range: i64 = max - min;
-        let rand_in_range: f64 = (range as f64) * rand::random::<f64>();
-        return min + rand_in_range as i64;
+    /// A number from `min` up to but excluding `max`. The range is computed in `i128`, as the
+    /// distance from `i64::MIN` to `i64::MAX` does not fit in an `i64`.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R, min: i64, max: i64) -> i64 {
+        let range: i128 = i128::from(max) - i128::from(min);
+        let rand_in_range: f64 = (range as f64) * rng.gen::<f64>();
+        (i128::from(min) + rand_in_range as i128) as i64
     }
 }
 
 pub struct Set;
 impl Set {
-    pub fn generate<'t>(options: Vec<String>) -> String {
-        let set: &Vec<String> = &options;
-        let index: usize = util::rand_index(set.len());
-        return set[index].to_string();
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R, options: &[String]) -> String {
+        let index: usize = util::rand_index(rng, options.len());
+        options[index].to_string()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn ints_cover_the_whole_range_of_i64() {
+        let rng = &mut rng::document_rng(1, 0);
+        let values: Vec<i64> = (0..100).map(|_| Int::generate(rng, i64::MIN, i64::MAX)).collect();
+        assert!(values.iter().any(|value: &i64| *value < 0) && values.iter().any(|value: &i64| *value > 0));
+        assert!((0..100).all(|_| Int::generate(rng, i64::MAX - 1, i64::MAX) == i64::MAX - 1));
+        assert!((0..100).all(|_| Int::generate(rng, i64::MIN, i64::MIN + 1) == i64::MIN));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::rng::{self, DocumentRng};
+use crate::types::Arrival;
+
+use rand::Rng;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// The number of documents between two checkpoints of a stream.
+const CHECKPOINT_INTERVAL: u64 = 1024;
+
+/// How far a thread has followed a stream: the milliseconds from its start to document `index`.
+struct Position {
+    index: u64,
+    elapsed: f64,
+    rng: DocumentRng
+}
+
+impl Position {
+    /// The position at document `index`, `elapsed` milliseconds into the stream named `key`.
+    fn at(seed: u64, key: &str, index: u64, elapsed: f64) -> Position {
+        let mut rng: DocumentRng = rng::stream_rng(seed, key);
+        // Each gap draws one u64, which is two words of the ChaCha stream.
+        rng.set_word_pos(u128::from(index) * 2);
+        Position { index, elapsed, rng }
+    }
+
+    fn advance(&mut self, index: u64, mean: f64, arrival: Arrival) {
+        while self.index < index {
+            self.elapsed += gap(&mut self.rng, mean, arrival);
+            self.index += 1;
+        }
+    }
+}
+
+thread_local! {
+    static POSITIONS: RefCell<HashMap<(u64, String), Position>> = RefCell::new(HashMap::new());
+}
+
+lazy_static! {
+    /// The elapsed time at every `CHECKPOINT_INTERVAL`th document of each stream, shared by all threads.
+    static ref CHECKPOINTS: Mutex<HashMap<(u64, String), Vec<f64>>> = Mutex::new(HashMap::new());
+}
+
+pub struct Stream;
+impl Stream {
+    /// Milliseconds from the start of the stream named `key` to document `index` of a run seeded with `seed`.
+    ///
+    /// The time between document `i - 1` and `i` is the `i`th draw from the stream's own RNG, so the result
+    /// only depends on the seed and the index. Each thread continues from the last document it rendered,
+    /// so rendering documents in order, or each worker's share of them, costs O(1) per document. Any other
+    /// document starts from the checkpoint before it, which costs at most `CHECKPOINT_INTERVAL` draws once
+    /// the checkpoints are known. They are shared by every thread and computed once per run, so `--skip n`
+    /// costs O(n) in total rather than per worker.
+    pub fn elapsed(seed: u64, key: &str, index: u64, mean: f64, arrival: Arrival) -> f64 {
+        POSITIONS.with(|positions: &RefCell<HashMap<(u64, String), Position>>| {
+            let mut positions = positions.borrow_mut();
+            let position: &mut Position = positions.entry((seed, key.to_owned()))
+                .or_insert_with(|| Position::at(seed, key, 0, 0.0));
+            if position.index > index || index - position.index > CHECKPOINT_INTERVAL {
+                let checkpoint: u64 = index / CHECKPOINT_INTERVAL * CHECKPOINT_INTERVAL;
+                *position = Position::at(seed, key, checkpoint, checkpoint_elapsed(seed, key, checkpoint, mean, arrival));
+            }
+            position.advance(index, mean, arrival);
+            position.elapsed
+        })
+    }
+}
+
+/// The elapsed time at document `index`, a multiple of `CHECKPOINT_INTERVAL`, computing any
+/// checkpoints of the stream before it that no thread has reached yet.
+fn checkpoint_elapsed(seed: u64, key: &str, index: u64, mean: f64, arrival: Arrival) -> f64 {
+    let mut checkpoints = CHECKPOINTS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let checkpoints: &mut Vec<f64> = checkpoints.entry((seed, key.to_owned())).or_insert_with(|| vec![0.0]);
+    let wanted: usize = (index / CHECKPOINT_INTERVAL) as usize;
+    if checkpoints.len() <= wanted {
+        let last: u64 = (checkpoints.len() - 1) as u64 * CHECKPOINT_INTERVAL;
+        let mut position: Position = Position::at(seed, key, last, checkpoints[checkpoints.len() - 1]);
+        while checkpoints.len() <= wanted {
+            position.advance(position.index + CHECKPOINT_INTERVAL, mean, arrival);
+            checkpoints.push(position.elapsed);
+        }
+    }
+    checkpoints[wanted]
+}
+
+/// Draws the time between two documents. Every arrival draws exactly one number.
+fn gap(rng: &mut DocumentRng, mean: f64, arrival: Arrival) -> f64 {
+    let uniform: f64 = rng.gen::<f64>();
+    match arrival {
+        Arrival::Exponential => -mean * (1.0 - uniform).ln(),
+        Arrival::Uniform => 2.0 * mean * uniform,
+        Arrival::Fixed => mean
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_time_increases_and_does_not_depend_on_order() {
+        let forward: Vec<f64> = (0..200).map(|index: u64| Stream::elapsed(3, "a", index, 200.0, Arrival::Exponential)).collect();
+        assert_eq!(forward[0], 0.0);
+        assert!(forward.windows(2).all(|pair: &[f64]| pair[0] <= pair[1]));
+        let mean_gap: f64 = forward[199] / 199.0;
+        assert!(mean_gap > 150.0 && mean_gap < 250.0, "{}", mean_gap);
+
+        let backward: Vec<f64> = (0..200).rev().map(|index: u64| Stream::elapsed(3, "a", index, 200.0, Arrival::Exponential)).collect();
+        assert!(backward.into_iter().rev().eq(forward.into_iter()));
+        assert_eq!(Stream::elapsed(3, "b", 4, 200.0, Arrival::Fixed), 800.0);
+    }
+
+    #[test]
+    fn seeking_agrees_with_following_the_stream() {
+        let mut followed = Position::at(4, "c", 0, 0.0);
+        let mut expected: Vec<f64> = Vec::new();
+        for index in [1, 1023, 1024, 1025, 3000, 5000] {
+            followed.advance(index, 50.0, Arrival::Uniform);
+            expected.push(followed.elapsed);
+        }
+        let seeked: Vec<f64> = [5000, 1024, 3000, 1, 1025, 1023].iter()
+            .map(|index: &u64| Stream::elapsed(4, "c", *index, 50.0, Arrival::Uniform))
+            .collect();
+        assert_eq!(seeked, [expected[5], expected[2], expected[4], expected[0], expected[3], expected[1]]);
+    }
+}
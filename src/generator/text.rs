@@ -0,0 +1,116 @@
+use super::data::word::WORD;
+use super::util;
+
+use rand::Rng;
+
+/// Lorem ipsum text, which never exceeds the number of words or characters it is asked for.
+pub struct Text;
+impl Text {
+    /// From `min` to `max` words, both included, separated by spaces.
+    pub fn words<R: Rng + ?Sized>(rng: &mut R, min: u64, max: u64) -> String {
+        let count: u64 = util::rand_inclusive(rng, min, max);
+        (0..count).map(|_| Text::word(rng)).collect::<Vec<&str>>().join(" ")
+    }
+
+    /// A capitalized sentence of `min` to `max` words ending with a full stop.
+    pub fn sentence<R: Rng + ?Sized>(rng: &mut R, min: u64, max: u64) -> String {
+        let words: String = Text::words(rng, min, max);
+        if words.is_empty() {
+            return words;
+        }
+        let mut sentence: String = capitalize(&words);
+        sentence.push('.');
+        sentence
+    }
+
+    /// `count` paragraphs of three to six sentences, separated by a blank line.
+    pub fn paragraphs<R: Rng + ?Sized>(rng: &mut R, count: u64) -> String {
+        (0..count).map(|_| Text::paragraph(rng)).collect::<Vec<String>>().join("\n\n")
+    }
+
+    /// Sentences cut to a length from `min` to `max` characters, both included. The text is
+    /// cut after the last whole word that fits, unless that would make it shorter than `min`.
+    pub fn chars<R: Rng + ?Sized>(rng: &mut R, min: u64, max: u64) -> String {
+        let length: usize = util::rand_inclusive(rng, min, max) as usize;
+        let mut text = String::with_capacity(length + 100);
+        while text.len() < length {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(&Text::sentence(rng, 4, 12));
+        }
+        fit(&text, min as usize, length)
+    }
+
+    fn paragraph<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let sentences: u64 = rng.gen_range(3, 7);
+        (0..sentences).map(|_| Text::sentence(rng, 4, 12)).collect::<Vec<String>>().join(" ")
+    }
+
+    fn word<R: Rng + ?Sized>(rng: &mut R) -> &'static str {
+        WORD[util::rand_index(rng, WORD.len())]
+    }
+}
+
+/// Cuts ASCII `text` to at most `length` characters, ending on a whole word with a full stop
+/// where one fits, and at least `min` characters long.
+fn fit(text: &str, min: usize, length: usize) -> String {
+    if text.len() <= length {
+        return text.to_owned();
+    }
+    let cut: &str = &text[..length];
+    let words: &str = match text.as_bytes()[length] == b' ' {
+        true => cut,
+        false => cut.rfind(' ').map_or("", |end: usize| &cut[..end])
+    };
+    let words: &str = words.trim_end_matches([' ', '.']);
+    if words.len() < min {
+        return cut.to_owned();
+    }
+    match words.len() < length {
+        true => format!("{}.", words),
+        false => words.to_owned()
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn text_stays_within_its_length() {
+        let rng = &mut rng::document_rng(3, 0);
+        for _ in 0..200 {
+            let words: usize = Text::words(rng, 3, 8).split(' ').count();
+            assert!((3..=8).contains(&words), "{} words", words);
+            let chars: String = Text::chars(rng, 20, 40);
+            assert!((20..=40).contains(&chars.len()), "{:?} is {} characters", chars, chars.len());
+            assert!(Text::chars(rng, 1, 1).len() == 1);
+        }
+        let sentence: String = Text::sentence(rng, 5, 5);
+        assert_eq!(sentence.split(' ').count(), 5);
+        assert!(sentence.starts_with(|c: char| c.is_ascii_uppercase()) && sentence.ends_with('.'), "{}", sentence);
+        assert_eq!(Text::paragraphs(rng, 3).split("\n\n").count(), 3);
+        assert_eq!(Text::words(rng, 0, 0), "");
+        assert_eq!(util::rand_inclusive(rng, u64::MAX, u64::MAX), u64::MAX);
+        assert!(util::rand_inclusive(rng, u64::MAX - 1, u64::MAX) >= u64::MAX - 1);
+    }
+
+    #[test]
+    fn text_is_cut_after_a_whole_word() {
+        assert_eq!(fit("Lorem ipsum dolor. Sit amet.", 1, 14), "Lorem ipsum.");
+        assert_eq!(fit("Lorem ipsum dolor. Sit amet.", 1, 18), "Lorem ipsum dolor.");
+        assert_eq!(fit("Lorem ipsum dolor. Sit amet.", 1, 19), "Lorem ipsum dolor.");
+        assert_eq!(fit("Lorem ipsum dolor. Sit amet.", 14, 14), "Lorem ipsum do");
+        assert_eq!(fit("Lorem ipsum.", 1, 20), "Lorem ipsum.");
+    }
+}
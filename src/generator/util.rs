@@ -1,4 +1,15 @@
-pub fn rand_index(length: usize) -> usize {
-    let index_approx: f64 = (length as f64) * rand::random::<f64>();
-    return math::round::floor(index_approx, 0) as usize;
-}
\ No newline at end of file
+use rand::Rng;
+use rand::distributions::Uniform;
+
+pub fn rand_index<R: Rng + ?Sized>(rng: &mut R, length: usize) -> usize {
+    let index_approx: f64 = (length as f64) * rng.gen::<f64>();
+    math::round::floor(index_approx, 0) as usize
+}
+
+/// A number from `min` to `max`, both included, even where `max + 1` does not fit in a `u64`.
+pub fn rand_inclusive<R: Rng + ?Sized>(rng: &mut R, min: u64, max: u64) -> u64 {
+    match max.checked_add(1) {
+        Some(end) => rng.gen_range(min, end),
+        None => rng.sample(Uniform::new_inclusive(min, max))
+    }
+}
@@ -0,0 +1,52 @@
+//! Generates randomised documents from templates containing typed placeholders.
+//!
+//! A template is compiled once and can then be rendered any number of times:
+//!
+//! ```
+//! use docgen::Template;
+//!
+//! let template = Template::compile("${<1>name::first} ${<1>name::last} (${int:min=18;max=65})").unwrap();
+//! let document: String = template.render(&mut docgen::rng::document_rng(42, 0));
+//! assert_eq!(document, template.render(&mut docgen::rng::document_rng(42, 0)));
+//! ```
+//!
+//! Compiling reports every invalid placeholder at once, each with its location in the template:
+//!
+//! ```
+//! let errors = docgen::Template::compile("${int:min=5;max=1}").unwrap_err();
+//! assert_eq!(errors[0].reason, "min > max");
+//! ```
+//!
+//! Single values come from an `Entity`, which keeps the values of one person or thing consistent:
+//!
+//! ```
+//! use docgen::{Entity, Placeholder};
+//!
+//! let rng = &mut docgen::rng::document_rng(42, 0);
+//! let mut person = Entity::new();
+//! let first = person.value_of(&Placeholder::parse("name::first").unwrap(), rng).unwrap();
+//! let email = person.value_of(&Placeholder::parse("email").unwrap(), rng).unwrap();
+//! assert_eq!(first, person.value_of(&Placeholder::parse("name::first").unwrap(), rng).unwrap());
+//! assert!(email.contains('@'));
+//! ```
+
+#[macro_use]
+extern crate lazy_static;
+
+pub(crate) mod parser;
+pub mod types;
+pub mod generator;
+pub(crate) mod entity;
+pub(crate) mod template;
+pub mod rng;
+pub(crate) mod run;
+pub(crate) mod output;
+
+pub use entity::Entity;
+pub use parser::Placeholder;
+pub use parser::error::{PlaceholderParseError, SourceLocation};
+pub use parser::registry::describe_types;
+pub use template::{Template, CompileOptions, RenderContext, WeightedTemplates};
+pub use template::{Escape, ESCAPE_NAMES, parse_var, parse_vars};
+pub use run::Run;
+pub use output::{DirectoryOutput, Envelope, StreamOutput, unescape};
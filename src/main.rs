@@ -1,20 +1,21 @@
-#[macro_use]
-extern crate lazy_static;
+use docgen::{Template, CompileOptions, RenderContext, PlaceholderParseError, WeightedTemplates};
+use docgen::{Escape, ESCAPE_NAMES, parse_var, parse_vars};
+use docgen::{Run, DirectoryOutput, Envelope, StreamOutput, unescape};
+use docgen::rng;
 
-mod parser;
-mod types;
-mod generator;
-mod entity;
-
-use parser::Placeholder;
-use parser::error::PlaceholderParseError;
-use entity::Entity;
-
-use clap::{App, Arg, ArgMatches};
-use regex::{Regex, Captures, Match};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 use std::collections::HashMap;
+use std::io::{self, BufWriter, Read};
+use std::path::PathBuf;
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn run() -> Result<(), Box<dyn std::error::Error>> {
     let matches: ArgMatches = App::new("Templated data generator tool")
         .version("0.3.0")
         .author("Konrad <ko.cybulski@gmail.com>")
@@ -23,24 +24,27 @@ Provide a template string or file containing typed placeholders for which this t
 
 Usage: 
     Inline template: './docgen --template \"Hi my name is ${name::first}\"'
-    Template file:   './docgen --f path/to/file -n 100'
+    Template file:   './docgen -f path/to/file -n 100'
+    Template stdin:  'cat path/to/file | ./docgen -f - -n 100'
+    Weighted mix:    './docgen -f login.json:70 -f purchase.json:25 -f error.json:5 -n 100'
 
 Supported data types:
-    - first => ${name::first}
-    - last => ${name::last}
-    - full => ${name::full}
-    - address => ${location::address}
-    - place => ${location::place}
-    - street => ${location::street}
-    - mobile phone => ${phone::mobile}
-    - landline phone => ${phone::landline}
-    - normal distribution => ${dist::normal:mean=0;stddev=1}
-    - integer => ${int:min=0;max=5}
-    - repeated integer => ${int:min=0;max=10;rep=4}
-    - float => ${float:min=0;max=1}
-    - set => ${set:options=[A,B,C,D]} (randomly selected element of the provided set e.g. B)
-    - guid => ${guid}
+    './docgen types' lists every placeholder type with its arguments and their defaults.
+    './docgen explain '${int:min=0;max=5}'' describes a placeholder and shows sample values.
         ")
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .subcommand(SubCommand::with_name("types")
+            .about("Lists every supported placeholder type with its arguments"))
+        .subcommand(SubCommand::with_name("explain")
+            .about("Describes the placeholders in a template and shows sample values")
+            .arg(Arg::with_name("placeholder")
+                .help("A placeholder such as '${int:min=0;max=5}', or a template containing placeholders")
+                .required(true))
+            .arg(Arg::with_name("samples")
+                .help("Number of sample values to show")
+                .short("n")
+                .takes_value(true)
+                .default_value("5")))
         .arg(Arg::with_name("template")
             .help("The template string to populate with generated data")
             .short("t")
@@ -48,110 +52,262 @@ Supported data types:
             .takes_value(true)
             .required_unless("template-file"))
         .arg(Arg::with_name("template-file")
-            .help("Path to file with template to populate with generated data")
+            .help("Path to file with template to populate with generated data, or '-' to read it from stdin. May be repeated, each as 'path:weight', to pick every document's template by weight")
             .short("f")
             .long("template-file")
             .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
             .required_unless("template"))
         .arg(Arg::with_name("number")
             .help("Number of populated documents to generate according to the template")
             .short("n")
             .takes_value(true)
             .default_value("1"))
+        .arg(Arg::with_name("seed")
+            .help("Seed for the random generator. The same seed and template always produce the same documents")
+            .long("seed")
+            .takes_value(true))
+        .arg(Arg::with_name("skip")
+            .help("Index of the first document to generate. Combined with --seed, regenerates documents from that index onwards")
+            .long("skip")
+            .takes_value(true)
+            .default_value("0"))
+        .arg(Arg::with_name("jobs")
+            .help("Number of worker threads rendering documents. Documents are always written in order")
+            .short("j")
+            .long("jobs")
+            .takes_value(true)
+            .default_value("1"))
+        .arg(Arg::with_name("null-token")
+            .help("Text rendered in place of a value that a placeholder's 'null=' modifier left out")
+            .long("null-token")
+            .takes_value(true)
+            .default_value("null"))
+        .arg(Arg::with_name("var")
+            .help("Value for ${var::name} placeholders, as 'name=value'. May be repeated")
+            .long("var")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1))
+        .arg(Arg::with_name("vars-file")
+            .help("TOML file of values for ${var::name} placeholders. --var takes precedence over it")
+            .long("vars-file")
+            .takes_value(true))
+        .arg(Arg::with_name("allow-env")
+            .help("Allow ${env::NAME} placeholders to read environment variables")
+            .long("allow-env"))
+        .arg(Arg::with_name("escape")
+            .help("How generated values are escaped for the document format. Defaults to the extension of each template file, and to none for other templates")
+            .long("escape")
+            .takes_value(true)
+            .possible_values(ESCAPE_NAMES))
+        .arg(Arg::with_name("output-dir")
+            .help("Directory to write each document to as its own file, instead of printing to the screen")
+            .long("output-dir")
+            .takes_value(true)
+            .requires("filename"))
+        .arg(Arg::with_name("filename")
+            .help("Template for the name of each document file. Entity placeholders share values with the document, e.g. 'user-${doc::index:pad=6}-${<1>name::last}.json'")
+            .long("filename")
+            .takes_value(true)
+            .requires("output-dir"))
+        .arg(Arg::with_name("no-overwrite")
+            .help("Fail instead of overwriting a document file that already exists")
+            .long("no-overwrite")
+            .requires("output-dir"))
+        .arg(Arg::with_name("wrap")
+            .help("How documents printed to the screen are combined: one per line, a single JSON array, or minified newline delimited JSON")
+            .long("wrap")
+            .takes_value(true)
+            .possible_values(&["lines", "json-array", "ndjson"])
+            .conflicts_with("output-dir"))
+        .arg(Arg::with_name("separator")
+            .help("Text written between documents, overriding --wrap. Supports \\n and \\t escapes")
+            .long("separator")
+            .takes_value(true)
+            .conflicts_with("output-dir"))
+        .arg(Arg::with_name("header")
+            .help("Text written before the first document, overriding --wrap")
+            .long("header")
+            .takes_value(true)
+            .conflicts_with("output-dir"))
+        .arg(Arg::with_name("footer")
+            .help("Text written after the last document, overriding --wrap")
+            .long("footer")
+            .takes_value(true)
+            .conflicts_with("output-dir"))
         .get_matches();
+
+    match matches.subcommand() {
+        ("types", Some(_)) => return list_types(),
+        ("explain", Some(explain_matches)) => return explain(explain_matches),
+        _ => ()
+    }
     
-    let template: String = matches.value_of("template-file")
-        .and_then(|filename| std::fs::read_to_string(filename).ok())
-        .or({
-            matches.value_of("template").map(|t: &str| t.to_owned())
-        })
-        .expect("No template supplied");
-    
-    let errors: Vec<PlaceholderParseError> = validate_template(&template);
-    if errors.len() > 0 {
-        for parse_error in errors {
-            println!("Validation error for placeholder '{}'. Reason: {}", parse_error.placeholder, parse_error.reason);
+    let mut sources: Vec<(String, CompileOptions, u32)> = match matches.values_of("template-file") {
+        Some(specs) => specs.map(read_weighted_template).collect::<io::Result<_>>()?,
+        None => vec![(matches.value_of("template").expect("No template supplied").to_owned(), CompileOptions::named("--template"), 1)]
+    };
+    if let Some(escape) = matches.value_of("escape") {
+        let escape: Escape = escape.parse::<Escape>()?;
+        sources.iter_mut().for_each(|(_, options, _)| options.escape = escape);
+    }
+    let mut vars: HashMap<String, String> = match matches.value_of("vars-file") {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|err: io::Error| format!("Could not read variables '{}': {}", path, err))
+            .and_then(|text: String| parse_vars(&text).map_err(|err: String| format!("Invalid variables file '{}': {}", path, err)))?,
+        None => HashMap::new()
+    };
+    for assignment in matches.values_of("var").into_iter().flatten() {
+        let (name, value): (String, String) = parse_var(assignment)?;
+        vars.insert(name, value);
+    }
+    let allow_env: bool = matches.is_present("allow-env");
+    for (_, options, _) in sources.iter_mut() {
+        options.vars = vars.clone();
+        options.allow_env = allow_env;
+    }
+
+    let mut errors: Vec<PlaceholderParseError> = Vec::new();
+    let mut weighted: Vec<(Template, u32)> = Vec::new();
+    for (template, options, weight) in &sources {
+        match Template::compile_with(template, options) {
+            Ok(compiled_template) => weighted.push((compiled_template, *weight)),
+            Err(template_errors) => errors.extend(template_errors)
         }
-        return Ok(());
     }
+    let filename_template: Option<Template> = match matches.value_of("filename").map(|filename: &str| Template::compile_with(filename, &CompileOptions { vars: vars.clone(), allow_env, ..CompileOptions::named("--filename") })) {
+        Some(Ok(filename_template)) => Some(filename_template),
+        Some(Err(filename_errors)) => {
+            errors.extend(filename_errors);
+            None
+        },
+        None => None
+    };
+    if !errors.is_empty() {
+        report(errors);
+    }
+    let templates: WeightedTemplates = WeightedTemplates::new(weighted)
+        .ok_or("At least one template must have a weight greater than zero")?;
 
     let repetitions: u64 = matches.value_of("number")
         .unwrap_or("1")
         .parse::<u64>()
         .unwrap_or(1);
     
-    for _ in 0..repetitions {
-        let generated_doc: String = populate_template(&template);
-        println!("{}", &generated_doc);
-    }
-
-    Ok(())
-}
+    let seed: u64 = match matches.value_of("seed") {
+        Some(seed) => seed.parse::<u64>()?,
+        None => rand::random()
+    };
+    let skip: u64 = matches.value_of("skip")
+        .unwrap_or("0")
+        .parse::<u64>()?;
+    let jobs: usize = matches.value_of("jobs")
+        .unwrap_or("1")
+        .parse::<usize>()?;
 
-lazy_static! {
-    static ref PLACEHOLDER_REGEX: Regex = Regex::new(r"\$\{(?:<(?P<entity_id>[a-zA-Z0-9]+)>)?(?P<placeholder>[^\}]*)\}").unwrap();
-}
+    let mut envelope: Envelope = matches.value_of("wrap")
+        .and_then(Envelope::from_name)
+        .unwrap_or_else(Envelope::lines);
+    if let Some(separator) = matches.value_of("separator") {
+        envelope.separator = unescape(separator);
+    }
+    if let Some(header) = matches.value_of("header") {
+        envelope.header = unescape(header);
+    }
+    if let Some(footer) = matches.value_of("footer") {
+        envelope.footer = unescape(footer);
+    }
 
-fn validate_template(template: &str) -> Vec<PlaceholderParseError> {
-    let errors: &mut Vec<PlaceholderParseError> = &mut Vec::new();
-    let x = Regex::new(r"\$\{(?:<(?P<entity_id>[a-zA-Z0-9]+)>)?(?P<placeholder>[^\}]*)\}").unwrap();
-    for captures in x.captures_iter(template) {
-        let placeholder_str: &str = captures.name("placeholder").unwrap().as_str();
-        // Validate placeholder can be parsed to a valid type 
-        if let Some(err) = Placeholder::validate(placeholder_str) {
-            errors.push(err);
-            continue;
+    let null_token: &str = matches.value_of("null-token").unwrap_or("null");
+    // Every document reserves the same values of each sequence, enough for its template and its filename.
+    let mut sequence_strides: HashMap<String, u64> = templates.sequence_strides();
+    if let Some(filename_template) = &filename_template {
+        for (name, stride) in filename_template.sequence_strides() {
+            *sequence_strides.entry(name.clone()).or_default() += stride;
         }
+    }
+
+    let run = Run { seed, skip, count: repetitions, jobs };
+    let render = |index: u64, rng: &mut rng::DocumentRng| {
+        let context = &mut RenderContext::new(index);
+        context.null_token = null_token.to_owned();
+        context.seed = seed;
+        context.count = repetitions;
+        context.reserve_sequences(&sequence_strides);
+        let generated_doc: String = envelope.prepare(templates.choose(rng).render_with(context, rng));
+        let filename: Option<String> = filename_template.as_ref()
+            .map(|filename: &Template| filename.render_with(context, rng));
+        (generated_doc, filename)
+    };
 
-        let placeholder: Placeholder = Placeholder::parse(placeholder_str);
-        // Validate placeholder arguments match placeholder type:
-        let data_option: Option<String> = Entity::validate(&placeholder);
-        if let None = data_option {
-            errors.push(PlaceholderParseError { placeholder: placeholder.to_string(), reason: "Invalid arguments for placeholder type".to_owned()});
-            continue;
+    match matches.value_of("output-dir") {
+        Some(directory) => {
+            let output = DirectoryOutput::new(PathBuf::from(directory), !matches.is_present("no-overwrite"))?;
+            run.execute(render, |_, (generated_doc, filename): (String, Option<String>)| {
+                output.write(&filename.unwrap_or_default(), &generated_doc)
+            })?;
+        },
+        None => {
+            let stdout = io::stdout();
+            let mut out = StreamOutput::start(BufWriter::new(stdout.lock()), envelope.clone())?;
+            run.execute(render, |_, (generated_doc, _): (String, Option<String>)| out.write(&generated_doc))?;
+            out.finish()?;
         }
     }
-    errors.clone()
+
+    Ok(())
 }
 
-fn populate_template(template: &str) -> String {
-    let entity_collection = &mut HashMap::new();
+/// Reads a `path` or `path:weight` template argument, where a path of `-` reads from stdin.
+fn read_weighted_template(spec: &str) -> io::Result<(String, CompileOptions, u32)> {
+    let (path, weight): (&str, u32) = match spec.rsplit_once(':') {
+        Some((path, weight)) if !path.is_empty() => match weight.parse::<u32>() {
+            Ok(weight) => (path, weight),
+            Err(_) => (spec, 1)
+        },
+        _ => (spec, 1)
+    };
+    match path {
+        "-" => {
+            let mut template = String::new();
+            io::stdin().read_to_string(&mut template)?;
+            Ok((template, CompileOptions::named("<stdin>"), weight))
+        },
+        _ => std::fs::read_to_string(path)
+            .map(|template: String| (template, CompileOptions::for_file(path), weight))
+            .map_err(|err: io::Error| io::Error::new(err.kind(), format!("Could not read template '{}': {}", path, err)))
+    }
+}
 
-    let populated_template = PLACEHOLDER_REGEX.replace_all(template, |captures: &Captures| {
-        let entity_ref: Option<&mut Entity> = captures.name("entity_id")
-            .map(|id: Match| id.as_str().to_owned())
-            .map(|id: String| entity_collection.entry(id).or_insert(Entity::new()));
+fn report(errors: Vec<PlaceholderParseError>) -> ! {
+    for parse_error in errors {
+        eprintln!("{}\n", parse_error);
+    }
+    std::process::exit(1);
+}
 
-        let placeholder_str: &str = captures.name("placeholder").unwrap().as_str();
-        let placeholder: Placeholder = Placeholder::parse(placeholder_str);
-        match entity_ref {
-            Some(entity) => entity.value_of(&placeholder),
-            None => Entity::new().value_of(&placeholder)
-        }
-    });
-    format!("{}", populated_template)
+fn list_types() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", docgen::describe_types());
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use regex::Match;
-
-    #[test]
-    fn placeholder_regex_with_entity_id () {
-        let caps: Captures = PLACEHOLDER_REGEX.captures("${<id>test}").unwrap();
-        let entity_id: &str = caps.name("entity_id").unwrap().as_str();
-        let placeholder: &str = caps.name("placeholder").unwrap().as_str();
-        assert_eq!("id", entity_id);
-        assert_eq!("test", placeholder);
-    }
+fn explain(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let placeholder: &str = matches.value_of("placeholder").unwrap_or_default();
+    let template: String = match placeholder.contains("${") {
+        true => placeholder.to_owned(),
+        false => format!("${{{}}}", placeholder)
+    };
+    let samples: u64 = matches.value_of("samples").unwrap_or("5").parse::<u64>()?;
+    let compiled_template: Template = Template::compile_with(&template, &CompileOptions::named("explain")).unwrap_or_else(|errors| report(errors));
 
-    #[test]
-    fn placeholder_regex_without_entity_id () {
-        let caps: Captures = PLACEHOLDER_REGEX.captures("${test}").unwrap();
-        let entity_id: Option<Match> = caps.name("entity_id");
-        let placeholder: &str = caps.name("placeholder").unwrap().as_str();
-        assert_eq!(true, entity_id.is_none());
-        assert_eq!("test", placeholder);
+    println!("{}\n", compiled_template.describe());
+    println!("Samples of {}", template);
+    let seed: u64 = rand::random();
+    for index in 0..samples {
+        let context = &mut RenderContext::new(index);
+        println!("    {}", compiled_template.render_with(context, &mut rng::document_rng(seed, index)));
     }
-}
\ No newline at end of file
+    Ok(())
+}
@@ -0,0 +1,230 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+/// What is written before, between and after the documents of a stream.
+#[derive(Clone,Debug)]
+pub struct Envelope {
+    pub header: String,
+    pub separator: String,
+    pub footer: String,
+    pub minify_json: bool
+}
+
+impl Envelope {
+    /// One document per line, as the tool has always printed them.
+    pub fn lines() -> Envelope {
+        Envelope { header: String::new(), separator: "\n".to_owned(), footer: "\n".to_owned(), minify_json: false }
+    }
+
+    /// A single JSON array containing every document.
+    pub fn json_array() -> Envelope {
+        Envelope { header: "[".to_owned(), separator: ",".to_owned(), footer: "]\n".to_owned(), minify_json: false }
+    }
+
+    /// Newline delimited JSON, with each document minified onto a single line.
+    pub fn ndjson() -> Envelope {
+        Envelope { minify_json: true, ..Envelope::lines() }
+    }
+
+    pub fn from_name(name: &str) -> Option<Envelope> {
+        match name {
+            "lines" => Some(Envelope::lines()),
+            "json-array" => Some(Envelope::json_array()),
+            "ndjson" => Some(Envelope::ndjson()),
+            _ => None
+        }
+    }
+
+    /// Applies any per-document transformation. This is kept apart from `StreamOutput`
+    /// so that it can run on the worker threads rendering the documents.
+    pub fn prepare(&self, document: String) -> String {
+        match self.minify_json {
+            true => minify_json(&document),
+            false => document
+        }
+    }
+}
+
+/// Writes documents one after another into a single stream, framed by an `Envelope`.
+pub struct StreamOutput<W: Write> {
+    out: W,
+    envelope: Envelope,
+    written: u64
+}
+
+impl<W: Write> StreamOutput<W> {
+    pub fn start(mut out: W, envelope: Envelope) -> io::Result<StreamOutput<W>> {
+        out.write_all(envelope.header.as_bytes())?;
+        Ok(StreamOutput { out, envelope, written: 0 })
+    }
+
+    pub fn write(&mut self, document: &str) -> io::Result<()> {
+        if self.written > 0 {
+            self.out.write_all(self.envelope.separator.as_bytes())?;
+        }
+        self.out.write_all(document.as_bytes())?;
+        self.written += 1;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        if self.written > 0 || !self.envelope.header.is_empty() {
+            self.out.write_all(self.envelope.footer.as_bytes())?;
+        }
+        self.out.flush()
+    }
+}
+
+/// Removes all whitespace outside of JSON strings.
+pub fn minify_json(document: &str) -> String {
+    let mut minified = String::with_capacity(document.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in document.chars() {
+        if in_string {
+            minified.push(c);
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '"') => in_string = false,
+                _ => ()
+            }
+        } else if c == '"' {
+            in_string = true;
+            minified.push(c);
+        } else if !c.is_whitespace() {
+            minified.push(c);
+        }
+    }
+    minified
+}
+
+/// Interprets the backslash escapes `\n`, `\r`, `\t` and `\\` in a separator given on the command line.
+pub fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('\\') => unescaped.push('\\'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            },
+            None => unescaped.push('\\')
+        }
+    }
+    unescaped
+}
+
+/// Writes every document to its own file inside a directory.
+#[derive(Clone,Debug)]
+pub struct DirectoryOutput {
+    directory: PathBuf,
+    overwrite: bool
+}
+
+impl DirectoryOutput {
+    pub fn new(directory: PathBuf, overwrite: bool) -> io::Result<DirectoryOutput> {
+        fs::create_dir_all(&directory)?;
+        Ok(DirectoryOutput { directory, overwrite })
+    }
+
+    /// Writes `document` to `filename` inside the directory. Names that would leave the directory,
+    /// being absolute or going up with `..`, whether written in the template or generated, are errors.
+    pub fn write(&self, filename: &str, document: &str) -> io::Result<()> {
+        let stays_inside: bool = Path::new(filename).components()
+            .all(|component: Component| matches!(component, Component::Normal(_) | Component::CurDir));
+        if !stays_inside {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Refusing to write '{}' outside of the output directory", filename)));
+        }
+        let path: PathBuf = self.directory.join(filename);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut options = OpenOptions::new();
+        match self.overwrite {
+            true => options.write(true).create(true).truncate(true),
+            false => options.write(true).create_new(true)
+        };
+        let mut file = options.open(&path).map_err(|err: io::Error| match err.kind() {
+            io::ErrorKind::AlreadyExists => io::Error::new(err.kind(), format!("Refusing to overwrite existing file '{}'", path.display())),
+            _ => err
+        })?;
+        file.write_all(document.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(envelope: Envelope, documents: &[&str]) -> String {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut output = StreamOutput::start(&mut buffer, envelope).unwrap();
+        for document in documents {
+            output.write(document).unwrap();
+        }
+        output.finish().unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn json_array_separates_with_commas() {
+        assert_eq!(stream(Envelope::json_array(), &["{}", "{\n}"]), "[{},{\n}]\n");
+        assert_eq!(stream(Envelope::json_array(), &[]), "[]\n");
+    }
+
+    #[test]
+    fn lines_terminates_each_document() {
+        assert_eq!(stream(Envelope::lines(), &["a", "b"]), "a\nb\n");
+        assert_eq!(stream(Envelope::lines(), &[]), "");
+    }
+
+    #[test]
+    fn minify_json_keeps_string_whitespace() {
+        let document = "{\n    \"name\": \"Jo \\\" Smith\",\n    \"age\": 4\n}";
+        assert_eq!(minify_json(document), "{\"name\":\"Jo \\\" Smith\",\"age\":4}");
+    }
+
+    #[test]
+    fn unescape_separators() {
+        assert_eq!(unescape("\\n---\\n"), "\n---\n");
+        assert_eq!(unescape("a\\\\b\\x"), "a\\b\\x");
+    }
+
+    #[test]
+    fn refuses_to_overwrite_unless_allowed() {
+        let directory: PathBuf = std::env::temp_dir().join(format!("docgen-output-{}", std::process::id()));
+        let output = DirectoryOutput::new(directory.clone(), false).unwrap();
+        output.write("doc.json", "first").unwrap();
+        let err = output.write("doc.json", "second").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        DirectoryOutput::new(directory.clone(), true).unwrap().write("doc.json", "second").unwrap();
+        assert_eq!(fs::read_to_string(directory.join("doc.json")).unwrap(), "second");
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn refuses_to_write_outside_the_directory() {
+        let directory: PathBuf = std::env::temp_dir().join(format!("docgen-escape-{}", std::process::id()));
+        let output = DirectoryOutput::new(directory.join("out"), false).unwrap();
+        for filename in &["../escaped-0.txt", "a/../../escaped-1.txt", "/tmp/escaped-2.txt"] {
+            let err = output.write(filename, "document").unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{}", filename);
+        }
+        assert!(!directory.join("escaped-0.txt").exists());
+        output.write("./users/doc.json", "document").unwrap();
+        assert!(directory.join("out/users/doc.json").exists());
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -1,94 +1,386 @@
-use super::types::{PlaceholderArgs, PlaceholderType, DistributionType};
-use regex::{Regex, CaptureMatches};
-use std::collections::HashMap;
+use super::net;
+use crate::generator::distribution::Normal;
+use super::time;
+use super::types::{Arrival, Modifiers, PlaceholderArgs, PlaceholderType, TimeFormat, UsernamePart};
+use super::registry::{self, ArgDefault, ArgSpec, MODIFIER_ARGS};
+use std::convert::TryFrom;
+use std::str::FromStr;
 
-lazy_static! {
-    pub static ref ARGS_REGEX: Regex = Regex::new("(?P<name>[a-zA-Z0-9_]+)[ ]*=[ ]*(?P<value>[^;]+)").unwrap();
-}
+// The most a single text placeholder may produce, so that a typo such as an extra zero
+// cannot make every document, and the validation of the template, allocate gigabytes.
+const MAX_WORDS: u64 = 10_000;
+const MAX_CHARS: u64 = 1_000_000;
+const MAX_PARAGRAPHS: u64 = 1_000;
 
 pub struct PlaceholderArgsParser;
 impl PlaceholderArgsParser {
 
-    pub fn parse_args(placeholder_type: &PlaceholderType, args: &String) -> Option<PlaceholderArgs> {
-        let keyed_args = PlaceholderArgsParser::get_keyed_args(args);
-        match placeholder_type {
-            PlaceholderType::Float => PlaceholderArgsParser::parse_float(&keyed_args),
-            PlaceholderType::Set => PlaceholderArgsParser::parse_set(&keyed_args),
-            PlaceholderType::Int => PlaceholderArgsParser::parse_int(&keyed_args),
-            PlaceholderType::Distribution(DistributionType::Normal) => PlaceholderArgsParser::parse_normal(&keyed_args),
-            _ => None
+    /// Parses the `name=value;...` arguments of a placeholder against the arguments its type
+    /// declares in the registry. Errors are a short reason naming the offending argument, such as "missing arg `max`".
+    /// The modifiers that any placeholder may be given, such as `null=`, are parsed alongside them.
+    pub fn parse_args(placeholder_type: &PlaceholderType, args: &str) -> Result<(Option<PlaceholderArgs>, Modifiers), String> {
+        let spec = registry::for_type(placeholder_type);
+        let mut keyed_args = KeyedArgs::parse(args)?;
+        let modifiers: Modifiers = PlaceholderArgsParser::parse_modifiers(&keyed_args.take(MODIFIER_ARGS).resolve(MODIFIER_ARGS)?)?;
+        let keyed_args = keyed_args.resolve(spec.args)?;
+        Ok(((spec.build)(&keyed_args)?, modifiers))
+    }
+
+    fn parse_modifiers(args: &KeyedArgs) -> Result<Modifiers, String> {
+        let null_probability: Option<f64> = args.get_optional("null");
+        if null_probability.is_some_and(|probability: f64| !(0.0..=1.0).contains(&probability)) {
+            return Err("null must be between 0 and 1".to_owned());
+        }
+        Ok(Modifiers { null_probability })
+    }
+
+    pub(super) fn parse_none(_: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        Ok(None)
+    }
+
+    pub(super) fn parse_int(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (min, max): (i64, i64) = (args.get("min")?, args.get("max")?);
+        if min > max {
+            return Err("min > max".to_owned());
+        }
+        if min == max {
+            return Err("max must be greater than min, as max itself is never generated".to_owned());
+        }
+        match args.get_optional::<u64>("rep") {
+            Some(repeat) => Ok(Some(PlaceholderArgs::IntRepeated { min, max, repeat })),
+            None => Ok(Some(PlaceholderArgs::Int { min, max }))
+        }
+    }
+
+    pub(super) fn parse_float(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (min, max): (f64, f64) = (args.get("min")?, args.get("max")?);
+        if min > max {
+            return Err("min > max".to_owned());
+        }
+        Ok(Some(PlaceholderArgs::Float { min, max }))
+    }
+
+    pub(super) fn parse_set(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let option_str: &str = args.raw("options").ok_or_else(|| "missing arg `options`".to_owned())?;
+        Ok(Some(PlaceholderArgs::Set { options: parse_list(option_str) }))
+    }
+
+    pub(super) fn parse_normal(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (mean, stddev): (f64, f64) = (args.get("mean")?, args.get("stddev")?);
+        Normal::new(mean, stddev)?;
+        Ok(Some(PlaceholderArgs::Normal { mean, stddev }))
+    }
+
+    pub(super) fn parse_index(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        Ok(args.get_optional::<usize>("pad").map(|pad: usize| PlaceholderArgs::Index { pad }))
+    }
+
+    pub(super) fn parse_time(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let now: i64 = time::now();
+        let bound = |name: &str| args.raw(name).map(unquote)
+            .ok_or_else(|| format!("missing arg `{}`", name))
+            .and_then(|value: String| time::parse_bound(&value, now));
+        let (from, to): (i64, i64) = (bound("from")?, bound("to")?);
+        if from > to {
+            return Err("from is after to".to_owned());
+        }
+        let format: TimeFormat = time::parse_format(&args.raw("format").map(unquote).unwrap_or_default())?;
+        Ok(Some(PlaceholderArgs::Time { from, to, format }))
+    }
+
+    pub(super) fn parse_stream(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let start: i64 = time::parse_bound(&args.raw("start").map(unquote).unwrap_or_default(), time::now())?;
+        let mean: &str = args.raw("mean").unwrap_or_default();
+        let mean: i64 = time::parse_duration(mean).ok_or_else(|| format!("invalid duration `{}`, expected e.g. 200ms, 5s or 1h", mean))?;
+        let arrival: Arrival = time::parse_arrival(args.raw("arrival").unwrap_or_default())?;
+        let format: TimeFormat = time::parse_format(&args.raw("format").map(unquote).unwrap_or_default())?;
+        let name: String = args.raw("name").map(unquote).unwrap_or_default();
+        Ok(Some(PlaceholderArgs::Stream { start, mean: mean as f64, arrival, format, name }))
+    }
+
+    pub(super) fn parse_sequence(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (start, step): (i64, i64) = (args.get("start")?, args.get("step")?);
+        let name: Option<String> = args.raw("name").map(unquote);
+        Ok(Some(PlaceholderArgs::Sequence { start, step, name }))
+    }
+
+    pub(super) fn parse_username(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (pattern, lowercase): (Vec<UsernamePart>, bool) = parse_username_args(args)?;
+        Ok(Some(PlaceholderArgs::Username { pattern, lowercase }))
+    }
+
+    pub(super) fn parse_email(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (pattern, lowercase): (Vec<UsernamePart>, bool) = parse_username_args(args)?;
+        let domains: Vec<String> = args.raw("domains").map(parse_list).unwrap_or_default();
+        if let Some(domain) = domains.iter().find(|domain: &&String| !net::is_domain(domain)) {
+            return Err(format!("invalid domain `{}`", domain));
         }
+        Ok(Some(PlaceholderArgs::Email { pattern, lowercase, domains }))
     }
 
-    fn get_keyed_args(args: &String) -> HashMap<String, String> {
-        let mut keyed_args = HashMap::new();
-        let matches: CaptureMatches = ARGS_REGEX.captures_iter(args.as_str());
-        for captures in matches {
-            let name = captures.name("name").unwrap().as_str().to_owned();
-            let value = captures.name("value").unwrap().as_str().to_owned();
-            keyed_args.insert(name, value);
+    pub(super) fn parse_ipv4(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (network, prefix): (u32, u32) = net::parse_ipv4_cidr(args.raw("cidr").unwrap_or_default())?;
+        Ok(Some(PlaceholderArgs::Ipv4 { network, prefix }))
+    }
+
+    pub(super) fn parse_ipv6(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (network, prefix): (u128, u32) = net::parse_ipv6_cidr(args.raw("cidr").unwrap_or_default())?;
+        Ok(Some(PlaceholderArgs::Ipv6 { network, prefix }))
+    }
+
+    pub(super) fn parse_hostname(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        Ok(Some(PlaceholderArgs::Host { domain: parse_domain(args)? }))
+    }
+
+    pub(super) fn parse_url(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let scheme: &str = args.raw("scheme").unwrap_or_default();
+        let valid_scheme: bool = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme.chars().all(|c: char| c.is_ascii_alphanumeric() || "+-.".contains(c));
+        if !valid_scheme {
+            return Err(format!("invalid scheme `{}`, expected e.g. https", scheme));
         }
-        return keyed_args;
+        Ok(Some(PlaceholderArgs::Url { scheme: scheme.to_lowercase(), domain: parse_domain(args)? }))
     }
 
-    fn parse_int(args: &HashMap<String, String>) -> Option<PlaceholderArgs> {
-        let min_arg = args.get("min").and_then(|val| val.parse::<i64>().ok());
-        let max_arg = args.get("max").and_then(|val| val.parse::<i64>().ok());
-        let rep_arg = args.get("rep").and_then(|val| val.parse::<u64>().ok());
-        match (min_arg, max_arg, rep_arg) {
-            (Some(min), Some(max), Some(rep)) => Some(PlaceholderArgs::IntRepeated { min: min, max: max, repeat: rep }),
-            (Some(min), Some(max), None) => Some(PlaceholderArgs::Int { min: min, max: max }),
-            _ => None
+    pub(super) fn parse_port(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (min, max): (u16, u16) = (port(args, "min")?, port(args, "max")?);
+        if min > max {
+            return Err("min > max".to_owned());
         }
+        Ok(Some(PlaceholderArgs::Port { min, max }))
     }
 
-    fn parse_float(args: &HashMap<String, String>) -> Option<PlaceholderArgs> {
-        let min_val = args.get("min").and_then(|min| min.parse::<f64>().ok());
-        let max_val = args.get("max").and_then(|max| max.parse::<f64>().ok());
-        if min_val.is_some() && max_val.is_some() {
-            return Some(PlaceholderArgs::Float {
-                min: min_val.unwrap(), max: max_val.unwrap() 
-            });
+    pub(super) fn parse_length(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let (min, max): (u64, u64) = (args.get("min")?, args.get("max")?);
+        if min > max {
+            return Err("min > max".to_owned());
         }
-        
-        return None;
+        if max > MAX_WORDS {
+            return Err(format!("max > {}", MAX_WORDS));
+        }
+        Ok(Some(PlaceholderArgs::Length { min, max }))
     }
 
-    fn parse_set(args: &HashMap<String, String>) -> Option<PlaceholderArgs> {
-        let parsed_options: Option<Vec<String>> = args.get("options").map(|option_str: &String| 
-            option_str
-                .trim_start_matches("[")
-                .trim_end_matches("]")
-                .split(",")
-                .into_iter()
-                .map(|val: &str| val.trim().to_owned())
-                .collect()
-        );
-        parsed_options.map(|options: Vec<String>| PlaceholderArgs::Set { options: options })
+    pub(super) fn parse_chars(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let max: u64 = args.get("max")?;
+        // Without a min, texts are from half of max up to max characters long.
+        let min: u64 = args.get_optional("min").unwrap_or(max / 2);
+        if min > max {
+            return Err("min > max".to_owned());
+        }
+        if max > MAX_CHARS {
+            return Err(format!("max > {}", MAX_CHARS));
+        }
+        Ok(Some(PlaceholderArgs::Length { min, max }))
     }
 
-    fn parse_normal(args: &HashMap<String, String>) -> Option<PlaceholderArgs> {
-        let mean_val: Option<f64> = args.get("mean").and_then(|mean| mean.parse::<f64>().ok());
-        let stddev_val: Option<f64> = args.get("stddev").and_then(|stddev| stddev.parse::<f64>().ok())
-            .and_then(|val: f64| match val < 0.0 {
-                true => None,
-                false => Some(val)
-            });
-        if mean_val.is_some() && stddev_val.is_some() {
-            return Some(PlaceholderArgs::Normal { mean: mean_val.unwrap(), stddev: stddev_val.unwrap() });
+    pub(super) fn parse_paragraphs(args: &KeyedArgs) -> Result<Option<PlaceholderArgs>, String> {
+        let count: u64 = args.get("count")?;
+        if count > MAX_PARAGRAPHS {
+            return Err(format!("count > {}", MAX_PARAGRAPHS));
         }
-        None
+        Ok(Some(PlaceholderArgs::Paragraphs { count }))
+    }
+}
+
+fn parse_domain(args: &KeyedArgs) -> Result<Option<String>, String> {
+    match args.raw("domain").map(unquote) {
+        Some(domain) if !net::is_domain(&domain) => Err(format!("invalid domain `{}`", domain)),
+        domain => Ok(domain)
     }
 }
 
+fn port(args: &KeyedArgs, name: &str) -> Result<u16, String> {
+    u16::try_from(args.get::<u64>(name)?).map_err(|_| format!("{} > 65535", name))
+}
+
+fn parse_username_args(args: &KeyedArgs) -> Result<(Vec<UsernamePart>, bool), String> {
+    let pattern: Vec<UsernamePart> = parse_username_pattern(&args.raw("pattern").map(unquote).unwrap_or_default())?;
+    let lowercase: bool = match args.raw("case").unwrap_or_default() {
+        "lower" => true,
+        "keep" => false,
+        case => return Err(format!("invalid case `{}`, expected lower or keep", case))
+    };
+    Ok((pattern, lowercase))
+}
+
+/// Reads a pattern such as `{first}.{last}{nn}` into the text and name parts it is made of.
+fn parse_username_pattern(pattern: &str) -> Result<Vec<UsernamePart>, String> {
+    let mut parts: Vec<UsernamePart> = Vec::new();
+    let mut rest: &str = pattern;
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            parts.push(UsernamePart::Text(rest[..start].to_owned()));
+        }
+        let end: usize = rest[start..].find('}')
+            .map(|end: usize| start + end)
+            .ok_or_else(|| format!("unclosed `{{` in pattern `{}`", pattern))?;
+        let part: UsernamePart = match &rest[start + 1..end] {
+            "first" => UsernamePart::First,
+            "last" => UsernamePart::Last,
+            "f" => UsernamePart::FirstInitial,
+            "l" => UsernamePart::LastInitial,
+            digits if !digits.is_empty() && digits.len() <= 9 && digits.chars().all(|c: char| c == 'n') => UsernamePart::Digits(digits.len()),
+            token => return Err(format!("unknown `{{{}}}` in pattern, expected {{first}}, {{last}}, {{f}}, {{l}} or digits such as {{nn}}", token))
+        };
+        parts.push(part);
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(UsernamePart::Text(rest.to_owned()));
+    }
+    let valid_text = |part: &UsernamePart| match part {
+        UsernamePart::Text(text) => text.chars().all(|c: char| c.is_ascii_alphanumeric() || "._-+".contains(c)),
+        _ => true
+    };
+    match parts.iter().all(valid_text) {
+        true => Ok(parts),
+        false => Err(format!("invalid pattern `{}`, text between tokens may only contain letters, digits and . _ - +", pattern))
+    }
+}
+
+/// Reads a list such as `[a,b,"c,d"]`, with or without its brackets.
+fn parse_list(value: &str) -> Vec<String> {
+    let list: &str = match value.starts_with('[') && value.ends_with(']') {
+        true => &value[1..value.len() - 1],
+        false => value
+    };
+    split_top_level(list, ',').into_iter()
+        .map(|val: &str| unquote(val.trim()))
+        .collect()
+}
+
+/// The `name=value` pairs of a placeholder, in the order they were written.
+pub(super) struct KeyedArgs {
+    values: Vec<(String, String)>
+}
+
+impl KeyedArgs {
+    pub(super) fn parse(args: &str) -> Result<KeyedArgs, String> {
+        let mut values: Vec<(String, String)> = Vec::new();
+        for arg in split_top_level(args, ';').into_iter().map(str::trim).filter(|arg: &&str| !arg.is_empty()) {
+            let equals: usize = arg.find('=').ok_or_else(|| format!("malformed arg `{}`, expected `name=value`", arg))?;
+            let (name, value) = (arg[..equals].trim(), arg[equals + 1..].trim());
+            if name.is_empty() {
+                return Err(format!("malformed arg `{}`, expected `name=value`", arg));
+            }
+            if values.iter().any(|(existing, _)| existing == name) {
+                return Err(format!("duplicate arg `{}`", name));
+            }
+            values.push((name.to_owned(), value.to_owned()));
+        }
+        Ok(KeyedArgs { values })
+    }
+
+    /// Checks the arguments against those declared by a placeholder type, and fills in defaults.
+    pub(super) fn resolve(mut self, specs: &[ArgSpec]) -> Result<KeyedArgs, String> {
+        if let Some((name, _)) = self.values.iter().find(|(name, _)| !specs.iter().any(|spec: &ArgSpec| spec.name == name)) {
+            return match specs.is_empty() {
+                true => Err(format!("unknown arg `{}`, this placeholder takes no arguments", name)),
+                false => Err(format!("unknown arg `{}`", name))
+            };
+        }
+        for spec in specs {
+            match (self.raw(spec.name), spec.default) {
+                (Some(value), _) => {
+                    if !spec.kind.accepts(&unquote(value)) {
+                        return Err(format!("invalid value `{}` for arg `{}`, expected {}", value, spec.name, spec.kind.describe()));
+                    }
+                },
+                (None, ArgDefault::Required) => return Err(format!("missing arg `{}`", spec.name)),
+                (None, ArgDefault::Optional) => (),
+                (None, ArgDefault::Value(default)) => self.values.push((spec.name.to_owned(), default.to_owned()))
+            }
+        }
+        Ok(self)
+    }
+
+    /// Removes and returns the arguments named in `specs`.
+    fn take(&mut self, specs: &[ArgSpec]) -> KeyedArgs {
+        let (taken, kept) = self.values.drain(..)
+            .partition(|(name, _): &(String, String)| specs.iter().any(|spec: &ArgSpec| spec.name == name));
+        self.values = kept;
+        KeyedArgs { values: taken }
+    }
+
+    pub(super) fn raw(&self, name: &str) -> Option<&str> {
+        self.values.iter()
+            .find(|(arg, _)| arg == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn get_optional<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.raw(name).and_then(|value: &str| unquote(value).parse::<T>().ok())
+    }
+
+    pub(super) fn get<T: FromStr>(&self, name: &str) -> Result<T, String> {
+        self.get_optional(name).ok_or_else(|| format!("missing arg `{}`", name))
+    }
+}
+
+/// Splits `text` on `separator`, ignoring separators inside double quotes or brackets.
+pub(super) fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts: Vec<&str> = Vec::new();
+    let mut depth: usize = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut start: usize = 0;
+    for (index, c) in text.char_indices() {
+        if in_quotes {
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '"') => in_quotes = false,
+                _ => ()
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            },
+            _ => ()
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Removes the quotes around a double quoted value and resolves its `\"`, `\\`, `\n` and `\t` escapes.
+/// Values that are not quoted are returned unchanged.
+pub(super) fn unquote(value: &str) -> String {
+    if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return value.to_owned();
+    }
+    let mut unquoted = String::with_capacity(value.len());
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => unquoted.push('\n'),
+                Some('t') => unquoted.push('\t'),
+                Some(escaped) => unquoted.push(escaped),
+                None => unquoted.push('\\')
+            },
+            c => unquoted.push(c)
+        }
+    }
+    unquoted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{DistributionType, DocType, InternetType, TextType};
 
     #[test]
     fn parse_int_args() {
         let args = "min=1;max=2".to_owned();
-        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Int, &args).unwrap();
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Int, &args).unwrap().0.unwrap();
         match parsed_args {
             PlaceholderArgs::Int { min, max } => {
                 assert_eq!(min, 1);
@@ -98,10 +390,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_int_args_at_the_extremes() {
+        let parsed_args = PlaceholderArgsParser::parse_args(&PlaceholderType::Int, "min=-9223372036854775808;max=9223372036854775807").unwrap().0;
+        assert_eq!(parsed_args, Some(PlaceholderArgs::Int { min: i64::MIN, max: i64::MAX }));
+    }
+
     #[test]
     fn parse_int_rep_args() {
         let args = "min=1;max=2;rep=3".to_owned();
-        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Int, &args).unwrap();
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Int, &args).unwrap().0.unwrap();
         match parsed_args {
             PlaceholderArgs::IntRepeated { min, max, repeat } => {
                 assert_eq!(min, 1);
@@ -115,7 +413,7 @@ mod tests {
     #[test]
     fn parse_float_args() {
         let args = "min=1;max=2".to_owned();
-        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Float, &args).unwrap();
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Float, &args).unwrap().0.unwrap();
         match parsed_args {
             PlaceholderArgs::Float { min, max } => {
                 assert_eq!(min, 1f64);
@@ -128,7 +426,7 @@ mod tests {
     #[test]
     fn parse_set_args() {
         let args = "options=[1,2,3,4]".to_owned();
-        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Set, &args).unwrap();
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Set, &args).unwrap().0.unwrap();
         match parsed_args {
             PlaceholderArgs::Set { options } => {
                 assert_eq!(options, vec!["1", "2", "3", "4"]);
@@ -137,10 +435,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_set_args_with_quoted_options() {
+        let args = r#"options=["a;b", "c}d", "say \"hi\""]"#.to_owned();
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Set, &args).unwrap().0.unwrap();
+        match parsed_args {
+            PlaceholderArgs::Set { options } => {
+                assert_eq!(options, vec!["a;b", "c}d", "say \"hi\""]);
+            },
+            arg_type => panic!("Set args were not parsed to PlaceholderArgs::Set. Actual: {:?}", arg_type)
+        }
+    }
+
     #[test]
     fn parse_normal_args() {
         let args = "mean=1;stddev=2".to_owned();
-        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Distribution(DistributionType::Normal), &args).unwrap();
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Distribution(DistributionType::Normal), &args).unwrap().0.unwrap();
         match parsed_args {
             PlaceholderArgs::Normal { mean, stddev } => {
                 assert_eq!(mean, 1f64);
@@ -149,4 +459,85 @@ mod tests {
             arg_type => panic!("Normal args were not parsed to PlaceholderArgs::Normal. Actual: {:?}", arg_type)
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_index_args() {
+        let args = "pad=6".to_owned();
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Doc(DocType::Index), &args).unwrap().0.unwrap();
+        match parsed_args {
+            PlaceholderArgs::Index { pad } => assert_eq!(pad, 6),
+            arg_type => panic!("Index args were not parsed to PlaceholderArgs::Index. Actual: {:?}", arg_type)
+        }
+    }
+
+    #[test]
+    fn parse_email_args() {
+        let args = "pattern={f}_{last}{nnn};domains=[example.com,mail.example.org];case=keep";
+        let parsed_args: PlaceholderArgs = PlaceholderArgsParser::parse_args(&PlaceholderType::Internet(InternetType::Email), args).unwrap().0.unwrap();
+        match parsed_args {
+            PlaceholderArgs::Email { pattern, lowercase, domains } => {
+                assert_eq!(pattern, vec![UsernamePart::FirstInitial, UsernamePart::Text("_".to_owned()), UsernamePart::Last, UsernamePart::Digits(3)]);
+                assert!(!lowercase);
+                assert_eq!(domains, vec!["example.com", "mail.example.org"]);
+            },
+            arg_type => panic!("Email args were not parsed to PlaceholderArgs::Email. Actual: {:?}", arg_type)
+        }
+    }
+
+    #[test]
+    fn username_pattern_errors() {
+        let parse = |args: &str| PlaceholderArgsParser::parse_args(&PlaceholderType::Internet(InternetType::Username), args).unwrap_err();
+        assert_eq!(parse("pattern={middle}"), "unknown `{middle}` in pattern, expected {first}, {last}, {f}, {l} or digits such as {nn}");
+        assert_eq!(parse("pattern={first"), "unclosed `{` in pattern `{first`");
+        assert_eq!(parse("pattern=\"{first} {last}\""), "invalid pattern `{first} {last}`, text between tokens may only contain letters, digits and . _ - +");
+        assert_eq!(parse("case=upper"), "invalid case `upper`, expected lower or keep");
+        let email = PlaceholderArgsParser::parse_args(&PlaceholderType::Internet(InternetType::Email), "domains=[example..com]").unwrap_err();
+        assert_eq!(email, "invalid domain `example..com`");
+    }
+
+    #[test]
+    fn specific_arg_errors() {
+        let parse = |placeholder_type: PlaceholderType, args: &str| PlaceholderArgsParser::parse_args(&placeholder_type, args).unwrap_err();
+        assert_eq!(parse(PlaceholderType::Set, ""), "missing arg `options`");
+        assert_eq!(parse(PlaceholderType::Int, "min=0;mx=5"), "unknown arg `mx`");
+        assert_eq!(parse(PlaceholderType::Int, "min=5;max=1"), "min > max");
+        assert_eq!(parse(PlaceholderType::Int, "min=5;max=5"), "max must be greater than min, as max itself is never generated");
+        assert_eq!(parse(PlaceholderType::Int, "min=-9223372036854775809;max=0"), "invalid value `-9223372036854775809` for arg `min`, expected an integer");
+        assert_eq!(parse(PlaceholderType::Float, "min=a;max=1"), "invalid value `a` for arg `min`, expected a number");
+        assert_eq!(parse(PlaceholderType::Int, "min=0;max=5;rep=-1"), "invalid value `-1` for arg `rep`, expected a positive integer");
+        assert_eq!(parse(PlaceholderType::Float, "1"), "malformed arg `1`, expected `name=value`");
+        assert_eq!(parse(PlaceholderType::Distribution(DistributionType::Normal), "stddev=nan"), "invalid value `nan` for arg `stddev`, expected a number");
+        assert_eq!(parse(PlaceholderType::Distribution(DistributionType::Normal), "mean=inf"), "invalid value `inf` for arg `mean`, expected a number");
+        assert_eq!(parse(PlaceholderType::Distribution(DistributionType::Normal), "stddev=-1"), "stddev < 0");
+        assert_eq!(parse(PlaceholderType::Guid, "v=4"), "unknown arg `v`, this placeholder takes no arguments");
+    }
+
+    #[test]
+    fn text_lengths_are_capped() {
+        let parse = |text_type: TextType, args: &str| PlaceholderArgsParser::parse_args(&PlaceholderType::Text(text_type), args);
+        assert_eq!(parse(TextType::Words, "min=0;max=10000").unwrap().0, Some(PlaceholderArgs::Length { min: 0, max: 10_000 }));
+        assert_eq!(parse(TextType::Words, "min=0;max=10001").unwrap_err(), "max > 10000");
+        assert_eq!(parse(TextType::Sentence, "min=0;max=18446744073709551615").unwrap_err(), "max > 10000");
+        assert_eq!(parse(TextType::Chars, "max=1000000").unwrap().0, Some(PlaceholderArgs::Length { min: 500_000, max: 1_000_000 }));
+        assert_eq!(parse(TextType::Chars, "max=18446744073709551615").unwrap_err(), "max > 1000000");
+        assert_eq!(parse(TextType::Paragraphs, "count=1001").unwrap_err(), "count > 1000");
+    }
+
+    #[test]
+    fn missing_args_use_registered_defaults() {
+        let parsed_args = PlaceholderArgsParser::parse_args(&PlaceholderType::Int, "max=10").unwrap().0;
+        assert_eq!(parsed_args, Some(PlaceholderArgs::Int { min: 0, max: 10 }));
+        let parsed_args = PlaceholderArgsParser::parse_args(&PlaceholderType::Distribution(DistributionType::Normal), "").unwrap().0;
+        assert_eq!(parsed_args, Some(PlaceholderArgs::Normal { mean: 0.0, stddev: 1.0 }));
+    }
+
+    #[test]
+    fn null_modifier_is_accepted_by_every_type() {
+        let (parsed_args, modifiers) = PlaceholderArgsParser::parse_args(&PlaceholderType::Int, "max=10;null=0.25").unwrap();
+        assert_eq!(parsed_args, Some(PlaceholderArgs::Int { min: 0, max: 10 }));
+        assert_eq!(modifiers.null_probability, Some(0.25));
+        let (_, modifiers) = PlaceholderArgsParser::parse_args(&PlaceholderType::Guid, "null=1").unwrap();
+        assert_eq!(modifiers.null_probability, Some(1.0));
+        assert_eq!(PlaceholderArgsParser::parse_args(&PlaceholderType::Guid, "null=2").unwrap_err(), "null must be between 0 and 1");
+    }
+}
@@ -0,0 +1,86 @@
+use super::args::{self, KeyedArgs};
+use super::registry::{ArgDefault, ArgKind, ArgSpec};
+use super::types::BlockType;
+
+/// The most times a block may repeat, which keeps a typo in `max` from rendering without end.
+const MAX_REPETITIONS: u64 = 10_000;
+
+pub const REPEAT_ARGS: &[ArgSpec] = &[
+    ArgSpec { name: "min", kind: ArgKind::Count, default: ArgDefault::Value("0"), help: "Fewest repetitions" },
+    ArgSpec { name: "max", kind: ArgKind::Count, default: ArgDefault::Required, help: "Most repetitions, included, up to 10000" },
+    ArgSpec { name: "sep", kind: ArgKind::Text, default: ArgDefault::Optional, help: "Text written between repetitions, e.g. \",\" for JSON arrays" }
+];
+
+pub const MAYBE_ARGS: &[ArgSpec] = &[
+    ArgSpec { name: "p", kind: ArgKind::Number, default: ArgDefault::Value("0.5"), help: "Probability, from 0 to 1, of rendering the section" }
+];
+
+/// Parses the directive of a block opening without its `#`, such as `repeat min=1;max=5;sep=","`.
+pub fn parse_open(directive: &str) -> Result<BlockType, String> {
+    let directive: &str = directive.trim();
+    let (name, args): (&str, &str) = match directive.find(|c: char| c.is_whitespace() || c == ':') {
+        Some(end) => (&directive[..end], &directive[end + 1..]),
+        None => (directive, "")
+    };
+    match name {
+        "repeat" => parse_repeat(&KeyedArgs::parse(args)?.resolve(REPEAT_ARGS)?),
+        "maybe" => parse_maybe(&KeyedArgs::parse(args)?.resolve(MAYBE_ARGS)?),
+        _ => Err(format!("unknown block `{}`", name))
+    }
+}
+
+/// The name a block is opened and closed with.
+pub fn name(block: &BlockType) -> &'static str {
+    match block {
+        BlockType::Repeat { .. } => "repeat",
+        BlockType::Maybe { .. } => "maybe"
+    }
+}
+
+fn parse_repeat(args: &KeyedArgs) -> Result<BlockType, String> {
+    let (min, max): (u64, u64) = (args.get("min")?, args.get("max")?);
+    if min > max {
+        return Err("min > max".to_owned());
+    }
+    if max > MAX_REPETITIONS {
+        return Err(format!("max > {}", MAX_REPETITIONS));
+    }
+    let separator: String = args.raw("sep").map(args::unquote).unwrap_or_default();
+    Ok(BlockType::Repeat { min, max, separator })
+}
+
+fn parse_maybe(args: &KeyedArgs) -> Result<BlockType, String> {
+    let probability: f64 = args.get("p")?;
+    if !(0.0..=1.0).contains(&probability) {
+        return Err("p must be between 0 and 1".to_owned());
+    }
+    Ok(BlockType::Maybe { probability })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_repeat_block() {
+        assert_eq!(parse_open(r#"repeat min=1;max=5;sep=",\n""#), Ok(BlockType::Repeat { min: 1, max: 5, separator: ",\n".to_owned() }));
+        assert_eq!(parse_open("repeat max=2"), Ok(BlockType::Repeat { min: 0, max: 2, separator: String::new() }));
+        assert_eq!(parse_open("repeat min=10000;max=10000"), Ok(BlockType::Repeat { min: 10_000, max: 10_000, separator: String::new() }));
+    }
+
+    #[test]
+    fn parse_maybe_block() {
+        assert_eq!(parse_open("maybe p=0.3"), Ok(BlockType::Maybe { probability: 0.3 }));
+        assert_eq!(parse_open("maybe"), Ok(BlockType::Maybe { probability: 0.5 }));
+        assert_eq!(parse_open("maybe p=1.5"), Err("p must be between 0 and 1".to_owned()));
+    }
+
+    #[test]
+    fn repeat_block_errors() {
+        assert_eq!(parse_open("repeat"), Err("missing arg `max`".to_owned()));
+        assert_eq!(parse_open("repeat min=3;max=2"), Err("min > max".to_owned()));
+        assert_eq!(parse_open("repeat max=10001"), Err("max > 10000".to_owned()));
+        assert_eq!(parse_open("repeat max=18446744073709551615"), Err("max > 10000".to_owned()));
+        assert_eq!(parse_open("loop max=2"), Err("unknown block `loop`".to_owned()));
+    }
+}
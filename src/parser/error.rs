@@ -1,21 +1,162 @@
+use std::fmt;
+use std::ops::Range;
+
 #[derive(Debug, Clone)]
 pub struct PlaceholderParseError {
     pub placeholder: String,
-    pub reason: String
+    pub reason: String,
+    pub location: Option<Box<SourceLocation>>
+}
+
+/// Where in a template an error was found, along with the line of source it was found on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceLocation {
+    pub file: Option<String>,
+    /// One-based line number.
+    pub line: usize,
+    /// One-based column, counted in characters.
+    pub column: usize,
+    pub source_line: String,
+    /// Number of characters to underline, starting at `column`.
+    pub length: usize,
+    /// The `file:line:column` of each `${include:...}` that led to this location, innermost first.
+    pub included_from: Vec<String>
+}
+
+impl SourceLocation {
+    /// Locates the byte range `span` within `template`.
+    pub fn of(template: &str, span: Range<usize>) -> SourceLocation {
+        let line_start: usize = template[..span.start].rfind('\n').map_or(0, |newline: usize| newline + 1);
+        let line_end: usize = template[span.start..].find('\n').map_or(template.len(), |newline: usize| span.start + newline);
+        let line_end: usize = match template[..line_end].ends_with('\r') {
+            true => line_end - 1,
+            false => line_end
+        };
+        let source_line: &str = &template[line_start..line_end];
+        // Spans covering several lines are only underlined up to the end of their first line.
+        let underlined: &str = &template[span.start..span.end.min(line_end).max(span.start)];
+        SourceLocation {
+            file: None,
+            line: template[..span.start].matches('\n').count() + 1,
+            column: template[line_start..span.start].chars().count() + 1,
+            source_line: source_line.to_owned(),
+            length: underlined.chars().count().max(1),
+            included_from: Vec::new()
+        }
+    }
 }
 
 impl PlaceholderParseError {
     pub fn invalid_placeholder(placeholder_string: &str) -> PlaceholderParseError {
         PlaceholderParseError {
             placeholder: String::from(placeholder_string),
-            reason: String::from("Placeholder type not supported.")
+            reason: String::from("placeholder is not of the form `type` or `type:arg=value;...`"),
+            location: None
+        }
+    }
+
+    pub fn unknown_type(placeholder_string: &str, data_type: &str) -> PlaceholderParseError {
+        PlaceholderParseError {
+            placeholder: String::from(placeholder_string),
+            reason: format!("unknown placeholder type `{}`", data_type),
+            location: None
+        }
+    }
+
+    pub fn invalid_arg(placeholder_string: &str, reason: String) -> PlaceholderParseError {
+        PlaceholderParseError {
+            placeholder: placeholder_string.to_owned(),
+            reason,
+            location: None
+        }
+    }
+
+    pub fn unterminated(template_rest: &str) -> PlaceholderParseError {
+        let placeholder: String = template_rest.lines().next().unwrap_or_default().to_owned();
+        PlaceholderParseError {
+            placeholder,
+            reason: String::from("placeholder is never closed with `}`"),
+            location: None
         }
     }
 
-    pub fn invalid_arg(placeholder_string: &String, arg_string: &String) -> PlaceholderParseError {
-        PlaceholderParseError { 
-            placeholder: placeholder_string.clone(),
-            reason: format!("Invalid argument for placeholder '{}'. Argument(s) provided '{}' were invalid.", &placeholder_string, &arg_string)
+    pub fn invalid_block(directive: &str, reason: String) -> PlaceholderParseError {
+        PlaceholderParseError {
+            placeholder: directive.to_owned(),
+            reason,
+            location: None
+        }
+    }
+
+    pub fn at(mut self, location: SourceLocation) -> PlaceholderParseError {
+        self.location = Some(Box::new(location));
+        self
+    }
+
+    /// Names the file the template was read from, for errors that have a location in no file yet.
+    pub fn in_file(mut self, file: &str) -> PlaceholderParseError {
+        if let Some(location) = self.location.as_mut() {
+            location.file.get_or_insert_with(|| file.to_owned());
+        }
+        self
+    }
+}
+
+impl fmt::Display for PlaceholderParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "error: {}", self.reason)?;
+        match &self.location {
+            Some(location) => {
+                let line_number: String = location.line.to_string();
+                let gutter: String = " ".repeat(line_number.len());
+                writeln!(f, "{}--> {}:{}:{}", gutter, location.file.as_deref().unwrap_or("<template>"), location.line, location.column)?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", line_number, location.source_line)?;
+                write!(f, "{} | {}{}", gutter, " ".repeat(location.column - 1), "^".repeat(location.length))?;
+                for include in &location.included_from {
+                    write!(f, "\n{} = note: included from {}", gutter, include)?;
+                }
+                Ok(())
+            },
+            None => write!(f, "  in placeholder '{}'", self.placeholder)
         }
     }
-}
\ No newline at end of file
+}
+
+impl std::error::Error for PlaceholderParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_span_on_later_line() {
+        let template = "{\n    \"age\": ${int:min=0},\n}";
+        let start: usize = template.find('$').unwrap();
+        let location = SourceLocation::of(template, start..start + 13);
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 12);
+        assert_eq!(location.source_line, "    \"age\": ${int:min=0},");
+        assert_eq!(location.length, 13);
+    }
+
+    #[test]
+    fn displays_caret_under_placeholder() {
+        let template = "name: ${nme}";
+        let error = PlaceholderParseError::unknown_type("nme", "nme")
+            .at(SourceLocation::of(template, 6..12))
+            .in_file("person.tpl");
+        let expected = "error: unknown placeholder type `nme`\n --> person.tpl:1:7\n  |\n1 | name: ${nme}\n  |       ^^^^^^";
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn displays_include_chain() {
+        let mut location = SourceLocation::of("${nme}", 0..6);
+        location.file = Some("partials/person.tpl".to_owned());
+        location.included_from = vec!["main.tpl:3:5".to_owned()];
+        let error = PlaceholderParseError::unknown_type("nme", "nme").at(location).in_file("main.tpl");
+        let expected = "error: unknown placeholder type `nme`\n --> partials/person.tpl:1:1\n  |\n1 | ${nme}\n  | ^^^^^^\n  = note: included from main.tpl:3:5";
+        assert_eq!(error.to_string(), expected);
+    }
+}
@@ -0,0 +1,524 @@
+use super::{split_entity_id, Placeholder};
+
+use std::fmt;
+
+/// A parsed `${= ...}` expression, such as `round($price * $qty + {float:min=0;max=10}, 2)`.
+#[derive(Clone,Debug)]
+pub enum Expression {
+    Number(f64),
+    Bool(bool),
+    /// A `$name` reference to a variable.
+    Variable(String),
+    /// A `{...}` placeholder embedded in the expression, optionally belonging to an entity.
+    Placeholder { entity_id: Option<String>, placeholder: Placeholder },
+    Unary(UnaryOp, Box<Expression>),
+    Binary(BinaryOp, Box<Expression>, Box<Expression>),
+    Call(Function, Vec<Expression>)
+}
+
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum UnaryOp {
+    Negate,
+    Not
+}
+
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum BinaryOp {
+    Add, Subtract, Multiply, Divide, Remainder,
+    Equal, NotEqual, Less, LessOrEqual, Greater, GreaterOrEqual,
+    And, Or
+}
+
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Function {
+    Round, Floor, Ceil, Abs, Min, Max, If
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Function> {
+        match name {
+            "round" => Some(Function::Round),
+            "floor" => Some(Function::Floor),
+            "ceil" => Some(Function::Ceil),
+            "abs" => Some(Function::Abs),
+            "min" => Some(Function::Min),
+            "max" => Some(Function::Max),
+            "if" => Some(Function::If),
+            _ => None
+        }
+    }
+
+    /// The smallest and largest number of arguments the function takes.
+    fn arity(self) -> (usize, usize) {
+        match self {
+            Function::Round => (1, 2),
+            Function::Floor | Function::Ceil | Function::Abs => (1, 1),
+            Function::Min | Function::Max => (1, usize::MAX),
+            Function::If => (3, 3)
+        }
+    }
+}
+
+/// The result of evaluating an expression, or one of its operands.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Value {
+    Number(f64),
+    Bool(bool),
+    Null
+}
+
+impl Value {
+    /// Reads a generated value as an operand: `true`, `false` or a number.
+    pub fn parse(text: &str) -> Result<Value, String> {
+        match text.trim() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            trimmed => trimmed.parse::<f64>().ok()
+                .filter(|number: &f64| number.is_finite())
+                .map(Value::Number)
+                .ok_or_else(|| format!("`{}` is not a number", text))
+        }
+    }
+
+    /// Null in place of a number that is infinite or not a number, such as the result of dividing by zero,
+    /// which has no place in a JSON, XML, CSV or SQL document.
+    fn finite(self) -> Value {
+        match self {
+            Value::Number(number) if !number.is_finite() => Value::Null,
+            value => value
+        }
+    }
+
+    fn number(self) -> Result<f64, String> {
+        match self {
+            Value::Number(number) => Ok(number),
+            value => Err(format!("expected a number, found {}", value))
+        }
+    }
+
+    fn bool(self) -> Result<bool, String> {
+        match self {
+            Value::Bool(value) => Ok(value),
+            value => Err(format!("expected true or false, found {}", value))
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Number(number) if number.fract() == 0.0 && number.abs() < 1e15 => write!(f, "{}", *number as i64),
+            Value::Number(number) => write!(f, "{}", number),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Null => write!(f, "null")
+        }
+    }
+}
+
+impl Expression {
+    pub fn parse(text: &str) -> Result<Expression, String> {
+        let mut parser = ExpressionParser { text, position: 0 };
+        let expression: Expression = parser.or()?;
+        parser.skip_whitespace();
+        match parser.position == text.len() {
+            true => Ok(expression),
+            false => Err(format!("unexpected `{}` in expression", parser.rest()))
+        }
+    }
+
+    /// Evaluates the expression, using `resolve` to look up its variables and placeholders.
+    /// A null operand makes the whole expression null, as does dividing by zero or any other
+    /// arithmetic that does not give a finite number.
+    pub fn evaluate(&self, resolve: &mut dyn FnMut(&Expression) -> Result<Value, String>) -> Result<Value, String> {
+        match self {
+            Expression::Number(number) => Ok(Value::Number(*number).finite()),
+            Expression::Bool(value) => Ok(Value::Bool(*value)),
+            Expression::Variable(_) | Expression::Placeholder { .. } => resolve(self),
+            Expression::Unary(op, operand) => match (op, operand.evaluate(resolve)?) {
+                (_, Value::Null) => Ok(Value::Null),
+                (UnaryOp::Negate, value) => Ok(Value::Number(-value.number()?)),
+                (UnaryOp::Not, value) => Ok(Value::Bool(!value.bool()?))
+            },
+            Expression::Binary(op, left, right) => {
+                let (left, right): (Value, Value) = (left.evaluate(resolve)?, right.evaluate(resolve)?);
+                if left == Value::Null || right == Value::Null {
+                    return Ok(Value::Null);
+                }
+                evaluate_binary(*op, left, right).map(Value::finite)
+            },
+            Expression::Call(function, args) => {
+                let values: Vec<Value> = args.iter()
+                    .map(|arg: &Expression| arg.evaluate(resolve))
+                    .collect::<Result<_, String>>()?;
+                if values.contains(&Value::Null) {
+                    return Ok(Value::Null);
+                }
+                call(*function, &values).map(Value::finite)
+            }
+        }
+    }
+
+    /// Calls `f` with every variable and placeholder the expression refers to.
+    pub fn visit_operands<'a, F: FnMut(&'a Expression)>(&'a self, f: &mut F) {
+        match self {
+            Expression::Number(_) | Expression::Bool(_) => (),
+            Expression::Variable(_) | Expression::Placeholder { .. } => f(self),
+            Expression::Unary(_, operand) => operand.visit_operands(f),
+            Expression::Binary(_, left, right) => {
+                left.visit_operands(f);
+                right.visit_operands(f);
+            },
+            Expression::Call(_, args) => args.iter().for_each(|arg: &Expression| arg.visit_operands(f))
+        }
+    }
+
+    /// Like `visit_operands`, but allows the variables and placeholders to be changed.
+    pub fn visit_operands_mut<F: FnMut(&mut Expression)>(&mut self, f: &mut F) {
+        match self {
+            Expression::Number(_) | Expression::Bool(_) => (),
+            Expression::Variable(_) | Expression::Placeholder { .. } => f(self),
+            Expression::Unary(_, operand) => operand.visit_operands_mut(f),
+            Expression::Binary(_, left, right) => {
+                left.visit_operands_mut(f);
+                right.visit_operands_mut(f);
+            },
+            Expression::Call(_, args) => args.iter_mut().for_each(|arg: &mut Expression| arg.visit_operands_mut(f))
+        }
+    }
+}
+
+fn evaluate_binary(op: BinaryOp, left: Value, right: Value) -> Result<Value, String> {
+    match op {
+        BinaryOp::Add => Ok(Value::Number(left.number()? + right.number()?)),
+        BinaryOp::Subtract => Ok(Value::Number(left.number()? - right.number()?)),
+        BinaryOp::Multiply => Ok(Value::Number(left.number()? * right.number()?)),
+        BinaryOp::Divide => Ok(Value::Number(left.number()? / right.number()?)),
+        BinaryOp::Remainder => Ok(Value::Number(left.number()? % right.number()?)),
+        BinaryOp::Equal => Ok(Value::Bool(left == right)),
+        BinaryOp::NotEqual => Ok(Value::Bool(left != right)),
+        BinaryOp::Less => Ok(Value::Bool(left.number()? < right.number()?)),
+        BinaryOp::LessOrEqual => Ok(Value::Bool(left.number()? <= right.number()?)),
+        BinaryOp::Greater => Ok(Value::Bool(left.number()? > right.number()?)),
+        BinaryOp::GreaterOrEqual => Ok(Value::Bool(left.number()? >= right.number()?)),
+        BinaryOp::And => Ok(Value::Bool(left.bool()? && right.bool()?)),
+        BinaryOp::Or => Ok(Value::Bool(left.bool()? || right.bool()?))
+    }
+}
+
+fn call(function: Function, args: &[Value]) -> Result<Value, String> {
+    match function {
+        Function::Round => {
+            let digits: i32 = match args.get(1) {
+                Some(digits) => digits.number()? as i32,
+                None => 0
+            };
+            let (number, scale): (f64, f64) = (args[0].number()?, 10f64.powi(digits));
+            match (number * scale).is_finite() && scale != 0.0 {
+                true => Ok(Value::Number((number * scale).round() / scale)),
+                // More digits than a float holds leave the number as it is, and rounding to the
+                // left of every digit it has leaves nothing.
+                false => Ok(Value::Number(match digits > 0 { true => number, false => 0.0 }))
+            }
+        },
+        Function::Floor => Ok(Value::Number(args[0].number()?.floor())),
+        Function::Ceil => Ok(Value::Number(args[0].number()?.ceil())),
+        Function::Abs => Ok(Value::Number(args[0].number()?.abs())),
+        Function::Min => args.iter().try_fold(f64::INFINITY, |min: f64, arg: &Value| Ok(min.min(arg.number()?))).map(Value::Number),
+        Function::Max => args.iter().try_fold(f64::NEG_INFINITY, |max: f64, arg: &Value| Ok(max.max(arg.number()?))).map(Value::Number),
+        Function::If => match args[0].bool()? {
+            true => Ok(args[1]),
+            false => Ok(args[2])
+        }
+    }
+}
+
+/// A recursive descent parser over the text of an expression, from lowest to highest precedence:
+/// `||`, `&&`, comparisons, `+ -`, `* / %`, unary `- !`, and operands.
+struct ExpressionParser<'a> {
+    text: &'a str,
+    position: usize
+}
+
+impl<'a> ExpressionParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest: &str = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `token` if the expression continues with it.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        match self.rest().starts_with(token) {
+            true => {
+                self.position += token.len();
+                true
+            },
+            false => false
+        }
+    }
+
+    fn or(&mut self) -> Result<Expression, String> {
+        let mut left: Expression = self.and()?;
+        while self.eat("||") {
+            left = Expression::Binary(BinaryOp::Or, Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expression, String> {
+        let mut left: Expression = self.comparison()?;
+        while self.eat("&&") {
+            left = Expression::Binary(BinaryOp::And, Box::new(left), Box::new(self.comparison()?));
+        }
+        Ok(left)
+    }
+
+    fn comparison(&mut self) -> Result<Expression, String> {
+        let left: Expression = self.sum()?;
+        let operators: [(&str, BinaryOp); 6] = [
+            ("==", BinaryOp::Equal), ("!=", BinaryOp::NotEqual),
+            ("<=", BinaryOp::LessOrEqual), (">=", BinaryOp::GreaterOrEqual),
+            ("<", BinaryOp::Less), (">", BinaryOp::Greater)
+        ];
+        for (token, op) in operators.iter() {
+            if self.eat(token) {
+                return Ok(Expression::Binary(*op, Box::new(left), Box::new(self.sum()?)));
+            }
+        }
+        Ok(left)
+    }
+
+    fn sum(&mut self) -> Result<Expression, String> {
+        let mut left: Expression = self.product()?;
+        loop {
+            let op: BinaryOp = match self.eat("+") {
+                true => BinaryOp::Add,
+                false if self.eat("-") => BinaryOp::Subtract,
+                false => return Ok(left)
+            };
+            left = Expression::Binary(op, Box::new(left), Box::new(self.product()?));
+        }
+    }
+
+    fn product(&mut self) -> Result<Expression, String> {
+        let mut left: Expression = self.unary()?;
+        loop {
+            let op: BinaryOp = match self.eat("*") {
+                true => BinaryOp::Multiply,
+                false if self.eat("/") => BinaryOp::Divide,
+                false if self.eat("%") => BinaryOp::Remainder,
+                false => return Ok(left)
+            };
+            left = Expression::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expression, String> {
+        if self.eat("-") {
+            return Ok(Expression::Unary(UnaryOp::Negate, Box::new(self.unary()?)));
+        }
+        if self.eat("!") {
+            return Ok(Expression::Unary(UnaryOp::Not, Box::new(self.unary()?)));
+        }
+        self.operand()
+    }
+
+    fn operand(&mut self) -> Result<Expression, String> {
+        self.skip_whitespace();
+        let rest: &str = self.rest();
+        if self.eat("(") {
+            let expression: Expression = self.or()?;
+            return match self.eat(")") {
+                true => Ok(expression),
+                false => Err("expected `)` in expression".to_owned())
+            };
+        }
+        if rest.starts_with('{') {
+            return self.placeholder();
+        }
+        if let Some(name) = rest.strip_prefix('$') {
+            let name: &str = identifier(name);
+            if name.is_empty() {
+                return Err("expected a variable name after `$`".to_owned());
+            }
+            self.position += 1 + name.len();
+            return Ok(Expression::Variable(name.to_owned()));
+        }
+        if rest.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            let length: usize = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+            self.position += length;
+            return rest[..length].parse::<f64>()
+                .map(Expression::Number)
+                .map_err(|_| format!("invalid number `{}`", &rest[..length]));
+        }
+        let name: &str = identifier(rest);
+        match name {
+            "" if rest.is_empty() => Err("expression ends where a value was expected".to_owned()),
+            "" => Err(format!("unexpected `{}` in expression", rest)),
+            "true" | "false" => {
+                self.position += name.len();
+                Ok(Expression::Bool(name == "true"))
+            },
+            _ => {
+                self.position += name.len();
+                self.call(name)
+            }
+        }
+    }
+
+    fn call(&mut self, name: &str) -> Result<Expression, String> {
+        if !self.eat("(") {
+            return Err(format!("unexpected `{}` in expression, variables are written `${}`", name, name));
+        }
+        let function: Function = Function::from_name(name).ok_or_else(|| format!("unknown function `{}`", name))?;
+        let mut args: Vec<Expression> = Vec::new();
+        if !self.eat(")") {
+            loop {
+                args.push(self.or()?);
+                if self.eat(")") {
+                    break;
+                }
+                if !self.eat(",") {
+                    return Err(format!("expected `,` or `)` in the arguments of `{}`", name));
+                }
+            }
+        }
+        let (min, max): (usize, usize) = function.arity();
+        if args.len() < min || args.len() > max {
+            return Err(format!("`{}` does not take {} arguments", name, args.len()));
+        }
+        Ok(Expression::Call(function, args))
+    }
+
+    /// Parses an embedded `{placeholder}`, whose end is found the same way as in templates.
+    fn placeholder(&mut self) -> Result<Expression, String> {
+        let rest: &str = self.rest();
+        let mut depth: usize = 0;
+        let mut in_quotes = false;
+        let mut end: Option<usize> = None;
+        for (index, c) in rest.char_indices() {
+            match (in_quotes, c) {
+                (true, '"') => in_quotes = false,
+                (true, _) => (),
+                (false, '"') => in_quotes = true,
+                (false, '{') | (false, '[') => depth += 1,
+                (false, '}') if depth == 1 => {
+                    end = Some(index);
+                    break;
+                },
+                (false, '}') | (false, ']') => depth = depth.saturating_sub(1),
+                _ => ()
+            }
+        }
+        let end: usize = end.ok_or_else(|| "placeholder in expression is never closed with `}`".to_owned())?;
+        let (entity_id, body): (Option<String>, &str) = split_entity_id(rest[1..end].trim());
+        let placeholder: Placeholder = Placeholder::parse(body).map_err(|err| err.reason)?;
+        self.position += end + 1;
+        Ok(Expression::Placeholder { entity_id, placeholder })
+    }
+}
+
+fn identifier(text: &str) -> &str {
+    let length: usize = text.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(text.len());
+    match text.starts_with(|c: char| c.is_ascii_digit()) {
+        true => "",
+        false => &text[..length]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(text: &str) -> Result<Value, String> {
+        Expression::parse(text)?.evaluate(&mut |operand: &Expression| match operand {
+            Expression::Variable(name) if name == "price" => Ok(Value::Number(12.5)),
+            Expression::Variable(name) if name == "missing" => Ok(Value::Null),
+            operand => Err(format!("unexpected {:?}", operand))
+        })
+    }
+
+    #[test]
+    fn arithmetic_follows_precedence() {
+        assert_eq!(evaluate("1 + 2 * 3 - 4 / 2"), Ok(Value::Number(5.0)));
+        assert_eq!(evaluate("1 + -2 - -3"), Ok(Value::Number(2.0)));
+        assert_eq!(evaluate("(1 + 2) * -3 % 4"), Ok(Value::Number(-1.0)));
+        assert_eq!(evaluate("$price * 2"), Ok(Value::Number(25.0)));
+    }
+
+    #[test]
+    fn comparisons_and_logic() {
+        assert_eq!(evaluate("$price > 10 && !(1 == 2)"), Ok(Value::Bool(true)));
+        assert_eq!(evaluate("1 >= 2 || 3 != 3"), Ok(Value::Bool(false)));
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(evaluate("round(2.345, 2)"), Ok(Value::Number(2.35)));
+        assert_eq!(evaluate("min(3, $price, 1) + max(4, 9)"), Ok(Value::Number(10.0)));
+        assert_eq!(evaluate("if($price < 10, 0, 4.99)"), Ok(Value::Number(4.99)));
+        assert_eq!(evaluate("floor(2.5) + ceil(2.5) + abs(-1)"), Ok(Value::Number(6.0)));
+    }
+
+    #[test]
+    fn null_operands_make_null() {
+        assert_eq!(evaluate("$missing * 2 + 1"), Ok(Value::Null));
+    }
+
+    #[test]
+    fn division_by_zero_makes_null() {
+        assert_eq!(evaluate("1 / 0"), Ok(Value::Null));
+        assert_eq!(evaluate("-$price / (2 - 2)"), Ok(Value::Null));
+        assert_eq!(evaluate("$price % 0"), Ok(Value::Null));
+        assert_eq!(evaluate("(1 / 0) * 0 + 1"), Ok(Value::Null));
+        assert_eq!(evaluate("1 / 0 == 1 / 0"), Ok(Value::Null));
+    }
+
+    #[test]
+    fn non_finite_numbers_make_null() {
+        assert_eq!(evaluate(&format!("1{} * 2", "0".repeat(400))), Ok(Value::Null));
+        assert_eq!(evaluate(&format!("1{} * 10", "0".repeat(308))), Ok(Value::Null));
+        assert_eq!(Value::parse("NaN"), Err("`NaN` is not a number".to_owned()));
+        assert_eq!(Value::parse("inf"), Err("`inf` is not a number".to_owned()));
+    }
+
+    #[test]
+    fn rounding_to_many_digits_keeps_the_number() {
+        assert_eq!(evaluate("round($price, 400)"), Ok(Value::Number(12.5)));
+        assert_eq!(evaluate("round($price, 2147483647)"), Ok(Value::Number(12.5)));
+        assert_eq!(evaluate("round(1234.5, -400)"), Ok(Value::Number(0.0)));
+        assert_eq!(evaluate("round(1234.5, -2)"), Ok(Value::Number(1200.0)));
+    }
+
+    #[test]
+    fn embedded_placeholders() {
+        match Expression::parse("{<1>int:min=1;max=5} * 2").unwrap() {
+            Expression::Binary(BinaryOp::Multiply, left, _) => match *left {
+                Expression::Placeholder { entity_id, .. } => assert_eq!(entity_id.as_deref(), Some("1")),
+                left => panic!("Expected placeholder operand. Actual: {:?}", left)
+            },
+            expression => panic!("Expected multiplication. Actual: {:?}", expression)
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Expression::parse("1 +").unwrap_err(), "expression ends where a value was expected");
+        assert_eq!(Expression::parse("sqrt(4)").unwrap_err(), "unknown function `sqrt`");
+        assert_eq!(Expression::parse("round(1, 2, 3)").unwrap_err(), "`round` does not take 3 arguments");
+        assert_eq!(Expression::parse("(1 + 2").unwrap_err(), "expected `)` in expression");
+        assert_eq!(Expression::parse("1 2").unwrap_err(), "unexpected `2` in expression");
+        assert_eq!(Expression::parse("price * 2").unwrap_err(), "unexpected `price` in expression, variables are written `$price`");
+        assert_eq!(evaluate("true + 1").unwrap_err(), "expected a number, found true");
+    }
+
+    #[test]
+    fn displays_whole_numbers_without_decimals() {
+        assert_eq!(Value::Number(42.0).to_string(), "42");
+        assert_eq!(Value::Number(4.5).to_string(), "4.5");
+    }
+}
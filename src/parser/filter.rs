@@ -0,0 +1,249 @@
+use super::args::{split_top_level, unquote};
+
+/// A step of a `value | filter | filter:arg` pipeline, applied to the generated value.
+#[derive(Clone,Debug,PartialEq)]
+pub enum Filter {
+    Upper,
+    Lower,
+    Capitalize,
+    Title,
+    Trim,
+    Slug,
+    Ascii,
+    Truncate(usize),
+    Pad { width: usize, fill: char },
+    RightPad { width: usize, fill: char },
+    Replace { from: String, to: String },
+    Round(usize),
+    Floor,
+    Ceil,
+    Abs,
+    /// Leaves the value unchanged, and marks it to be written out without escaping.
+    Raw
+}
+
+pub struct FilterSpec {
+    pub usage: &'static str,
+    pub help: &'static str
+}
+
+pub static FILTERS: &[FilterSpec] = &[
+    FilterSpec { usage: "upper", help: "Upper case" },
+    FilterSpec { usage: "lower", help: "Lower case" },
+    FilterSpec { usage: "capitalize", help: "Upper case first letter" },
+    FilterSpec { usage: "title", help: "Upper case first letter of every word" },
+    FilterSpec { usage: "trim", help: "Remove surrounding whitespace" },
+    FilterSpec { usage: "slug", help: "Lower case ASCII letters and digits joined by dashes, for URLs" },
+    FilterSpec { usage: "ascii", help: "Replace accented letters with their ASCII equivalent" },
+    FilterSpec { usage: "truncate:n", help: "At most n characters" },
+    FilterSpec { usage: "pad:n[:c]", help: "Pad on the left to n characters with c, 0 by default" },
+    FilterSpec { usage: "rpad:n[:c]", help: "Pad on the right to n characters with c, a space by default" },
+    FilterSpec { usage: "replace:from:to", help: "Replace all occurrences of from with to" },
+    FilterSpec { usage: "round:n", help: "Number with exactly n decimals" },
+    FilterSpec { usage: "floor", help: "Number rounded down" },
+    FilterSpec { usage: "ceil", help: "Number rounded up" },
+    FilterSpec { usage: "abs", help: "Absolute value of a number" },
+    FilterSpec { usage: "raw", help: "Written out without --escape, for values that are already markup" }
+];
+
+/// Splits `${value | filter | filter:arg}` into the value and the text of each filter.
+/// The `|` of a filter is never inside quotes or brackets and is never part of `||`.
+pub fn split_pipeline(text: &str) -> (&str, Vec<&str>) {
+    let pieces: Vec<&str> = split_top_level(text, '|');
+    let offset = |piece: &str| piece.as_ptr() as usize - text.as_ptr() as usize;
+    let mut parts: Vec<&str> = Vec::new();
+    let mut start: usize = 0;
+    for pair in pieces.windows(2) {
+        // An empty piece is the middle of `||`, which belongs to an expression.
+        if !pair[0].is_empty() && !pair[1].is_empty() {
+            parts.push(text[start..offset(pair[1]) - 1].trim());
+            start = offset(pair[1]);
+        }
+    }
+    parts.push(text[start..].trim());
+    let value: &str = parts.remove(0);
+    (value, parts)
+}
+
+impl Filter {
+    pub fn parse(text: &str) -> Result<Filter, String> {
+        let parts: Vec<String> = split_top_level(text, ':').into_iter().map(|part: &str| unquote(part.trim())).collect();
+        let name: &str = &parts[0];
+        let args: &[String] = &parts[1..];
+        let count = |min: usize, max: usize| match args.len() >= min && args.len() <= max {
+            true => Ok(()),
+            false => Err(format!("filter `{}` does not take {} arguments", name, args.len()))
+        };
+        let number = |index: usize| args[index].parse::<usize>()
+            .map_err(|_| format!("invalid value `{}` for filter `{}`, expected a positive integer", args[index], name));
+        let fill = |default: char| match args.get(1) {
+            None => Ok(default),
+            Some(fill) if fill.chars().count() == 1 => Ok(fill.chars().next().unwrap_or(default)),
+            Some(fill) => Err(format!("invalid value `{}` for filter `{}`, expected a single character", fill, name))
+        };
+        match name {
+            "truncate" => count(1, 1).and_then(|_| Ok(Filter::Truncate(number(0)?))),
+            "round" => count(1, 1).and_then(|_| Ok(Filter::Round(number(0)?))),
+            "pad" => count(1, 2).and_then(|_| Ok(Filter::Pad { width: number(0)?, fill: fill('0')? })),
+            "rpad" => count(1, 2).and_then(|_| Ok(Filter::RightPad { width: number(0)?, fill: fill(' ')? })),
+            "replace" => count(2, 2).map(|_| Filter::Replace { from: args[0].clone(), to: args[1].clone() }),
+            "" => Err("missing filter after `|`".to_owned()),
+            _ => {
+                let filter: Filter = match name {
+                    "upper" => Filter::Upper,
+                    "lower" => Filter::Lower,
+                    "capitalize" => Filter::Capitalize,
+                    "title" => Filter::Title,
+                    "trim" => Filter::Trim,
+                    "slug" => Filter::Slug,
+                    "ascii" => Filter::Ascii,
+                    "floor" => Filter::Floor,
+                    "ceil" => Filter::Ceil,
+                    "abs" => Filter::Abs,
+                    "raw" => Filter::Raw,
+                    _ => return Err(format!("unknown filter `{}`", name))
+                };
+                count(0, 0).map(|_| filter)
+            }
+        }
+    }
+
+    /// Applies the filter. Number filters fail on values that are not numbers.
+    pub fn apply(&self, value: &str) -> Result<String, String> {
+        let number = || value.trim().parse::<f64>().map_err(|_| format!("`{}` is not a number", value));
+        match self {
+            Filter::Upper => Ok(value.to_uppercase()),
+            Filter::Lower => Ok(value.to_lowercase()),
+            Filter::Capitalize => Ok(capitalize(value)),
+            Filter::Title => Ok(value.split(' ').map(capitalize).collect::<Vec<String>>().join(" ")),
+            Filter::Trim => Ok(value.trim().to_owned()),
+            Filter::Slug => Ok(slug(value)),
+            Filter::Ascii => Ok(fold_to_ascii(value)),
+            Filter::Truncate(length) => Ok(value.chars().take(*length).collect()),
+            Filter::Pad { width, fill } => {
+                let padding: String = std::iter::repeat_n(*fill, width.saturating_sub(value.chars().count())).collect();
+                Ok(match value.strip_prefix('-') {
+                    // Zero padding goes after the sign of negative numbers.
+                    Some(digits) if *fill == '0' && number().is_ok() => format!("-{}{}", padding, digits),
+                    _ => format!("{}{}", padding, value)
+                })
+            },
+            Filter::RightPad { width, fill } => {
+                let padding: String = std::iter::repeat_n(*fill, width.saturating_sub(value.chars().count())).collect();
+                Ok(format!("{}{}", value, padding))
+            },
+            Filter::Replace { from, to } => Ok(value.replace(from.as_str(), to)),
+            Filter::Round(decimals) => Ok(format!("{:.*}", decimals, number()?)),
+            Filter::Floor => Ok(number()?.floor().to_string()),
+            Filter::Ceil => Ok(number()?.ceil().to_string()),
+            Filter::Abs => Ok(number()?.abs().to_string()),
+            Filter::Raw => Ok(value.to_owned())
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new()
+    }
+}
+
+fn slug(value: &str) -> String {
+    let folded: String = fold_to_ascii(value).to_lowercase();
+    folded.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word: &&str| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+/// Replaces accented Latin letters with the ASCII letters they are based on, and drops any
+/// other character outside ASCII.
+pub fn fold_to_ascii(value: &str) -> String {
+    let mut folded = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            c if c.is_ascii() => folded.push(c),
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ą' => folded.push('a'),
+            'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ą' => folded.push('A'),
+            'æ' => folded.push_str("ae"),
+            'Æ' => folded.push_str("AE"),
+            'ç' | 'ć' | 'č' => folded.push('c'),
+            'Ç' | 'Ć' | 'Č' => folded.push('C'),
+            'ď' | 'đ' | 'ð' => folded.push('d'),
+            'Ď' | 'Đ' | 'Ð' => folded.push('D'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' | 'ě' => folded.push('e'),
+            'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ę' | 'Ě' => folded.push('E'),
+            'ì' | 'í' | 'î' | 'ï' | 'ī' => folded.push('i'),
+            'Ì' | 'Í' | 'Î' | 'Ï' | 'Ī' => folded.push('I'),
+            'ł' => folded.push('l'),
+            'Ł' => folded.push('L'),
+            'ñ' | 'ń' | 'ň' => folded.push('n'),
+            'Ñ' | 'Ń' | 'Ň' => folded.push('N'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' => folded.push('o'),
+            'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' => folded.push('O'),
+            'œ' => folded.push_str("oe"),
+            'Œ' => folded.push_str("OE"),
+            'ř' => folded.push('r'),
+            'Ř' => folded.push('R'),
+            'ś' | 'š' | 'ş' => folded.push('s'),
+            'Ś' | 'Š' | 'Ş' => folded.push('S'),
+            'ß' => folded.push_str("ss"),
+            'ť' | 'ţ' => folded.push('t'),
+            'Ť' | 'Ţ' => folded.push('T'),
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' => folded.push('u'),
+            'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ū' | 'Ů' => folded.push('U'),
+            'ý' | 'ÿ' => folded.push('y'),
+            'Ý' | 'Ÿ' => folded.push('Y'),
+            'ź' | 'ż' | 'ž' => folded.push('z'),
+            'Ź' | 'Ż' | 'Ž' => folded.push('Z'),
+            _ => ()
+        }
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(filter: &str, value: &str) -> Result<String, String> {
+        Filter::parse(filter)?.apply(value)
+    }
+
+    #[test]
+    fn split_pipeline_ignores_or_and_quoted_bars() {
+        assert_eq!(split_pipeline("<1>name::last | lower | slug"), ("<1>name::last", vec!["lower", "slug"]));
+        assert_eq!(split_pipeline("= $a || $b | upper"), ("= $a || $b", vec!["upper"]));
+        assert_eq!(split_pipeline(r#"set:options=["a|b",c]|replace:"|":"-""#), (r#"set:options=["a|b",c]"#, vec![r#"replace:"|":"-""#]));
+        assert_eq!(split_pipeline("guid"), ("guid", vec![]));
+    }
+
+    #[test]
+    fn string_filters() {
+        assert_eq!(apply("upper", "Smith"), Ok("SMITH".to_owned()));
+        assert_eq!(apply("title", "new south wales"), Ok("New South Wales".to_owned()));
+        assert_eq!(apply("slug", "Saint-Étienne du Rouvray!"), Ok("saint-etienne-du-rouvray".to_owned()));
+        assert_eq!(apply("truncate:3", "Zoë Smith"), Ok("Zoë".to_owned()));
+        assert_eq!(apply("replace:\" \":_", "a b c"), Ok("a_b_c".to_owned()));
+        assert_eq!(apply("rpad:5:.", "ab"), Ok("ab...".to_owned()));
+    }
+
+    #[test]
+    fn number_filters() {
+        assert_eq!(apply("pad:6", "42"), Ok("000042".to_owned()));
+        assert_eq!(apply("pad:4", "-7"), Ok("-007".to_owned()));
+        assert_eq!(apply("round:2", "3.14159"), Ok("3.14".to_owned()));
+        assert_eq!(apply("round:2", "3"), Ok("3.00".to_owned()));
+        assert_eq!(apply("round:2", "Smith"), Err("`Smith` is not a number".to_owned()));
+    }
+
+    #[test]
+    fn filter_errors() {
+        assert_eq!(Filter::parse("shout"), Err("unknown filter `shout`".to_owned()));
+        assert_eq!(Filter::parse("pad"), Err("filter `pad` does not take 0 arguments".to_owned()));
+        assert_eq!(Filter::parse("upper:1"), Err("filter `upper` does not take 1 arguments".to_owned()));
+        assert_eq!(Filter::parse("pad:x"), Err("invalid value `x` for filter `pad`, expected a positive integer".to_owned()));
+    }
+}
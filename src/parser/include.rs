@@ -0,0 +1,59 @@
+use super::args::{self, KeyedArgs};
+use super::registry::{ArgDefault, ArgKind, ArgSpec};
+
+pub const INCLUDE_ARGS: &[ArgSpec] = &[
+    ArgSpec { name: "entity", kind: ArgKind::Text, default: ArgDefault::Optional, help: "Entity the partial's <1> refers to, e.g. 2 or <2>" }
+];
+
+/// A `${include:path;entity=2}` of another template file.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Include {
+    /// Path of the partial, relative to the directory of the including template.
+    pub path: String,
+    /// The entity id that `<1>` in the partial is renamed to.
+    pub entity: Option<String>
+}
+
+impl Include {
+    /// Parses what follows `include:`, a path optionally followed by `;name=value` args.
+    pub fn parse(args: &str) -> Result<Include, String> {
+        let parts: Vec<&str> = args::split_top_level(args, ';');
+        let path: String = args::unquote(parts[0].trim());
+        if path.is_empty() {
+            return Err("missing path of the template to include".to_owned());
+        }
+        let keyed: KeyedArgs = KeyedArgs::parse(&parts[1..].join(";"))?.resolve(INCLUDE_ARGS)?;
+        let entity: Option<String> = match keyed.raw("entity").map(args::unquote) {
+            Some(entity) => Some(parse_entity_id(&entity)?),
+            None => None
+        };
+        Ok(Include { path, entity })
+    }
+}
+
+/// Reads an entity id written either bare, `2`, or as in a placeholder, `<2>`.
+fn parse_entity_id(value: &str) -> Result<String, String> {
+    let id: &str = value.strip_prefix('<').and_then(|id: &str| id.strip_suffix('>')).unwrap_or(value);
+    match !id.is_empty() && id.chars().all(|c: char| c.is_ascii_alphanumeric()) {
+        true => Ok(id.to_owned()),
+        false => Err(format!("invalid entity id `{}`, expected letters and digits such as `2`", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_include_with_entity() {
+        assert_eq!(Include::parse("partials/person.json;entity=<2>"), Ok(Include { path: "partials/person.json".to_owned(), entity: Some("2".to_owned()) }));
+        assert_eq!(Include::parse("\"my partial.json\""), Ok(Include { path: "my partial.json".to_owned(), entity: None }));
+    }
+
+    #[test]
+    fn include_errors() {
+        assert_eq!(Include::parse(""), Err("missing path of the template to include".to_owned()));
+        assert_eq!(Include::parse("a.json;entity=a-b"), Err("invalid entity id `a-b`, expected letters and digits such as `2`".to_owned()));
+        assert_eq!(Include::parse("a.json;id=2"), Err("unknown arg `id`".to_owned()));
+    }
+}
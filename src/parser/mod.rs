@@ -1,75 +1,51 @@
 pub mod error;
+pub mod registry;
+pub mod block;
+pub mod expression;
+pub mod filter;
+pub mod include;
+pub mod net;
+pub mod time;
 mod args;
 
 use super::types;
-use super::types::{
-    PlaceholderType,
-    PlaceholderArgs,
-    PhoneType,
-    NameType,
-    LocationType,
-    DistributionType
-};
+use super::types::{PlaceholderType, PlaceholderArgs, Modifiers, ExternalType};
 
 use args::PlaceholderArgsParser;
 use error::PlaceholderParseError;
 use regex::{Regex, Captures, Match};
+use std::fmt;
 
 lazy_static! {
-    pub static ref PLACEHOLDER_REGEX: Regex = Regex::new("(?P<data_type>(?:[a-zA-Z0-9_]+(?:::)?)+)(?P<args>:[^:]*)?$").unwrap();
-}
-
-pub trait Args {
-    fn default() -> Self;
-    fn help() -> &'static str;
-    fn parse(args: &String) -> Option<Self> where Self: Sized;
+    pub static ref PLACEHOLDER_REGEX: Regex = Regex::new("(?s)^(?P<data_type>[a-zA-Z0-9_]+(?:::[a-zA-Z0-9_.]+)*)(?::(?P<args>.*))?$").unwrap();
 }
 
 #[derive(Clone,Debug)]
 pub struct Placeholder {
     pub data_type: PlaceholderType,
     pub data_args: Option<PlaceholderArgs>,
+    pub modifiers: Modifiers,
     original_type: String,
     args: Option<String>
 }
 
 impl Placeholder {
-    pub fn validate<'t>(placeholder: &'t str) -> Option<PlaceholderParseError> {
-        let capture_option: Option<Captures> = PLACEHOLDER_REGEX.captures(placeholder);
-        if let None = capture_option {
-            return Some(PlaceholderParseError::invalid_placeholder(placeholder));
-        }
-        let captures: Captures = capture_option.unwrap();
+    pub fn parse(placeholder: &str) -> Result<Placeholder, PlaceholderParseError> {
+        let captures: Captures = PLACEHOLDER_REGEX.captures(placeholder)
+            .ok_or_else(|| PlaceholderParseError::invalid_placeholder(placeholder))?;
         // Get parsed PlaceholderType
-        let data_type_string: String = Placeholder::get_data_type(&captures);
-        let parsed_type: Option<PlaceholderType> = Placeholder::parse_type(&data_type_string);
-        if let None = parsed_type {
-            return Some(PlaceholderParseError::invalid_placeholder(placeholder));
-        }
+        let data_type: String = Placeholder::get_data_type(&captures);
+        let placeholder_type: PlaceholderType = Placeholder::parse_type(&data_type)
+            .ok_or_else(|| PlaceholderParseError::unknown_type(placeholder, &data_type))?;
 
         // Get parsed PlaceholderArgs
-        let data_type = parsed_type.unwrap();
-        let args_capture: Option<String> = Placeholder::get_args(&captures);
-        if let Some(args_string) = args_capture {
-            if PlaceholderArgsParser::parse_args(&data_type, &args_string).is_none() {
-                return Some(PlaceholderParseError::invalid_arg(&placeholder.to_owned(), &args_string));
-            }
+        let arguments: Option<String> = Placeholder::get_args(&captures);
+        let (mut placeholder_args, modifiers): (Option<PlaceholderArgs>, Modifiers) = PlaceholderArgsParser::parse_args(&placeholder_type, arguments.as_deref().unwrap_or_default())
+            .map_err(|reason: String| PlaceholderParseError::invalid_arg(placeholder, reason))?;
+        if let (PlaceholderType::External(_), Some((_, name))) = (&placeholder_type, data_type.split_once("::")) {
+            placeholder_args = Some(PlaceholderArgs::External { name: name.to_owned(), value: None });
         }
-
-        None
-    }
-
-    pub fn parse<'t>(placeholder: &'t str) -> Placeholder {
-        PLACEHOLDER_REGEX.captures(placeholder)
-            .map(|captures: Captures| {
-                let data_type: String = Placeholder::get_data_type(&captures);
-                let placeholder_type: PlaceholderType = Placeholder::parse_type(&data_type).unwrap();
-                let arguments: Option<String> = Placeholder::get_args(&captures);
-                let placeholder_args: Option<PlaceholderArgs> = arguments.clone()
-                    .and_then(|args: String| PlaceholderArgsParser::parse_args(&placeholder_type, &args));
-                Placeholder { original_type: data_type, args: arguments, data_type: placeholder_type, data_args: placeholder_args }
-            })
-            .unwrap()
+        Ok(Placeholder { original_type: data_type, args: arguments, data_type: placeholder_type, data_args: placeholder_args, modifiers })
     }
 
     fn get_data_type(placeholder_captures: &Captures) -> String {
@@ -78,34 +54,36 @@ impl Placeholder {
 
     fn get_args(captures: &Captures) -> Option<String> {
         captures.name("args")
-            .map(|args: Match| args.as_str())
-            .map(|args: &str| args.trim_start_matches(":"))
-            .map(|args: &str| args.to_owned())
+            .map(|args: Match| args.as_str().to_owned())
     }
 
-    pub fn to_string(&self) -> String {
-        format!("${{{}:{}}}", self.original_type, self.args.clone().unwrap_or_default())
+    fn parse_type(data_type: &str) -> Option<PlaceholderType> {
+        // External values are named by what follows the `::`, rather than by a registered type.
+        match data_type.split_once("::") {
+            Some(("var", _)) => Some(PlaceholderType::External(ExternalType::Var)),
+            Some(("env", _)) => Some(PlaceholderType::External(ExternalType::Env)),
+            _ => registry::lookup(data_type).map(|spec: &registry::PlaceholderSpec| spec.placeholder_type.clone())
+        }
     }
+}
 
-    fn parse_type(data_type: &String) -> Option<PlaceholderType> {
-        match data_type.as_str() {
-            "name::first" => Some(PlaceholderType::Name(NameType::First)),
-            "name::last" => Some(PlaceholderType::Name(NameType::Last)),
-            "name::full" => Some(PlaceholderType::Name(NameType::Full)),
-            "phone" => Some(PlaceholderType::Phone(PhoneType::Any)),
-            "phone::mobile" => Some(PlaceholderType::Phone(PhoneType::Mobile)),
-            "phone::landline" => Some(PlaceholderType::Phone(PhoneType::Landline)),
-            "location::place" => Some(PlaceholderType::Location(LocationType::Place)),
-            "location::street" => Some(PlaceholderType::Location(LocationType::Street)),
-            "location::address" => Some(PlaceholderType::Location(LocationType::Address)),
-            "dist::normal" => Some(PlaceholderType::Distribution(DistributionType::Normal)),
-            "guid" => Some(PlaceholderType::Guid),
-            "float" => Some(PlaceholderType::Float),
-            "int" => Some(PlaceholderType::Int),
-            "set" => Some(PlaceholderType::Set),
-            _ => None
+impl fmt::Display for Placeholder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "${{{}:{}}}", self.original_type, self.args.as_deref().unwrap_or_default())
+    }
+}
+
+/// Splits the `<id>` an entity placeholder starts with from the rest of the placeholder.
+pub fn split_entity_id(body: &str) -> (Option<String>, &str) {
+    if body.starts_with('<') {
+        if let Some(end) = body.find('>') {
+            let id: &str = &body[1..end];
+            if !id.is_empty() && id.chars().all(|c: char| c.is_ascii_alphanumeric()) {
+                return (Some(id.to_owned()), &body[end + 1..]);
+            }
         }
     }
+    (None, body)
 }
 
 // #[cfg(test)]
@@ -0,0 +1,58 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Parses an IPv4 network such as `10.0.0.0/8` into its address and prefix length.
+/// Bits of the address beyond the prefix are cleared, so `10.1.2.3/8` is `10.0.0.0/8`.
+pub fn parse_ipv4_cidr(cidr: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("invalid IPv4 network `{}`, expected e.g. 10.0.0.0/8", cidr);
+    let (address, prefix): (&str, u32) = split_cidr(cidr, 32).ok_or_else(invalid)?;
+    let address: u32 = address.parse::<Ipv4Addr>().map_err(|_| invalid())?.into();
+    Ok((address & u32::MAX.checked_shl(32 - prefix).unwrap_or(0), prefix))
+}
+
+/// Parses an IPv6 network such as `2001:db8::/32` into its address and prefix length.
+pub fn parse_ipv6_cidr(cidr: &str) -> Result<(u128, u32), String> {
+    let invalid = || format!("invalid IPv6 network `{}`, expected e.g. 2001:db8::/32", cidr);
+    let (address, prefix): (&str, u32) = split_cidr(cidr, 128).ok_or_else(invalid)?;
+    let address: u128 = address.parse::<Ipv6Addr>().map_err(|_| invalid())?.into();
+    Ok((address & u128::MAX.checked_shl(128 - prefix).unwrap_or(0), prefix))
+}
+
+/// Splits `address/prefix`, where a missing prefix is a network of a single address.
+fn split_cidr(cidr: &str, bits: u32) -> Option<(&str, u32)> {
+    match cidr.split_once('/') {
+        Some((address, prefix)) => prefix.parse::<u32>().ok()
+            .filter(|prefix: &u32| *prefix <= bits)
+            .map(|prefix: u32| (address, prefix)),
+        None => Some((cidr, bits))
+    }
+}
+
+/// Whether `domain` is a valid domain name such as `mail.example.com`.
+pub fn is_domain(domain: &str) -> bool {
+    domain.contains('.') && domain.split('.').all(|label: &str| {
+        !label.is_empty() && !label.starts_with('-') && !label.ends_with('-')
+            && label.chars().all(|c: char| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_networks() {
+        assert_eq!(parse_ipv4_cidr("10.0.0.0/8"), Ok((0x0a00_0000, 8)));
+        assert_eq!(parse_ipv4_cidr("192.168.1.77/24"), Ok((0xc0a8_0100, 24)));
+        assert_eq!(parse_ipv4_cidr("0.0.0.0/0"), Ok((0, 0)));
+        assert_eq!(parse_ipv4_cidr("8.8.8.8"), Ok((0x0808_0808, 32)));
+        assert_eq!(parse_ipv6_cidr("2001:db8::/32"), Ok((0x2001_0db8 << 96, 32)));
+        assert_eq!(parse_ipv6_cidr("::/0"), Ok((0, 0)));
+    }
+
+    #[test]
+    fn network_errors() {
+        assert_eq!(parse_ipv4_cidr("10.0.0.0/33"), Err("invalid IPv4 network `10.0.0.0/33`, expected e.g. 10.0.0.0/8".to_owned()));
+        assert_eq!(parse_ipv4_cidr("10.0.0/8"), Err("invalid IPv4 network `10.0.0/8`, expected e.g. 10.0.0.0/8".to_owned()));
+        assert_eq!(parse_ipv6_cidr("10.0.0.0/8"), Err("invalid IPv6 network `10.0.0.0/8`, expected e.g. 2001:db8::/32".to_owned()));
+    }
+}
@@ -0,0 +1,531 @@
+use super::args::{KeyedArgs, PlaceholderArgsParser};
+use super::block::{MAYBE_ARGS, REPEAT_ARGS};
+use super::filter::FILTERS;
+use super::include::INCLUDE_ARGS;
+use super::types::{
+    PlaceholderType,
+    PlaceholderArgs,
+    PhoneType,
+    InternetType,
+    NetworkType,
+    TextType,
+    NameType,
+    LocationType,
+    DistributionType,
+    DocType,
+    ExternalType
+};
+
+use std::fmt::{self, Write};
+
+/// The kind of value an argument accepts.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum ArgKind {
+    Integer,
+    Count,
+    Number,
+    List,
+    Text
+}
+
+impl ArgKind {
+    pub fn describe(self) -> &'static str {
+        match self {
+            ArgKind::Integer => "an integer",
+            ArgKind::Count => "a positive integer",
+            ArgKind::Number => "a number",
+            ArgKind::List => "a list such as [a,b,c]",
+            ArgKind::Text => "text"
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ArgKind::Integer => "integer",
+            ArgKind::Count => "count",
+            ArgKind::Number => "number",
+            ArgKind::List => "list",
+            ArgKind::Text => "text"
+        }
+    }
+
+    pub(super) fn accepts(self, value: &str) -> bool {
+        match self {
+            ArgKind::Integer => value.parse::<i64>().is_ok(),
+            ArgKind::Count => value.parse::<u64>().is_ok(),
+            ArgKind::Number => value.parse::<f64>().is_ok_and(f64::is_finite),
+            ArgKind::List | ArgKind::Text => true
+        }
+    }
+}
+
+/// What happens when an argument is left out of a placeholder.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum ArgDefault {
+    Required,
+    Optional,
+    Value(&'static str)
+}
+
+#[derive(Clone,Copy,Debug)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub kind: ArgKind,
+    pub default: ArgDefault,
+    pub help: &'static str
+}
+
+/// Everything the tool knows about one placeholder type: how it is written, which arguments it
+/// takes and how to describe it. Parsing, validation and the `types` and `explain` commands all read from here.
+pub struct PlaceholderSpec {
+    pub name: &'static str,
+    pub placeholder_type: PlaceholderType,
+    pub help: &'static str,
+    pub args: &'static [ArgSpec],
+    pub example: &'static str,
+    pub(super) build: fn(&KeyedArgs) -> Result<Option<PlaceholderArgs>, String>
+}
+
+impl fmt::Debug for PlaceholderSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PlaceholderSpec").field("name", &self.name).finish()
+    }
+}
+
+impl fmt::Display for ArgSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let default: String = match self.default {
+            ArgDefault::Required => "required".to_owned(),
+            ArgDefault::Optional => "optional".to_owned(),
+            ArgDefault::Value(value) => format!("default {}", value)
+        };
+        write!(f, "{:<4}{:<10}{:<9}{:<13} {}", "", self.name, self.kind.name(), default, self.help)
+    }
+}
+
+impl fmt::Display for PlaceholderSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<20}{}\n{:<20}e.g. {}", self.name, self.help, "", self.example)?;
+        for arg in self.args {
+            write!(f, "\n{}", arg)?;
+        }
+        Ok(())
+    }
+}
+
+const NO_ARGS: &[ArgSpec] = &[];
+
+/// Arguments accepted by every placeholder, whatever its type.
+pub static MODIFIER_ARGS: &[ArgSpec] = &[
+    ArgSpec { name: "null", kind: ArgKind::Number, default: ArgDefault::Optional, help: "Probability, from 0 to 1, of rendering the null token instead of a value" }
+];
+
+pub static REGISTRY: &[PlaceholderSpec] = &[
+    PlaceholderSpec {
+        name: "name::first",
+        placeholder_type: PlaceholderType::Name(NameType::First),
+        help: "First name",
+        args: NO_ARGS,
+        example: "${name::first}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "name::last",
+        placeholder_type: PlaceholderType::Name(NameType::Last),
+        help: "Last name",
+        args: NO_ARGS,
+        example: "${name::last}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "name::full",
+        placeholder_type: PlaceholderType::Name(NameType::Full),
+        help: "First name, sometimes a middle name, and last name",
+        args: NO_ARGS,
+        example: "${name::full}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "location::address",
+        placeholder_type: PlaceholderType::Location(LocationType::Address),
+        help: "Street number, street and place",
+        args: NO_ARGS,
+        example: "${location::address}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "location::place",
+        placeholder_type: PlaceholderType::Location(LocationType::Place),
+        help: "Town or city",
+        args: NO_ARGS,
+        example: "${location::place}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "location::street",
+        placeholder_type: PlaceholderType::Location(LocationType::Street),
+        help: "Street name and type",
+        args: NO_ARGS,
+        example: "${location::street}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "phone",
+        placeholder_type: PlaceholderType::Phone(PhoneType::Any),
+        help: "Either a mobile or a landline phone number",
+        args: NO_ARGS,
+        example: "${phone}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "phone::mobile",
+        placeholder_type: PlaceholderType::Phone(PhoneType::Mobile),
+        help: "Mobile phone number",
+        args: NO_ARGS,
+        example: "${phone::mobile}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "phone::landline",
+        placeholder_type: PlaceholderType::Phone(PhoneType::Landline),
+        help: "Landline phone number",
+        args: NO_ARGS,
+        example: "${phone::landline}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "email",
+        placeholder_type: PlaceholderType::Internet(InternetType::Email),
+        help: "Email address made from the entity's name, e.g. jane.doe84@example.com",
+        args: &[
+            ArgSpec { name: "pattern", kind: ArgKind::Text, default: ArgDefault::Value("{first}.{last}{nn}"), help: "Username before the @, from {first}, {last}, {f}, {l} and digits such as {nn}" },
+            ArgSpec { name: "domains", kind: ArgKind::List, default: ArgDefault::Optional, help: "Domains to choose from, by default example.com, example.net and example.org" },
+            ArgSpec { name: "case", kind: ArgKind::Text, default: ArgDefault::Value("lower"), help: "lower, or keep for the case of the name" }
+        ],
+        example: "${email:pattern={f}{last};domains=[acme.example,mail.example]}",
+        build: PlaceholderArgsParser::parse_email
+    },
+    PlaceholderSpec {
+        name: "username",
+        placeholder_type: PlaceholderType::Internet(InternetType::Username),
+        help: "Username made from the entity's name, e.g. jane.doe84",
+        args: &[
+            ArgSpec { name: "pattern", kind: ArgKind::Text, default: ArgDefault::Value("{first}.{last}{nn}"), help: "From {first}, {last}, {f}, {l} and digits such as {nn}" },
+            ArgSpec { name: "case", kind: ArgKind::Text, default: ArgDefault::Value("lower"), help: "lower, or keep for the case of the name" }
+        ],
+        example: "${username:pattern={f}{last}}",
+        build: PlaceholderArgsParser::parse_username
+    },
+    PlaceholderSpec {
+        name: "net::ipv4",
+        placeholder_type: PlaceholderType::Network(NetworkType::Ipv4),
+        help: "IPv4 address in a network",
+        args: &[
+            ArgSpec { name: "cidr", kind: ArgKind::Text, default: ArgDefault::Value("0.0.0.0/0"), help: "Network of the address, e.g. 10.0.0.0/8" }
+        ],
+        example: "${net::ipv4:cidr=10.0.0.0/8}",
+        build: PlaceholderArgsParser::parse_ipv4
+    },
+    PlaceholderSpec {
+        name: "net::ipv6",
+        placeholder_type: PlaceholderType::Network(NetworkType::Ipv6),
+        help: "IPv6 address in a network",
+        args: &[
+            ArgSpec { name: "cidr", kind: ArgKind::Text, default: ArgDefault::Value("2000::/3"), help: "Network of the address, e.g. fd00::/8" }
+        ],
+        example: "${net::ipv6:cidr=2001:db8::/32}",
+        build: PlaceholderArgsParser::parse_ipv6
+    },
+    PlaceholderSpec {
+        name: "net::mac",
+        placeholder_type: PlaceholderType::Network(NetworkType::Mac),
+        help: "MAC address of a network interface, e.g. 3c:22:fb:0a:91:7e",
+        args: NO_ARGS,
+        example: "${net::mac}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "net::hostname",
+        placeholder_type: PlaceholderType::Network(NetworkType::Hostname),
+        help: "Host name, e.g. api-03.example.net",
+        args: &[
+            ArgSpec { name: "domain", kind: ArgKind::Text, default: ArgDefault::Optional, help: "Domain of the host, by default example.com, example.net or example.org" }
+        ],
+        example: "${net::hostname:domain=corp.example}",
+        build: PlaceholderArgsParser::parse_hostname
+    },
+    PlaceholderSpec {
+        name: "net::url",
+        placeholder_type: PlaceholderType::Network(NetworkType::Url),
+        help: "URL of a page on the host of net::hostname",
+        args: &[
+            ArgSpec { name: "scheme", kind: ArgKind::Text, default: ArgDefault::Value("https"), help: "Scheme of the URL, e.g. http" },
+            ArgSpec { name: "domain", kind: ArgKind::Text, default: ArgDefault::Optional, help: "Domain of the host, as for net::hostname" }
+        ],
+        example: "${net::url:scheme=https}",
+        build: PlaceholderArgsParser::parse_url
+    },
+    PlaceholderSpec {
+        name: "net::port",
+        placeholder_type: PlaceholderType::Network(NetworkType::Port),
+        help: "Port number from min to max, both included",
+        args: &[
+            ArgSpec { name: "min", kind: ArgKind::Count, default: ArgDefault::Value("1024"), help: "Lowest port" },
+            ArgSpec { name: "max", kind: ArgKind::Count, default: ArgDefault::Value("65535"), help: "Highest port, at most 65535" }
+        ],
+        example: "${net::port:min=49152}",
+        build: PlaceholderArgsParser::parse_port
+    },
+    PlaceholderSpec {
+        name: "net::user_agent",
+        placeholder_type: PlaceholderType::Network(NetworkType::UserAgent),
+        help: "User agent of a common browser",
+        args: NO_ARGS,
+        example: "${net::user_agent}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "text::words",
+        placeholder_type: PlaceholderType::Text(TextType::Words),
+        help: "Lorem ipsum words separated by spaces",
+        args: &[
+            ArgSpec { name: "min", kind: ArgKind::Count, default: ArgDefault::Value("3"), help: "Fewest words" },
+            ArgSpec { name: "max", kind: ArgKind::Count, default: ArgDefault::Value("8"), help: "Most words, included" }
+        ],
+        example: "${text::words:min=3;max=8}",
+        build: PlaceholderArgsParser::parse_length
+    },
+    PlaceholderSpec {
+        name: "text::sentence",
+        placeholder_type: PlaceholderType::Text(TextType::Sentence),
+        help: "Capitalized lorem ipsum sentence ending with a full stop",
+        args: &[
+            ArgSpec { name: "min", kind: ArgKind::Count, default: ArgDefault::Value("4"), help: "Fewest words" },
+            ArgSpec { name: "max", kind: ArgKind::Count, default: ArgDefault::Value("12"), help: "Most words, included" }
+        ],
+        example: "${text::sentence}",
+        build: PlaceholderArgsParser::parse_length
+    },
+    PlaceholderSpec {
+        name: "text::paragraphs",
+        placeholder_type: PlaceholderType::Text(TextType::Paragraphs),
+        help: "Paragraphs of three to six sentences, separated by a blank line",
+        args: &[
+            ArgSpec { name: "count", kind: ArgKind::Count, default: ArgDefault::Value("1"), help: "Number of paragraphs" }
+        ],
+        example: "${text::paragraphs:count=2}",
+        build: PlaceholderArgsParser::parse_paragraphs
+    },
+    PlaceholderSpec {
+        name: "text::chars",
+        placeholder_type: PlaceholderType::Text(TextType::Chars),
+        help: "Sentences cut after a whole word to fit in max characters, e.g. for varchar(n) columns",
+        args: &[
+            ArgSpec { name: "min", kind: ArgKind::Count, default: ArgDefault::Optional, help: "Fewest characters, by default half of max" },
+            ArgSpec { name: "max", kind: ArgKind::Count, default: ArgDefault::Value("200"), help: "Most characters, included" }
+        ],
+        example: "${text::chars:max=280}",
+        build: PlaceholderArgsParser::parse_chars
+    },
+    PlaceholderSpec {
+        name: "dist::normal",
+        placeholder_type: PlaceholderType::Distribution(DistributionType::Normal),
+        help: "Number drawn from a normal distribution",
+        args: &[
+            ArgSpec { name: "mean", kind: ArgKind::Number, default: ArgDefault::Value("0"), help: "Mean of the distribution" },
+            ArgSpec { name: "stddev", kind: ArgKind::Number, default: ArgDefault::Value("1"), help: "Standard deviation, at least 0" }
+        ],
+        example: "${dist::normal:mean=0;stddev=1}",
+        build: PlaceholderArgsParser::parse_normal
+    },
+    PlaceholderSpec {
+        name: "int",
+        placeholder_type: PlaceholderType::Int,
+        help: "Integer from min up to, but not including, max",
+        args: &[
+            ArgSpec { name: "min", kind: ArgKind::Integer, default: ArgDefault::Value("0"), help: "Smallest value" },
+            ArgSpec { name: "max", kind: ArgKind::Integer, default: ArgDefault::Value("100"), help: "Upper bound, not included" },
+            ArgSpec { name: "rep", kind: ArgKind::Count, default: ArgDefault::Optional, help: "Concatenate this many integers, e.g. for account numbers" }
+        ],
+        example: "${int:min=0;max=5}",
+        build: PlaceholderArgsParser::parse_int
+    },
+    PlaceholderSpec {
+        name: "float",
+        placeholder_type: PlaceholderType::Float,
+        help: "Number between min and max",
+        args: &[
+            ArgSpec { name: "min", kind: ArgKind::Number, default: ArgDefault::Value("0"), help: "Lower bound" },
+            ArgSpec { name: "max", kind: ArgKind::Number, default: ArgDefault::Value("1"), help: "Upper bound" }
+        ],
+        example: "${float:min=0;max=1}",
+        build: PlaceholderArgsParser::parse_float
+    },
+    PlaceholderSpec {
+        name: "set",
+        placeholder_type: PlaceholderType::Set,
+        help: "One of the given options",
+        args: &[
+            ArgSpec { name: "options", kind: ArgKind::List, default: ArgDefault::Required, help: "Options to choose from. Quote options containing , ; or ]" }
+        ],
+        example: "${set:options=[A,B,C,D]}",
+        build: PlaceholderArgsParser::parse_set
+    },
+    PlaceholderSpec {
+        name: "date",
+        placeholder_type: PlaceholderType::Date,
+        help: "Day between from and to, both included",
+        args: &[
+            ArgSpec { name: "from", kind: ArgKind::Text, default: ArgDefault::Value("2000-01-01"), help: "A date such as 2015-01-01, now, or an offset from now such as -30d" },
+            ArgSpec { name: "to", kind: ArgKind::Text, default: ArgDefault::Value("2030-12-31"), help: "Like from, e.g. now" },
+            ArgSpec { name: "format", kind: ArgKind::Text, default: ArgDefault::Value("%Y-%m-%d"), help: "rfc3339, epoch, epoch_millis or a strftime pattern" }
+        ],
+        example: "${date:from=2015-01-01;to=now;format=%d/%m/%Y}",
+        build: PlaceholderArgsParser::parse_time
+    },
+    PlaceholderSpec {
+        name: "datetime",
+        placeholder_type: PlaceholderType::DateTime,
+        help: "Time between from and to, in UTC",
+        args: &[
+            ArgSpec { name: "from", kind: ArgKind::Text, default: ArgDefault::Value("2000-01-01"), help: "A date, a time such as 2015-01-01T09:30:00Z, now, or an offset from now such as -12h" },
+            ArgSpec { name: "to", kind: ArgKind::Text, default: ArgDefault::Value("2030-12-31"), help: "Like from, e.g. now" },
+            ArgSpec { name: "format", kind: ArgKind::Text, default: ArgDefault::Value("rfc3339"), help: "rfc3339, epoch, epoch_millis or a strftime pattern" }
+        ],
+        example: "${datetime:from=-30d;to=now;format=epoch_millis}",
+        build: PlaceholderArgsParser::parse_time
+    },
+    PlaceholderSpec {
+        name: "guid",
+        placeholder_type: PlaceholderType::Guid,
+        help: "Random (version 4) UUID",
+        args: NO_ARGS,
+        example: "${guid}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "doc::index",
+        placeholder_type: PlaceholderType::Doc(DocType::Index),
+        help: "Index of the document being generated",
+        args: &[
+            ArgSpec { name: "pad", kind: ArgKind::Count, default: ArgDefault::Optional, help: "Zero-pad the index to this many digits" }
+        ],
+        example: "${doc::index:pad=6}",
+        build: PlaceholderArgsParser::parse_index
+    },
+    PlaceholderSpec {
+        name: "doc::count",
+        placeholder_type: PlaceholderType::Doc(DocType::Count),
+        help: "Number of documents being generated",
+        args: &[
+            ArgSpec { name: "pad", kind: ArgKind::Count, default: ArgDefault::Optional, help: "Zero-pad the count to this many digits" }
+        ],
+        example: "${doc::count}",
+        build: PlaceholderArgsParser::parse_index
+    },
+    PlaceholderSpec {
+        name: "seq",
+        placeholder_type: PlaceholderType::Doc(DocType::Sequence),
+        help: "Next value of a counter that continues from one document to the next, skipping the values a #repeat did not use",
+        args: &[
+            ArgSpec { name: "start", kind: ArgKind::Integer, default: ArgDefault::Value("1"), help: "First value" },
+            ArgSpec { name: "step", kind: ArgKind::Integer, default: ArgDefault::Value("1"), help: "Difference between consecutive values" },
+            ArgSpec { name: "name", kind: ArgKind::Text, default: ArgDefault::Optional, help: "Placeholders with the same name draw from one sequence" }
+        ],
+        example: "${seq:start=1000;step=5;name=order}",
+        build: PlaceholderArgsParser::parse_sequence
+    },
+    PlaceholderSpec {
+        name: "stream::time",
+        placeholder_type: PlaceholderType::Doc(DocType::Stream),
+        help: "Time that moves forward from one document to the next, by a random amount around mean",
+        args: &[
+            ArgSpec { name: "start", kind: ArgKind::Text, default: ArgDefault::Value("2024-01-01T00:00:00Z"), help: "Time of the first document, like the from of datetime" },
+            ArgSpec { name: "mean", kind: ArgKind::Text, default: ArgDefault::Value("1s"), help: "Mean time between documents, e.g. 200ms, 5s or 1h" },
+            ArgSpec { name: "arrival", kind: ArgKind::Text, default: ArgDefault::Value("exponential"), help: "Distribution of the time between documents: exponential, uniform or fixed" },
+            ArgSpec { name: "format", kind: ArgKind::Text, default: ArgDefault::Value("rfc3339"), help: "rfc3339, epoch, epoch_millis or a strftime pattern" },
+            ArgSpec { name: "name", kind: ArgKind::Text, default: ArgDefault::Optional, help: "Tells apart streams with the same start, mean and arrival" }
+        ],
+        example: "${stream::time:start=2024-06-01T00:00:00Z;mean=200ms}",
+        build: PlaceholderArgsParser::parse_stream
+    },
+    PlaceholderSpec {
+        name: "var::<name>",
+        placeholder_type: PlaceholderType::External(ExternalType::Var),
+        help: "Variable given with --var name=value or --vars-file",
+        args: NO_ARGS,
+        example: "${var::region}",
+        build: PlaceholderArgsParser::parse_none
+    },
+    PlaceholderSpec {
+        name: "env::<name>",
+        placeholder_type: PlaceholderType::External(ExternalType::Env),
+        help: "Environment variable, read only with --allow-env",
+        args: NO_ARGS,
+        example: "${env::HOME}",
+        build: PlaceholderArgsParser::parse_none
+    }
+];
+
+/// The listing of `docgen types`: every placeholder type, modifier, filter and block, with their arguments.
+pub fn describe_types() -> String {
+    let mut listing = String::new();
+    for spec in REGISTRY {
+        let _ = write!(listing, "{}\n\n", spec);
+    }
+    listing.push_str("Modifiers accepted by every placeholder, e.g. ${phone:null=0.1}:");
+    describe_args(&mut listing, MODIFIER_ARGS);
+    listing.push_str("\n\nFilters, e.g. ${<1>name::last | lower | truncate:8}:");
+    for filter in FILTERS {
+        let _ = write!(listing, "\n{:<4}{:<20}{}", "", filter.usage, filter.help);
+    }
+    listing.push_str("\n\nBlocks:\n");
+    describe_block(&mut listing, "#repeat", "Renders the section a random number of times", "${#repeat min=1;max=5;sep=\",\"}...${/repeat}", REPEAT_ARGS);
+    describe_block(&mut listing, "#maybe", "Renders the section with some probability", "${#maybe p=0.3}...${/maybe}", MAYBE_ARGS);
+    listing.push_str("\n\nIncludes:\n");
+    describe_block(&mut listing, "include", "Inserts another template, relative to this one", "${include:partials/person.json;entity=2}", INCLUDE_ARGS);
+    listing
+}
+
+fn describe_block(listing: &mut String, name: &str, help: &str, example: &str, args: &[ArgSpec]) {
+    let _ = write!(listing, "\n{:<20}{}\n{}e.g. {}", name, help, " ".repeat(20), example);
+    describe_args(listing, args);
+}
+
+fn describe_args(listing: &mut String, args: &[ArgSpec]) {
+    for arg in args {
+        let _ = write!(listing, "\n{}", arg);
+    }
+}
+
+pub fn lookup(name: &str) -> Option<&'static PlaceholderSpec> {
+    REGISTRY.iter().find(|spec: &&PlaceholderSpec| spec.name == name)
+}
+
+pub fn for_type(placeholder_type: &PlaceholderType) -> &'static PlaceholderSpec {
+    REGISTRY.iter()
+        .find(|spec: &&PlaceholderSpec| spec.placeholder_type == *placeholder_type)
+        .expect("every placeholder type is registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_unique() {
+        for spec in REGISTRY {
+            assert_eq!(REGISTRY.iter().filter(|other| other.name == spec.name).count(), 1, "{} is registered twice", spec.name);
+        }
+    }
+
+    #[test]
+    fn examples_parse() {
+        for spec in REGISTRY {
+            let body: &str = spec.example.strip_prefix("${").and_then(|body: &str| body.strip_suffix('}')).unwrap();
+            let placeholder = crate::parser::Placeholder::parse(body)
+                .unwrap_or_else(|err| panic!("example for {} does not parse: {}", spec.name, err.reason));
+            assert_eq!(placeholder.data_type, spec.placeholder_type);
+        }
+    }
+}
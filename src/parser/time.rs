@@ -0,0 +1,113 @@
+use super::types::{Arrival, TimeFormat};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono::format::{Item, StrftimeItems};
+
+/// Parses a bound of a date or time range into milliseconds since the Unix epoch. A bound is
+/// a date such as `2015-01-01`, a date and time such as `2015-01-01T09:30:00Z`, `now`, or an
+/// offset from now such as `-30d` or `now+2h`, in `s`, `m`, `h`, `d`, `w` or `y` of 365 days.
+pub fn parse_bound(value: &str, now: i64) -> Result<i64, String> {
+    let relative: &str = value.strip_prefix("now").unwrap_or(value);
+    if relative.is_empty() {
+        return Ok(now);
+    }
+    if relative.starts_with('+') || relative.starts_with('-') {
+        return parse_offset(relative).map(|offset: i64| now + offset)
+            .ok_or_else(|| format!("invalid offset `{}`, expected e.g. -30d", value));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc().timestamp_millis());
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.timestamp_millis());
+    }
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .map(|datetime: NaiveDateTime| datetime.and_utc().timestamp_millis())
+        .map_err(|_| format!("invalid date `{}`, expected e.g. 2015-01-01, 2015-01-01T09:30:00Z, now or -30d", value))
+}
+
+/// Parses `-30d` or `+2h` into milliseconds.
+fn parse_offset(offset: &str) -> Option<i64> {
+    let sign: i64 = match offset.starts_with('-') {
+        true => -1,
+        false => 1
+    };
+    parse_duration(&offset[1..]).filter(|millis: &i64| *millis >= 0).map(|millis: i64| sign * millis)
+}
+
+/// Parses a duration such as `200ms`, `30d` or `2h` into milliseconds.
+pub fn parse_duration(duration: &str) -> Option<i64> {
+    let digits: usize = duration.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit): (&str, &str) = duration.split_at(digits);
+    let unit_millis: i64 = match unit {
+        "ms" => 1,
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        "w" => 7 * 86_400_000,
+        "y" => 365 * 86_400_000,
+        _ => return None
+    };
+    amount.parse::<i64>().ok()?.checked_mul(unit_millis)
+}
+
+/// Parses `exponential`, `uniform` or `fixed`.
+pub fn parse_arrival(value: &str) -> Result<Arrival, String> {
+    match value {
+        "exponential" => Ok(Arrival::Exponential),
+        "uniform" => Ok(Arrival::Uniform),
+        "fixed" => Ok(Arrival::Fixed),
+        _ => Err(format!("unknown arrival `{}`, expected exponential, uniform or fixed", value))
+    }
+}
+
+/// Parses `rfc3339`, `epoch`, `epoch_millis` or a strftime pattern such as `%Y-%m-%d %H:%M`.
+pub fn parse_format(value: &str) -> Result<TimeFormat, String> {
+    match value {
+        "rfc3339" => Ok(TimeFormat::Rfc3339),
+        "epoch" => Ok(TimeFormat::Epoch),
+        "epoch_millis" => Ok(TimeFormat::EpochMillis),
+        pattern if StrftimeItems::new(pattern).any(|item: Item| item == Item::Error) => Err(format!("invalid format `{}`", pattern)),
+        pattern => Ok(TimeFormat::Pattern(pattern.to_owned()))
+    }
+}
+
+pub fn now() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_absolute_and_relative_bounds() {
+        let now: i64 = 1_000_000_000_000;
+        assert_eq!(parse_bound("1970-01-02", now), Ok(86_400_000));
+        assert_eq!(parse_bound("1970-01-01T00:00:01Z", now), Ok(1_000));
+        assert_eq!(parse_bound("1970-01-01T01:00:00+01:00", now), Ok(0));
+        assert_eq!(parse_bound("now", now), Ok(now));
+        assert_eq!(parse_bound("-30d", now), Ok(now - 30 * 86_400_000));
+        assert_eq!(parse_bound("now+2h", now), Ok(now + 7_200_000));
+        assert_eq!(parse_bound("2019-02-31", now), Err("invalid date `2019-02-31`, expected e.g. 2015-01-01, 2015-01-01T09:30:00Z, now or -30d".to_owned()));
+        assert_eq!(parse_bound("-30x", now), Err("invalid offset `-30x`, expected e.g. -30d".to_owned()));
+        assert_eq!(parse_bound("-1500ms", now), Ok(now - 1500));
+    }
+
+    #[test]
+    fn parse_durations() {
+        assert_eq!(parse_duration("200ms"), Some(200));
+        assert_eq!(parse_duration("2h"), Some(7_200_000));
+        assert_eq!(parse_duration("ms"), None);
+        assert_eq!(parse_duration("5"), None);
+        assert_eq!(parse_duration("5x"), None);
+    }
+
+    #[test]
+    fn parse_formats() {
+        assert_eq!(parse_format("epoch_millis"), Ok(TimeFormat::EpochMillis));
+        assert_eq!(parse_format("%d/%m/%Y"), Ok(TimeFormat::Pattern("%d/%m/%Y".to_owned())));
+        assert_eq!(parse_format("%Q"), Err("invalid format `%Q`".to_owned()));
+    }
+}
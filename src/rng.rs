@@ -0,0 +1,51 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// The RNG documents are rendered with. ChaCha is used rather than `StdRng` because its
+/// output is fixed by its specification, so a seed reproduces the same documents on any machine.
+pub type DocumentRng = ChaCha8Rng;
+
+/// Returns the RNG for the document at `index` of a run seeded with `seed`.
+/// Every document draws from its own ChaCha stream, which is what allows a single
+/// document to be regenerated without rendering the ones before it.
+pub fn document_rng(seed: u64, index: u64) -> DocumentRng {
+    let mut rng = DocumentRng::seed_from_u64(seed);
+    rng.set_stream(index);
+    rng
+}
+
+/// Returns the RNG of the values that continue from one document to the next in a stream named `key`,
+/// such as the time between documents. Its ChaCha stream is kept apart from those of the documents.
+pub(crate) fn stream_rng(seed: u64, key: &str) -> DocumentRng {
+    // FNV-1a, which unlike the standard library's hasher is the same in every build.
+    let hash: u64 = key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash: u64, byte: u8| (hash ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3));
+    let mut rng = DocumentRng::seed_from_u64(seed);
+    rng.set_stream(1 << 63 | hash >> 1);
+    rng
+}
+
+/// A fixed RNG for generating throwaway values while validating a template.
+pub(crate) fn validation_rng() -> DocumentRng {
+    DocumentRng::seed_from_u64(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn same_seed_and_index_produce_same_values() {
+        let (first, second) = (&mut document_rng(42, 7), &mut document_rng(42, 7));
+        for _ in 0..8 {
+            assert_eq!(first.gen::<u64>(), second.gen::<u64>());
+        }
+    }
+
+    #[test]
+    fn documents_draw_from_different_streams() {
+        let first: u64 = document_rng(42, 0).gen();
+        let second: u64 = document_rng(42, 1).gen();
+        assert_ne!(first, second);
+    }
+}
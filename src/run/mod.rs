@@ -0,0 +1,103 @@
+use crate::rng::{self, DocumentRng};
+
+use std::io;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread;
+
+/// How many rendered documents each worker may have waiting for the writer.
+const WORKER_BUFFER: usize = 64;
+
+/// The documents to produce in a single invocation of the tool.
+#[derive(Clone,Debug)]
+pub struct Run {
+    pub seed: u64,
+    pub skip: u64,
+    pub count: u64,
+    pub jobs: usize
+}
+
+impl Run {
+    /// Renders every document of the run and hands them to `write` in index order.
+    ///
+    /// With more than one job, worker `w` renders documents `w`, `w + jobs`, `w + 2 * jobs`, ...
+    /// and the calling thread reads from the workers round-robin. Each document is rendered with
+    /// its own seeded RNG, so the output does not depend on the number of jobs.
+    pub fn execute<T, R, W>(&self, render: R, mut write: W) -> io::Result<()>
+        where T: Send,
+              R: Fn(u64, &mut DocumentRng) -> T + Sync,
+              W: FnMut(u64, T) -> io::Result<()>
+    {
+        if self.jobs <= 1 {
+            for index in self.indices() {
+                write(index, render(index, &mut rng::document_rng(self.seed, index)))?;
+            }
+            return Ok(());
+        }
+
+        thread::scope(|scope| {
+            let receivers: Vec<Receiver<T>> = (0..self.jobs)
+                .map(|worker: usize| {
+                    let (sender, receiver) = mpsc::sync_channel(WORKER_BUFFER);
+                    let render = &render;
+                    scope.spawn(move || self.work(render, worker, sender));
+                    receiver
+                })
+                .collect();
+
+            for (position, index) in self.indices().enumerate() {
+                let document: T = receivers[position % self.jobs].recv()
+                    .map_err(|_| io::Error::other("document worker stopped unexpectedly"))?;
+                write(index, document)?;
+            }
+            // Dropping the receivers on an early return stops any worker still rendering.
+            Ok(())
+        })
+    }
+
+    fn work<T, R>(&self, render: &R, worker: usize, sender: SyncSender<T>)
+        where R: Fn(u64, &mut DocumentRng) -> T
+    {
+        for index in self.indices().skip(worker).step_by(self.jobs) {
+            let document: T = render(index, &mut rng::document_rng(self.seed, index));
+            if sender.send(document).is_err() {
+                return;
+            }
+        }
+    }
+
+    fn indices(&self) -> std::ops::Range<u64> {
+        self.skip..self.skip + self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::{Template, RenderContext};
+
+    fn collect(run: &Run, template: &Template) -> Vec<(u64, String)> {
+        let mut documents = Vec::new();
+        run.execute(|index, rng| template.render_with(&mut RenderContext::new(index), rng), |index, document| {
+            documents.push((index, document));
+            Ok(())
+        }).unwrap();
+        documents
+    }
+
+    #[test]
+    fn output_does_not_depend_on_jobs() {
+        let template = Template::compile("${<1>name::full} ${guid} ${int:min=0;max=100} ${#repeat max=3}${seq}${/repeat} ${stream::time:mean=200ms}").unwrap();
+        let single = collect(&Run { seed: 11, skip: 3, count: 50, jobs: 1 }, &template);
+        let parallel = collect(&Run { seed: 11, skip: 3, count: 50, jobs: 4 }, &template);
+        assert_eq!(single.len(), 50);
+        assert_eq!(single, parallel);
+        assert_eq!(parallel.first().map(|doc| doc.0), Some(3));
+    }
+
+    #[test]
+    fn more_jobs_than_documents() {
+        let template = Template::compile("${guid}").unwrap();
+        let documents = collect(&Run { seed: 1, skip: 0, count: 2, jobs: 8 }, &template);
+        assert_eq!(documents.len(), 2);
+    }
+}
@@ -0,0 +1,564 @@
+use super::{lexer, visit, CompileOptions, Escape, Segment, Template};
+use super::lexer::Token;
+use crate::parser::{block, registry, split_entity_id, Placeholder, PLACEHOLDER_REGEX};
+use crate::parser::error::{PlaceholderParseError, SourceLocation};
+use crate::parser::expression::Expression;
+use crate::parser::filter::{self, Filter};
+use crate::parser::include::Include;
+use crate::types::{BlockType, ExternalType, PlaceholderArgs, PlaceholderType};
+
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+lazy_static! {
+    static ref LET_REGEX: Regex = Regex::new(r"(?s)^let\s+(?P<name>[a-zA-Z_][a-zA-Z0-9_]*)\s*=\s*(?P<value>.*)$").unwrap();
+    static ref VARIABLE_REGEX: Regex = Regex::new(r"^\$(?P<name>[a-zA-Z_][a-zA-Z0-9_]*)$").unwrap();
+}
+
+/// A block that has been opened but not yet closed.
+struct OpenBlock {
+    block: BlockType,
+    location: SourceLocation,
+    segments: Vec<Segment>
+}
+
+/// Turns the tokens of a template into a tree of segments, collecting every error on the way.
+struct Compiler {
+    /// The text of the template or partial being compiled.
+    template: String,
+    /// The name of the template or partial being compiled, for error locations.
+    file: Option<String>,
+    /// The directory that includes of the template or partial being compiled are relative to.
+    include_dir: PathBuf,
+    /// The files being compiled, outermost first, with the names they are reported by.
+    include_stack: Vec<(PathBuf, String)>,
+    /// The `file:line:column` of each include being compiled, innermost first.
+    included_from: Vec<String>,
+    /// Entity ids of the partial being compiled, renamed to those given by its include.
+    entity_ids: HashMap<String, String>,
+    /// The number of blocks opened outside of the partial being compiled, which it cannot close.
+    block_floor: usize,
+    escape: Escape,
+    /// The start and step of each sequence given a name, which every use of the name must agree on.
+    sequences: HashMap<String, (i64, i64)>,
+    vars: HashMap<String, String>,
+    allow_env: bool,
+    segments: Vec<Segment>,
+    open_blocks: Vec<OpenBlock>,
+    variables: HashMap<String, Segment>,
+    references: Vec<(String, SourceLocation)>,
+    errors: Vec<PlaceholderParseError>
+}
+
+pub(super) fn compile(template: &str, options: &CompileOptions) -> Result<Template, Vec<PlaceholderParseError>> {
+    let mut compiler = Compiler {
+        template: template.to_owned(),
+        file: options.name.clone(),
+        include_dir: options.path.as_deref().and_then(Path::parent).map(Path::to_path_buf).unwrap_or_default(),
+        include_stack: Vec::new(),
+        included_from: Vec::new(),
+        entity_ids: HashMap::new(),
+        block_floor: 0,
+        escape: options.escape,
+        sequences: HashMap::new(),
+        vars: options.vars.clone(),
+        allow_env: options.allow_env,
+        segments: Vec::new(),
+        open_blocks: Vec::new(),
+        variables: HashMap::new(),
+        references: Vec::new(),
+        errors: Vec::new()
+    };
+    if let Some(path) = &options.path {
+        compiler.include_stack.push((canonical(path), path.display().to_string()));
+    }
+    let tokens: Vec<Token> = lexer::tokenize(template).map_err(|err| vec![compiler.relocate(err)])?;
+    for token in tokens {
+        compiler.compile_token(token);
+    }
+    compiler.finish()
+}
+
+impl Compiler {
+    fn compile_token(&mut self, token: Token) {
+        let (entity_id, body, span) = match token {
+            Token::Literal(text) => return self.push(Segment::Literal(text)),
+            Token::Placeholder { entity_id, body, span } => (entity_id, body, span)
+        };
+        let location: SourceLocation = self.locate(span);
+        if entity_id.is_none() {
+            if let Some(path) = body.strip_prefix("include:") {
+                return self.include(path, location);
+            }
+            if let Some(directive) = body.strip_prefix('#') {
+                // `${# note}` is a comment, while blocks are opened with `${#name}`.
+                if directive.is_empty() || directive.starts_with(char::is_whitespace) {
+                    return;
+                }
+                return self.open_block(directive, location);
+            }
+            if let Some(name) = body.strip_prefix('/') {
+                return self.close_block(name.trim(), location);
+            }
+            if let Some(captures) = LET_REGEX.captures(&body) {
+                return self.define(&captures["name"], &captures["value"], location);
+            }
+        }
+        if let Some(segment) = self.compile_value(entity_id, &body, location, false) {
+            self.push(segment);
+        }
+    }
+
+    /// Compiles anything that renders a value: a placeholder, `$variable` or `= expression`, followed
+    /// by any `| filter`s. With `bare_expression`, text that is not a placeholder is read as an expression.
+    fn compile_value(&mut self, entity_id: Option<String>, text: &str, location: SourceLocation, bare_expression: bool) -> Option<Segment> {
+        let entity_id: Option<String> = entity_id.map(|id: String| self.entity_id(id));
+        let (value, filter_texts): (&str, Vec<&str>) = filter::split_pipeline(text);
+        let mut filters: Vec<Filter> = Vec::new();
+        for filter_text in filter_texts {
+            match Filter::parse(filter_text) {
+                Ok(filter) => filters.push(filter),
+                Err(reason) => {
+                    self.errors.push(PlaceholderParseError::invalid_arg(filter_text, reason).at(location));
+                    return None;
+                }
+            }
+        }
+        let segment: Segment = match (entity_id.is_none(), value.strip_prefix('='), VARIABLE_REGEX.captures(value)) {
+            (true, Some(expression), _) => self.compile_expression(expression, location.clone())?,
+            (true, None, _) if bare_expression && !is_placeholder(value) => self.compile_expression(value, location.clone())?,
+            (true, None, Some(captures)) => {
+                let name: String = captures["name"].to_owned();
+                self.references.push((name.clone(), location.clone()));
+                Segment::Variable { name, quoted: false }
+            },
+            _ => match compile_placeholder(entity_id, value, location.clone()) {
+                Ok(Segment::Document(mut placeholder)) => {
+                    self.bind(&mut placeholder, site_name(&location), &location);
+                    Segment::Document(placeholder)
+                },
+                Ok(segment) => segment,
+                Err(err) => {
+                    self.errors.push(err.at(location));
+                    return None;
+                }
+            }
+        };
+        match filters.is_empty() {
+            true => Some(segment),
+            false => Some(Segment::Filtered { value: Box::new(segment), filters, location })
+        }
+    }
+
+    /// Adds a segment to the innermost open block, or to the top level of the template.
+    fn push(&mut self, segment: Segment) {
+        match self.open_blocks.last_mut() {
+            Some(open_block) => open_block.segments.push(segment),
+            None => self.segments.push(segment)
+        }
+    }
+
+    fn open_block(&mut self, directive: &str, location: SourceLocation) {
+        match block::parse_open(directive) {
+            Ok(block) => self.open_blocks.push(OpenBlock { block, location, segments: Vec::new() }),
+            Err(reason) => self.errors.push(PlaceholderParseError::invalid_block(directive, reason).at(location))
+        }
+    }
+
+    fn close_block(&mut self, name: &str, location: SourceLocation) {
+        let open_block: OpenBlock = match self.open_blocks.len() > self.block_floor {
+            true => self.open_blocks.pop().expect("an open block"),
+            false => {
+                self.errors.push(PlaceholderParseError::invalid_block(name, format!("`${{/{}}}` does not close any block", name)).at(location));
+                return;
+            }
+        };
+        if block::name(&open_block.block) != name {
+            self.errors.push(PlaceholderParseError::invalid_block(name, format!(
+                "`${{/{}}}` closes the `{}` block opened on line {}",
+                name, block::name(&open_block.block), open_block.location.line)).at(location));
+        }
+        self.push_block(open_block);
+    }
+
+    fn push_block(&mut self, open_block: OpenBlock) {
+        let mut local_variables: Vec<String> = Vec::new();
+        visit(&open_block.segments, &mut |segment: &Segment| {
+            if let Segment::Let { name, .. } = segment {
+                local_variables.push(name.clone());
+            }
+        });
+        self.push(Segment::Block { block: open_block.block, body: open_block.segments, local_entities: Vec::new(), local_variables });
+    }
+
+    /// Compiles `${let name = value}`, where the value is either a placeholder, which may belong
+    /// to an entity, or an expression.
+    fn define(&mut self, name: &str, value: &str, location: SourceLocation) {
+        if self.variables.contains_key(name) {
+            return self.errors.push(PlaceholderParseError::invalid_arg(value, format!("variable `${}` is defined more than once", name)).at(location));
+        }
+        let (entity_id, body): (Option<String>, &str) = split_entity_id(value.trim());
+        if let Some(segment) = self.compile_value(entity_id, body, location, true) {
+            self.variables.insert(name.to_owned(), segment.clone());
+            self.push(Segment::Let { name: name.to_owned(), value: Box::new(segment) });
+        }
+    }
+
+    fn compile_expression(&mut self, text: &str, location: SourceLocation) -> Option<Segment> {
+        match Expression::parse(text.trim()) {
+            Ok(mut expression) => {
+                let mut operand_index: usize = 0;
+                expression.visit_operands_mut(&mut |operand: &mut Expression| {
+                    if let Expression::Placeholder { entity_id, placeholder } = operand {
+                        *entity_id = entity_id.take().map(|id: String| self.entity_id(id));
+                        self.bind(placeholder, format!("{}#{}", site_name(&location), operand_index), &location);
+                        operand_index += 1;
+                    }
+                });
+                expression.visit_operands(&mut |operand: &Expression| {
+                    if let Expression::Variable(name) = operand {
+                        self.references.push((name.clone(), location.clone()));
+                    }
+                });
+                Some(Segment::Expression { expression, location })
+            },
+            Err(reason) => {
+                self.errors.push(PlaceholderParseError::invalid_arg(text.trim(), reason).at(location));
+                None
+            }
+        }
+    }
+
+    /// Reports variables whose values depend on each other, which could never be evaluated.
+    fn check_cycles(&mut self) -> bool {
+        let mut names: Vec<&String> = self.variables.keys().collect();
+        names.sort();
+        let mut cycles: Vec<Vec<String>> = Vec::new();
+        let mut finished: Vec<String> = Vec::new();
+        for name in names {
+            find_cycles(name, &self.variables, &mut Vec::new(), &mut finished, &mut cycles);
+        }
+        for cycle in &cycles {
+            let location: Option<SourceLocation> = self.references.iter()
+                .find(|(name, _)| *name == cycle[0])
+                .map(|(_, location)| location.clone());
+            let path: Vec<String> = cycle.iter().map(|name: &String| format!("`${}`", name)).collect();
+            let mut err = PlaceholderParseError::invalid_arg(&format!("${}", cycle[0]), format!("variables depend on each other: {}", path.join(" -> ")));
+            if let Some(location) = location {
+                err = err.at(location);
+            }
+            self.errors.push(err);
+        }
+        !cycles.is_empty()
+    }
+
+    /// Compiles the tokens of the partial at `path` in place of its `${include:...}`.
+    fn include(&mut self, args: &str, location: SourceLocation) {
+        let include: Include = match Include::parse(args) {
+            Ok(include) => include,
+            Err(reason) => return self.errors.push(PlaceholderParseError::invalid_arg(args, reason).at(location))
+        };
+        let path: PathBuf = self.include_dir.join(&include.path);
+        let name: String = path.display().to_string();
+        let canonical_path: PathBuf = canonical(&path);
+        if let Some(start) = self.include_stack.iter().position(|(included, _)| *included == canonical_path) {
+            let mut cycle: Vec<&str> = self.include_stack[start..].iter().map(|(_, name)| name.as_str()).collect();
+            cycle.push(&name);
+            return self.errors.push(PlaceholderParseError::invalid_arg(args, format!("templates include each other: {}", cycle.join(" -> "))).at(location));
+        }
+        let template: String = match std::fs::read_to_string(&path) {
+            Ok(template) => template,
+            Err(err) => return self.errors.push(PlaceholderParseError::invalid_arg(args, format!("could not read `{}`: {}", name, err)).at(location))
+        };
+
+        let mut entity_ids: HashMap<String, String> = HashMap::new();
+        if let Some(entity) = include.entity {
+            entity_ids.insert("1".to_owned(), self.entity_id(entity));
+        }
+        let included_from: String = format!("{}:{}:{}", location.file.as_deref().unwrap_or("<template>"), location.line, location.column);
+        let outer_template: String = std::mem::replace(&mut self.template, template);
+        let outer_file: Option<String> = self.file.replace(name.clone());
+        let outer_dir: PathBuf = std::mem::replace(&mut self.include_dir, path.parent().map(Path::to_path_buf).unwrap_or_default());
+        let outer_entity_ids: HashMap<String, String> = std::mem::replace(&mut self.entity_ids, entity_ids);
+        let outer_block_floor: usize = std::mem::replace(&mut self.block_floor, self.open_blocks.len());
+        self.include_stack.push((canonical_path, name));
+        self.included_from.insert(0, included_from);
+
+        match lexer::tokenize(&self.template) {
+            Ok(tokens) => tokens.into_iter().for_each(|token: Token| self.compile_token(token)),
+            Err(err) => {
+                let err: PlaceholderParseError = self.relocate(err);
+                self.errors.push(err);
+            }
+        }
+        // Blocks are closed in the file they are opened in.
+        self.close_open_blocks();
+
+        self.included_from.remove(0);
+        self.include_stack.pop();
+        self.block_floor = outer_block_floor;
+        self.entity_ids = outer_entity_ids;
+        self.include_dir = outer_dir;
+        self.file = outer_file;
+        self.template = outer_template;
+    }
+
+    fn close_open_blocks(&mut self) {
+        while self.open_blocks.len() > self.block_floor {
+            let open_block: OpenBlock = self.open_blocks.pop().expect("an open block");
+            let name: &str = block::name(&open_block.block);
+            self.errors.push(PlaceholderParseError::invalid_block(name, format!("`{}` block is never closed with `${{/{}}}`", name, name))
+                .at(open_block.location.clone()));
+            self.push_block(open_block);
+        }
+    }
+
+    /// Locates the byte range `span` within the template or partial being compiled.
+    fn locate(&self, span: std::ops::Range<usize>) -> SourceLocation {
+        let mut location: SourceLocation = SourceLocation::of(&self.template, span);
+        location.file = self.file.clone();
+        location.included_from = self.included_from.clone();
+        location
+    }
+
+    /// Moves an error located by the lexer into the file being compiled.
+    fn relocate(&self, mut err: PlaceholderParseError) -> PlaceholderParseError {
+        if let Some(location) = err.location.as_mut() {
+            location.file = self.file.clone();
+            location.included_from = self.included_from.clone();
+        }
+        err
+    }
+
+    /// Fills in what a document placeholder needs from the compiler. A sequence without a name is given one
+    /// of its own, `unnamed`, and sequences with a name are checked to always have the same start and step.
+    /// External placeholders are given their value.
+    fn bind(&mut self, placeholder: &mut Placeholder, unnamed: String, location: &SourceLocation) {
+        let text: String = placeholder.to_string();
+        if let (PlaceholderType::External(external_type), Some(PlaceholderArgs::External { name, value })) = (&placeholder.data_type, &mut placeholder.data_args) {
+            match self.external_value(external_type, name) {
+                Ok(external) => *value = Some(external),
+                Err(reason) => self.errors.push(PlaceholderParseError::invalid_arg(&text, reason).at(location.clone()))
+            }
+        }
+        if let Some(PlaceholderArgs::Sequence { start, step, name }) = &mut placeholder.data_args {
+            match name {
+                None => *name = Some(unnamed),
+                Some(name) => match self.sequences.get(name.as_str()) {
+                    Some(first) if *first != (*start, *step) => self.errors.push(PlaceholderParseError::invalid_arg(
+                        &text, format!("sequence `{}` is used with a different start or step elsewhere", name)).at(location.clone())),
+                    Some(_) => (),
+                    None => {
+                        self.sequences.insert(name.clone(), (*start, *step));
+                    }
+                }
+            }
+        }
+    }
+
+    fn external_value(&self, external_type: &ExternalType, name: &str) -> Result<String, String> {
+        match external_type {
+            ExternalType::Var => self.vars.get(name).cloned()
+                .ok_or_else(|| format!("undefined variable `var::{}`, set it with --var {}=... or --vars-file", name, name)),
+            ExternalType::Env if !self.allow_env => Err(format!("`env::{}` reads the environment, which is only allowed with --allow-env", name)),
+            ExternalType::Env => std::env::var(name).map_err(|_| format!("environment variable `{}` is not set", name))
+        }
+    }
+
+    /// The id an entity of the partial being compiled is known by in the including template.
+    fn entity_id(&self, id: String) -> String {
+        self.entity_ids.get(&id).cloned().unwrap_or(id)
+    }
+
+    fn finish(mut self) -> Result<Template, Vec<PlaceholderParseError>> {
+        self.close_open_blocks();
+        for (name, location) in &self.references {
+            if !self.variables.contains_key(name) {
+                self.errors.push(PlaceholderParseError::invalid_arg(&format!("${}", name), format!("undefined variable `${}`", name)).at(location.clone()));
+            }
+        }
+
+        let has_cycles: bool = self.check_cycles();
+
+        let mut segments: Vec<Segment> = self.segments;
+        quote_nullable_segments(&mut segments, &self.variables);
+        scope_entities(&mut segments, &HashSet::new());
+
+        let mut sequence_strides: HashMap<String, u64> = HashMap::new();
+        count_sequence_draws(&segments, 1, &mut sequence_strides);
+
+        let compiled = Template { segments, variables: self.variables, escape: self.escape, sequence_strides };
+        let mut errors: Vec<PlaceholderParseError> = self.errors;
+        // Expressions are evaluated to validate them, which would never end if variables depend on each other.
+        if !has_cycles {
+            errors.extend(compiled.validate());
+        }
+        match errors.is_empty() {
+            true => Ok(compiled),
+            false => Err(errors)
+        }
+    }
+}
+
+/// The path a file is known by however it is written, so that an include cycle is always noticed.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn compile_placeholder(entity_id: Option<String>, body: &str, location: SourceLocation) -> Result<Segment, PlaceholderParseError> {
+    let placeholder: Placeholder = Placeholder::parse(body)?;
+    match placeholder.data_type {
+        PlaceholderType::Doc(_) | PlaceholderType::External(_) => Ok(Segment::Document(placeholder)),
+        _ => Ok(Segment::Placeholder { entity_id, placeholder, location, quoted: false })
+    }
+}
+
+/// Whether the value of a `let` is a placeholder, as opposed to an expression.
+fn is_placeholder(value: &str) -> bool {
+    PLACEHOLDER_REGEX.captures(value).is_some_and(|captures: regex::Captures| {
+        captures.name("args").is_some() || registry::lookup(&captures["data_type"]).is_some()
+    })
+}
+
+/// Depth first search for variables that refer back to themselves, through `path`.
+fn find_cycles(name: &str, variables: &HashMap<String, Segment>, path: &mut Vec<String>, finished: &mut Vec<String>, cycles: &mut Vec<Vec<String>>) {
+    if finished.iter().any(|done: &String| done == name) {
+        return;
+    }
+    if let Some(start) = path.iter().position(|visiting: &String| visiting == name) {
+        let mut cycle: Vec<String> = path[start..].to_vec();
+        cycle.push(name.to_owned());
+        cycles.push(cycle);
+        return;
+    }
+    path.push(name.to_owned());
+    if let Some(Segment::Expression { expression, .. }) = variables.get(name) {
+        let mut dependencies: Vec<String> = Vec::new();
+        expression.visit_operands(&mut |operand: &Expression| {
+            if let Expression::Variable(dependency) = operand {
+                dependencies.push(dependency.clone());
+            }
+        });
+        for dependency in dependencies {
+            find_cycles(&dependency, variables, path, finished, cycles);
+        }
+    }
+    path.pop();
+    finished.push(name.to_owned());
+}
+
+/// Whether a segment can render the null token instead of a value.
+fn is_nullable(segment: &Segment, variables: &HashMap<String, Segment>) -> bool {
+    match segment {
+        Segment::Placeholder { placeholder, .. } => placeholder.modifiers.null_probability.is_some(),
+        Segment::Variable { name, .. } => variables.get(name).is_some_and(|value: &Segment| is_nullable(value, variables)),
+        Segment::Filtered { value, .. } => is_nullable(value, variables),
+        _ => false
+    }
+}
+
+/// Moves the double quotes around each segment that can render the null token into the segment,
+/// so that they can be left out around the null token.
+fn quote_nullable_segments(segments: &mut [Segment], variables: &HashMap<String, Segment>) {
+    for index in 0..segments.len() {
+        if let Segment::Block { body, .. } = &mut segments[index] {
+            quote_nullable_segments(body, variables);
+        }
+        if !is_nullable(&segments[index], variables) || index == 0 || index + 1 == segments.len() {
+            continue;
+        }
+        let (before, rest) = segments.split_at_mut(index);
+        let (current, after) = rest.split_at_mut(1);
+        let quoted: &mut bool = match quoted_flag(&mut current[0]) {
+            Some(quoted) => quoted,
+            None => continue
+        };
+        if let (Segment::Literal(before), Segment::Literal(after)) = (&mut before[index - 1], &mut after[0]) {
+            if before.ends_with('"') && after.starts_with('"') {
+                before.pop();
+                after.remove(0);
+                *quoted = true;
+            }
+        }
+    }
+}
+
+fn quoted_flag(segment: &mut Segment) -> Option<&mut bool> {
+    match segment {
+        Segment::Placeholder { quoted, .. } | Segment::Variable { quoted, .. } => Some(quoted),
+        Segment::Filtered { value, .. } => quoted_flag(value),
+        _ => None
+    }
+}
+
+/// The name of a sequence without one, which is where it is used in the template.
+fn site_name(location: &SourceLocation) -> String {
+    format!("{}:{}:{}", location.file.as_deref().unwrap_or("<template>"), location.line, location.column)
+}
+
+/// Adds the most values of each sequence that `segments` can take, rendered `times` times, to `draws`.
+fn count_sequence_draws(segments: &[Segment], times: u64, draws: &mut HashMap<String, u64>) {
+    let mut add = |placeholder: &Placeholder| {
+        if let Some(PlaceholderArgs::Sequence { name: Some(name), .. }) = &placeholder.data_args {
+            *draws.entry(name.clone()).or_default() += times;
+        }
+    };
+    let mut nested: Vec<(&[Segment], u64)> = Vec::new();
+    for segment in segments {
+        match segment {
+            Segment::Document(placeholder) => add(placeholder),
+            Segment::Expression { expression, .. } => expression.visit_operands(&mut |operand: &Expression| {
+                if let Expression::Placeholder { placeholder, .. } = operand {
+                    add(placeholder);
+                }
+            }),
+            Segment::Block { block: BlockType::Repeat { max, .. }, body, .. } => nested.push((body, times.saturating_mul(*max))),
+            Segment::Block { body, .. } => nested.push((body, times)),
+            Segment::Let { value, .. } | Segment::Filtered { value, .. } => nested.push((std::slice::from_ref(value), times)),
+            _ => ()
+        }
+    }
+    for (segments, times) in nested {
+        count_sequence_draws(segments, times, draws);
+    }
+}
+
+/// Adds the entities used in `segments` outside of any block to `entities`.
+fn direct_entities(segments: &[Segment], entities: &mut HashSet<String>) {
+    for segment in segments {
+        match segment {
+            Segment::Placeholder { entity_id: Some(id), .. } => {
+                entities.insert(id.clone());
+            },
+            Segment::Expression { expression, .. } => expression.visit_operands(&mut |operand: &Expression| {
+                if let Expression::Placeholder { entity_id: Some(id), .. } = operand {
+                    entities.insert(id.clone());
+                }
+            }),
+            Segment::Let { value, .. } | Segment::Filtered { value, .. } => direct_entities(std::slice::from_ref(value), entities),
+            _ => ()
+        }
+    }
+}
+
+/// Makes each entity local to the blocks that use it, so that it starts over each time one of them
+/// is rendered. An entity that is also used outside those blocks, in the section around them, belongs
+/// to that section instead, and entities used outside every block are shared by the whole document.
+/// `outer` are the entities that belong to the sections enclosing `segments`.
+fn scope_entities(segments: &mut [Segment], outer: &HashSet<String>) {
+    let mut enclosing: HashSet<String> = outer.clone();
+    direct_entities(segments, &mut enclosing);
+    for segment in segments {
+        if let Segment::Block { body, local_entities, .. } = segment {
+            let mut used: HashSet<String> = HashSet::new();
+            direct_entities(body, &mut used);
+            let mut local: Vec<String> = used.into_iter().filter(|id: &String| !enclosing.contains(id)).collect();
+            local.sort();
+            let mut inner: HashSet<String> = enclosing.clone();
+            inner.extend(local.iter().cloned());
+            scope_entities(body, &inner);
+            *local_entities = local;
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::parser::Placeholder;
+use crate::entity::Entity;
+use crate::generator::date;
+use crate::generator::stream::Stream;
+use crate::types::{PlaceholderType, PlaceholderArgs, DocType};
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// The state shared by everything rendered for one document, so that a body and its
+/// filename template see the same index and the same entities.
+#[derive(Debug)]
+pub struct RenderContext {
+    pub index: u64,
+    /// The seed of the run, which streams continue from one document to the next with.
+    pub seed: u64,
+    /// The number of documents being generated, for `${doc::count}`.
+    pub count: u64,
+    /// Rendered in place of values that a `null=` modifier chose to leave out.
+    pub null_token: String,
+    entities: HashMap<String, Entity>,
+    variables: HashMap<String, Option<String>>,
+    /// The number of values of each sequence reserved for every document, by name.
+    sequence_strides: HashMap<String, u64>,
+    /// The number of values of each sequence this document has taken so far.
+    sequence_draws: HashMap<String, u64>
+}
+
+impl RenderContext {
+    pub fn new(index: u64) -> RenderContext {
+        RenderContext {
+            index,
+            seed: 0,
+            count: 1,
+            null_token: "null".to_owned(),
+            entities: HashMap::new(),
+            variables: HashMap::new(),
+            sequence_strides: HashMap::new(),
+            sequence_draws: HashMap::new()
+        }
+    }
+
+    /// Reserves `strides` values of each sequence for every document, unless more were reserved already.
+    ///
+    /// Document `index` takes its values of a sequence from position `index * stride` onwards, so the
+    /// values of a document never depend on the documents rendered before it. Every document of a run
+    /// must reserve the same strides, enough for all of the templates rendered into it.
+    pub fn reserve_sequences(&mut self, strides: &HashMap<String, u64>) {
+        for (name, stride) in strides {
+            let reserved: &mut u64 = self.sequence_strides.entry(name.clone()).or_default();
+            *reserved = (*reserved).max(*stride);
+        }
+    }
+
+    pub(super) fn entity(&mut self, id: &str) -> &mut Entity {
+        self.entities.entry(id.to_owned()).or_default()
+    }
+
+    pub(super) fn variable(&self, name: &str) -> Option<&Option<String>> {
+        self.variables.get(name)
+    }
+
+    pub(super) fn set_variable(&mut self, name: &str, value: Option<String>) {
+        self.variables.insert(name.to_owned(), value);
+    }
+
+    /// Forgets the given entities and variables, so that their next use generates new values.
+    pub(super) fn forget(&mut self, entity_ids: &[String], variables: &[String]) {
+        for id in entity_ids {
+            self.entities.remove(id);
+        }
+        for name in variables {
+            self.variables.remove(name);
+        }
+    }
+
+    /// The value of a document placeholder, or `None` where a sequence runs past the range of an `i64`.
+    pub(super) fn value_of(&mut self, placeholder: &Placeholder) -> Option<String> {
+        match (&placeholder.data_type, &placeholder.data_args) {
+            (PlaceholderType::Doc(DocType::Index), Some(PlaceholderArgs::Index { pad })) => Some(format!("{:0width$}", self.index, width = pad)),
+            (PlaceholderType::Doc(DocType::Index), _) => Some(self.index.to_string()),
+            (PlaceholderType::Doc(DocType::Count), Some(PlaceholderArgs::Index { pad })) => Some(format!("{:0width$}", self.count, width = pad)),
+            (PlaceholderType::Doc(DocType::Count), _) => Some(self.count.to_string()),
+            (PlaceholderType::Doc(DocType::Sequence), Some(PlaceholderArgs::Sequence { start, step, name: Some(name) })) => {
+                let stride: u64 = self.sequence_strides.get(name).copied().unwrap_or(1);
+                let draws: &mut u64 = self.sequence_draws.entry(name.clone()).or_default();
+                let position: Option<u64> = self.index.checked_mul(stride).and_then(|first: u64| first.checked_add(*draws));
+                *draws += 1;
+                position.and_then(|position: u64| i64::try_from(position).ok())
+                    .and_then(|position: i64| step.checked_mul(position))
+                    .and_then(|offset: i64| start.checked_add(offset))
+                    .map(|value: i64| value.to_string())
+            },
+            (PlaceholderType::Doc(DocType::Stream), Some(PlaceholderArgs::Stream { start, mean, arrival, format, name })) => {
+                // Placeholders that only differ in their format share a stream, so it can be written in several formats.
+                let key: String = format!("{}:{}:{}:{:?}", name, start, mean, arrival);
+                let elapsed: f64 = Stream::elapsed(self.seed, &key, self.index, *mean, *arrival);
+                Some(date::format_millis(start + elapsed as i64, format))
+            },
+            // The compiler reports external placeholders it could not find a value for.
+            (PlaceholderType::External(_), Some(PlaceholderArgs::External { value, .. })) => Some(value.clone().unwrap_or_default()),
+            (data_type, _) => unreachable!("{:?} is not a document value", data_type)
+        }
+    }
+}
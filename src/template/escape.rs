@@ -0,0 +1,117 @@
+use std::path::Path;
+use std::str::FromStr;
+
+/// How generated values are escaped for the format of the document they are written into.
+/// Literal text of the template, and the null token, are never escaped.
+#[derive(Clone,Copy,Debug,PartialEq,Default)]
+pub enum Escape {
+    /// For values inside JSON strings.
+    Json,
+    /// For values in XML text or attributes.
+    Xml,
+    /// For unquoted CSV fields, which are quoted when they need to be.
+    Csv,
+    /// For values inside single quoted SQL strings.
+    Sql,
+    #[default]
+    None
+}
+
+pub static ESCAPE_NAMES: &[&str] = &["json", "xml", "csv", "sql", "none"];
+
+impl Escape {
+    /// The escaping for a template file with the extension of `path`, or none for other extensions.
+    pub fn for_path(path: &Path) -> Escape {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension: &str| extension.to_lowercase().parse::<Escape>().ok())
+            .unwrap_or_default()
+    }
+
+    pub fn escape(self, value: &str) -> String {
+        match self {
+            Escape::Json => escape_json(value),
+            Escape::Xml => escape_xml(value),
+            Escape::Csv => escape_csv(value),
+            Escape::Sql => value.replace('\'', "''"),
+            Escape::None => value.to_owned()
+        }
+    }
+}
+
+impl FromStr for Escape {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Escape, String> {
+        match name {
+            "json" => Ok(Escape::Json),
+            "xml" => Ok(Escape::Xml),
+            "csv" => Ok(Escape::Csv),
+            "sql" => Ok(Escape::Sql),
+            "none" => Ok(Escape::None),
+            _ => Err(format!("unknown escaping `{}`, expected one of {}", name, ESCAPE_NAMES.join(", ")))
+        }
+    }
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+fn escape_csv(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_for_each_format() {
+        let value = "O'Neil & \"Sons\" <1>,\n";
+        assert_eq!(Escape::Json.escape(value), "O'Neil & \\\"Sons\\\" <1>,\\n");
+        assert_eq!(Escape::Xml.escape(value), "O&apos;Neil &amp; &quot;Sons&quot; &lt;1&gt;,\n");
+        assert_eq!(Escape::Csv.escape(value), "\"O'Neil & \"\"Sons\"\" <1>,\n\"");
+        assert_eq!(Escape::Csv.escape("plain"), "plain");
+        assert_eq!(Escape::Sql.escape(value), "O''Neil & \"Sons\" <1>,\n");
+        assert_eq!(Escape::None.escape(value), value);
+    }
+
+    #[test]
+    fn detects_escaping_from_extension() {
+        assert_eq!(Escape::for_path(Path::new("templates/order.JSON")), Escape::Json);
+        assert_eq!(Escape::for_path(Path::new("rows.csv")), Escape::Csv);
+        assert_eq!(Escape::for_path(Path::new("example_template.tpl")), Escape::None);
+        assert_eq!(Escape::for_path(Path::new("Makefile")), Escape::None);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::parser::split_entity_id;
+use crate::parser::error::{PlaceholderParseError, SourceLocation};
+use std::ops::Range;
+
+#[derive(Clone,Debug,PartialEq)]
+pub enum Token {
+    Literal(String),
+    /// A `${...}` placeholder. `span` is its byte range in the template, from `$` to `}`.
+    Placeholder { entity_id: Option<String>, body: String, span: Range<usize> }
+}
+
+/// Splits a template into literal text and placeholders.
+///
+/// A placeholder starts at `${` and ends at the first `}` that is neither inside a double quoted
+/// string nor closing a `{` or `[` opened within the placeholder, except that a `${# ...}` comment
+/// ends at its first `}`. `$${` is written out as a literal `${`.
+/// `${- ...}` removes the whitespace, including newlines, before the placeholder, and `${... -}` the whitespace after it.
+pub fn tokenize(template: &str) -> Result<Vec<Token>, PlaceholderParseError> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut literal = String::new();
+    let mut position: usize = 0;
+
+    while let Some(found) = template[position..].find('$') {
+        let start: usize = position + found;
+        literal.push_str(&template[position..start]);
+        let rest: &str = &template[start..];
+
+        if rest.starts_with("$${") {
+            literal.push_str("${");
+            position = start + 3;
+        } else if rest.starts_with("${") {
+            let length: usize = placeholder_length(rest)
+                .ok_or_else(|| PlaceholderParseError::unterminated(rest).at(SourceLocation::of(template, start..start + 2)))?;
+            let (body, trim_before, trim_after): (&str, bool, bool) = trim_markers(&rest[2..length - 1]);
+            if trim_before {
+                literal.truncate(literal.trim_end().len());
+            }
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(std::mem::take(&mut literal)));
+            }
+            tokens.push(placeholder_token(body, start..start + length));
+            position = start + length;
+            if trim_after {
+                position = template.len() - template[position..].trim_start().len();
+            }
+        } else {
+            literal.push('$');
+            position = start + 1;
+        }
+    }
+    literal.push_str(&template[position..]);
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    Ok(tokens)
+}
+
+/// Returns the length of the placeholder at the start of `text`, including `${` and `}`.
+fn placeholder_length(text: &str) -> Option<usize> {
+    if is_comment(&text[2..]) {
+        return text.find('}').map(|end: usize| end + 1);
+    }
+    let mut depth: usize = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (index, c) in text.char_indices().skip(2) {
+        if in_quotes {
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '"') => in_quotes = false,
+                _ => ()
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            '{' | '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            '}' if depth == 0 => return Some(index + 1),
+            '}' => depth -= 1,
+            _ => ()
+        }
+    }
+    None
+}
+
+/// Whether `body`, what follows `${`, is a comment, i.e. a `#` followed by whitespace or nothing,
+/// possibly after a trim marker. Comments are not lexed, and end at the first `}`.
+fn is_comment(body: &str) -> bool {
+    let body: &str = match body.strip_prefix('-') {
+        Some(rest) if rest.starts_with(char::is_whitespace) => rest.trim_start(),
+        _ => body
+    };
+    body.strip_prefix('#').is_some_and(|rest: &str| rest.starts_with(|c: char| c.is_whitespace() || c == '}'))
+}
+
+/// Strips the trim markers from the body of `${- ... -}`. A marker is separated from the rest of the
+/// body by whitespace, so that the dashes of `${= -1}` or `${x | replace:a:-}` are left alone.
+fn trim_markers(body: &str) -> (&str, bool, bool) {
+    let trim_before: bool = body.strip_prefix('-').is_some_and(|rest: &str| rest.starts_with(char::is_whitespace));
+    let body: &str = match trim_before {
+        true => &body[1..],
+        false => body
+    };
+    let trim_after: bool = body.strip_suffix('-').is_some_and(|rest: &str| rest.ends_with(char::is_whitespace));
+    let body: &str = match trim_after {
+        true => &body[..body.len() - 1],
+        false => body
+    };
+    match trim_before || trim_after {
+        true => (body.trim(), trim_before, trim_after),
+        false => (body, false, false)
+    }
+}
+
+fn placeholder_token(body: &str, span: Range<usize>) -> Token {
+    let (entity_id, body): (Option<String>, &str) = split_entity_id(body);
+    Token::Placeholder { entity_id, body: body.to_owned(), span }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholder(entity_id: Option<&str>, body: &str, span: Range<usize>) -> Token {
+        Token::Placeholder { entity_id: entity_id.map(|id| id.to_owned()), body: body.to_owned(), span }
+    }
+
+    #[test]
+    fn placeholder_with_entity_id() {
+        assert_eq!(tokenize("${<id>test}").unwrap(), vec![placeholder(Some("id"), "test", 0..11)]);
+    }
+
+    #[test]
+    fn placeholder_without_entity_id() {
+        assert_eq!(tokenize("a ${test} b").unwrap(), vec![
+            Token::Literal("a ".to_owned()),
+            placeholder(None, "test", 2..9),
+            Token::Literal(" b".to_owned())
+        ]);
+    }
+
+    #[test]
+    fn escaped_placeholder_is_literal() {
+        assert_eq!(tokenize("echo $${HOME} $5 ${guid}").unwrap(), vec![
+            Token::Literal("echo ${HOME} $5 ".to_owned()),
+            placeholder(None, "guid", 17..24)
+        ]);
+    }
+
+    #[test]
+    fn braces_inside_quotes_and_nesting() {
+        assert_eq!(tokenize(r#"${set:options=["a;b","c}d"]}"#).unwrap(), vec![placeholder(None, r#"set:options=["a;b","c}d"]"#, 0..28)]);
+        assert_eq!(tokenize("${set:options=[{a},{b}]}!").unwrap(), vec![
+            placeholder(None, "set:options=[{a},{b}]", 0..24),
+            Token::Literal("!".to_owned())
+        ]);
+    }
+
+    #[test]
+    fn unterminated_placeholder() {
+        let err = tokenize("ok\n  ${name::first").unwrap_err();
+        assert_eq!(err.location.map(|location| (location.line, location.column)), Some((2, 3)));
+        assert!(tokenize(r#"${set:options=["a}]}"#).is_err());
+    }
+
+    #[test]
+    fn comments_end_at_the_first_brace() {
+        assert_eq!(tokenize(r#"a ${# the "name field } b"#).unwrap(), vec![
+            Token::Literal("a ".to_owned()),
+            placeholder(None, r#"# the "name field "#, 2..23),
+            Token::Literal(" b".to_owned())
+        ]);
+        assert_eq!(tokenize("${# see {x }b}").unwrap(), vec![
+            placeholder(None, "# see {x ", 0..12),
+            Token::Literal("b}".to_owned())
+        ]);
+        assert_eq!(tokenize("a\n  ${- # note [ -}\n b ${#}").unwrap(), vec![
+            Token::Literal("a".to_owned()),
+            placeholder(None, "# note [", 4..19),
+            Token::Literal("b ".to_owned()),
+            placeholder(None, "#", 23..27)
+        ]);
+    }
+
+    #[test]
+    fn trim_markers_remove_whitespace() {
+        assert_eq!(tokenize("[\n  ${- guid -}\n]").unwrap(), vec![
+            Token::Literal("[".to_owned()),
+            placeholder(None, "guid", 4..15),
+            Token::Literal("]".to_owned())
+        ]);
+        assert_eq!(tokenize("a ${= -1} ${x | replace:a:-} b").unwrap(), vec![
+            Token::Literal("a ".to_owned()),
+            placeholder(None, "= -1", 2..9),
+            Token::Literal(" ".to_owned()),
+            placeholder(None, "x | replace:a:-", 10..28),
+            Token::Literal(" b".to_owned())
+        ]);
+    }
+}
@@ -0,0 +1,680 @@
+mod lexer;
+mod compiler;
+mod context;
+mod weighted;
+mod escape;
+mod vars;
+
+pub use context::RenderContext;
+pub use escape::{Escape, ESCAPE_NAMES};
+pub use vars::{parse_var, parse_vars};
+pub use weighted::WeightedTemplates;
+
+use crate::parser::Placeholder;
+use crate::parser::error::{PlaceholderParseError, SourceLocation};
+use crate::parser::expression::{Expression, Value};
+use crate::parser::filter::Filter;
+use crate::parser::registry::{self, PlaceholderSpec};
+use crate::entity::Entity;
+use crate::rng;
+use crate::types::{BlockType, PlaceholderType};
+
+use rand::Rng;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone,Debug)]
+pub(crate) enum Segment {
+    Literal(String),
+    /// A generated value. `quoted` placeholders were written between double quotes, which are only
+    /// rendered around a value and left out around the null token, so that JSON gets a real `null`.
+    Placeholder { entity_id: Option<String>, placeholder: Placeholder, location: SourceLocation, quoted: bool },
+    Document(Placeholder),
+    /// A `${#name}...${/name}` section. `local_entities` are the entities only used inside `body`, and
+    /// `local_variables` the variables defined inside it, which all start over each time the body is rendered.
+    Block { block: BlockType, body: Vec<Segment>, local_entities: Vec<String>, local_variables: Vec<String> },
+    /// A `${let name = value}` definition, which renders nothing.
+    Let { name: String, value: Box<Segment> },
+    /// A `${$name}` reference to a variable.
+    Variable { name: String, quoted: bool },
+    /// A `${= ...}` expression, which renders the null token if any of its operands is null.
+    Expression { expression: Expression, location: SourceLocation },
+    /// A value followed by `| filter`s, which are applied to it in order.
+    Filtered { value: Box<Segment>, filters: Vec<Filter>, location: SourceLocation }
+}
+
+/// A template that has been split into literal text and pre-parsed placeholders,
+/// so that rendering a document never has to touch the lexer or argument parsers.
+#[derive(Clone,Debug)]
+pub struct Template {
+    segments: Vec<Segment>,
+    /// The value of each variable, by name. Variables are evaluated when first referenced,
+    /// so they can be used before the text that defines them.
+    variables: HashMap<String, Segment>,
+    escape: Escape,
+    /// The most values of each sequence, by name, that one document can take.
+    sequence_strides: HashMap<String, u64>
+}
+
+/// Where a template was read from, which names it in errors and is where its `${include:...}`s are looked up.
+#[derive(Clone,Debug,Default)]
+pub struct CompileOptions {
+    /// The name errors in the template are reported under, usually its path.
+    pub name: Option<String>,
+    /// The file the template was read from. Includes are relative to its directory, or to the
+    /// working directory for templates that were not read from a file.
+    pub path: Option<PathBuf>,
+    /// How generated values are escaped, by default according to the extension of the file.
+    pub escape: Escape,
+    /// Values for `${var::name}`, by name.
+    pub vars: HashMap<String, String>,
+    /// Whether `${env::NAME}` may read environment variables.
+    pub allow_env: bool
+}
+
+impl CompileOptions {
+    pub fn for_file<P: AsRef<Path>>(path: P) -> CompileOptions {
+        let path: &Path = path.as_ref();
+        CompileOptions { name: Some(path.display().to_string()), path: Some(path.to_path_buf()), escape: Escape::for_path(path), ..CompileOptions::default() }
+    }
+
+    /// Options for a template that was not read from a file, reported under `name`.
+    pub fn named(name: &str) -> CompileOptions {
+        CompileOptions { name: Some(name.to_owned()), ..CompileOptions::default() }
+    }
+}
+
+impl Template {
+    pub fn compile(template: &str) -> Result<Template, Vec<PlaceholderParseError>> {
+        Template::compile_with(template, &CompileOptions::default())
+    }
+
+    pub fn compile_with(template: &str, options: &CompileOptions) -> Result<Template, Vec<PlaceholderParseError>> {
+        compiler::compile(template, options)
+    }
+
+    /// Checks that every compiled placeholder can actually be generated with the arguments it was given.
+    /// Expressions and filters are evaluated once, to check that their operands are numbers where numbers are expected.
+    pub fn validate(&self) -> Vec<PlaceholderParseError> {
+        let mut errors: Vec<PlaceholderParseError> = Vec::new();
+        let invalid = |placeholder: &Placeholder, location: &SourceLocation| {
+            PlaceholderParseError::invalid_arg(&placeholder.to_string(), "invalid arguments for placeholder type".to_owned()).at(location.clone())
+        };
+        visit(&self.segments, &mut |segment: &Segment| match segment {
+            Segment::Placeholder { placeholder, location, .. } if Entity::validate(placeholder).is_none() => {
+                errors.push(invalid(placeholder, location));
+            },
+            Segment::Expression { expression, location } => {
+                let mut valid = true;
+                expression.visit_operands(&mut |operand: &Expression| {
+                    if let Expression::Placeholder { placeholder, .. } = operand {
+                        if !matches!(placeholder.data_type, PlaceholderType::Doc(_) | PlaceholderType::External(_)) && Entity::validate(placeholder).is_none() {
+                            errors.push(invalid(placeholder, location));
+                            valid = false;
+                        }
+                    }
+                });
+                if valid {
+                    if let Err(reason) = self.expression_value(expression, &mut RenderContext::new(0), &mut rng::validation_rng()) {
+                        errors.push(PlaceholderParseError::invalid_arg("=", reason).at(location.clone()));
+                    }
+                }
+            },
+            _ => ()
+        });
+        if !errors.is_empty() {
+            return errors;
+        }
+        visit(&self.segments, &mut |segment: &Segment| {
+            if let Segment::Filtered { value, filters, location } = segment {
+                if let Some(Err(reason)) = self.value_of(value, &mut RenderContext::new(0), &mut rng::validation_rng()).map(|value: String| apply(filters, value)) {
+                    errors.push(PlaceholderParseError::invalid_arg("|", reason).at(location.clone()));
+                }
+            }
+        });
+        errors
+    }
+
+    /// The most values of each sequence, by name, that one document of the template can take,
+    /// counting each use inside a `${#repeat}` block as many times as the block can repeat.
+    pub fn sequence_strides(&self) -> &HashMap<String, u64> {
+        &self.sequence_strides
+    }
+
+    /// The `docgen types` entry of each type of placeholder in the template, once per type.
+    pub fn describe(&self) -> String {
+        let mut described: Vec<&PlaceholderSpec> = Vec::new();
+        for placeholder in self.placeholders() {
+            let spec: &PlaceholderSpec = registry::for_type(&placeholder.data_type);
+            if !described.iter().any(|other: &&PlaceholderSpec| other.name == spec.name) {
+                described.push(spec);
+            }
+        }
+        described.iter().map(|spec: &&PlaceholderSpec| spec.to_string()).collect::<Vec<String>>().join("\n\n")
+    }
+
+    /// Every placeholder in the template, in the order they appear.
+    pub(crate) fn placeholders(&self) -> impl Iterator<Item = &Placeholder> {
+        let mut placeholders: Vec<&Placeholder> = Vec::new();
+        visit(&self.segments, &mut |segment| match segment {
+            Segment::Placeholder { placeholder, .. } | Segment::Document(placeholder) => placeholders.push(placeholder),
+            Segment::Expression { expression, .. } => expression.visit_operands(&mut |operand: &Expression| {
+                if let Expression::Placeholder { placeholder, .. } = operand {
+                    placeholders.push(placeholder);
+                }
+            }),
+            _ => ()
+        });
+        placeholders.into_iter()
+    }
+
+    /// Renders a single document.
+    pub fn render<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.render_with(&mut RenderContext::new(0), rng)
+    }
+
+    /// Renders into an existing context, so that several templates can share the
+    /// document index and entities of one document.
+    pub fn render_with<R: Rng + ?Sized>(&self, context: &mut RenderContext, rng: &mut R) -> String {
+        context.reserve_sequences(&self.sequence_strides);
+        let mut document = String::new();
+        self.render_segments(&self.segments, context, rng, &mut document);
+        document
+    }
+
+    fn render_segments<R: Rng + ?Sized>(&self, segments: &[Segment], context: &mut RenderContext, rng: &mut R, document: &mut String) {
+        for segment in segments {
+            match segment {
+                Segment::Literal(text) => document.push_str(text),
+                Segment::Let { .. } => (),
+                Segment::Block { block: BlockType::Repeat { min, max, separator }, body, local_entities, local_variables } => {
+                    let repetitions: u64 = rng.gen_range(*min, *max + 1);
+                    for repetition in 0..repetitions {
+                        if repetition > 0 {
+                            document.push_str(separator);
+                        }
+                        context.forget(local_entities, local_variables);
+                        self.render_segments(body, context, rng, document);
+                    }
+                },
+                Segment::Block { block: BlockType::Maybe { probability }, body, local_entities, local_variables } => {
+                    if rng.gen_bool(*probability) {
+                        context.forget(local_entities, local_variables);
+                        self.render_segments(body, context, rng, document);
+                    }
+                },
+                segment => match (self.value_of(segment, context, rng), is_quoted(segment)) {
+                    (Some(value), quoted) => {
+                        let value: String = match is_raw(segment) {
+                            true => value,
+                            false => self.escape.escape(&value)
+                        };
+                        match quoted {
+                            true => {
+                                document.push('"');
+                                document.push_str(&value);
+                                document.push('"');
+                            },
+                            false => document.push_str(&value)
+                        }
+                    },
+                    (None, _) => document.push_str(&context.null_token)
+                }
+            }
+        }
+    }
+
+    /// The value of a placeholder, variable or expression, or `None` where a `null=` modifier chose null
+    /// or a sequence ran out of values.
+    fn value_of<R: Rng + ?Sized>(&self, segment: &Segment, context: &mut RenderContext, rng: &mut R) -> Option<String> {
+        match segment {
+            Segment::Placeholder { entity_id, placeholder, .. } => placeholder_value(entity_id.as_deref(), placeholder, context, rng),
+            Segment::Document(placeholder) => context.value_of(placeholder),
+            Segment::Variable { name, .. } => self.variable_value(name, context, rng),
+            // Expressions are validated when the template is compiled, so an operand can only fail to be
+            // a number when its placeholder sometimes generates something else, which renders as null.
+            Segment::Expression { expression, .. } => self.expression_value(expression, context, rng).unwrap_or(None),
+            // The same goes for number filters.
+            Segment::Filtered { value, filters, .. } => self.value_of(value, context, rng)
+                .and_then(|value: String| apply(filters, value).ok()),
+            segment => unreachable!("{:?} does not have a value", segment)
+        }
+    }
+
+    /// Variables are evaluated when first referenced in a document, and keep that value for the rest of it.
+    fn variable_value<R: Rng + ?Sized>(&self, name: &str, context: &mut RenderContext, rng: &mut R) -> Option<String> {
+        if let Some(value) = context.variable(name) {
+            return value.clone();
+        }
+        let value: Option<String> = self.value_of(&self.variables[name], context, rng);
+        context.set_variable(name, value.clone());
+        value
+    }
+
+    /// An expression that is only a variable or placeholder renders its value unchanged, even when it is not a number.
+    fn expression_value<R: Rng + ?Sized>(&self, expression: &Expression, context: &mut RenderContext, rng: &mut R) -> Result<Option<String>, String> {
+        match expression {
+            Expression::Variable(name) => Ok(self.variable_value(name, context, rng)),
+            Expression::Placeholder { entity_id, placeholder } => Ok(placeholder_value(entity_id.as_deref(), placeholder, context, rng)),
+            expression => self.evaluate(expression, context, rng).map(|value: Value| match value {
+                Value::Null => None,
+                value => Some(value.to_string())
+            })
+        }
+    }
+
+    fn evaluate<R: Rng + ?Sized>(&self, expression: &Expression, context: &mut RenderContext, rng: &mut R) -> Result<Value, String> {
+        expression.evaluate(&mut |operand: &Expression| {
+            let value: Option<String> = match operand {
+                Expression::Variable(name) => self.variable_value(name, context, rng),
+                Expression::Placeholder { entity_id, placeholder } => placeholder_value(entity_id.as_deref(), placeholder, context, rng),
+                operand => unreachable!("{:?} is not an operand", operand)
+            };
+            match value {
+                Some(text) => Value::parse(&text),
+                None => Ok(Value::Null)
+            }
+        })
+    }
+}
+
+fn apply(filters: &[Filter], value: String) -> Result<String, String> {
+    filters.iter().try_fold(value, |value: String, filter: &Filter| filter.apply(&value))
+}
+
+fn is_quoted(segment: &Segment) -> bool {
+    match segment {
+        Segment::Placeholder { quoted, .. } | Segment::Variable { quoted, .. } => *quoted,
+        Segment::Filtered { value, .. } => is_quoted(value),
+        _ => false
+    }
+}
+
+fn is_raw(segment: &Segment) -> bool {
+    match segment {
+        Segment::Filtered { filters, .. } => filters.contains(&Filter::Raw),
+        _ => false
+    }
+}
+
+fn placeholder_value<R: Rng + ?Sized>(entity_id: Option<&str>, placeholder: &Placeholder, context: &mut RenderContext, rng: &mut R) -> Option<String> {
+    match (entity_id, &placeholder.data_type) {
+        (_, PlaceholderType::Doc(_)) | (_, PlaceholderType::External(_)) => context.value_of(placeholder),
+        (Some(id), _) => context.entity(id).value_of(placeholder, rng),
+        (None, _) => Entity::new().value_of(placeholder, rng)
+    }
+}
+
+/// Calls `f` with every segment, descending into the bodies of blocks and the values of variables.
+fn visit<'a, F: FnMut(&'a Segment)>(segments: &'a [Segment], f: &mut F) {
+    for segment in segments {
+        f(segment);
+        match segment {
+            Segment::Block { body, .. } => visit(body, f),
+            Segment::Let { value, .. } | Segment::Filtered { value, .. } => visit(std::slice::from_ref(value), f),
+            _ => ()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+
+    #[test]
+    fn compile_splits_literals_and_placeholders() {
+        let template = Template::compile("a ${guid} b ${<1>name::first}").unwrap();
+        assert_eq!(template.segments.len(), 4);
+        match &template.segments[3] {
+            Segment::Placeholder { entity_id, .. } => assert_eq!(entity_id.as_deref(), Some("1")),
+            segment => panic!("Expected placeholder segment. Actual: {:?}", segment)
+        }
+    }
+
+    #[test]
+    fn compile_keeps_escaped_placeholders() {
+        let template = Template::compile("echo $${HOME} ${set:options=[\"}\"]}").unwrap();
+        assert_eq!(template.render(&mut rand::thread_rng()), "echo ${HOME} }");
+    }
+
+    #[test]
+    fn compile_collects_errors() {
+        let errors = Template::compile("${unsupported} ${float:1} ${guid}").unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn compile_errors_are_located() {
+        let errors = Template::compile("{\n  \"age\": ${int:min=0;mx=5}\n}").unwrap_err();
+        assert_eq!(errors[0].reason, "unknown arg `mx`");
+        let location = errors[0].location.clone().unwrap();
+        assert_eq!((location.line, location.column, location.length), (2, 10, 17));
+    }
+
+    #[test]
+    fn render_is_reproducible_with_same_rng() {
+        let template = Template::compile("${guid} ${name::full} ${location::address} ${phone} ${dist::normal:mean=0;stddev=1}").unwrap();
+        assert_eq!(template.render(&mut rng::document_rng(7, 3)), template.render(&mut rng::document_rng(7, 3)));
+    }
+
+    #[test]
+    fn render_with_shares_context_between_templates() {
+        let body = Template::compile("${<1>name::last}").unwrap();
+        let filename = Template::compile("user-${doc::index:pad=4}-${<1>name::last}.json").unwrap();
+        let rng = &mut rand::thread_rng();
+        let context = &mut RenderContext::new(12);
+        let last_name = body.render_with(context, rng);
+        assert_eq!(filename.render_with(context, rng), format!("user-0012-{}.json", last_name));
+    }
+
+    #[test]
+    fn render_shares_entity_values() {
+        let template = Template::compile("${<1>name::first}|${<1>name::first}").unwrap();
+        let document = template.render(&mut rand::thread_rng());
+        let parts: Vec<&str> = document.split('|').collect();
+        assert_eq!(parts[0], parts[1]);
+    }
+
+    #[test]
+    fn emails_belong_to_the_entity_name() {
+        let template = Template::compile("${<1>name::first}|${<1>name::last}|${<1>email:domains=[acme.example]}|${<1>username:pattern={f}{last}{nn};case=keep}|${<1>email:pattern={l}{nn}}").unwrap();
+        let document = template.render(&mut rng::document_rng(3, 0));
+        let parts: Vec<&str> = document.split('|').collect();
+        let (first, last): (String, String) = (parts[0].replace(['-', ' '], ""), parts[1].replace(['-', ' '], ""));
+        let email: &str = parts[2].strip_suffix("@acme.example").unwrap();
+        assert!(email.starts_with(&format!("{}.{}", first, last).to_lowercase()), "{}", document);
+        let number: &str = &email[email.len() - 2..];
+        assert_eq!(parts[3], format!("{}{}{}", &first[..1], last, number));
+        assert!(parts[4].starts_with(&format!("{}{}@", last[..1].to_lowercase(), number)), "{}", document);
+    }
+
+    #[test]
+    fn clients_keep_their_network_values() {
+        let template = Template::compile("${<client>net::ipv4:cidr=10.0.0.0/8}|${<client>net::ipv4:cidr=10.0.0.0/8}|${<client>net::hostname}|${<client>net::url:scheme=http}|${<client>net::user_agent}|${<client>net::user_agent}").unwrap();
+        let document = template.render(&mut rng::document_rng(5, 0));
+        let parts: Vec<&str> = document.split('|').collect();
+        assert_eq!(parts[0], parts[1]);
+        assert!(parts[0].starts_with("10."), "{}", document);
+        assert!(parts[3].starts_with(&format!("http://{}/", parts[2])), "{}", document);
+        assert_eq!(parts[4], parts[5]);
+    }
+
+    #[test]
+    fn repeat_renders_between_min_and_max_times() {
+        let template = Template::compile(r#"[${#repeat min=2;max=4;sep=","}${int:min=0;max=9}${/repeat}]"#).unwrap();
+        for seed in 0..20 {
+            let document = template.render(&mut rng::document_rng(seed, 0));
+            let items: Vec<&str> = document.trim_start_matches('[').trim_end_matches(']').split(',').collect();
+            assert!(items.len() >= 2 && items.len() <= 4, "{}", document);
+            assert!(items.iter().all(|item: &&str| item.len() == 1), "{}", document);
+        }
+    }
+
+    #[test]
+    fn repeat_gives_each_iteration_its_own_local_entities() {
+        let template = Template::compile("${<1>guid}${#repeat min=5;max=5;sep=\"|\"}${<1>guid} ${<item>guid} ${<item>guid}${/repeat}").unwrap();
+        let document = template.render(&mut rng::document_rng(1, 0));
+        let (customer, lines): (&str, &str) = document.split_at(36);
+        let lines: Vec<Vec<&str>> = lines.split('|').map(|line: &str| line.split(' ').collect()).collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines.iter().all(|line: &Vec<&str>| line[0] == customer && line[1] == line[2]));
+        assert_ne!(lines[0][1], lines[1][1]);
+    }
+
+    #[test]
+    fn sibling_blocks_each_get_their_own_entities() {
+        let template = Template::compile("${#repeat min=3;max=3}${<item>guid} ${/repeat}|${#repeat min=2;max=2}${<item>guid} ${/repeat}|${#maybe p=1}${<item>guid}${/maybe}").unwrap();
+        let document = template.render(&mut rng::document_rng(1, 0));
+        let mut guids: Vec<&str> = document.split(['|', ' ']).filter(|guid: &&str| !guid.is_empty()).collect();
+        assert_eq!(guids.len(), 6);
+        guids.sort();
+        guids.dedup();
+        assert_eq!(guids.len(), 6, "{}", document);
+
+        let template = Template::compile("${#repeat min=2;max=2;sep=\"|\"}${<order>guid}${#repeat min=2;max=2}/${<order>guid}${/repeat}${/repeat}").unwrap();
+        let document = template.render(&mut rng::document_rng(1, 0));
+        let orders: Vec<Vec<&str>> = document.split('|').map(|order: &str| order.split('/').collect()).collect();
+        assert!(orders.iter().all(|order: &Vec<&str>| order.iter().all(|guid: &&str| *guid == order[0])), "{}", document);
+        assert_ne!(orders[0][0], orders[1][0]);
+    }
+
+    #[test]
+    fn maybe_renders_section_with_probability() {
+        let template = Template::compile("a${#maybe p=0.5}b${/maybe}").unwrap();
+        let documents: Vec<String> = (0..100).map(|index: u64| template.render(&mut rng::document_rng(3, index))).collect();
+        assert!(documents.iter().all(|document: &String| document == "a" || document == "ab"));
+        assert!(documents.iter().any(|document: &String| document == "a"));
+        assert!(documents.iter().any(|document: &String| document == "ab"));
+        assert_eq!(Template::compile("a${#maybe p=0}b${/maybe}").unwrap().render(&mut rand::thread_rng()), "a");
+    }
+
+    #[test]
+    fn null_modifier_unquotes_null_token() {
+        let template = Template::compile(r#"{"a": "${int:min=1;max=2;null=1}", "b": "${int:min=1;max=2;null=0}"}"#).unwrap();
+        assert_eq!(template.render(&mut rand::thread_rng()), r#"{"a": null, "b": "1"}"#);
+        let context = &mut RenderContext::new(0);
+        context.null_token = "NULL".to_owned();
+        assert_eq!(Template::compile("(${guid:null=1})").unwrap().render_with(context, &mut rand::thread_rng()), "(NULL)");
+    }
+
+    #[test]
+    fn null_choice_is_shared_by_entity() {
+        let template = Template::compile("${<1>phone:null=0.5}|${<1>phone:null=0.5}").unwrap();
+        for index in 0..20 {
+            let document = template.render(&mut rng::document_rng(9, index));
+            let parts: Vec<&str> = document.split('|').collect();
+            assert_eq!(parts[0], parts[1]);
+        }
+    }
+
+    #[test]
+    fn variables_are_evaluated_once_per_document() {
+        let template = Template::compile("${$total}|${let total = float:min=10;max=500}${$total}").unwrap();
+        for index in 0..10 {
+            let document = template.render(&mut rng::document_rng(4, index));
+            let parts: Vec<&str> = document.split('|').collect();
+            assert_eq!(parts[0], parts[1]);
+            assert!(parts[0].parse::<f64>().is_ok(), "{}", document);
+        }
+    }
+
+    #[test]
+    fn variables_can_belong_to_entities() {
+        let template = Template::compile("${let first = <1>name::first}${$first}|${<1>name::first}").unwrap();
+        let document = template.render(&mut rand::thread_rng());
+        let parts: Vec<&str> = document.split('|').collect();
+        assert_eq!(parts[0], parts[1]);
+    }
+
+    #[test]
+    fn variables_defined_in_blocks_start_over_each_repetition() {
+        let template = Template::compile("${#repeat min=5;max=5;sep=\",\"}${let id = guid}${$id}=${$id}${/repeat}").unwrap();
+        let document = template.render(&mut rand::thread_rng());
+        let ids: Vec<&str> = document.split(',').map(|pair: &str| pair.split('=').next().unwrap()).collect();
+        assert!(document.split(',').all(|pair: &str| pair[..36] == pair[37..]));
+        assert_ne!(ids[0], ids[1]);
+    }
+
+    #[test]
+    fn variable_errors() {
+        let errors = Template::compile("${$missing} ${let a = guid}${let a = guid}").unwrap_err();
+        assert_eq!(errors[0].reason, "variable `$a` is defined more than once");
+        assert_eq!(errors[1].reason, "undefined variable `$missing`");
+        assert_eq!(errors[1].location.as_ref().map(|location| location.column), Some(1));
+    }
+
+    #[test]
+    fn expressions_use_variables_defined_later() {
+        let template = Template::compile("${= $price * $qty}|${let price = int:min=1;max=100}${let qty = int:min=1;max=10}${$price}|${$qty}").unwrap();
+        for index in 0..10 {
+            let document = template.render(&mut rng::document_rng(6, index));
+            let parts: Vec<i64> = document.split('|').map(|part: &str| part.parse::<i64>().unwrap()).collect();
+            assert_eq!(parts[0], parts[1] * parts[2]);
+        }
+    }
+
+    #[test]
+    fn expressions_can_be_bound_to_variables() {
+        let template = Template::compile("${let start = <1>int:min=0;max=1000}${let end = $start + {<1>int:min=1;max=5}}${= $end > $start} ${= {doc::index} + 1}").unwrap();
+        assert_eq!(template.render_with(&mut RenderContext::new(41), &mut rand::thread_rng()), "true 42");
+    }
+
+    #[test]
+    fn expression_errors() {
+        let errors = Template::compile("${let a = $b + 1}${let b = $a * 2}${= $a}").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].reason, "variables depend on each other: `$a` -> `$b` -> `$a`");
+        let errors = Template::compile("${= {name::first} * 2} ${= 1 +}").unwrap_err();
+        assert_eq!(errors[0].reason, "expression ends where a value was expected");
+        assert!(errors[1].reason.ends_with("is not a number"), "{}", errors[1].reason);
+        assert_eq!(errors[1].location.as_ref().map(|location| location.column), Some(1));
+    }
+
+    #[test]
+    fn filters_apply_to_entity_values() {
+        let template = Template::compile("${<1>name::last | upper}|${<1>name::last}|${int:min=5;max=6 | pad:3}|${doc::index | pad:4}").unwrap();
+        let document = template.render_with(&mut RenderContext::new(7), &mut rand::thread_rng());
+        let parts: Vec<&str> = document.split('|').collect();
+        assert_eq!(parts[0], parts[1].to_uppercase());
+        assert_eq!(&parts[2..], &["005", "0007"]);
+    }
+
+    #[test]
+    fn filters_apply_to_variables_and_expressions() {
+        let template = Template::compile("${let city = location::place | slug}${$city | upper}|${= 10 / 4 | round:2}|${= 1 > 2 || 2 > 1 | upper}").unwrap();
+        let document = template.render(&mut rand::thread_rng());
+        let parts: Vec<&str> = document.split('|').collect();
+        assert!(parts[0].chars().all(|c: char| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-'), "{}", parts[0]);
+        assert_eq!(&parts[1..], &["2.50", "TRUE"]);
+    }
+
+    #[test]
+    fn filters_are_validated() {
+        let errors = Template::compile("${name::first | shout} ${name::first | round:2}").unwrap_err();
+        assert_eq!(errors[0].reason, "unknown filter `shout`");
+        let errors = Template::compile("${name::first | round:2}").unwrap_err();
+        assert!(errors[0].reason.ends_with("is not a number"), "{}", errors[0].reason);
+    }
+
+    #[test]
+    fn unbalanced_blocks_are_errors() {
+        let errors = Template::compile("${#repeat max=2}\n${/maybe}${/repeat}").unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].reason, "`${/maybe}` closes the `repeat` block opened on line 1");
+        assert_eq!(errors[1].reason, "`${/repeat}` does not close any block");
+        let errors = Template::compile("a\n${#repeat max=2}${guid}").unwrap_err();
+        assert_eq!(errors[0].reason, "`repeat` block is never closed with `${/repeat}`");
+        assert_eq!(errors[0].location.as_ref().map(|location| location.line), Some(2));
+    }
+
+    #[test]
+    fn includes_rename_entity_one() {
+        let directory: PathBuf = std::env::temp_dir().join(format!("docgen-include-{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("partials")).unwrap();
+        std::fs::write(directory.join("partials/person.txt"), "${<1>name::first} ${= {<1>int:min=1;max=9} * 1}").unwrap();
+        std::fs::write(directory.join("main.txt"), "").unwrap();
+        let options = CompileOptions::for_file(directory.join("main.txt"));
+        let template = Template::compile_with("${<2>name::first} ${<2>int:min=1;max=9}|${include:partials/person.txt;entity=2}", &options).unwrap();
+        let document = template.render(&mut rand::thread_rng());
+        let (outer, included) = document.split_once('|').unwrap();
+        assert_eq!(included, outer);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn include_errors_report_chain_and_cycles() {
+        let directory: PathBuf = std::env::temp_dir().join(format!("docgen-include-errors-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("a.txt"), "${include:b.txt}").unwrap();
+        std::fs::write(directory.join("b.txt"), "\n${nme}${include:a.txt}${#maybe}").unwrap();
+        let main: PathBuf = directory.join("a.txt");
+        let errors = Template::compile_with("${include:b.txt}", &CompileOptions::for_file(&main)).unwrap_err();
+        assert_eq!(errors.len(), 3);
+
+        let location = errors[0].location.as_ref().unwrap();
+        assert_eq!(errors[0].reason, "unknown placeholder type `nme`");
+        assert_eq!(location.file, Some(directory.join("b.txt").display().to_string()));
+        assert_eq!((location.line, location.column), (2, 1));
+        assert_eq!(location.included_from, vec![format!("{}:1:1", main.display())]);
+        assert_eq!(errors[1].reason, format!("templates include each other: {} -> {} -> {}",
+            main.display(), directory.join("b.txt").display(), directory.join("a.txt").display()));
+        assert_eq!(errors[2].reason, "`maybe` block is never closed with `${/maybe}`");
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn values_are_escaped_unless_raw() {
+        let options = CompileOptions { escape: Escape::Json, ..CompileOptions::default() };
+        let template = Template::compile_with(r#"{"a": "${set:options=["say \"hi\""]}", "b": "${set:options=["x"];null=1}", "c": "${set:options=["\\n"] | raw}"}"#, &options).unwrap();
+        assert_eq!(template.render(&mut rand::thread_rng()), r#"{"a": "say \"hi\"", "b": null, "c": "\n"}"#);
+    }
+
+    #[test]
+    fn sequences_continue_across_documents() {
+        let template = Template::compile("${seq:start=1000;step=5} ${seq:name=id} ${seq:name=id}${#repeat max=2},${= {seq:name=id} * 1}${/repeat} ${doc::count}").unwrap();
+        assert_eq!(template.sequence_strides().get("id"), Some(&4));
+        let documents: Vec<String> = (0..3)
+            .map(|index: u64| {
+                let context = &mut RenderContext::new(index);
+                context.count = 3;
+                template.render_with(context, &mut rng::document_rng(1, index))
+            })
+            .collect();
+        for (index, document) in documents.iter().enumerate() {
+            let values: Vec<&str> = document.split([' ', ',']).collect();
+            assert_eq!(values[0], (1000 + 5 * index).to_string());
+            assert_eq!(values[1], (1 + 4 * index).to_string());
+            assert_eq!(values[2], (2 + 4 * index).to_string());
+            assert_eq!(values.last(), Some(&"3"));
+        }
+    }
+
+    #[test]
+    fn sequences_skip_the_values_a_repeat_did_not_use() {
+        let template = Template::compile("${#repeat min=1;max=3;sep=\",\"}${seq:name=line}${/repeat}").unwrap();
+        assert_eq!(template.sequence_strides().get("line"), Some(&3));
+        for index in 0..20 {
+            let document: String = template.render_with(&mut RenderContext::new(index), &mut rng::document_rng(2, index));
+            let expected: Vec<String> = (0..document.split(',').count() as u64).map(|draw: u64| (1 + 3 * index + draw).to_string()).collect();
+            assert_eq!(document, expected.join(","));
+        }
+    }
+
+    #[test]
+    fn sequences_past_the_largest_integer_render_null() {
+        let template = Template::compile("${seq:start=9223372036854775806} ${seq:step=-4611686018427387904;name=down}").unwrap();
+        let render = |index: u64| template.render_with(&mut RenderContext::new(index), &mut rng::document_rng(1, index));
+        assert_eq!(render(1), "9223372036854775807 -4611686018427387903");
+        assert_eq!(render(2), "null -9223372036854775807");
+        assert_eq!(render(3), "null null");
+        assert_eq!(render(u64::MAX), "null null");
+    }
+
+    #[test]
+    fn named_sequences_agree_on_start_and_step() {
+        let errors = Template::compile("${seq:name=id;start=5} ${seq:name=id}").unwrap_err();
+        assert_eq!(errors[0].reason, "sequence `id` is used with a different start or step elsewhere");
+    }
+
+    #[test]
+    fn comments_and_trim_markers_render_nothing() {
+        let template = Template::compile("${# ids of the order }[\n    ${- #repeat min=2;max=2;sep=\",\" -}\n    ${seq}\n    ${- /repeat -}\n]${#}").unwrap();
+        assert_eq!(template.render(&mut rand::thread_rng()), "[1,2]");
+    }
+
+    #[test]
+    fn external_variables_are_given_at_compile_time() {
+        let mut vars: HashMap<String, String> = HashMap::new();
+        vars.insert("region".to_owned(), "ap-southeast-2".to_owned());
+        vars.insert("shards".to_owned(), "3".to_owned());
+        let options = CompileOptions { vars, ..CompileOptions::default() };
+        let template = Template::compile_with("${var::region | upper} ${= {var::shards} + 1}", &options).unwrap();
+        assert_eq!(template.render(&mut rand::thread_rng()), "AP-SOUTHEAST-2 4");
+
+        let errors = Template::compile_with("${var::tenant} ${env::HOME}", &options).unwrap_err();
+        assert_eq!(errors[0].reason, "undefined variable `var::tenant`, set it with --var tenant=... or --vars-file");
+        assert_eq!(errors[1].reason, "`env::HOME` reads the environment, which is only allowed with --allow-env");
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use toml::Value;
+
+/// Parses a `--var name=value` assignment.
+pub fn parse_var(assignment: &str) -> Result<(String, String), String> {
+    match assignment.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_owned(), value.to_owned())),
+        _ => Err(format!("malformed variable `{}`, expected `name=value`", assignment))
+    }
+}
+
+/// Parses the variables of a TOML file. Values in tables are named by their dotted path,
+/// so `host` in `[db]` is `${var::db.host}`.
+pub fn parse_vars(text: &str) -> Result<HashMap<String, String>, String> {
+    let table: toml::Table = text.parse::<toml::Table>().map_err(|err: toml::de::Error| err.message().to_owned())?;
+    let mut vars: HashMap<String, String> = HashMap::new();
+    flatten("", &table, &mut vars)?;
+    Ok(vars)
+}
+
+fn flatten(prefix: &str, table: &toml::Table, vars: &mut HashMap<String, String>) -> Result<(), String> {
+    for (key, value) in table {
+        let name: String = format!("{}{}", prefix, key);
+        let text: String = match value {
+            Value::String(text) => text.clone(),
+            Value::Integer(number) => number.to_string(),
+            Value::Float(number) => number.to_string(),
+            Value::Boolean(flag) => flag.to_string(),
+            Value::Datetime(datetime) => datetime.to_string(),
+            Value::Table(table) => {
+                flatten(&format!("{}.", name), table, vars)?;
+                continue;
+            },
+            Value::Array(_) => return Err(format!("variable `{}` is an array, which cannot be rendered", name))
+        };
+        vars.insert(name, text);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_vars_file_with_tables() {
+        let vars = parse_vars("region = \"ap-southeast-2\"\nshards = 3\n[db]\nhost = \"localhost\"").unwrap();
+        assert_eq!(vars.get("region").map(String::as_str), Some("ap-southeast-2"));
+        assert_eq!(vars.get("shards").map(String::as_str), Some("3"));
+        assert_eq!(vars.get("db.host").map(String::as_str), Some("localhost"));
+        assert!(parse_vars("hosts = [\"a\"]").unwrap_err().contains("array"));
+    }
+
+    #[test]
+    fn parse_var_assignments() {
+        assert_eq!(parse_var("tenant=acme=1"), Ok(("tenant".to_owned(), "acme=1".to_owned())));
+        assert_eq!(parse_var("tenant"), Err("malformed variable `tenant`, expected `name=value`".to_owned()));
+    }
+}
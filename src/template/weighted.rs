@@ -0,0 +1,74 @@
+use super::Template;
+
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+use std::collections::HashMap;
+
+/// Several templates of which each document uses one, picked at random in proportion to its weight.
+#[derive(Clone,Debug)]
+pub struct WeightedTemplates {
+    templates: Vec<Template>,
+    weights: WeightedIndex<u32>
+}
+
+impl WeightedTemplates {
+    /// Returns `None` if there are no templates or every weight is zero.
+    pub fn new(weighted: Vec<(Template, u32)>) -> Option<WeightedTemplates> {
+        let weights = WeightedIndex::new(weighted.iter().map(|(_, weight)| *weight)).ok()?;
+        let templates: Vec<Template> = weighted.into_iter().map(|(template, _)| template).collect();
+        Some(WeightedTemplates { templates, weights })
+    }
+
+    /// Picks the template for a document. A single template is returned without drawing
+    /// from `rng`, so its documents are the same as when it is rendered on its own.
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> &Template {
+        match self.templates.len() {
+            1 => &self.templates[0],
+            _ => &self.templates[self.weights.sample(rng)]
+        }
+    }
+
+    /// The values of each sequence that any one of the templates can take in a document.
+    pub fn sequence_strides(&self) -> HashMap<String, u64> {
+        let mut strides: HashMap<String, u64> = HashMap::new();
+        for template in &self.templates {
+            for (name, stride) in template.sequence_strides() {
+                let most: &mut u64 = strides.entry(name.clone()).or_default();
+                *most = (*most).max(*stride);
+            }
+        }
+        strides
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    #[test]
+    fn picks_templates_in_proportion_to_weight() {
+        let weighted = WeightedTemplates::new(vec![
+            (Template::compile("login").unwrap(), 70),
+            (Template::compile("purchase").unwrap(), 25),
+            (Template::compile("error").unwrap(), 5),
+            (Template::compile("never").unwrap(), 0)
+        ]).unwrap();
+        let documents: Vec<String> = (0..1000)
+            .map(|index: u64| {
+                let rng = &mut rng::document_rng(3, index);
+                weighted.choose(rng).render(rng)
+            })
+            .collect();
+        let logins: usize = documents.iter().filter(|doc| *doc == "login").count();
+        assert!(logins > 600 && logins < 800, "{} logins out of 1000", logins);
+        assert!(documents.iter().any(|doc| doc == "error"));
+        assert!(documents.iter().all(|doc| doc != "never"));
+    }
+
+    #[test]
+    fn rejects_all_zero_weights() {
+        assert!(WeightedTemplates::new(vec![(Template::compile("a").unwrap(), 0)]).is_none());
+        assert!(WeightedTemplates::new(vec![]).is_none());
+    }
+}
@@ -1,35 +1,82 @@
-#[derive(Clone,Debug)]
+#[derive(Clone,Debug,PartialEq)]
 pub enum NameType {
     First,
     Last,
     Full
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone,Debug,PartialEq)]
 pub enum LocationType {
     Place,
     Street,
     Address
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone,Debug,PartialEq)]
 pub enum PhoneType {
     Mobile,
     Landline,
     Any
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone,Debug,PartialEq)]
+pub enum InternetType {
+    Email,
+    Username
+}
+
+#[derive(Clone,Debug,PartialEq)]
+pub enum NetworkType {
+    Ipv4,
+    Ipv6,
+    Mac,
+    Hostname,
+    Url,
+    Port,
+    UserAgent
+}
+
+#[derive(Clone,Debug,PartialEq)]
+pub enum TextType {
+    Words,
+    Sentence,
+    Paragraphs,
+    Chars
+}
+
+#[derive(Clone,Debug,PartialEq)]
 pub enum DistributionType {
     Normal
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone,Debug,PartialEq)]
+pub enum DocType {
+    Index,
+    Count,
+    Sequence,
+    Stream
+}
+
+/// Values given to the tool rather than generated, such as `${var::region}`.
+#[derive(Clone,Debug,PartialEq)]
+pub enum ExternalType {
+    Var,
+    Env
+}
+
+#[derive(Clone,Debug,PartialEq)]
 pub enum PlaceholderType {
     Name(NameType),
     Location(LocationType),
     Phone(PhoneType),
+    Internet(InternetType),
+    Network(NetworkType),
+    Text(TextType),
     Distribution(DistributionType),
+    Doc(DocType),
+    External(ExternalType),
+    Date,
+    DateTime,
     Guid,
     Float,
     Int,
@@ -38,11 +85,77 @@ pub enum PlaceholderType {
 
 // ------------------------------------------
 
-#[derive(Clone,Debug)]
+#[derive(Clone,Debug,PartialEq)]
 pub enum PlaceholderArgs {
     Float { min: f64, max: f64 },
     Int { min: i64, max: i64 },
     IntRepeated { min: i64, max: i64, repeat: u64 },
     Set { options: Vec<String> },
-    Normal { mean: f64, stddev: f64 }
-}
\ No newline at end of file
+    Normal { mean: f64, stddev: f64 },
+    Index { pad: usize },
+    /// `name` is filled in by the template compiler for sequences that were not given one.
+    Sequence { start: i64, step: i64, name: Option<String> },
+    /// `value` is filled in by the template compiler from the variables it is given.
+    External { name: String, value: Option<String> },
+    /// Bounds are milliseconds since the Unix epoch, both included.
+    Time { from: i64, to: i64, format: TimeFormat },
+    /// `start` is milliseconds since the Unix epoch, and `mean` the mean milliseconds between documents.
+    Stream { start: i64, mean: f64, arrival: Arrival, format: TimeFormat, name: String },
+    Username { pattern: Vec<UsernamePart>, lowercase: bool },
+    Email { pattern: Vec<UsernamePart>, lowercase: bool, domains: Vec<String> },
+    /// A network of `2^(32 - prefix)` addresses starting at `network`.
+    Ipv4 { network: u32, prefix: u32 },
+    Ipv6 { network: u128, prefix: u32 },
+    Host { domain: Option<String> },
+    Url { scheme: String, domain: Option<String> },
+    /// Both bounds are included.
+    Port { min: u16, max: u16 },
+    /// A number of words, or of characters for `text::chars`, from `min` to `max`, both included.
+    Length { min: u64, max: u64 },
+    Paragraphs { count: u64 }
+}
+
+/// A piece of a username pattern such as `{first}.{last}{nn}`.
+#[derive(Clone,Debug,PartialEq)]
+pub enum UsernamePart {
+    Text(String),
+    First,
+    Last,
+    FirstInitial,
+    LastInitial,
+    /// The entity's number, written with this many digits.
+    Digits(usize)
+}
+
+/// How the time between consecutive documents of a stream is distributed.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Arrival {
+    Exponential,
+    Uniform,
+    Fixed
+}
+
+/// How a generated date or time is written.
+#[derive(Clone,Debug,PartialEq)]
+pub enum TimeFormat {
+    Rfc3339,
+    Epoch,
+    EpochMillis,
+    /// A strftime pattern such as `%Y-%m-%d`.
+    Pattern(String)
+}
+// ------------------------------------------
+
+/// A section of a template delimited by `${#name ...}` and `${/name}`.
+#[derive(Clone,Debug,PartialEq)]
+pub enum BlockType {
+    Repeat { min: u64, max: u64, separator: String },
+    Maybe { probability: f64 }
+}
+
+/// Arguments every placeholder accepts on top of those of its type.
+#[derive(Clone,Debug,PartialEq,Default)]
+pub struct Modifiers {
+    /// Probability of rendering the null token instead of a generated value.
+    pub null_probability: Option<f64>
+}